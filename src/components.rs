@@ -22,6 +22,8 @@ pub struct GameOverModal;
 pub enum GameState {
     #[default]
     MainMenu,
+    Lobby, // Online games gather here before Setup
+    Gallery, // Card gallery, opened from the main menu
     Setup,
     Spring,
     Summer,
@@ -36,9 +38,10 @@ pub struct TurnOrder {
     pub current_player: usize,
     pub wake_up_order: Vec<(PlayerId, u8)>,
     pub wake_up_bonuses: Vec<WakeUpBonus>,
+    pub passed: Vec<PlayerId>, // Out of the current season; cleared when the next one starts
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum WakeUpBonus {
     DrawVineCard,
     GainLira(u8),
@@ -57,19 +60,42 @@ impl TurnOrder {
         }
     }
     
-    pub fn get_wake_up_bonus(&self, player_id: PlayerId) -> Option<WakeUpBonus> {
-        if let Some(position) = self.wake_up_order.iter().position(|(id, _)| *id == player_id) {
-            match position {
-                0 => Some(WakeUpBonus::DrawVineCard),
-                1 => Some(WakeUpBonus::GainLira(1)),
-                2 => None,
-                3 => Some(WakeUpBonus::GainLira(1)),
-                4 => Some(WakeUpBonus::DrawWineOrderCard),
-                _ => Some(WakeUpBonus::GainVictoryPoint),
+    pub fn get_wake_up_bonus(&self, player_id: PlayerId, chart: &WakeUpChart) -> Option<WakeUpBonus> {
+        let (_, row) = self.wake_up_order.iter().find(|(id, _)| *id == player_id)?;
+        chart.bonus_at(row.saturating_sub(1) as usize)
+    }
+
+    pub fn has_passed(&self, player_id: PlayerId) -> bool {
+        self.passed.contains(&player_id)
+    }
+
+    pub fn pass(&mut self, player_id: PlayerId) {
+        if !self.has_passed(player_id) {
+            self.passed.push(player_id);
+        }
+    }
+
+    pub fn all_passed(&self) -> bool {
+        self.players.iter().all(|id| self.has_passed(*id))
+    }
+
+    // Hands the turn to the next player still in the season; false once everyone has passed
+    pub fn advance(&mut self) -> bool {
+        let count = self.players.len();
+        for offset in 1..=count {
+            let idx = (self.current_player + offset) % count;
+            if !self.has_passed(self.players[idx]) {
+                self.current_player = idx;
+                return true;
             }
-        } else {
-            None
         }
+        false
+    }
+
+    // First in wake-up order starts, with nobody passed
+    pub fn start_season(&mut self) {
+        self.current_player = 0;
+        self.passed.clear();
     }
 }
 
@@ -80,6 +106,7 @@ pub struct GameConfig {
     pub current_year: u8,
     pub max_years: u8,
     pub ai_count: u8, // New: track AI players separately
+    pub seed: Option<u64>, // Fixed seed for a reproducible game; None draws one at setup
 }
 
 impl Default for GameConfig {
@@ -90,19 +117,120 @@ impl Default for GameConfig {
             current_year: 1,
             max_years: 7,
             ai_count: 1, // Default to 1 AI opponent
+            seed: None,
         }
     }
 }
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct PlayerId(pub u8);
 
-#[derive(Component)]
+// Occupant of action spaces blocked by the co-op Automa opponent
+pub const AUTOMA_PLAYER: PlayerId = PlayerId(u8::MAX);
+
+// A resource count that can't leave its game bounds: adding saturates at MAX, spending
+// saturates at zero. Compares and adds against plain u8 amounts.
+macro_rules! bounded_quantity {
+    ($name:ident, $max:expr) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(u8);
+
+        impl $name {
+            pub const MAX: u8 = $max;
+
+            pub const fn new(amount: u8) -> Self {
+                Self(if amount > Self::MAX { Self::MAX } else { amount })
+            }
+
+            pub const fn get(self) -> u8 {
+                self.0
+            }
+
+            // Takes `cost` if there is enough; nothing changes otherwise
+            pub fn spend(&mut self, cost: u8) -> bool {
+                if self.0 < cost {
+                    return false;
+                }
+                self.0 -= cost;
+                true
+            }
+        }
+
+        impl From<u8> for $name {
+            fn from(amount: u8) -> Self {
+                Self::new(amount)
+            }
+        }
+
+        impl std::ops::Add<u8> for $name {
+            type Output = Self;
+            fn add(self, amount: u8) -> Self {
+                Self::new(self.0.saturating_add(amount))
+            }
+        }
+
+        impl std::ops::AddAssign<u8> for $name {
+            fn add_assign(&mut self, amount: u8) {
+                *self = *self + amount;
+            }
+        }
+
+        impl std::ops::Sub<u8> for $name {
+            type Output = Self;
+            fn sub(self, amount: u8) -> Self {
+                Self(self.0.saturating_sub(amount))
+            }
+        }
+
+        impl std::ops::SubAssign<u8> for $name {
+            fn sub_assign(&mut self, amount: u8) {
+                *self = *self - amount;
+            }
+        }
+
+        // Whole units per `divisor`, e.g. a VP per 7 lira
+        impl std::ops::Div<u8> for $name {
+            type Output = u8;
+            fn div(self, divisor: u8) -> u8 {
+                self.0 / divisor
+            }
+        }
+
+        impl PartialEq<u8> for $name {
+            fn eq(&self, other: &u8) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialOrd<u8> for $name {
+            fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+// Lira held by a player or on a vineyard
+bounded_quantity!(Lira, 50);
+// A player's score track
+bounded_quantity!(VicPoints, 99);
+
+// Trained workers, not counting the grande
+pub const MAX_WORKERS: u8 = 8;
+
+#[derive(Component, Clone)]
 pub struct Player {
     pub id: PlayerId,
     pub name: String,
-    pub victory_points: u8,
-    pub lira: u8,
+    pub victory_points: VicPoints,
+    pub lira: Lira,
     pub workers: u8,
     pub grande_worker_available: bool,
     pub is_ai: bool, // New: track if player is AI
@@ -113,8 +241,8 @@ impl Player {
         Self {
             id: PlayerId(id),
             name,
-            victory_points: 0,
-            lira: 3,
+            victory_points: VicPoints::new(0),
+            lira: Lira::new(3),
             workers: 2, // Base workers (not counting grande)
             grande_worker_available: true,
             is_ai,
@@ -122,11 +250,20 @@ impl Player {
     }
     
     pub fn gain_victory_points(&mut self, points: u8) {
-        self.victory_points = self.victory_points.saturating_add(points);
+        self.victory_points += points;
     }
     
     pub fn gain_lira(&mut self, amount: u8) {
-        self.lira = self.lira.saturating_add(amount);
+        self.lira += amount;
+    }
+
+    // Adds a trained worker unless the player already has MAX_WORKERS
+    pub fn gain_worker(&mut self) -> bool {
+        if self.workers >= MAX_WORKERS {
+            return false;
+        }
+        self.workers += 1;
+        true
     }
     
     // New: get total worker count (including grande)
@@ -136,15 +273,189 @@ impl Player {
 }
 
 // Enhanced vineyard with better field representation
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Vineyard {
     pub owner: PlayerId,
     pub fields: [VineyardField; 9],
-    pub red_grapes: u8,
-    pub white_grapes: u8,
-    pub red_wine: u8,
-    pub white_wine: u8,
-    pub lira: u8,
+    // Crush pad
+    pub red_grapes: ValueTrack,
+    pub white_grapes: ValueTrack,
+    // Cellar; blush is kept on the white row and sparkling on the red
+    pub red_wine: ValueTrack,
+    pub white_wine: ValueTrack,
+    pub lira: Lira,
+    // Recipe picked in the make-wine dialog for the next Make Wine action; None lets the rules choose
+    pub wine_recipe: Option<WineType>,
+    // Fields picked in the harvest picker for the next Harvest action; empty harvests the best ones
+    pub harvest_selection: Vec<usize>,
+    // Red and white tokens picked in the order token picker for the next Fill Order; None spends the cheapest
+    pub order_wines: (Option<u8>, Option<u8>),
+    // When each cellar token was made; tokens from visitors and old saves have no entry
+    pub vintages: Vec<Vintage>,
+    // Sparkling aging house rule: sparkling can't fill an order until it has aged a year
+    pub sparkling_aging: bool,
+}
+
+pub const MAX_TOKEN_VALUE: u8 = 9;
+
+// Fields a Harvest action may pick; the first worker on the bonus slot picks one more
+pub const HARVEST_FIELD_LIMIT: usize = 2;
+
+pub fn harvest_limit(bonus: bool) -> usize {
+    HARVEST_FIELD_LIMIT + if bonus { 1 } else { 0 }
+}
+
+// A crush pad or cellar row: one slot per value 1-9, each holding at most one token
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ValueTrack {
+    pub slots: [bool; MAX_TOKEN_VALUE as usize],
+}
+
+impl ValueTrack {
+    pub fn from_values(values: &[u8]) -> Self {
+        let mut track = Self::default();
+        for value in values {
+            track.place(*value);
+        }
+        track
+    }
+
+    pub fn count(&self) -> u8 {
+        self.slots.iter().filter(|s| **s).count() as u8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    // Token values, lowest first
+    pub fn values(&self) -> Vec<u8> {
+        (1..=MAX_TOKEN_VALUE).filter(|v| self.has(*v)).collect()
+    }
+
+    pub fn has(&self, value: u8) -> bool {
+        (1..=MAX_TOKEN_VALUE).contains(&value) && self.slots[value as usize - 1]
+    }
+
+    pub fn highest(&self) -> Option<u8> {
+        (1..=MAX_TOKEN_VALUE).rev().find(|v| self.has(*v))
+    }
+
+    pub fn total_value(&self) -> u8 {
+        self.values().iter().fold(0u8, |sum, v| sum.saturating_add(*v))
+    }
+
+    // A token that lands on a taken slot slides down to the next free one; with none free it is lost
+    pub fn place(&mut self, value: u8) -> Option<u8> {
+        let value = value.clamp(1, MAX_TOKEN_VALUE);
+        let slot = (1..=value).rev().find(|v| !self.has(*v))?;
+        self.slots[slot as usize - 1] = true;
+        Some(slot)
+    }
+
+    pub fn take(&mut self, value: u8) -> bool {
+        if self.has(value) {
+            self.slots[value as usize - 1] = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn take_highest(&mut self) -> Option<u8> {
+        let value = self.highest()?;
+        self.take(value);
+        Some(value)
+    }
+
+    // Cheapest token that still meets the minimum, so better wine is kept for bigger orders
+    pub fn lowest_at_least(&self, min: u8) -> Option<u8> {
+        (min.max(1)..=MAX_TOKEN_VALUE).find(|v| self.has(*v))
+    }
+
+    pub fn take_at_least(&mut self, min: u8) -> Option<u8> {
+        let value = self.lowest_at_least(min)?;
+        self.take(value);
+        Some(value)
+    }
+
+    // A picked token is spent if it is here and meets the minimum, otherwise the cheapest that does
+    pub fn take_picked_or_lowest(&mut self, min: u8, picked: Option<u8>) -> Option<u8> {
+        match picked.filter(|v| *v >= min && self.has(*v)) {
+            Some(value) => {
+                self.take(value);
+                Some(value)
+            }
+            None => self.take_at_least(min),
+        }
+    }
+
+    pub fn clear(&mut self) -> Vec<u8> {
+        let values = self.values();
+        self.slots = [false; MAX_TOKEN_VALUE as usize];
+        values
+    }
+
+    // Year-end aging: highest tokens move first, each up one slot if it is free and within the cap
+    pub fn age(&mut self, cap: u8) {
+        for value in (1..MAX_TOKEN_VALUE).rev() {
+            if self.has(value) && value + 1 <= cap && !self.has(value + 1) {
+                self.slots[value as usize - 1] = false;
+                self.slots[value as usize] = true;
+            }
+        }
+    }
+}
+
+// Lira for one grape token on the Sell Grapes action
+pub fn grape_sale_price(value: u8) -> u8 {
+    (value + 2) / 3
+}
+
+// Year tag for a wine token in the cellar; it follows the token as it ages
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vintage {
+    pub wine_type: WineType,
+    pub value: u8,
+    pub year: u8,
+    pub aged: u8, // Year ends spent in the cellar
+}
+
+impl Vintage {
+    // Sparkling shares the red row and blush the white
+    pub fn on_red_row(&self) -> bool {
+        matches!(self.wine_type, WineType::Red | WineType::Sparkling)
+    }
+
+    // Sparkling made this year, which the aging house rule keeps off orders
+    pub fn resting(&self) -> bool {
+        self.wine_type == WineType::Sparkling && self.aged == 0
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct WineAgingPreview {
+    pub wine_type: WineType,
+    pub current: u8,
+    pub next: u8,
+    pub cellar_capacity: u8,
+}
+
+impl WineAgingPreview {
+    pub fn overflows(&self) -> bool {
+        self.next > self.cellar_capacity
+    }
+    
+    // Cellar needed to store the aged token, if the player doesn't already have it
+    pub fn required_cellar(&self) -> Option<StructureType> {
+        if !self.overflows() {
+            None
+        } else if self.next <= 6 {
+            Some(StructureType::MediumCellar)
+        } else {
+            Some(StructureType::LargeCellar)
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -162,7 +473,17 @@ pub enum FieldType {
     Poor,    // -1 to vine value (minimum 1)
 }
 
-#[derive(Clone, Copy, Debug)]
+impl FieldType {
+    pub fn description(&self) -> &'static str {
+        match self {
+            FieldType::Standard => "Standard field: vines harvest at face value",
+            FieldType::Premium => "Premium field: +1 grape per harvest",
+            FieldType::Poor => "Poor field: -1 grape per harvest (minimum 1)",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum WineType {
     Red,
     White,
@@ -181,20 +502,25 @@ impl VineyardField {
     
     pub fn get_harvest_value(&self) -> u8 {
         if let Some(vine) = self.vine {
-            let base_value = match vine {
-                VineType::Red(v) | VineType::White(v) => v,
-            };
-            
-            match self.field_type {
-                FieldType::Premium => base_value + 1,
-                FieldType::Poor => base_value.saturating_sub(1).max(1),
-                FieldType::Standard => base_value,
-            }
+            self.projected_harvest(vine)
         } else {
             0
         }
     }
     
+    // Grapes a vine would yield on this field, including the field quality modifier
+    pub fn projected_harvest(&self, vine: VineType) -> u8 {
+        let base_value = match vine {
+            VineType::Red(v) | VineType::White(v) => v,
+        };
+        
+        match self.field_type {
+            FieldType::Premium => base_value + 1,
+            FieldType::Poor => base_value.saturating_sub(1).max(1),
+            FieldType::Standard => base_value,
+        }
+    }
+    
     pub fn can_sell(&self) -> bool {
         self.vine.is_none() // Can only sell empty fields
     }
@@ -243,11 +569,16 @@ impl Vineyard {
         Self {
             owner,
             fields,
-            red_grapes: 0,
-            white_grapes: 0,
-            red_wine: 0,
-            white_wine: 0,
-            lira: 3,
+            red_grapes: ValueTrack::default(),
+            white_grapes: ValueTrack::default(),
+            red_wine: ValueTrack::default(),
+            white_wine: ValueTrack::default(),
+            lira: Lira::new(3),
+            wine_recipe: None,
+            harvest_selection: Vec::new(),
+            order_wines: (None, None),
+            vintages: Vec::new(),
+            sparkling_aging: false,
         }
     }
     
@@ -322,38 +653,63 @@ impl Vineyard {
             }
             
             self.fields[field_index].vine = Some(vine_card.vine_type);
-            self.lira = self.lira.saturating_sub(cost);
+            self.lira -= cost;
             true
         } else {
             false
         }
     }
     
+    // Each planted field puts one grape token on the crush pad at its harvest value; returns tokens placed
     pub fn harvest_grapes(&mut self, structures: &[Structure]) -> u8 {
+        self.harvest_fields(structures, self.fields.len())
+    }
+    
+    // Planted fields that would yield a grape, highest harvest value first
+    pub fn harvestable_fields(&self) -> Vec<usize> {
+        let mut planted: Vec<usize> = (0..self.fields.len())
+            .filter(|i| self.fields[*i].get_harvest_value() > 0)
+            .collect();
+        planted.sort_by_key(|i| std::cmp::Reverse(self.fields[*i].get_harvest_value()));
+        planted
+    }
+    
+    // Harvests at most `limit` planted fields, highest harvest value first
+    pub fn harvest_fields(&mut self, structures: &[Structure], limit: usize) -> u8 {
+        let best: Vec<usize> = self.harvestable_fields().into_iter().take(limit).collect();
+        self.harvest_selected(structures, &best)
+    }
+    
+    // Harvests exactly the given fields; unplanted or repeated ones are skipped
+    pub fn harvest_selected(&mut self, structures: &[Structure], selection: &[usize]) -> u8 {
         let mut total_gained = 0;
+        let has_trellis = structures.iter().any(|s| matches!(s.structure_type, StructureType::Trellis) && s.owner == self.owner);
         
-        for field in &mut self.fields {
-            let harvest_value = field.get_harvest_value();
-            if harvest_value > 0 {
-                let mut final_value = harvest_value;
-                
-                // Trellis structure bonus
-                if structures.iter().any(|s| matches!(s.structure_type, StructureType::Trellis) && s.owner == self.owner) {
-                    final_value += 1;
-                }
-                
-                if let Some(vine) = field.vine {
-                    match vine {
-                        VineType::Red(_) => {
-                            self.red_grapes += final_value;
-                            total_gained += final_value;
-                        },
-                        VineType::White(_) => {
-                            self.white_grapes += final_value;
-                            total_gained += final_value;
-                        },
-                    }
-                }
+        let mut picked: Vec<usize> = Vec::new();
+        for index in selection.iter().copied() {
+            if index < self.fields.len() && !picked.contains(&index) {
+                picked.push(index);
+            }
+        }
+        let planted: Vec<(VineType, u8)> = picked.into_iter()
+            .filter_map(|i| self.fields[i].vine.map(|vine| (vine, self.fields[i].get_harvest_value())))
+            .filter(|(_, value)| *value > 0)
+            .collect();
+        
+        for (vine, harvest_value) in planted {
+            let mut final_value = harvest_value;
+            
+            // Trellis structure bonus
+            if has_trellis {
+                final_value += 1;
+            }
+            
+            let placed = match vine {
+                VineType::Red(_) => self.red_grapes.place(final_value),
+                VineType::White(_) => self.white_grapes.place(final_value),
+            };
+            if placed.is_some() {
+                total_gained += 1;
             }
         }
         
@@ -367,42 +723,94 @@ impl Vineyard {
         total_gained
     }
     
-    pub fn make_wine(&mut self, red_grapes_used: u8, white_grapes_used: u8) -> bool {
-        if self.red_grapes >= red_grapes_used && self.white_grapes >= white_grapes_used {
-            self.red_grapes -= red_grapes_used;
-            self.white_grapes -= white_grapes_used;
-            self.red_wine += red_grapes_used;
-            self.white_wine += white_grapes_used;
-            true
-        } else {
-            false
-        }
+    // Single-grape red or white wine from the best grape of that color, no higher than the
+    // cellar capacity; returns the wine value
+    pub fn make_wine(&mut self, wine_type: WineType, capacity: u8) -> Option<u8> {
+        let (grapes, cellar) = match wine_type {
+            WineType::Red => (&mut self.red_grapes, &mut self.red_wine),
+            WineType::White => (&mut self.white_grapes, &mut self.white_wine),
+            _ => return None,
+        };
+        let value = grapes.take_highest()?;
+        cellar.place(value.min(capacity))
     }
     
+    // Values 4-6 need the medium cellar and 7-9 the large one; blush needs the medium
+    // cellar at all and sparkling the large
     pub fn can_make_wine(&self, wine_type: WineType, value: u8, structures: &[Structure]) -> bool {
-        let has_medium = structures.iter().any(|s| matches!(s.structure_type, StructureType::Cottage)); // Should be Medium Cellar
-        let has_large = structures.iter().any(|s| matches!(s.structure_type, StructureType::Windmill)); // Should be Large Cellar
+        let capacity = self.cellar_capacity(structures);
         
         match wine_type {
-            WineType::Red | WineType::White => {
-                if value <= 3 { true }
-                else if value <= 6 && has_medium { true }
-                else if value <= 9 && has_medium && has_large { true }
-                else { false }
-            }
-            WineType::Blush => has_medium && value >= 4,
-            WineType::Sparkling => has_large && value >= 7,
+            WineType::Red | WineType::White => value <= capacity,
+            WineType::Blush => capacity >= 6 && value >= 4 && value <= capacity,
+            WineType::Sparkling => capacity >= 9 && value >= 7,
         }
     }
 
+    // Orders ask for one red and/or one white wine of at least the printed value
     pub fn can_fulfill_order(&self, order: &WineOrderCard) -> bool {
-        self.red_wine >= order.red_wine_needed && self.white_wine >= order.white_wine_needed
+        let red_ok = order.red_wine_needed == 0 || self.order_red_wine().lowest_at_least(order.red_wine_needed).is_some();
+        let white_ok = order.white_wine_needed == 0 || self.white_wine.lowest_at_least(order.white_wine_needed).is_some();
+        red_ok && white_ok
+    }
+    
+    // Red row minus any sparkling the aging house rule says is still resting
+    pub fn order_red_wine(&self) -> ValueTrack {
+        let mut track = self.red_wine;
+        if self.sparkling_aging {
+            for vintage in self.vintages.iter().filter(|v| v.resting()) {
+                track.take(vintage.value);
+            }
+        }
+        track
+    }
+    
+    // Removes the picked wines if they still satisfy the order, otherwise the cheapest that do
+    pub fn take_order_wines(&mut self, order: &WineOrderCard) -> bool {
+        let (red, white) = std::mem::take(&mut self.order_wines);
+        if !self.can_fulfill_order(order) {
+            return false;
+        }
+        if order.red_wine_needed > 0 {
+            if let Some(value) = self.order_red_wine().take_picked_or_lowest(order.red_wine_needed, red) {
+                self.red_wine.take(value);
+                self.vintages.retain(|v| !(v.on_red_row() && v.value == value));
+            }
+        }
+        if order.white_wine_needed > 0 {
+            if let Some(value) = self.white_wine.take_picked_or_lowest(order.white_wine_needed, white) {
+                self.vintages.retain(|v| v.on_red_row() || v.value != value);
+            }
+        }
+        true
+    }
+    
+    // The tag for the token at `value` on the row `wine_type` is kept on
+    pub fn vintage(&self, wine_type: WineType, value: u8) -> Option<&Vintage> {
+        let red_row = matches!(wine_type, WineType::Red | WineType::Sparkling);
+        let track = if red_row { &self.red_wine } else { &self.white_wine };
+        self.vintages.iter()
+            .find(|v| v.on_red_row() == red_row && v.value == value)
+            .filter(|_| track.has(value))
+    }
+    
+    // Tags a freshly made token, replacing whatever tag its slot had
+    pub fn record_vintage(&mut self, wine_type: WineType, value: u8, year: u8) {
+        let vintage = Vintage { wine_type, value, year, aged: 0 };
+        self.vintages.retain(|v| v.on_red_row() != vintage.on_red_row() || v.value != value);
+        self.vintages.push(vintage);
+    }
+    
+    // A visitor moved a token along its row; its tag goes with it
+    pub fn move_vintage(&mut self, wine_type: WineType, from: u8, to: u8) {
+        let red_row = matches!(wine_type, WineType::Red | WineType::Sparkling);
+        for vintage in self.vintages.iter_mut().filter(|v| v.on_red_row() == red_row && v.value == from) {
+            vintage.value = to;
+        }
     }
     
     pub fn fulfill_order(&mut self, order: &WineOrderCard) -> bool {
-        if self.can_fulfill_order(order) {
-            self.red_wine -= order.red_wine_needed;
-            self.white_wine -= order.white_wine_needed;
+        if self.take_order_wines(order) {
             self.lira += order.payout;
             true
         } else {
@@ -436,13 +844,66 @@ impl Vineyard {
                 StructureType::Cottage => 4,
                 StructureType::TastingRoom => 6,
             };
-            self.lira = self.lira.saturating_sub(cost);
+            self.lira -= cost;
             true
         } else {
             false
         }
     }
     
+    // Highest wine value the player's cellar can hold: 3 base, 6 with Medium, 9 with Medium + Large
+    pub fn cellar_capacity(&self, structures: &[Structure]) -> u8 {
+        let owns = |structure_type: StructureType| structures.iter()
+            .any(|s| s.structure_type == structure_type && s.owner == self.owner);
+        
+        if owns(StructureType::MediumCellar) && owns(StructureType::LargeCellar) {
+            9
+        } else if owns(StructureType::MediumCellar) {
+            6
+        } else {
+            3
+        }
+    }
+    
+    // Year-end aging step: every grape and wine token moves up one slot; wine stops at the cellar cap
+    pub fn apply_aging(&mut self, structures: &[Structure]) {
+        let capacity = self.cellar_capacity(structures);
+        self.red_grapes.age(MAX_TOKEN_VALUE);
+        self.white_grapes.age(MAX_TOKEN_VALUE);
+        
+        // Tags whose token was spent elsewhere are dropped; the rest follow their token, which
+        // keeps its rank on the row since aging never lets one token pass another
+        let (red, white) = (self.red_wine, self.white_wine);
+        self.vintages.retain(|v| if v.on_red_row() { red.has(v.value) } else { white.has(v.value) });
+        self.red_wine.age(capacity);
+        self.white_wine.age(capacity);
+        for vintage in self.vintages.iter_mut() {
+            let (before, after) = if vintage.on_red_row() { (red, self.red_wine) } else { (white, self.white_wine) };
+            if let Some(rank) = before.values().iter().position(|v| *v == vintage.value) {
+                vintage.value = after.values()[rank];
+            }
+            vintage.aged = vintage.aged.saturating_add(1);
+        }
+    }
+    
+    // Dry run of the next aging step for each wine token, without touching the live vineyard.
+    // `next` ignores the cellar cap so the tooltip can say which cellar would let it age.
+    pub fn dry_run_aging(&self, structures: &[Structure]) -> Vec<WineAgingPreview> {
+        let cellar_capacity = self.cellar_capacity(structures);
+        let mut previews = Vec::new();
+        
+        for (wine_type, track) in [(WineType::Red, self.red_wine), (WineType::White, self.white_wine)] {
+            let mut aged = track;
+            aged.age(MAX_TOKEN_VALUE);
+            let (before, after) = (track.values(), aged.values());
+            for (current, next) in before.into_iter().zip(after) {
+                previews.push(WineAgingPreview { wine_type, current, next, cellar_capacity });
+            }
+        }
+        
+        previews
+    }
+    
     pub fn get_end_game_bonus(&self, structures: &[Structure]) -> u8 {
         let mut bonus = 0;
         
@@ -495,7 +956,7 @@ impl Vineyard {
     }    
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum VineType {
     Red(u8),
     White(u8),
@@ -670,9 +1131,47 @@ impl WineOrderCard {
     
     pub fn residual_payment(&self) -> u8 {
         self.residual_payment
-    }    
+    }
+
+    // Premium orders push the residual tracker one step further than printed
+    pub fn residual_steps(&self) -> u8 {
+        match self.order_type {
+            OrderType::Premium => self.residual_payment + PREMIUM_ORDER_EXTRA_RESIDUAL,
+            _ => self.residual_payment,
+        }
+    }
+
+    // Seasonal orders are "in season" for a single year of the game
+    pub fn seasonal_bonus_year(&self) -> Option<u8> {
+        match self.order_type {
+            OrderType::Seasonal => Some(2 + (self.id % 5) as u8),
+            _ => None,
+        }
+    }
+
+    pub fn seasonal_bonus(&self, current_year: u8) -> u8 {
+        match self.seasonal_bonus_year() {
+            Some(year) if year == current_year => SEASONAL_ORDER_BONUS_LIRA,
+            _ => 0,
+        }
+    }
+
+    pub fn effect_text(&self) -> String {
+        match self.order_type {
+            OrderType::Regular => String::new(),
+            OrderType::Premium => format!("Premium: residual +{}", PREMIUM_ORDER_EXTRA_RESIDUAL),
+            OrderType::Seasonal => format!(
+                "Seasonal: +{} lira in year {}",
+                SEASONAL_ORDER_BONUS_LIRA,
+                self.seasonal_bonus_year().unwrap_or(0)
+            ),
+        }
+    }
 }
 
+pub const PREMIUM_ORDER_EXTRA_RESIDUAL: u8 = 1;
+pub const SEASONAL_ORDER_BONUS_LIRA: u8 = 2;
+
 #[derive(Component)]
 pub struct Worker {
     pub owner: PlayerId,
@@ -692,7 +1191,7 @@ impl Worker {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ActionSpace {
     // Summer actions
     DrawVine,
@@ -722,6 +1221,53 @@ pub struct ActionSpaceSlot {
     pub position: Vec2,
     pub is_summer: bool,
     pub has_bonus_slot: bool, // New: some spaces have bonus slots
+    pub bonus_chit: Option<BonusChit>, // Rotating yearly bonus for the first worker here
+    pub column: usize, // 0 is the leftmost space; higher columns open up with more players
+}
+
+pub const ACTION_COLUMNS: usize = 3;
+const ACTION_COLUMN_SPACING: f32 = 75.0;
+
+// Columns in play per action: the leftmost at 1–2 players, two at 3–4, all three at 5–6
+pub fn open_columns(player_count: u8) -> usize {
+    match player_count {
+        0..=2 => 1,
+        3..=4 => 2,
+        _ => ACTION_COLUMNS,
+    }
+}
+
+// Player counts printed above each column, as on the printed board
+pub fn column_player_label(column: usize) -> &'static str {
+    match column {
+        0 => "1-6",
+        1 => "3-6",
+        _ => "5-6",
+    }
+}
+
+// Tuscany-style bonus tokens placed on random action spaces each year
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BonusChit {
+    ExtraCard,
+    ExtraLira,
+}
+
+impl BonusChit {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BonusChit::ExtraCard => "+1 card",
+            BonusChit::ExtraLira => "+1 lira",
+        }
+    }
+    
+    // Extra score the AI adds to a space carrying this chit
+    pub fn ai_value(&self) -> f32 {
+        match self {
+            BonusChit::ExtraCard => 0.3,
+            BonusChit::ExtraLira => 0.25,
+        }
+    }
 }
 
 #[derive(Component)]
@@ -738,8 +1284,31 @@ impl ActionSpaceSlot {
             position,
             is_summer,
             has_bonus_slot,
+            bonus_chit: None,
+            column: 0,
+        }
+    }
+
+    // A further column of the same action, stepped outward from the board centre. Only the
+    // leftmost space keeps the grande bonus slot.
+    pub fn extra_column(&self, column: usize) -> Self {
+        let step = if self.is_summer { -ACTION_COLUMN_SPACING } else { ACTION_COLUMN_SPACING };
+        Self {
+            position: self.position + Vec2::new(step * column as f32, 0.0),
+            has_bonus_slot: false,
+            column,
+            ..Self::new(self.action, self.position, self.is_summer, false)
         }
     }
+
+    pub fn is_open(&self, player_count: u8) -> bool {
+        self.is_available_for_player_count(player_count, self.column)
+    }
+
+    // The first worker on a bonus space (the leftmost column of a "(+1)" action) gets its extra effect
+    pub fn bonus_free(&self) -> bool {
+        self.has_bonus_slot && self.occupied_by.is_none()
+    }
     
     pub fn can_place_worker(&self, _player_id: PlayerId, current_state: &GameState) -> bool {
         if self.occupied_by.is_some() {
@@ -764,12 +1333,7 @@ impl ActionSpaceSlot {
     }
 
     pub fn is_available_for_player_count(&self, player_count: u8, position: usize) -> bool {
-        match player_count {
-            1..=2 => position == 0, // Only leftmost space
-            3..=4 => position <= 1,  // Left and middle spaces
-            5..=6 => position <= 2,  // All three spaces
-            _ => true,
-        }
+        position < open_columns(player_count)
     }
 
     pub fn place_grande_on_occupied(&mut self, player_id: PlayerId) -> bool {
@@ -809,10 +1373,54 @@ impl ActionBoard {
         spaces.push(ActionSpaceSlot::new(ActionSpace::FillOrder, Vec2::new(300.0, -50.0), false, false));
         spaces.push(ActionSpaceSlot::new(ActionSpace::TrainWorker, Vec2::new(300.0, -100.0), false, false));
         
+        // Extra columns go after the leftmost ones so saves from before they existed still line up
+        let leftmost = spaces.clone();
+        for column in 1..ACTION_COLUMNS {
+            spaces.extend(leftmost.iter().map(|space| space.extra_column(column)));
+        }
+        
         Self { spaces }
     }
 }
 
+// The grande's dedicated slot on an occupied space is only for when every open column of
+// the action is taken; while one is free the grande goes there like any other worker
+pub fn action_row_full<'a>(
+    spaces: impl IntoIterator<Item = &'a ActionSpaceSlot>,
+    action: ActionSpace,
+    player_count: u8,
+) -> bool {
+    spaces.into_iter()
+        .filter(|s| s.action == action && s.is_open(player_count))
+        .all(|s| s.occupied_by.is_some())
+}
+
+// Where a worker sent to `action` goes: the leftmost free open column, or failing that
+// (grande only) the bonus slot of an occupied one. The bool is true for the bonus slot.
+pub fn placement_slot<'a>(
+    spaces: impl IntoIterator<Item = &'a ActionSpaceSlot>,
+    action: ActionSpace,
+    player: PlayerId,
+    player_count: u8,
+    state: &GameState,
+    grande: bool,
+) -> Option<(usize, bool)> {
+    let mut open: Vec<&ActionSpaceSlot> = spaces.into_iter()
+        .filter(|s| s.action == action && s.is_open(player_count))
+        .collect();
+    open.sort_by_key(|s| s.column);
+
+    if let Some(space) = open.iter().find(|s| s.can_place_worker(player, state)) {
+        return Some((space.column, false));
+    }
+    if !grande {
+        return None;
+    }
+    open.iter()
+        .find(|s| s.can_place_grande_worker(player, state))
+        .map(|s| (s.column, true))
+}
+
 // Mama & Papa Cards - Essential for game variety
 #[derive(Component, Clone)]
 pub struct MamaCard {
@@ -1025,7 +1633,7 @@ impl CardDecks {
     }
 }
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Hand {
     pub owner: PlayerId,
     pub vine_cards: Vec<VineCard>,
@@ -1102,13 +1710,54 @@ pub struct GameAssets {
     pub field_texture: Handle<Image>,
 }
 
-#[derive(Resource)]
+// Handicaps a seat can opt into at setup
+pub const AI_HANDICAP_LIRA: u8 = 3;
+pub const HUMAN_HANDICAP_VINES: usize = 1;
+pub const GAME_SETTINGS_PATH: &str = "viticulture_game_settings.json";
+
+// One seat's choices on the setup screen. An AI seat without a difficulty plays at the
+// table's AISettings level, so adaptive difficulty still moves it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct SeatSetup {
+    pub difficulty: Option<AIDifficulty>,
+    // AI seats start with AI_HANDICAP_LIRA more lira, human seats with HUMAN_HANDICAP_VINES more vine cards
+    pub handicap: bool,
+}
+
+impl SeatSetup {
+    pub fn label(&self, is_ai: bool) -> String {
+        let level = match (is_ai, self.difficulty) {
+            (false, _) => "Human".to_string(),
+            (true, Some(difficulty)) => format!("AI {:?}", difficulty),
+            (true, None) => "AI (table level)".to_string(),
+        };
+        match (self.handicap, is_ai) {
+            (false, _) => level,
+            (true, true) => format!("{}, +{} lira", level, AI_HANDICAP_LIRA),
+            (true, false) => format!("{}, +{} vine card", level, HUMAN_HANDICAP_VINES),
+        }
+    }
+
+    // Table level, then each rung of the ladder in turn
+    pub fn cycle_difficulty(&mut self) {
+        self.difficulty = match self.difficulty {
+            None => Some(AIDifficulty::LADDER[0]),
+            Some(difficulty) => difficulty.harder(),
+        };
+    }
+}
+
+#[derive(Resource, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct GameSettings {
     pub ai_enabled: bool,
     pub ai_difficulty: u8,
     pub audio_enabled: bool,
     pub sfx_volume: f32,
     pub music_volume: f32,
+    // By seat index; seats past the end use SeatSetup::default()
+    pub seats: Vec<SeatSetup>,
 }
 
 impl Default for GameSettings {
@@ -1119,7 +1768,38 @@ impl Default for GameSettings {
             audio_enabled: true,
             sfx_volume: 0.7,
             music_volume: 0.3,
+            seats: Vec::new(),
+        }
+    }
+}
+
+impl GameSettings {
+    pub fn load_or_default() -> Self {
+        match std::fs::read_to_string(GAME_SETTINGS_PATH) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = std::fs::write(GAME_SETTINGS_PATH, json);
+        }
+    }
+
+    pub fn seat(&self, seat: u8) -> SeatSetup {
+        self.seats.get(seat as usize).copied().unwrap_or_default()
+    }
+
+    pub fn seat_mut(&mut self, seat: u8) -> &mut SeatSetup {
+        if self.seats.len() <= seat as usize {
+            self.seats.resize(seat as usize + 1, SeatSetup::default());
         }
+        &mut self.seats[seat as usize]
+    }
+
+    pub fn ai_difficulty(&self, seat: u8, table: AIDifficulty) -> AIDifficulty {
+        self.seat(seat).difficulty.unwrap_or(table)
     }
 }
 
@@ -1136,7 +1816,7 @@ pub struct Structure {
     pub owner: PlayerId,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum StructureType {
     Trellis,      // $2 - Required for some vines
     Irrigation,   // $3 - Required for some vines  
@@ -1151,7 +1831,7 @@ pub enum StructureType {
 #[derive(Component)]
 pub struct MarkedForDespawn;
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct ResidualPaymentTracker {
     pub owner: PlayerId,
     pub level: u8, // 0-5, corresponds to lira earned each year
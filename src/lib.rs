@@ -0,0 +1,7 @@
+// Viticulture as a library: add the `ViticulturePlugins` group to a Bevy app, disable the
+// parts you don't want, or compose RulesPlugin / AiPlugin / UiPlugin and friends individually.
+pub mod components;
+pub mod systems;
+pub mod plugins;
+
+pub use plugins::*;
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use crate::components::*;
+use crate::systems::clock::{GameClock, GameTimer};
+use crate::systems::render_layers::UiLayer;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Achievement {
@@ -269,7 +271,7 @@ pub fn achievement_tracking_system(
         // High VP achievement
         let unlocked = achievement_manager.check_achievement(
             &AchievementCondition::ReachVP(30),
-            player.victory_points as u32,
+            player.victory_points.get() as u32,
         );
         for achievement_id in unlocked {
             show_achievement_notification(&mut commands, &achievement_manager, &achievement_id);
@@ -334,11 +336,11 @@ fn show_achievement_notification(
                     ..default()
                 },
                 background_color: Color::from(Srgba::new(0.8, 0.6, 0.0, 0.95)).into(),
-                z_index: ZIndex::Global(1200),
+                z_index: UiLayer::Toast.z_index(),
                 ..default()
             },
             AchievementNotification {
-                timer: Timer::from_seconds(4.0, TimerMode::Once),
+                timer: GameClock::once(GameTimer::AchievementToast),
                 achievement_name: achievement.name.clone(),
             },
         )).with_children(|parent| {
@@ -401,7 +403,7 @@ fn show_achievement_menu(commands: &mut Commands, achievement_manager: &Achievem
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.0, 0.0, 0.8)).into(),
-            z_index: ZIndex::Global(1000),
+            z_index: UiLayer::Screen.z_index(),
             ..default()
         },
         AchievementUI,
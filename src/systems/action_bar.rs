@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::clock::GameClock;
+use crate::systems::legal_actions::{legal_actions, LegalAction, RulesState};
+
+const BUTTON_WIDTH: f32 = 200.0;
+const SWAP_DURATION: f32 = 0.35;
+
+pub const SUMMER_BUTTON_COLOR: Srgba = Srgba::new(0.8, 0.8, 0.2, 0.8);
+pub const WINTER_BUTTON_COLOR: Srgba = Srgba::new(0.2, 0.2, 0.8, 0.8);
+pub const GRANDE_BUTTON_COLOR: Srgba = Srgba::new(0.7, 0.3, 0.8, 0.9);
+
+// Every button the action bar can show; only the current season's entries are visible
+pub const ACTION_BAR_ENTRIES: [(&str, ActionSpace); 10] = [
+    ("Draw Vine", ActionSpace::DrawVine),
+    ("Plant Vine (+1)", ActionSpace::PlantVine),
+    ("Build Structure", ActionSpace::BuildStructure),
+    ("Give Tour (+1)", ActionSpace::GiveTour),
+    ("Sell Grapes", ActionSpace::SellGrapes),
+    ("Train Worker", ActionSpace::TrainWorker),
+    ("Draw Wine Order", ActionSpace::DrawWineOrder),
+    ("Harvest (+1)", ActionSpace::Harvest),
+    ("Make Wine (+1)", ActionSpace::MakeWine),
+    ("Fill Order", ActionSpace::FillOrder),
+];
+
+// Season a button belongs to (matches the season check in ui_button_system)
+pub fn is_summer_button(action: ActionSpace) -> bool {
+    matches!(action,
+        ActionSpace::DrawVine | ActionSpace::PlantVine | ActionSpace::BuildStructure |
+        ActionSpace::GiveTour | ActionSpace::SellGrapes | ActionSpace::TrainWorker)
+}
+
+pub fn button_label(action: ActionSpace) -> &'static str {
+    ACTION_BAR_ENTRIES.iter()
+        .find(|(_, a)| *a == action)
+        .map(|(label, _)| *label)
+        .unwrap_or("Action")
+}
+
+#[derive(Component)]
+pub struct ActionBarHeader;
+
+// Present on buttons whose space is taken but can still be used by the grande worker
+#[derive(Component)]
+pub struct GrandeOverride;
+
+#[derive(Resource)]
+pub struct ActionBarState {
+    pub shown_season: Option<GameState>,
+    pub swap_timer: Timer,
+}
+
+impl Default for ActionBarState {
+    fn default() -> Self {
+        Self {
+            shown_season: None,
+            swap_timer: GameClock::once_secs(SWAP_DURATION),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ButtonAvailability {
+    Open,
+    GrandeOnly,
+    Collapsed,
+}
+
+fn button_availability(
+    action: ActionSpace,
+    season: &GameState,
+    player_id: Option<PlayerId>,
+    spaces: &Query<&ActionSpaceSlot>,
+    legal: &[LegalAction],
+) -> ButtonAvailability {
+    let in_season = match season {
+        GameState::Summer => is_summer_button(action),
+        GameState::Winter => !is_summer_button(action),
+        _ => false,
+    };
+    if player_id.is_none() || !in_season {
+        return ButtonAvailability::Collapsed;
+    }
+
+    if !spaces.iter().any(|s| s.action == action) {
+        return ButtonAvailability::Open;
+    }
+
+    match legal.iter().find(|l| l.action == action) {
+        Some(legal) if legal.blocked => ButtonAvailability::GrandeOnly,
+        Some(_) => ButtonAvailability::Open,
+        None => ButtonAvailability::Collapsed,
+    }
+}
+
+// Shows only the current season's actions, collapses ones that cannot be taken,
+// relabels occupied spaces the grande worker can still use, and grows the
+// buttons back in when the season changes
+pub fn contextual_action_bar_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    current_state: Res<State<GameState>>,
+    turn_order: Res<TurnOrder>,
+    mut bar_state: ResMut<ActionBarState>,
+    mut buttons: Query<(Entity, &ActionButton, &mut Style, &Children, Has<GrandeOverride>)>,
+    mut texts: Query<&mut Text, Without<ActionBarHeader>>,
+    mut headers: Query<&mut Text, With<ActionBarHeader>>,
+    spaces: Query<&ActionSpaceSlot>,
+    workers: Query<&Worker>,
+    config: Res<GameConfig>,
+) {
+    let season = current_state.get().clone();
+    if bar_state.shown_season.as_ref() != Some(&season) {
+        bar_state.shown_season = Some(season.clone());
+        bar_state.swap_timer.reset();
+
+        for mut text in headers.iter_mut() {
+            text.sections[0].value = match season {
+                GameState::Summer => "SUMMER ACTIONS".to_string(),
+                GameState::Winter => "WINTER ACTIONS".to_string(),
+                GameState::Spring => "SPRING - choose wake-up order".to_string(),
+                GameState::Fall => "FALL - harvesting".to_string(),
+                _ => String::new(),
+            };
+            text.sections[0].style.color = match season {
+                GameState::Winter => Color::from(Srgba::new(0.5, 0.5, 1.0, 1.0)),
+                _ => Color::from(Srgba::new(1.0, 1.0, 0.5, 1.0)),
+            };
+        }
+    }
+    bar_state.swap_timer.tick(time.delta());
+
+    // Ease-out so the new season's buttons slide to full width
+    let t = bar_state.swap_timer.fraction();
+    let width = BUTTON_WIDTH * (1.0 - (1.0 - t).powi(2));
+
+    let current_player = turn_order.players.get(turn_order.current_player).copied();
+    let board = RulesState::new(spaces.iter(), &season, config.player_count).with_workers(workers.iter());
+    let legal = current_player.map(|player| legal_actions(&board, player)).unwrap_or_default();
+
+    for (entity, button, mut style, children, has_override) in buttons.iter_mut() {
+        let availability = button_availability(button.action, &season, current_player, &spaces, &legal);
+
+        let display = if availability == ButtonAvailability::Collapsed { Display::None } else { Display::Flex };
+        if style.display != display {
+            style.display = display;
+        }
+        if style.width != Val::Px(width) {
+            style.width = Val::Px(width);
+        }
+
+        let grande_only = availability == ButtonAvailability::GrandeOnly;
+        if grande_only != has_override {
+            if grande_only {
+                commands.entity(entity).insert(GrandeOverride);
+            } else {
+                commands.entity(entity).remove::<GrandeOverride>();
+            }
+
+            let label = if grande_only {
+                format!("{} ★ Grande", button_label(button.action))
+            } else {
+                button_label(button.action).to_string()
+            };
+            for &child in children.iter() {
+                if let Ok(mut text) = texts.get_mut(child) {
+                    text.sections[0].value = label.clone();
+                }
+            }
+        }
+    }
+}
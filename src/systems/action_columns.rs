@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::render_layers::WorldLayer;
+
+// Draws every column of the action board: open columns in their season's colour, columns
+// closed at this player count greyed out, and a gold rim on spaces with a grande bonus slot.
+// Each column is headed by the player counts that use it.
+
+#[derive(Component)]
+pub struct ActionColumnSprite;
+
+const BONUS_RIM: f32 = 4.0;
+const LABEL_OFFSET: f32 = 28.0;
+
+pub fn action_column_sprite_system(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    action_spaces: Query<(&ActionSpaceSlot, &Clickable)>,
+    existing: Query<Entity, With<ActionColumnSprite>>,
+) {
+    // The board itself never moves, so only redraw for a new game or player count
+    if !config.is_changed() && !existing.is_empty() {
+        return;
+    }
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for (space, clickable) in action_spaces.iter() {
+        let color = if !space.is_open(config.player_count) {
+            Color::srgba(0.3, 0.3, 0.3, 0.25)
+        } else if space.is_summer {
+            Color::srgba(0.9, 0.8, 0.3, 0.45)
+        } else {
+            Color::srgba(0.4, 0.5, 0.9, 0.45)
+        };
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(clickable.size),
+                    ..default()
+                },
+                transform: Transform::from_translation(space.position.extend(WorldLayer::Board.at(0.1))),
+                ..default()
+            },
+            ActionColumnSprite,
+        ));
+
+        // The top row of each side carries the column heading
+        if matches!(space.action, ActionSpace::DrawVine | ActionSpace::DrawWineOrder) {
+            let alpha = if space.is_open(config.player_count) { 1.0 } else { 0.4 };
+            commands.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        format!("👥 {}", column_player_label(space.column)),
+                        TextStyle {
+                            font_size: 11.0,
+                            color: Color::srgba(0.9, 0.9, 0.9, alpha),
+                            ..default()
+                        },
+                    ),
+                    transform: Transform::from_translation((space.position + Vec2::new(0.0, LABEL_OFFSET)).extend(WorldLayer::Board.at(0.2))),
+                    ..default()
+                },
+                ActionColumnSprite,
+            ));
+        }
+
+        if space.has_bonus_slot && space.is_open(config.player_count) {
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgba(1.0, 0.84, 0.0, 0.6),
+                        custom_size: Some(clickable.size + Vec2::splat(BONUS_RIM)),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(space.position.extend(WorldLayer::Board.z())),
+                    ..default()
+                },
+                ActionColumnSprite,
+            ));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use crate::components::*;
 use crate::systems::expansions::*;
+use crate::systems::rng::GameRng;
 
 // Extended wine order variety for more strategic depth
 pub fn create_premium_wine_orders() -> Vec<WineOrderCard> {
@@ -366,6 +367,7 @@ pub fn trigger_season_event_system(
     mut players: Query<&mut Player>,
     mut vineyards: Query<&mut Vineyard>,
     expansion_settings: Res<ExpansionSettings>,
+    mut rng: ResMut<GameRng>,
 ) {
     if !expansion_settings.tuscany_enabled {
         return;
@@ -373,7 +375,6 @@ pub fn trigger_season_event_system(
     
     // 20% chance of event each season change
     use rand::Rng;
-    let mut rng = rand::rng();
     
     if current_state.is_changed() && rng.random_bool(0.2) {
         let matching_events: Vec<_> = expansion_content.season_events.iter()
@@ -410,8 +411,9 @@ fn apply_season_event_effect(
         SeasonEventEffect::WeatherEffect(description) => {
             info!("Weather effect: {}", description);
             for mut vineyard in vineyards.iter_mut() {
-                vineyard.red_grapes = vineyard.red_grapes.saturating_sub(1);
-                vineyard.white_grapes = vineyard.white_grapes.saturating_sub(1);
+                // Frost takes the weakest grape of each color
+                vineyard.red_grapes.take_at_least(1);
+                vineyard.white_grapes.take_at_least(1);
             }
         }
         SeasonEventEffect::MarketEvent(description) => {
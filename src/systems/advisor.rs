@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::ai::{gather_ai_decision_input, AIDifficulty, AiDecisionInput, AiRules};
+use crate::systems::game_logic::TOUR_LIRA_REWARD;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Advisor: runs the AI's evaluation for the human on turn and outlines the three best spaces
+// on the action bar, best in gold, with a line saying why each is worth it. The scores are
+// Intermediate's, so the advice is exactly what that AI would weigh in the same seat.
+pub const ADVISOR_HINTS: usize = 3;
+const RANK_COLORS: [Srgba; ADVISOR_HINTS] = [
+    Srgba::new(1.0, 0.84, 0.2, 1.0),
+    Srgba::new(0.85, 0.88, 0.95, 1.0),
+    Srgba::new(0.85, 0.55, 0.3, 1.0),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdvisorHint {
+    pub action: ActionSpace,
+    pub reason: String,
+}
+
+// On an action button outlined by the advisor; the rank picks the glow colour
+#[derive(Component)]
+pub struct AdvisorGlow(pub usize);
+
+#[derive(Component)]
+pub struct AdvisorPanel;
+
+// The best-scoring legal actions, leaving out any the evaluation finds pointless
+pub fn advisor_hints(input: &AiDecisionInput) -> Vec<AdvisorHint> {
+    input.scored_actions().into_iter()
+        .filter(|(_, score)| *score > 0.0)
+        .take(ADVISOR_HINTS)
+        .map(|(action, _)| AdvisorHint { action, reason: hint_reason(action, input) })
+        .collect()
+}
+
+pub fn hint_reason(action: ActionSpace, input: &AiDecisionInput) -> String {
+    let (hand, vineyard) = (&input.hand, &input.vineyard);
+    let grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
+    let reason = match action {
+        ActionSpace::FillOrder => match hand.wine_order_cards.first() {
+            Some(order) => format!("Fill Order: +{} VP available", order.victory_points),
+            None => "Fill Order".to_string(),
+        },
+        ActionSpace::PlantVine => format!("Plant Vine: {} vine card(s) ready to plant", hand.vine_cards.len()),
+        ActionSpace::Harvest => format!("Harvest: {} planted field(s)",
+            vineyard.fields.iter().filter(|f| f.vine.is_some()).count()),
+        ActionSpace::MakeWine => format!("Make Wine: {} grape(s) on the crush pad", grapes),
+        ActionSpace::DrawVine => format!("Draw Vine: {} vine card(s) in hand", hand.vine_cards.len()),
+        ActionSpace::DrawWineOrder => format!("Draw Wine Order: {} order(s) in hand", hand.wine_order_cards.len()),
+        ActionSpace::GiveTour => format!("Give Tour: +{} lira", TOUR_LIRA_REWARD),
+        ActionSpace::SellGrapes => format!("Sell Grapes: +{} lira",
+            vineyard.red_grapes.values().into_iter().chain(vineyard.white_grapes.values())
+                .map(grape_sale_price).sum::<u8>()),
+        ActionSpace::TrainWorker => format!("Train Worker: worker {} for next year", input.player.workers + 1),
+        ActionSpace::BuildStructure => "Build Structure: a trellis for red vines".to_string(),
+    };
+    if input.grande_actions.contains(&action) {
+        format!("{} (grande worker)", reason)
+    } else {
+        reason
+    }
+}
+
+pub fn advisor_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    (settings, current_state, config, turn_order): (Res<UserSettings>, Res<State<GameState>>, Res<GameConfig>, Res<TurnOrder>),
+    (workers, action_spaces, hands, vineyards, players): (Query<&Worker>, Query<&ActionSpaceSlot>, Query<&Hand>, Query<&Vineyard>, Query<&Player>),
+    buttons: Query<(Entity, &ActionButton)>,
+    mut glowing: Query<(Entity, &AdvisorGlow, &mut Outline)>,
+    panels: Query<Entity, With<AdvisorPanel>>,
+    mut shown: Local<Vec<AdvisorHint>>,
+) {
+    let human_on_turn = turn_order.players.get(turn_order.current_player).copied()
+        .filter(|id| players.iter().any(|p| p.id == *id && !p.is_ai));
+    let hints = match human_on_turn {
+        Some(player_id) if settings.advisor => gather_ai_decision_input(
+            player_id,
+            AIDifficulty::Intermediate,
+            &workers,
+            &action_spaces,
+            &hands,
+            &vineyards,
+            &players,
+            current_state.get(),
+            config.current_year,
+            config.player_count,
+            AiRules::from_settings(&settings, &config),
+            0,
+        ).map(|input| advisor_hints(&input)).unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    if hints != *shown {
+        for (entity, _, _) in glowing.iter() {
+            commands.entity(entity).remove::<(AdvisorGlow, Outline)>();
+        }
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        for (rank, hint) in hints.iter().enumerate() {
+            for (entity, _) in buttons.iter().filter(|(_, b)| b.action == hint.action) {
+                commands.entity(entity).insert((
+                    AdvisorGlow(rank),
+                    Outline::new(Val::Px(3.0), Val::Px(2.0), Color::from(RANK_COLORS[rank])),
+                ));
+            }
+        }
+        if !hints.is_empty() {
+            spawn_advisor_panel(&mut commands, &hints);
+        }
+        *shown = hints;
+        return;
+    }
+
+    // A slow pulse, so the outlines glow without drawing the eye away from the board
+    let pulse = 0.55 + 0.45 * (time.elapsed_seconds() * 3.0).sin().abs();
+    for (_, glow, mut outline) in glowing.iter_mut() {
+        outline.color = Color::from(RANK_COLORS[glow.0].with_alpha(pulse));
+    }
+}
+
+fn spawn_advisor_panel(commands: &mut Commands, hints: &[AdvisorHint]) {
+    let lines: Vec<String> = hints.iter().enumerate()
+        .map(|(rank, hint)| format!("{}. {}", rank + 1, hint.reason))
+        .collect();
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                left: Val::Px(20.0),
+                max_width: Val::Px(340.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.12, 0.1, 0.05).with_alpha(0.9).into(),
+            border_color: Color::from(RANK_COLORS[0]).into(),
+            z_index: UiLayer::Tips.z_index(),
+            ..default()
+        },
+        AdvisorPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("🔮 Advisor\n{}", lines.join("\n")),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
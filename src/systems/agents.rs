@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::components::*;
+use crate::systems::ai::*;
+use crate::systems::ai_scheduler::AiScheduler;
+use crate::systems::clock::GameClock;
+use crate::systems::input::{ActionRequested, PassEvent};
+use crate::systems::planner::{PlannerInput, PlannerWorld, DEFAULT_PLANNER_BUDGET_MS};
+use crate::systems::rng::GameRng;
+use crate::systems::settings::UserSettings;
+use crate::systems::simulation::SimGame;
+
+pub const AGENT_MATCH_MAX_STEPS: usize = 2000;
+
+/// Everything an agent may look at when it is asked for a move: its own board in full,
+/// the public state of everyone else, and the spaces it can legally take right now.
+#[derive(Clone)]
+pub struct AIView {
+    pub player: Player,
+    pub hand: Hand,
+    pub vineyard: Vineyard,
+    pub opponents: Vec<Player>,
+    pub legal_actions: Vec<ActionSpace>,
+    pub bonus_chits: Vec<(ActionSpace, BonusChit)>,
+    // Legal actions whose bonus space (second vine, extra lira, ...) is still free
+    pub bonus_actions: Vec<ActionSpace>,
+    pub season: GameState,
+    pub year: u8,
+    /// Variants in play, game length and the winning score
+    pub rules: AiRules,
+    /// Fresh from the game's RNG each turn; agents that randomise should draw from it so
+    /// seeded games replay
+    pub seed: u64,
+    /// Spaces this player already has a worker on this year
+    pub placed: Vec<ActionSpace>,
+    /// Rules engine snapshot for agents that search ahead, with the configured thinking time.
+    /// Live agents run on the main thread, so a search holds the frame for that long.
+    pub planner: Option<PlannerInput>,
+}
+
+/// An agent's answer. A pass sits the agent out for the rest of the season; placing on a
+/// space that isn't in `legal_actions` counts as one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AgentAction {
+    Place(ActionSpace),
+    Pass,
+}
+
+/// A pluggable AI player. Agents run on the main thread in live games, so `choose`
+/// should return quickly; the headless simulator calls it as fast as it can.
+///
+/// ```ignore
+/// struct Greedy;
+/// impl Agent for Greedy {
+///     fn name(&self) -> &str { "greedy" }
+///     fn choose(&mut self, view: &AIView) -> AgentAction {
+///         view.legal_actions.first().map_or(AgentAction::Pass, |a| AgentAction::Place(*a))
+///     }
+/// }
+///
+/// app.add_plugins(ViticulturePlugins)
+///     .register_agent("greedy", || Box::new(Greedy))
+///     .seat_agent(PlayerId(1), "greedy");
+/// ```
+pub trait Agent: Send + Sync + 'static {
+    fn name(&self) -> &str;
+    fn choose(&mut self, view: &AIView) -> AgentAction;
+}
+
+/// Built-in AI exposed through the same trait, so custom agents can be matched against it
+pub struct BuiltinAgent {
+    pub difficulty: AIDifficulty,
+}
+
+impl Agent for BuiltinAgent {
+    fn name(&self) -> &str {
+        match self.difficulty {
+            AIDifficulty::Beginner => "beginner",
+            AIDifficulty::Intermediate => "intermediate",
+            AIDifficulty::Advanced => "advanced",
+            AIDifficulty::Expert => "expert",
+            AIDifficulty::Planner => "planner",
+        }
+    }
+
+    fn choose(&mut self, view: &AIView) -> AgentAction {
+        let input = AiDecisionInput {
+            difficulty: self.difficulty,
+            valid_actions: view.legal_actions.clone(),
+            // Agents place through legal_actions only, so the grande stays on open spaces
+            grande_actions: Vec::new(),
+            bonus_chits: view.bonus_chits.clone(),
+            bonus_actions: view.bonus_actions.clone(),
+            player: view.player.clone(),
+            hand: view.hand.clone(),
+            vineyard: view.vineyard.clone(),
+            state: view.season.clone(),
+            year: view.year,
+            seed: view.seed,
+            rules: view.rules,
+            placed: view.placed.clone(),
+            player_count: view.opponents.len() as u8 + 1,
+            planner: view.planner.as_ref().and_then(|planner| self.difficulty.planner_budget(planner.budget)
+                .map(|budget| PlannerInput { budget, ..planner.clone() })),
+        };
+        input.choose().map_or(AgentAction::Pass, AgentAction::Place)
+    }
+}
+
+pub type AgentFactory = Box<dyn Fn() -> Box<dyn Agent> + Send + Sync>;
+
+/// Agents available by name; every game gets a fresh instance from the factory
+#[derive(Resource)]
+pub struct AgentRegistry {
+    factories: HashMap<String, AgentFactory>,
+}
+
+impl Default for AgentRegistry {
+    fn default() -> Self {
+        let mut registry = Self { factories: HashMap::new() };
+        registry.register("beginner", || Box::new(BuiltinAgent { difficulty: AIDifficulty::Beginner }));
+        registry.register("intermediate", || Box::new(BuiltinAgent { difficulty: AIDifficulty::Intermediate }));
+        registry.register("advanced", || Box::new(BuiltinAgent { difficulty: AIDifficulty::Advanced }));
+        registry.register("expert", || Box::new(BuiltinAgent { difficulty: AIDifficulty::Expert }));
+        registry.register("planner", || Box::new(BuiltinAgent { difficulty: AIDifficulty::Planner }));
+        registry
+    }
+}
+
+impl AgentRegistry {
+    pub fn register(&mut self, name: &str, factory: impl Fn() -> Box<dyn Agent> + Send + Sync + 'static) {
+        self.factories.insert(name.to_string(), Box::new(factory));
+    }
+
+    pub fn create(&self, name: &str) -> Option<Box<dyn Agent>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.factories.keys().map(|k| k.as_str()).collect();
+        names.sort();
+        names
+    }
+}
+
+/// Which AI seats are played by a registered agent instead of the built-in AI
+#[derive(Resource, Default)]
+pub struct AgentSeats {
+    pub seats: HashMap<PlayerId, String>,
+}
+
+/// On an AIPlayer entity whose decisions come from an agent
+#[derive(Component)]
+pub struct AgentController {
+    pub agent: Box<dyn Agent>,
+}
+
+/// Registration helpers for crates embedding the game
+pub trait AgentAppExt {
+    fn register_agent(&mut self, name: &str, factory: impl Fn() -> Box<dyn Agent> + Send + Sync + 'static) -> &mut Self;
+    fn seat_agent(&mut self, player: PlayerId, name: &str) -> &mut Self;
+}
+
+impl AgentAppExt for App {
+    fn register_agent(&mut self, name: &str, factory: impl Fn() -> Box<dyn Agent> + Send + Sync + 'static) -> &mut Self {
+        self.world_mut().get_resource_or_insert_with(AgentRegistry::default).register(name, factory);
+        self
+    }
+
+    fn seat_agent(&mut self, player: PlayerId, name: &str) -> &mut Self {
+        self.world_mut().get_resource_or_insert_with(AgentSeats::default).seats.insert(player, name.to_string());
+        self
+    }
+}
+
+impl SimGame {
+    pub fn view_for(&self, player_idx: usize, seed: u64) -> AIView {
+        let id = self.players[player_idx].id;
+        let legal_actions = self.legal_actions(player_idx);
+        AIView {
+            player: self.players[player_idx].clone(),
+            hand: self.hands[player_idx].clone(),
+            vineyard: self.vineyards[player_idx].clone(),
+            opponents: self.players.iter().enumerate()
+                .filter(|(idx, _)| *idx != player_idx)
+                .map(|(_, p)| p.clone())
+                .collect(),
+            bonus_chits: self.bonus_chits().into_iter().filter(|(action, _)| legal_actions.contains(action)).collect(),
+            legal_actions,
+            bonus_actions: self.bonus_actions(player_idx),
+            season: self.phase.clone(),
+            year: self.current_year,
+            rules: self.rules,
+            seed,
+            placed: self.spaces.iter()
+                .filter(|s| s.occupied_by == Some(id) || s.bonus_worker_slot == Some(id))
+                .map(|s| s.action)
+                .collect(),
+            planner: Some(PlannerInput {
+                game: self.clone(),
+                player_idx,
+                budget: Duration::from_millis(DEFAULT_PLANNER_BUDGET_MS),
+            }),
+        }
+    }
+}
+
+/// Final standings of a headless agent match, best first
+#[derive(Clone)]
+pub struct AgentMatchResult {
+    pub seed: u64,
+    pub steps: usize,
+    pub standings: Vec<(String, Player)>,
+}
+
+/// Plays a full headless game with one agent per seat, in seat order, under the given rules
+pub fn run_agent_match(rules: &AiRules, seed: u64, agents: &mut [Box<dyn Agent>]) -> AgentMatchResult {
+    let seated = GameConfig {
+        player_count: agents.len() as u8,
+        ai_count: agents.len() as u8,
+        target_victory_points: rules.target_victory_points,
+        current_year: 1,
+        max_years: rules.max_years,
+        seed: Some(seed),
+    };
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut game = SimGame::new(&seated, &mut rng).with_rules(*rules);
+    let mut steps = 0;
+
+    while !game.is_over() && steps < AGENT_MATCH_MAX_STEPS {
+        game.step_with(&mut rng, |game, idx, rng| {
+            let view = game.view_for(idx, rng.random());
+            match agents[idx].choose(&view) {
+                AgentAction::Place(action) if view.legal_actions.contains(&action) => Some(action),
+                _ => None,
+            }
+        });
+        steps += 1;
+    }
+
+    let mut standings: Vec<(String, Player)> = agents.iter()
+        .zip(game.players.iter())
+        .map(|(agent, player)| (agent.name().to_string(), player.clone()))
+        .collect();
+    standings.sort_by(|a, b| b.1.victory_points.cmp(&a.1.victory_points).then(b.1.lira.cmp(&a.1.lira)));
+
+    AgentMatchResult { seed, steps, standings }
+}
+
+// Hands seated AI players their agent once the game has been set up
+pub fn attach_agents_system(
+    mut commands: Commands,
+    registry: Res<AgentRegistry>,
+    seats: Res<AgentSeats>,
+    ai_players: Query<(Entity, &AIPlayer), Without<AgentController>>,
+    mut reported: Local<Vec<PlayerId>>,
+) {
+    for (entity, ai_player) in ai_players.iter() {
+        let Some(name) = seats.seats.get(&ai_player.player_id) else { continue };
+        if reported.contains(&ai_player.player_id) {
+            continue;
+        }
+        match registry.create(name) {
+            Some(agent) => {
+                info!("🧩 Player {} is played by agent \"{}\"", ai_player.player_id.0 + 1, agent.name());
+                commands.entity(entity).insert(AgentController { agent });
+            }
+            None => {
+                warn!("No agent registered as \"{}\"; known agents: {:?}", name, registry.names());
+                reported.push(ai_player.player_id);
+            }
+        }
+    }
+}
+
+// Live-game counterpart of ai_decision_system for agent seats, paced by the same decision timer
+pub fn agent_decision_system(
+    time: Res<Time>,
+    mut agents: Query<(&mut AIPlayer, &mut AgentController)>,
+    workers: Query<&Worker>,
+    action_spaces: Query<&ActionSpaceSlot>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+    players: Query<&Player>,
+    (turn_order, current_state, config, clock): (Res<TurnOrder>, Res<State<GameState>>, Res<GameConfig>, Res<GameClock>),
+    mut place_events: EventWriter<ActionRequested>,
+    (mut scheduler, mut pass_events, mut rng, settings): (ResMut<AiScheduler>, EventWriter<PassEvent>, ResMut<GameRng>, Res<UserSettings>),
+    (ai_settings, planner_world): (Res<AISettings>, PlannerWorld),
+) {
+    if clock.paused {
+        return;
+    }
+    let Some(current_player_id) = turn_order.players.get(turn_order.current_player).copied() else { return };
+    let Some((mut ai_player, mut controller)) = agents.iter_mut().find(|(ai, _)| ai.player_id == current_player_id) else { return };
+
+    ai_player.decision_timer.tick(time.delta());
+    if !ai_player.decision_timer.finished() || !scheduler.try_begin(current_player_id, time.elapsed()) {
+        return;
+    }
+    ai_player.decision_timer.reset();
+
+    let Some(input) = gather_ai_decision_input(
+        current_player_id,
+        ai_player.difficulty,
+        &workers,
+        &action_spaces,
+        &hands,
+        &vineyards,
+        &players,
+        current_state.get(),
+        config.current_year,
+        config.player_count,
+        AiRules::from_settings(&settings, &config),
+        rng.random(),
+    ) else {
+        pass_events.send(PassEvent { player: current_player_id });
+        return;
+    };
+
+    let view = AIView {
+        opponents: players.iter().filter(|p| p.id != current_player_id).cloned().collect(),
+        player: input.player,
+        hand: input.hand,
+        vineyard: input.vineyard,
+        legal_actions: input.valid_actions,
+        bonus_chits: input.bonus_chits,
+        bonus_actions: input.bonus_actions,
+        season: input.state,
+        year: input.year,
+        rules: input.rules,
+        seed: input.seed,
+        placed: input.placed,
+        planner: Some(planner_world.snapshot(current_player_id, input.rules, ai_settings.planner_budget)),
+    };
+
+    let action = match controller.agent.choose(&view) {
+        AgentAction::Place(action) if view.legal_actions.contains(&action) => action,
+        AgentAction::Place(action) => {
+            warn!("Agent \"{}\" chose illegal space {:?}; treated as a pass", controller.agent.name(), action);
+            pass_events.send(PassEvent { player: current_player_id });
+            return;
+        }
+        AgentAction::Pass => {
+            pass_events.send(PassEvent { player: current_player_id });
+            return;
+        }
+    };
+
+    place_events.send(ActionRequested { player: current_player_id, action, grande: false });
+}
@@ -1,21 +1,258 @@
 use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
 use crate::components::*;
 use crate::systems::*;
-use crate::systems::audio::*;
-use crate::systems::game_logic::execute_action;
 use rand::prelude::*;
+use std::time::Duration;
+
+// A blocked space is only worth the grande worker when the action scores at least this
+pub const GRANDE_PLACEMENT_THRESHOLD: f32 = 0.9;
+// Expected value a season's visitor pile must offer before the AI draws from it
+pub const VISITOR_PLAY_THRESHOLD: f32 = 0.3;
+// Expert's rollout time per decision, capped further by a shorter configured budget
+pub const EXPERT_PLANNER_BUDGET: Duration = Duration::from_millis(100);
 
 #[derive(Component)]
 pub struct AIPlayer {
     pub player_id: PlayerId,
     pub difficulty: AIDifficulty,
     pub decision_timer: Timer,
+    // Decision running on the async compute pool; dropping it cancels the task
+    pub pending: Option<PendingDecision>,
+}
+
+pub struct PendingDecision {
+    pub task: Task<Option<ActionSpace>>,
+    pub state: GameState,
+    pub undo_count: u32,
+}
+
+// Owned copy of everything an AI decision reads, so it can run off the main thread
+#[derive(Clone)]
+pub struct AiDecisionInput {
+    pub difficulty: AIDifficulty,
+    pub valid_actions: Vec<ActionSpace>,
+    // Blocked spaces with a free bonus slot, while the grande worker is idle
+    pub grande_actions: Vec<ActionSpace>,
+    pub bonus_chits: Vec<(ActionSpace, BonusChit)>,
+    // Actions whose bonus space is still free
+    pub bonus_actions: Vec<ActionSpace>,
+    pub player: Player,
+    pub hand: Hand,
+    pub vineyard: Vineyard,
+    pub state: GameState,
+    pub year: u8,
+    // Tie-breaks draw from this, taken from GameRng, so the task replays off-thread
+    pub seed: u64,
+    // Variants and game length the AI scores under
+    pub rules: AiRules,
+    // Spaces this player already has a worker on this year
+    pub placed: Vec<ActionSpace>,
+    // Seats at the table, which picks the opening book
+    pub player_count: u8,
+    // Rules engine snapshot for Expert and the planner; without one they take the best score
+    pub planner: Option<PlannerInput>,
+}
+
+impl AiDecisionInput {
+    // A seat with an empty hand and vineyard and nowhere to go yet; fill in the rest with
+    // struct update syntax
+    pub fn new(player: Player, state: GameState, year: u8) -> Self {
+        Self {
+            difficulty: AIDifficulty::default(),
+            valid_actions: Vec::new(),
+            grande_actions: Vec::new(),
+            bonus_chits: Vec::new(),
+            bonus_actions: Vec::new(),
+            hand: Hand::new(player.id),
+            vineyard: Vineyard::new(player.id),
+            player,
+            state,
+            year,
+            seed: 0,
+            rules: AiRules::default(),
+            placed: Vec::new(),
+            player_count: 2,
+            planner: None,
+        }
+    }
+
+    pub fn choose(&self) -> Option<ActionSpace> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(self.seed);
+        // The first two years follow a known opening where one applies
+        if let Some(action) = opening_book_action(self, &mut rng) {
+            return Some(action);
+        }
+        match self.difficulty {
+            AIDifficulty::Beginner => choose_random_action(&self.valid_actions, &mut rng),
+            AIDifficulty::Intermediate | AIDifficulty::Advanced => self.choose_smart(&mut rng),
+            AIDifficulty::Expert | AIDifficulty::Planner => match &self.planner {
+                Some(planner) => planner.plan(&self.candidates(), self.seed),
+                None => self.choose_smart(&mut rng),
+            },
+        }
+    }
+
+    // Legal spaces, plus the blocked ones worth sending the grande worker to
+    pub fn candidates(&self) -> Vec<ActionSpace> {
+        let mut candidates = self.valid_actions.clone();
+        if self.difficulty.uses_grande_and_visitors() {
+            candidates.extend(self.grande_actions.iter().copied().filter(|&action| {
+                evaluate_action(action, &self.player, &self.hand, &self.vineyard, &self.state, self.year)
+                    + self.rules.action_adjustment(action, &self.player, &self.hand, &self.vineyard, self.year)
+                    >= GRANDE_PLACEMENT_THRESHOLD
+            }));
+        }
+        candidates
+    }
+
+    // Candidates as Intermediate scores them, best first; the Advisor shows the same list
+    pub fn scored_actions(&self) -> Vec<(ActionSpace, f32)> {
+        score_actions(&self.candidates(), &self.bonus_chits, &self.bonus_actions, &self.player, &self.hand, &self.vineyard, &self.state, self.year, &self.rules)
+    }
+
+    fn choose_smart(&self, rng: &mut rand::rngs::StdRng) -> Option<ActionSpace> {
+        // Add some randomness to prevent predictable play, less of it the stronger the AI
+        let top_actions: Vec<_> = self.scored_actions().iter()
+            .take(self.difficulty.top_choices())
+            .map(|(action, _)| *action)
+            .collect();
+        top_actions.choose(rng).copied()
+    }
+}
+
+#[derive(Component)]
+pub struct AiThinkingIndicator;
+
+// The rules a game is played under, as AI scoring sees them. Built from the settings and the
+// game config, so live, agent and simulated AIs all plan for the same variants.
+// The order swap and its separate hand limits, temporary-worker bidding and the simultaneous
+// wake-up are left out on purpose: they never change what a placement is worth, only choices
+// made outside it, which ai_order_to_swap, ai_discard_choice, WakeUpDraft::ai_bid and
+// WakeUpDraft::ai_ranking already answer under whichever rule is on. The simulator seats the
+// wake-up order by player and has no hand limit, so they have no variant to measure either.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AiRules {
+    pub wine_festival: bool,
+    pub worker_upkeep: bool,
+    // Scored through Vineyard::can_fulfill_order; carried so simulations set it on vineyards
+    pub sparkling_aging: bool,
+    // Live AIs score the chits on the board (AiDecisionInput::bonus_chits); simulations deal their own
+    pub bonus_chits: bool,
+    pub max_years: u8,
+    pub target_victory_points: u8,
 }
 
-#[derive(Clone, Copy, Debug)]
+impl Default for AiRules {
+    fn default() -> Self {
+        Self::from_settings(&UserSettings::default(), &GameConfig::default())
+    }
+}
+
+impl AiRules {
+    pub fn from_settings(settings: &UserSettings, config: &GameConfig) -> Self {
+        Self {
+            wine_festival: settings.house_rule_wine_festival,
+            worker_upkeep: settings.house_rule_worker_upkeep,
+            sparkling_aging: settings.house_rule_sparkling_aging,
+            bonus_chits: settings.house_rule_bonus_chits,
+            max_years: config.max_years,
+            target_victory_points: config.target_victory_points,
+        }
+    }
+
+    // Years still to play after this one
+    pub fn years_left(&self, year: u8) -> u8 {
+        self.max_years.saturating_sub(year)
+    }
+
+    // Added to evaluate_action: what the variants and the game's length change about an action
+    pub fn action_adjustment(&self, action: ActionSpace, player: &Player, hand: &Hand, vineyard: &Vineyard, year: u8) -> f32 {
+        let mut score = 0.0;
+        if self.wine_festival {
+            score += festival_ai_bonus(action, vineyard, year);
+        }
+        let final_year = year >= self.max_years;
+        match action {
+            ActionSpace::TrainWorker => {
+                // A new worker past the free ones costs upkeep every remaining year
+                if self.worker_upkeep && player.workers >= UPKEEP_FREE_WORKERS {
+                    score -= 0.1 * (self.years_left(year) * UPKEEP_PER_WORKER) as f32;
+                }
+                if final_year {
+                    score -= 0.5;
+                }
+            }
+            // Investments that won't pay back before the game ends
+            ActionSpace::DrawVine | ActionSpace::BuildStructure if final_year => score -= 0.3,
+            ActionSpace::FillOrder => {
+                let wins = hand.wine_order_cards.first().is_some_and(|order| {
+                    vineyard.can_fulfill_order(order)
+                        && player.victory_points.get().saturating_add(order.victory_points) >= self.target_victory_points
+                });
+                if wins {
+                    score += 1.0;
+                }
+            }
+            _ => {}
+        }
+        score
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum AIDifficulty {
     Beginner,
     Intermediate,
+    Advanced,
+    Expert,
+    // Searches ahead with rollouts in the rules engine, within AISettings::planner_budget
+    Planner,
+}
+
+impl AIDifficulty {
+    // The tiers offered at setup and in settings, weakest first
+    pub const LADDER: [AIDifficulty; 5] = [
+        AIDifficulty::Beginner,
+        AIDifficulty::Intermediate,
+        AIDifficulty::Advanced,
+        AIDifficulty::Expert,
+        AIDifficulty::Planner,
+    ];
+
+    // Beginners keep to open spaces and ignore the visitor piles
+    pub fn uses_grande_and_visitors(&self) -> bool {
+        !matches!(self, AIDifficulty::Beginner)
+    }
+
+    // How many of the best-scoring actions the AI picks among at random. Expert and the
+    // planner only score this way without a rules snapshot, and then take the best.
+    pub fn top_choices(&self) -> usize {
+        match self {
+            AIDifficulty::Beginner | AIDifficulty::Intermediate => 3,
+            AIDifficulty::Advanced => 2,
+            AIDifficulty::Expert | AIDifficulty::Planner => 1,
+        }
+    }
+
+    // Rollout time per decision: Expert searches briefly, the planner for the configured budget
+    pub fn planner_budget(&self, configured: Duration) -> Option<Duration> {
+        match self {
+            AIDifficulty::Expert => Some(configured.min(EXPERT_PLANNER_BUDGET)),
+            AIDifficulty::Planner => Some(configured),
+            _ => None,
+        }
+    }
+
+    pub fn harder(&self) -> Option<AIDifficulty> {
+        let rung = Self::LADDER.iter().position(|d| d == self)?;
+        Self::LADDER.get(rung + 1).copied()
+    }
+
+    pub fn easier(&self) -> Option<AIDifficulty> {
+        let rung = Self::LADDER.iter().position(|d| d == self)?;
+        rung.checked_sub(1).map(|rung| Self::LADDER[rung])
+    }
 }
 
 impl AIPlayer {
@@ -23,16 +260,36 @@ impl AIPlayer {
         Self {
             player_id,
             difficulty,
-            decision_timer: Timer::from_seconds(1.5, TimerMode::Once),
+            decision_timer: GameClock::once(GameTimer::AiDecision),
+            pending: None,
         }
     }
 }
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct AISettings {
     pub player_count: u8,
     pub ai_count: u8,
     pub ai_difficulty: AIDifficulty,
+    // Thinking time per planner decision; rollouts also stop at PLANNER_MAX_ROLLOUTS
+    pub planner_budget: Duration,
+}
+
+impl Default for AISettings {
+    fn default() -> Self {
+        Self::from_settings(&UserSettings::default())
+    }
+}
+
+impl AISettings {
+    pub fn from_settings(settings: &UserSettings) -> Self {
+        Self {
+            player_count: 0,
+            ai_count: 0,
+            ai_difficulty: settings.table_difficulty(),
+            planner_budget: settings.planner_budget(),
+        }
+    }
 }
 
 impl Default for AIDifficulty {
@@ -43,37 +300,71 @@ impl Default for AIDifficulty {
 
 pub fn ai_decision_system(
     time: Res<Time>,
-    mut ai_players: Query<&mut AIPlayer>,
-    mut workers: Query<&mut Worker>,
-    mut action_spaces: Query<&mut ActionSpaceSlot>,
-    mut hands: Query<&mut Hand>,
-    mut vineyards: Query<&mut Vineyard>,
-    mut players: Query<&mut Player>,
-    mut card_decks: ResMut<CardDecks>,
-    mut commands: Commands,
+    mut ai_players: Query<&mut AIPlayer, Without<AgentController>>,
+    workers: Query<&Worker>,
+    action_spaces: Query<&ActionSpaceSlot>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+    players: Query<&Player>,
     turn_order: Res<TurnOrder>,
     current_state: Res<State<GameState>>,
-    audio_assets: Res<AudioAssets>,
-    audio_settings: Res<AudioSettings>,
-    animation_settings: Res<AnimationSettings>,
-    // mut trackers: Query<&mut ResidualPaymentTracker>,
-    (mut trackers, structures) : (Query<&mut ResidualPaymentTracker>, Query<&Structure>),
-    // structures: Query<&Structure>, 
+    (config, mut place_events): (Res<GameConfig>, EventWriter<ActionRequested>),
+    (clock, undo_system, mut scheduler, mut pass_events, mut rng, settings): (Res<GameClock>, Option<Res<UndoSystem>>, ResMut<AiScheduler>, EventWriter<PassEvent>, ResMut<GameRng>, Res<UserSettings>),
+    (ai_settings, planner_world): (Res<AISettings>, PlannerWorld),
 ) {
     if !matches!(current_state.get(), GameState::Summer | GameState::Winter) {
         return;
     }
+    // Undo comes with CorePlugin; without it nothing is ever taken back
+    let undo_count = undo_system.map_or(0, |undo| undo.undo_count);
     
     if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
         let ai_player = ai_players.iter_mut().find(|ai| ai.player_id == *current_player_id);
         
         if let Some(mut ai_player) = ai_players.iter_mut().find(|ai| ai.player_id == *current_player_id) {
+            if clock.paused {
+                ai_player.pending = None;
+                return;
+            }
+
+            // Poll the running decision; apply it once it completes
+            if let Some(pending) = ai_player.pending.as_mut() {
+                if pending.state != *current_state.get() || pending.undo_count != undo_count {
+                    ai_player.pending = None;
+                    return;
+                }
+                let Some(action) = block_on(future::poll_once(&mut pending.task)) else { return };
+                ai_player.pending = None;
+
+                // Nothing worth doing: sit out the rest of the season
+                if action.is_none() {
+                    pass_events.send(PassEvent { player: *current_player_id });
+                    return;
+                }
+
+                // The board may have moved on while the task ran
+                let board = RulesState::new(action_spaces.iter(), current_state.get(), config.player_count)
+                    .with_workers(workers.iter());
+                let still_legal = action.is_some_and(|chosen| legal_action(&board, *current_player_id, chosen).is_some());
+                if !still_legal {
+                    return;
+                }
+
+                if let Some(chosen_action) = action {
+                    let grande = needs_grande(chosen_action, &action_spaces, &workers, *current_player_id, current_state.get(), config.player_count);
+                    place_events.send(ActionRequested { player: *current_player_id, action: chosen_action, grande });
+                    info!("AI Player {:?} chose {:?}{}", current_player_id, chosen_action, if grande { " with the grande worker" } else { "" });
+                }
+                return;
+            }
+
             ai_player.decision_timer.tick(time.delta());
             
-            if ai_player.decision_timer.finished() {
+            // A due decision waits for its scheduler slot with the timer left finished
+            if ai_player.decision_timer.finished() && scheduler.try_begin(*current_player_id, time.elapsed()) {
                 ai_player.decision_timer.reset();
-                
-                let action = choose_ai_action(
+
+                let input = gather_ai_decision_input(
                     *current_player_id,
                     ai_player.difficulty,
                     &workers,
@@ -82,46 +373,129 @@ pub fn ai_decision_system(
                     &vineyards,
                     &players,
                     current_state.get(),
+                    config.current_year,
+                    config.player_count,
+                    AiRules::from_settings(&settings, &config),
+                    rng.random(),
                 );
-                
-                if let Some(chosen_action) = action {
-                    execute_ai_action(
-                        chosen_action,
-                        *current_player_id,
-                        &mut workers,
-                        &mut action_spaces,
-                        &mut hands,
-                        &mut vineyards,
-                        &mut players,
-                        &mut card_decks,
-                        &mut commands,
-                        &audio_assets,
-                        &audio_settings,
-                        &animation_settings,
-                        &mut trackers,
-                        &structures,
-                    );
+
+                let input = input.map(|input| match ai_player.difficulty.planner_budget(ai_settings.planner_budget) {
+                    Some(budget) => AiDecisionInput {
+                        planner: Some(planner_world.snapshot(*current_player_id, input.rules, budget)),
+                        ..input
+                    },
+                    None => input,
+                });
+
+                if let Some(input) = input {
+                    ai_player.pending = Some(PendingDecision {
+                        task: AsyncComputeTaskPool::get().spawn(async move { input.choose() }),
+                        state: current_state.get().clone(),
+                        undo_count: undo_count,
+                    });
+                } else {
+                    pass_events.send(PassEvent { player: *current_player_id });
                 }
             }
         }
     }
 }
 
+// Drops running decisions when the game is paused, left, or the turn has moved on;
+// undo is caught by ai_decision_system through UndoSystem::undo_count
+pub fn cancel_ai_tasks_system(
+    clock: Res<GameClock>,
+    current_state: Res<State<GameState>>,
+    turn_order: Res<TurnOrder>,
+    mut ai_players: Query<&mut AIPlayer>,
+) {
+    let current_player = turn_order.players.get(turn_order.current_player).copied();
+    for mut ai_player in ai_players.iter_mut() {
+        let stale = ai_player.pending.as_ref().is_some_and(|pending| {
+            clock.paused || pending.state != *current_state.get() || current_player != Some(ai_player.player_id)
+        });
+        if stale {
+            ai_player.pending = None;
+        }
+    }
+}
+
+// "Thinking…" label while any AI decision is in flight
+pub fn ai_thinking_indicator_system(
+    mut commands: Commands,
+    ai_players: Query<&AIPlayer>,
+    players: Query<&Player>,
+    existing: Query<Entity, With<AiThinkingIndicator>>,
+    mut shown_for: Local<Option<PlayerId>>,
+) {
+    let thinking = ai_players.iter().find(|ai| ai.pending.is_some()).map(|ai| ai.player_id);
+    if thinking == *shown_for {
+        return;
+    }
+    *shown_for = thinking;
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(player_id) = thinking else { return };
+    let name = players.iter().find(|p| p.id == player_id)
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| format!("Player {}", player_id.0 + 1));
+
+    commands.spawn((
+        TextBundle::from_section(
+            format!("🤖 {} is thinking…", name),
+            TextStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.7, 0.85, 1.0),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(36.0),
+            left: Val::Percent(45.0),
+            ..default()
+        }),
+        AiThinkingIndicator,
+    ));
+}
+
 pub fn choose_ai_action(
     player_id: PlayerId,
     difficulty: AIDifficulty,
-    workers: &Query<&mut Worker>,
-    action_spaces: &Query<&mut ActionSpaceSlot>,
-    hands: &Query<&mut Hand>,
-    vineyards: &Query<&mut Vineyard>,
-    players: &Query<&mut Player>,
+    workers: &Query<&Worker>,
+    action_spaces: &Query<&ActionSpaceSlot>,
+    hands: &Query<&Hand>,
+    vineyards: &Query<&Vineyard>,
+    players: &Query<&Player>,
     current_state: &GameState,
+    current_year: u8,
+    player_count: u8,
+    rules: AiRules,
+    seed: u64,
 ) -> Option<ActionSpace> {
-    let available_workers = workers.iter()
-        .filter(|w| w.owner == player_id && w.placed_at.is_none())
-        .count();
-    
-    if available_workers == 0 {
+    gather_ai_decision_input(player_id, difficulty, workers, action_spaces, hands, vineyards, players, current_state, current_year, player_count, rules, seed)?
+        .choose()
+}
+
+pub fn gather_ai_decision_input(
+    player_id: PlayerId,
+    difficulty: AIDifficulty,
+    workers: &Query<&Worker>,
+    action_spaces: &Query<&ActionSpaceSlot>,
+    hands: &Query<&Hand>,
+    vineyards: &Query<&Vineyard>,
+    players: &Query<&Player>,
+    current_state: &GameState,
+    current_year: u8,
+    player_count: u8,
+    rules: AiRules,
+    seed: u64,
+) -> Option<AiDecisionInput> {
+    let board = RulesState::new(action_spaces.iter(), current_state, player_count).with_workers(workers.iter());
+    let legal = legal_actions(&board, player_id);
+    if legal.is_empty() {
         return None;
     }
     
@@ -129,62 +503,153 @@ pub fn choose_ai_action(
     let hand = hands.iter().find(|h| h.owner == player_id)?;
     let vineyard = vineyards.iter().find(|v| v.owner == player_id)?;
     
-    let mut valid_actions = Vec::new();
-    
-    for space in action_spaces.iter() {
-        if space.can_place_worker(player_id, current_state) ||
-           space.can_place_grande_worker(player_id, current_state) {
-            valid_actions.push(space.action);
-        }
-    }
-    
-    if valid_actions.is_empty() {
-        return None;
-    }
+    let placed = workers.iter()
+        .filter(|w| w.owner == player_id)
+        .filter_map(|w| w.placed_at)
+        .collect();
+
+    // Spaces with every column taken are left to the grande
+    let valid_actions: Vec<ActionSpace> = legal.iter().filter(|l| !l.blocked).map(|l| l.action).collect();
+    let grande_actions = legal.iter().filter(|l| l.blocked).map(|l| l.action).collect();
+    let bonus_actions = legal.iter().filter(|l| l.bonus).map(|l| l.action).collect();
+    // Only the first worker on a space collects its chit
+    let bonus_chits = action_spaces.iter()
+        .filter(|s| s.is_open(player_count) && s.occupied_by.is_none() && valid_actions.contains(&s.action))
+        .filter_map(|s| s.bonus_chit.map(|chit| (s.action, chit)))
+        .collect();
     
-    match difficulty {
-        AIDifficulty::Beginner => choose_random_action(&valid_actions),
-        AIDifficulty::Intermediate => choose_smart_action(&valid_actions, player, hand, vineyard, current_state),
-    }
+    Some(AiDecisionInput {
+        difficulty,
+        valid_actions,
+        grande_actions,
+        bonus_chits,
+        bonus_actions,
+        player: player.clone(),
+        hand: hand.clone(),
+        vineyard: vineyard.clone(),
+        state: current_state.clone(),
+        year: current_year,
+        seed,
+        rules,
+        placed,
+        player_count,
+        planner: None,
+    })
 }
 
-fn choose_random_action(valid_actions: &[ActionSpace]) -> Option<ActionSpace> {
-    let mut rng = rand::rng();
-    valid_actions.choose(&mut rng).copied()
+fn choose_random_action(valid_actions: &[ActionSpace], rng: &mut impl Rng) -> Option<ActionSpace> {
+    valid_actions.choose(rng).copied()
 }
 
-fn choose_smart_action(
+fn score_actions(
     valid_actions: &[ActionSpace],
+    bonus_chits: &[(ActionSpace, BonusChit)],
+    bonus_actions: &[ActionSpace],
     player: &Player,
     hand: &Hand,
     vineyard: &Vineyard,
     current_state: &GameState,
-) -> Option<ActionSpace> {
+    current_year: u8,
+    rules: &AiRules,
+) -> Vec<(ActionSpace, f32)> {
     let mut scored_actions = Vec::new();
     
     for &action in valid_actions {
-        let score = evaluate_action(action, player, hand, vineyard, current_state);
+        let chit_value: f32 = bonus_chits.iter()
+            .filter(|(a, _)| *a == action)
+            .map(|(_, chit)| chit.ai_value())
+            .sum();
+        let bonus_value = if bonus_actions.contains(&action) { action_bonus_value(action, hand, vineyard) } else { 0.0 };
+        let rules_value = rules.action_adjustment(action, player, hand, vineyard, current_year);
+        let score = evaluate_action(action, player, hand, vineyard, current_state, current_year) + chit_value + bonus_value + rules_value;
         scored_actions.push((action, score));
     }
     
     scored_actions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-    
-    // Add some randomness to prevent predictable play
-    let mut rng = rand::rng();
-    let top_actions: Vec<_> = scored_actions.iter()
-        .take(3)
-        .map(|(action, _)| *action)
-        .collect();
-    
-    top_actions.choose(&mut rng).copied()
+    scored_actions
 }
 
-fn evaluate_action(
+// True when the placement has to use the grande: no idle regular worker can take the action
+pub fn needs_grande(
+    action: ActionSpace,
+    action_spaces: &Query<&ActionSpaceSlot>,
+    workers: &Query<&Worker>,
+    player_id: PlayerId,
+    current_state: &GameState,
+    player_count: u8,
+) -> bool {
+    let board = RulesState::new(action_spaces.iter(), current_state, player_count).with_workers(workers.iter());
+    legal_action(&board, player_id, action).and_then(|legal| legal.uses_grande(false)).unwrap_or(false)
+}
+
+// What playing a visitor would be worth, on the evaluate_action scale, after its cost
+pub fn visitor_value(card: &VisitorCard, player: &Player, hand: &Hand, vineyard: &Vineyard) -> f32 {
+    let has_wine = vineyard.red_wine.count() + vineyard.white_wine.count() > 0;
+    let empty_field = vineyard.fields.iter().any(|f| f.vine.is_none());
+    let value = match card.effect {
+        VisitorEffect::GainLira(n) | VisitorEffect::StealLira(n) => 0.15 * n as f32,
+        VisitorEffect::GainVP(n) => 0.5 * n as f32,
+        VisitorEffect::PlantFreeVine if !hand.vine_cards.is_empty() && empty_field => 0.9,
+        VisitorEffect::HarvestBonus(_) => 0.4,
+        VisitorEffect::WineBonus(_) => 0.6,
+        VisitorEffect::ExtraWorker if player.workers < 4 => 0.5,
+        VisitorEffect::AgeWine(n) if has_wine => 0.1 * n as f32,
+        // Scripted visitors trade one resource for another; a modest gain on average
+        VisitorEffect::Script(_) => 0.2,
+        _ => 0.0,
+    };
+    if player.lira < card.cost { 0.0 } else { value - 0.15 * card.cost as f32 }
+}
+
+// Visitors are drawn blind, so the AI weighs the pile it would draw from
+pub fn should_play_visitor(difficulty: AIDifficulty, pile: &[VisitorCard], player: &Player, hand: &Hand, vineyard: &Vineyard) -> bool {
+    if !difficulty.uses_grande_and_visitors() || pile.is_empty() {
+        return false;
+    }
+    let expected = pile.iter().map(|card| visitor_value(card, player, hand, vineyard)).sum::<f32>() / pile.len() as f32;
+    expected >= VISITOR_PLAY_THRESHOLD
+}
+
+// Picks among the targets a human would be offered: hit the leader, age the best wine
+pub fn choose_visitor_target(
+    effect: &VisitorEffect,
+    targets: &[VisitorTarget],
+    scores: &[(PlayerId, u8)],
+) -> Option<VisitorTarget> {
+    let score_of = |id: PlayerId| scores.iter().find(|(p, _)| *p == id).map_or(0, |(_, vp)| *vp);
+    match effect {
+        VisitorEffect::StealLira(_) => targets.iter().copied().max_by_key(|t| match t {
+            VisitorTarget::Opponent(id) => score_of(*id),
+            _ => 0,
+        }),
+        VisitorEffect::AgeWine(_) => targets.iter().copied().max_by_key(|t| match t {
+            VisitorTarget::Wine { value, .. } => *value,
+            _ => 0,
+        }),
+        _ => targets.first().copied(),
+    }
+}
+
+// Extra score for taking an action's bonus space, when the bonus would actually do something
+pub fn action_bonus_value(action: ActionSpace, hand: &Hand, vineyard: &Vineyard) -> f32 {
+    let planted = vineyard.fields.iter().filter(|f| f.vine.is_some()).count();
+    let grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
+    match action {
+        ActionSpace::PlantVine if hand.vine_cards.len() >= 2 => 0.4,
+        ActionSpace::GiveTour => 0.2,
+        ActionSpace::Harvest if planted >= 2 => 0.3,
+        ActionSpace::MakeWine if grapes >= 2 => 0.3,
+        _ => 0.0,
+    }
+}
+
+pub fn evaluate_action(
     action: ActionSpace,
     player: &Player,
     hand: &Hand,
     vineyard: &Vineyard,
     current_state: &GameState,
+    current_year: u8,
 ) -> f32 {
     match action {
         ActionSpace::DrawVine => {
@@ -202,13 +667,22 @@ fn evaluate_action(
             if planted_vines > 0 { 0.9 } else { 0.0 }
         }
         ActionSpace::MakeWine => {
-            let total_grapes = vineyard.red_grapes + vineyard.white_grapes;
+            let total_grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
             if total_grapes > 0 { 0.8 } else { 0.0 }
         }
         ActionSpace::FillOrder => {
-            let can_fill = hand.wine_order_cards.iter()
-                .any(|order| vineyard.can_fulfill_order(order));
-            if can_fill { 1.2 } else { 0.0 }
+            // Only the first order is resolved, so score its type effects
+            match hand.wine_order_cards.first() {
+                Some(order) if vineyard.can_fulfill_order(order) => {
+                    let mut score = 1.2;
+                    score += order.seasonal_bonus(current_year) as f32 * 0.15;
+                    if matches!(order.order_type, OrderType::Premium) {
+                        score += 0.2;
+                    }
+                    score
+                }
+                _ => 0.0,
+            }
         }
         ActionSpace::GiveTour => {
             if vineyard.lira < 5 { 0.6 } else { 0.3 }
@@ -220,84 +694,21 @@ fn evaluate_action(
             if vineyard.lira >= 2 { 0.4 } else { 0.0 }
         }
         ActionSpace::SellGrapes => {
-            let total_grapes = vineyard.red_grapes + vineyard.white_grapes;
+            let total_grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
             if total_grapes > 3 && vineyard.lira < 3 { 0.7 } else { 0.2 }
         }
     }
 }
 
-pub fn execute_ai_action(
-    action: ActionSpace,
-    player_id: PlayerId,
-    workers: &mut Query<&mut Worker>,
-    action_spaces: &mut Query<&mut ActionSpaceSlot>,
-    hands: &mut Query<&mut Hand>,
-    vineyards: &mut Query<&mut Vineyard>,
-    players: &mut Query<&mut Player>,
-    card_decks: &mut ResMut<CardDecks>,
-    commands: &mut Commands,
-    audio_assets: &Res<AudioAssets>,    // Fixed: removed mut
-    audio_settings: &Res<AudioSettings>, // Fixed: removed mut
-    animation_settings: &Res<AnimationSettings>,
-    trackers: &mut Query<&mut ResidualPaymentTracker>,
-    structures: &Query<&Structure>, 
-) {
-    // Find and place a worker
-    let mut worker_placed = false;
-    
-    // Try to place regular worker first
-    for mut worker in workers.iter_mut() {
-        if worker.owner == player_id && worker.placed_at.is_none() && !worker.is_grande {
-            // Find the action space
-            for mut space in action_spaces.iter_mut() {
-                if space.action == action && space.occupied_by.is_none() {
-                    worker.placed_at = Some(action);
-                    worker.position = space.position;
-                    space.occupied_by = Some(player_id);
-                    worker_placed = true;
-                    break;
-                }
-            }
-            if worker_placed { break; }
-        }
-    }
-    
-    // If no regular worker could be placed, try grande worker
-    if !worker_placed {
-        for mut worker in workers.iter_mut() {
-            if worker.owner == player_id && worker.placed_at.is_none() && worker.is_grande {
-                for mut space in action_spaces.iter_mut() {
-                    if space.action == action {
-                        worker.placed_at = Some(action);
-                        worker.position = space.position;
-                        if space.occupied_by.is_some() {
-                            space.bonus_worker_slot = Some(player_id);
-                        } else {
-                            space.occupied_by = Some(player_id);
-                        }
-                        worker_placed = true;
-                        break;
-                    }
-                }
-                if worker_placed { break; }
-            }
-        }
-    }
-    
-    if worker_placed {
-        execute_action(action, player_id, hands, vineyards, players, card_decks, commands, trackers, structures, audio_assets, audio_settings, animation_settings);
-        info!("AI Player {:?} executed action {:?}", player_id, action);
-    }
-}
-
 pub fn setup_ai_players(
     mut commands: Commands,
     ai_settings: Res<AISettings>,
+    game_settings: Res<GameSettings>,
     players: Query<&Player>,
 ) {
     for player in players.iter() {
         if player.id.0 >= ai_settings.player_count - ai_settings.ai_count {
-            commands.spawn(AIPlayer::new(player.id, ai_settings.ai_difficulty));
+            commands.spawn(AIPlayer::new(player.id, game_settings.ai_difficulty(player.id.0, ai_settings.ai_difficulty)));
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::time::Duration;
+use crate::components::PlayerId;
+
+// Staggers AI evaluations so several AIs never think in the same frame. An AI whose decision
+// is due asks for a slot every frame; slots are handed out first come, first served, at most
+// `max_per_frame` per frame and no closer together than `min_gap`.
+#[derive(Resource)]
+pub struct AiScheduler {
+    pub max_per_frame: u32,
+    pub min_gap: Duration,
+    queue: VecDeque<PlayerId>,
+    asked_this_frame: Vec<PlayerId>,
+    granted_this_frame: u32,
+    last_grant: Option<Duration>,
+}
+
+impl Default for AiScheduler {
+    fn default() -> Self {
+        Self {
+            max_per_frame: 1,
+            min_gap: Duration::ZERO,
+            queue: VecDeque::new(),
+            asked_this_frame: Vec::new(),
+            granted_this_frame: 0,
+            last_grant: None,
+        }
+    }
+}
+
+impl AiScheduler {
+    // True when `player` may evaluate now; otherwise it keeps its place in the queue
+    pub fn try_begin(&mut self, player: PlayerId, now: Duration) -> bool {
+        if !self.asked_this_frame.contains(&player) {
+            self.asked_this_frame.push(player);
+        }
+        if !self.queue.contains(&player) {
+            self.queue.push_back(player);
+        }
+
+        let gap_ok = self.last_grant.map_or(true, |last| now.saturating_sub(last) >= self.min_gap);
+        if self.queue.front() != Some(&player) || self.granted_this_frame >= self.max_per_frame || !gap_ok {
+            return false;
+        }
+        self.queue.pop_front();
+        self.granted_this_frame += 1;
+        self.last_grant = Some(now);
+        true
+    }
+
+    pub fn waiting(&self) -> usize {
+        self.queue.len()
+    }
+
+    // AIs that stopped asking (their turn ended, the game was left) give up their place
+    fn begin_frame(&mut self) {
+        let asked = std::mem::take(&mut self.asked_this_frame);
+        self.queue.retain(|player| asked.contains(player));
+        self.granted_this_frame = 0;
+    }
+}
+
+pub fn ai_scheduler_frame_system(mut scheduler: ResMut<AiScheduler>) {
+    scheduler.begin_frame();
+}
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::clock::{GameClock, GameTimer};
+use crate::systems::rng::effect_rng;
+use crate::systems::render_layers::{UiLayer, WorldLayer};
 
 // Enhanced animation components
 #[derive(Component)]
@@ -105,7 +108,7 @@ pub fn animate_worker_placement(
     commands.entity(worker_entity).insert(WorkerAnimation {
         start_pos,
         target_pos,
-        timer: Timer::from_seconds(duration, TimerMode::Once),
+        timer: GameClock::once_secs(duration),
         animation_type,
     });
 }
@@ -156,13 +159,13 @@ pub fn animate_card_draw(
                 custom_size: Some(Vec2::new(30.0, 40.0)),
                 ..default()
             },
-            transform: Transform::from_translation(start_pos.extend(5.0)),
+            transform: Transform::from_translation(start_pos.extend(WorldLayer::CardFlight.z())),
             ..default()
         },
         CardAnimation {
             start_pos,
             target_pos,
-            timer: Timer::from_seconds(duration, TimerMode::Once),
+            timer: GameClock::once_secs(duration),
             animation_type: CardAnimationType::Draw,
             card_id: 0,
         },
@@ -181,7 +184,7 @@ pub fn animate_card_play(
     commands.entity(card_entity).insert(CardAnimation {
         start_pos,
         target_pos,
-        timer: Timer::from_seconds(duration, TimerMode::Once),
+        timer: GameClock::once_secs(duration),
         animation_type: CardAnimationType::Play,
         card_id: 0,
     });
@@ -249,11 +252,11 @@ pub fn trigger_season_transition(
                 ..default()
             },
             background_color: get_season_color(&to_season_clone).into(),
-            z_index: ZIndex::Global(50),
+            z_index: UiLayer::SeasonTransition.z_index(),
             ..default()
         },
         SeasonTransition {
-            timer: Timer::from_seconds(1.0, TimerMode::Once),
+            timer: GameClock::once(GameTimer::SeasonTransition),
             from_season,
             to_season,
             overlay_alpha: 0.8,
@@ -276,7 +279,7 @@ pub fn trigger_season_transition(
             ..default()
         }),
         SeasonTransition {
-            timer: Timer::from_seconds(1.5, TimerMode::Once),
+            timer: GameClock::once(GameTimer::SeasonBanner),
             from_season: from_season_clone,
             to_season: to_season_clone,
             overlay_alpha: 1.0,
@@ -344,13 +347,13 @@ pub fn spawn_harvest_particles(
     
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_translation(position.extend(3.0)),
+            transform: Transform::from_translation(position.extend(WorldLayer::Particles.z())),
             ..default()
         },
         ParticleEffect {
             particles,
             effect_type: ParticleType::HarvestSparkles,
-            timer: Timer::from_seconds(2.0, TimerMode::Once),
+            timer: GameClock::once_secs(2.0),
         },
     ));
 }
@@ -365,13 +368,13 @@ pub fn spawn_wine_pouring_effect(
     
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_translation(position.extend(3.0)),
+            transform: Transform::from_translation(position.extend(WorldLayer::Particles.z())),
             ..default()
         },
         ParticleEffect {
             particles,
             effect_type: ParticleType::WinePouring,
-            timer: Timer::from_seconds(1.5, TimerMode::Once),
+            timer: GameClock::once_secs(1.5),
         },
     ));
 }
@@ -387,13 +390,13 @@ pub fn spawn_lira_particles(
     
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_translation(position.extend(3.0)),
+            transform: Transform::from_translation(position.extend(WorldLayer::Particles.z())),
             ..default()
         },
         ParticleEffect {
             particles,
             effect_type: ParticleType::LiraGain,
-            timer: Timer::from_seconds(1.0, TimerMode::Once),
+            timer: GameClock::once_secs(1.0),
         },
     ));
 }
@@ -455,7 +458,7 @@ pub fn particle_system(
 // Utility functions
 fn create_harvest_particles(center: Vec2, count: usize) -> Vec<Particle> {
     use rand::Rng;
-    let mut rng = rand::rng();
+    let mut rng = effect_rng(center, count);
     
     (0..count)
         .map(|_| {
@@ -480,7 +483,7 @@ fn create_harvest_particles(center: Vec2, count: usize) -> Vec<Particle> {
 
 fn create_pouring_particles(center: Vec2, count: usize) -> Vec<Particle> {
     use rand::Rng;
-    let mut rng = rand::rng();
+    let mut rng = effect_rng(center, count);
     
     (0..count)
         .map(|_| {
@@ -503,7 +506,7 @@ fn create_pouring_particles(center: Vec2, count: usize) -> Vec<Particle> {
 
 fn create_lira_particles(center: Vec2, count: usize) -> Vec<Particle> {
     use rand::Rng;
-    let mut rng = rand::rng();
+    let mut rng = effect_rng(center, count);
     
     (0..count)
         .map(|_| {
@@ -614,11 +617,11 @@ pub fn spawn_animated_text(commands: &mut Commands, player_id: PlayerId, text: &
                     ..default()
                 },
             ),
-            transform: Transform::from_translation(start_pos.extend(10.0)),
+            transform: Transform::from_translation(start_pos.extend(WorldLayer::FloatingText.z())),
             ..default()
         },
         AnimatedText {
-            timer: Timer::from_seconds(1.5, TimerMode::Once),
+            timer: GameClock::once(GameTimer::FloatingText),
             start_pos,
             end_pos,
         },
@@ -635,7 +638,7 @@ pub fn animate_text_system(
         
         let progress = animated_text.timer.elapsed_secs() / animated_text.timer.duration().as_secs_f32();
         let current_pos = animated_text.start_pos.lerp(animated_text.end_pos, progress);
-        transform.translation = current_pos.extend(10.0);
+        transform.translation = current_pos.extend(WorldLayer::FloatingText.z());
         
         let alpha = (1.0 - progress).max(0.0);
         for section in text.sections.iter_mut() {
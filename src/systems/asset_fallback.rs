@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use bevy::asset::LoadState;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use crate::components::*;
+use crate::systems::audio::AudioAssets;
+use crate::systems::keymap::*;
+use crate::systems::theme::BoardTheme;
+use crate::systems::render_layers::UiLayer;
+
+const PLACEHOLDER_SIZE: u32 = 8;
+
+// Assets that failed to load and what was done about them
+#[derive(Resource, Default)]
+pub struct AssetDiagnostics {
+    pub unresolved: Vec<UnresolvedAsset>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnresolvedAsset {
+    pub path: &'static str,
+    pub fallback: &'static str,
+}
+
+impl AssetDiagnostics {
+    fn report(&mut self, path: &'static str, fallback: &'static str) -> bool {
+        if self.unresolved.iter().any(|a| a.path == path) {
+            return false;
+        }
+        warn!("Missing asset {} - {}", path, fallback);
+        self.unresolved.push(UnresolvedAsset { path, fallback });
+        true
+    }
+}
+
+#[derive(Component)]
+pub struct AssetDiagnosticsPanel;
+
+// Magenta/black checkerboard so missing textures are obvious but harmless
+fn placeholder_image() -> Image {
+    let mut data = Vec::with_capacity((PLACEHOLDER_SIZE * PLACEHOLDER_SIZE * 4) as usize);
+    for y in 0..PLACEHOLDER_SIZE {
+        for x in 0..PLACEHOLDER_SIZE {
+            let pixel = if (x + y) % 2 == 0 { [255, 0, 255, 255] } else { [0, 0, 0, 255] };
+            data.extend_from_slice(&pixel);
+        }
+    }
+
+    Image::new(
+        Extent3d { width: PLACEHOLDER_SIZE, height: PLACEHOLDER_SIZE, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn failed(asset_server: &AssetServer, id: impl Into<bevy::asset::UntypedAssetId>) -> bool {
+    matches!(asset_server.get_load_state(id), Some(LoadState::Failed(_)))
+}
+
+// Swaps failed textures for a generated placeholder and mutes failed sounds, once each
+pub fn asset_fallback_system(
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    game_assets: Option<ResMut<GameAssets>>,
+    audio_assets: Option<ResMut<AudioAssets>>,
+    theme: Option<Res<BoardTheme>>,
+    mut diagnostics: ResMut<AssetDiagnostics>,
+    mut placeholder: Local<Option<Handle<Image>>>,
+) {
+    if let Some(mut game_assets) = game_assets {
+        let game_assets = &mut *game_assets;
+        let textures: [(&'static str, &mut Handle<Image>); 4] = [
+            ("worker.png", &mut game_assets.worker_texture),
+            ("vine_card.png", &mut game_assets.vine_card_texture),
+            ("wine_order.png", &mut game_assets.wine_order_card_texture),
+            ("field.png", &mut game_assets.field_texture),
+        ];
+
+        for (path, handle) in textures {
+            if failed(&asset_server, handle.id()) {
+                let fallback = placeholder.get_or_insert_with(|| images.add(placeholder_image())).clone();
+                *handle = fallback;
+                diagnostics.report(path, "using generated placeholder texture");
+            }
+        }
+    }
+
+    if let Some(mut audio_assets) = audio_assets {
+        let failed_sfx: Vec<_> = audio_assets.sfx_handles().into_iter()
+            .filter(|(sound, handle, _)| !audio_assets.unavailable.contains(sound) && failed(&asset_server, handle.id()))
+            .map(|(sound, _, path)| (sound, path))
+            .collect();
+
+        for (sound, path) in failed_sfx {
+            audio_assets.unavailable.push(sound);
+            diagnostics.report(path, "sound effect skipped");
+        }
+
+        if !audio_assets.music_unavailable && failed(&asset_server, audio_assets.background_music.id()) {
+            audio_assets.music_unavailable = true;
+            diagnostics.report("audio/background_music.ogg", "music disabled");
+        }
+    }
+
+    if let Some(theme) = theme {
+        for background in &theme.backgrounds {
+            if failed(&asset_server, background.image.id()) {
+                diagnostics.report(background.path, "using flat season colour");
+            }
+        }
+    }
+}
+
+pub fn asset_diagnostics_panel_system(
+    input: BoundInput,
+    mut commands: Commands,
+    diagnostics: Res<AssetDiagnostics>,
+    existing: Query<Entity, With<AssetDiagnosticsPanel>>,
+) {
+    if !input.just_pressed(InputAction::AssetDiagnostics) {
+        return;
+    }
+
+    if !existing.is_empty() {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let mut text = String::from("🧩 ASSET DIAGNOSTICS\n\n");
+    if diagnostics.unresolved.is_empty() {
+        text.push_str("All assets loaded");
+    }
+    for asset in &diagnostics.unresolved {
+        text.push_str(&format!("❌ {}\n   {}\n", asset.path, asset.fallback));
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(100.0),
+                left: Val::Px(50.0),
+                width: Val::Px(360.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.15, 0.1, 0.1, 0.95)).into(),
+            z_index: UiLayer::Screen.z_index(),
+            ..default()
+        },
+        AssetDiagnosticsPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 13.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
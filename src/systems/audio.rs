@@ -1,5 +1,17 @@
 use bevy::prelude::*;
 use bevy::audio::Volume;
+use crate::components::*;
+use crate::systems::input::ActionResolved;
+use crate::systems::game_logic::ActionOutcome;
+use crate::systems::prediction::RemotePlay;
+
+// AI moves get a quieter, higher placement cue so the human can tell them apart by ear
+const AI_CUE_VOLUME: f32 = 0.45;
+const AI_CUE_SPEED: f32 = 1.25;
+// An opponent's order worth at least this much VP plays the sting
+pub const ORDER_STING_MIN_VP: u8 = 4;
+// The sting has no file of its own: it is the phase-change chime, slowed down and so pitched low
+const ORDER_STING_SPEED: f32 = 0.7;
 
 #[derive(Resource)]
 pub struct AudioAssets {
@@ -11,6 +23,25 @@ pub struct AudioAssets {
     pub lira_gain: Handle<AudioSource>,
     pub error: Handle<AudioSource>,
     pub phase_change: Handle<AudioSource>,
+    pub background_music: Handle<AudioSource>,
+    // Sounds whose files failed to load; play_sfx skips them
+    pub unavailable: Vec<AudioType>,
+    pub music_unavailable: bool,
+}
+
+impl AudioAssets {
+    pub fn sfx_handles(&self) -> [(AudioType, &Handle<AudioSource>, &'static str); 8] {
+        [
+            (AudioType::WorkerPlace, &self.worker_place, "audio/worker_place.ogg"),
+            (AudioType::CardDraw, &self.card_draw, "audio/card_draw.ogg"),
+            (AudioType::Harvest, &self.harvest, "audio/harvest.ogg"),
+            (AudioType::WineMake, &self.wine_make, "audio/wine_make.ogg"),
+            (AudioType::VictoryPoint, &self.victory_point, "audio/victory_point.ogg"),
+            (AudioType::LiraGain, &self.lira_gain, "audio/lira_gain.ogg"),
+            (AudioType::Error, &self.error, "audio/error.ogg"),
+            (AudioType::PhaseChange, &self.phase_change, "audio/phase_change.ogg"),
+        ]
+    }
 }
 
 #[derive(Resource)]
@@ -43,6 +74,9 @@ pub fn load_audio_assets(mut commands: Commands, asset_server: Res<AssetServer>)
         lira_gain: asset_server.load("audio/lira_gain.ogg"),
         error: asset_server.load("audio/error.ogg"),
         phase_change: asset_server.load("audio/phase_change.ogg"),
+        background_music: asset_server.load("audio/background_music.ogg"),
+        unavailable: Vec::new(),
+        music_unavailable: false,
     };
     
     commands.insert_resource(audio_assets);
@@ -55,7 +89,20 @@ pub fn play_sfx(
     settings: &Res<AudioSettings>,
     sound: AudioType,
 ) {
-    if !settings.enabled || settings.sfx_volume <= 0.0 {
+    play_sfx_with(commands, audio_assets, settings, sound, 1.0, 1.0);
+}
+
+// Like play_sfx, with the volume scaled and the playback speed (and so the pitch) changed
+pub fn play_sfx_with(
+    commands: &mut Commands,
+    audio_assets: &Res<AudioAssets>,
+    settings: &Res<AudioSettings>,
+    sound: AudioType,
+    volume_scale: f32,
+    speed: f32,
+) {
+    let file = if sound == AudioType::OrderSting { AudioType::PhaseChange } else { sound };
+    if !settings.enabled || settings.sfx_volume <= 0.0 || audio_assets.unavailable.contains(&file) {
         return;
     }
     
@@ -68,19 +115,21 @@ pub fn play_sfx(
         AudioType::LiraGain => &audio_assets.lira_gain,
         AudioType::Error => &audio_assets.error,
         AudioType::PhaseChange => &audio_assets.phase_change,
+        AudioType::OrderSting => &audio_assets.phase_change,
     };
     
     commands.spawn(AudioBundle {
         source: source.clone(),
         settings: PlaybackSettings {
-            volume: Volume::new(settings.sfx_volume),
+            volume: Volume::new(settings.sfx_volume * volume_scale),
+            speed,
             mode: bevy::audio::PlaybackMode::Despawn,
             ..default()
         },
     });
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum AudioType {
     WorkerPlace,
     CardDraw,
@@ -90,18 +139,58 @@ pub enum AudioType {
     LiraGain,
     Error,
     PhaseChange,
+    OrderSting,
+}
+
+// Plays the placement cue for every resolved action, softer and pitched up for AI players,
+// then the outcome's own sound, and a sting when an opponent fills a high-VP order
+pub fn action_audio_cue_system(
+    mut commands: Commands,
+    audio_assets: Res<AudioAssets>,
+    audio_settings: Res<AudioSettings>,
+    mut resolved_events: EventReader<ActionResolved>,
+    remote: Option<Res<RemotePlay>>,
+    players: Query<&Player>,
+) {
+    for event in resolved_events.read() {
+        let is_ai = players.iter().any(|p| p.id == event.player && p.is_ai);
+        if is_ai {
+            play_sfx_with(&mut commands, &audio_assets, &audio_settings, AudioType::WorkerPlace, AI_CUE_VOLUME, AI_CUE_SPEED);
+        } else {
+            play_sfx(&mut commands, &audio_assets, &audio_settings, AudioType::WorkerPlace);
+        }
+
+        let outcome_sound = match event.outcome {
+            ActionOutcome::DrewVine | ActionOutcome::DrewOrder => Some(AudioType::CardDraw),
+            ActionOutcome::Harvested { .. } => Some(AudioType::Harvest),
+            ActionOutcome::MadeWine { .. } => Some(AudioType::WineMake),
+            ActionOutcome::FilledOrder { .. } => Some(AudioType::VictoryPoint),
+            ActionOutcome::GainedLira { .. } => Some(AudioType::LiraGain),
+            _ => None,
+        };
+        if let Some(sound) = outcome_sound {
+            play_sfx(&mut commands, &audio_assets, &audio_settings, sound);
+        }
+
+        let opponent = remote.as_ref().map_or(is_ai, |remote| event.player != remote.local_player);
+        if let ActionOutcome::FilledOrder { victory_points, .. } = event.outcome {
+            if opponent && victory_points >= ORDER_STING_MIN_VP {
+                play_sfx_with(&mut commands, &audio_assets, &audio_settings, AudioType::OrderSting, 1.0, ORDER_STING_SPEED);
+            }
+        }
+    }
 }
 
 pub fn start_background_music(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    audio_assets: Res<AudioAssets>,
     settings: Res<AudioSettings>,
     music_query: Query<Entity, With<BackgroundMusic>>,
 ) {
-    if music_query.is_empty() && settings.enabled && settings.music_volume > 0.0 {
+    if music_query.is_empty() && settings.enabled && settings.music_volume > 0.0 && !audio_assets.music_unavailable {
         commands.spawn((
             AudioBundle {
-                source: asset_server.load("audio/background_music.ogg"),
+                source: audio_assets.background_music.clone(),
                 settings: PlaybackSettings {
                     volume: Volume::new(settings.music_volume),
                     mode: bevy::audio::PlaybackMode::Loop,
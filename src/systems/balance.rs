@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use crate::components::*;
 use crate::systems::*;
 use crate::systems::ai::*;
+use rand::Rng;
 
 #[derive(Resource, Default)]
 pub struct BalanceTestResults {
@@ -32,7 +33,7 @@ impl AutoTestConfig {
             target_games: 10,
             ai_only_mode: true,
             fast_mode: true,
-            restart_timer: Timer::from_seconds(1.0, TimerMode::Once), // 1 second delay
+            restart_timer: GameClock::once(GameTimer::TestRestart),
             ui_protected: false,
             ai_count: 1,
         }
@@ -225,7 +226,7 @@ pub fn protected_setup_system(
     
     // Reset turn order completely
     turn_order.players.clear();
-    turn_order.current_player = 0;
+    turn_order.start_season();
     turn_order.wake_up_order.clear();
     
     // Create new players for testing
@@ -264,7 +265,7 @@ fn setup_normal_game(
     // Reset game state
     config.current_year = 1;
     turn_order.players.clear();
-    turn_order.current_player = 0;
+    turn_order.start_season();
     turn_order.wake_up_order.clear();
     
     // Create players
@@ -394,7 +395,7 @@ pub fn fast_test_mode_system(
     
     // Initialize timer on first run
     if timer.duration() == std::time::Duration::ZERO {
-        *timer = Timer::from_seconds(0.5, TimerMode::Repeating);
+        *timer = GameClock::repeating(GameTimer::FastTestTick);
     }
     
     timer.tick(time.delta());
@@ -492,31 +493,26 @@ fn advance_to_next_year(config: &mut ResMut<GameConfig>, next_state: &mut ResMut
 // Enhanced fast AI system to show which AI is acting
 pub fn fast_ai_decision_system(
     time: Res<Time>,
-    mut ai_players: Query<&mut AIPlayer>,
-    mut workers: Query<&mut Worker>,
-    mut action_spaces: Query<&mut ActionSpaceSlot>,
-    mut hands: Query<&mut Hand>,
-    mut vineyards: Query<&mut Vineyard>,
-    mut players: Query<&mut Player>,
-    mut card_decks: ResMut<CardDecks>,
-    mut commands: Commands,
+    mut ai_players: Query<&mut AIPlayer, Without<AgentController>>,
+    workers: Query<&Worker>,
+    action_spaces: Query<&ActionSpaceSlot>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+    players: Query<&Player>,
     turn_order: Res<TurnOrder>,
     current_state: Res<State<GameState>>,
-    audio_assets: Res<AudioAssets>,
-    audio_settings: Res<AudioSettings>,
-    animation_settings: Res<AnimationSettings>,
-    (mut trackers, structures): (Query<&mut ResidualPaymentTracker>, Query<&Structure>),
-    mut test_config: ResMut<AutoTestConfig>,
+    (config, mut place_events, settings): (Res<GameConfig>, EventWriter<ActionRequested>, Res<UserSettings>),
+    (mut test_config, mut scheduler, mut pass_events, mut rng): (ResMut<AutoTestConfig>, ResMut<AiScheduler>, EventWriter<PassEvent>, ResMut<GameRng>),
 ) {
     if !matches!(current_state.get(), GameState::Summer | GameState::Winter) {
         return;
     }
     
     // Use faster decision making during testing
-    let decision_time = if test_config.enabled && test_config.fast_mode {
-        0.1 // Very fast decisions during testing
+    let decision_timer = if test_config.enabled && test_config.fast_mode {
+        GameTimer::FastAiDecision
     } else {
-        1.5 // Normal decision time
+        GameTimer::AiDecision
     };
     
     let ai_count = ai_players.iter().count();
@@ -524,13 +520,15 @@ pub fn fast_ai_decision_system(
     // Process all AI players
     for mut ai_player in ai_players.iter_mut() {
         // Update timer duration for testing
-        if test_config.enabled && test_config.fast_mode {
-            ai_player.decision_timer = Timer::from_seconds(decision_time, TimerMode::Once);
+        // Only swap the timer when the mode changes, or it would restart every frame
+        if ai_player.decision_timer.duration().as_secs_f32() != decision_timer.seconds() {
+            ai_player.decision_timer = GameClock::once(decision_timer);
         }
         
         ai_player.decision_timer.tick(time.delta());
         
-        if ai_player.decision_timer.finished() {
+        // The rest of the due AIs queue for later frames instead of all thinking at once
+        if ai_player.decision_timer.finished() && scheduler.try_begin(ai_player.player_id, time.elapsed()) {
             ai_player.decision_timer.reset();
             
             // Check if this AI has available workers
@@ -548,25 +546,15 @@ pub fn fast_ai_decision_system(
                     &vineyards,
                     &players,
                     current_state.get(),
+                    config.current_year,
+                    config.player_count,
+                    AiRules::from_settings(&settings, &config),
+                    rng.random(),
                 );
                 
                 if let Some(chosen_action) = action {
-                    execute_ai_action(
-                        chosen_action,
-                        ai_player.player_id,
-                        &mut workers,
-                        &mut action_spaces,
-                        &mut hands,
-                        &mut vineyards,
-                        &mut players,
-                        &mut card_decks,
-                        &mut commands,
-                        &audio_assets,
-                        &audio_settings,
-                        &animation_settings,
-                        &mut trackers,
-                        &structures,
-                    );
+                    let grande = needs_grande(chosen_action, &action_spaces, &workers, ai_player.player_id, current_state.get(), config.player_count);
+                    place_events.send(ActionRequested { player: ai_player.player_id, action: chosen_action, grande });
                     
                     if test_config.enabled {
                         info!("🤖 AI Player {} executed {:?} ({} workers left)", 
@@ -574,6 +562,7 @@ pub fn fast_ai_decision_system(
                     }
                 } else {
                     // AI can't find a valid action - this shouldn't happen
+                    pass_events.send(PassEvent { player: ai_player.player_id });
                     if test_config.enabled {
                         warn!("🚨 AI Player {} has {} workers but no valid actions!", 
                               ai_player.player_id.0 + 1, available_workers);
@@ -601,7 +590,7 @@ pub fn unstuck_system(
     
     // Initialize stuck timer
     if stuck_timer.duration() == std::time::Duration::ZERO {
-        *stuck_timer = Timer::from_seconds(5.0, TimerMode::Once); // 5 second timeout
+        *stuck_timer = GameClock::once(GameTimer::StuckDetection);
     }
     
     match current_state.get() {
@@ -783,30 +772,86 @@ pub fn track_action_usage_system(
     }
 }
 
+#[derive(Component)]
+pub struct DifficultyNotification {
+    pub timer: Timer,
+}
+
+// Opt-in adaptive AI: difficulty for the *next* game is nudged from aggregate win
+// rates, never while a game is running, and every change is announced
 pub fn dynamic_difficulty_system(
-    mut ai_players: Query<&mut AIPlayer>,
+    mut commands: Commands,
+    mut ai_settings: ResMut<AISettings>,
     results: Res<BalanceTestResults>,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    mut last_evaluated: Local<u32>,
 ) {
-    if results.games_played < 5 {
+    if !settings.adaptive_ai || results.games_played < 5 || results.games_played == *last_evaluated {
         return;
     }
     
+    if !matches!(current_state.get(), GameState::MainMenu | GameState::GameOver) {
+        return;
+    }
+    *last_evaluated = results.games_played;
+    
     let ai_win_rate = results.ai_wins as f32 / results.games_played as f32;
     
-    for mut ai_player in ai_players.iter_mut() {
-        match ai_player.difficulty {
-            AIDifficulty::Beginner => {
-                if ai_win_rate < 0.3 {
-                    ai_player.difficulty = AIDifficulty::Intermediate;
-                    info!("Upgraded AI {:?} to Intermediate difficulty", ai_player.player_id);
-                }
-            }
-            AIDifficulty::Intermediate => {
-                if ai_win_rate > 0.8 {
-                    ai_player.difficulty = AIDifficulty::Beginner;
-                    info!("Downgraded AI {:?} to Beginner difficulty", ai_player.player_id);
-                }
-            }
+    // One rung of the ladder at a time
+    let new_difficulty = match ai_win_rate {
+        rate if rate < 0.3 => ai_settings.ai_difficulty.harder(),
+        rate if rate > 0.8 => ai_settings.ai_difficulty.easier(),
+        _ => None,
+    };
+    let Some(new_difficulty) = new_difficulty else { return };
+    
+    let message = format!(
+        "Adaptive AI: next game {:?} -> {:?} (AI win rate {:.0}%)",
+        ai_settings.ai_difficulty, new_difficulty, ai_win_rate * 100.0
+    );
+    info!("🎚️ {}", message);
+    ai_settings.ai_difficulty = new_difficulty;
+    
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                right: Val::Px(20.0),
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.3, 0.5, 0.95)).into(),
+            z_index: UiLayer::Toast.z_index(),
+            ..default()
+        },
+        DifficultyNotification {
+            timer: GameClock::once(GameTimer::Toast),
+        },
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("🎚️ {}", message),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
+
+pub fn difficulty_notification_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notifications: Query<(Entity, &mut DifficultyNotification), Without<MarkedForDespawn>>,
+) {
+    for (entity, mut notification) in notifications.iter_mut() {
+        notification.timer.tick(time.delta());
+        
+        if notification.timer.finished() {
+            commands.entity(entity).insert(MarkedForDespawn);
         }
     }
 }
@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::components::*;
+use crate::systems::animations::spawn_animated_text;
+use crate::systems::expansions::ExpansionSettings;
+use crate::systems::rng::GameRng;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::WorldLayer;
+
+pub const CHITS_PER_YEAR: usize = 3;
+
+#[derive(Component)]
+pub struct BonusChitSprite;
+
+pub fn bonus_chits_active(expansion_settings: &ExpansionSettings, settings: &UserSettings) -> bool {
+    expansion_settings.tuscany_enabled || settings.house_rule_bonus_chits
+}
+
+// At the start of each year, clear last year's chits and deal new ones onto random spaces
+pub fn place_bonus_chits_system(
+    config: Res<GameConfig>,
+    expansion_settings: Res<ExpansionSettings>,
+    settings: Res<UserSettings>,
+    mut action_spaces: Query<&mut ActionSpaceSlot>,
+    mut rng: ResMut<GameRng>,
+    mut last_year: Local<u8>,
+) {
+    if *last_year == config.current_year || action_spaces.is_empty() {
+        return;
+    }
+    *last_year = config.current_year;
+
+    for mut space in action_spaces.iter_mut() {
+        space.bonus_chit = None;
+    }
+
+    if !bonus_chits_active(&expansion_settings, &settings) {
+        return;
+    }
+
+    // One chit per action at most, on its leftmost space
+    let mut spaces: Vec<_> = action_spaces.iter_mut().filter(|s| s.column == 0).collect();
+    spaces.shuffle(&mut *rng);
+
+    for space in spaces.iter_mut().take(CHITS_PER_YEAR) {
+        let chit = if rng.random_bool(0.5) { BonusChit::ExtraCard } else { BonusChit::ExtraLira };
+        space.bonus_chit = Some(chit);
+        info!("🎲 Year {}: {} chit on {:?}", config.current_year, chit.label(), space.action);
+    }
+}
+
+// The first worker placed on a chit space collects it, whichever system placed the worker
+pub fn award_bonus_chits_system(
+    mut commands: Commands,
+    mut action_spaces: Query<&mut ActionSpaceSlot, Changed<ActionSpaceSlot>>,
+    mut players: Query<&mut Player>,
+    mut hands: Query<&mut Hand>,
+    mut card_decks: ResMut<CardDecks>,
+) {
+    for mut space in action_spaces.iter_mut() {
+        let (Some(chit), Some(player_id)) = (space.bonus_chit, space.occupied_by) else {
+            continue;
+        };
+        space.bonus_chit = None;
+
+        match chit {
+            BonusChit::ExtraCard => {
+                if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == player_id) {
+                    if space.is_summer {
+                        if let Some(card) = card_decks.draw_vine_card() {
+                            hand.vine_cards.push(card);
+                        }
+                    } else if let Some(card) = card_decks.draw_wine_order_card() {
+                        hand.wine_order_cards.push(card);
+                    }
+                }
+            }
+            BonusChit::ExtraLira => {
+                if let Some(mut player) = players.iter_mut().find(|p| p.id == player_id) {
+                    player.gain_lira(1);
+                }
+            }
+        }
+
+        spawn_animated_text(&mut commands, player_id, &format!("Chit: {}", chit.label()), Color::srgb(1.0, 0.8, 0.3));
+        info!("🎲 Player {:?} collected {} from {:?}", player_id, chit.label(), space.action);
+    }
+}
+
+pub fn bonus_chit_sprite_system(
+    mut commands: Commands,
+    action_spaces: Query<&ActionSpaceSlot>,
+    existing: Query<Entity, With<BonusChitSprite>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for space in action_spaces.iter() {
+        let Some(chit) = space.bonus_chit else { continue };
+        let color = match chit {
+            BonusChit::ExtraCard => Color::srgb(0.3, 0.8, 0.9),
+            BonusChit::ExtraLira => Color::srgb(1.0, 0.84, 0.0),
+        };
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(10.0, 10.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation((space.position + Vec2::new(-35.0, 0.0)).extend(WorldLayer::Markers.z()))
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ..default()
+            },
+            BonusChitSprite,
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::resignation::Resigned;
+use crate::systems::rng::GameRng;
 
 pub fn fix_worker_state_system(
     mut workers: Query<&mut Worker>,
@@ -30,6 +32,7 @@ pub fn fix_worker_state_system(
 
 pub fn fix_card_deck_system(
     mut card_decks: ResMut<CardDecks>,
+    mut rng: ResMut<GameRng>,
     current_state: Res<State<GameState>>,
 ) {
     if !matches!(current_state.get(), GameState::Summer | GameState::Winter | GameState::Spring) {
@@ -42,45 +45,19 @@ pub fn fix_card_deck_system(
     if card_decks.vine_deck.is_empty() && !card_decks.vine_discard.is_empty() {
         card_decks.vine_deck.append(&mut card_decks_clone.vine_discard);
         use rand::seq::SliceRandom;
-        let mut rng = rand::rng();
-        card_decks.vine_deck.shuffle(&mut rng);
+        card_decks.vine_deck.shuffle(&mut *rng);
     }
     
     if card_decks.wine_order_deck.is_empty() && !card_decks.wine_order_discard.is_empty() {
         card_decks.wine_order_deck.append(&mut card_decks_clone.wine_order_discard);
         use rand::seq::SliceRandom;
-        let mut rng = rand::rng();
-        card_decks.wine_order_deck.shuffle(&mut rng);
-    }
-}
-
-pub fn fix_resource_overflow_system(
-    mut players: Query<&mut Player>,
-    mut vineyards: Query<&mut Vineyard>,
-    current_state: Res<State<GameState>>,
-) {
-    if !matches!(current_state.get(), GameState::Summer | GameState::Winter | GameState::Fall) {
-        return;
-    }
-    
-    for mut player in players.iter_mut() {
-        player.victory_points = player.victory_points.min(99);
-        player.lira = player.lira.min(50);
-        player.workers = player.workers.max(1).min(8); // More reasonable max
-    }
-    
-    for mut vineyard in vineyards.iter_mut() {
-        vineyard.red_grapes = vineyard.red_grapes.min(20);
-        vineyard.white_grapes = vineyard.white_grapes.min(20);
-        vineyard.red_wine = vineyard.red_wine.min(20);
-        vineyard.white_wine = vineyard.white_wine.min(20);
-        vineyard.lira = vineyard.lira.min(50);
+        card_decks.wine_order_deck.shuffle(&mut *rng);
     }
 }
 
 pub fn fix_turn_order_system(
     mut turn_order: ResMut<TurnOrder>,
-    players: Query<&Player>,
+    players: Query<(&Player, Has<Resigned>)>,
     config: Res<GameConfig>,
     current_state: Res<State<GameState>>,
     time: Res<Time>,
@@ -101,16 +78,22 @@ pub fn fix_turn_order_system(
         return; // Skip validation during setup/transition
     }
     
-    if turn_order.current_player >= actual_player_count {
+    // Resigned players keep their entity but never come back into the order
+    let active_players: Vec<PlayerId> = players.iter()
+        .filter(|(_, resigned)| !resigned)
+        .map(|(p, _)| p.id)
+        .collect();
+
+    if turn_order.current_player >= active_players.len() {
         turn_order.current_player = 0;
     }
     
     // Ensure all existing players are in turn order
     let existing_players: std::collections::HashSet<_> = turn_order.players.iter().collect();
-    let all_players: std::collections::HashSet<_> = players.iter().map(|p| &p.id).collect();
+    let all_players: std::collections::HashSet<_> = active_players.iter().collect();
     
-    if existing_players != all_players && actual_player_count > 0 {
-        turn_order.players = players.iter().map(|p| p.id).collect();
+    if existing_players != all_players && !active_players.is_empty() {
+        turn_order.players = active_players;
     }
 }
 
@@ -125,6 +108,10 @@ pub fn fix_action_space_consistency_system(
     
     for mut space in action_spaces.iter_mut() {
         if let Some(occupying_player) = space.occupied_by {
+            // Automa blocks have no worker entity behind them
+            if occupying_player == AUTOMA_PLAYER {
+                continue;
+            }
             let worker_present = workers.iter()
                 .any(|w| w.owner == occupying_player && w.placed_at == Some(space.action));
             
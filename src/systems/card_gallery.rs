@@ -0,0 +1,424 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::expansions::*;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::keymap::*;
+use crate::systems::visitor_script::*;
+use crate::systems::render_layers::UiLayer;
+
+// Ctrl+G on the main menu opens the card gallery: every vine, order, visitor, Mama and Papa
+// card in the enabled pool with its rules text. The list is built from the same constructors
+// and visitor files the decks are dealt from, so it can't drift from them. Typing searches
+// names and rules; the buttons narrow the list to one kind of card.
+
+// Rows drawn at once; the rest wait for a narrower search
+const GALLERY_ROWS: usize = 30;
+const MAX_SEARCH: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardKind {
+    Vine,
+    Order,
+    Visitor,
+    Mama,
+    Papa,
+}
+
+pub const CARD_KINDS: [CardKind; 5] = [CardKind::Vine, CardKind::Order, CardKind::Visitor, CardKind::Mama, CardKind::Papa];
+
+impl CardKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardKind::Vine => "Vines",
+            CardKind::Order => "Orders",
+            CardKind::Visitor => "Visitors",
+            CardKind::Mama => "Mamas",
+            CardKind::Papa => "Papas",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GalleryCard {
+    pub kind: CardKind,
+    pub name: String,
+    pub rules: String,
+    pub tint: Color,
+    // Identical cards in the pool are listed once
+    pub copies: u8,
+}
+
+impl GalleryCard {
+    fn new(kind: CardKind, name: String, rules: String, tint: Color) -> Self {
+        Self { kind, name, rules, tint, copies: 1 }
+    }
+
+    // Case-insensitive, over the name and the rules text
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.trim().to_lowercase();
+        search.is_empty() || self.name.to_lowercase().contains(&search) || self.rules.to_lowercase().contains(&search)
+    }
+}
+
+fn vine_card(card: &VineCard) -> GalleryCard {
+    let (color, value) = match card.vine_type {
+        VineType::Red(v) => ("Red", v),
+        VineType::White(v) => ("White", v),
+    };
+    let mut rules = format!("Plant for {} lira. Harvests a {} grape of value {}.", card.cost, color.to_lowercase(), value);
+    let requirements = card.requirements();
+    match (requirements.needs_trellis, requirements.needs_irrigation) {
+        (true, true) => rules.push_str(" Needs a trellis and irrigation."),
+        (true, false) => rules.push_str(" Needs a trellis."),
+        (false, true) => rules.push_str(" Needs irrigation."),
+        (false, false) => {}
+    }
+    rules.push_str(match card.special_ability {
+        Some(VineAbility::EarlyHarvest) => " Can be harvested in summer.",
+        Some(VineAbility::DiseaseResistant) => " Immune to negative events.",
+        Some(VineAbility::HighYield) => " Yields one extra grape.",
+        None => "",
+    });
+    GalleryCard::new(CardKind::Vine, format!("{} {} vine", color, value), rules, card.art_style.get_color())
+}
+
+fn order_card(order: &WineOrderCard) -> GalleryCard {
+    let mut rules = format!(
+        "Fill with {} red and {} white wine for {} VP and {} lira.",
+        order.red_wine_needed, order.white_wine_needed, order.victory_points, order.immediate_payout(),
+    );
+    if order.residual_payment() > 0 {
+        rules.push_str(&format!(" Residual income +{}.", order.residual_payment()));
+    }
+    let name = format!("{:?} order {}R {}W", order.order_type, order.red_wine_needed, order.white_wine_needed);
+    GalleryCard::new(CardKind::Order, name, rules, order.art_style.get_color())
+}
+
+fn visitor_card(visitor: &VisitorCard) -> GalleryCard {
+    let (season, tint) = match visitor.season {
+        VisitorSeason::Summer => ("Summer", Color::srgb(0.9, 0.7, 0.2)),
+        VisitorSeason::Winter => ("Winter", Color::srgb(0.4, 0.6, 0.9)),
+    };
+    let cost = if visitor.cost > 0 { format!(" Costs {} lira.", visitor.cost) } else { String::new() };
+    let rules = format!("{} visitor.{} {}", season, cost, visitor.effect.rules_text());
+    GalleryCard::new(CardKind::Visitor, visitor.name.clone(), rules, tint)
+}
+
+// "Start with 4 lira and 1 vine card." from the non-zero parts
+fn starting_bonus(parts: Vec<String>) -> String {
+    match parts.split_last() {
+        None => String::new(),
+        Some((last, [])) => format!("Start with {}.", last),
+        Some((last, rest)) => format!("Start with {} and {}.", rest.join(", "), last),
+    }
+}
+
+fn counted(amount: u8, one: &str, many: &str) -> Option<String> {
+    match amount {
+        0 => None,
+        1 => Some(format!("1 {}", one)),
+        n => Some(format!("{} {}", n, many)),
+    }
+}
+
+fn mama_card(mama: &MamaCard) -> GalleryCard {
+    let parts = [
+        counted(mama.bonus_lira, "lira", "lira"),
+        counted(mama.bonus_workers, "extra worker", "extra workers"),
+        counted(mama.bonus_vine_cards, "vine card", "vine cards"),
+    ];
+    let mut rules = starting_bonus(parts.into_iter().flatten().collect());
+    rules.push_str(match mama.special_ability {
+        Some(MamaAbility::ExtraBonusAction) => " One extra action each year.",
+        Some(MamaAbility::DiscountedStructures) => " Structures cost 1 lira less.",
+        Some(MamaAbility::BonusHarvest) => " One extra grape when harvesting.",
+        Some(MamaAbility::FreeVinePlanting) => " The first vine each year is planted for free.",
+        None => "",
+    });
+    GalleryCard::new(CardKind::Mama, mama.name.clone(), rules.trim().to_string(), Color::srgb(0.8, 0.5, 0.6))
+}
+
+fn papa_card(papa: &PapaCard) -> GalleryCard {
+    let mut parts: Vec<String> = counted(papa.bonus_vp, "VP", "VP").into_iter().collect();
+    parts.extend(papa.starting_structures.iter().map(|s| format!("{:?}", s)));
+    parts.extend(counted(papa.bonus_fields, "extra field", "extra fields"));
+    let mut rules = starting_bonus(parts);
+    rules.push_str(match papa.special_ability {
+        Some(PapaAbility::ExtraVineyardField) => " Starts with an extra field.",
+        Some(PapaAbility::AdvancedCellar) => " Can store extra wine.",
+        Some(PapaAbility::TradingConnections) => " Better wine order prices.",
+        Some(PapaAbility::WineExpertise) => " Makes blush wine more efficiently.",
+        None => "",
+    });
+    GalleryCard::new(CardKind::Papa, papa.name.clone(), rules.trim().to_string(), Color::srgb(0.5, 0.6, 0.8))
+}
+
+// The enabled pool: orders the preset allows, and whatever visitors are in play
+pub fn gallery_cards(decks: &CardDecks, visitors: &[VisitorCard], preset: RulesPreset) -> Vec<GalleryCard> {
+    let all = decks.vine_deck.iter().map(vine_card)
+        .chain(decks.wine_order_deck.iter().filter(|o| preset.allows_order(o)).map(order_card))
+        .chain(visitors.iter().map(visitor_card))
+        .chain(decks.mama_cards.iter().map(mama_card))
+        .chain(decks.papa_cards.iter().map(papa_card));
+
+    let mut cards: Vec<GalleryCard> = Vec::new();
+    for card in all {
+        match cards.iter_mut().find(|c| c.kind == card.kind && c.name == card.name && c.rules == card.rules) {
+            Some(existing) => existing.copies = existing.copies.saturating_add(1),
+            None => cards.push(card),
+        }
+    }
+    cards
+}
+
+#[derive(Resource, Default)]
+pub struct CardGallery {
+    pub cards: Vec<GalleryCard>,
+    pub kind: Option<CardKind>,
+    pub search: String,
+}
+
+impl CardGallery {
+    pub fn filtered(&self) -> Vec<&GalleryCard> {
+        self.cards.iter()
+            .filter(|c| self.kind.map_or(true, |kind| c.kind == kind))
+            .filter(|c| c.matches(&self.search))
+            .collect()
+    }
+}
+
+#[derive(Component)]
+pub struct CardGalleryScreen;
+
+#[derive(Component, Clone, Copy)]
+pub enum CardGalleryButton {
+    Kind(Option<CardKind>),
+    Back,
+}
+
+// Ctrl+G opens the gallery from the main menu and closes it again, as does Back
+pub fn card_gallery_system(
+    mut commands: Commands,
+    input: BoundInput,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    (current_state, mut next_state): (Res<State<GameState>>, ResMut<NextState<GameState>>),
+    mut gallery: ResMut<CardGallery>,
+    (preset, expansions, assets): (Res<RulesPreset>, Res<ExpansionSettings>, Option<Res<GameAssets>>),
+    buttons: Query<(&Interaction, &CardGalleryButton), Changed<Interaction>>,
+    screens: Query<Entity, With<CardGalleryScreen>>,
+    menu_text: Query<Entity, With<PhaseText>>,
+) {
+    let toggled = input.just_pressed(InputAction::CardGallery);
+    match current_state.get() {
+        GameState::Gallery => {}
+        GameState::MainMenu if toggled => {
+            let visitors: Vec<VisitorCard> = if expansions.visitor_cards_enabled {
+                let mut deck = VisitorDeck::new();
+                deck.add_visitors(load_visitor_scripts(VISITOR_SCRIPT_DIR));
+                deck.summer_visitors.into_iter().chain(deck.winter_visitors).collect()
+            } else {
+                Vec::new()
+            };
+            gallery.cards = gallery_cards(&CardDecks::new(), &visitors, *preset);
+            gallery.kind = None;
+            gallery.search.clear();
+            for entity in menu_text.iter() {
+                commands.entity(entity).despawn();
+            }
+            keyboard_events.clear();
+            next_state.set(GameState::Gallery);
+            return;
+        }
+        _ => {
+            // Keys pressed elsewhere don't end up in the search
+            keyboard_events.clear();
+            for entity in screens.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            return;
+        }
+    }
+
+    let ctrl_held = input.keyboard.pressed(KeyCode::ControlLeft) || input.keyboard.pressed(KeyCode::ControlRight);
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed || ctrl_held {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) => {
+                for c in chars.chars().filter(|c| c.is_alphanumeric()) {
+                    if gallery.search.len() < MAX_SEARCH {
+                        gallery.search.push(c);
+                    }
+                }
+            }
+            Key::Space if !gallery.search.is_empty() && gallery.search.len() < MAX_SEARCH => gallery.search.push(' '),
+            Key::Backspace => {
+                gallery.search.pop();
+            }
+            _ => {}
+        }
+    }
+
+    let mut leave = toggled;
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            CardGalleryButton::Kind(kind) => gallery.kind = *kind,
+            CardGalleryButton::Back => leave = true,
+        }
+    }
+    if leave {
+        next_state.set(GameState::MainMenu);
+        return;
+    }
+
+    if !gallery.is_changed() && !screens.is_empty() {
+        return;
+    }
+    for entity in screens.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(40.0),
+                left: Val::Percent(10.0),
+                width: Val::Percent(80.0),
+                max_height: Val::Percent(90.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.08, 0.06, 0.97)).into(),
+            z_index: UiLayer::Screen.z_index(),
+            ..default()
+        },
+        CardGalleryScreen,
+    )).with_children(|screen| {
+        spawn_text(screen, "🃏 CARD GALLERY (Ctrl+G to close)".to_string(), 20.0);
+        spawn_text(screen, format!("Search: {}_", gallery.search), 15.0);
+
+        screen.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                margin: UiRect::vertical(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|filters| {
+            spawn_button(filters, "← Main menu".to_string(), CardGalleryButton::Back, false);
+            spawn_button(filters, format!("All ({})", gallery.cards.len()), CardGalleryButton::Kind(None), gallery.kind.is_none());
+            for kind in CARD_KINDS {
+                let count = gallery.cards.iter().filter(|c| c.kind == kind).count();
+                if count > 0 {
+                    let label = format!("{} ({})", kind.label(), count);
+                    spawn_button(filters, label, CardGalleryButton::Kind(Some(kind)), gallery.kind == Some(kind));
+                }
+            }
+        });
+
+        let shown = gallery.filtered();
+        if shown.is_empty() {
+            spawn_text(screen, "No cards match".to_string(), 14.0);
+        }
+        for card in shown.iter().take(GALLERY_ROWS) {
+            spawn_card_row(screen, card, assets.as_deref());
+        }
+        if shown.len() > GALLERY_ROWS {
+            spawn_text(screen, format!("…and {} more; type to narrow the list", shown.len() - GALLERY_ROWS), 13.0);
+        }
+    });
+}
+
+fn spawn_card_row(screen: &mut ChildBuilder, card: &GalleryCard, assets: Option<&GameAssets>) {
+    screen.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            margin: UiRect::vertical(Val::Px(3.0)),
+            ..default()
+        },
+        ..default()
+    }).with_children(|row| {
+        let art_style = Style {
+            width: Val::Px(30.0),
+            height: Val::Px(42.0),
+            margin: UiRect::right(Val::Px(10.0)),
+            flex_shrink: 0.0,
+            ..default()
+        };
+        // Vines and orders show their card art; the rest a swatch in their colour
+        let texture = assets.and_then(|a| match card.kind {
+            CardKind::Vine => Some(a.vine_card_texture.clone()),
+            CardKind::Order => Some(a.wine_order_card_texture.clone()),
+            _ => None,
+        });
+        match texture {
+            Some(texture) => {
+                row.spawn(ImageBundle {
+                    style: art_style,
+                    image: UiImage::new(texture).with_color(card.tint),
+                    ..default()
+                });
+            }
+            None => {
+                row.spawn(NodeBundle {
+                    style: art_style,
+                    background_color: card.tint.into(),
+                    ..default()
+                });
+            }
+        }
+
+        let name = if card.copies > 1 { format!("{} ×{}\n", card.name, card.copies) } else { format!("{}\n", card.name) };
+        row.spawn(TextBundle::from_sections([
+            TextSection::new(name, TextStyle { font_size: 15.0, color: Color::WHITE, ..default() }),
+            TextSection::new(card.rules.clone(), TextStyle { font_size: 13.0, color: Color::srgb(0.8, 0.8, 0.75), ..default() }),
+        ]));
+    });
+}
+
+fn spawn_text(parent: &mut ChildBuilder, text: String, font_size: f32) {
+    parent.spawn(TextBundle::from_section(
+        text,
+        TextStyle {
+            font_size,
+            color: Color::WHITE,
+            ..default()
+        },
+    ));
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: String, button: CardGalleryButton, selected: bool) {
+    let background = if selected { Srgba::new(0.45, 0.3, 0.15, 1.0) } else { Srgba::new(0.25, 0.2, 0.15, 1.0) };
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                margin: UiRect::all(Val::Px(4.0)),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                ..default()
+            },
+            background_color: Color::from(background).into(),
+            ..default()
+        },
+        button,
+    )).with_children(|b| {
+        b.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
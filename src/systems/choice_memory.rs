@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::components::*;
+use crate::systems::settings::UserSettings;
+
+// Each player's last pick in the choice dialogs: the vine planted, the order filled, the
+// recipe made and the fields harvested. A dialog opens with that pick highlighted so a
+// repeated plan is one key press. Kept in the local profile by player name.
+// Build Structure has no choice to remember; it always builds the trellis.
+pub const CHOICE_MEMORY_PATH: &str = "viticulture_choices.json";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct RememberedChoices {
+    pub vine: Option<VineType>,
+    // Red and white wines the order asked for; the same card is rarely drawn twice
+    pub order: Option<(u8, u8)>,
+    pub recipe: Option<WineType>,
+    pub harvest_fields: Vec<usize>,
+}
+
+#[derive(Serialize, Deserialize, Resource, Clone, Default)]
+#[serde(default)]
+pub struct ChoiceMemory {
+    pub players: HashMap<String, RememberedChoices>,
+}
+
+impl ChoiceMemory {
+    pub fn load_or_default() -> Self {
+        match std::fs::read_to_string(CHOICE_MEMORY_PATH) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = std::fs::write(CHOICE_MEMORY_PATH, json);
+        }
+    }
+
+    pub fn get(&self, player: &str) -> RememberedChoices {
+        self.players.get(player).cloned().unwrap_or_default()
+    }
+
+    pub fn remember(&mut self, player: &str, update: impl FnOnce(&mut RememberedChoices)) {
+        update(self.players.entry(player.to_string()).or_default());
+    }
+
+    // Where the remembered vine sits in the hand, if one like it is still there
+    pub fn vine_index(&self, player: &str, hand: &Hand) -> Option<usize> {
+        let vine = self.get(player).vine?;
+        hand.vine_cards.iter().position(|card| card.vine_type == vine)
+    }
+
+    pub fn order_index(&self, player: &str, hand: &Hand) -> Option<usize> {
+        let order = self.get(player).order?;
+        hand.wine_order_cards.iter().position(|o| (o.red_wine_needed, o.white_wine_needed) == order)
+    }
+
+    // Remembered fields still harvestable, or None when none of them are
+    pub fn harvest_fields(&self, player: &str, harvestable: &[usize], limit: usize) -> Option<Vec<usize>> {
+        let fields: Vec<usize> = self.get(player).harvest_fields.into_iter()
+            .filter(|f| harvestable.contains(f))
+            .take(limit)
+            .collect();
+        (!fields.is_empty()).then_some(fields)
+    }
+}
+
+pub fn initialize_choice_memory_system(mut commands: Commands) {
+    commands.insert_resource(ChoiceMemory::load_or_default());
+}
+
+// With the setting on, every new game starts from the dialogs' usual defaults
+pub fn reset_choice_memory_system(
+    current_state: Res<State<GameState>>,
+    settings: Res<UserSettings>,
+    mut memory: ResMut<ChoiceMemory>,
+) {
+    if settings.reset_choices_each_game && current_state.is_changed()
+        && matches!(current_state.get(), GameState::Setup) && !memory.players.is_empty() {
+        memory.players.clear();
+    }
+}
+
+pub fn save_choice_memory_system(memory: Res<ChoiceMemory>) {
+    if memory.is_changed() && !memory.is_added() {
+        memory.save();
+    }
+}
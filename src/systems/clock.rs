@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use crate::systems::keymap::*;
+
+const MIN_SPEED: f32 = 0.25;
+const MAX_SPEED: f32 = 4.0;
+
+// Every named timer in the game, in seconds at 1x speed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameTimer {
+    AiDecision,
+    FastAiDecision,
+    FastTestTick,
+    StuckDetection,
+    TestRestart,
+    TooltipHover,
+    Toast,
+    AchievementToast,
+    GameplayTip,
+    SeasonTransition,
+    SeasonBanner,
+    FloatingText,
+    UndoHighlight,
+}
+
+impl GameTimer {
+    pub fn seconds(&self) -> f32 {
+        match self {
+            GameTimer::AiDecision => 1.5,
+            GameTimer::FastAiDecision => 0.1,
+            GameTimer::FastTestTick => 0.5,
+            GameTimer::StuckDetection => 5.0,
+            GameTimer::TestRestart => 1.0,
+            GameTimer::TooltipHover => 0.5,
+            GameTimer::Toast => 5.0,
+            GameTimer::AchievementToast => 4.0,
+            GameTimer::GameplayTip => 6.0,
+            GameTimer::SeasonTransition => 1.0,
+            GameTimer::SeasonBanner => 1.5,
+            GameTimer::FloatingText => 1.5,
+            GameTimer::UndoHighlight => 2.0,
+        }
+    }
+}
+
+// Global speed and pause. Applied to Time<Virtual>, so every timer made here and
+// ticked with Res<Time> speeds up, slows down and freezes together.
+#[derive(Resource)]
+pub struct GameClock {
+    pub speed: f32,
+    pub paused: bool,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self { speed: 1.0, paused: false }
+    }
+}
+
+impl GameClock {
+    pub fn once(timer: GameTimer) -> Timer {
+        Timer::from_seconds(timer.seconds(), TimerMode::Once)
+    }
+
+    pub fn repeating(timer: GameTimer) -> Timer {
+        Timer::from_seconds(timer.seconds(), TimerMode::Repeating)
+    }
+
+    // For durations computed at the call site (animation speeds, particle lifetimes)
+    pub fn once_secs(seconds: f32) -> Timer {
+        Timer::from_seconds(seconds.max(0.0), TimerMode::Once)
+    }
+
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.clamp(MIN_SPEED, MAX_SPEED);
+    }
+}
+
+#[derive(Component)]
+pub struct ClockIndicator;
+
+pub fn sync_game_clock_system(clock: Res<GameClock>, mut virtual_time: ResMut<Time<Virtual>>) {
+    if !clock.is_changed() {
+        return;
+    }
+
+    virtual_time.set_relative_speed(clock.speed);
+    if clock.paused {
+        virtual_time.pause();
+    } else {
+        virtual_time.unpause();
+    }
+}
+
+// P pauses, [ and ] halve and double the game speed
+pub fn game_clock_input_system(input: BoundInput, mut clock: ResMut<GameClock>) {
+    if input.just_pressed(InputAction::Pause) {
+        clock.paused = !clock.paused;
+        info!("⏯️ Game {}", if clock.paused { "paused" } else { "resumed" });
+    }
+    if input.just_pressed(InputAction::SpeedUp) {
+        let speed = clock.speed * 2.0;
+        clock.set_speed(speed);
+        info!("⏩ Game speed {}x", clock.speed);
+    }
+    if input.just_pressed(InputAction::SlowDown) {
+        let speed = clock.speed / 2.0;
+        clock.set_speed(speed);
+        info!("⏪ Game speed {}x", clock.speed);
+    }
+}
+
+pub fn clock_indicator_system(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    existing: Query<Entity, With<ClockIndicator>>,
+) {
+    if !clock.is_changed() {
+        return;
+    }
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let label = if clock.paused {
+        "⏸ PAUSED (P)".to_string()
+    } else if clock.speed != 1.0 {
+        format!("⏩ {}x", clock.speed)
+    } else {
+        return;
+    };
+
+    commands.spawn((
+        TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 20.0,
+                color: Color::from(Srgba::new(1.0, 0.9, 0.4, 1.0)),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Percent(48.0),
+            ..default()
+        }),
+        ClockIndicator,
+    ));
+}
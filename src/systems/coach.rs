@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::game_logic::auto_wine_recipe;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Optional coach: when a human's turn starts it looks at their position and shows at most
+// one tip per season. Click the tip to dismiss it; the Coach Tips setting turns it off.
+
+#[derive(Resource, Default)]
+pub struct Coach {
+    // Year and season of the last tip, so each season gets one at most
+    pub last_tip: Option<(u8, GameState)>,
+}
+
+#[derive(Component)]
+pub struct CoachTip;
+
+// The single most useful suggestion for this position, if any
+pub fn coach_tip(
+    state: &GameState,
+    player: &Player,
+    hand: &Hand,
+    vineyard: &Vineyard,
+    structures: &[Structure],
+) -> Option<String> {
+    let empty_fields = vineyard.fields.iter().filter(|f| f.vine.is_none()).count();
+    let planted_fields = vineyard.fields.len() - empty_fields;
+
+    match state {
+        GameState::Summer => {
+            let plantable = hand.vine_cards.iter()
+                .any(|card| (0..9).any(|i| vineyard.can_plant_vine_with_requirements(i, card, structures)));
+            if plantable {
+                return Some(format!("You have {} vine card(s) and {} empty field(s) — consider Plant Vine",
+                    hand.vine_cards.len(), empty_fields));
+            }
+            if hand.vine_cards.is_empty() && empty_fields > 0 {
+                return Some("No vines in hand and fields to fill — consider Draw Vine".to_string());
+            }
+            if player.lira < 2 {
+                return Some(format!("Only {} lira left — Give Tour pays 2", player.lira));
+            }
+            None
+        }
+        GameState::Winter => {
+            if let Some(order) = hand.wine_order_cards.iter().find(|o| vineyard.can_fulfill_order(o)) {
+                let (held, needed) = if order.red_wine_needed > 0 {
+                    (format!("red wine {}", vineyard.red_wine.highest().unwrap_or(0)), order.red_wine_needed)
+                } else {
+                    (format!("white wine {}", vineyard.white_wine.highest().unwrap_or(0)), order.white_wine_needed)
+                };
+                return Some(format!("You have {} and an order needing {} — consider Fill Order", held, needed));
+            }
+            if let Some(recipe) = auto_wine_recipe(vineyard, structures) {
+                return Some(format!("Grapes on the crush pad ({} red, {} white) — Make Wine could turn them into {:?}",
+                    vineyard.red_grapes.count(), vineyard.white_grapes.count(), recipe));
+            }
+            if planted_fields > 0 && vineyard.red_grapes.is_empty() && vineyard.white_grapes.is_empty() {
+                return Some(format!("{} planted field(s) and an empty crush pad — consider Harvest", planted_fields));
+            }
+            let cellar = vineyard.red_wine.count() + vineyard.white_wine.count();
+            if cellar > 0 && hand.wine_order_cards.is_empty() {
+                return Some(format!("{} wine aging with no order to fill — consider Draw Wine Order", cellar));
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+pub fn coach_system(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    turn_order: Res<TurnOrder>,
+    mut coach: ResMut<Coach>,
+    players: Query<&Player>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+    structures: Query<&Structure>,
+    tips: Query<(Entity, &Interaction), With<CoachTip>>,
+) {
+    let dismissed = tips.iter().any(|(_, interaction)| *interaction == Interaction::Pressed);
+    let season = (config.current_year, current_state.get().clone());
+    let stale = coach.last_tip.as_ref().is_some_and(|last| *last != season);
+    if !settings.coach_tips || dismissed || stale {
+        for (entity, _) in tips.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+    if !settings.coach_tips || coach.last_tip.as_ref() == Some(&season) {
+        return;
+    }
+
+    let Some(player_id) = turn_order.players.get(turn_order.current_player).copied() else { return };
+    let Some(player) = players.iter().find(|p| p.id == player_id && !p.is_ai) else { return };
+    let (Some(hand), Some(vineyard)) = (
+        hands.iter().find(|h| h.owner == player_id),
+        vineyards.iter().find(|v| v.owner == player_id),
+    ) else { return };
+    let owned: Vec<Structure> = structures.iter().filter(|s| s.owner == player_id).cloned().collect();
+
+    let Some(tip) = coach_tip(current_state.get(), player, hand, vineyard, &owned) else { return };
+    coach.last_tip = Some(season);
+    spawn_coach_tip(&mut commands, &tip);
+}
+
+fn spawn_coach_tip(commands: &mut Commands, tip: &str) {
+    commands.spawn((
+        ButtonBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(110.0),
+                left: Val::Px(20.0),
+                max_width: Val::Px(320.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.1, 0.2, 0.15).with_alpha(0.95).into(),
+            border_color: Color::srgb(0.4, 0.8, 0.5).into(),
+            z_index: UiLayer::Tips.z_index(),
+            ..default()
+        },
+        CoachTip,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("🎓 {}\n(click to dismiss)", tip),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
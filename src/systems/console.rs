@@ -0,0 +1,432 @@
+use bevy::prelude::*;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::components::*;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::game_logic::{resolve_action, TemporaryWorker};
+use crate::systems::setup::match_regular_workers;
+use crate::systems::simulation::SimGame;
+use crate::systems::wake_up::WakeUpCharts;
+use crate::systems::render_layers::UiLayer;
+
+// Developer console, opened with ` while testing mode is on. Commands run against a SimGame,
+// so the same text works headless; in game the live state is snapshotted, the command runs,
+// and players, vineyards, hands and decks are written back.
+
+const MAX_OUTPUT_LINES: usize = 18;
+const MAX_ROLLOUTS: u32 = 500;
+const MAX_ROLLOUT_STEPS: usize = 5000;
+
+const ALL_ACTIONS: [ActionSpace; 10] = [
+    ActionSpace::DrawVine,
+    ActionSpace::PlantVine,
+    ActionSpace::BuildStructure,
+    ActionSpace::GiveTour,
+    ActionSpace::SellGrapes,
+    ActionSpace::DrawWineOrder,
+    ActionSpace::Harvest,
+    ActionSpace::MakeWine,
+    ActionSpace::FillOrder,
+    ActionSpace::TrainWorker,
+];
+
+pub const CONSOLE_HELP: &str = "dump | force <player> <action> | set <player> <lira|vp|workers> <n> | advance | rollouts <m>";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsoleResource {
+    Lira,
+    VictoryPoints,
+    Workers,
+}
+
+// Players are numbered from 1, as on screen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsoleCommand {
+    Help,
+    Dump,
+    Force { player: PlayerId, action: ActionSpace },
+    Set { player: PlayerId, resource: ConsoleResource, value: u8 },
+    Advance,
+    Rollouts(u32),
+}
+
+impl ConsoleCommand {
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let words: Vec<String> = line.split_whitespace().map(str::to_lowercase).collect();
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        match words.as_slice() {
+            ["help"] | ["?"] => Ok(Self::Help),
+            ["dump"] => Ok(Self::Dump),
+            ["force", player, action] => Ok(Self::Force { player: parse_player(player)?, action: parse_action(action)? }),
+            ["set", player, resource, value] => {
+                let resource = match *resource {
+                    "lira" => ConsoleResource::Lira,
+                    "vp" => ConsoleResource::VictoryPoints,
+                    "workers" => ConsoleResource::Workers,
+                    other => return Err(format!("unknown resource '{}'", other)),
+                };
+                let value = value.parse().map_err(|_| format!("bad value '{}'", value))?;
+                Ok(Self::Set { player: parse_player(player)?, resource, value })
+            }
+            ["advance"] => Ok(Self::Advance),
+            ["rollouts", games] => match games.parse() {
+                Ok(games) if (1..=MAX_ROLLOUTS).contains(&games) => Ok(Self::Rollouts(games)),
+                _ => Err(format!("rollouts takes 1 to {} games", MAX_ROLLOUTS)),
+            },
+            [] => Err("empty command".to_string()),
+            [other, ..] => Err(format!("unknown command '{}' — {}", other, CONSOLE_HELP)),
+        }
+    }
+}
+
+fn parse_player(text: &str) -> Result<PlayerId, String> {
+    match text.parse::<u8>() {
+        Ok(n) if n >= 1 => Ok(PlayerId(n - 1)),
+        _ => Err(format!("bad player '{}'", text)),
+    }
+}
+
+// Matches the enum name without case or underscores, e.g. "makewine" or "make_wine"
+fn parse_action(text: &str) -> Result<ActionSpace, String> {
+    let wanted = text.replace('_', "");
+    ALL_ACTIONS.into_iter()
+        .find(|a| format!("{:?}", a).to_lowercase() == wanted)
+        .ok_or_else(|| format!("unknown action '{}'", text))
+}
+
+// The season after `phase`, and whether the year turns over on the way
+pub fn next_season(phase: &GameState) -> Option<(GameState, bool)> {
+    match phase {
+        GameState::Spring => Some((GameState::Summer, false)),
+        GameState::Summer => Some((GameState::Fall, false)),
+        GameState::Fall => Some((GameState::Winter, false)),
+        GameState::Winter => Some((GameState::Spring, true)),
+        _ => None,
+    }
+}
+
+pub fn dump_state(game: &SimGame) -> String {
+    let mut text = format!("{:?}, year {} of {}", game.phase, game.current_year, game.max_years);
+    for (i, player) in game.players.iter().enumerate() {
+        let vineyard = &game.vineyards[i];
+        let hand = &game.hands[i];
+        text.push_str(&format!(
+            "\nP{} {}: {} VP, {} lira, {} workers | grapes R{:?} W{:?} | wine R{:?} W{:?} | hand {}v {}o",
+            player.id.0 + 1, player.name, player.victory_points.get(), player.lira.get(), player.workers,
+            vineyard.red_grapes.values(), vineyard.white_grapes.values(),
+            vineyard.red_wine.values(), vineyard.white_wine.values(),
+            hand.vine_cards.len(), hand.wine_order_cards.len(),
+        ));
+    }
+    text
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RolloutScore {
+    pub player: PlayerId,
+    pub avg_victory_points: f32,
+    pub wins: f32,
+}
+
+// Plays the game out with the simulator AI; ties share the win
+pub fn rollout_scores(base: &SimGame, games: u32, seed: u64) -> Vec<RolloutScore> {
+    let mut totals = vec![(0.0f32, 0.0f32); base.players.len()];
+    for game_idx in 0..games {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(game_idx as u64));
+        let mut game = base.clone();
+        for _ in 0..MAX_ROLLOUT_STEPS {
+            if game.is_over() {
+                break;
+            }
+            game.step(&mut rng);
+        }
+
+        let best = game.players.iter().map(|p| p.victory_points.get()).max().unwrap_or(0);
+        let leaders = game.players.iter().filter(|p| p.victory_points == best).count() as f32;
+        for (total, player) in totals.iter_mut().zip(&game.players) {
+            total.0 += player.victory_points.get() as f32;
+            if player.victory_points == best {
+                total.1 += 1.0 / leaders;
+            }
+        }
+    }
+
+    let games = games.max(1) as f32;
+    base.players.iter().zip(totals)
+        .map(|(player, (vp, wins))| RolloutScore { player: player.id, avg_victory_points: vp / games, wins })
+        .collect()
+}
+
+// Runs one command; `seed` feeds the rollouts. Forced actions resolve without placing a worker.
+pub fn run_console_command(game: &mut SimGame, command: ConsoleCommand, seed: u64) -> Result<String, String> {
+    let index_of = |game: &SimGame, player: PlayerId| game.players.iter().position(|p| p.id == player)
+        .ok_or_else(|| format!("no player {}", player.0 + 1));
+
+    match command {
+        ConsoleCommand::Help => Ok(CONSOLE_HELP.to_string()),
+        ConsoleCommand::Dump => Ok(dump_state(game)),
+        ConsoleCommand::Force { player, action } => {
+            let idx = index_of(game, player)?;
+            let structures: Vec<Structure> = game.structures.iter().filter(|s| s.owner == player).cloned().collect();
+            let outcome = resolve_action(
+                action,
+                game.current_year,
+                game.hands.get_mut(idx),
+                game.vineyards.get_mut(idx),
+                game.players.get_mut(idx),
+                game.trackers.get_mut(idx),
+                &mut game.decks,
+                &structures,
+                false,
+            );
+            Ok(format!("P{} {:?}: {:?}", player.0 + 1, action, outcome))
+        }
+        ConsoleCommand::Set { player, resource, value } => {
+            let idx = index_of(game, player)?;
+            let player = &mut game.players[idx];
+            match resource {
+                ConsoleResource::Lira => {
+                    player.lira = Lira::new(value);
+                    game.vineyards[idx].lira = Lira::new(value);
+                }
+                ConsoleResource::VictoryPoints => player.victory_points = VicPoints::new(value),
+                ConsoleResource::Workers => player.workers = value.clamp(1, MAX_WORKERS),
+            }
+            Ok(format!("P{} {:?} = {}", player.id.0 + 1, resource, match resource {
+                ConsoleResource::Lira => player.lira.get(),
+                ConsoleResource::VictoryPoints => player.victory_points.get(),
+                ConsoleResource::Workers => player.workers,
+            }))
+        }
+        ConsoleCommand::Advance => {
+            let (phase, new_year) = next_season(&game.phase).ok_or("not in a season")?;
+            game.phase = phase;
+            if new_year {
+                game.current_year += 1;
+            }
+            game.current_player = 0;
+            Ok(format!("now {:?}, year {}", game.phase, game.current_year))
+        }
+        ConsoleCommand::Rollouts(games) => {
+            let mut text = format!("{} rollouts from {:?}, year {}", games, game.phase, game.current_year);
+            for score in rollout_scores(game, games, seed) {
+                text.push_str(&format!("\nP{}: avg {:.1} VP, {:.0}% wins", score.player.0 + 1, score.avg_victory_points, score.wins * 100.0 / games as f32));
+            }
+            Ok(text)
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct DebugConsole {
+    pub open: bool,
+    pub input: String,
+    pub output: Vec<String>,
+    // Entered line waiting for debug_console_command_system
+    pub submitted: Option<String>,
+}
+
+impl DebugConsole {
+    // Keyboard shortcuts are suppressed while the console has focus
+    pub fn capturing_text(&self) -> bool {
+        self.open
+    }
+
+    pub fn print(&mut self, text: &str) {
+        self.output.extend(text.lines().map(String::from));
+        let overflow = self.output.len().saturating_sub(MAX_OUTPUT_LINES);
+        self.output.drain(..overflow);
+    }
+}
+
+#[derive(Component)]
+pub struct DebugConsolePanel;
+
+#[derive(Component)]
+pub struct DebugConsoleText;
+
+// ` toggles the console in testing mode; Enter submits the line, Escape clears it
+pub fn debug_console_input_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut console: ResMut<DebugConsole>,
+    test_config: Res<AutoTestConfig>,
+    panels: Query<Entity, With<DebugConsolePanel>>,
+    mut texts: Query<&mut Text, With<DebugConsoleText>>,
+) {
+    let toggled = keyboard.just_pressed(KeyCode::Backquote);
+    if toggled || (console.open && !test_config.enabled) {
+        console.open = toggled && !console.open && test_config.enabled;
+        keyboard_events.clear();
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        if console.open {
+            spawn_console_panel(&mut commands, &console_display(&console));
+        }
+        return;
+    }
+    if !console.open {
+        keyboard_events.clear();
+        return;
+    }
+
+    let mut changed = console.is_changed();
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) => console.input.push_str(chars),
+            Key::Space => console.input.push(' '),
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Escape => console.input.clear(),
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                console.submitted = Some(line);
+            }
+            _ => continue,
+        }
+        changed = true;
+    }
+
+    if changed {
+        let display = console_display(&console);
+        for mut text in texts.iter_mut() {
+            text.sections[0].value = display.clone();
+        }
+    }
+}
+
+// Runs a submitted line against a snapshot of the live game and writes the results back
+pub fn debug_console_command_system(
+    mut commands: Commands,
+    mut console: ResMut<DebugConsole>,
+    mut players: Query<&mut Player>,
+    workers: Query<(Entity, &Worker), Without<TemporaryWorker>>,
+    mut vineyards: Query<&mut Vineyard>,
+    mut hands: Query<&mut Hand>,
+    mut trackers: Query<&mut ResidualPaymentTracker>,
+    structures: Query<&Structure>,
+    (mut card_decks, mut config, mut turn_order): (ResMut<CardDecks>, ResMut<GameConfig>, ResMut<TurnOrder>),
+    (current_state, mut next_state, wake_up_charts): (Res<State<GameState>>, ResMut<NextState<GameState>>, Res<WakeUpCharts>),
+    // Seeds the rollouts; kept apart from GameRng so asking for them never changes the game's draws
+    mut rollout_rng: Local<Option<StdRng>>,
+) {
+    let Some(line) = console.submitted.take() else { return };
+    console.print(&format!("> {}", line));
+
+    let command = match ConsoleCommand::parse(&line) {
+        Ok(command) => command,
+        Err(error) => {
+            console.print(&format!("error: {}", error));
+            return;
+        }
+    };
+
+    let mut game = SimGame::from_parts(
+        players.iter().cloned().collect(),
+        vineyards.iter().cloned().collect(),
+        hands.iter().cloned().collect(),
+        trackers.iter().cloned().collect(),
+        structures.iter().cloned().collect(),
+        card_decks.clone(),
+        &config,
+        current_state.get().clone(),
+        0,
+    )
+    .with_wake_up_chart(wake_up_charts.active())
+    .with_player_on_turn(turn_order.players.get(turn_order.current_player).copied());
+
+    let seed = rollout_rng.get_or_insert_with(|| StdRng::seed_from_u64(rand::random())).random();
+    match run_console_command(&mut game, command, seed) {
+        Ok(output) => {
+            info!("🖥 Console: {} -> {}", line, output);
+            console.print(&output);
+        }
+        Err(error) => {
+            console.print(&format!("error: {}", error));
+            return;
+        }
+    }
+
+    match command {
+        ConsoleCommand::Force { .. } | ConsoleCommand::Set { .. } => {
+            for mut player in players.iter_mut() {
+                if let Some(sim) = game.players.iter().find(|p| p.id == player.id) {
+                    *player = sim.clone();
+                    // A changed worker count puts workers on or takes them off the board
+                    match_regular_workers(&mut commands, player.id, player.workers, workers.iter());
+                }
+            }
+            for mut vineyard in vineyards.iter_mut() {
+                if let Some(sim) = game.vineyards.iter().find(|v| v.owner == vineyard.owner) {
+                    *vineyard = sim.clone();
+                }
+            }
+            for mut hand in hands.iter_mut() {
+                if let Some(sim) = game.hands.iter().find(|h| h.owner == hand.owner) {
+                    *hand = sim.clone();
+                }
+            }
+            for mut tracker in trackers.iter_mut() {
+                if let Some(sim) = game.trackers.iter().find(|t| t.owner == tracker.owner) {
+                    *tracker = sim.clone();
+                }
+            }
+            *card_decks = game.decks;
+        }
+        ConsoleCommand::Advance => {
+            config.current_year = game.current_year;
+            turn_order.start_season();
+            next_state.set(game.phase);
+        }
+        _ => {}
+    }
+}
+
+fn console_display(console: &DebugConsole) -> String {
+    let mut text = String::from("🖥 CONSOLE (` to close) — type 'help'\n");
+    for line in &console.output {
+        text.push_str(line);
+        text.push('\n');
+    }
+    text.push_str(&format!("> {}_", console.input));
+    text
+}
+
+fn spawn_console_panel(commands: &mut Commands, text: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                width: Val::Percent(60.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.02, 0.02, 0.05, 0.92)).into(),
+            z_index: UiLayer::DevTools.z_index(),
+            ..default()
+        },
+        DebugConsolePanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                text,
+                TextStyle {
+                    font_size: 12.0,
+                    color: Color::srgb(0.7, 1.0, 0.7),
+                    ..default()
+                },
+            ),
+            DebugConsoleText,
+        ));
+    });
+}
@@ -0,0 +1,518 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::components::*;
+use crate::systems::game_logic::create_game_over_modal;
+use crate::systems::keymap::*;
+use crate::systems::rng::GameRng;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::{UiLayer, WorldLayer};
+
+// Spaces the Automa grabs first, most damaging to the team first
+const SUMMER_BLOCK_PRIORITY: [ActionSpace; 5] = [
+    ActionSpace::PlantVine, ActionSpace::DrawVine, ActionSpace::GiveTour, ActionSpace::BuildStructure, ActionSpace::SellGrapes,
+];
+const WINTER_BLOCK_PRIORITY: [ActionSpace; 5] = [
+    ActionSpace::FillOrder, ActionSpace::MakeWine, ActionSpace::Harvest, ActionSpace::DrawWineOrder, ActionSpace::TrainWorker,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AutomaDifficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl AutomaDifficulty {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AutomaDifficulty::Easy => "Easy",
+            AutomaDifficulty::Normal => "Normal",
+            AutomaDifficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn blocks_per_season(&self) -> usize {
+        match self {
+            AutomaDifficulty::Easy => 1,
+            AutomaDifficulty::Normal => 2,
+            AutomaDifficulty::Hard => 3,
+        }
+    }
+
+    pub fn steal_chance(&self) -> f64 {
+        match self {
+            AutomaDifficulty::Easy => 0.25,
+            AutomaDifficulty::Normal => 0.5,
+            AutomaDifficulty::Hard => 0.75,
+        }
+    }
+
+    // Combined VP the team needs, per human player
+    pub fn target_per_player(&self) -> u8 {
+        match self {
+            AutomaDifficulty::Easy => 12,
+            AutomaDifficulty::Normal => 15,
+            AutomaDifficulty::Hard => 18,
+        }
+    }
+}
+
+// Solo mercy: from this year on, trailing the Automa by more than MERCY_DEFICIT VP makes it
+// skip the top-priority block of its next season, at most once a year
+pub const MERCY_YEAR: u8 = 4;
+pub const MERCY_DEFICIT: u8 = 8;
+
+pub fn mercy_applies(human_vp: u8, automa_vp: u8, year: u8) -> bool {
+    year >= MERCY_YEAR && automa_vp.saturating_sub(human_vp) > MERCY_DEFICIT
+}
+
+// A block the Automa left open under the mercy rule
+#[derive(Clone, Debug, PartialEq)]
+pub struct MercySkip {
+    pub year: u8,
+    pub season: GameState,
+    pub action: ActionSpace,
+}
+
+// Co-op variant: all humans share one VP target against the Automa
+#[derive(Resource, Default)]
+pub struct CoopMode {
+    pub difficulty: Option<AutomaDifficulty>,
+    pub automa_vp: u8,
+    pub orders_stolen: u8,
+    pub result: Option<CoopResult>,
+    pub mercy_skips: Vec<MercySkip>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CoopResult {
+    pub team_won: bool,
+    pub team_vp: u8,
+    pub score: i32,
+}
+
+impl CoopMode {
+    pub fn is_active(&self) -> bool {
+        self.difficulty.is_some()
+    }
+
+    // One human against the Automa
+    pub fn is_solo(&self, player_count: u8) -> bool {
+        self.is_active() && player_count == 1
+    }
+
+    // Games the Automa went easy in stay out of the career records
+    pub fn mercy_triggered(&self) -> bool {
+        !self.mercy_skips.is_empty()
+    }
+
+    pub fn mercy_text(&self) -> Option<String> {
+        let skips: Vec<String> = self.mercy_skips.iter()
+            .map(|skip| format!("{:?} in {:?} of year {}", skip.action, skip.season, skip.year))
+            .collect();
+        (!skips.is_empty()).then(|| format!("🕊 Mercy: the Automa left open {}", skips.join(", ")))
+    }
+
+    pub fn shared_target(&self, player_count: u8) -> u8 {
+        self.difficulty.map(|d| d.target_per_player().saturating_mul(player_count)).unwrap_or(0)
+    }
+
+    fn cycle(&mut self) {
+        self.difficulty = match self.difficulty {
+            None => Some(AutomaDifficulty::Easy),
+            Some(AutomaDifficulty::Easy) => Some(AutomaDifficulty::Normal),
+            Some(AutomaDifficulty::Normal) => Some(AutomaDifficulty::Hard),
+            Some(AutomaDifficulty::Hard) => None,
+        };
+    }
+}
+
+// Final score: margin over the Automa, plus 2 per unused year when the team wins
+pub fn coop_score(team_vp: u8, automa_vp: u8, years_left: u8, team_won: bool) -> i32 {
+    let bonus = if team_won { 2 * years_left as i32 } else { 0 };
+    team_vp as i32 - automa_vp as i32 + bonus
+}
+
+pub fn coop_rating(score: i32) -> &'static str {
+    match score {
+        i32::MIN..=-1 => "Pruned",
+        0..=9 => "Table Wine",
+        10..=19 => "Reserve",
+        _ => "Grand Cru",
+    }
+}
+
+pub const COOP_SCORING_TABLE: &str = "  < 0   Pruned\n  0-9   Table Wine\n 10-19  Reserve\n  20+   Grand Cru";
+
+#[derive(Component)]
+pub struct CoopMenuText;
+
+#[derive(Component)]
+pub struct CoopInfoPanel;
+
+#[derive(Component)]
+pub struct CoopInfoText;
+
+#[derive(Component)]
+pub struct CoopResultPanel;
+
+#[derive(Component)]
+pub struct AutomaBlockSprite;
+
+// O (by default) in the main menu cycles Off / Easy / Normal / Hard; co-op seats are all
+// human, and a single seat plays solo against the Automa
+pub fn coop_menu_system(
+    input: BoundInput,
+    mut commands: Commands,
+    mut coop: ResMut<CoopMode>,
+    mut config: ResMut<GameConfig>,
+    existing: Query<Entity, With<CoopMenuText>>,
+) {
+    let pressed = input.just_pressed(InputAction::CoopMode);
+    if pressed {
+        coop.cycle();
+    }
+
+    if coop.is_active() && (config.ai_count != 0 || !(1..=4).contains(&config.player_count)) {
+        config.player_count = config.player_count.clamp(1, 4);
+        config.ai_count = 0;
+    }
+
+    if !pressed && !existing.is_empty() && !config.is_changed() {
+        return;
+    }
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let key = input.keymap.binding(InputAction::CoopMode).map(|b| b.key_label()).unwrap_or_default();
+    let text = match coop.difficulty {
+        Some(difficulty) => format!(
+            "{} vs Automa: {} (team target {} VP) - press {} to change",
+            if coop.is_solo(config.player_count) { "Solo" } else { "Co-op" },
+            difficulty.label(), coop.shared_target(config.player_count), key,
+        ),
+        None => format!("Co-op vs Automa: OFF - press {} to enable", key),
+    };
+
+    commands.spawn((
+        TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.6, 0.9, 0.7),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(410.0),
+            left: Val::Px(50.0),
+            ..default()
+        }),
+        CoopMenuText,
+    ));
+}
+
+pub fn coop_setup_system(
+    mut commands: Commands,
+    mut coop: ResMut<CoopMode>,
+    menu_text: Query<Entity, With<CoopMenuText>>,
+) {
+    for entity in menu_text.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if coop.automa_vp != 0 || coop.orders_stolen != 0 || coop.result.is_some() || coop.mercy_triggered() {
+        coop.automa_vp = 0;
+        coop.orders_stolen = 0;
+        coop.result = None;
+        coop.mercy_skips.clear();
+    }
+}
+
+// At the start of each worker season the Automa occupies the most valuable open spaces
+pub fn automa_block_system(
+    mut coop: ResMut<CoopMode>,
+    config: Res<GameConfig>,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    players: Query<&Player>,
+    mut action_spaces: Query<&mut ActionSpaceSlot>,
+    mut last_blocked: Local<Option<(u8, GameState)>>,
+) {
+    let Some(difficulty) = coop.difficulty else { return };
+    let season = current_state.get();
+    let key = (config.current_year, season.clone());
+    if last_blocked.as_ref() == Some(&key) {
+        return;
+    }
+    *last_blocked = Some(key);
+
+    if !matches!(season, GameState::Summer | GameState::Winter) {
+        return;
+    }
+
+    let year = config.current_year;
+    let mut mercy = settings.automa_mercy
+        && coop.is_solo(config.player_count)
+        && !coop.mercy_skips.iter().any(|skip| skip.year == year)
+        && mercy_applies(team_vp(&players), coop.automa_vp, year);
+
+    let priority: &[ActionSpace] = match season {
+        GameState::Summer => &SUMMER_BLOCK_PRIORITY,
+        GameState::Winter => &WINTER_BLOCK_PRIORITY,
+        _ => return,
+    };
+
+    let mut blocked = 0;
+    for action in priority {
+        if blocked >= difficulty.blocks_per_season() {
+            break;
+        }
+        if let Some(mut space) = action_spaces.iter_mut()
+            .find(|s| s.action == *action && s.is_open(config.player_count) && s.can_place_worker(AUTOMA_PLAYER, season)) {
+            blocked += 1;
+            // Mercy spares only the block the Automa wanted most
+            if mercy {
+                mercy = false;
+                coop.mercy_skips.push(MercySkip { year, season: season.clone(), action: *action });
+                info!("🕊 Mercy: the Automa leaves {:?} open in year {}", action, year);
+                continue;
+            }
+            space.occupied_by = Some(AUTOMA_PLAYER);
+            info!("🤖 Automa blocks {:?}", action);
+        }
+    }
+}
+
+// Each Fall the Automa may steal the best order from whichever player holds the most
+pub fn automa_steal_system(
+    mut coop: ResMut<CoopMode>,
+    config: Res<GameConfig>,
+    mut hands: Query<&mut Hand>,
+    mut rng: ResMut<GameRng>,
+    mut last_year: Local<Option<u8>>,
+) {
+    let Some(difficulty) = coop.difficulty else { return };
+    if *last_year == Some(config.current_year) {
+        return;
+    }
+    *last_year = Some(config.current_year);
+
+    if !rng.random_bool(difficulty.steal_chance()) {
+        return;
+    }
+
+    let Some(mut hand) = hands.iter_mut()
+        .filter(|h| !h.wine_order_cards.is_empty())
+        .max_by_key(|h| h.wine_order_cards.len()) else { return };
+
+    let Some((index, _)) = hand.wine_order_cards.iter().enumerate()
+        .max_by_key(|(_, order)| order.victory_points) else { return };
+
+    let order = hand.wine_order_cards.remove(index);
+    coop.automa_vp = coop.automa_vp.saturating_add(order.victory_points);
+    coop.orders_stolen += 1;
+    info!("🤖 Automa steals a {} VP order from Player {}", order.victory_points, hand.owner.0 + 1);
+}
+
+pub fn automa_block_sprite_system(
+    mut commands: Commands,
+    coop: Res<CoopMode>,
+    action_spaces: Query<&ActionSpaceSlot>,
+    existing: Query<Entity, With<AutomaBlockSprite>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if !coop.is_active() {
+        return;
+    }
+
+    for space in action_spaces.iter().filter(|s| s.occupied_by == Some(AUTOMA_PLAYER)) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.5, 0.5, 0.55),
+                    custom_size: Some(Vec2::new(18.0, 18.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(space.position.extend(WorldLayer::Markers.z())),
+                ..default()
+            },
+            AutomaBlockSprite,
+        ));
+    }
+}
+
+fn team_vp(players: &Query<&Player>) -> u8 {
+    players.iter()
+        .filter(|p| !p.is_ai)
+        .fold(0u8, |total, p| total.saturating_add(p.victory_points.get()))
+}
+
+// Team wins on reaching the shared target; the Automa wins if the years run out first
+pub fn coop_victory_system(
+    mut commands: Commands,
+    mut coop: ResMut<CoopMode>,
+    config: Res<GameConfig>,
+    players: Query<&Player>,
+    mut next_state: ResMut<NextState<GameState>>,
+    text_query: Query<Entity, With<PhaseText>>,
+    existing_modal: Query<Entity, With<GameOverModal>>,
+) {
+    if !coop.is_active() || coop.result.is_some() || !existing_modal.is_empty() || players.is_empty() {
+        return;
+    }
+
+    let team_vp = team_vp(&players);
+    let team_won = team_vp >= coop.shared_target(config.player_count);
+    if !team_won && config.current_year <= config.max_years {
+        return;
+    }
+
+    let years_left = config.max_years.saturating_sub(config.current_year);
+    let score = coop_score(team_vp, coop.automa_vp, years_left, team_won);
+    coop.result = Some(CoopResult { team_won, team_vp, score });
+    info!("🤝 Co-op game over: team {} VP vs Automa {} VP, score {}", team_vp, coop.automa_vp, score);
+
+    for entity in text_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if team_won {
+        create_game_over_modal(&mut commands, "The Team", team_vp);
+    } else {
+        create_game_over_modal(&mut commands, "The Automa", coop.automa_vp);
+    }
+    next_state.set(GameState::GameOver);
+}
+
+// Co-op is played with open information: every hand and the Automa's haul are public
+pub fn coop_info_panel_system(
+    mut commands: Commands,
+    coop: Res<CoopMode>,
+    config: Res<GameConfig>,
+    current_state: Res<State<GameState>>,
+    players: Query<&Player>,
+    hands: Query<&Hand>,
+    panels: Query<Entity, With<CoopInfoPanel>>,
+    mut texts: Query<&mut Text, With<CoopInfoText>>,
+) {
+    let in_game = matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+    if !coop.is_active() || !in_game {
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let mut text = format!(
+        "🤝 TEAM {} / {} VP\n🤖 Automa {} VP ({} orders stolen)\n",
+        team_vp(&players), coop.shared_target(config.player_count), coop.automa_vp, coop.orders_stolen,
+    );
+    if let Some(mercy) = coop.mercy_text() {
+        text.push_str(&format!("{}\n", mercy));
+    }
+    let mut sorted: Vec<_> = players.iter().collect();
+    sorted.sort_by_key(|p| p.id.0);
+    for player in sorted {
+        let Some(hand) = hands.iter().find(|h| h.owner == player.id) else { continue };
+        let orders: Vec<_> = hand.wine_order_cards.iter()
+            .map(|o| format!("{}R/{}W→{}VP", o.red_wine_needed, o.white_wine_needed, o.victory_points))
+            .collect();
+        text.push_str(&format!("\n{}: {} vines | orders {}", player.name, hand.vine_cards.len(),
+            if orders.is_empty() { "-".to_string() } else { orders.join(", ") }));
+    }
+
+    if let Ok(mut existing) = texts.get_single_mut() {
+        if existing.sections[0].value != text {
+            existing.sections[0].value = text;
+        }
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                right: Val::Px(10.0),
+                max_width: Val::Px(420.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.18, 0.12, 0.9)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        CoopInfoPanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                text,
+                TextStyle {
+                    font_size: 13.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ),
+            CoopInfoText,
+        ));
+    });
+}
+
+pub fn coop_result_panel_system(
+    mut commands: Commands,
+    coop: Res<CoopMode>,
+    modal: Query<Entity, With<GameOverModal>>,
+    existing: Query<Entity, With<CoopResultPanel>>,
+) {
+    let Some(result) = coop.result.filter(|_| !modal.is_empty()) else {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    if !existing.is_empty() {
+        return;
+    }
+
+    let mut text = format!(
+        "🤝 CO-OP RESULT\n\n{}\nTeam {} VP, Automa {} VP\nScore {} - {}\n\nSCORING\n{}",
+        if result.team_won { "Target reached!" } else { "The Automa held on" },
+        result.team_vp, coop.automa_vp, result.score, coop_rating(result.score), COOP_SCORING_TABLE,
+    );
+    if let Some(mercy) = coop.mercy_text() {
+        text.push_str(&format!("\n\n{}\nNot counted in career statistics", mercy));
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                left: Val::Px(20.0),
+                width: Val::Px(260.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.18, 0.12, 0.95)).into(),
+            z_index: UiLayer::GameOver.z_index(),
+            ..default()
+        },
+        CoopResultPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 13.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
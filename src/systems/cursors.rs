@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+use bevy::window::{CursorIcon, PrimaryWindow};
+use crate::components::*;
+use crate::systems::grande::GrandeSelection;
+use crate::systems::hand_picker::{order_label, vine_label, HandCardPicker};
+use crate::systems::legal_actions::{legal_action, RulesState};
+use crate::systems::settings::UserSettings;
+use crate::systems::sprites::hand_card_position;
+use crate::systems::split_screen::SplitScreen;
+use crate::systems::handoff::Handoff;
+use crate::systems::render_layers::UiLayer;
+
+// Contextual cursors for the human on turn: a grab hand over their idle workers, a magnifier
+// over hand cards (click one to zoom it), and a blocked sign over spaces they can't use.
+
+const HAND_CARD_SIZE: Vec2 = Vec2::new(32.0, 42.0);
+const ZOOM_CARD_SIZE: Vec2 = Vec2::new(180.0, 240.0);
+
+#[derive(Component)]
+pub struct CardZoomPanel;
+
+#[derive(Clone, Copy, PartialEq)]
+enum HandCardRef {
+    Vine(usize),
+    Order(usize),
+}
+
+fn world_cursor(window: &Window, camera_q: &Query<(&Camera, &GlobalTransform)>) -> Option<Vec2> {
+    let cursor = window.cursor_position()?;
+    let (camera, transform) = camera_q.get_single().ok()?;
+    camera.viewport_to_world_2d(transform, cursor)
+}
+
+fn hand_card_at(hand: &Hand, world_pos: Vec2) -> Option<HandCardRef> {
+    let over = |card_type, idx| Rect::from_center_size(hand_card_position(hand, card_type, idx), HAND_CARD_SIZE).contains(world_pos);
+    (0..hand.vine_cards.len()).find(|i| over(CardType::Vine, *i)).map(HandCardRef::Vine)
+        .or_else(|| (0..hand.wine_order_cards.len()).find(|i| over(CardType::WineOrder, *i)).map(HandCardRef::Order))
+}
+
+pub fn contextual_cursor_system(
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    settings: Res<UserSettings>,
+    turn_order: Res<TurnOrder>,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    grande_selection: Res<GrandeSelection>,
+    players: Query<&Player>,
+    workers: Query<(&Worker, &Clickable)>,
+    action_spaces: Query<(&ActionSpaceSlot, &Clickable)>,
+    (hands, split, handoff): (Query<&Hand>, Res<SplitScreen>, Res<Handoff>),
+) {
+    let Ok(mut window) = windows.get_single_mut() else { return };
+    let icon = if settings.contextual_cursors {
+        world_cursor(&window, &camera_q)
+            .and_then(|world_pos| {
+                let player = turn_order.players.get(turn_order.current_player).copied()?;
+                players.iter().any(|p| p.id == player && !p.is_ai).then_some((world_pos, player))
+            })
+            .map_or(CursorIcon::Default, |(world_pos, player)| {
+                let over_worker = workers.iter().any(|(w, clickable)| {
+                    w.owner == player && w.placed_at.is_none()
+                        && Rect::from_center_size(w.position, clickable.size).contains(world_pos)
+                });
+                // The hand row isn't drawn in split screen or behind the hotseat screen
+                let over_card = hands.iter()
+                    .find(|h| h.owner == player && !split.active() && !handoff.hides_hand())
+                    .is_some_and(|hand| hand_card_at(hand, world_pos).is_some());
+                let space = action_spaces.iter()
+                    .find(|(s, clickable)| Rect::from_center_size(s.position, clickable.size).contains(world_pos))
+                    .map(|(s, _)| s);
+
+                if over_worker {
+                    CursorIcon::Grab
+                } else if over_card {
+                    CursorIcon::ZoomIn
+                } else if let Some(space) = space {
+                    let wants_grande = grande_selection.wants_grande(player, &keyboard);
+                    let board = RulesState::new(action_spaces.iter().map(|(s, _)| s), current_state.get(), config.player_count)
+                        .with_workers(workers.iter().map(|(w, _)| w));
+                    // Same test as a click: an open column, and an occupied one only for the grande once the row is full
+                    let legal = space.is_open(config.player_count)
+                        && legal_action(&board, player, space.action).is_some_and(|legal| {
+                            (space.occupied_by.is_none() || legal.blocked) && legal.uses_grande(wants_grande).is_some()
+                        });
+                    if legal { CursorIcon::Pointer } else { CursorIcon::NotAllowed }
+                } else {
+                    CursorIcon::Default
+                }
+            })
+    } else {
+        CursorIcon::Default
+    };
+
+    if window.cursor.icon != icon {
+        window.cursor.icon = icon;
+    }
+}
+
+// Left-click a hand card to see it full size; any click or Esc puts it back
+pub fn card_zoom_system(
+    mut commands: Commands,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    settings: Res<UserSettings>,
+    picker: Res<HandCardPicker>,
+    turn_order: Res<TurnOrder>,
+    hands: Query<&Hand>,
+    panels: Query<Entity, With<CardZoomPanel>>,
+    (split, handoff): (Res<SplitScreen>, Res<Handoff>),
+) {
+    if !panels.is_empty() {
+        if mouse_input.just_pressed(MouseButton::Left) || keyboard.just_pressed(KeyCode::Escape) || !settings.contextual_cursors {
+            for entity in panels.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+        return;
+    }
+
+    // Shift-clicks belong to the sandbox, and the hand picker owns clicks while it is open
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if !settings.contextual_cursors || !mouse_input.just_pressed(MouseButton::Left) || shift || picker.pending.is_some() || split.active() || handoff.hides_hand() {
+        return;
+    }
+    let Some(world_pos) = windows.get_single().ok().and_then(|window| world_cursor(window, &camera_q)) else { return };
+    let Some(hand) = turn_order.players.get(turn_order.current_player)
+        .and_then(|id| hands.iter().find(|h| h.owner == *id)) else { return };
+
+    let card = match hand_card_at(hand, world_pos) {
+        Some(HandCardRef::Vine(i)) => {
+            let card = &hand.vine_cards[i];
+            (vine_label(card), card.art_style.get_color(), card.art_style.get_border_color())
+        }
+        Some(HandCardRef::Order(i)) => {
+            let order = &hand.wine_order_cards[i];
+            let mut label = order_label(order);
+            if !order.effect_text().is_empty() {
+                label.push_str(&format!("\n\n{}", order.effect_text()));
+            }
+            (label, order.art_style.get_color(), order.art_style.get_border_color())
+        }
+        None => return,
+    };
+    spawn_card_zoom(&mut commands, card);
+}
+
+fn spawn_card_zoom(commands: &mut Commands, (label, color, border): (String, Color, Color)) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            z_index: UiLayer::Tooltip.z_index(),
+            ..default()
+        },
+        CardZoomPanel,
+    )).with_children(|overlay| {
+        overlay.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(ZOOM_CARD_SIZE.x),
+                height: Val::Px(ZOOM_CARD_SIZE.y),
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(4.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: color.into(),
+            border_color: border.into(),
+            ..default()
+        }).with_children(|card| {
+            card.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
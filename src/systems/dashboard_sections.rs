@@ -0,0 +1,242 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::game_log::GameLog;
+use crate::systems::game_logic::ActionOutcome;
+use crate::systems::recent_actions::{RecentActionDetail, RecentActionsRow};
+use crate::systems::settings::UserSettings;
+
+// Progressive disclosure on the player dashboards: resources are always shown, the details
+// sit in sections that start folded. Clicking a header opens or closes that section on every
+// dashboard, and the open ones are kept in the profile's settings for the next game.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DashboardSection {
+    Structures,
+    Residuals,
+    FilledOrders,
+    RecentActions,
+}
+
+impl DashboardSection {
+    pub const ALL: [DashboardSection; 4] = [
+        DashboardSection::Structures,
+        DashboardSection::Residuals,
+        DashboardSection::FilledOrders,
+        DashboardSection::RecentActions,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            DashboardSection::Structures => "Structures",
+            DashboardSection::Residuals => "Residual income",
+            DashboardSection::FilledOrders => "Filled orders",
+            DashboardSection::RecentActions => "Recent actions",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct DashboardSections {
+    pub expanded: Vec<DashboardSection>,
+}
+
+impl DashboardSections {
+    pub fn is_expanded(&self, section: DashboardSection) -> bool {
+        self.expanded.contains(&section)
+    }
+
+    pub fn toggle(&mut self, section: DashboardSection) {
+        if self.is_expanded(section) {
+            self.expanded.retain(|s| *s != section);
+        } else {
+            self.expanded.push(section);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct DashboardSectionHeader {
+    pub player_id: PlayerId,
+    pub section: DashboardSection,
+}
+
+#[derive(Component)]
+pub struct DashboardSectionBody {
+    pub player_id: PlayerId,
+    pub section: DashboardSection,
+}
+
+// The text inside a section body; recent actions keep their own icon row instead
+#[derive(Component)]
+pub struct DashboardSectionText {
+    pub player_id: PlayerId,
+    pub section: DashboardSection,
+}
+
+pub fn header_label(section: DashboardSection, expanded: bool) -> String {
+    format!("{} {}", if expanded { "▾" } else { "▸" }, section.title())
+}
+
+pub fn structures_summary(player: PlayerId, structures: &[&Structure]) -> String {
+    let owned: Vec<String> = structures.iter()
+        .filter(|s| s.owner == player)
+        .map(|s| format!("{:?}", s.structure_type))
+        .collect();
+    if owned.is_empty() { "None built".to_string() } else { owned.join(", ") }
+}
+
+pub fn residuals_summary(tracker: Option<&ResidualPaymentTracker>) -> String {
+    let level = tracker.map_or(0, |t| t.annual_income());
+    format!("Level {}: +{} lira each year", level, level)
+}
+
+pub fn filled_orders_summary(log: &GameLog, player: PlayerId) -> String {
+    let filled: Vec<(u8, u8)> = log.entries.iter()
+        .filter(|e| e.player == player)
+        .filter_map(|e| match e.outcome {
+            ActionOutcome::FilledOrder { victory_points, .. } => Some((e.year, victory_points)),
+            _ => None,
+        })
+        .collect();
+    if filled.is_empty() {
+        return "None yet".to_string();
+    }
+    let total: u32 = filled.iter().map(|(_, vp)| *vp as u32).sum();
+    let list: Vec<String> = filled.iter().map(|(year, vp)| format!("Y{} +{} VP", year, vp)).collect();
+    format!("{} filled, {} VP: {}", filled.len(), total, list.join(", "))
+}
+
+// A folded section under a dashboard: the clickable header, then the body it shows or hides
+pub fn spawn_dashboard_section(parent: &mut ChildBuilder, player_id: PlayerId, section: DashboardSection) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                margin: UiRect::top(Val::Px(6.0)),
+                padding: UiRect::horizontal(Val::Px(4.0)),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            ..default()
+        },
+        DashboardSectionHeader { player_id, section },
+    )).with_children(|header| {
+        header.spawn(TextBundle::from_section(
+            header_label(section, false),
+            TextStyle {
+                font_size: 13.0,
+                color: Color::from(Srgba::new(0.7, 0.7, 0.8, 1.0)),
+                ..default()
+            },
+        ));
+    });
+
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                display: Display::None,
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                margin: UiRect::left(Val::Px(14.0)),
+                ..default()
+            },
+            ..default()
+        },
+        DashboardSectionBody { player_id, section },
+    )).with_children(|body| {
+        if section == DashboardSection::RecentActions {
+            // Filled in by recent_actions_row_system
+            body.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    },
+                    ..default()
+                },
+                RecentActionsRow { player_id },
+            ));
+            body.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::from(Srgba::new(0.8, 0.8, 0.7, 1.0)),
+                        ..default()
+                    },
+                ),
+                RecentActionDetail { player_id },
+            ));
+        } else {
+            body.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 12.0,
+                        color: Color::from(Srgba::new(0.8, 0.8, 0.8, 1.0)),
+                        ..default()
+                    },
+                ),
+                DashboardSectionText { player_id, section },
+            ));
+        }
+    });
+}
+
+pub fn dashboard_section_toggle_system(
+    headers: Query<(&Interaction, &DashboardSectionHeader), Changed<Interaction>>,
+    mut settings: ResMut<UserSettings>,
+) {
+    let mut toggled = false;
+    for (_, header) in headers.iter().filter(|(interaction, _)| **interaction == Interaction::Pressed) {
+        settings.dashboard_sections.toggle(header.section);
+        toggled = true;
+    }
+    if toggled {
+        settings.save();
+    }
+}
+
+// Folds and unfolds the bodies, and keeps the open ones' text current
+pub fn dashboard_sections_system(
+    settings: Res<UserSettings>,
+    game_log: Res<GameLog>,
+    (structures, trackers): (Query<&Structure>, Query<&ResidualPaymentTracker>),
+    headers: Query<(&DashboardSectionHeader, &Children)>,
+    mut bodies: Query<(&DashboardSectionBody, &mut Style)>,
+    mut texts: Query<&mut Text, Without<DashboardSectionText>>,
+    mut section_texts: Query<(&DashboardSectionText, &mut Text)>,
+) {
+    let sections = &settings.dashboard_sections;
+    for (header, children) in headers.iter() {
+        let label = header_label(header.section, sections.is_expanded(header.section));
+        for &child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                if text.sections[0].value != label {
+                    text.sections[0].value = label.clone();
+                }
+            }
+        }
+    }
+
+    for (body, mut style) in bodies.iter_mut() {
+        let display = if sections.is_expanded(body.section) { Display::Flex } else { Display::None };
+        if style.display != display {
+            style.display = display;
+        }
+    }
+
+    let structures: Vec<&Structure> = structures.iter().collect();
+    for (text_section, mut text) in section_texts.iter_mut().filter(|(t, _)| sections.is_expanded(t.section)) {
+        let value = match text_section.section {
+            DashboardSection::Structures => structures_summary(text_section.player_id, &structures),
+            DashboardSection::Residuals => residuals_summary(trackers.iter().find(|t| t.owner == text_section.player_id)),
+            DashboardSection::FilledOrders => filled_orders_summary(&game_log, text_section.player_id),
+            DashboardSection::RecentActions => continue,
+        };
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::components::*;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::simulation::{SimGame, SimStep};
+
+const AUDIT_MAX_STEPS: usize = 500;
+
+// Determinism audit: two headless simulations of the same seed run in lockstep,
+// state hashes are compared after every step and the first divergence is reported
+#[derive(Resource)]
+pub struct DeterminismAudit {
+    pub seed: u64,
+    pub max_steps: usize,
+    pub runs: u32,
+    pub last_report: Option<AuditReport>,
+}
+
+impl Default for DeterminismAudit {
+    fn default() -> Self {
+        Self {
+            seed: 0x5EED,
+            max_steps: AUDIT_MAX_STEPS,
+            runs: 0,
+            last_report: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AuditReport {
+    pub seed: u64,
+    pub steps_checked: usize,
+    pub divergence: Option<Divergence>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Divergence {
+    pub step: usize,
+    pub system: &'static str,
+    pub step_a: SimStep,
+    pub step_b: SimStep,
+    pub hash_a: u64,
+    pub hash_b: u64,
+}
+
+pub fn run_lockstep_audit(base: &SimGame, seed: u64, max_steps: usize) -> AuditReport {
+    let mut sim_a = base.clone();
+    let mut sim_b = base.clone();
+    let mut rng_a = StdRng::seed_from_u64(seed);
+    let mut rng_b = StdRng::seed_from_u64(seed);
+
+    for step in 0..max_steps {
+        let step_a = sim_a.step(&mut rng_a);
+        let step_b = sim_b.step(&mut rng_b);
+        let hash_a = sim_a.state_hash();
+        let hash_b = sim_b.state_hash();
+
+        if step_a != step_b || hash_a != hash_b {
+            return AuditReport {
+                seed,
+                steps_checked: step + 1,
+                divergence: Some(Divergence {
+                    step,
+                    system: step_a.system,
+                    step_a,
+                    step_b,
+                    hash_a,
+                    hash_b,
+                }),
+            };
+        }
+
+        if sim_a.is_over() {
+            return AuditReport { seed, steps_checked: step + 1, divergence: None };
+        }
+    }
+
+    AuditReport { seed, steps_checked: max_steps, divergence: None }
+}
+
+// F3 in testing mode: audit from the current position (or a fresh game from the menu)
+pub fn determinism_audit_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    test_config: Res<AutoTestConfig>,
+    mut audit: ResMut<DeterminismAudit>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+    hands: Query<&Hand>,
+    trackers: Query<&ResidualPaymentTracker>,
+    structures: Query<&Structure>,
+    card_decks: Res<CardDecks>,
+    config: Res<GameConfig>,
+    turn_order: Res<TurnOrder>,
+    current_state: Res<State<GameState>>,
+) {
+    if !test_config.enabled || !keyboard.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    let seed = audit.seed.wrapping_add(audit.runs as u64);
+    let base = if players.is_empty() {
+        SimGame::new(&config, &mut StdRng::seed_from_u64(seed))
+    } else {
+        SimGame::from_parts(
+            players.iter().cloned().collect(),
+            vineyards.iter().cloned().collect(),
+            hands.iter().cloned().collect(),
+            trackers.iter().cloned().collect(),
+            structures.iter().cloned().collect(),
+            card_decks.clone(),
+            &config,
+            current_state.get().clone(),
+            0,
+        )
+        .with_player_on_turn(turn_order.players.get(turn_order.current_player).copied())
+    };
+
+    let report = run_lockstep_audit(&base, seed, audit.max_steps);
+    match &report.divergence {
+        None => info!("🔁 Determinism audit passed: seed {:#x}, {} steps in lockstep", seed, report.steps_checked),
+        Some(div) => warn!(
+            "🚨 Determinism audit FAILED at step {} in {}: {:?} vs {:?} (hash {:#x} != {:#x})",
+            div.step, div.system, div.step_a, div.step_b, div.hash_a, div.hash_b
+        ),
+    }
+
+    audit.runs += 1;
+    audit.last_report = Some(report);
+}
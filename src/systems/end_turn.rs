@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::action_bar::is_summer_button;
+use crate::systems::field_picker::HarvestFieldPicker;
+use crate::systems::grande::GrandeSelection;
+use crate::systems::hand_picker::HandCardPicker;
+use crate::systems::handoff::Handoff;
+use crate::systems::input::PassEvent;
+use crate::systems::keymap::{BoundInput, InputAction};
+use crate::systems::order_tokens::OrderTokenPicker;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+
+// The End Turn button under the action bar is the one way a local player gives up their turn:
+// a click or the End Turn key both land here and go out as a PassEvent. Its label follows the
+// turn, and it only takes input while ending the turn is something the player can do.
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum EndTurnState {
+    // Outside the worker seasons there is no turn to end
+    #[default]
+    Hidden,
+    WaitingForAi,
+    // Another human's turn: a remote seat, or the next hotseat player before the handoff
+    WaitingFor(String),
+    // A placement is under way: a picker is open or the grande is armed
+    PlaceWorker,
+    // Workers already out this season; ending now leaves the rest idle until next season
+    EndTurn { workers_left: usize },
+    // Nothing placed yet this season
+    PassSeason,
+}
+
+impl EndTurnState {
+    pub fn label(&self) -> String {
+        match self {
+            EndTurnState::Hidden => String::new(),
+            EndTurnState::WaitingForAi => "Waiting for AI…".to_string(),
+            EndTurnState::WaitingFor(name) => format!("Waiting for {}…", name),
+            EndTurnState::PlaceWorker => "Place a worker…".to_string(),
+            EndTurnState::EndTurn { workers_left: 1 } => "End Turn (1 worker left)".to_string(),
+            EndTurnState::EndTurn { workers_left } => format!("End Turn ({} workers left)", workers_left),
+            EndTurnState::PassSeason => "Pass for the season".to_string(),
+        }
+    }
+
+    // Whether a click or the End Turn key does anything
+    pub fn enabled(&self) -> bool {
+        matches!(self, EndTurnState::EndTurn { .. } | EndTurnState::PassSeason)
+    }
+}
+
+pub fn end_turn_state(
+    season: &GameState,
+    on_turn: Option<&Player>,
+    local: bool,
+    placing: bool,
+    workers_left: usize,
+    placed_this_season: usize,
+) -> EndTurnState {
+    let Some(player) = on_turn.filter(|_| matches!(season, GameState::Summer | GameState::Winter)) else {
+        return EndTurnState::Hidden;
+    };
+    if player.is_ai {
+        EndTurnState::WaitingForAi
+    } else if !local {
+        EndTurnState::WaitingFor(player.name.clone())
+    } else if placing {
+        EndTurnState::PlaceWorker
+    } else if placed_this_season == 0 {
+        EndTurnState::PassSeason
+    } else {
+        EndTurnState::EndTurn { workers_left }
+    }
+}
+
+// The state the button was last drawn in, read by the input side
+#[derive(Resource, Default)]
+pub struct EndTurnStatus {
+    pub state: EndTurnState,
+}
+
+#[derive(Component)]
+pub struct EndTurnButton;
+
+#[derive(Component)]
+pub struct EndTurnLabel;
+
+pub fn end_turn_button_system(
+    input: BoundInput,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<EndTurnButton>)>,
+    status: Res<EndTurnStatus>,
+    turn_order: Res<TurnOrder>,
+    mut pass_events: EventWriter<PassEvent>,
+) {
+    if !buttons.iter().any(|i| *i == Interaction::Pressed) && !input.just_pressed(InputAction::EndTurn) {
+        return;
+    }
+    if !status.state.enabled() {
+        return;
+    }
+    if let Some(player) = turn_order.players.get(turn_order.current_player).copied() {
+        pass_events.send(PassEvent { player });
+    }
+}
+
+pub fn end_turn_status_system(
+    mut status: ResMut<EndTurnStatus>,
+    input: BoundInput,
+    (current_state, turn_order, handoff): (Res<State<GameState>>, Res<TurnOrder>, Res<Handoff>),
+    (remote, pbf): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>),
+    (hand_picker, field_picker, token_picker, grande): (Res<HandCardPicker>, Res<HarvestFieldPicker>, Res<OrderTokenPicker>, Res<GrandeSelection>),
+    (players, workers): (Query<&Player>, Query<&Worker>),
+    mut buttons: Query<(&mut Style, &mut BackgroundColor), With<EndTurnButton>>,
+    mut labels: Query<&mut Text, With<EndTurnLabel>>,
+) {
+    let on_turn = turn_order.players.get(turn_order.current_player).copied();
+    let player = on_turn.and_then(|id| players.iter().find(|p| p.id == id));
+    let local = on_turn.is_some_and(|id| remote.map_or(true, |r| r.local_player == id) && pbf.map_or(true, |p| p.local_player == id))
+        && handoff.pending.is_none();
+    let placing = hand_picker.pending.is_some() || field_picker.pending.is_some() || token_picker.pending.is_some() || grande.armed.is_some();
+    let summer = matches!(current_state.get(), GameState::Summer);
+    let own: Vec<&Worker> = workers.iter().filter(|w| Some(w.owner) == on_turn).collect();
+    let workers_left = own.iter().filter(|w| w.placed_at.is_none()).count();
+    let placed_this_season = own.iter().filter(|w| w.placed_at.is_some_and(|a| is_summer_button(a) == summer)).count();
+
+    let state = end_turn_state(current_state.get(), player, local, placing, workers_left, placed_this_season);
+    let display = if state == EndTurnState::Hidden { Display::None } else { Display::Flex };
+    let color = if state.enabled() { Color::srgb(0.45, 0.2, 0.2) } else { Color::srgb(0.25, 0.25, 0.25) };
+    for (mut style, mut background) in buttons.iter_mut() {
+        if style.display != display {
+            style.display = display;
+        }
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+    // The key is only offered while it does something
+    let label = match input.keymap.binding(InputAction::EndTurn).filter(|_| state.enabled()) {
+        Some(binding) => format!("{} [{}]", state.label(), binding.key_label()),
+        None => state.label(),
+    };
+    for mut text in labels.iter_mut() {
+        if text.sections[0].value != label {
+            text.sections[0].value = label.clone();
+        }
+    }
+    if status.state != state {
+        status.state = state;
+    }
+}
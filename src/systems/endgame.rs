@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::resignation::Resigned;
 
 #[derive(Resource, Default)]
 pub struct EndGameScoring {
@@ -8,19 +9,19 @@ pub struct EndGameScoring {
 
 pub fn calculate_final_scores(
     mut scoring: ResMut<EndGameScoring>,
-    players: Query<&Player>,
+    players: Query<(&Player, Option<&Resigned>)>,
     vineyards: Query<&Vineyard>,
     structures: Query<&Structure>,
 ) {
     scoring.final_scores.clear();
     
-    for player in players.iter() {
+    for (player, resigned) in players.iter() {
         let vineyard = vineyards.iter().find(|v| v.owner == player.id).unwrap();
         let player_structures: Vec<_> = structures.iter()
             .filter(|s| s.owner == player.id)
             .collect();
         
-        let mut final_vp = player.victory_points;
+        let mut final_vp = player.victory_points.get();
         let mut breakdown = format!("Base VP: {}", player.victory_points);
         
         // Windmill bonus: +1 VP for every 7 lira
@@ -46,6 +47,10 @@ pub fn calculate_final_scores(
             breakdown.push_str(&format!(" | Structures: +{}", structure_bonus));
         }
         
+        if let Some(resigned) = resigned {
+            breakdown.push_str(&format!(" | {} in Year {} ({:?})", resigned.reason.label(), resigned.year, resigned.season));
+        }
+        
         scoring.final_scores.push((player.id, final_vp, breakdown));
     }
     
@@ -54,8 +59,8 @@ pub fn calculate_final_scores(
 }
 
 fn calculate_leftover_bonus(vineyard: &Vineyard) -> u8 {
-    let total_wine = vineyard.red_wine + vineyard.white_wine;
-    let total_grapes = vineyard.red_grapes + vineyard.white_grapes;
+    let total_wine = vineyard.red_wine.count() + vineyard.white_wine.count();
+    let total_grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
     
     // Small bonus for leftover resources (max 2 VP)
     let wine_bonus = (total_wine / 3).min(1); // 1 VP per 3 wine
@@ -99,8 +104,8 @@ pub fn enhanced_tie_breaker(
                 .filter(|s| s.owner == *player_id)
                 .count();
             
-            (*player_id, *vp, vineyard.lira, vineyard.red_wine + vineyard.white_wine, 
-             vineyard.red_grapes + vineyard.white_grapes, structure_count)
+            (*player_id, *vp, vineyard.lira, vineyard.red_wine.count() + vineyard.white_wine.count(), 
+             vineyard.red_grapes.count() + vineyard.white_grapes.count(), structure_count)
         })
         .collect();
     
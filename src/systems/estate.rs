@@ -0,0 +1,344 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::keymap::*;
+use crate::systems::settings::UserSettings;
+use crate::systems::wake_up::WakeUpCharts;
+use crate::systems::render_layers::UiLayer;
+
+// Optional meta layer: the local profile's estate grows with every finished game and
+// unlocks cosmetic mats and scenario packs. Nothing here changes the odds of a game.
+pub const ESTATE_PATH: &str = "viticulture_estate.json";
+
+// Experience needed to reach each level above 1
+const LEVEL_THRESHOLDS: [u32; 9] = [30, 80, 150, 240, 350, 480, 630, 800, 1000];
+
+#[derive(Clone, Copy)]
+pub struct EstateMat {
+    pub name: &'static str,
+    pub level: u8,
+    pub color: Srgba,
+}
+
+pub const ESTATE_MATS: [EstateMat; 5] = [
+    EstateMat { name: "Weathered Oak", level: 1, color: Srgba::new(0.2, 0.2, 0.2, 0.9) },
+    EstateMat { name: "Terracotta", level: 2, color: Srgba::new(0.45, 0.22, 0.14, 0.9) },
+    EstateMat { name: "Olive Grove", level: 4, color: Srgba::new(0.25, 0.32, 0.16, 0.9) },
+    EstateMat { name: "Cellar Stone", level: 6, color: Srgba::new(0.28, 0.27, 0.3, 0.9) },
+    EstateMat { name: "Gilded Vintage", level: 9, color: Srgba::new(0.42, 0.33, 0.1, 0.9) },
+];
+
+// Scenario packs only change the shared setup, the same for every player at the table
+#[derive(Clone, Copy)]
+pub struct ScenarioPack {
+    pub name: &'static str,
+    pub level: u8,
+    pub description: &'static str,
+    pub target_victory_points: u8,
+    pub max_years: u8,
+    pub wake_up_chart: Option<&'static str>,
+    pub bonus_chits: bool,
+    pub order_swap: bool,
+}
+
+pub const SCENARIO_PACKS: [ScenarioPack; 4] = [
+    ScenarioPack {
+        name: "Quick Vintage", level: 3, description: "15 VP within 5 years",
+        target_victory_points: 15, max_years: 5, wake_up_chart: None, bonus_chits: false, order_swap: false,
+    },
+    ScenarioPack {
+        name: "Tuscan Dawn", level: 5, description: "Tuscany wake-up chart with bonus chits",
+        target_victory_points: 20, max_years: 7, wake_up_chart: Some("Tuscany"), bonus_chits: true, order_swap: false,
+    },
+    ScenarioPack {
+        name: "Merchant's Market", level: 7, description: "Order swapping allowed, 22 VP",
+        target_victory_points: 22, max_years: 7, wake_up_chart: None, bonus_chits: false, order_swap: true,
+    },
+    ScenarioPack {
+        name: "Long Vintage", level: 10, description: "25 VP over 9 years",
+        target_victory_points: 25, max_years: 9, wake_up_chart: None, bonus_chits: false, order_swap: false,
+    },
+];
+
+#[derive(Serialize, Deserialize, Resource, Clone)]
+#[serde(default)]
+pub struct Estate {
+    pub enabled: bool,
+    pub experience: u32,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub selected_mat: String,
+}
+
+impl Default for Estate {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            experience: 0,
+            games_played: 0,
+            games_won: 0,
+            selected_mat: ESTATE_MATS[0].name.to_string(),
+        }
+    }
+}
+
+impl Estate {
+    pub fn load_or_default() -> Self {
+        match std::fs::read_to_string(ESTATE_PATH) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = std::fs::write(ESTATE_PATH, json);
+        }
+    }
+
+    pub fn level(&self) -> u8 {
+        1 + LEVEL_THRESHOLDS.iter().filter(|t| self.experience >= **t).count() as u8
+    }
+
+    pub fn next_threshold(&self) -> Option<u32> {
+        LEVEL_THRESHOLDS.iter().copied().find(|t| self.experience < *t)
+    }
+
+    // Finishing counts most; winning and scoring add a little on top
+    pub fn experience_for(won: bool, victory_points: u8) -> u32 {
+        10 + if won { 10 } else { 0 } + victory_points as u32 / 2
+    }
+
+    pub fn mat(&self) -> EstateMat {
+        ESTATE_MATS.iter().copied()
+            .find(|m| m.name == self.selected_mat && m.level <= self.level())
+            .unwrap_or(ESTATE_MATS[0])
+    }
+
+    pub fn unlocked_mats(&self) -> Vec<EstateMat> {
+        ESTATE_MATS.iter().copied().filter(|m| m.level <= self.level()).collect()
+    }
+
+    pub fn unlocked_packs(&self) -> Vec<ScenarioPack> {
+        SCENARIO_PACKS.iter().copied().filter(|p| p.level <= self.level()).collect()
+    }
+}
+
+impl ScenarioPack {
+    pub fn apply(&self, config: &mut GameConfig, settings: &mut UserSettings, charts: &mut WakeUpCharts) {
+        config.target_victory_points = self.target_victory_points;
+        config.max_years = self.max_years;
+        settings.house_rule_bonus_chits = self.bonus_chits;
+        settings.house_rule_order_swap = self.order_swap;
+        if let Some(chart) = self.wake_up_chart {
+            if let Some(index) = charts.charts.iter().position(|c| c.name == chart) {
+                charts.active = index;
+            }
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct EstateScreen {
+    pub open: bool,
+}
+
+#[derive(Component)]
+pub struct EstatePanel;
+
+#[derive(Component, Clone, Copy)]
+pub enum EstateButton {
+    ToggleEnabled,
+    Mat(usize),
+    Scenario(usize),
+}
+
+// Marks the top status bar, which wears the selected estate mat
+#[derive(Component)]
+pub struct EstateMatSurface;
+
+pub fn initialize_estate_system(mut commands: Commands) {
+    commands.insert_resource(Estate::load_or_default());
+}
+
+// Grants estate experience once per finished game to the human seats' profile
+pub fn estate_progress_system(
+    current_state: Res<State<GameState>>,
+    mut estate: ResMut<Estate>,
+    players: Query<&Player>,
+) {
+    if !estate.enabled || !current_state.is_changed() || !matches!(current_state.get(), GameState::GameOver) {
+        return;
+    }
+
+    let top_vp = players.iter().map(|p| p.victory_points.get()).max().unwrap_or(0);
+    let Some(best_human) = players.iter().filter(|p| !p.is_ai).max_by_key(|p| p.victory_points) else { return };
+    let won = best_human.victory_points == top_vp;
+
+    let level_before = estate.level();
+    estate.experience += Estate::experience_for(won, best_human.victory_points.get());
+    estate.games_played += 1;
+    if won {
+        estate.games_won += 1;
+    }
+    estate.save();
+
+    if estate.level() > level_before {
+        info!("🏡 Estate reached level {}", estate.level());
+    }
+}
+
+pub fn estate_mat_system(
+    estate: Res<Estate>,
+    mut surfaces: Query<&mut BackgroundColor, With<EstateMatSurface>>,
+    added: Query<(), Added<EstateMatSurface>>,
+) {
+    if !estate.is_changed() && added.is_empty() {
+        return;
+    }
+    let color = if estate.enabled { estate.mat().color } else { ESTATE_MATS[0].color };
+    for mut background in surfaces.iter_mut() {
+        *background = Color::from(color).into();
+    }
+}
+
+// Ctrl+L on the main menu opens the estate: level, mats to wear and scenario packs to set up
+pub fn estate_screen_system(
+    mut commands: Commands,
+    input: BoundInput,
+    current_state: Res<State<GameState>>,
+    mut screen: ResMut<EstateScreen>,
+    mut estate: ResMut<Estate>,
+    (mut config, mut settings, mut charts): (ResMut<GameConfig>, ResMut<UserSettings>, ResMut<WakeUpCharts>),
+    buttons: Query<(&Interaction, &EstateButton), Changed<Interaction>>,
+    panels: Query<Entity, With<EstatePanel>>,
+) {
+    let on_menu = matches!(current_state.get(), GameState::MainMenu);
+    if input.just_pressed(InputAction::Estate) && on_menu {
+        screen.open = !screen.open;
+    } else if screen.open && !on_menu {
+        screen.open = false;
+    }
+
+    if let Some(button) = buttons.iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| *button)
+    {
+        match button {
+            EstateButton::ToggleEnabled => estate.enabled = !estate.enabled,
+            EstateButton::Mat(index) => {
+                if let Some(mat) = estate.unlocked_mats().get(index) {
+                    estate.selected_mat = mat.name.to_string();
+                }
+            }
+            EstateButton::Scenario(index) => {
+                if let Some(pack) = estate.unlocked_packs().get(index) {
+                    pack.apply(&mut config, &mut settings, &mut charts);
+                    settings.save();
+                    info!("🏡 Next game uses scenario pack \"{}\"", pack.name);
+                }
+            }
+        }
+        estate.save();
+    }
+
+    if !screen.is_changed() && !estate.is_changed() {
+        return;
+    }
+
+    for entity in panels.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !screen.open {
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Percent(25.0),
+                width: Val::Percent(50.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.12, 0.1, 0.06, 0.97)).into(),
+            z_index: UiLayer::Screen.z_index(),
+            ..default()
+        },
+        EstatePanel,
+    )).with_children(|panel| {
+        let progress = match estate.next_threshold() {
+            Some(next) => format!("{} / {} XP to level {}", estate.experience, next, estate.level() + 1),
+            None => format!("{} XP - fully grown", estate.experience),
+        };
+        spawn_text(panel, format!(
+            "🏡 YOUR ESTATE (Ctrl+L to close)\n\nLevel {}  |  {}\nGames finished: {}  |  Won: {}",
+            estate.level(), progress, estate.games_played, estate.games_won,
+        ), 18.0);
+
+        spawn_button(panel, format!("Estate progression: {}", if estate.enabled { "ON" } else { "OFF" }), EstateButton::ToggleEnabled, false);
+
+        spawn_text(panel, "\nMats (cosmetic)".to_string(), 16.0);
+        for (index, mat) in estate.unlocked_mats().iter().enumerate() {
+            spawn_button(panel, mat.name.to_string(), EstateButton::Mat(index), mat.name == estate.mat().name);
+        }
+        let locked_mats: Vec<String> = ESTATE_MATS.iter()
+            .filter(|m| m.level > estate.level())
+            .map(|m| format!("🔒 {} (level {})", m.name, m.level))
+            .collect();
+        if !locked_mats.is_empty() {
+            spawn_text(panel, locked_mats.join("\n"), 13.0);
+        }
+
+        spawn_text(panel, "\nScenario packs (click to set up the next game)".to_string(), 16.0);
+        for (index, pack) in estate.unlocked_packs().iter().enumerate() {
+            spawn_button(panel, format!("{}: {}", pack.name, pack.description), EstateButton::Scenario(index), false);
+        }
+        let locked_packs: Vec<String> = SCENARIO_PACKS.iter()
+            .filter(|p| p.level > estate.level())
+            .map(|p| format!("🔒 {} (level {})", p.name, p.level))
+            .collect();
+        if !locked_packs.is_empty() {
+            spawn_text(panel, locked_packs.join("\n"), 13.0);
+        }
+    });
+}
+
+fn spawn_text(parent: &mut ChildBuilder, text: String, font_size: f32) {
+    parent.spawn(TextBundle::from_section(
+        text,
+        TextStyle {
+            font_size,
+            color: Color::WHITE,
+            ..default()
+        },
+    ));
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: String, button: EstateButton, selected: bool) {
+    let color = if selected { Srgba::new(0.55, 0.42, 0.15, 1.0) } else { Srgba::new(0.3, 0.24, 0.12, 1.0) };
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                margin: UiRect::all(Val::Px(4.0)),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                ..default()
+            },
+            background_color: Color::from(color).into(),
+            ..default()
+        },
+        button,
+    )).with_children(|b| {
+        b.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
@@ -1,5 +1,15 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::keymap::*;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::visitor_targeting::*;
+use crate::systems::ai::{choose_visitor_target, should_play_visitor, AIPlayer};
+use crate::systems::rng::GameRng;
+use crate::systems::visitor_script::*;
+use crate::systems::net::plays_locally;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::seat_choices::{SeatChoice, SeatChoiceMade};
 
 #[derive(Resource, Default)]
 pub struct ExpansionSettings {
@@ -29,6 +39,36 @@ pub enum VisitorEffect {
     StructureDiscount(u8),
     ExtraWorker,
     SwapFields,
+    // Targeted: lira from a chosen opponent
+    StealLira(u8),
+    // Targeted: one chosen wine token ages by this much
+    AgeWine(u8),
+    // Loaded from assets/visitors; targeted when it holds a choose
+    Script(VisitorScript),
+}
+
+impl VisitorEffect {
+    // What the card does, as the gallery prints it
+    pub fn rules_text(&self) -> String {
+        match self {
+            VisitorEffect::GainLira(n) => format!("Gain {} lira.", n),
+            VisitorEffect::GainVP(n) => format!("Gain {} VP.", n),
+            VisitorEffect::DrawCards(n) => format!("Draw {} cards.", n),
+            VisitorEffect::PlantFreeVine => "Plant a vine from your hand in an empty field for free.".to_string(),
+            VisitorEffect::HarvestBonus(n) => format!("Place a red and a white grape of value {}.", n),
+            VisitorEffect::WineBonus(n) => format!("Place a red and a white wine of value {}.", n),
+            VisitorEffect::StructureDiscount(n) => format!("Your next structure costs {} lira less.", n),
+            VisitorEffect::ExtraWorker => "Train a worker.".to_string(),
+            VisitorEffect::SwapFields => "Swap the vines in your first two planted fields.".to_string(),
+            VisitorEffect::StealLira(n) => format!("Take up to {} lira from an opponent.", n),
+            VisitorEffect::AgeWine(n) => format!("Age one of your wines by {}.", n),
+            VisitorEffect::Script(script) => {
+                let text = script.to_string();
+                let mut chars = text.chars();
+                chars.next().map_or(String::new(), |first| format!("{}{}.", first.to_uppercase(), chars.as_str()))
+            }
+        }
+    }
 }
 
 
@@ -124,6 +164,14 @@ impl VisitorDeck {
             cost: 0,
         });
         
+        summer_visitors.push(VisitorCard {
+            id: 1004,
+            name: "Tax Collector".to_string(),
+            effect: VisitorEffect::StealLira(2),
+            season: VisitorSeason::Summer,
+            cost: 0,
+        });
+        
         // Winter visitors (production/scoring focused)
         winter_visitors.push(VisitorCard {
             id: 2000,
@@ -157,6 +205,14 @@ impl VisitorDeck {
             cost: 2,
         });
         
+        winter_visitors.push(VisitorCard {
+            id: 2004,
+            name: "Sommelier".to_string(),
+            effect: VisitorEffect::AgeWine(2),
+            season: VisitorSeason::Winter,
+            cost: 0,
+        });
+        
         Self {
             summer_visitors,
             winter_visitors,
@@ -165,25 +221,41 @@ impl VisitorDeck {
         }
     }
     
-    pub fn draw_summer_visitor(&mut self) -> Option<VisitorCard> {
+    pub fn draw_summer_visitor(&mut self, rng: &mut impl rand::Rng) -> Option<VisitorCard> {
         if self.summer_visitors.is_empty() && !self.summer_discard.is_empty() {
             self.summer_visitors.append(&mut self.summer_discard);
             use rand::seq::SliceRandom;
-            let mut rng = rand::rng();
-            self.summer_visitors.shuffle(&mut rng);
+            self.summer_visitors.shuffle(rng);
         }
         self.summer_visitors.pop()
     }
     
-    pub fn draw_winter_visitor(&mut self) -> Option<VisitorCard> {
+    pub fn draw_winter_visitor(&mut self, rng: &mut impl rand::Rng) -> Option<VisitorCard> {
         if self.winter_visitors.is_empty() && !self.winter_discard.is_empty() {
             self.winter_visitors.append(&mut self.winter_discard);
             use rand::seq::SliceRandom;
-            let mut rng = rand::rng();
-            self.winter_visitors.shuffle(&mut rng);
+            self.winter_visitors.shuffle(rng);
         }
         self.winter_visitors.pop()
     }
+    
+    // Cards loaded from data join the piles of their season
+    pub fn add_visitors(&mut self, visitors: Vec<VisitorCard>) {
+        for visitor in visitors {
+            match visitor.season {
+                VisitorSeason::Summer => self.summer_visitors.push(visitor),
+                VisitorSeason::Winter => self.winter_visitors.push(visitor),
+            }
+        }
+    }
+
+    // A card whose play was cancelled goes back on top of its pile
+    pub fn return_visitor(&mut self, visitor: VisitorCard) {
+        match visitor.season {
+            VisitorSeason::Summer => self.summer_visitors.push(visitor),
+            VisitorSeason::Winter => self.winter_visitors.push(visitor),
+        }
+    }
 }
 
 // Extended action spaces for Tuscany
@@ -214,8 +286,10 @@ pub fn setup_tuscany_expansion_system(
         commands.entity(entity).insert(MarkedForDespawn);
     }
     
-    // Initialize visitor deck
-    commands.insert_resource(VisitorDeck::new());
+    // Initialize visitor deck, with any scripted visitors shipped as data
+    let mut deck = VisitorDeck::new();
+    deck.add_visitors(load_visitor_scripts(VISITOR_SCRIPT_DIR));
+    commands.insert_resource(deck);
     
     info!("Tuscany expansion enabled with visitor cards");
 }
@@ -225,10 +299,16 @@ pub fn handle_visitor_cards_system(
     mut hands: Query<&mut Hand>,
     mut players: Query<&mut Player>,
     mut vineyards: Query<&mut Vineyard>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     turn_order: Res<TurnOrder>,
     current_state: Res<State<GameState>>,
     expansion_settings: Res<ExpansionSettings>,
+    mut targeting: ResMut<VisitorTargeting>,
+    mut rng: ResMut<GameRng>,
+    mut card_decks: ResMut<CardDecks>,
+    ai_players: Query<&AIPlayer>,
+    (remote, pbf, mut choices): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, EventWriter<SeatChoiceMade>),
+    mut ai_weighed: Local<Vec<PlayerId>>,
 ) {
     // Early return if expansion not enabled or resource not available
     if !expansion_settings.visitor_cards_enabled {
@@ -238,41 +318,191 @@ pub fn handle_visitor_cards_system(
     let Some(mut visitor_deck) = visitor_deck else {
         return; // Resource not available, skip system
     };
+
+    // An AI on turn weighs the season's visitor pile once, before placing its first worker
+    if current_state.is_changed() {
+        ai_weighed.clear();
+    }
+    let ai_on_turn = turn_order.players.get(turn_order.current_player)
+        .filter(|id| !ai_weighed.contains(id))
+        .and_then(|id| ai_players.iter().find(|ai| ai.player_id == *id));
+    let mut ai_plays = false;
+    if let Some(ai) = ai_on_turn {
+        ai_weighed.push(ai.player_id);
+        let pile = match current_state.get() {
+            GameState::Summer => visitor_deck.summer_visitors.as_slice(),
+            GameState::Winter => visitor_deck.winter_visitors.as_slice(),
+            _ => &[],
+        };
+        let player = players.iter().find(|p| p.id == ai.player_id);
+        let hand = hands.iter().find(|h| h.owner == ai.player_id);
+        let vineyard = vineyards.iter().find(|v| v.owner == ai.player_id);
+        if let (Some(player), Some(hand), Some(vineyard)) = (player, hand, vineyard) {
+            ai_plays = should_play_visitor(ai.difficulty, pile, player, hand, vineyard);
+        }
+    }
     
-    // Draw visitor card with V key
-    if keyboard.just_pressed(KeyCode::KeyV) {
+    // Draw visitor card with V key, or when the AI chose to; one card at a time while a
+    // target is being chosen. The key only draws for a seat this machine plays.
+    let local_key = input.just_pressed(InputAction::VisitorCard) && turn_order.players.get(turn_order.current_player)
+        .is_some_and(|id| plays_locally(remote.as_deref(), pbf.as_deref(), *id));
+    if (local_key || ai_plays) && targeting.pending.is_none() {
         if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
+            let summer = matches!(current_state.get(), GameState::Summer);
             let visitor = match current_state.get() {
-                GameState::Summer => visitor_deck.draw_summer_visitor(),
-                GameState::Winter => visitor_deck.draw_winter_visitor(),
+                GameState::Summer => visitor_deck.draw_summer_visitor(&mut *rng),
+                GameState::Winter => visitor_deck.draw_winter_visitor(&mut *rng),
                 _ => None,
             };
             
-            if let Some(visitor_card) = visitor {
-                execute_visitor_effect(*current_player_id, &visitor_card, &mut hands, &mut players, &mut vineyards);
+            let Some(visitor_card) = visitor else { return };
+            // AI seats play the same way on every machine; a human's play is replayed elsewhere
+            let is_ai = players.iter().any(|p| p.id == *current_player_id && p.is_ai);
+            let untargeted = SeatChoiceMade { player: *current_player_id, choice: SeatChoice::Visitor { summer, target: None } };
+            if !needs_target(&visitor_card.effect) {
+                execute_visitor_effect(*current_player_id, &visitor_card, None, &mut hands, &mut players, &mut vineyards, &mut card_decks);
                 info!("Player {:?} played visitor: {}", current_player_id, visitor_card.name);
+                if !is_ai {
+                    choices.send(untargeted);
+                }
+                return;
+            }
+            
+            let targets = visitor_target_options(*current_player_id, &visitor_card, &players, &vineyards, &hands, &turn_order, &card_decks);
+            if targets.is_empty() {
+                info!("Visitor {} has nothing to target", visitor_card.name);
+                visitor_deck.return_visitor(visitor_card);
+                if !is_ai {
+                    choices.send(untargeted);
+                }
+                return;
+            }
+            
+            // The AI picks from the same targets a human would see highlighted
+            if is_ai {
+                let scores: Vec<(PlayerId, u8)> = players.iter().map(|p| (p.id, p.victory_points.get())).collect();
+                let target = choose_visitor_target(&visitor_card.effect, &targets, &scores);
+                execute_visitor_effect(*current_player_id, &visitor_card, target, &mut hands, &mut players, &mut vineyards, &mut card_decks);
+                info!("Player {:?} played visitor: {} on {:?}", current_player_id, visitor_card.name, target);
+            } else {
+                targeting.pending = Some(PendingVisitor {
+                    player: *current_player_id,
+                    card: visitor_card,
+                    targets,
+                    summer,
+                });
             }
         }
     }
 }
 
-fn execute_visitor_effect(
+// Every target the player could pick for the card; empty when it has nothing to act on or
+// they can't pay for it
+pub fn visitor_target_options(
+    player_id: PlayerId,
+    card: &VisitorCard,
+    players: &Query<&mut Player>,
+    vineyards: &Query<&mut Vineyard>,
+    hands: &Query<&mut Hand>,
+    turn_order: &TurnOrder,
+    decks: &CardDecks,
+) -> Vec<VisitorTarget> {
+    let Some(player) = players.iter().find(|p| p.id == player_id).filter(|p| p.lira >= card.cost) else { return Vec::new() };
+    let (Some(vineyard), Some(hand)) = (vineyards.iter().find(|v| v.owner == player_id), hands.iter().find(|h| h.owner == player_id)) else {
+        return Vec::new();
+    };
+    match &card.effect {
+        VisitorEffect::Script(script) => script_choices(script, card.cost, player, vineyard, hand, decks),
+        effect => {
+            let opponents: Vec<PlayerId> = turn_order.players.iter().copied().filter(|id| *id != player_id).collect();
+            visitor_targets(effect, vineyard, hand, &opponents)
+        }
+    }
+}
+
+// Replays a visitor another machine's human played: draws the same card from the same pile
+// and plays it on their target. A targeted card without one went back on its pile there, so
+// it goes back here too. False when the target isn't one they could have picked.
+pub fn replay_visitor(
+    player_id: PlayerId,
+    summer: bool,
+    target: Option<VisitorTarget>,
+    deck: &mut VisitorDeck,
+    rng: &mut GameRng,
+    hands: &mut Query<&mut Hand>,
+    players: &mut Query<&mut Player>,
+    vineyards: &mut Query<&mut Vineyard>,
+    turn_order: &TurnOrder,
+    decks: &mut CardDecks,
+) -> bool {
+    let visitor = if summer { deck.draw_summer_visitor(rng) } else { deck.draw_winter_visitor(rng) };
+    let Some(card) = visitor else { return false };
+    match target {
+        None if !needs_target(&card.effect) => {
+            execute_visitor_effect(player_id, &card, None, hands, players, vineyards, decks);
+            true
+        }
+        Some(target) if visitor_target_options(player_id, &card, players, vineyards, hands, turn_order, decks).contains(&target) => {
+            execute_visitor_effect(player_id, &card, Some(target), hands, players, vineyards, decks);
+            true
+        }
+        None => {
+            deck.return_visitor(card);
+            true
+        }
+        Some(_) => {
+            deck.return_visitor(card);
+            false
+        }
+    }
+}
+
+// `target` is one of visitor_targets() for targeted effects and None otherwise
+pub fn execute_visitor_effect(
     player_id: PlayerId,
     visitor: &VisitorCard,
+    target: Option<VisitorTarget>,
     hands: &mut Query<&mut Hand>,
     players: &mut Query<&mut Player>,
     vineyards: &mut Query<&mut Vineyard>,
+    decks: &mut CardDecks,
 ) {
+    // Scripts pay their own cost, and only if every step can be played
+    if let VisitorEffect::Script(script) = &visitor.effect {
+        let choice = match target {
+            Some(VisitorTarget::Choice(idx)) => Some(idx),
+            _ => None,
+        };
+        let player = players.iter_mut().find(|p| p.id == player_id);
+        let vineyard = vineyards.iter_mut().find(|v| v.owner == player_id);
+        let hand = hands.iter_mut().find(|h| h.owner == player_id);
+        if let (Some(mut p), Some(mut v), Some(mut h)) = (player, vineyard, hand) {
+            if let Err(reason) = run_visitor_script(script, visitor.cost, choice, &mut p, &mut v, &mut h, decks) {
+                info!("Visitor {} had no effect: {}", visitor.name, reason);
+            }
+        }
+        return;
+    }
+
+    if players.iter().any(|p| p.id == player_id && p.lira < visitor.cost) {
+        return; // Can't afford
+    }
+    
+    // The opponent pays before the player's own entry is borrowed
+    let mut stolen = 0;
+    if let (VisitorEffect::StealLira(amount), Some(VisitorTarget::Opponent(opponent))) = (&visitor.effect, target) {
+        if let Some(mut victim) = players.iter_mut().find(|p| p.id == opponent) {
+            stolen = victim.lira.get().min(*amount);
+            victim.lira -= stolen;
+        }
+    }
+    
     let mut player = players.iter_mut().find(|p| p.id == player_id);
     let mut vineyard = vineyards.iter_mut().find(|v| v.owner == player_id);
     let mut hand = hands.iter_mut().find(|h| h.owner == player_id);
     
-    // Check if player can afford the visitor
     if let Some(ref mut p) = player {
-        if p.lira < visitor.cost {
-            return; // Can't afford
-        }
-        p.lira = p.lira.saturating_sub(visitor.cost);
+        p.lira -= visitor.cost;
     }
     
     match visitor.effect {
@@ -295,36 +525,57 @@ fn execute_visitor_effect(
         VisitorEffect::PlantFreeVine => {
             if let (Some(ref mut h), Some(ref mut v)) = (hand.as_mut(), vineyard.as_mut()) {
                 if !h.vine_cards.is_empty() {
-                    let vine_card = h.vine_cards.remove(0);
-                    // FIXED: Find an empty field and plant the vine
-                    for field in v.fields.iter_mut() {
-                        if field.vine.is_none() { // FIXED: Check field.vine instead of field
-                            field.vine = Some(vine_card.vine_type); // FIXED: Set field.vine instead of field
-                            break;
-                        }
+                    // The chosen field, or the first empty one when no target was given
+                    let field_idx = match target {
+                        Some(VisitorTarget::Field(idx)) => Some(idx),
+                        _ => v.fields.iter().position(|f| f.vine.is_none()),
+                    };
+                    if let Some(field) = field_idx.and_then(|idx| v.fields.get_mut(idx)).filter(|f| f.vine.is_none()) {
+                        let vine_card = h.vine_cards.remove(0);
+                        field.vine = Some(vine_card.vine_type);
                     }
                 }
             }
         }
         VisitorEffect::HarvestBonus(amount) => {
             if let Some(ref mut v) = vineyard {
-                v.red_grapes += amount;
-                v.white_grapes += amount;
+                v.red_grapes.place(amount);
+                v.white_grapes.place(amount);
             }
         }
         VisitorEffect::WineBonus(amount) => {
             if let Some(ref mut v) = vineyard {
-                v.red_wine += amount;
-                v.white_wine += amount;
+                v.red_wine.place(amount);
+                v.white_wine.place(amount);
+            }
+        }
+        VisitorEffect::StealLira(_) => {
+            if let Some(ref mut p) = player {
+                p.gain_lira(stolen);
+            }
+        }
+        VisitorEffect::AgeWine(amount) => {
+            if let (Some(ref mut v), Some(VisitorTarget::Wine { wine_type, value })) = (vineyard.as_mut(), target) {
+                let track = match wine_type {
+                    WineType::Red | WineType::Sparkling => &mut v.red_wine,
+                    _ => &mut v.white_wine,
+                };
+                if track.take(value) {
+                    if let Some(aged) = track.place(value.saturating_add(amount)) {
+                        v.move_vintage(wine_type, value, aged);
+                    }
+                }
             }
         }
+        // Played above
+        VisitorEffect::Script(_) => {}
         VisitorEffect::StructureDiscount(_amount) => {
             // Temporary discount applied to next structure build
             info!("Structure discount applied");
         }
         VisitorEffect::ExtraWorker => {
             if let Some(ref mut p) = player {
-                p.workers += 1;
+                p.gain_worker();
             }
         }
         VisitorEffect::SwapFields => {
@@ -447,21 +698,22 @@ pub fn create_extended_wine_orders() -> Vec<WineOrderCard> {
 }
 
 pub fn expansion_toggle_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut expansion_settings: ResMut<ExpansionSettings>,
+    preset: Res<RulesPreset>,
 ) {
     // Toggle expansions with function keys
-    if keyboard.just_pressed(KeyCode::F6) {
+    if input.just_pressed(InputAction::ToggleTuscany) {
         expansion_settings.tuscany_enabled = !expansion_settings.tuscany_enabled;
         info!("Tuscany expansion: {}", if expansion_settings.tuscany_enabled { "ON" } else { "OFF" });
     }
     
-    if keyboard.just_pressed(KeyCode::F7) {
+    if input.just_pressed(InputAction::ToggleVisitorCards) && preset.visitors() {
         expansion_settings.visitor_cards_enabled = !expansion_settings.visitor_cards_enabled;
         info!("Visitor cards: {}", if expansion_settings.visitor_cards_enabled { "ON" } else { "OFF" });
     }
     
-    if keyboard.just_pressed(KeyCode::F8) {
+    if input.just_pressed(InputAction::ToggleAdvancedBoards) {
         expansion_settings.advanced_boards_enabled = !expansion_settings.advanced_boards_enabled;
         info!("Advanced boards: {}", if expansion_settings.advanced_boards_enabled { "ON" } else { "OFF" });
     }
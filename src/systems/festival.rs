@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::clock::GameClock;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Wine festival variant: spring of year 4 opens with a festival that pays 1 VP for every
+// wine of value 5 or more in a player's cellar. It is announced as the game starts so
+// players can plan their cellars toward it.
+
+pub const FESTIVAL_YEAR: u8 = 4;
+pub const FESTIVAL_MIN_WINE_VALUE: u8 = 5;
+const FESTIVAL_CARD_SECONDS: f32 = 8.0;
+
+pub fn festival_points(vineyard: &Vineyard) -> u8 {
+    vineyard.red_wine.values().into_iter()
+        .chain(vineyard.white_wine.values())
+        .filter(|v| *v >= FESTIVAL_MIN_WINE_VALUE)
+        .count() as u8
+}
+
+pub fn festival_announcement() -> String {
+    format!("🍷 Wine Festival in spring of year {}\nEvery wine of value {}+ in your cellar will be worth 1 VP",
+        FESTIVAL_YEAR, FESTIVAL_MIN_WINE_VALUE)
+}
+
+pub fn festival_summary(entries: &[(PlayerId, u8)]) -> String {
+    let mut text = format!("🍷 Wine Festival, year {}", FESTIVAL_YEAR);
+    for (player, points) in entries {
+        text.push_str(&format!("\nPlayer {}: +{} VP", player.0 + 1, points));
+    }
+    text
+}
+
+// Extra AI score for steering the cellar toward the festival: press strong grapes while it
+// is still ahead, and hold festival wines back from orders in the year before it
+pub fn festival_ai_bonus(action: ActionSpace, vineyard: &Vineyard, current_year: u8) -> f32 {
+    if current_year > FESTIVAL_YEAR {
+        return 0.0;
+    }
+    match action {
+        ActionSpace::MakeWine => {
+            let strong_grape = vineyard.red_grapes.values().into_iter()
+                .chain(vineyard.white_grapes.values())
+                .any(|v| v >= FESTIVAL_MIN_WINE_VALUE);
+            if strong_grape && current_year < FESTIVAL_YEAR { 0.3 } else { 0.0 }
+        }
+        ActionSpace::FillOrder if current_year + 1 == FESTIVAL_YEAR && festival_points(vineyard) > 0 => -0.3,
+        _ => 0.0,
+    }
+}
+
+// Whether this game's festival has been scored; saved, so loading a spring save of the
+// festival year doesn't award it a second time
+#[derive(Resource, Default)]
+pub struct WineFestival {
+    pub held: bool,
+}
+
+#[derive(Component)]
+pub struct FestivalCard {
+    pub timer: Timer,
+}
+
+// Runs on entering spring: the announcement in year 1, the awards in the festival year.
+// Scheduled after the year-end aging, so the cellar is scored as it stands that spring.
+pub fn wine_festival_system(
+    mut commands: Commands,
+    mut festival: ResMut<WineFestival>,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    mut players: Query<&mut Player>,
+    vineyards: Query<&Vineyard>,
+) {
+    if !settings.house_rule_wine_festival || !current_state.is_changed() || !matches!(current_state.get(), GameState::Spring) {
+        return;
+    }
+    if config.current_year == 1 {
+        festival.held = false;
+        if config.max_years >= FESTIVAL_YEAR {
+            spawn_festival_card(&mut commands, &festival_announcement());
+        }
+        return;
+    }
+    if config.current_year != FESTIVAL_YEAR || festival.held {
+        return;
+    }
+    festival.held = true;
+
+    let mut entries = Vec::new();
+    for mut player in players.iter_mut() {
+        let Some(vineyard) = vineyards.iter().find(|v| v.owner == player.id) else { continue };
+        let points = festival_points(vineyard);
+        if points > 0 {
+            player.gain_victory_points(points);
+        }
+        info!("🍷 Player {:?} scores {} VP at the wine festival", player.id, points);
+        entries.push((player.id, points));
+    }
+
+    entries.sort_by_key(|(id, _)| id.0);
+    spawn_festival_card(&mut commands, &festival_summary(&entries));
+}
+
+pub fn festival_card_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut cards: Query<(Entity, &mut FestivalCard), Without<MarkedForDespawn>>,
+) {
+    for (entity, mut card) in cards.iter_mut() {
+        card.timer.tick(time.delta());
+        if card.timer.finished() || mouse.just_pressed(MouseButton::Left) {
+            commands.entity(entity).insert(MarkedForDespawn);
+        }
+    }
+}
+
+fn spawn_festival_card(commands: &mut Commands, text: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Px(20.0),
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.06, 0.1, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.75, 0.3, 0.45, 1.0)).into(),
+            z_index: UiLayer::Announcement.z_index(),
+            ..default()
+        },
+        FestivalCard {
+            timer: GameClock::once_secs(FESTIVAL_CARD_SECONDS),
+        },
+    )).with_children(|card| {
+        card.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
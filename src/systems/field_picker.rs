@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::choice_memory::ChoiceMemory;
+use crate::systems::input::ActionRequested;
+use crate::systems::keymap::*;
+use crate::systems::render_layers::UiLayer;
+
+// Harvest brings in up to HARVEST_FIELD_LIMIT fields, one more from the bonus slot. When a
+// human has more planted fields than that, the placement waits here while fields are toggled
+// (the player's last harvest, else the best ones, start selected); confirming stores the choice
+// on the vineyard and sends the worker out through ActionRequested.
+#[derive(Resource, Default)]
+pub struct HarvestFieldPicker {
+    pub pending: Option<ActionRequested>,
+    pub selected: Vec<usize>,
+}
+
+impl HarvestFieldPicker {
+    pub fn open(&mut self, event: ActionRequested, vineyard: &Vineyard) {
+        self.selected = vineyard.harvestable_fields().into_iter().take(HARVEST_FIELD_LIMIT).collect();
+        self.pending = Some(event);
+    }
+
+    fn close(&mut self) {
+        self.pending = None;
+        self.selected.clear();
+    }
+}
+
+#[derive(Component)]
+pub struct FieldPickerOverlay;
+
+#[derive(Component)]
+pub struct FieldPickerField(pub usize);
+
+#[derive(Component)]
+pub struct FieldPickerConfirm;
+
+#[derive(Component)]
+pub struct FieldPickerCancel;
+
+const FIELD_BUTTON_SIZE: Vec2 = Vec2::new(76.0, 56.0);
+
+// True when the action should open the picker instead of resolving straight away
+pub fn needs_field_pick(action: ActionSpace, vineyard: &Vineyard) -> bool {
+    action == ActionSpace::Harvest && vineyard.harvestable_fields().len() > HARVEST_FIELD_LIMIT
+}
+
+// The bonus slot is only known once the worker has somewhere to go
+fn pick_limit(
+    event: &ActionRequested,
+    spaces: &Query<&ActionSpaceSlot>,
+    workers: &Query<&Worker>,
+    player_count: u8,
+    state: &GameState,
+) -> Option<usize> {
+    let use_grande = event.grande
+        || !workers.iter().any(|w| w.owner == event.player && w.placed_at.is_none() && !w.is_grande);
+    let (column, on_bonus_slot) = placement_slot(spaces.iter(), event.action, event.player, player_count, state, use_grande)?;
+    let bonus = !on_bonus_slot && spaces.iter()
+        .any(|s| s.action == event.action && s.column == column && s.bonus_free());
+    Some(harvest_limit(bonus))
+}
+
+pub fn field_picker_system(
+    mut commands: Commands,
+    input: BoundInput,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    mut picker: ResMut<HarvestFieldPicker>,
+    mut place_events: EventWriter<ActionRequested>,
+    mut vineyards: Query<&mut Vineyard>,
+    (spaces, workers): (Query<&ActionSpaceSlot>, Query<&Worker>),
+    field_buttons: Query<(&Interaction, &FieldPickerField), Changed<Interaction>>,
+    confirm_buttons: Query<&Interaction, (Changed<Interaction>, With<FieldPickerConfirm>)>,
+    cancel_buttons: Query<&Interaction, (Changed<Interaction>, With<FieldPickerCancel>)>,
+    overlays: Query<Entity, With<FieldPickerOverlay>>,
+    (players, mut memory): (Query<&Player>, ResMut<ChoiceMemory>),
+) {
+    let close = |commands: &mut Commands| {
+        for entity in overlays.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    };
+
+    let Some(pending) = picker.pending else {
+        close(&mut commands);
+        return;
+    };
+
+    let state = current_state.get();
+    let limit = pick_limit(&pending, &spaces, &workers, config.player_count, state);
+    let cancelled = input.keyboard.just_pressed(KeyCode::Escape)
+        || cancel_buttons.iter().any(|i| *i == Interaction::Pressed)
+        || !matches!(state, GameState::Summer | GameState::Winter)
+        || limit.is_none();
+    let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == pending.player) else { return };
+    let (Some(limit), false) = (limit, cancelled) else {
+        picker.close();
+        close(&mut commands);
+        return;
+    };
+
+    let name = players.iter().find(|p| p.id == pending.player).map(|p| p.name.clone()).unwrap_or_default();
+    let harvestable = vineyard.harvestable_fields();
+    if overlays.is_empty() {
+        if let Some(fields) = memory.harvest_fields(&name, &harvestable, limit) {
+            picker.selected = fields;
+        }
+    }
+
+    let confirmed = confirm_buttons.iter().any(|i| *i == Interaction::Pressed)
+        || input.just_pressed(InputAction::RepeatChoice);
+    if confirmed && !picker.selected.is_empty() {
+        vineyard.harvest_selection = picker.selected.iter().copied().take(limit).collect();
+        memory.remember(&name, |choices| choices.harvest_fields = vineyard.harvest_selection.clone());
+        place_events.send(pending);
+        picker.close();
+        close(&mut commands);
+        return;
+    }
+
+    let mut changed = false;
+    for (interaction, field) in field_buttons.iter() {
+        if *interaction != Interaction::Pressed || !harvestable.contains(&field.0) {
+            continue;
+        }
+        if let Some(index) = picker.selected.iter().position(|f| *f == field.0) {
+            picker.selected.remove(index);
+        } else if picker.selected.len() < limit {
+            picker.selected.push(field.0);
+        }
+        changed = true;
+    }
+
+    if changed || overlays.is_empty() {
+        close(&mut commands);
+        let confirm_key = input.keymap.binding(InputAction::RepeatChoice).map(|b| b.key_label()).unwrap_or_default();
+        spawn_picker(&mut commands, &vineyard, &harvestable, &picker.selected, limit, &confirm_key);
+    }
+}
+
+fn field_label(field: &VineyardField) -> String {
+    match field.vine {
+        Some(VineType::Red(_)) => format!("🍇 Red\n→ {}", field.get_harvest_value()),
+        Some(VineType::White(_)) => format!("🍇 White\n→ {}", field.get_harvest_value()),
+        None => "empty".to_string(),
+    }
+}
+
+fn spawn_picker(commands: &mut Commands, vineyard: &Vineyard, harvestable: &[usize], selected: &[usize], limit: usize, confirm_key: &str) {
+    let title = format!("🍇 Harvest up to {} fields — {} selected ({} harvests, Esc cancels)", limit, selected.len(), confirm_key);
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(130.0),
+                left: Val::Percent(30.0),
+                max_width: Val::Percent(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.1, 0.1, 0.1).with_alpha(0.95).into(),
+            border_color: Color::srgb(0.6, 0.5, 0.3).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        FieldPickerOverlay,
+    )).with_children(|overlay| {
+        overlay.spawn(TextBundle::from_section(
+            title,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        // Same 3x3 layout as the vineyard board
+        overlay.spawn(NodeBundle {
+            style: Style {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::px(3, FIELD_BUTTON_SIZE.x + 8.0),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|grid| {
+            for (index, field) in vineyard.fields.iter().enumerate() {
+                let enabled = harvestable.contains(&index);
+                let chosen = selected.contains(&index);
+                let alpha = if enabled { 1.0 } else { 0.35 };
+                let background = match field.vine {
+                    Some(VineType::Red(_)) => Color::srgb(0.55, 0.15, 0.2),
+                    Some(VineType::White(_)) => Color::srgb(0.75, 0.7, 0.35),
+                    None => Color::srgb(0.3, 0.25, 0.2),
+                };
+                let border = if chosen { Color::srgb(1.0, 0.84, 0.0) } else { Color::srgb(0.2, 0.2, 0.2) };
+                grid.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(FIELD_BUTTON_SIZE.x),
+                            height: Val::Px(FIELD_BUTTON_SIZE.y),
+                            margin: UiRect::all(Val::Px(4.0)),
+                            padding: UiRect::all(Val::Px(6.0)),
+                            border: UiRect::all(Val::Px(if chosen { 3.0 } else { 1.0 })),
+                            ..default()
+                        },
+                        background_color: background.with_alpha(alpha).into(),
+                        border_color: border.into(),
+                        ..default()
+                    },
+                    FieldPickerField(index),
+                )).with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        field_label(field),
+                        TextStyle {
+                            font_size: 13.0,
+                            color: Color::WHITE.with_alpha(alpha),
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+
+        overlay.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row| {
+            for (label, confirm) in [("Harvest", true), ("Cancel", false)] {
+                let mut button = row.spawn(ButtonBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: if confirm { Color::srgb(0.25, 0.45, 0.25) } else { Color::srgb(0.3, 0.3, 0.3) }.into(),
+                    ..default()
+                });
+                if confirm {
+                    button.insert(FieldPickerConfirm);
+                } else {
+                    button.insert(FieldPickerCancel);
+                }
+                button.with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        label,
+                        TextStyle {
+                            font_size: 13.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+    });
+}
@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::hand_picker::{order_label, vine_label};
+use crate::systems::keymap::*;
+use crate::systems::render_layers::UiLayer;
+
+// Once the game is over every pile is public. The game-over screen can page through what
+// was left in the decks and discards, including the orders that never came out.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DeckPile {
+    #[default]
+    WineOrderDeck,
+    VineDeck,
+    WineOrderDiscard,
+    VineDiscard,
+}
+
+pub const DECK_PILES: [DeckPile; 4] = [
+    DeckPile::WineOrderDeck,
+    DeckPile::VineDeck,
+    DeckPile::WineOrderDiscard,
+    DeckPile::VineDiscard,
+];
+
+impl DeckPile {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeckPile::WineOrderDeck => "Orders never drawn",
+            DeckPile::VineDeck => "Vines never drawn",
+            DeckPile::WineOrderDiscard => "Order discard",
+            DeckPile::VineDiscard => "Vine discard",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = DECK_PILES.iter().position(|p| *p == self).unwrap_or(0);
+        DECK_PILES[(i + 1) % DECK_PILES.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let i = DECK_PILES.iter().position(|p| *p == self).unwrap_or(0);
+        DECK_PILES[(i + DECK_PILES.len() - 1) % DECK_PILES.len()]
+    }
+
+    pub fn count(&self, decks: &CardDecks) -> usize {
+        match self {
+            DeckPile::WineOrderDeck => decks.wine_order_deck.len(),
+            DeckPile::VineDeck => decks.vine_deck.len(),
+            DeckPile::WineOrderDiscard => decks.wine_order_discard.len(),
+            DeckPile::VineDiscard => decks.vine_discard.len(),
+        }
+    }
+}
+
+fn one_line(label: String) -> String {
+    label.replace('\n', ", ")
+}
+
+// Identical cards are folded into one "3× ..." line, in the order they sit in the pile
+pub fn pile_lines(decks: &CardDecks, pile: DeckPile) -> Vec<String> {
+    let labels: Vec<String> = match pile {
+        DeckPile::WineOrderDeck => decks.wine_order_deck.iter().map(|o| one_line(order_label(o))).collect(),
+        DeckPile::VineDeck => decks.vine_deck.iter().map(|v| one_line(vine_label(v))).collect(),
+        DeckPile::WineOrderDiscard => decks.wine_order_discard.iter().map(|o| one_line(order_label(o))).collect(),
+        DeckPile::VineDiscard => decks.vine_discard.iter().map(|v| one_line(vine_label(v))).collect(),
+    };
+
+    let mut grouped: Vec<(String, usize)> = Vec::new();
+    for label in labels {
+        match grouped.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, count)) => *count += 1,
+            None => grouped.push((label, 1)),
+        }
+    }
+    grouped.into_iter()
+        .map(|(label, count)| if count > 1 { format!("{}× {}", count, label) } else { label })
+        .collect()
+}
+
+pub fn final_decks_text(decks: &CardDecks, pile: DeckPile, keymap: &Keymap) -> String {
+    let key = |action| keymap.binding(action).map(|b| b.key_label()).unwrap_or_default();
+    let mut text = format!("🃏 FINAL DECKS  ({}/{} change pile, {} to close)\n",
+        key(InputAction::PreviousPile), key(InputAction::NextPile), key(InputAction::FinalDecks));
+    for p in DECK_PILES {
+        let marker = if p == pile { "▶" } else { " " };
+        text.push_str(&format!("\n{} {}: {}", marker, p.label(), p.count(decks)));
+    }
+    text.push_str(&format!("\n\n{}:", pile.label()));
+    let lines = pile_lines(decks, pile);
+    if lines.is_empty() {
+        text.push_str("\nEmpty");
+    }
+    for line in lines {
+        text.push_str("\n  ");
+        text.push_str(&line);
+    }
+    text
+}
+
+#[derive(Resource, Default)]
+pub struct FinalDecksView {
+    pub open: bool,
+    pub pile: DeckPile,
+}
+
+#[derive(Component)]
+pub struct FinalDecksPanel;
+
+// D (by default) on the game-over screen opens the piles; the decks are only read, never touched
+pub fn final_decks_system(
+    mut commands: Commands,
+    input: BoundInput,
+    mut view: ResMut<FinalDecksView>,
+    decks: Res<CardDecks>,
+    modal: Query<Entity, With<GameOverModal>>,
+    existing: Query<Entity, With<FinalDecksPanel>>,
+) {
+    let mut refresh = false;
+    if modal.is_empty() {
+        if view.open {
+            view.open = false;
+        }
+    } else if input.just_pressed(InputAction::FinalDecks) {
+        view.open = !view.open;
+        refresh = true;
+    }
+
+    if view.open && input.just_pressed(InputAction::NextPile) {
+        view.pile = view.pile.next();
+        refresh = true;
+    }
+    if view.open && input.just_pressed(InputAction::PreviousPile) {
+        view.pile = view.pile.previous();
+        refresh = true;
+    }
+    if !refresh && existing.is_empty() != view.open {
+        return;
+    }
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !view.open {
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                left: Val::Px(20.0),
+                width: Val::Px(320.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.12, 0.2, 0.95)).into(),
+            z_index: UiLayer::GameOver.z_index(),
+            ..default()
+        },
+        FinalDecksPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            final_decks_text(&decks, view.pile, &input.keymap),
+            TextStyle {
+                font_size: 12.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
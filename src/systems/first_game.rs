@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::expansions::ExpansionSettings;
+use crate::systems::keymap::{BoundInput, InputAction};
+use crate::systems::rng::GameRng;
+
+// Rules configuration chosen on the main menu. "First Game" is a gentler introduction:
+// no visitors, residual payments or grande workers, plain orders only, 15 VP over 5 years.
+// "Quick Game" keeps the full rules but plays to 15 VP over 5 years with smaller decks.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum RulesPreset {
+    #[default]
+    Full,
+    FirstGame,
+    QuickGame,
+}
+
+// Share of each deck a quick game is dealt from
+pub const QUICK_GAME_DECK_SHARE: f32 = 0.6;
+
+impl RulesPreset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RulesPreset::Full => "Full rules",
+            RulesPreset::FirstGame => "First Game",
+            RulesPreset::QuickGame => "Quick Game",
+        }
+    }
+
+    pub fn target_victory_points(&self) -> u8 {
+        match self {
+            RulesPreset::Full => 20,
+            RulesPreset::FirstGame | RulesPreset::QuickGame => 15,
+        }
+    }
+
+    pub fn max_years(&self) -> u8 {
+        match self {
+            RulesPreset::Full => 7,
+            RulesPreset::FirstGame | RulesPreset::QuickGame => 5,
+        }
+    }
+
+    pub fn is_first_game(&self) -> bool {
+        matches!(self, RulesPreset::FirstGame)
+    }
+
+    pub fn is_quick_game(&self) -> bool {
+        matches!(self, RulesPreset::QuickGame)
+    }
+
+    pub fn deck_share(&self) -> f32 {
+        if self.is_quick_game() { QUICK_GAME_DECK_SHARE } else { 1.0 }
+    }
+
+    pub fn visitors(&self) -> bool {
+        !self.is_first_game()
+    }
+
+    pub fn residuals(&self) -> bool {
+        !self.is_first_game()
+    }
+
+    pub fn grande_workers(&self) -> bool {
+        !self.is_first_game()
+    }
+
+    // The reduced deck keeps only regular orders that pay everything up front
+    pub fn allows_order(&self, order: &WineOrderCard) -> bool {
+        !self.is_first_game()
+            || (matches!(order.order_type, OrderType::Regular) && order.residual_payment == 0)
+    }
+
+    fn toggle(&mut self) {
+        *self = match self {
+            RulesPreset::Full => RulesPreset::FirstGame,
+            RulesPreset::FirstGame => RulesPreset::QuickGame,
+            RulesPreset::QuickGame => RulesPreset::Full,
+        };
+    }
+}
+
+#[derive(Component)]
+pub struct FirstGameMenuText;
+
+// F (by default) on the main menu cycles the full rules, the First Game and the Quick Game presets
+pub fn first_game_menu_system(
+    input: BoundInput,
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    mut preset: ResMut<RulesPreset>,
+    mut config: ResMut<GameConfig>,
+    mut expansions: ResMut<ExpansionSettings>,
+    existing: Query<Entity, With<FirstGameMenuText>>,
+) {
+    if !matches!(current_state.get(), GameState::MainMenu) {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let pressed = input.just_pressed(InputAction::RulesPreset);
+    if pressed {
+        preset.toggle();
+        config.target_victory_points = preset.target_victory_points();
+        config.max_years = preset.max_years();
+    }
+
+    // Visitors can't be switched back on underneath the preset
+    if !preset.visitors() && expansions.visitor_cards_enabled {
+        expansions.visitor_cards_enabled = false;
+    }
+
+    if !pressed && !existing.is_empty() {
+        return;
+    }
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let key = input.keymap.binding(InputAction::RulesPreset).map(|b| b.key_label()).unwrap_or_default();
+    let text = match *preset {
+        RulesPreset::FirstGame => format!(
+            "Rules: First Game ({} VP in {} years, no visitors, residuals or grande workers) - press {} to change",
+            preset.target_victory_points(), preset.max_years(), key,
+        ),
+        RulesPreset::QuickGame => format!(
+            "Rules: Quick Game ({} VP in {} years, smaller decks) - press {} to change",
+            preset.target_victory_points(), preset.max_years(), key,
+        ),
+        RulesPreset::Full => format!("Rules: Full - press {} for a simplified First Game or a Quick Game", key),
+    };
+
+    commands.spawn((
+        TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.7, 0.85, 1.0),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(470.0),
+            left: Val::Px(50.0),
+            ..default()
+        }),
+        FirstGameMenuText,
+    ));
+}
+
+// Builds the decks for the chosen preset: plain orders for a First Game, a cut-down share
+// of both decks for a Quick Game. Decks live for the whole session, so a full game after
+// either gets the complete decks back.
+pub fn prepare_order_deck_system(
+    preset: Res<RulesPreset>,
+    mut card_decks: ResMut<CardDecks>,
+    mut rng: ResMut<GameRng>,
+    mut reduced: Local<bool>,
+) {
+    if preset.is_first_game() || preset.is_quick_game() {
+        use rand::seq::SliceRandom;
+        let share = |len: usize| ((len as f32 * preset.deck_share()).round() as usize).max(1);
+
+        let CardDecks { mut vine_deck, wine_order_deck: mut orders, .. } = CardDecks::new();
+        orders.retain(|order| preset.allows_order(order));
+        orders.shuffle(&mut *rng);
+        orders.truncate(share(orders.len()));
+        vine_deck.shuffle(&mut *rng);
+        vine_deck.truncate(share(vine_deck.len()));
+
+        card_decks.wine_order_deck = orders;
+        card_decks.wine_order_discard.clear();
+        card_decks.vine_deck = vine_deck;
+        card_decks.vine_discard.clear();
+        *reduced = true;
+    } else if *reduced {
+        let fresh = CardDecks::new();
+        card_decks.wine_order_deck = fresh.wine_order_deck;
+        card_decks.wine_order_discard.clear();
+        card_decks.vine_deck = fresh.vine_deck;
+        card_decks.vine_discard.clear();
+        *reduced = false;
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::expansions::ExpansionSettings;
+use crate::systems::game_logic::ActionOutcome;
+use crate::systems::keymap::*;
+use crate::systems::settings::UserSettings;
+
+#[derive(Clone, Debug)]
+pub struct GameLogEntry {
+    pub year: u8,
+    pub season: GameState,
+    pub player: PlayerId,
+    pub action: ActionSpace,
+    pub outcome: ActionOutcome,
+}
+
+// Every resolved action of the current game, recorded by action_resolution_system
+#[derive(Resource, Default)]
+pub struct GameLog {
+    pub entries: Vec<GameLogEntry>,
+    pub seed: Option<u64>,
+}
+
+impl GameLog {
+    pub fn record(&mut self, year: u8, season: GameState, player: PlayerId, action: ActionSpace, outcome: ActionOutcome) {
+        self.entries.push(GameLogEntry { year, season, player, action, outcome });
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.seed = None;
+    }
+
+    pub fn to_markdown(
+        &self,
+        config: &GameConfig,
+        expansions: &ExpansionSettings,
+        settings: &UserSettings,
+        players: &[Player],
+        vineyards: &[Vineyard],
+    ) -> String {
+        let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+        let player_name = |id: PlayerId| players.iter()
+            .find(|p| p.id == id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| format!("Player {}", id.0 + 1));
+
+        let mut md = String::from("# Viticulture Game Log\n\n## Setup\n\n");
+        md.push_str(&format!("- Seed: {}\n", self.seed.map(|s| format!("{:#x}", s)).unwrap_or_else(|| "not recorded".to_string())));
+        md.push_str(&format!("- Players: {} ({} AI, difficulty {:?})\n", config.player_count, config.ai_count, settings.table_difficulty()));
+        md.push_str(&format!("- Target: {} VP within {} years\n", config.target_victory_points, config.max_years));
+        md.push_str(&format!("- Tuscany: {}, visitor cards: {}, advanced boards: {}\n",
+            on_off(expansions.tuscany_enabled), on_off(expansions.visitor_cards_enabled), on_off(expansions.advanced_boards_enabled)));
+        md.push_str(&format!("- House rules: bonus chits {}, order swap {}\n",
+            on_off(settings.house_rule_bonus_chits), on_off(settings.house_rule_order_swap)));
+
+        md.push_str("\n## Players\n\n| Player | VP | Lira | Wine (R/W) | Actions | Orders filled |\n|---|---|---|---|---|---|\n");
+        for player in players {
+            let actions: Vec<_> = self.entries.iter().filter(|e| e.player == player.id).collect();
+            let orders = actions.iter()
+                .filter(|e| matches!(e.outcome, ActionOutcome::FilledOrder { .. }))
+                .count();
+            let wine = vineyards.iter()
+                .find(|v| v.owner == player.id)
+                .map(|v| format!("{:?}/{:?}", v.red_wine.values(), v.white_wine.values()))
+                .unwrap_or_else(|| "-".to_string());
+            md.push_str(&format!("| {} | {} | {} | {} | {} | {} |\n",
+                player.name, player.victory_points, player.lira, wine, actions.len(), orders));
+        }
+
+        let mut current: Option<(u8, GameState)> = None;
+        for entry in &self.entries {
+            if current.as_ref().map(|(y, _)| *y) != Some(entry.year) {
+                md.push_str(&format!("\n## Year {}\n", entry.year));
+            }
+            if current.as_ref() != Some(&(entry.year, entry.season.clone())) {
+                md.push_str(&format!("\n### {:?}\n\n", entry.season));
+                current = Some((entry.year, entry.season.clone()));
+            }
+            md.push_str(&format!("- **{}**: {:?} — {}\n", player_name(entry.player), entry.action, entry.outcome.summary()));
+        }
+
+        if self.entries.is_empty() {
+            md.push_str("\n_No actions recorded._\n");
+        }
+
+        md
+    }
+}
+
+#[derive(Component)]
+pub struct ExportLogButton;
+
+pub fn reset_game_log_system(mut log: ResMut<GameLog>) {
+    if !log.entries.is_empty() {
+        log.clear();
+    }
+}
+
+// Ctrl+E or the game-over button writes the log to a timestamped markdown file
+pub fn export_game_log_system(
+    input: BoundInput,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ExportLogButton>)>,
+    log: Res<GameLog>,
+    config: Res<GameConfig>,
+    expansions: Res<ExpansionSettings>,
+    settings: Res<UserSettings>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+) {
+    let clicked = buttons.iter().any(|i| *i == Interaction::Pressed);
+    if !clicked && !input.just_pressed(InputAction::ExportLog) {
+        return;
+    }
+
+    let mut players: Vec<Player> = players.iter().cloned().collect();
+    players.sort_by_key(|p| p.id.0);
+    let vineyards: Vec<Vineyard> = vineyards.iter().cloned().collect();
+
+    let markdown = log.to_markdown(&config, &expansions, &settings, &players, &vineyards);
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let path = format!("viticulture_log_{}.md", timestamp);
+
+    match std::fs::write(&path, markdown) {
+        Ok(()) => info!("📜 Game log exported to {} ({} actions)", path, log.entries.len()),
+        Err(e) => warn!("Failed to export game log: {}", e),
+    }
+}
@@ -13,7 +13,7 @@ const BLUE: Srgba = Srgba::new(0.5, 0.8, 1.0, 1.0);
 const WHITE: Color = Color::WHITE;
 
 // Game text constants
-const SPRING_TEXT: &str = "SPRING PHASE - YEAR {}\nChoose wake-up times (1-7)\nPress SPACE to auto-assign and continue";
+const SPRING_TEXT: &str = "SPRING PHASE - YEAR {}\nChoose wake-up times (1-7) on the track\nPress SPACE to auto-assign the rest and continue";
 const FALL_TEXT: &str = "FALL PHASE\nAutomatic harvest from planted vines\n\nPress SPACE to continue to Winter";
 const FALL_VISITOR_TEXT: &str = "FALL PHASE\nEach player draws a visitor card\nPress SPACE to continue to Winter";
 const GAME_OVER_TEXT: &str = "GAME OVER!\n{} WINS with {} Victory Points!\n\nPress SPACE to play again";
@@ -42,20 +42,10 @@ const FIELD_SPACING_Y: f32 = 40.0;
 const FIELDS_PER_ROW: usize = 3;
 
 // Action rewards
-const TOUR_LIRA_REWARD: u8 = 2;
+pub const TOUR_LIRA_REWARD: u8 = 2;
+// Extra lira for the first worker on Give Tour
+const TOUR_BONUS_LIRA: u8 = 1;
 const WORKER_TRAIN_COST: u8 = 4;
-const HAND_LIMIT: usize = 7;
-
-// Wake-up bonuses mapping
-const WAKE_UP_BONUSES: [Option<WakeUpBonus>; 7] = [
-    Some(WakeUpBonus::DrawVineCard),        // Position 1
-    Some(WakeUpBonus::GainLira(1)),         // Position 2  
-    None,                                   // Position 3
-    Some(WakeUpBonus::GainLira(1)),         // Position 4
-    Some(WakeUpBonus::DrawWineOrderCard),   // Position 5
-    Some(WakeUpBonus::GainVictoryPoint),    // Position 6
-    None,                                   // Position 7 (gets temporary worker)
-];
 
 // === HELPER FUNCTIONS ===
 
@@ -88,7 +78,7 @@ fn spawn_phase_text(commands: &mut Commands, text: &str) {
             color: WHITE,
             ..default()
         }),
-        transform: Transform::from_translation(Vec3::new(0.0, 200.0, 1000.0)),
+        transform: Transform::from_translation(Vec3::new(0.0, 200.0, WorldLayer::PhaseText.z())),
         ..default()
     });
 }
@@ -118,20 +108,17 @@ fn reset_action_spaces(action_spaces: &mut Query<&mut ActionSpaceSlot>) {
     }
 }
 
-/// Apply wake-up bonus efficiently using lookup table
+/// Apply wake-up bonus from the active chart
 fn apply_wake_up_bonus_optimized(
     player_id: PlayerId,
     position: usize,
+    chart: &WakeUpChart,
     hands: &mut Query<&mut Hand>,
     players: &mut Query<&mut Player>,
     card_decks: &mut ResMut<CardDecks>,
     commands: &mut Commands,
 ) {
-    if position >= WAKE_UP_BONUSES.len() {
-        return;
-    }
-
-    if let Some(bonus) = WAKE_UP_BONUSES[position] {
+    if let Some(bonus) = chart.bonus_at(position) {
         apply_wake_up_bonus(player_id, bonus, hands, players, card_decks, commands);
     }
 }
@@ -157,13 +144,13 @@ fn spawn_particles_optimized(
     
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_translation(position.extend(3.0)),
+            transform: Transform::from_translation(position.extend(WorldLayer::Particles.z())),
             ..default()
         },
         ParticleEffect {
             particles,
             effect_type: particle_type,
-            timer: Timer::from_seconds(duration, TimerMode::Once),
+            timer: GameClock::once_secs(duration),
         },
     ));
 }
@@ -171,7 +158,7 @@ fn spawn_particles_optimized(
 /// Generic particle creation function
 fn create_particles_by_type(center: Vec2, count: usize, color: Color) -> Vec<Particle> {
     use rand::Rng;
-    let mut rng = rand::rng();
+    let mut rng = effect_rng(center, count);
     
     (0..count)
         .map(|_| {
@@ -240,54 +227,154 @@ fn trigger_season_transition(
 
 // === MAIN SYSTEMS ===
 
-pub fn spring_system(
+// The table is done with the Spring or Fall screen: Spring hands out whatever is left of the
+// wake-up draft, Fall harvests and moves on to Winter. The UI sends it on SPACE; an embedder
+// running the rules without it sends its own.
+#[derive(Event, Clone, Copy, Debug, Default)]
+pub struct PhaseContinue;
+
+pub fn phase_continue_input_system(
     keyboard: Res<ButtonInput<KeyCode>>,
+    mut continues: EventWriter<PhaseContinue>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        continues.send(PhaseContinue);
+    }
+}
+
+// Board panels and the Spring and Fall instructions, shown while no other text is up
+pub fn phase_text_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    text_query: Query<Entity, (With<Text>, Without<UIPanel>)>,
+    ui_query: Query<Entity, With<UIPanel>>,
+) {
+    match current_state.get() {
+        GameState::Spring => {
+            if ui_query.is_empty() {
+                crate::systems::ui::setup_ui(&mut commands);
+            }
+            if text_query.is_empty() {
+                let text = SPRING_TEXT.replace("{}", &config.current_year.to_string());
+                spawn_phase_text(&mut commands, &text);
+            }
+        }
+        GameState::Fall => {
+            if text_query.is_empty() {
+                spawn_phase_text(&mut commands, FALL_TEXT);
+                spawn_phase_text(&mut commands, FALL_VISITOR_TEXT);
+            }
+        }
+        _ => {}
+    }
+}
+
+// Leaving Spring or Fall takes its instructions down
+pub fn clear_phase_text_system(mut commands: Commands, text_query: Query<Entity, (With<Text>, Without<UIPanel>)>) {
+    cleanup_phase_text(&mut commands, &text_query);
+}
+
+pub fn spring_system(
+    mut continues: EventReader<PhaseContinue>,
     mut next_state: ResMut<NextState<GameState>>,
     mut turn_order: ResMut<TurnOrder>,
     mut workers: Query<&mut Worker>,
     mut action_spaces: Query<&mut ActionSpaceSlot>,
     config: ResMut<GameConfig>,
     mut commands: Commands,
-    text_query: Query<Entity, (With<Text>, Without<UIPanel>)>,
-    ui_query: Query<Entity, With<UIPanel>>,
     mut hands: Query<&mut Hand>,
     mut players: Query<&mut Player>,
     mut card_decks: ResMut<CardDecks>,
-    animation_settings: Res<AnimationSettings>,
+    animation_settings: Option<Res<AnimationSettings>>,
+    (wake_up_charts, mut draft, discards, settings): (Res<WakeUpCharts>, ResMut<WakeUpDraft>, Res<HandLimitDiscard>, Res<UserSettings>),
 ) {
-    // Setup UI if not present
-    if ui_query.is_empty() {
-        crate::systems::ui::setup_ui(&mut commands);
+    let continued = continues.read().count() > 0;
+
+    // Wake-up draft, furthest behind picks first; the track UI records human picks
+    if draft.year != Some(config.current_year) {
+        let standings: Vec<_> = turn_order.players.iter()
+            .filter_map(|id| players.iter().find(|p| p.id == *id).map(|p| (*id, p.victory_points.get())))
+            .collect();
+        draft.start(config.current_year, &standings);
     }
-    
-    // Display spring phase text
-    if text_query.is_empty() {
-        let text = SPRING_TEXT.replace("{}", &config.current_year.to_string());
-        spawn_phase_text(&mut commands, &text);
+
+    if settings.house_rule_simultaneous_wake_up {
+        // Secret rankings: AIs hand theirs in at once, humans through the ranking panel,
+        // and continuing ranks the rows top to bottom for anyone still deciding
+        let ai_rankers: Vec<PlayerId> = draft.pickers.iter().copied()
+            .filter(|id| !draft.has_ranked(*id) && players.iter().any(|p| p.id == *id && p.is_ai))
+            .collect();
+        for ai_id in ai_rankers {
+            draft.submit_ranking(ai_id, WakeUpDraft::ai_ranking(wake_up_charts.active()));
+        }
+        if continued {
+            while let Some(player) = draft.next_to_rank() {
+                draft.submit_ranking(player, (1..=WAKE_UP_ROWS).collect());
+            }
+        }
+        draft.resolve_rankings();
+    } else {
+        let ai_picking = draft.current_picker()
+            .filter(|id| players.iter().any(|p| p.id == *id && p.is_ai));
+        if let Some(ai_id) = ai_picking {
+            if let Some(row) = draft.ai_row(wake_up_charts.active()) {
+                draft.pick(ai_id, row);
+            }
+        }
+
+        if continued {
+            draft.auto_assign_rest();
+        }
     }
-    
-    if keyboard.just_pressed(KeyCode::Space) {
-        cleanup_phase_text(&mut commands, &text_query);
-        
+
+    // Blind bidding for the temporary worker opens once the rows are settled; AIs bid at
+    // once, humans through the bid panel, and continuing sits out anyone still deciding
+    if settings.house_rule_temp_worker_bidding && draft.is_complete() {
+        let ai_bidders: Vec<(PlayerId, u8)> = players.iter()
+            .filter(|p| p.is_ai && draft.pickers.contains(&p.id) && !draft.has_bid(p.id))
+            .map(|p| (p.id, WakeUpDraft::ai_bid(p.lira.get())))
+            .collect();
+        for (ai_id, bid) in ai_bidders {
+            draft.submit_bid(ai_id, bid);
+        }
+        if continued {
+            while let Some(player) = draft.next_to_bid() {
+                draft.submit_bid(player, 0);
+            }
+        }
+        if draft.resolve_bids() {
+            // The winning bid goes to the bank
+            if let Some((winner, price)) = draft.temp_worker {
+                if let Some(mut player) = players.iter_mut().find(|p| p.id == winner) {
+                    player.lira.spend(price.min(player.lira.get()));
+                }
+                info!("⏳ Player {} hires the temporary worker for {} lira", winner.0 + 1, price);
+            }
+            spawn_bid_reveal(&mut commands, &draft);
+        }
+    }
+    let bids_settled = !settings.house_rule_temp_worker_bidding || draft.bids_revealed;
+
+    // Summer waits for any hand-limit discards
+    if draft.is_complete() && bids_settled && discards.pending.is_empty() {
         // Reset game state efficiently
         reset_workers_to_start(&mut workers);
         reset_action_spaces(&mut action_spaces);
         
-        // Assign wake-up order
-        let wake_up_assignments: Vec<_> = turn_order.players.iter()
-            .enumerate()
-            .map(|(i, &player_id)| (player_id, (i + 1) as u8))
-            .collect();
-        turn_order.set_wake_up_order(wake_up_assignments);
+        // Turn order follows the chosen rows
+        turn_order.set_wake_up_order(draft.picks.clone());
         
-        // Apply wake-up bonuses efficiently
-        for (i, &(player_id, _)) in turn_order.wake_up_order.iter().enumerate() {
-            apply_wake_up_bonus_optimized(player_id, i, &mut hands, &mut players, &mut card_decks, &mut commands);
+        // Each row pays its own bonus, whether or not the rows above it were taken
+        for &(player_id, row) in turn_order.wake_up_order.iter() {
+            apply_wake_up_bonus_optimized(player_id, row.saturating_sub(1) as usize, wake_up_charts.active(), &mut hands, &mut players, &mut card_decks, &mut commands);
         }
         
-        turn_order.current_player = 0;
+        turn_order.start_season();
         
-        trigger_season_transition(&mut commands, GameState::Spring, GameState::Summer, &animation_settings);
+        if let Some(animation_settings) = &animation_settings {
+            trigger_season_transition(&mut commands, GameState::Spring, GameState::Summer, animation_settings);
+        }
         next_state.set(GameState::Summer);
     }
 }
@@ -334,224 +421,418 @@ fn apply_wake_up_bonus(
     }
 }
 
-/// Optimized action execution with proper types
-pub fn execute_action(
+/// Result of resolving an action against the game rules, used to drive effects
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ActionOutcome {
+    Nothing,
+    DrewVine,
+    DrewOrder,
+    Planted { field: usize },
+    Harvested { grapes: u8 },
+    // value is where the new wine token landed in the cellar
+    MadeWine { wine_type: WineType, value: u8 },
+    FilledOrder { victory_points: u8, lira: u8, seasonal_bonus: u8 },
+    GainedLira { amount: u8 },
+    TrainedWorker,
+    Built,
+}
+
+impl ActionOutcome {
+    pub fn summary(&self) -> String {
+        match self {
+            ActionOutcome::Nothing => "No effect".to_string(),
+            ActionOutcome::DrewVine => "+1 vine card".to_string(),
+            ActionOutcome::DrewOrder => "+1 wine order".to_string(),
+            ActionOutcome::Planted { field } => format!("Plant in field {}", field + 1),
+            ActionOutcome::Harvested { grapes } => format!("+{} grape tokens", grapes),
+            ActionOutcome::MadeWine { wine_type, value } => format!("+{:?} wine ({})", wine_type, value),
+            ActionOutcome::FilledOrder { victory_points, lira, .. } => format!("+{} VP, +{} lira", victory_points, lira),
+            ActionOutcome::GainedLira { amount } => format!("+{} lira", amount),
+            ActionOutcome::TrainedWorker => format!("+1 worker (-{} lira)", WORKER_TRAIN_COST),
+            ActionOutcome::Built => "New structure".to_string(),
+        }
+    }
+}
+
+/// Resolves an action against copies of the player's state, leaving the live game untouched
+pub fn preview_action(
     action: ActionSpace,
-    player_id: PlayerId,
-    hands: &mut Query<&mut Hand>,
-    vineyards: &mut Query<&mut Vineyard>,
-    players: &mut Query<&mut Player>,
-    card_decks: &mut ResMut<CardDecks>,
-    commands: &mut Commands,
-    trackers: &mut Query<&mut ResidualPaymentTracker>,
-    structures: &Query<&Structure>, 
-    audio_assets: &Res<AudioAssets>,
-    audio_settings: &Res<AudioSettings>,
-    animation_settings: &Res<AnimationSettings>,
-) {
-    // Pre-calculate commonly used values
-    let player_pos = player_position_offset(player_id);
-    let player_structures: Vec<_> = structures.iter()
-        .filter(|s| s.owner == player_id)
-        .cloned()
-        .collect();
-
-    // Get mutable references once
-    let mut hand = hands.iter_mut().find(|h| h.owner == player_id);
-    let mut vineyard = vineyards.iter_mut().find(|v| v.owner == player_id);
-    let mut player = players.iter_mut().find(|p| p.id == player_id);
+    current_year: u8,
+    hand: Option<&Hand>,
+    vineyard: Option<&Vineyard>,
+    player: Option<&Player>,
+    tracker: Option<&ResidualPaymentTracker>,
+    card_decks: &CardDecks,
+    player_structures: &[Structure],
+    bonus: bool,
+) -> ActionOutcome {
+    let mut hand = hand.cloned();
+    let mut vineyard = vineyard.cloned();
+    let mut player = player.cloned();
+    let mut tracker = tracker.cloned();
+    let mut card_decks = card_decks.clone();
+
+    resolve_action(
+        action,
+        current_year,
+        hand.as_mut(),
+        vineyard.as_mut(),
+        player.as_mut(),
+        tracker.as_mut(),
+        &mut card_decks,
+        player_structures,
+        bonus,
+    )
+}
+
+/// Pure rules resolution shared by the live game and headless simulations
+// The recipe Make Wine picks on its own: sparkling, then blush, then a single wine
+pub fn auto_wine_recipe(vineyard: &Vineyard, structures: &[Structure]) -> Option<WineType> {
+    let (red, white) = (vineyard.red_grapes.count(), vineyard.white_grapes.count());
+    let capacity = vineyard.cellar_capacity(structures);
+    if red >= 2 && white >= 1 && capacity >= 9 {
+        Some(WineType::Sparkling)
+    } else if red >= 1 && white >= 1 && capacity >= 6 {
+        Some(WineType::Blush)
+    } else if red > 0 {
+        Some(WineType::Red)
+    } else if white > 0 {
+        Some(WineType::White)
+    } else {
+        None
+    }
+}
+
+// Blush needs the medium cellar and sparkling the large one
+pub fn can_make_recipe(vineyard: &Vineyard, recipe: WineType, structures: &[Structure]) -> bool {
+    let (red, white) = (vineyard.red_grapes.count(), vineyard.white_grapes.count());
+    let capacity = vineyard.cellar_capacity(structures);
+    match recipe {
+        WineType::Red => red >= 1,
+        WineType::White => white >= 1,
+        WineType::Blush => red >= 1 && white >= 1 && capacity >= 6,
+        WineType::Sparkling => red >= 2 && white >= 1 && capacity >= 9,
+    }
+}
+
+// Wines are worth the sum of the grapes that went in, capped by the owned cellars, and tagged
+// with the year they were made
+fn make_wine_recipe(vineyard: &mut Vineyard, recipe: WineType, structures: &[Structure], year: u8) -> ActionOutcome {
+    let capacity = vineyard.cellar_capacity(structures);
+    let value = match recipe {
+        WineType::Sparkling => {
+            let grapes = [vineyard.red_grapes.take_highest(), vineyard.red_grapes.take_highest(), vineyard.white_grapes.take_highest()];
+            let value = grapes.iter().flatten().fold(0u8, |sum, v| sum.saturating_add(*v));
+            vineyard.red_wine.place(value.min(capacity))
+        }
+        WineType::Blush => {
+            let grapes = [vineyard.red_grapes.take_highest(), vineyard.white_grapes.take_highest()];
+            let value = grapes.iter().flatten().fold(0u8, |sum, v| sum.saturating_add(*v));
+            vineyard.white_wine.place(value.min(capacity))
+        }
+        WineType::Red | WineType::White => vineyard.make_wine(recipe, capacity),
+    };
+    
+    match value {
+        Some(value) => {
+            vineyard.record_vintage(recipe, value, year);
+            ActionOutcome::MadeWine { wine_type: recipe, value }
+        }
+        None => ActionOutcome::Nothing,
+    }
+}
 
+// Plants the first vine card in hand into the first field that takes it
+fn plant_first_vine(hand: &mut Hand, vineyard: &mut Vineyard, player_structures: &[Structure]) -> Option<usize> {
+    let card = hand.vine_cards.first()?;
+    let field = (0..9).find(|i| vineyard.can_plant_vine_with_requirements(*i, card, player_structures))?;
+    let vine_card = hand.vine_cards.remove(0);
+    vineyard.fields[field].vine = Some(vine_card.vine_type);
+    vineyard.lira -= vine_card.cost;
+    Some(field)
+}
+
+// `bonus` is set for the first worker on a bonus space: plant a second vine, +1 lira on a
+// tour, harvest an extra field, or make a second wine
+pub fn resolve_action(
+    action: ActionSpace,
+    current_year: u8,
+    hand: Option<&mut Hand>,
+    vineyard: Option<&mut Vineyard>,
+    player: Option<&mut Player>,
+    tracker: Option<&mut ResidualPaymentTracker>,
+    card_decks: &mut CardDecks,
+    player_structures: &[Structure],
+    bonus: bool,
+) -> ActionOutcome {
     match action {
         ActionSpace::DrawVine => {
-            if let (Some(hand), Some(card)) = (hand.as_mut(), card_decks.draw_vine_card()) {
+            if let (Some(hand), Some(card)) = (hand, card_decks.draw_vine_card()) {
                 hand.vine_cards.push(card);
-                let target_pos = Vec2::new(player_pos.x - 100.0, -200.0);
-                animate_card_draw(commands, CardType::Vine, target_pos, animation_settings);
-                crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::CardDraw);
-                spawn_animated_text(commands, player_id, "+Vine", Color::from(GREEN));
+                return ActionOutcome::DrewVine;
             }
         }
         ActionSpace::DrawWineOrder => {
-            if let (Some(hand), Some(card)) = (hand.as_mut(), card_decks.draw_wine_order_card()) {
+            if let (Some(hand), Some(card)) = (hand, card_decks.draw_wine_order_card()) {
                 hand.wine_order_cards.push(card);
-                let target_pos = Vec2::new(player_pos.x + 100.0, -200.0);
-                animate_card_draw(commands, CardType::WineOrder, target_pos, animation_settings);
-                crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::CardDraw);
-                spawn_animated_text(commands, player_id, "+Order", Color::from(PURPLE));
+                return ActionOutcome::DrewOrder;
             }
         }
         ActionSpace::PlantVine => {
-            if let (Some(hand), Some(vineyard)) = (hand.as_mut(), vineyard.as_mut()) {
-                if !hand.vine_cards.is_empty() {
-                    let vine_card = &hand.vine_cards[0];
-                    
-                    for i in 0..9 {
-                        if vineyard.can_plant_vine_with_requirements(i, vine_card, &player_structures) {
-                            let vine_card = hand.vine_cards.remove(0);
-                            vineyard.fields[i].vine = Some(vine_card.vine_type);
-                            vineyard.lira -= vine_card.cost;
-                            
-                            let field_pos = calculate_field_position(player_id, i);
-                            spawn_construction_particles(commands, field_pos, animation_settings);
-                            spawn_animated_text(commands, player_id, "Planted!", Color::from(Srgba::new(0.4, 0.8, 0.4, 1.0)));
-                            break;
-                        }
+            if let (Some(hand), Some(vineyard)) = (hand, vineyard) {
+                if let Some(field) = plant_first_vine(hand, vineyard, player_structures) {
+                    if bonus {
+                        plant_first_vine(hand, vineyard, player_structures);
                     }
+                    return ActionOutcome::Planted { field };
                 }
             }
         }
         ActionSpace::Harvest => {
-            if let Some(vineyard) = vineyard.as_mut() {
+            if let Some(vineyard) = vineyard {
                 let structures = Vec::new();
-                let gained = vineyard.harvest_grapes(&structures);
+                let limit = harvest_limit(bonus);
+                // Fields chosen in the harvest picker win; otherwise the best ones are taken
+                let mut chosen = std::mem::take(&mut vineyard.harvest_selection);
+                let gained = if chosen.is_empty() {
+                    vineyard.harvest_fields(&structures, limit)
+                } else {
+                    chosen.truncate(limit);
+                    vineyard.harvest_selected(&structures, &chosen)
+                };
                 if gained > 0 {
-                    spawn_harvest_particles(commands, player_pos, gained, animation_settings);
-                    crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::Harvest);
-                    spawn_animated_text(commands, player_id, &format!("+{} Grapes", gained), Color::from(Srgba::new(0.8, 0.4, 0.8, 1.0)));
+                    return ActionOutcome::Harvested { grapes: gained };
                 }
             }
         }
         ActionSpace::MakeWine => {
-            if let Some(vineyard) = vineyard.as_mut() {
-                let red_available = vineyard.red_grapes;
-                let white_available = vineyard.white_grapes;
-                
-                if red_available >= 2 && white_available >= 2 {
-                    vineyard.red_grapes -= 1;
-                    vineyard.white_grapes -= 1;
-                    vineyard.red_wine += 2;
-                    
-                    spawn_wine_pouring_effect(commands, player_pos, animation_settings);
-                    crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::WineMake);
-                    spawn_animated_text(commands, player_id, "+Sparkling Wine", Color::from(Srgba::new(0.9, 0.7, 0.2, 1.0)));
-                } else if red_available >= 1 && white_available >= 1 {
-                    vineyard.red_grapes -= 1;
-                    vineyard.white_grapes -= 1;
-                    vineyard.white_wine += 1;
-                    
-                    spawn_wine_pouring_effect(commands, player_pos, animation_settings);
-                    crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::WineMake);
-                    spawn_animated_text(commands, player_id, "+Blush Wine", Color::from(Srgba::new(0.9, 0.5, 0.6, 1.0)));
-                } else {
-                    let red_to_use = if red_available > 0 { 1 } else { 0 };
-                    let white_to_use = if white_available > 0 { 1 } else { 0 };
-                    
-                    if vineyard.make_wine(red_to_use, white_to_use) {
-                        let total_wine = red_to_use + white_to_use;
-                        if total_wine > 0 {
-                            spawn_wine_pouring_effect(commands, player_pos, animation_settings);
-                            crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::WineMake);
-                            spawn_animated_text(commands, player_id, &format!("+{} Wine", total_wine), Color::from(Srgba::new(0.7, 0.2, 0.2, 1.0)));
+            if let Some(vineyard) = vineyard {
+                // A recipe chosen in the make-wine dialog wins if the grapes still allow it
+                let recipe = vineyard.wine_recipe.take()
+                    .filter(|recipe| can_make_recipe(vineyard, *recipe, player_structures))
+                    .or_else(|| auto_wine_recipe(vineyard, player_structures));
+                if let Some(recipe) = recipe {
+                    let outcome = make_wine_recipe(vineyard, recipe, player_structures, current_year);
+                    if bonus {
+                        if let Some(second) = auto_wine_recipe(vineyard, player_structures) {
+                            make_wine_recipe(vineyard, second, player_structures, current_year);
                         }
                     }
+                    return outcome;
                 }
             }
         }
         ActionSpace::FillOrder => {
-            if let (Some(hand), Some(vineyard), Some(player)) = (hand.as_mut(), vineyard.as_mut(), player.as_mut()) {
-                if !hand.wine_order_cards.is_empty() {
-                    let order = &hand.wine_order_cards[0];
-                    if vineyard.can_fulfill_order(order) {
-                        let order = hand.wine_order_cards.remove(0);
-                        vineyard.red_wine -= order.red_wine_needed;
-                        vineyard.white_wine -= order.white_wine_needed;
-                        
-                        player.gain_victory_points(order.victory_points);
-                        player.gain_lira(order.immediate_payout());
-                        
-                        if let Some(mut tracker) = trackers.iter_mut().find(|t| t.owner == player_id) {
-                            tracker.advance(order.residual_payment());
-                        }
-                        
-                        spawn_victory_point_particles(commands, player_pos, order.victory_points, animation_settings);
-                        if order.immediate_payout() > 0 {
-                            spawn_lira_particles(commands, player_pos + Vec2::new(50.0, 0.0), order.immediate_payout(), animation_settings);
-                        }
-                        
-                        crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::VictoryPoint);
-                        spawn_animated_text(commands, player_id, &format!("+{} VP", order.victory_points), Color::from(YELLOW));
+            if let (Some(hand), Some(vineyard), Some(player)) = (hand, vineyard, player) {
+                if !hand.wine_order_cards.is_empty() && vineyard.can_fulfill_order(&hand.wine_order_cards[0]) {
+                    let order = hand.wine_order_cards.remove(0);
+                    vineyard.take_order_wines(&order);
+                    
+                    let seasonal_bonus = order.seasonal_bonus(current_year);
+                    player.gain_victory_points(order.victory_points);
+                    player.gain_lira(order.immediate_payout() + seasonal_bonus);
+                    
+                    if let Some(tracker) = tracker {
+                        tracker.advance(order.residual_steps());
                     }
+                    
+                    return ActionOutcome::FilledOrder {
+                        victory_points: order.victory_points,
+                        lira: order.immediate_payout() + seasonal_bonus,
+                        seasonal_bonus,
+                    };
                 }
             }
         }
         ActionSpace::GiveTour => {
-            if let Some(player) = player.as_mut() {
-                player.gain_lira(TOUR_LIRA_REWARD);
-                spawn_lira_particles(commands, player_pos, TOUR_LIRA_REWARD, animation_settings);
-                crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::LiraGain);
-                spawn_animated_text(commands, player_id, &format!("+{} Lira", TOUR_LIRA_REWARD), Color::from(GOLD));
+            if let Some(player) = player {
+                let amount = TOUR_LIRA_REWARD + if bonus { TOUR_BONUS_LIRA } else { 0 };
+                player.gain_lira(amount);
+                return ActionOutcome::GainedLira { amount };
             }
         }
         ActionSpace::SellGrapes => {
-            if let (Some(vineyard), Some(player)) = (vineyard.as_mut(), player.as_mut()) {
-                let grapes_sold = vineyard.red_grapes + vineyard.white_grapes;
-                if grapes_sold > 0 {
-                    player.gain_lira(grapes_sold);
-                    vineyard.red_grapes = 0;
-                    vineyard.white_grapes = 0;
-                    
-                    spawn_lira_particles(commands, player_pos, grapes_sold, animation_settings);
-                    crate::systems::audio::play_sfx(commands, audio_assets, audio_settings, AudioType::LiraGain);
-                    spawn_animated_text(commands, player_id, &format!("+{} Lira", grapes_sold), Color::from(GOLD));
+            if let (Some(vineyard), Some(player)) = (vineyard, player) {
+                let mut sold = vineyard.red_grapes.clear();
+                sold.extend(vineyard.white_grapes.clear());
+                if !sold.is_empty() {
+                    let amount = sold.iter().map(|v| grape_sale_price(*v)).sum();
+                    player.gain_lira(amount);
+                    return ActionOutcome::GainedLira { amount };
                 }
             }
         }
         ActionSpace::TrainWorker => {
-            if let Some(player) = player.as_mut() {
-                if player.lira >= WORKER_TRAIN_COST {
-                    player.lira -= WORKER_TRAIN_COST;
-                    player.workers += 1;
-                    
-                    spawn_construction_particles(commands, player_pos, animation_settings);
-                    spawn_animated_text(commands, player_id, "+Worker", Color::from(BLUE));
+            if let Some(player) = player {
+                if player.workers < MAX_WORKERS && player.lira.spend(WORKER_TRAIN_COST) {
+                    player.gain_worker();
+                    return ActionOutcome::TrainedWorker;
                 }
             }
         }
         ActionSpace::BuildStructure => {
-            if let Some(vineyard) = vineyard.as_mut() {
-                if vineyard.can_build_structure(StructureType::Trellis) {
-                    if vineyard.build_structure(StructureType::Trellis) {
-                        spawn_construction_particles(commands, player_pos, animation_settings);
-                        spawn_animated_text(commands, player_id, "+Structure", Color::from(Srgba::new(0.8, 0.8, 0.2, 1.0)));
-                    }
+            if let Some(vineyard) = vineyard {
+                if vineyard.can_build_structure(StructureType::Trellis)
+                    && vineyard.build_structure(StructureType::Trellis) {
+                    return ActionOutcome::Built;
+                }
+            }
+        }
+    }
+    
+    ActionOutcome::Nothing
+}
+
+// Worker movement, particles and floating text for every resolved action; the matching
+// sounds are played by action_audio_cue_system
+pub fn action_feedback_system(
+    mut resolved_events: EventReader<ActionResolved>,
+    mut commands: Commands,
+    workers: Query<&Worker>,
+    animation_settings: Res<AnimationSettings>,
+) {
+    for event in resolved_events.read() {
+        let player_id = event.player;
+        let commands = &mut commands;
+        let animation_settings = &animation_settings;
+        if let Ok(worker) = workers.get(event.worker) {
+            animate_worker_placement(commands, event.worker, event.from, worker.position, WorkerAnimationType::Placement, animation_settings);
+        }
+        let player_pos = player_position_offset(player_id);
+
+        match event.outcome {
+            ActionOutcome::Nothing => {}
+            ActionOutcome::DrewVine => {
+                let target_pos = Vec2::new(player_pos.x - 100.0, -200.0);
+                animate_card_draw(commands, CardType::Vine, target_pos, animation_settings);
+                spawn_animated_text(commands, player_id, "+Vine", Color::from(GREEN));
+            }
+            ActionOutcome::DrewOrder => {
+                let target_pos = Vec2::new(player_pos.x + 100.0, -200.0);
+                animate_card_draw(commands, CardType::WineOrder, target_pos, animation_settings);
+                spawn_animated_text(commands, player_id, "+Order", Color::from(PURPLE));
+            }
+            ActionOutcome::Planted { field } => {
+                let field_pos = calculate_field_position(player_id, field);
+                spawn_construction_particles(commands, field_pos, animation_settings);
+                spawn_animated_text(commands, player_id, "Planted!", Color::from(Srgba::new(0.4, 0.8, 0.4, 1.0)));
+            }
+            ActionOutcome::Harvested { grapes } => {
+                spawn_harvest_particles(commands, player_pos, grapes, animation_settings);
+                spawn_animated_text(commands, player_id, &format!("+{} Grapes", grapes), Color::from(Srgba::new(0.8, 0.4, 0.8, 1.0)));
+            }
+            ActionOutcome::MadeWine { wine_type, value } => {
+                spawn_wine_pouring_effect(commands, player_pos, animation_settings);
+                match wine_type {
+                    WineType::Sparkling => spawn_animated_text(commands, player_id, "+Sparkling Wine", Color::from(Srgba::new(0.9, 0.7, 0.2, 1.0))),
+                    WineType::Blush => spawn_animated_text(commands, player_id, "+Blush Wine", Color::from(Srgba::new(0.9, 0.5, 0.6, 1.0))),
+                    _ => spawn_animated_text(commands, player_id, &format!("+Wine ({})", value), Color::from(Srgba::new(0.7, 0.2, 0.2, 1.0))),
+                }
+            }
+            ActionOutcome::FilledOrder { victory_points, lira, seasonal_bonus } => {
+                spawn_victory_point_particles(commands, player_pos, victory_points, animation_settings);
+                if lira > 0 {
+                    spawn_lira_particles(commands, player_pos + Vec2::new(50.0, 0.0), lira, animation_settings);
+                }
+                if seasonal_bonus > 0 {
+                    info!("🍂 Player {:?} filled seasonal order in season: +{} lira", player_id, seasonal_bonus);
                 }
+                spawn_animated_text(commands, player_id, &format!("+{} VP", victory_points), Color::from(YELLOW));
+            }
+            ActionOutcome::GainedLira { amount } => {
+                spawn_lira_particles(commands, player_pos, amount, animation_settings);
+                spawn_animated_text(commands, player_id, &format!("+{} Lira", amount), Color::from(GOLD));
+            }
+            ActionOutcome::TrainedWorker => {
+                spawn_construction_particles(commands, player_pos, animation_settings);
+                spawn_animated_text(commands, player_id, "+Worker", Color::from(BLUE));
+            }
+            ActionOutcome::Built => {
+                spawn_construction_particles(commands, player_pos, animation_settings);
+                spawn_animated_text(commands, player_id, "+Structure", Color::from(Srgba::new(0.8, 0.8, 0.2, 1.0)));
             }
         }
     }
 }
 
 pub fn fall_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    mut continues: EventReader<PhaseContinue>,
     mut next_state: ResMut<NextState<GameState>>,
     mut vineyards: Query<&mut Vineyard>,
     mut commands: Commands,
-    text_query: Query<Entity, (With<Text>, Without<UIPanel>)>,
-    animation_settings: Res<AnimationSettings>,
+    animation_settings: Option<Res<AnimationSettings>>,
 ) {
-    if text_query.is_empty() {
-        spawn_phase_text(&mut commands, FALL_TEXT);
-    }
-    
-    if keyboard.just_pressed(KeyCode::Space) {
-        cleanup_phase_text(&mut commands, &text_query);
-        
+    if continues.read().count() > 0 {
         let structures = Vec::new();
         for mut vineyard in vineyards.iter_mut() {
             let gained = vineyard.harvest_grapes(&structures);
-            if gained > 0 {
+            if let (true, Some(animation_settings)) = (gained > 0, &animation_settings) {
                 let player_pos = player_position_offset(vineyard.owner);
-                spawn_harvest_particles(&mut commands, player_pos, gained, &animation_settings);
+                spawn_harvest_particles(&mut commands, player_pos, gained, animation_settings);
             }
         }
         
-        trigger_season_transition(&mut commands, GameState::Fall, GameState::Winter, &animation_settings);
+        if let Some(animation_settings) = &animation_settings {
+            trigger_season_transition(&mut commands, GameState::Fall, GameState::Winter, animation_settings);
+        }
         next_state.set(GameState::Winter);
     }
 }
 
 
+// Why the game is ending; check_victory_system settles the winner either way
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EndgameTriggered {
+    TargetReached(PlayerId),
+    YearLimit,
+}
+
+// Score a player is judged on: victory points plus the vineyard's end-game bonus
+fn victory_total(player: &Player, vineyards: &Query<&Vineyard>) -> u8 {
+    let structures = Vec::new(); // TODO: Query actual structures
+    player.victory_points.get() + vineyards.iter()
+        .find(|v| v.owner == player.id)
+        .map_or(0, |v| v.get_end_game_bonus(&structures))
+}
+
+// Looks for the endgame only when a score can have moved (a player or vineyard changed and
+// a total differs from last time) or the year rolled over, instead of every frame
+pub fn victory_trigger_system(
+    players: Query<&Player, Without<Resigned>>,
+    changed_players: Query<(), Changed<Player>>,
+    changed_vineyards: Query<(), Changed<Vineyard>>,
+    vineyards: Query<&Vineyard>,
+    config: Res<GameConfig>,
+    current_state: Res<State<GameState>>,
+    coop: Res<CoopMode>,
+    mut last_totals: Local<Vec<(PlayerId, u8)>>,
+    mut endgame: EventWriter<EndgameTriggered>,
+) {
+    if matches!(current_state.get(), GameState::GameOver) || coop.is_active() {
+        return;
+    }
+
+    if changed_players.iter().next().is_some() || changed_vineyards.iter().next().is_some() {
+        let totals: Vec<(PlayerId, u8)> = players.iter().map(|p| (p.id, victory_total(p, &vineyards))).collect();
+        if totals != *last_totals {
+            *last_totals = totals;
+            let leader = last_totals.iter()
+                .filter(|(_, total)| *total >= config.target_victory_points)
+                .max_by_key(|(_, total)| *total);
+            if let Some((player, _)) = leader {
+                endgame.send(EndgameTriggered::TargetReached(*player));
+                return;
+            }
+        }
+    }
+
+    if config.is_changed() && config.current_year > config.max_years {
+        endgame.send(EndgameTriggered::YearLimit);
+    }
+}
+
 pub fn check_victory_system(
-    players: Query<&Player>,
+    players: Query<&Player, Without<Resigned>>,
     vineyards: Query<&Vineyard>,
     mut next_state: ResMut<NextState<GameState>>,
     config: Res<GameConfig>,
@@ -559,11 +840,23 @@ pub fn check_victory_system(
     text_query: Query<Entity, With<PhaseText>>,
     current_state: Res<State<GameState>>,
     existing_modal: Query<Entity, With<GameOverModal>>,
+    coop: Res<CoopMode>,
+    mut endgame: EventReader<EndgameTriggered>,
 ) {
+    // Nothing to decide until something ends the game
+    if endgame.read().last().is_none() {
+        return;
+    }
+
     // Don't check victory if already in GameOver state
     if matches!(current_state.get(), GameState::GameOver) {
         return;
     }
+
+    // Co-op games are decided by coop_victory_system instead
+    if coop.is_active() {
+        return;
+    }
     
     // Don't create multiple modals
     if !existing_modal.is_empty() {
@@ -575,13 +868,7 @@ pub fn check_victory_system(
     
     // Check all players for victory points
     for player in players.iter() {
-        let mut total_vp = player.victory_points;
-        
-        // Add end-game bonuses
-        if let Some(vineyard) = vineyards.iter().find(|v| v.owner == player.id) {
-            let structures = Vec::new(); // TODO: Query actual structures
-            total_vp += vineyard.get_end_game_bonus(&structures);
-        }
+        let total_vp = victory_total(player, &vineyards);
         
         if total_vp >= config.target_victory_points {
             if total_vp > highest_vp {
@@ -598,11 +885,7 @@ pub fn check_victory_system(
         // If no winner from VP, find highest scoring player
         if winner.is_none() && year_limit_reached {
             for player in players.iter() {
-                let mut total_vp = player.victory_points;
-                if let Some(vineyard) = vineyards.iter().find(|v| v.owner == player.id) {
-                    let structures = Vec::new();
-                    total_vp += vineyard.get_end_game_bonus(&structures);
-                }
+                let total_vp = victory_total(player, &vineyards);
                 if total_vp > highest_vp {
                     highest_vp = total_vp;
                     winner = Some(player);
@@ -627,7 +910,7 @@ pub fn check_victory_system(
 }
 
 // Create a proper modal window for game over
-fn create_game_over_modal(commands: &mut Commands, winner_name: &str, victory_points: u8) {
+pub fn create_game_over_modal(commands: &mut Commands, winner_name: &str, victory_points: u8) {
     // Create backdrop
     commands.spawn((
         NodeBundle {
@@ -642,7 +925,7 @@ fn create_game_over_modal(commands: &mut Commands, winner_name: &str, victory_po
                 ..default()
             },
             background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(), // Semi-transparent backdrop
-            z_index: ZIndex::Global(1000), // Very high z-index
+            z_index: UiLayer::GameOver.z_index(),
             ..default()
         },
         GameOverModal,
@@ -711,9 +994,32 @@ fn create_game_over_modal(commands: &mut Commands, winner_name: &str, victory_po
                 ..default()
             });
             
+            // Export log button
+            modal.spawn((
+                ButtonBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                        margin: UiRect::bottom(Val::Px(20.0)),
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.25, 0.25, 0.35).into(),
+                    ..default()
+                },
+                crate::systems::game_log::ExportLogButton,
+            )).with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    "📜 Export Game Log (Ctrl+E)",
+                    TextStyle {
+                        font_size: 18.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ));
+            });
+            
             // Instructions
             modal.spawn(TextBundle::from_section(
-                "Press SPACE to play again",
+                "Press SPACE to play again, D to browse the final decks",
                 TextStyle {
                     font_size: 20.0,
                     color: Color::srgb(0.6, 0.8, 1.0), // Light blue
@@ -777,7 +1083,7 @@ pub fn apply_mama_abilities_system(
                 match (&mama.special_ability, action) {
                     (Some(MamaAbility::BonusHarvest), ActionSpace::Harvest) => {
                         if let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == worker.owner) {
-                            vineyard.red_grapes += 1; // Bonus harvest grape
+                            vineyard.red_grapes.place(1); // Bonus harvest grape
                             info!("Mama ability: {} got bonus harvest grape", mama.name);
                         }
                     },
@@ -805,6 +1111,7 @@ pub fn enhanced_make_wine_action(
     player_id: PlayerId,
     vineyards: &mut Query<&mut Vineyard>,
     papa_cards: &Query<&PapaCard>,
+    structures: &[Structure],
 ) -> u8 {
     if let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == player_id) {
         // Check if player has wine expertise papa ability
@@ -812,36 +1119,32 @@ pub fn enhanced_make_wine_action(
             .any(|p| p.id == player_id.0 && 
                  matches!(p.special_ability, Some(PapaAbility::WineExpertise)));
         
-        let red_available = vineyard.red_grapes;
-        let white_available = vineyard.white_grapes;
+        let capacity = vineyard.cellar_capacity(structures);
+        let red_available = vineyard.red_grapes.count();
+        let white_available = vineyard.white_grapes.count();
         let mut wine_made = 0;
         
         // Enhanced wine making with multiple options
-        if red_available >= 1 && white_available >= 1 {
-            // Blush wine: 1 red + 1 white grape → wine (more efficient with expertise)
-            let blush_efficiency = if has_wine_expertise { 2 } else { 1 };
-            vineyard.red_grapes -= 1;
-            vineyard.white_grapes -= 1;
-            vineyard.white_wine += blush_efficiency; // Store blush as white wine
-            wine_made += blush_efficiency;
-            info!("Made blush wine (efficiency: {})", blush_efficiency);
-        } else if red_available >= 2 && white_available >= 2 {
-            // Sparkling wine: 2 red + 2 white → 3 wine (premium option)
-            vineyard.red_grapes -= 2;
-            vineyard.white_grapes -= 2;
-            vineyard.red_wine += 3; // Sparkling gives bonus wine
-            wine_made += 3;
-            info!("Made sparkling wine");
+        if red_available >= 1 && white_available >= 1 && capacity >= 6 {
+            // Blush wine: 1 red + 1 white grape, one value higher with expertise
+            let blush_bonus = if has_wine_expertise { 1 } else { 0 };
+            let red = vineyard.red_grapes.take_highest().unwrap_or(0);
+            let white = vineyard.white_grapes.take_highest().unwrap_or(0);
+            if vineyard.white_wine.place(red.saturating_add(white).saturating_add(blush_bonus).min(capacity)).is_some() { // Store blush as white wine
+                wine_made += 1;
+            }
+            info!("Made blush wine (bonus: {})", blush_bonus);
         } else {
-            // Regular wine making: 1 grape → 1 wine
-            let red_to_use = red_available.min(2);
-            let white_to_use = white_available.min(2);
-            vineyard.red_grapes -= red_to_use;
-            vineyard.white_grapes -= white_to_use;
-            vineyard.red_wine += red_to_use;
-            vineyard.white_wine += white_to_use;
-            wine_made += red_to_use + white_to_use;
-            info!("Made regular wine: {} red, {} white", red_to_use, white_to_use);
+            // Regular wine making: up to 2 grapes of each color become wine at the same value
+            for _ in 0..2 {
+                if vineyard.make_wine(WineType::Red, capacity).is_some() {
+                    wine_made += 1;
+                }
+                if vineyard.make_wine(WineType::White, capacity).is_some() {
+                    wine_made += 1;
+                }
+            }
+            info!("Made {} regular wine", wine_made);
         }
         
         wine_made
@@ -853,40 +1156,59 @@ pub fn enhanced_make_wine_action(
 // 3. Add aging system
 pub fn year_end_aging_system(
     mut vineyards: Query<&mut Vineyard>,
+    structures: Query<&Structure>,
     current_state: Res<State<GameState>>,
 ) {
     if current_state.is_changed() && matches!(current_state.get(), GameState::Spring) {
+        let structures: Vec<Structure> = structures.iter().cloned().collect();
         for mut vineyard in vineyards.iter_mut() {
-            // Age grapes (max 9)
-            vineyard.red_grapes = (vineyard.red_grapes + 1).min(9);
-            vineyard.white_grapes = (vineyard.white_grapes + 1).min(9);
-            
-            // Age wines (max 9)
-            vineyard.red_wine = (vineyard.red_wine + 1).min(9);
-            vineyard.white_wine = (vineyard.white_wine + 1).min(9);
+            vineyard.apply_aging(&structures);
+        }
+    }
+}
+
+// Keeps every vineyard's copy of the sparkling aging house rule in step with the settings
+pub fn sparkling_aging_rule_system(
+    mut vineyards: Query<&mut Vineyard>,
+    settings: Res<UserSettings>,
+) {
+    for mut vineyard in vineyards.iter_mut() {
+        if vineyard.sparkling_aging != settings.house_rule_sparkling_aging {
+            vineyard.sparkling_aging = settings.house_rule_sparkling_aging;
         }
     }
 }
 
-// 4. Add hand limit enforcement
+// 4. Add hand limit enforcement: AIs discard by card value, humans queue for the discard modal
 pub fn enforce_hand_limit_system(
     mut hands: Query<&mut Hand>,
     current_state: Res<State<GameState>>,
+    settings: Res<UserSettings>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+    structures: Query<&Structure>,
+    mut card_decks: ResMut<CardDecks>,
+    mut discards: ResMut<HandLimitDiscard>,
 ) {
     if current_state.is_changed() && matches!(current_state.get(), GameState::Spring) {
         for mut hand in hands.iter_mut() {
-            let total_cards = hand.vine_cards.len() + hand.wine_order_cards.len();
-            if total_cards > HAND_LIMIT {
-                let excess = total_cards - HAND_LIMIT;
-                // Simple implementation: remove vine cards first
-                for _ in 0..excess {
-                    if !hand.vine_cards.is_empty() {
-                        hand.vine_cards.remove(0);
-                    } else if !hand.wine_order_cards.is_empty() {
-                        hand.wine_order_cards.remove(0);
-                    }
+            let requirement = discard_requirement(&hand, settings.house_rule_order_swap);
+            if requirement.total() == 0 {
+                continue;
+            }
+
+            let is_ai = players.iter().any(|p| p.id == hand.owner && p.is_ai);
+            if !is_ai {
+                if !discards.pending.contains(&hand.owner) {
+                    discards.pending.push(hand.owner);
                 }
+                continue;
             }
+
+            let Some(vineyard) = vineyards.iter().find(|v| v.owner == hand.owner) else { continue };
+            let owned: Vec<Structure> = structures.iter().filter(|s| s.owner == hand.owner).cloned().collect();
+            let selection = ai_discard_choice(&hand, vineyard, &owned, requirement);
+            discard_cards(&mut hand, &mut card_decks, &selection);
         }
     }
 }
@@ -902,14 +1224,21 @@ pub fn assign_temporary_worker_system(
     mut commands: Commands,
     turn_order: Res<TurnOrder>,
     existing_temp: Query<Entity, With<TemporaryWorker>>,
+    settings: Res<UserSettings>,
+    draft: Res<WakeUpDraft>,
 ) {
     // Clean up old temp workers
     for entity in existing_temp.iter() {
         commands.entity(entity).despawn();
     }
     
-    // Find player who chose position 7 (last wake-up)
-    if let Some((player_id, time)) = turn_order.wake_up_order.iter().find(|(_, t)| *t == 7) {
+    // Find player who chose position 7 (last wake-up), or the top bidder in the blind bidding variant
+    let owner = if settings.house_rule_temp_worker_bidding {
+        draft.temp_worker.map(|(id, _)| id)
+    } else {
+        turn_order.wake_up_order.iter().find(|(_, t)| *t == 7).map(|(id, _)| *id)
+    };
+    if let Some(player_id) = owner.as_ref() {
         let worker_pos = Vec2::new(-500.0 + (player_id.0 as f32 * 120.0), -230.0);
         commands.spawn((
             Worker::new(*player_id, false, worker_pos),
@@ -921,17 +1250,15 @@ pub fn assign_temporary_worker_system(
 
 // 7. Add Fall phase for visitor cards
 pub fn fall_visitor_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    mut continues: EventReader<PhaseContinue>,
     mut next_state: ResMut<NextState<GameState>>,
     mut hands: Query<&mut Hand>,
     mut card_decks: ResMut<CardDecks>,
     turn_order: Res<TurnOrder>,
-    mut commands: Commands,
-    text_query: Query<Entity, (With<Text>, Without<UIPanel>)>,
+    current_state: Res<State<GameState>>,
 ) {
-    if text_query.is_empty() {
-        spawn_phase_text(&mut commands, FALL_VISITOR_TEXT);
-        
+    // Dealt on the first frame of each Fall
+    if current_state.is_changed() {
         // Each player draws a visitor card (simplified: give summer visitor)
         for player_id in &turn_order.players {
             if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == *player_id) {
@@ -943,8 +1270,7 @@ pub fn fall_visitor_system(
         }
     }
     
-    if keyboard.just_pressed(KeyCode::Space) {
-        cleanup_phase_text(&mut commands, &text_query);
+    if continues.read().count() > 0 {
         next_state.set(GameState::Winter);
     }
 }
@@ -959,6 +1285,7 @@ pub fn fall_draw_visitors_system(
     mut commands: Commands,
     text_query: Query<Entity, (With<Text>, Without<UIPanel>)>,
     expansion_settings: Res<ExpansionSettings>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Only run if Tuscany expansion is enabled (where visitor cards exist)
     if !expansion_settings.tuscany_enabled {
@@ -980,7 +1307,7 @@ pub fn fall_draw_visitors_system(
         for player_id in &turn_order.players {
             if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == *player_id) {
                 // Draw 1 summer visitor card (player's choice simplified to summer)
-                if let Some(visitor) = visitor_deck.draw_summer_visitor() {
+                if let Some(visitor) = visitor_deck.draw_summer_visitor(&mut *rng) {
                     hand.add_visitor_card(visitor);
                 }
                 
@@ -990,7 +1317,7 @@ pub fn fall_draw_visitors_system(
                 
                 if has_cottage {
                     // Draw bonus winter visitor
-                    if let Some(bonus_visitor) = visitor_deck.draw_winter_visitor() {
+                    if let Some(bonus_visitor) = visitor_deck.draw_winter_visitor(&mut *rng) {
                         hand.add_visitor_card(bonus_visitor);
                     }
                 }
@@ -1011,18 +1338,18 @@ pub fn fulfill_order_with_residual(
     vineyard: &mut Vineyard,
     player: &mut Player,
     trackers: &mut Query<&mut ResidualPaymentTracker>,
+    current_year: u8,
 ) -> bool {
     if vineyard.can_fulfill_order(order) {
-        vineyard.red_wine -= order.red_wine_needed;
-        vineyard.white_wine -= order.white_wine_needed;
+        vineyard.take_order_wines(order);
         
-        // Apply immediate rewards
+        // Apply immediate rewards (seasonal orders pay extra when in season)
         player.gain_victory_points(order.victory_points);
-        player.gain_lira(order.immediate_payout());
+        player.gain_lira(order.immediate_payout() + order.seasonal_bonus(current_year));
         
-        // Advance residual payment tracker
+        // Advance residual payment tracker (premium orders advance further)
         if let Some(mut tracker) = trackers.iter_mut().find(|t| t.owner == player_id) {
-            tracker.advance(order.residual_payment());
+            tracker.advance(order.residual_steps());
         }
         
         true
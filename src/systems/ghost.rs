@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::game_logic::{preview_action, ActionOutcome};
+use crate::systems::legal_actions::{legal_action, RulesState};
+use crate::systems::lobby::PlayerColors;
+use crate::systems::render_layers::WorldLayer;
+
+const GHOST_ALPHA: f32 = 0.45;
+
+#[derive(Component)]
+pub struct GhostWorker;
+
+// Translucent worker + projected gains on the hovered action space, rebuilt every frame
+pub fn ghost_worker_preview_system(
+    mut commands: Commands,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    action_spaces: Query<(&ActionSpaceSlot, &Clickable)>,
+    workers: Query<&Worker>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+    players: Query<&Player>,
+    (trackers, structures): (Query<&ResidualPaymentTracker>, Query<&Structure>),
+    (card_decks, player_colors): (Res<CardDecks>, Res<PlayerColors>),
+    turn_order: Res<TurnOrder>,
+    config: Res<GameConfig>,
+    current_state: Res<State<GameState>>,
+    ghosts: Query<Entity, With<GhostWorker>>,
+) {
+    for entity in ghosts.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(current_player_id) = turn_order.players.get(turn_order.current_player).copied() else { return };
+    if players.iter().any(|p| p.id == current_player_id && p.is_ai) {
+        return;
+    }
+
+    let window = windows.single();
+    let (camera, camera_transform) = camera_q.single();
+    let Some(cursor_pos) = window.cursor_position() else { return };
+    let Some(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) else { return };
+
+    let Some((space, _)) = action_spaces.iter()
+        .find(|(space, clickable)| space.is_open(config.player_count)
+            && Rect::from_center_size(space.position, clickable.size).contains(world_pos)) else { return };
+
+    // Same worker choice as mouse_input_system: a regular worker first, the grande worker as fallback
+    let board = RulesState::new(action_spaces.iter().map(|(s, _)| s), current_state.get(), config.player_count)
+        .with_workers(workers.iter());
+    let Some(legal) = legal_action(&board, current_player_id, space.action) else { return };
+    // An occupied column only previews the grande's bonus slot once the row is full
+    if space.occupied_by.is_some() && !legal.blocked {
+        return;
+    }
+    let Some(is_grande) = legal.uses_grande(false) else { return };
+
+    let player_structures: Vec<_> = structures.iter()
+        .filter(|s| s.owner == current_player_id)
+        .cloned()
+        .collect();
+    let outcome = preview_action(
+        space.action,
+        config.current_year,
+        hands.iter().find(|h| h.owner == current_player_id),
+        vineyards.iter().find(|v| v.owner == current_player_id),
+        players.iter().find(|p| p.id == current_player_id),
+        trackers.iter().find(|t| t.owner == current_player_id),
+        &card_decks,
+        &player_structures,
+        space.bonus_free(),
+    );
+
+    let base = player_colors.srgba(current_player_id);
+    let color = Color::srgba(base.red, base.green, base.blue, GHOST_ALPHA);
+    let size = if is_grande { Vec2::new(24.0, 24.0) } else { Vec2::new(18.0, 18.0) };
+
+    // Grande workers on an occupied space sit in the bonus slot to the right
+    let ghost_pos = if is_grande && space.occupied_by.is_some() {
+        space.position + Vec2::new(25.0, 0.0)
+    } else {
+        space.position
+    };
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(size),
+                ..default()
+            },
+            transform: Transform::from_translation(ghost_pos.extend(WorldLayer::Preview.z())),
+            ..default()
+        },
+        GhostWorker,
+    ));
+
+    // Gains text points toward the centre of the board so it stays on screen
+    let text_offset = if space.position.x > 0.0 { Vec2::new(-90.0, 0.0) } else { Vec2::new(90.0, 0.0) };
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                outcome.summary(),
+                TextStyle {
+                    font_size: 14.0,
+                    color: if outcome == ActionOutcome::Nothing {
+                        Color::srgb(0.8, 0.5, 0.5)
+                    } else {
+                        Color::srgb(0.6, 1.0, 0.6)
+                    },
+                    ..default()
+                },
+            ),
+            transform: Transform::from_translation((ghost_pos + text_offset).extend(WorldLayer::Preview.at(0.1))),
+            ..default()
+        },
+        GhostWorker,
+    ));
+}
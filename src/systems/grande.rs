@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::render_layers::WorldLayer;
+
+// Deliberate grande placement: click your grande token (or hold Shift) and the next space
+// you click or pick from the action bar takes the grande worker, even when it is occupied.
+#[derive(Resource, Default)]
+pub struct GrandeSelection {
+    pub armed: Option<PlayerId>,
+}
+
+impl GrandeSelection {
+    // True when the next placement by `player` should use the grande worker
+    pub fn wants_grande(&self, player: PlayerId, keyboard: &ButtonInput<KeyCode>) -> bool {
+        self.armed == Some(player) || keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+    }
+}
+
+#[derive(Component)]
+pub struct GrandeArmedMarker;
+
+const MARKER_SIZE: Vec2 = Vec2::new(34.0, 34.0);
+
+// Runs before mouse_input_system so the click that arms the grande never places a worker
+pub fn grande_selection_system(
+    mut commands: Commands,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    turn_order: Res<TurnOrder>,
+    current_state: Res<State<GameState>>,
+    mut selection: ResMut<GrandeSelection>,
+    players: Query<&Player>,
+    workers: Query<(&Worker, &Clickable)>,
+    markers: Query<Entity, With<GrandeArmedMarker>>,
+) {
+    let current_player = turn_order.players.get(turn_order.current_player).copied();
+    let human_turn = current_player.is_some_and(|id| players.iter().any(|p| p.id == id && !p.is_ai));
+    let in_season = matches!(current_state.get(), GameState::Summer | GameState::Winter);
+    let grande = current_player.and_then(|id| workers.iter()
+        .find(|(w, _)| w.owner == id && w.is_grande && w.placed_at.is_none()));
+
+    // Disarm on Esc, a turn change, or once the grande has gone out
+    if selection.armed.is_some()
+        && (keyboard.just_pressed(KeyCode::Escape) || !human_turn || !in_season || selection.armed != current_player || grande.is_none())
+    {
+        selection.armed = None;
+    }
+
+    if human_turn && in_season && mouse_input.just_pressed(MouseButton::Left) {
+        let world_pos = windows.get_single().ok()
+            .and_then(|window| window.cursor_position())
+            .zip(camera_q.get_single().ok())
+            .and_then(|(cursor, (camera, transform))| camera.viewport_to_world_2d(transform, cursor));
+        if let (Some(world_pos), Some((worker, clickable))) = (world_pos, grande) {
+            if Rect::from_center_size(worker.position, clickable.size).contains(world_pos) {
+                selection.armed = if selection.armed.is_some() { None } else { current_player };
+                info!("★ Grande worker {}", if selection.armed.is_some() { "selected - click a space" } else { "deselected" });
+            }
+        }
+    }
+
+    for entity in markers.iter() {
+        commands.entity(entity).despawn();
+    }
+    if let (Some(_), Some((worker, _))) = (selection.armed, grande) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(1.0, 0.85, 0.2, 0.6),
+                    custom_size: Some(MARKER_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_translation(worker.position.extend(WorldLayer::Workers.z())),
+                ..default()
+            },
+            GrandeArmedMarker,
+        ));
+    }
+}
@@ -0,0 +1,300 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::hand_picker::{order_label, vine_label};
+use crate::systems::order_swap::{wine_deficit, ORDER_HAND_LIMIT, VINE_HAND_LIMIT};
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+use crate::systems::net::plays_locally;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::seat_choices::{SeatChoice, SeatChoiceMade};
+
+// Hands over the limit at the start of a year are trimmed by their owner: AIs right away by
+// card value, humans through a discard modal that holds up Spring until they confirm.
+// Discarded cards go to the discard piles.
+pub const HAND_LIMIT: usize = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DiscardCard {
+    Vine(usize),
+    Order(usize),
+}
+
+// How many cards must go: `vines` and `orders` under the order-swap house rule's separate
+// limits, otherwise `any` mix of the two
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiscardRequirement {
+    pub vines: usize,
+    pub orders: usize,
+    pub any: usize,
+}
+
+impl DiscardRequirement {
+    pub fn total(&self) -> usize {
+        self.vines + self.orders + self.any
+    }
+
+    pub fn satisfied_by(&self, selection: &[DiscardCard]) -> bool {
+        let vines = selection.iter().filter(|c| matches!(c, DiscardCard::Vine(_))).count();
+        let orders = selection.len() - vines;
+        vines >= self.vines && orders >= self.orders && selection.len() == self.total()
+    }
+}
+
+pub fn discard_requirement(hand: &Hand, separate_limits: bool) -> DiscardRequirement {
+    if separate_limits {
+        DiscardRequirement {
+            vines: hand.vine_cards.len().saturating_sub(VINE_HAND_LIMIT),
+            orders: hand.wine_order_cards.len().saturating_sub(ORDER_HAND_LIMIT),
+            any: 0,
+        }
+    } else {
+        DiscardRequirement {
+            any: (hand.vine_cards.len() + hand.wine_order_cards.len()).saturating_sub(HAND_LIMIT),
+            ..default()
+        }
+    }
+}
+
+// Rough worth of keeping a card: plantable vines by value, orders by VP less what is missing
+fn card_value(card: DiscardCard, hand: &Hand, vineyard: &Vineyard, structures: &[Structure]) -> i32 {
+    match card {
+        DiscardCard::Vine(i) => {
+            let vine = &hand.vine_cards[i];
+            let value = match vine.vine_type {
+                VineType::Red(v) | VineType::White(v) => v as i32,
+            };
+            let plantable = (0..vineyard.fields.len()).any(|f| vineyard.can_plant_vine_with_requirements(f, vine, structures));
+            value + if plantable { 2 } else { 0 }
+        }
+        DiscardCard::Order(i) => {
+            let order = &hand.wine_order_cards[i];
+            let ready = if vineyard.can_fulfill_order(order) { 3 } else { 0 };
+            order.victory_points as i32 + ready - wine_deficit(order, vineyard) as i32
+        }
+    }
+}
+
+// The AI throws away its least valuable cards that meet the requirement
+pub fn ai_discard_choice(hand: &Hand, vineyard: &Vineyard, structures: &[Structure], requirement: DiscardRequirement) -> Vec<DiscardCard> {
+    let mut by_value: Vec<DiscardCard> = (0..hand.vine_cards.len()).map(DiscardCard::Vine)
+        .chain((0..hand.wine_order_cards.len()).map(DiscardCard::Order))
+        .collect();
+    by_value.sort_by_key(|c| card_value(*c, hand, vineyard, structures));
+
+    let mut chosen: Vec<DiscardCard> = by_value.iter().filter(|c| matches!(c, DiscardCard::Vine(_))).take(requirement.vines).copied().collect();
+    chosen.extend(by_value.iter().filter(|c| matches!(c, DiscardCard::Order(_))).take(requirement.orders));
+    let rest: Vec<DiscardCard> = by_value.iter().filter(|c| !chosen.contains(c)).take(requirement.any).copied().collect();
+    chosen.extend(rest);
+    chosen
+}
+
+// Moves the selected cards from the hand to the discard piles
+pub fn discard_cards(hand: &mut Hand, card_decks: &mut CardDecks, selection: &[DiscardCard]) {
+    let mut vines: Vec<usize> = selection.iter().filter_map(|c| match c { DiscardCard::Vine(i) => Some(*i), _ => None }).collect();
+    let mut orders: Vec<usize> = selection.iter().filter_map(|c| match c { DiscardCard::Order(i) => Some(*i), _ => None }).collect();
+    // Highest index first so the rest stay put
+    vines.sort_unstable_by(|a, b| b.cmp(a));
+    vines.dedup();
+    orders.sort_unstable_by(|a, b| b.cmp(a));
+    orders.dedup();
+
+    for i in vines.into_iter().filter(|i| *i < hand.vine_cards.len()) {
+        card_decks.vine_discard.push(hand.vine_cards.remove(i));
+    }
+    for i in orders.into_iter().filter(|i| *i < hand.wine_order_cards.len()) {
+        card_decks.wine_order_discard.push(hand.wine_order_cards.remove(i));
+    }
+}
+
+// Humans still to discard, in turn; the first one has the modal
+#[derive(Resource, Default)]
+pub struct HandLimitDiscard {
+    pub pending: Vec<PlayerId>,
+    pub selected: Vec<DiscardCard>,
+}
+
+#[derive(Component)]
+pub struct DiscardModal;
+
+#[derive(Component)]
+pub struct DiscardModalCard(pub DiscardCard);
+
+#[derive(Component)]
+pub struct DiscardModalConfirm;
+
+const THUMB_SIZE: Vec2 = Vec2::new(84.0, 112.0);
+
+pub fn hand_limit_modal_system(
+    mut commands: Commands,
+    mut discards: ResMut<HandLimitDiscard>,
+    mut card_decks: ResMut<CardDecks>,
+    settings: Res<UserSettings>,
+    mut hands: Query<&mut Hand>,
+    players: Query<&Player>,
+    card_buttons: Query<(&Interaction, &DiscardModalCard), Changed<Interaction>>,
+    confirm_buttons: Query<&Interaction, (Changed<Interaction>, With<DiscardModalConfirm>)>,
+    modals: Query<Entity, With<DiscardModal>>,
+    (remote, pbf, mut choices): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, EventWriter<SeatChoiceMade>),
+) {
+    let close = |commands: &mut Commands| {
+        for entity in modals.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    };
+
+    let Some(player_id) = discards.pending.first().copied() else {
+        close(&mut commands);
+        return;
+    };
+    let Some(mut hand) = hands.iter_mut().find(|h| h.owner == player_id) else {
+        discards.pending.remove(0);
+        return;
+    };
+    let requirement = discard_requirement(&hand, settings.house_rule_order_swap);
+    if requirement.total() == 0 {
+        discards.pending.remove(0);
+        close(&mut commands);
+        return;
+    }
+    // A human at another machine discards there; their pick arrives as a seat choice
+    if !plays_locally(remote.as_deref(), pbf.as_deref(), player_id) {
+        close(&mut commands);
+        return;
+    }
+
+    let confirmed = confirm_buttons.iter().any(|i| *i == Interaction::Pressed);
+    if confirmed && requirement.satisfied_by(&discards.selected) {
+        let selection = std::mem::take(&mut discards.selected);
+        discard_cards(&mut hand, &mut card_decks, &selection);
+        info!("🗑 Player {} discarded {} card(s) to the hand limit", player_id.0 + 1, selection.len());
+        choices.send(SeatChoiceMade { player: player_id, choice: SeatChoice::Discard(selection) });
+        discards.pending.remove(0);
+        close(&mut commands);
+        return;
+    }
+
+    let mut changed = false;
+    for (_, card) in card_buttons.iter().filter(|(i, _)| **i == Interaction::Pressed) {
+        if let Some(index) = discards.selected.iter().position(|c| *c == card.0) {
+            discards.selected.remove(index);
+        } else if discards.selected.len() < requirement.total() {
+            discards.selected.push(card.0);
+        }
+        changed = true;
+    }
+
+    if changed || modals.is_empty() {
+        close(&mut commands);
+        let name = players.iter().find(|p| p.id == player_id).map_or_else(|| format!("Player {}", player_id.0 + 1), |p| p.name.clone());
+        spawn_discard_modal(&mut commands, &name, &hand, requirement, &discards.selected);
+    }
+}
+
+fn spawn_discard_modal(commands: &mut Commands, name: &str, hand: &Hand, requirement: DiscardRequirement, selected: &[DiscardCard]) {
+    let title = if requirement.any > 0 {
+        format!("🗑 {}: hand limit is {} — choose {} card(s) to discard", name, HAND_LIMIT, requirement.any)
+    } else {
+        format!("🗑 {}: hand limits are {} vines and {} orders — discard {} vine(s) and {} order(s)",
+            name, VINE_HAND_LIMIT, ORDER_HAND_LIMIT, requirement.vines, requirement.orders)
+    };
+    let cards: Vec<(DiscardCard, String, Color, Color)> = hand.vine_cards.iter().enumerate()
+        .map(|(i, c)| (DiscardCard::Vine(i), vine_label(c), c.art_style.get_color(), c.art_style.get_border_color()))
+        .chain(hand.wine_order_cards.iter().enumerate()
+            .map(|(i, o)| (DiscardCard::Order(i), order_label(o), o.art_style.get_color(), o.art_style.get_border_color())))
+        .collect();
+    let ready = requirement.satisfied_by(selected);
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(140.0),
+                left: Val::Percent(15.0),
+                max_width: Val::Percent(70.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.1, 0.1, 0.1).with_alpha(0.95).into(),
+            border_color: Color::srgb(0.7, 0.3, 0.3).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        DiscardModal,
+    )).with_children(|modal| {
+        modal.spawn(TextBundle::from_section(
+            title,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        modal.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row| {
+            for (card, label, color, border) in cards {
+                // Cards marked for discard are dimmed with a red border
+                let marked = selected.contains(&card);
+                let alpha = if marked { 0.4 } else { 1.0 };
+                row.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(THUMB_SIZE.x),
+                            height: Val::Px(THUMB_SIZE.y),
+                            margin: UiRect::all(Val::Px(4.0)),
+                            padding: UiRect::all(Val::Px(6.0)),
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        background_color: color.with_alpha(alpha).into(),
+                        border_color: if marked { Color::srgb(0.9, 0.1, 0.1) } else { border }.into(),
+                        ..default()
+                    },
+                    DiscardModalCard(card),
+                )).with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        label,
+                        TextStyle {
+                            font_size: 13.0,
+                            color: Color::BLACK.with_alpha(alpha.max(0.6)),
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+
+        modal.spawn((
+            ButtonBundle {
+                style: Style {
+                    margin: UiRect::top(Val::Px(8.0)),
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    align_self: AlignSelf::FlexEnd,
+                    ..default()
+                },
+                background_color: if ready { Color::srgb(0.5, 0.2, 0.2) } else { Color::srgb(0.25, 0.25, 0.25) }.into(),
+                ..default()
+            },
+            DiscardModalConfirm,
+        )).with_children(|button| {
+            button.spawn(TextBundle::from_section(
+                format!("Discard {} / {}", selected.len(), requirement.total()),
+                TextStyle {
+                    font_size: 13.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::choice_memory::ChoiceMemory;
+use crate::systems::input::ActionRequested;
+use crate::systems::keymap::*;
+use crate::systems::order_tokens::{wants_token_pick, OrderTokenPicker};
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Plant Vine and Fill Order resolve the first card in hand. When a human has more than
+// one card to choose from, the placement waits here until a thumbnail is clicked; the
+// chosen card moves to the front of the hand and the worker goes out through ActionRequested.
+// The player's last pick of the same kind starts highlighted and R takes it.
+#[derive(Resource, Default)]
+pub struct HandCardPicker {
+    pub pending: Option<ActionRequested>,
+}
+
+#[derive(Component)]
+pub struct HandPickerOverlay;
+
+#[derive(Component)]
+pub struct HandPickerCard(pub usize);
+
+#[derive(Component)]
+pub struct HandPickerCancel;
+
+const THUMB_SIZE: Vec2 = Vec2::new(84.0, 112.0);
+
+// True when the action should open the picker instead of resolving straight away
+pub fn needs_card_pick(action: ActionSpace, hand: &Hand) -> bool {
+    match action {
+        ActionSpace::PlantVine => hand.vine_cards.len() > 1,
+        ActionSpace::FillOrder => hand.wine_order_cards.len() > 1,
+        _ => false,
+    }
+}
+
+pub fn vine_label(card: &VineCard) -> String {
+    let (color, value) = match card.vine_type {
+        VineType::Red(v) => ("Red", v),
+        VineType::White(v) => ("White", v),
+    };
+    let requirements = card.requirements();
+    let mut label = format!("{} {}\n{} lira", color, value, card.cost);
+    if requirements.needs_trellis {
+        label.push_str("\nTrellis");
+    }
+    if requirements.needs_irrigation {
+        label.push_str("\nIrrigation");
+    }
+    label
+}
+
+pub fn order_label(order: &WineOrderCard) -> String {
+    format!("{}R {}W\n{} VP\n{} lira", order.red_wine_needed, order.white_wine_needed, order.victory_points, order.immediate_payout())
+}
+
+pub fn hand_picker_system(
+    mut commands: Commands,
+    input: BoundInput,
+    current_state: Res<State<GameState>>,
+    mut picker: ResMut<HandCardPicker>,
+    mut place_events: EventWriter<ActionRequested>,
+    mut hands: Query<&mut Hand>,
+    vineyards: Query<&Vineyard>,
+    structures: Query<&Structure>,
+    card_buttons: Query<(&Interaction, &HandPickerCard), Changed<Interaction>>,
+    cancel_buttons: Query<&Interaction, (Changed<Interaction>, With<HandPickerCancel>)>,
+    overlays: Query<Entity, With<HandPickerOverlay>>,
+    (settings, mut token_picker): (Res<UserSettings>, ResMut<OrderTokenPicker>),
+    (players, mut memory): (Query<&Player>, ResMut<ChoiceMemory>),
+) {
+    let close = |commands: &mut Commands| {
+        for entity in overlays.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    };
+
+    let Some(pending) = picker.pending else {
+        close(&mut commands);
+        return;
+    };
+
+    let cancelled = input.keyboard.just_pressed(KeyCode::Escape)
+        || cancel_buttons.iter().any(|i| *i == Interaction::Pressed)
+        || !matches!(current_state.get(), GameState::Summer | GameState::Winter);
+    if cancelled {
+        picker.pending = None;
+        close(&mut commands);
+        return;
+    }
+
+    let Some(mut hand) = hands.iter_mut().find(|h| h.owner == pending.player) else { return };
+    let Some(vineyard) = vineyards.iter().find(|v| v.owner == pending.player) else { return };
+    let owned: Vec<Structure> = structures.iter().filter(|s| s.owner == pending.player).cloned().collect();
+
+    let usable: Vec<bool> = match pending.action {
+        ActionSpace::PlantVine => hand.vine_cards.iter()
+            .map(|card| (0..9).any(|i| vineyard.can_plant_vine_with_requirements(i, card, &owned)))
+            .collect(),
+        _ => hand.wine_order_cards.iter().map(|order| vineyard.can_fulfill_order(order)).collect(),
+    };
+
+    let name = players.iter().find(|p| p.id == pending.player).map(|p| p.name.clone()).unwrap_or_default();
+    let remembered = match pending.action {
+        ActionSpace::PlantVine => memory.vine_index(&name, &hand),
+        _ => memory.order_index(&name, &hand),
+    }.filter(|index| usable.get(*index).copied().unwrap_or(false));
+
+    let clicked = card_buttons.iter()
+        .find(|(interaction, card)| **interaction == Interaction::Pressed && usable.get(card.0).copied().unwrap_or(false))
+        .map(|(_, card)| card.0)
+        .or(remembered.filter(|_| input.just_pressed(InputAction::RepeatChoice)));
+
+    if let Some(index) = clicked {
+        match pending.action {
+            ActionSpace::PlantVine => {
+                let card = hand.vine_cards.remove(index);
+                memory.remember(&name, |choices| choices.vine = Some(card.vine_type));
+                hand.vine_cards.insert(0, card);
+            }
+            _ => {
+                let order = hand.wine_order_cards.remove(index);
+                memory.remember(&name, |choices| choices.order = Some((order.red_wine_needed, order.white_wine_needed)));
+                hand.wine_order_cards.insert(0, order);
+            }
+        }
+        // The chosen order may still need its wines picked
+        if wants_token_pick(&settings, pending.action, &hand, vineyard) {
+            token_picker.open(pending, &hand.wine_order_cards[0], vineyard);
+        } else {
+            place_events.send(pending);
+        }
+        picker.pending = None;
+        close(&mut commands);
+        return;
+    }
+
+    if overlays.is_empty() {
+        let cards: Vec<(String, Color, Color)> = match pending.action {
+            ActionSpace::PlantVine => hand.vine_cards.iter()
+                .map(|c| (vine_label(c), c.art_style.get_color(), c.art_style.get_border_color()))
+                .collect(),
+            _ => hand.wine_order_cards.iter()
+                .map(|o| (order_label(o), o.art_style.get_color(), o.art_style.get_border_color()))
+                .collect(),
+        };
+        let repeat_key = input.keymap.binding(InputAction::RepeatChoice).map(|b| b.key_label()).unwrap_or_default();
+        spawn_picker(&mut commands, pending.action, &cards, &usable, remembered, &repeat_key);
+    }
+}
+
+fn spawn_picker(commands: &mut Commands, action: ActionSpace, cards: &[(String, Color, Color)], usable: &[bool], remembered: Option<usize>, repeat_key: &str) {
+    let title = match action {
+        ActionSpace::PlantVine => "🌱 Choose a vine to plant (Esc cancels)",
+        _ => "📜 Choose an order to fill (Esc cancels)",
+    };
+    let title = match remembered {
+        Some(_) => format!("{}\n{} repeats your last choice", title, repeat_key),
+        None => title.to_string(),
+    };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(130.0),
+                left: Val::Percent(20.0),
+                max_width: Val::Percent(60.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.1, 0.1, 0.1).with_alpha(0.95).into(),
+            border_color: Color::srgb(0.6, 0.5, 0.3).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        HandPickerOverlay,
+    )).with_children(|overlay| {
+        overlay.spawn(TextBundle::from_section(
+            title,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        overlay.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row| {
+            for (index, (label, color, border)) in cards.iter().enumerate() {
+                let enabled = usable.get(index).copied().unwrap_or(false);
+                let alpha = if enabled { 1.0 } else { 0.35 };
+                let border = if remembered == Some(index) { Color::WHITE } else { *border };
+                row.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(THUMB_SIZE.x),
+                            height: Val::Px(THUMB_SIZE.y),
+                            margin: UiRect::all(Val::Px(4.0)),
+                            padding: UiRect::all(Val::Px(6.0)),
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        background_color: color.with_alpha(alpha).into(),
+                        border_color: border.with_alpha(alpha).into(),
+                        ..default()
+                    },
+                    HandPickerCard(index),
+                )).with_children(|card| {
+                    card.spawn(TextBundle::from_section(
+                        label.clone(),
+                        TextStyle {
+                            font_size: 13.0,
+                            color: Color::BLACK.with_alpha(alpha),
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+
+        overlay.spawn((
+            ButtonBundle {
+                style: Style {
+                    margin: UiRect::top(Val::Px(8.0)),
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    align_self: AlignSelf::FlexEnd,
+                    ..default()
+                },
+                background_color: Color::srgb(0.3, 0.3, 0.3).into(),
+                ..default()
+            },
+            HandPickerCancel,
+        )).with_children(|button| {
+            button.spawn(TextBundle::from_section(
+                "Cancel",
+                TextStyle {
+                    font_size: 13.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
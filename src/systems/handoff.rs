@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::components::*;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::settings::UserSettings;
+use crate::systems::split_screen::SplitScreen;
+use crate::systems::render_layers::UiLayer;
+
+// Pass-the-device play: when the turn moves to a different human in a local game, the board
+// is covered and the hand row stays hidden until the next player confirms they have the device.
+
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct Handoff {
+    // Human the screen is waiting on
+    pub pending: Option<PlayerId>,
+    // Last human whose cards were shown
+    pub last_shown: Option<PlayerId>,
+}
+
+impl Handoff {
+    // A different human on turn puts the screen up; AI turns leave it as it is
+    pub fn turn_changed(&mut self, human_on_turn: Option<PlayerId>) {
+        let Some(player) = human_on_turn else { return };
+        if self.last_shown != Some(player) && self.pending != Some(player) {
+            self.pending = Some(player);
+        }
+    }
+
+    pub fn confirm(&mut self) {
+        if let Some(player) = self.pending.take() {
+            self.last_shown = Some(player);
+        }
+    }
+
+    pub fn hides_hand(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+// Run condition: board input waits until the next player has taken the device
+pub fn handoff_clear(handoff: Res<Handoff>) -> bool {
+    handoff.pending.is_none()
+}
+
+#[derive(Component)]
+pub struct HandoffScreen;
+
+#[derive(Component)]
+pub struct HandoffConfirmButton;
+
+pub fn hotseat_handoff_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<UserSettings>,
+    (remote, pbf, split): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, Res<SplitScreen>),
+    current_state: Res<State<GameState>>,
+    turn_order: Res<TurnOrder>,
+    players: Query<&Player>,
+    mut handoff: ResMut<Handoff>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<HandoffConfirmButton>)>,
+    screens: Query<Entity, With<HandoffScreen>>,
+) {
+    let humans = players.iter().filter(|p| !p.is_ai).count();
+    let in_game = matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+    // Online and play-by-file games already keep each seat on its own machine
+    let applicable = settings.hotseat_handoff && remote.is_none() && pbf.is_none() && !split.active() && humans >= 2 && in_game;
+    if !applicable {
+        if *handoff != Handoff::default() {
+            *handoff = Handoff::default();
+        }
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if matches!(current_state.get(), GameState::Summer | GameState::Winter) {
+        let on_turn = turn_order.players.get(turn_order.current_player).copied()
+            .filter(|id| players.iter().any(|p| p.id == *id && !p.is_ai));
+        handoff.turn_changed(on_turn);
+    }
+
+    let Some(next) = handoff.pending else {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Space) || buttons.iter().any(|i| *i == Interaction::Pressed) {
+        handoff.confirm();
+        return;
+    }
+    if !screens.is_empty() {
+        return;
+    }
+
+    let name = players.iter().find(|p| p.id == next).map_or_else(|| format!("Player {}", next.0 + 1), |p| p.name.clone());
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.05, 0.05, 0.08, 1.0)).into(),
+            focus_policy: FocusPolicy::Block,
+            z_index: UiLayer::Handoff.z_index(),
+            ..default()
+        },
+        HandoffScreen,
+    )).with_children(|screen| {
+        screen.spawn(TextBundle::from_section(
+            format!("🔒 Pass the device to {}\n\nThe other players' cards are hidden.\nPress SPACE or the button when {} has it.", name, name),
+            TextStyle {
+                font_size: 28.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+        screen.spawn((
+            ButtonBundle {
+                style: Style {
+                    margin: UiRect::top(Val::Px(30.0)),
+                    padding: UiRect::axes(Val::Px(24.0), Val::Px(12.0)),
+                    ..default()
+                },
+                background_color: Color::from(Srgba::new(0.2, 0.4, 0.2, 1.0)).into(),
+                ..default()
+            },
+            HandoffConfirmButton,
+        )).with_children(|button| {
+            button.spawn(TextBundle::from_section(
+                format!("I'm {}, show my cards", name),
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
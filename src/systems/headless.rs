@@ -0,0 +1,303 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::systems::agents::*;
+use crate::systems::ai::AiRules;
+
+// Batch balance runs without a window: `--headless --games N` plays AI-only games through
+// the headless rules engine as fast as the loop turns, then writes the totals as JSON and
+// CSV in the working directory and exits. With `--variants` the same games are played again
+// under each variant, so AI quality with house rules on is measured rather than assumed.
+
+pub const DEFAULT_BATCH_GAMES: u32 = 1000;
+pub const DEFAULT_REPORT_STEM: &str = "balance_report";
+// Games per frame, so progress is logged while a long batch runs
+const GAMES_PER_FRAME: u32 = 25;
+
+// `--headless [--games N] [--agents a,b,..] [--seed S] [--report stem] [--variants]`
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct HeadlessBatch {
+    pub games: u32,
+    // Registered agent names, one per seat; seats rotate every game
+    pub agents: Vec<String>,
+    pub seed: u64,
+    pub report: String,
+    // Also play every game under each rule set of variant_rule_sets
+    pub variants: bool,
+}
+
+// The variant matrix: each rule-changing setting on its own, then all of them together. The
+// rules AiRules leaves out don't touch placements, so they have nothing to measure here.
+pub fn variant_rule_sets() -> Vec<(&'static str, AiRules)> {
+    let base = AiRules::default();
+    vec![
+        ("worker_upkeep", AiRules { worker_upkeep: true, ..base }),
+        ("wine_festival", AiRules { wine_festival: true, ..base }),
+        ("sparkling_aging", AiRules { sparkling_aging: true, ..base }),
+        ("bonus_chits", AiRules { bonus_chits: true, ..base }),
+        ("short_game", AiRules { max_years: 5, target_victory_points: 15, ..base }),
+        ("all_variants", AiRules { worker_upkeep: true, wine_festival: true, sparkling_aging: true, bonus_chits: true, ..base }),
+    ]
+}
+
+pub fn parse_headless_args(args: &[String]) -> Option<HeadlessBatch> {
+    if !args.iter().any(|a| a == "--headless") {
+        return None;
+    }
+    let value_after = |flag: &str| args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1)).filter(|v| !v.starts_with("--"));
+    let agents: Vec<String> = value_after("--agents")
+        .map(|list| list.split(',').map(|a| a.trim().to_string()).filter(|a| !a.is_empty()).collect())
+        .filter(|list: &Vec<String>| (2..=6).contains(&list.len()))
+        .unwrap_or_else(|| vec!["intermediate".to_string(), "beginner".to_string()]);
+    Some(HeadlessBatch {
+        games: value_after("--games").and_then(|n| n.parse().ok()).filter(|n| *n > 0).unwrap_or(DEFAULT_BATCH_GAMES),
+        agents,
+        seed: value_after("--seed").and_then(|s| s.parse().ok()).unwrap_or(0),
+        report: value_after("--report").cloned().unwrap_or_else(|| DEFAULT_REPORT_STEM.to_string()),
+        variants: args.iter().any(|a| a == "--variants"),
+    })
+}
+
+impl HeadlessBatch {
+    // Agent names by seat for one game; rotating spreads the first-player advantage evenly
+    pub fn seating(&self, game: u32) -> Vec<String> {
+        let n = self.agents.len();
+        (0..n).map(|seat| self.agents[(seat + game as usize) % n].clone()).collect()
+    }
+
+    // Base rules first, then the variant matrix when it was asked for
+    pub fn rule_sets(&self) -> Vec<(&'static str, AiRules)> {
+        let mut sets = vec![("base", AiRules::default())];
+        if self.variants {
+            sets.extend(variant_rule_sets());
+        }
+        sets
+    }
+
+    pub fn total_games(&self) -> u32 {
+        self.games * self.rule_sets().len() as u32
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AgentSummary {
+    pub agent: String,
+    pub games: u32,
+    pub wins: u32,
+    pub win_rate: f32,
+    pub average_vp: f32,
+    pub average_lira: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SeatSummary {
+    // Counted from 1
+    pub seat: u8,
+    pub wins: u32,
+    pub win_rate: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BatchReport {
+    pub games: u32,
+    pub players: u8,
+    pub seed: u64,
+    pub average_steps: f32,
+    // Games stopped at the step cap before anyone won
+    pub unfinished: u32,
+    pub agents: Vec<AgentSummary>,
+    pub seats: Vec<SeatSummary>,
+    // The same games under each variant; empty unless the batch ran with --variants
+    #[serde(default)]
+    pub variants: Vec<VariantSummary>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VariantSummary {
+    pub variant: String,
+    pub games: u32,
+    pub average_steps: f32,
+    pub unfinished: u32,
+    pub agents: Vec<AgentSummary>,
+}
+
+impl BatchReport {
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("kind,name,games,wins,win_rate,average_vp,average_lira\n");
+        for a in &self.agents {
+            csv.push_str(&format!("agent,{},{},{},{:.4},{:.2},{:.2}\n", a.agent, a.games, a.wins, a.win_rate, a.average_vp, a.average_lira));
+        }
+        for s in &self.seats {
+            csv.push_str(&format!("seat,{},{},{},{:.4},,\n", s.seat, self.games, s.wins, s.win_rate));
+        }
+        for v in &self.variants {
+            for a in &v.agents {
+                csv.push_str(&format!("{},{},{},{},{:.4},{:.2},{:.2}\n", v.variant, a.agent, a.games, a.wins, a.win_rate, a.average_vp, a.average_lira));
+            }
+        }
+        csv
+    }
+
+    // `<stem>.json` and `<stem>.csv`
+    pub fn write(&self, stem: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(format!("{}.json", stem), json).map_err(|e| e.to_string())?;
+        std::fs::write(format!("{}.csv", stem), self.to_csv()).map_err(|e| e.to_string())
+    }
+}
+
+fn rate(wins: u32, games: u32) -> f32 {
+    if games == 0 { 0.0 } else { wins as f32 / games as f32 }
+}
+
+#[derive(Clone, Default)]
+struct AgentTotals {
+    games: u32,
+    wins: u32,
+    vp: u32,
+    lira: u32,
+}
+
+// Running totals while the batch plays
+#[derive(Resource, Default)]
+pub struct BatchTally {
+    pub played: u32,
+    steps: usize,
+    unfinished: u32,
+    agents: Vec<(String, AgentTotals)>,
+    seat_wins: Vec<u32>,
+    // Games played under each variant, in the order they started
+    variants: Vec<(String, BatchTally)>,
+}
+
+impl BatchTally {
+    pub fn record(&mut self, result: &AgentMatchResult) {
+        self.played += 1;
+        self.steps += result.steps;
+        if result.steps >= AGENT_MATCH_MAX_STEPS {
+            self.unfinished += 1;
+        }
+        if self.seat_wins.len() < result.standings.len() {
+            self.seat_wins.resize(result.standings.len(), 0);
+        }
+
+        for (place, (name, player)) in result.standings.iter().enumerate() {
+            let index = match self.agents.iter().position(|(n, _)| n == name) {
+                Some(index) => index,
+                None => {
+                    self.agents.push((name.clone(), AgentTotals::default()));
+                    self.agents.len() - 1
+                }
+            };
+            let totals = &mut self.agents[index].1;
+            totals.games += 1;
+            totals.vp += player.victory_points.get() as u32;
+            totals.lira += player.lira.get() as u32;
+            if place == 0 {
+                totals.wins += 1;
+                if let Some(wins) = self.seat_wins.get_mut(player.id.0 as usize) {
+                    *wins += 1;
+                }
+            }
+        }
+    }
+
+    // A game played under a variant; base-rules games go through record
+    pub fn record_variant(&mut self, variant: &str, result: &AgentMatchResult) {
+        let index = match self.variants.iter().position(|(name, _)| name == variant) {
+            Some(index) => index,
+            None => {
+                self.variants.push((variant.to_string(), BatchTally::default()));
+                self.variants.len() - 1
+            }
+        };
+        self.variants[index].1.record(result);
+    }
+
+    // Games recorded under every rule set
+    pub fn total_played(&self) -> u32 {
+        self.played + self.variants.iter().map(|(_, t)| t.played).sum::<u32>()
+    }
+
+    fn average_steps(&self) -> f32 {
+        if self.played == 0 { 0.0 } else { self.steps as f32 / self.played as f32 }
+    }
+
+    fn agent_summaries(&self) -> Vec<AgentSummary> {
+        self.agents.iter().map(|(agent, t)| AgentSummary {
+            agent: agent.clone(),
+            games: t.games,
+            wins: t.wins,
+            win_rate: rate(t.wins, t.games),
+            average_vp: rate(t.vp, t.games),
+            average_lira: rate(t.lira, t.games),
+        }).collect()
+    }
+
+    pub fn report(&self, batch: &HeadlessBatch) -> BatchReport {
+        BatchReport {
+            games: self.played,
+            players: batch.agents.len() as u8,
+            seed: batch.seed,
+            average_steps: self.average_steps(),
+            unfinished: self.unfinished,
+            agents: self.agent_summaries(),
+            seats: self.seat_wins.iter().enumerate().map(|(seat, wins)| SeatSummary {
+                seat: seat as u8 + 1,
+                wins: *wins,
+                win_rate: rate(*wins, self.played),
+            }).collect(),
+            variants: self.variants.iter().map(|(variant, t)| VariantSummary {
+                variant: variant.clone(),
+                games: t.played,
+                average_steps: t.average_steps(),
+                unfinished: t.unfinished,
+                agents: t.agent_summaries(),
+            }).collect(),
+        }
+    }
+}
+
+// Plays the next games of the batch; once all are in, writes the report and exits
+pub fn headless_batch_system(
+    batch: Res<HeadlessBatch>,
+    registry: Res<AgentRegistry>,
+    mut tally: ResMut<BatchTally>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let rule_sets = batch.rule_sets();
+    let total = batch.total_games();
+    let start = tally.total_played();
+    let end = (start + GAMES_PER_FRAME).min(total);
+    for index in start..end {
+        // Every rule set replays the same seeds, so the variants are compared game for game
+        let (variant, rules) = rule_sets[(index / batch.games) as usize];
+        let game = index % batch.games;
+        let agents: Option<Vec<Box<dyn Agent>>> = batch.seating(game).iter().map(|name| registry.create(name)).collect();
+        let Some(mut agents) = agents else {
+            error!("Unknown agent in {:?}; known agents: {:?}", batch.agents, registry.names());
+            exit.send(AppExit::error());
+            return;
+        };
+        let result = run_agent_match(&rules, batch.seed.wrapping_add(game as u64), &mut agents);
+        if index < batch.games {
+            tally.record(&result);
+        } else {
+            tally.record_variant(variant, &result);
+        }
+    }
+
+    if end < total {
+        if end % 250 == 0 {
+            info!("🧪 {}/{} games", end, total);
+        }
+        return;
+    }
+
+    let report = tally.report(&batch);
+    match report.write(&batch.report) {
+        Ok(()) => info!("🧪 {} games done; report written to {}.json and {}.csv", total, batch.report, batch.report),
+        Err(e) => error!("Couldn't write the batch report: {}", e),
+    }
+    exit.send(AppExit::Success);
+}
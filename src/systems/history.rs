@@ -0,0 +1,423 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::expansions::ExpansionSettings;
+use crate::systems::game_log::GameLog;
+use crate::systems::keymap::*;
+use crate::systems::lobby::MAX_PLAYERS;
+use crate::systems::save::{create_save_data, GameSnapshot, SaveData};
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+pub const HISTORY_DIR: &str = "history";
+
+// Lines of the archived log shown in the viewer; the full log stays in the file
+const VIEWER_LOG_LINES: usize = 40;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum GameResult {
+    Won,
+    Lost,
+    AiOnly,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Standing {
+    pub name: String,
+    pub victory_points: u8,
+    pub lira: u8,
+    pub is_ai: bool,
+}
+
+// One finished game as written to history/game_<timestamp>.json
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ArchivedGame {
+    pub timestamp: i64,
+    pub seed: Option<u64>,
+    pub player_count: u8,
+    pub ai_count: u8,
+    pub years_played: u8,
+    pub result: GameResult,
+    pub standings: Vec<Standing>,
+    pub settings: UserSettings,
+    pub final_state: SaveData,
+    pub log: String,
+}
+
+impl ArchivedGame {
+    pub fn date_label(&self) -> String {
+        chrono::DateTime::from_timestamp(self.timestamp, 0)
+            .map(|d| d.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "unknown date".to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum DateFilter {
+    #[default]
+    All,
+    LastWeek,
+    Today,
+}
+
+impl DateFilter {
+    fn next(self) -> Self {
+        match self {
+            DateFilter::All => DateFilter::LastWeek,
+            DateFilter::LastWeek => DateFilter::Today,
+            DateFilter::Today => DateFilter::All,
+        }
+    }
+
+    fn max_age_secs(self) -> Option<i64> {
+        match self {
+            DateFilter::All => None,
+            DateFilter::LastWeek => Some(7 * 24 * 3600),
+            DateFilter::Today => Some(24 * 3600),
+        }
+    }
+}
+
+// Past Games screen: archives loaded from disk, active filters and the game open in the viewer
+#[derive(Resource, Default)]
+pub struct PastGames {
+    pub open: bool,
+    pub games: Vec<ArchivedGame>,
+    pub player_filter: Option<u8>,
+    pub result_filter: Option<GameResult>,
+    pub date_filter: DateFilter,
+    pub viewing: Option<usize>,
+}
+
+impl PastGames {
+    pub fn filtered(&self, now: i64) -> Vec<usize> {
+        self.games.iter().enumerate()
+            .filter(|(_, g)| self.player_filter.map_or(true, |count| g.player_count == count))
+            .filter(|(_, g)| self.result_filter.map_or(true, |result| g.result == result))
+            .filter(|(_, g)| self.date_filter.max_age_secs().map_or(true, |age| now - g.timestamp <= age))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+#[derive(Component)]
+pub struct PastGamesPanel;
+
+#[derive(Component, Clone, Copy)]
+pub enum PastGamesButton {
+    PlayerFilter,
+    ResultFilter,
+    DateFilter,
+    Open(usize),
+    Back,
+}
+
+pub fn load_archived_games() -> Vec<ArchivedGame> {
+    let Ok(dir) = std::fs::read_dir(HISTORY_DIR) else { return Vec::new() };
+
+    let mut games: Vec<ArchivedGame> = dir
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| {
+            let json = std::fs::read_to_string(entry.path()).ok()?;
+            match serde_json::from_str(&json) {
+                Ok(game) => Some(game),
+                Err(e) => {
+                    warn!("Skipping unreadable archive {:?}: {}", entry.path(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    games.sort_by_key(|g| std::cmp::Reverse(g.timestamp));
+    games
+}
+
+// Writes every completed game to the history folder once, on entering GameOver
+pub fn archive_finished_game_system(
+    snapshot: GameSnapshot,
+    (log, expansions, settings): (Res<GameLog>, Res<ExpansionSettings>, Res<UserSettings>),
+) {
+    if !snapshot.current_state.is_changed() || !matches!(snapshot.current_state.get(), GameState::GameOver) {
+        return;
+    }
+    let (players, vineyards, config) = (&snapshot.players, &snapshot.vineyards, &snapshot.config);
+
+    let final_state = match create_save_data(&snapshot) {
+        Ok(data) => data,
+        Err(e) => {
+            warn!("Could not archive game: {}", e);
+            return;
+        }
+    };
+
+    let mut sorted: Vec<Player> = players.iter().cloned().collect();
+    sorted.sort_by_key(|p| p.id.0);
+    let all_vineyards: Vec<Vineyard> = vineyards.iter().cloned().collect();
+
+    let top_vp = sorted.iter().map(|p| p.victory_points.get()).max().unwrap_or(0);
+    let result = match sorted.iter().find(|p| !p.is_ai) {
+        Some(human) if human.victory_points == top_vp => GameResult::Won,
+        Some(_) => GameResult::Lost,
+        None => GameResult::AiOnly,
+    };
+
+    let mut standings: Vec<Standing> = sorted.iter().map(|p| Standing {
+        name: p.name.clone(),
+        victory_points: p.victory_points.get(),
+        lira: p.lira.get(),
+        is_ai: p.is_ai,
+    }).collect();
+    standings.sort_by_key(|s| std::cmp::Reverse((s.victory_points, s.lira)));
+
+    let timestamp = chrono::Utc::now().timestamp();
+    let archive = ArchivedGame {
+        timestamp,
+        seed: log.seed,
+        player_count: config.player_count,
+        ai_count: config.ai_count,
+        years_played: config.current_year,
+        result,
+        standings,
+        settings: settings.clone(),
+        final_state,
+        log: log.to_markdown(config, &expansions, &settings, &sorted, &all_vineyards),
+    };
+
+    let path = format!("{}/game_{}.json", HISTORY_DIR, timestamp);
+    let written = std::fs::create_dir_all(HISTORY_DIR)
+        .map_err(|e| e.to_string())
+        .and_then(|_| serde_json::to_string_pretty(&archive).map_err(|e| e.to_string()))
+        .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+
+    match written {
+        Ok(()) => info!("🗄️ Game archived to {}", path),
+        Err(e) => warn!("Failed to archive game: {}", e),
+    }
+}
+
+// The archived final board, one block per vineyard: fields, crush pad, cellar, buildings and
+// the cards left in hand
+pub fn final_board_text(save: &SaveData) -> String {
+    let mut players: Vec<_> = save.players.iter().collect();
+    players.sort_by_key(|p| p.id);
+
+    let mut text = String::new();
+    for player in players {
+        text.push_str(&format!("{}{}: {} VP, {} lira\n", player.name, if player.is_ai { " (AI)" } else { "" }, player.victory_points, player.lira));
+        if let Some(vineyard) = save.vineyards.iter().find(|v| v.owner_id == player.id) {
+            let fields: Vec<String> = vineyard.fields.iter()
+                .map(|field| match field.as_ref().and_then(|f| f.vine.as_ref()) {
+                    Some(vine) => format!("{}{}", if vine.is_red { "R" } else { "W" }, vine.value),
+                    None => "-".to_string(),
+                })
+                .collect();
+            text.push_str(&format!("  Fields: {}\n", fields.join(" ")));
+            text.push_str(&format!("  Crush pad: red {:?}, white {:?}\n", vineyard.red_grape_tokens, vineyard.white_grape_tokens));
+            text.push_str(&format!("  Cellar: red {:?}, white {:?}\n", vineyard.red_wine_tokens, vineyard.white_wine_tokens));
+        }
+        let buildings: Vec<String> = save.structures.iter()
+            .filter(|s| s.owner_id == player.id)
+            .map(|s| format!("{:?}", s.structure_type))
+            .collect();
+        if !buildings.is_empty() {
+            text.push_str(&format!("  Buildings: {}\n", buildings.join(", ")));
+        }
+        if let Some(hand) = save.hands.iter().find(|h| h.owner_id == player.id) {
+            text.push_str(&format!("  Hand: {} vine, {} order cards\n", hand.vine_cards.len(), hand.wine_order_cards.len()));
+        }
+    }
+    text
+}
+
+// Ctrl+H on the main menu opens the Past Games list; clicking a game opens it in the viewer
+pub fn past_games_system(
+    mut commands: Commands,
+    input: BoundInput,
+    current_state: Res<State<GameState>>,
+    mut past_games: ResMut<PastGames>,
+    buttons: Query<(&Interaction, &PastGamesButton), Changed<Interaction>>,
+    panels: Query<Entity, With<PastGamesPanel>>,
+) {
+    let on_menu = matches!(current_state.get(), GameState::MainMenu);
+
+    if input.just_pressed(InputAction::PastGames) && on_menu {
+        past_games.open = !past_games.open;
+        past_games.viewing = None;
+        if past_games.open {
+            past_games.games = load_archived_games();
+        }
+    } else if past_games.open && !on_menu {
+        past_games.open = false;
+    }
+
+    if let Some(button) = buttons.iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| *button)
+    {
+        match button {
+            PastGamesButton::PlayerFilter => {
+                past_games.player_filter = match past_games.player_filter {
+                    None => Some(1),
+                    Some(count) if count < MAX_PLAYERS => Some(count + 1),
+                    Some(_) => None,
+                };
+            }
+            PastGamesButton::ResultFilter => {
+                past_games.result_filter = match past_games.result_filter {
+                    None => Some(GameResult::Won),
+                    Some(GameResult::Won) => Some(GameResult::Lost),
+                    Some(GameResult::Lost) => Some(GameResult::AiOnly),
+                    Some(GameResult::AiOnly) => None,
+                };
+            }
+            PastGamesButton::DateFilter => past_games.date_filter = past_games.date_filter.next(),
+            PastGamesButton::Open(index) => past_games.viewing = Some(index),
+            PastGamesButton::Back => past_games.viewing = None,
+        }
+    }
+
+    if !past_games.is_changed() {
+        return;
+    }
+
+    for entity in panels.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !past_games.open {
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Percent(20.0),
+                width: Val::Percent(60.0),
+                max_height: Val::Percent(85.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.08, 0.12, 0.97)).into(),
+            z_index: UiLayer::Screen.z_index(),
+            ..default()
+        },
+        PastGamesPanel,
+    )).with_children(|panel| {
+        match past_games.viewing.and_then(|i| past_games.games.get(i)) {
+            Some(game) => spawn_game_viewer(panel, game),
+            None => spawn_game_list(panel, &past_games),
+        }
+    });
+}
+
+fn spawn_game_list(panel: &mut ChildBuilder, past_games: &PastGames) {
+    spawn_text(panel, "🗄️ PAST GAMES (Ctrl+H to close)".to_string(), 20.0);
+
+    panel.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            margin: UiRect::vertical(Val::Px(8.0)),
+            ..default()
+        },
+        ..default()
+    }).with_children(|filters| {
+        let players = past_games.player_filter.map_or("any".to_string(), |c| c.to_string());
+        let result = past_games.result_filter.map_or("any".to_string(), |r| format!("{:?}", r));
+        spawn_button(filters, format!("Players: {}", players), PastGamesButton::PlayerFilter);
+        spawn_button(filters, format!("Result: {}", result), PastGamesButton::ResultFilter);
+        spawn_button(filters, format!("Date: {:?}", past_games.date_filter), PastGamesButton::DateFilter);
+    });
+
+    let visible = past_games.filtered(chrono::Utc::now().timestamp());
+    if visible.is_empty() {
+        spawn_text(panel, "No archived games match these filters".to_string(), 14.0);
+    }
+
+    for index in visible {
+        let game = &past_games.games[index];
+        let winner = game.standings.first().map_or("-".to_string(), |s| format!("{} ({} VP)", s.name, s.victory_points));
+        spawn_button(
+            panel,
+            format!("{}  |  {} players ({} AI)  |  {:?}  |  Winner: {}", game.date_label(), game.player_count, game.ai_count, game.result, winner),
+            PastGamesButton::Open(index),
+        );
+    }
+}
+
+fn spawn_game_viewer(panel: &mut ChildBuilder, game: &ArchivedGame) {
+    spawn_button(panel, "← Back to list".to_string(), PastGamesButton::Back);
+
+    let mut text = format!(
+        "{}  |  {} years  |  seed {}\n\n",
+        game.date_label(),
+        game.years_played,
+        game.seed.map_or("not recorded".to_string(), |s| format!("{:#x}", s)),
+    );
+    for (place, standing) in game.standings.iter().enumerate() {
+        text.push_str(&format!("{}. {}{}: {} VP, {} lira\n", place + 1, standing.name, if standing.is_ai { " (AI)" } else { "" }, standing.victory_points, standing.lira));
+    }
+    spawn_text(panel, text, 13.0);
+
+    let mut log = String::new();
+    for line in game.log.lines().take(VIEWER_LOG_LINES) {
+        log.push_str(line);
+        log.push('\n');
+    }
+    if game.log.lines().count() > VIEWER_LOG_LINES {
+        log.push_str(&format!("… full log in {}/game_{}.json", HISTORY_DIR, game.timestamp));
+    }
+
+    // The final board beside the log
+    panel.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(24.0),
+            margin: UiRect::top(Val::Px(8.0)),
+            ..default()
+        },
+        ..default()
+    }).with_children(|columns| {
+        spawn_text(columns, format!("FINAL BOARD\n{}", final_board_text(&game.final_state)), 13.0);
+        spawn_text(columns, format!("LOG\n{}", log), 13.0);
+    });
+}
+
+fn spawn_text(parent: &mut ChildBuilder, text: String, font_size: f32) {
+    parent.spawn(TextBundle::from_section(
+        text,
+        TextStyle {
+            font_size,
+            color: Color::WHITE,
+            ..default()
+        },
+    ));
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: String, button: PastGamesButton) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                margin: UiRect::all(Val::Px(4.0)),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.25, 0.2, 0.3, 1.0)).into(),
+            ..default()
+        },
+        button,
+    )).with_children(|b| {
+        b.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
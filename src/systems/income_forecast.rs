@@ -0,0 +1,220 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::handoff::Handoff;
+use crate::systems::keymap::*;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::settings::UserSettings;
+use crate::systems::upkeep::UPKEEP_FREE_WORKERS;
+use crate::systems::render_layers::UiLayer;
+
+// Ctrl+U panel projecting the lira each player can count on over the next years: residual
+// payments, standing income, Yoke harvests and worker upkeep. The viewing player sees their
+// own sources; opponents show as totals only.
+
+pub const FORECAST_YEARS: u8 = 3;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForecastLine {
+    pub source: String,
+    // One entry per forecast year
+    pub per_year: Vec<i16>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncomeForecast {
+    pub player: PlayerId,
+    // Calendar years covered; fewer than FORECAST_YEARS near the end of the game
+    pub years: Vec<u8>,
+    pub lines: Vec<ForecastLine>,
+}
+
+impl IncomeForecast {
+    pub fn yearly_totals(&self) -> Vec<i16> {
+        (0..self.years.len()).map(|i| self.lines.iter().map(|l| l.per_year[i]).sum()).collect()
+    }
+
+    pub fn total(&self) -> i16 {
+        self.yearly_totals().iter().sum()
+    }
+}
+
+// What one player's income depends on
+pub struct ForecastInputs<'a> {
+    pub player: &'a Player,
+    pub vineyard: &'a Vineyard,
+    pub tracker: Option<&'a ResidualPaymentTracker>,
+    pub residual_incomes: &'a [&'a ResidualIncome],
+    pub structures: &'a [Structure],
+    pub worker_upkeep: bool,
+}
+
+// Each coming year collects its spring payments and its harvest, and pays upkeep for the
+// year just ended; years past the last one never come
+pub fn forecast_income(inputs: &ForecastInputs, current_year: u8, max_years: u8) -> IncomeForecast {
+    let owner = inputs.player.id;
+    let last = current_year.saturating_add(FORECAST_YEARS).min(max_years);
+    let years: Vec<u8> = (current_year + 1..=last).collect();
+    let mut lines = Vec::new();
+    let mut add = |source: String, amount: i16| {
+        if amount != 0 {
+            lines.push(ForecastLine { source, per_year: vec![amount; years.len()] });
+        }
+    };
+
+    add("Residual payments".to_string(), inputs.tracker.map_or(0, |t| t.annual_income() as i16));
+    for income in inputs.residual_incomes.iter().filter(|i| i.owner == owner) {
+        add(income.source.clone(), income.amount as i16);
+    }
+    let has_yoke = inputs.structures.iter().any(|s| s.owner == owner && s.structure_type == StructureType::Yoke);
+    if has_yoke && inputs.vineyard.fields.iter().any(|f| f.vine.is_some()) {
+        add("Yoke harvests".to_string(), 1);
+    }
+    if inputs.worker_upkeep {
+        add("Worker upkeep".to_string(), -(inputs.player.workers.saturating_sub(UPKEEP_FREE_WORKERS) as i16));
+    }
+
+    IncomeForecast { player: owner, years, lines }
+}
+
+fn signed(amount: i16) -> String {
+    if amount > 0 { format!("+{}", amount) } else { amount.to_string() }
+}
+
+fn per_year(amounts: &[i16]) -> String {
+    amounts.iter().map(|a| signed(*a)).collect::<Vec<_>>().join(" / ")
+}
+
+// `own` gets every source; everyone else only their total
+pub fn forecast_text(own: Option<(&str, &IncomeForecast)>, opponents: &[(&str, &IncomeForecast)]) -> String {
+    let years = own.map(|(_, f)| f).or_else(|| opponents.first().map(|(_, f)| *f)).map_or(&[][..], |f| f.years.as_slice());
+    let Some((first, last)) = years.first().zip(years.last()) else {
+        return "📈 INCOME FORECAST\n\nNo further years: the game ends this year.".to_string();
+    };
+
+    let mut text = if first == last {
+        format!("📈 INCOME FORECAST\nYear {}\n", first)
+    } else {
+        format!("📈 INCOME FORECAST\nYears {}-{}\n", first, last)
+    };
+
+    if let Some((name, forecast)) = own {
+        text.push_str(&format!("\n{}\n", name));
+        if forecast.lines.is_empty() {
+            text.push_str("  No income beyond your actions\n");
+        }
+        for line in &forecast.lines {
+            text.push_str(&format!("  {}: {}\n", line.source, per_year(&line.per_year)));
+        }
+        text.push_str(&format!("  Total: {} ({} lira)\n", per_year(&forecast.yearly_totals()), signed(forecast.total())));
+    }
+
+    if !opponents.is_empty() {
+        text.push_str(if own.is_some() { "\nOpponents\n" } else { "\nPlayers\n" });
+        for (name, forecast) in opponents {
+            text.push_str(&format!("  {}: {} lira\n", name, signed(forecast.total())));
+        }
+    }
+    text
+}
+
+#[derive(Resource, Default)]
+pub struct IncomeForecastState {
+    pub open: bool,
+}
+
+#[derive(Component)]
+pub struct IncomeForecastPanel;
+
+// Ctrl+U toggles the panel during a game; it is rebuilt whenever a forecast changes
+pub fn income_forecast_system(
+    mut commands: Commands,
+    input: BoundInput,
+    mut state: ResMut<IncomeForecastState>,
+    (current_state, config, settings, turn_order): (Res<State<GameState>>, Res<GameConfig>, Res<UserSettings>, Res<TurnOrder>),
+    (remote, pbf, handoff): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, Res<Handoff>),
+    (players, vineyards, trackers, residual_incomes, structures): (Query<&Player>, Query<&Vineyard>, Query<&ResidualPaymentTracker>, Query<&ResidualIncome>, Query<&Structure>),
+    existing: Query<Entity, With<IncomeForecastPanel>>,
+    mut last_text: Local<String>,
+) {
+    if input.just_pressed(InputAction::IncomeForecast) {
+        state.open = !state.open;
+    }
+
+    let in_game = matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+    if !state.open || !in_game {
+        if !existing.is_empty() {
+            for entity in existing.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            last_text.clear();
+        }
+        return;
+    }
+
+    // Whose details are shown: this machine's seat online, otherwise the human on turn or
+    // the last one shown; nobody's while the device is being passed
+    let human = |id: PlayerId| players.iter().any(|p| p.id == id && !p.is_ai);
+    let viewer = remote.map(|r| r.local_player)
+        .or(pbf.map(|p| p.local_player))
+        .or_else(|| turn_order.players.get(turn_order.current_player).copied().filter(|id| human(*id)))
+        .or(handoff.last_shown)
+        .filter(|_| !handoff.hides_hand());
+
+    let residual_incomes: Vec<&ResidualIncome> = residual_incomes.iter().collect();
+    let structures: Vec<Structure> = structures.iter().cloned().collect();
+    let mut sorted: Vec<&Player> = players.iter().collect();
+    sorted.sort_by_key(|p| p.id.0);
+    let forecasts: Vec<(&str, IncomeForecast)> = sorted.iter().copied().filter_map(|player| {
+        let inputs = ForecastInputs {
+            player,
+            vineyard: vineyards.iter().find(|v| v.owner == player.id)?,
+            tracker: trackers.iter().find(|t| t.owner == player.id),
+            residual_incomes: &residual_incomes,
+            structures: &structures,
+            worker_upkeep: settings.house_rule_worker_upkeep,
+        };
+        Some((player.name.as_str(), forecast_income(&inputs, config.current_year, config.max_years)))
+    }).collect();
+
+    let own = forecasts.iter().find(|(_, f)| Some(f.player) == viewer).map(|(name, f)| (*name, f));
+    let others: Vec<(&str, &IncomeForecast)> = forecasts.iter()
+        .filter(|(_, f)| Some(f.player) != viewer)
+        .map(|(name, f)| (*name, f))
+        .collect();
+    let text = forecast_text(own, &others);
+    if *last_text == text && !existing.is_empty() {
+        return;
+    }
+    *last_text = text.clone();
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                right: Val::Px(0.0),
+                width: Val::Px(260.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.12, 0.1, 0.05, 0.92)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        IncomeForecastPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 13.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
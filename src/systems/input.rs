@@ -2,7 +2,6 @@ use bevy::prelude::*;
 use crate::components::*;
 use crate::systems::*;
 use crate::systems::game_logic::*;
-use crate::systems::audio::*;
 
 const GREY: Srgba = Srgba::new(0.6, 0.6, 0.6, 1.0);
 
@@ -11,21 +10,16 @@ pub fn mouse_input_system(
     mouse_input: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
     camera_q: Query<(&Camera, &GlobalTransform)>,
-    mut workers: Query<(Entity, &mut Worker, &Clickable)>,
-    mut action_spaces: Query<(Entity, &mut ActionSpaceSlot, &Clickable), Without<Worker>>,
-    mut hands: Query<&mut Hand>,
-    mut vineyards: Query<&mut Vineyard>,
-    mut players: Query<&mut Player>,
-    mut card_decks: ResMut<CardDecks>,
-    mut commands: Commands,
+    workers: Query<&Worker>,
+    action_spaces: Query<(&ActionSpaceSlot, &Clickable)>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+    players: Query<&Player>,
     turn_order: Res<TurnOrder>,
     current_state: Res<State<GameState>>,
-    audio_assets: Res<AudioAssets>,
-    audio_settings: Res<AudioSettings>,
-    animation_settings: Res<AnimationSettings>,
-    // mut trackers: Query<&mut ResidualPaymentTracker>,
-    (mut trackers, structures) : (Query<&mut ResidualPaymentTracker>, Query<&Structure>),
-    // structures: Query<&Structure>, 
+    (config, mut picker, mut place_events): (Res<GameConfig>, ResMut<HandCardPicker>, EventWriter<ActionRequested>),
+    (keyboard, mut grande_selection, mut field_picker): (Res<ButtonInput<KeyCode>>, ResMut<GrandeSelection>, ResMut<HarvestFieldPicker>),
+    (settings, mut token_picker): (Res<UserSettings>, ResMut<OrderTokenPicker>),
 ) {
     if !mouse_input.just_pressed(MouseButton::Left) {
         return;
@@ -38,113 +32,103 @@ pub fn mouse_input_system(
         let world_pos = camera.viewport_to_world_2d(camera_transform, cursor_pos).unwrap_or(Vec2::ZERO);
         
         if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
-            for (space_entity, mut action_space, clickable) in action_spaces.iter_mut() {
+            let board = RulesState::new(action_spaces.iter().map(|(s, _)| s), current_state.get(), config.player_count)
+                .with_workers(workers.iter());
+            for (action_space, clickable) in action_spaces.iter() {
                 let bounds = Rect::from_center_size(action_space.position, clickable.size);
                 
                 if bounds.contains(world_pos) {
-                    let can_place_regular = action_space.can_place_worker(*current_player_id, current_state.get());
-                    let can_place_grande = action_space.can_place_grande_worker(*current_player_id, current_state.get());
-                    
-                    if can_place_regular || can_place_grande {
-                        let mut selected_worker = None;
-                        
-                        if can_place_regular {
-                            for (worker_entity, worker, _) in workers.iter() {
-                                if worker.owner == *current_player_id && worker.placed_at.is_none() && !worker.is_grande {
-                                    selected_worker = Some((worker_entity, false, worker.position));
-                                    break;
-                                }
-                            }
-                        }
-                        
-                        if selected_worker.is_none() && can_place_grande {
-                            for (worker_entity, worker, _) in workers.iter() {
-                                if worker.owner == *current_player_id && worker.placed_at.is_none() && worker.is_grande {
-                                    selected_worker = Some((worker_entity, true, worker.position));
-                                    break;
-                                }
-                            }
-                        }
-                        
-                        if let Some((worker_entity, is_grande, start_pos)) = selected_worker {
-                            // Animate worker movement
-                            animate_worker_placement(
-                                &mut commands,
-                                worker_entity,
-                                start_pos,
-                                action_space.position,
-                                WorkerAnimationType::Placement,
-                                &animation_settings,
-                            );
-                            
-                            // Update worker state
-                            for (w_entity, mut worker, _) in workers.iter_mut() {
-                                if w_entity == worker_entity {
-                                    worker.placed_at = Some(action_space.action);
-                                    // Position will be updated by animation
-                                    
-                                    if is_grande && action_space.occupied_by.is_some() {
-                                        action_space.bonus_worker_slot = Some(*current_player_id);
-                                    } else {
-                                        action_space.occupied_by = Some(*current_player_id);
-                                    }
-                                    
-                                    crate::systems::audio::play_sfx(&mut commands, &audio_assets, &audio_settings, AudioType::WorkerPlace);
-                                    
-                                    execute_action(
-                                        action_space.action, 
-                                        *current_player_id, 
-                                        &mut hands, 
-                                        &mut vineyards, 
-                                        &mut players, 
-                                        &mut card_decks, 
-                                        &mut commands,
-                                        &mut trackers,
-                                        &structures,
-                                        &audio_assets, 
-                                        &audio_settings,
-                                        &animation_settings,
-                                    );
-                                    
-                                    info!("Player {:?} placed {} worker on {:?}", 
-                                          current_player_id, 
-                                          if is_grande { "grande" } else { "regular" },
-                                          action_space.action);
-                                    break;
-                                }
-                            }
-                            break;
-                        }
+                    // Columns beyond this player count's limit stay closed
+                    if !action_space.is_open(config.player_count) {
+                        continue;
+                    }
+                    // A selected grande (or Shift) skips the regular workers entirely
+                    let wants_grande = grande_selection.wants_grande(*current_player_id, &keyboard);
+                    let Some(legal) = legal_action(&board, *current_player_id, action_space.action) else { continue };
+                    // An occupied column only takes the grande, and only once the row is full
+                    if action_space.occupied_by.is_some() && !legal.blocked {
+                        continue;
                     }
+                    let Some(is_grande) = legal.uses_grande(wants_grande) else { continue };
+                    if is_grande {
+                        grande_selection.armed = None;
+                    }
+                    let event = ActionRequested {
+                        player: *current_player_id,
+                        action: action_space.action,
+                        grande: is_grande,
+                    };
+
+                    // Several cards to choose from: the hand picker places the worker once one is picked
+                    let human = players.iter().any(|p| p.id == *current_player_id && !p.is_ai);
+                    let needs_pick = hands.iter()
+                        .find(|h| h.owner == *current_player_id)
+                        .is_some_and(|h| needs_card_pick(action_space.action, h));
+                    // Likewise when there are more fields to harvest than the action allows
+                    let field_pick = vineyards.iter()
+                        .find(|v| v.owner == *current_player_id)
+                        .filter(|v| needs_field_pick(action_space.action, v));
+                    // And when more than one cellar token could pay the order
+                    let token_pick = hands.iter()
+                        .find(|h| h.owner == *current_player_id)
+                        .zip(vineyards.iter().find(|v| v.owner == *current_player_id))
+                        .filter(|(h, v)| wants_token_pick(&settings, action_space.action, h, v));
+                    if human && needs_pick {
+                        picker.pending = Some(event);
+                    } else if let (true, Some(vineyard)) = (human, field_pick) {
+                        field_picker.open(event, vineyard);
+                    } else if let (true, Some((hand, vineyard))) = (human, token_pick) {
+                        token_picker.open(event, &hand.wine_order_cards[0], vineyard);
+                    } else {
+                        place_events.send(event);
+                    }
+                    break;
                 }
             }
         }
     }
 }
 
+// A worker placement asked for by any source: clicks, the action bar, shortcuts, pickers,
+// AIs, remote players and turn-file replays. Only action_resolution_system acts on it.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ActionRequested {
+    pub player: PlayerId,
+    pub action: ActionSpace,
+    // Place the grande worker; on an occupied space it takes the bonus slot
+    pub grande: bool,
+}
+
+// A placement that went through, for the presentation to animate and sound out
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ActionResolved {
+    pub player: PlayerId,
+    pub action: ActionSpace,
+    pub worker: Entity,
+    // Where the worker stood before it went out
+    pub from: Vec2,
+    pub grande: bool,
+    pub outcome: ActionOutcome,
+}
+
 pub fn ui_button_system(
-    mut interaction_query: Query<(&Interaction, &ActionButton, &mut BackgroundColor)>,
-    mut workers: Query<&mut Worker>,
-    mut action_spaces: Query<&mut ActionSpaceSlot>,
-    mut hands: Query<&mut Hand>,
-    mut vineyards: Query<&mut Vineyard>,
-    mut players: Query<&mut Player>,
-    mut card_decks: ResMut<CardDecks>,
-    mut commands: Commands,
+    mut interaction_query: Query<(&Interaction, &ActionButton, &mut BackgroundColor, Has<GrandeOverride>)>,
+    mut place_events: EventWriter<ActionRequested>,
     turn_order: Res<TurnOrder>,
     current_state: Res<State<GameState>>,
-    audio_assets: Res<AudioAssets>,
-    audio_settings: Res<AudioSettings>,
-    animation_settings: Res<AnimationSettings>,
-    mut trackers: Query<&mut ResidualPaymentTracker>,
-    structures: Query<&Structure>, 
+    mut picker: ResMut<HandCardPicker>,
+    players: Query<&Player>,
+    hands: Query<&Hand>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut grande_selection: ResMut<GrandeSelection>,
+    (vineyards, mut field_picker): (Query<&Vineyard>, ResMut<HarvestFieldPicker>),
+    (settings, mut token_picker): (Res<UserSettings>, ResMut<OrderTokenPicker>),
+    (workers, action_spaces, config): (Query<&Worker>, Query<&ActionSpaceSlot>, Res<GameConfig>),
 ) {
-    for (interaction, action_button, mut color) in &mut interaction_query {
+    for (interaction, action_button, mut color, grande_override) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
-                let is_summer_action = matches!(action_button.action, 
-                    ActionSpace::DrawVine | ActionSpace::PlantVine | ActionSpace::BuildStructure | 
-                    ActionSpace::GiveTour | ActionSpace::SellGrapes | ActionSpace::TrainWorker);
+                let is_summer_action = is_summer_button(action_button.action);
                 let is_valid_season = match current_state.get() {
                     GameState::Summer => is_summer_action,
                     GameState::Winter => !is_summer_action,
@@ -156,28 +140,40 @@ pub fn ui_button_system(
                 }
                 
                 if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
-                    let has_available_worker = workers.iter()
-                        .any(|w| w.owner == *current_player_id && w.placed_at.is_none());
-                    
-                    if has_available_worker {
-                        for mut worker in workers.iter_mut() {
-                            if worker.owner == *current_player_id && worker.placed_at.is_none() {
-                                worker.placed_at = Some(action_button.action);
-                                break;
-                            }
-                        }
-                        
-                        execute_action(action_button.action, *current_player_id, &mut hands, &mut vineyards, &mut players, &mut card_decks, &mut commands, 
-                            &mut trackers,
-                            &structures,
-                            &audio_assets, &audio_settings, &animation_settings);
-                        
-                        for mut space in action_spaces.iter_mut() {
-                            if space.action == action_button.action {
-                                space.occupied_by = Some(*current_player_id);
-                                break;
-                            }
-                        }
+                    let wants_grande = grande_override || grande_selection.wants_grande(*current_player_id, &keyboard);
+                    let board = RulesState::new(action_spaces.iter(), current_state.get(), config.player_count)
+                        .with_workers(workers.iter());
+                    let Some(grande) = legal_action(&board, *current_player_id, action_button.action)
+                        .and_then(|legal| legal.uses_grande(wants_grande)) else {
+                        continue;
+                    };
+                    if grande {
+                        grande_selection.armed = None;
+                    }
+                    let event = ActionRequested {
+                        player: *current_player_id,
+                        action: action_button.action,
+                        grande,
+                    };
+                    let human = players.iter().any(|p| p.id == *current_player_id && !p.is_ai);
+                    let needs_pick = hands.iter()
+                        .find(|h| h.owner == *current_player_id)
+                        .is_some_and(|h| needs_card_pick(action_button.action, h));
+                    let field_pick = vineyards.iter()
+                        .find(|v| v.owner == *current_player_id)
+                        .filter(|v| needs_field_pick(action_button.action, v));
+                    let token_pick = hands.iter()
+                        .find(|h| h.owner == *current_player_id)
+                        .zip(vineyards.iter().find(|v| v.owner == *current_player_id))
+                        .filter(|(h, v)| wants_token_pick(&settings, action_button.action, h, v));
+                    if human && needs_pick {
+                        picker.pending = Some(event);
+                    } else if let (true, Some(vineyard)) = (human, field_pick) {
+                        field_picker.open(event, vineyard);
+                    } else if let (true, Some((hand, vineyard))) = (human, token_pick) {
+                        token_picker.open(event, &hand.wine_order_cards[0], vineyard);
+                    } else {
+                        place_events.send(event);
                     }
                 }
             }
@@ -185,48 +181,213 @@ pub fn ui_button_system(
                 *color = Color::from(Srgba::new(0.9, 0.9, 0.9, 1.0)).into();
             }
             Interaction::None => {
-                let is_summer_action = matches!(action_button.action, 
-                    ActionSpace::DrawVine | ActionSpace::PlantVine | ActionSpace::BuildStructure | 
-                    ActionSpace::GiveTour | ActionSpace::SellGrapes | ActionSpace::TrainWorker);
-                *color = if is_summer_action {
-                    Color::from(Srgba::new(0.8, 0.8, 0.2, 0.8)).into()
+                *color = if grande_override {
+                    Color::from(GRANDE_BUTTON_COLOR).into()
+                } else if is_summer_button(action_button.action) {
+                    Color::from(SUMMER_BUTTON_COLOR).into()
                 } else {
-                    Color::from(Srgba::new(0.2, 0.2, 0.8, 0.8)).into()
+                    Color::from(WINTER_BUTTON_COLOR).into()
                 };
             }
         }
     }
 }
 
+// Validates, applies and logs every requested placement; requests with no legal slot or
+// no idle worker are dropped
+pub fn action_resolution_system(
+    mut place_events: EventReader<ActionRequested>,
+    mut resolved_events: EventWriter<ActionResolved>,
+    mut workers: Query<(Entity, &mut Worker)>,
+    mut action_spaces: Query<&mut ActionSpaceSlot>,
+    mut hands: Query<&mut Hand>,
+    mut vineyards: Query<&mut Vineyard>,
+    mut players: Query<&mut Player>,
+    mut trackers: Query<&mut ResidualPaymentTracker>,
+    structures: Query<&Structure>,
+    mut card_decks: ResMut<CardDecks>,
+    mut log: ResMut<GameLog>,
+    config: Res<GameConfig>,
+    current_state: Res<State<GameState>>,
+) {
+    for event in place_events.read() {
+        // Grande placements need the grande worker; regular ones prefer a regular worker
+        let idle = |w: &Worker, grande: bool| w.owner == event.player && w.placed_at.is_none() && w.is_grande == grande;
+        let use_grande = event.grande || !workers.iter().any(|(_, w)| idle(w, false));
+        
+        let Some((column, on_bonus_slot)) = placement_slot(action_spaces.iter(), event.action, event.player,
+            config.player_count, current_state.get(), use_grande) else {
+            continue;
+        };
+        let Some((worker_entity, mut worker)) = workers.iter_mut().find(|(_, w)| idle(w, use_grande)) else {
+            continue;
+        };
+        let Some(mut space) = action_spaces.iter_mut().find(|s| s.action == event.action && s.column == column) else {
+            continue;
+        };
+        let action_bonus = !on_bonus_slot && space.bonus_free();
+        if on_bonus_slot {
+            space.bonus_worker_slot = Some(event.player);
+        } else {
+            space.occupied_by = Some(event.player);
+        }
+        let from = worker.position;
+        worker.placed_at = Some(event.action);
+        worker.position = space.position;
+
+        let player_structures: Vec<Structure> = structures.iter().filter(|s| s.owner == event.player).cloned().collect();
+        let outcome = resolve_action(
+            event.action,
+            config.current_year,
+            hands.iter_mut().find(|h| h.owner == event.player).as_deref_mut(),
+            vineyards.iter_mut().find(|v| v.owner == event.player).as_deref_mut(),
+            players.iter_mut().find(|p| p.id == event.player).as_deref_mut(),
+            trackers.iter_mut().find(|t| t.owner == event.player).as_deref_mut(),
+            &mut card_decks,
+            &player_structures,
+            action_bonus,
+        );
+        log.record(config.current_year, current_state.get().clone(), event.player, event.action, outcome);
+        info!("Player {:?} placed {} worker on {:?}", event.player, if use_grande { "grande" } else { "regular" }, event.action);
+
+        resolved_events.send(ActionResolved {
+            player: event.player,
+            action: event.action,
+            worker: worker_entity,
+            from,
+            grande: use_grande,
+            outcome,
+        });
+    }
+}
+
+// The player on turn gives up the rest of the season
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PassEvent {
+    pub player: PlayerId,
+}
+
+// Players take turns placing one worker each. A player passes by choice (the End Turn button or
+// key, an AI with nothing left to do) or once out of workers, and sits out the rest of
+// the season; the season ends when everyone has passed.
 pub fn worker_placement_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut turn_order: ResMut<TurnOrder>,
     mut config: ResMut<GameConfig>,
-    players: Query<&Player>,
     workers: Query<&Worker>,
+    players: Query<&Player>,
     current_state: Res<State<GameState>>,
+    mut pass_events: EventReader<PassEvent>,
+    // Workers the player on turn had placed when their turn began
+    mut turn_start: Local<Option<(PlayerId, usize)>>,
+    (mut commands, spaces): (Commands, Query<&ActionSpaceSlot>),
 ) {
-    if keyboard.just_pressed(KeyCode::Enter) {
-        if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
-            let available_workers = workers.iter()
-                .filter(|w| w.owner == *current_player_id && w.placed_at.is_none())
-                .count();
-            
-            if available_workers == 0 || keyboard.just_pressed(KeyCode::Enter) {
-                turn_order.current_player = (turn_order.current_player + 1) % players.iter().count();
-                
-                if turn_order.current_player == 0 {
-                    match current_state.get() {
-                        GameState::Summer => next_state.set(GameState::Fall),
-                        GameState::Winter => {
-                            config.current_year += 1;
-                            next_state.set(GameState::Spring);
-                        },
-                        _ => {}
-                    }
-                }
-            }
+    let Some(current_player_id) = turn_order.players.get(turn_order.current_player).copied() else { return };
+    let placed = |id: PlayerId| workers.iter().filter(|w| w.owner == id && w.placed_at.is_some()).count();
+    let idle = workers.iter().filter(|w| w.owner == current_player_id && w.placed_at.is_none()).count();
+
+    let human = players.iter().any(|p| p.id == current_player_id && !p.is_ai);
+    let passing = pass_events.read().any(|e| e.player == current_player_id);
+
+    // Any placement path counts: clicks, the action bar, shortcuts, AIs and remote players
+    let placed_now = placed(current_player_id);
+    let took_turn = matches!(*turn_start, Some((id, before)) if id == current_player_id && placed_now > before);
+    // A new turn, or an undo/reload that took workers back, starts counting afresh
+    if !matches!(*turn_start, Some((id, before)) if id == current_player_id && placed_now >= before) {
+        *turn_start = Some((current_player_id, placed_now));
+    }
+
+    if passing || idle == 0 {
+        turn_order.pass(current_player_id);
+        info!("⏭ Player {} passes", current_player_id.0 + 1);
+    } else if human && !turn_order.has_passed(current_player_id)
+        && !has_legal_placement(&spaces, &workers, current_player_id, config.player_count, current_state.get())
+    {
+        // Workers left but every space they could use is taken: end the season for them
+        // rather than wait on a click that can never land
+        turn_order.pass(current_player_id);
+        warn!("⛔ Player {} has {} idle worker(s) but no legal placement in {:?}; passing", current_player_id.0 + 1, idle, current_state.get());
+        spawn_season_end_toast(&mut commands, "No legal placements — your season ends");
+    }
+    if !took_turn && !turn_order.has_passed(current_player_id) {
+        return;
+    }
+
+    if turn_order.advance() {
+        let next = turn_order.players[turn_order.current_player];
+        *turn_start = Some((next, placed(next)));
+        return;
+    }
+
+    *turn_start = None;
+    turn_order.start_season();
+    match current_state.get() {
+        GameState::Summer => next_state.set(GameState::Fall),
+        GameState::Winter => {
+            config.current_year += 1;
+            next_state.set(GameState::Spring);
+        },
+        _ => {}
+    }
+}
+
+fn has_legal_placement(
+    spaces: &Query<&ActionSpaceSlot>,
+    workers: &Query<&Worker>,
+    player: PlayerId,
+    player_count: u8,
+    state: &GameState,
+) -> bool {
+    let board = RulesState::new(spaces.iter(), state, player_count).with_workers(workers.iter());
+    !legal_actions(&board, player).is_empty()
+}
+
+#[derive(Component)]
+pub struct SeasonEndToast {
+    pub timer: Timer,
+}
+
+fn spawn_season_end_toast(commands: &mut Commands, message: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Percent(35.0),
+                width: Val::Percent(30.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.45, 0.2, 0.2, 0.95)).into(),
+            z_index: UiLayer::Toast.z_index(),
+            ..default()
+        },
+        SeasonEndToast {
+            timer: GameClock::once(GameTimer::Toast),
+        },
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("⛔ {}", message),
+            TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
+
+pub fn season_end_toast_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut SeasonEndToast), Without<MarkedForDespawn>>,
+) {
+    for (entity, mut toast) in toasts.iter_mut() {
+        toast.timer.tick(time.delta());
+
+        if toast.timer.finished() {
+            commands.entity(entity).insert(MarkedForDespawn);
         }
     }
-}
\ No newline at end of file
+}
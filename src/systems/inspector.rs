@@ -0,0 +1,240 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::game_logic::TemporaryWorker;
+use crate::systems::setup::match_regular_workers;
+use crate::systems::render_layers::UiLayer;
+
+// Dev-only entity inspector (build with `--features inspector`), scoped to game components.
+// F4 toggles the panel, Up/Down picks a row, Left/Right adjusts the selected value.
+#[derive(Resource, Default)]
+pub struct EntityInspector {
+    pub open: bool,
+    pub selected: usize,
+}
+
+#[derive(Component)]
+pub struct InspectorPanel;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InspectorField {
+    VictoryPoints,
+    Lira,
+    Workers,
+    RedGrapes,
+    WhiteGrapes,
+    RedWine,
+    WhiteWine,
+}
+
+struct InspectorRow {
+    entity: Entity,
+    field: Option<InspectorField>,
+    text: String,
+}
+
+fn editable(entity: Entity, field: InspectorField, name: &str, value: u8) -> InspectorRow {
+    InspectorRow { entity, field: Some(field), text: format!("    {}: {}", name, value) }
+}
+
+fn track_row(entity: Entity, field: InspectorField, name: &str, track: &ValueTrack) -> InspectorRow {
+    InspectorRow { entity, field: Some(field), text: format!("    {}: {:?}", name, track.values()) }
+}
+
+fn heading(entity: Entity, text: String) -> InspectorRow {
+    InspectorRow { entity, field: None, text }
+}
+
+fn collect_rows(
+    players: &Query<(Entity, &mut Player)>,
+    vineyards: &Query<(Entity, &mut Vineyard)>,
+    workers: &Query<(Entity, &Worker)>,
+    hands: &Query<(Entity, &Hand)>,
+) -> Vec<InspectorRow> {
+    let mut rows = Vec::new();
+
+    let mut sorted: Vec<_> = players.iter().collect();
+    sorted.sort_by_key(|(_, p)| p.id.0);
+
+    for (entity, player) in sorted {
+        rows.push(heading(entity, format!("{:?} Player {} \"{}\"{}", entity, player.id.0 + 1, player.name, if player.is_ai { " (AI)" } else { "" })));
+        rows.push(editable(entity, InspectorField::VictoryPoints, "victory_points", player.victory_points.get()));
+        rows.push(editable(entity, InspectorField::Lira, "lira", player.lira.get()));
+        rows.push(editable(entity, InspectorField::Workers, "workers", player.workers));
+
+        if let Some((entity, vineyard)) = vineyards.iter().find(|(_, v)| v.owner == player.id) {
+            let planted = vineyard.fields.iter().filter(|f| f.vine.is_some()).count();
+            rows.push(heading(entity, format!("  {:?} Vineyard ({} of 9 fields planted)", entity, planted)));
+            rows.push(track_row(entity, InspectorField::RedGrapes, "red_grapes", &vineyard.red_grapes));
+            rows.push(track_row(entity, InspectorField::WhiteGrapes, "white_grapes", &vineyard.white_grapes));
+            rows.push(track_row(entity, InspectorField::RedWine, "red_wine", &vineyard.red_wine));
+            rows.push(track_row(entity, InspectorField::WhiteWine, "white_wine", &vineyard.white_wine));
+        }
+
+        if let Some((entity, hand)) = hands.iter().find(|(_, h)| h.owner == player.id) {
+            rows.push(heading(entity, format!("  {:?} Hand: {} vines, {} orders", entity, hand.vine_cards.len(), hand.wine_order_cards.len())));
+        }
+
+        for (entity, worker) in workers.iter().filter(|(_, w)| w.owner == player.id) {
+            let placed = worker.placed_at.map_or("available".to_string(), |space| format!("{:?}", space));
+            rows.push(heading(entity, format!("  {:?} {}: {}", entity, if worker.is_grande { "Grande" } else { "Worker" }, placed)));
+        }
+    }
+
+    rows
+}
+
+fn adjust(value: &mut u8, delta: i8, max: u8) {
+    *value = (*value as i16 + delta as i16).clamp(0, max as i16) as u8;
+}
+
+// Lira and VP stay in their own bounds
+fn step<T: std::ops::Add<u8, Output = T> + std::ops::Sub<u8, Output = T>>(value: T, delta: i8) -> T {
+    if delta >= 0 { value + delta as u8 } else { value - delta.unsigned_abs() }
+}
+
+// + adds a token in the lowest free slot, - removes the highest token
+fn adjust_track(track: &mut ValueTrack, delta: i8) {
+    if delta > 0 {
+        if let Some(free) = (1..=MAX_TOKEN_VALUE).find(|v| !track.has(*v)) {
+            track.place(free);
+        }
+    } else {
+        track.take_highest();
+    }
+}
+
+fn apply_edit(
+    row: &InspectorRow,
+    delta: i8,
+    players: &mut Query<(Entity, &mut Player)>,
+    vineyards: &mut Query<(Entity, &mut Vineyard)>,
+) {
+    let Some(field) = row.field else { return };
+
+    match field {
+        InspectorField::VictoryPoints | InspectorField::Lira | InspectorField::Workers => {
+            let Ok((_, mut player)) = players.get_mut(row.entity) else { return };
+            match field {
+                InspectorField::VictoryPoints => player.victory_points = step(player.victory_points, delta),
+                InspectorField::Lira => player.lira = step(player.lira, delta),
+                _ => adjust(&mut player.workers, delta, MAX_WORKERS),
+            }
+        }
+        _ => {
+            let Ok((_, mut vineyard)) = vineyards.get_mut(row.entity) else { return };
+            match field {
+                InspectorField::RedGrapes => adjust_track(&mut vineyard.red_grapes, delta),
+                InspectorField::WhiteGrapes => adjust_track(&mut vineyard.white_grapes, delta),
+                InspectorField::RedWine => adjust_track(&mut vineyard.red_wine, delta),
+                _ => adjust_track(&mut vineyard.white_wine, delta),
+            }
+        }
+    }
+}
+
+pub fn entity_inspector_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut inspector: ResMut<EntityInspector>,
+    mut players: Query<(Entity, &mut Player)>,
+    mut vineyards: Query<(Entity, &mut Vineyard)>,
+    workers: Query<(Entity, &Worker)>,
+    temporary_workers: Query<Entity, With<TemporaryWorker>>,
+    hands: Query<(Entity, &Hand)>,
+    panel: Query<(Entity, &Children), With<InspectorPanel>>,
+    mut texts: Query<&mut Text>,
+) {
+    if keyboard.just_pressed(KeyCode::F4) {
+        inspector.open = !inspector.open;
+        if !inspector.open {
+            for (entity, _) in panel.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+
+    if !inspector.open {
+        return;
+    }
+
+    let rows = collect_rows(&players, &vineyards, &workers, &hands);
+    let editable_rows: Vec<usize> = rows.iter().enumerate()
+        .filter(|(_, row)| row.field.is_some())
+        .map(|(i, _)| i)
+        .collect();
+
+    if editable_rows.is_empty() {
+        inspector.selected = 0;
+    } else {
+        if keyboard.just_pressed(KeyCode::ArrowDown) {
+            inspector.selected = (inspector.selected + 1) % editable_rows.len();
+        }
+        if keyboard.just_pressed(KeyCode::ArrowUp) {
+            inspector.selected = (inspector.selected + editable_rows.len() - 1) % editable_rows.len();
+        }
+        inspector.selected = inspector.selected.min(editable_rows.len() - 1);
+
+        let delta = match (keyboard.just_pressed(KeyCode::ArrowRight), keyboard.just_pressed(KeyCode::ArrowLeft)) {
+            (true, false) => 1,
+            (false, true) => -1,
+            _ => 0,
+        };
+        if delta != 0 {
+            let row = &rows[editable_rows[inspector.selected]];
+            apply_edit(row, delta, &mut players, &mut vineyards);
+            // As in setup, the board holds one worker entity per trained worker
+            if let (Some(InspectorField::Workers), Ok((_, player))) = (row.field, players.get(row.entity)) {
+                let regulars = workers.iter().filter(|(entity, _)| !temporary_workers.contains(*entity));
+                match_regular_workers(&mut commands, player.id, player.workers, regulars);
+            }
+            info!("🔧 Inspector: {:?} {:?} {:+}", row.entity, row.field, delta);
+        }
+    }
+
+    let selected_row = editable_rows.get(inspector.selected).copied();
+    let mut text = String::from("🔧 ENTITY INSPECTOR (F4 to close)\n↑/↓ select  ←/→ adjust\n\n");
+    // Re-read after edits so the panel never lags a frame behind
+    for (i, row) in collect_rows(&players, &vineyards, &workers, &hands).iter().enumerate() {
+        text.push_str(if Some(i) == selected_row { "▶" } else { " " });
+        text.push_str(&row.text);
+        text.push('\n');
+    }
+
+    if let Ok((_, children)) = panel.get_single() {
+        for &child in children.iter() {
+            if let Ok(mut panel_text) = texts.get_mut(child) {
+                panel_text.sections[0].value = text;
+                break;
+            }
+        }
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                right: Val::Px(20.0),
+                width: Val::Px(380.0),
+                max_height: Val::Percent(85.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.05, 0.08, 0.12, 0.95)).into(),
+            z_index: UiLayer::DevTools.z_index(),
+            ..default()
+        },
+        InspectorPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 12.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
@@ -0,0 +1,313 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::systems::notes::PlayerNotes;
+use crate::systems::console::DebugConsole;
+
+// Actions whose bindings live in the keymap (and therefore in the F2 cheat-sheet)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputAction {
+    ContextualHelp,
+    QuickReference,
+    SaveGame,
+    LoadGame,
+    Statistics,
+    Settings,
+    Undo,
+    Redo,
+    EndTurn,
+    ExportLog,
+    AssetDiagnostics,
+    ToggleTuscany,
+    ToggleVisitorCards,
+    ToggleAdvancedBoards,
+    VisitorCard,
+    Pause,
+    SpeedUp,
+    SlowDown,
+    PastGames,
+    Resign,
+    SupplyPanel,
+    Estate,
+    IncomeForecast,
+    CardGallery,
+    PasteTurnCode,
+    Harvest,
+    MakeWine,
+    NextOrder,
+    FillOrder,
+    WakeUpChart,
+    RulesPreset,
+    ContinueGame,
+    NextSeat,
+    SeatDifficulty,
+    SeatHandicap,
+    CoopMode,
+    RepeatChoice,
+    SandboxPlantRed,
+    SandboxPlantWhite,
+    SandboxAddGrapes,
+    SandboxClear,
+    EnterSeed,
+    FinalDecks,
+    NextPile,
+    PreviousPile,
+    Notes,
+    LayerOutlines,
+}
+
+impl InputAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::ContextualHelp => "Contextual Help",
+            InputAction::QuickReference => "Quick Reference",
+            InputAction::SaveGame => "Save Game",
+            InputAction::LoadGame => "Load Game",
+            InputAction::Statistics => "Statistics",
+            InputAction::Settings => "Settings",
+            InputAction::Undo => "Undo",
+            InputAction::Redo => "Redo",
+            InputAction::EndTurn => "End Turn",
+            InputAction::ExportLog => "Export Game Log",
+            InputAction::AssetDiagnostics => "Asset Diagnostics",
+            InputAction::ToggleTuscany => "Toggle Tuscany",
+            InputAction::ToggleVisitorCards => "Toggle Visitor Cards",
+            InputAction::ToggleAdvancedBoards => "Toggle Advanced Boards",
+            InputAction::VisitorCard => "Draw/Play Visitor Card",
+            InputAction::Pause => "Pause",
+            InputAction::SpeedUp => "Faster Game Speed",
+            InputAction::SlowDown => "Slower Game Speed",
+            InputAction::PastGames => "Past Games",
+            InputAction::Resign => "Resign",
+            InputAction::SupplyPanel => "Supply Panel",
+            InputAction::Estate => "Estate",
+            InputAction::IncomeForecast => "Income Forecast",
+            InputAction::CardGallery => "Card Gallery",
+            InputAction::PasteTurnCode => "Paste Turn Code",
+            InputAction::Harvest => "Harvest",
+            InputAction::MakeWine => "Make Wine (again to confirm)",
+            InputAction::NextOrder => "Select Next Order",
+            InputAction::FillOrder => "Fill Selected Order",
+            InputAction::WakeUpChart => "Change Wake-up Chart",
+            InputAction::RulesPreset => "Change Rules Preset",
+            InputAction::ContinueGame => "Continue Last Game",
+            InputAction::NextSeat => "Pick Next Seat",
+            InputAction::SeatDifficulty => "Seat AI Level",
+            InputAction::SeatHandicap => "Seat Handicap",
+            InputAction::CoopMode => "Co-op vs Automa",
+            InputAction::RepeatChoice => "Repeat Last Choice / Confirm Harvest",
+            InputAction::SandboxPlantRed => "Sandbox: Plant Red Vines",
+            InputAction::SandboxPlantWhite => "Sandbox: Plant White Vines",
+            InputAction::SandboxAddGrapes => "Sandbox: Add Grapes",
+            InputAction::SandboxClear => "Sandbox: Clear Selection",
+            InputAction::EnterSeed => "Enter Game Seed",
+            InputAction::FinalDecks => "Final Decks",
+            InputAction::NextPile => "Next Pile",
+            InputAction::PreviousPile => "Previous Pile",
+            InputAction::Notes => "Player Notes",
+            InputAction::LayerOutlines => "Layer Outlines (testing)",
+        }
+    }
+
+    pub fn is_expansion(&self) -> bool {
+        matches!(self,
+            InputAction::ToggleTuscany | InputAction::ToggleVisitorCards |
+            InputAction::ToggleAdvancedBoards | InputAction::VisitorCard)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct KeyBinding {
+    pub action: InputAction,
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub gamepad: Option<GamepadButtonType>,
+}
+
+impl KeyBinding {
+    fn new(action: InputAction, key: KeyCode) -> Self {
+        Self { action, key, ctrl: false, gamepad: None }
+    }
+
+    fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    fn with_gamepad(mut self, button: GamepadButtonType) -> Self {
+        self.gamepad = Some(button);
+        self
+    }
+
+    pub fn key_label(&self) -> String {
+        let key = key_name(self.key);
+        if self.ctrl { format!("Ctrl+{}", key) } else { key }
+    }
+}
+
+#[derive(Resource)]
+pub struct Keymap {
+    pub bindings: Vec<KeyBinding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                KeyBinding::new(InputAction::ContextualHelp, KeyCode::F1),
+                KeyBinding::new(InputAction::QuickReference, KeyCode::F2).with_gamepad(GamepadButtonType::Select),
+                KeyBinding::new(InputAction::SaveGame, KeyCode::KeyS).with_ctrl(),
+                KeyBinding::new(InputAction::LoadGame, KeyCode::F9),
+                KeyBinding::new(InputAction::Statistics, KeyCode::Tab).with_gamepad(GamepadButtonType::North),
+                KeyBinding::new(InputAction::Settings, KeyCode::Escape).with_gamepad(GamepadButtonType::Start),
+                KeyBinding::new(InputAction::Undo, KeyCode::KeyZ).with_ctrl().with_gamepad(GamepadButtonType::West),
+                KeyBinding::new(InputAction::Redo, KeyCode::KeyY).with_ctrl(),
+                KeyBinding::new(InputAction::EndTurn, KeyCode::Enter).with_gamepad(GamepadButtonType::South),
+                KeyBinding::new(InputAction::ExportLog, KeyCode::KeyE).with_ctrl(),
+                KeyBinding::new(InputAction::AssetDiagnostics, KeyCode::KeyD).with_ctrl(),
+                KeyBinding::new(InputAction::Pause, KeyCode::KeyP),
+                KeyBinding::new(InputAction::SpeedUp, KeyCode::BracketRight),
+                KeyBinding::new(InputAction::SlowDown, KeyCode::BracketLeft),
+                KeyBinding::new(InputAction::PastGames, KeyCode::KeyH).with_ctrl(),
+                KeyBinding::new(InputAction::Resign, KeyCode::KeyR).with_ctrl(),
+                KeyBinding::new(InputAction::SupplyPanel, KeyCode::KeyB).with_ctrl(),
+                KeyBinding::new(InputAction::Estate, KeyCode::KeyL).with_ctrl(),
+                KeyBinding::new(InputAction::IncomeForecast, KeyCode::KeyU).with_ctrl(),
+                KeyBinding::new(InputAction::CardGallery, KeyCode::KeyG).with_ctrl(),
+                KeyBinding::new(InputAction::PasteTurnCode, KeyCode::KeyV).with_ctrl(),
+                KeyBinding::new(InputAction::Harvest, KeyCode::KeyH),
+                KeyBinding::new(InputAction::MakeWine, KeyCode::KeyM),
+                KeyBinding::new(InputAction::NextOrder, KeyCode::KeyO),
+                KeyBinding::new(InputAction::FillOrder, KeyCode::KeyF),
+                KeyBinding::new(InputAction::WakeUpChart, KeyCode::KeyW),
+                KeyBinding::new(InputAction::RulesPreset, KeyCode::KeyF),
+                KeyBinding::new(InputAction::ContinueGame, KeyCode::Enter),
+                KeyBinding::new(InputAction::NextSeat, KeyCode::KeyK),
+                KeyBinding::new(InputAction::SeatDifficulty, KeyCode::KeyD),
+                KeyBinding::new(InputAction::SeatHandicap, KeyCode::KeyH),
+                KeyBinding::new(InputAction::CoopMode, KeyCode::KeyO),
+                KeyBinding::new(InputAction::RepeatChoice, KeyCode::KeyR),
+                KeyBinding::new(InputAction::SandboxPlantRed, KeyCode::KeyR),
+                KeyBinding::new(InputAction::SandboxPlantWhite, KeyCode::KeyW),
+                KeyBinding::new(InputAction::SandboxAddGrapes, KeyCode::KeyG),
+                KeyBinding::new(InputAction::SandboxClear, KeyCode::Delete),
+                KeyBinding::new(InputAction::EnterSeed, KeyCode::KeyS),
+                KeyBinding::new(InputAction::FinalDecks, KeyCode::KeyD),
+                KeyBinding::new(InputAction::NextPile, KeyCode::ArrowRight),
+                KeyBinding::new(InputAction::PreviousPile, KeyCode::ArrowLeft),
+                KeyBinding::new(InputAction::Notes, KeyCode::KeyN),
+                KeyBinding::new(InputAction::LayerOutlines, KeyCode::Backslash),
+                KeyBinding::new(InputAction::ToggleTuscany, KeyCode::F6),
+                KeyBinding::new(InputAction::ToggleVisitorCards, KeyCode::F7),
+                KeyBinding::new(InputAction::ToggleAdvancedBoards, KeyCode::F8),
+                KeyBinding::new(InputAction::VisitorCard, KeyCode::KeyV).with_gamepad(GamepadButtonType::East),
+            ],
+        }
+    }
+}
+
+impl Keymap {
+    pub fn binding(&self, action: InputAction) -> Option<&KeyBinding> {
+        self.bindings.iter().find(|b| b.action == action)
+    }
+
+    pub fn rebind(&mut self, action: InputAction, key: KeyCode, ctrl: bool) {
+        if let Some(binding) = self.bindings.iter_mut().find(|b| b.action == action) {
+            binding.key = key;
+            binding.ctrl = ctrl;
+            info!("⌨️ Rebound {} to {}", action.label(), binding.key_label());
+        }
+    }
+
+    // The keyboard half of BoundInput, for systems that already hold the notes mutably
+    pub fn key_just_pressed(&self, action: InputAction, keyboard: &ButtonInput<KeyCode>) -> bool {
+        let Some(binding) = self.binding(action) else {
+            return false;
+        };
+        let ctrl_held = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+        keyboard.just_pressed(binding.key) && ctrl_held == binding.ctrl
+    }
+
+    // Cheat-sheet lines for the F2 overlay, generated from the live bindings
+    pub fn reference_lines(&self, expansion: bool, show_gamepad: bool) -> String {
+        let mut text = String::new();
+        for binding in self.bindings.iter().filter(|b| b.action.is_expansion() == expansion) {
+            text.push_str(&format!("{} - {}", binding.key_label(), binding.action.label()));
+            if show_gamepad {
+                if let Some(button) = binding.gamepad {
+                    text.push_str(&format!(" {}", gamepad_glyph(button)));
+                }
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+// Keyboard + gamepad input resolved through the keymap
+#[derive(SystemParam)]
+pub struct BoundInput<'w> {
+    pub keymap: Res<'w, Keymap>,
+    pub keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Res<'w, Gamepads>,
+    gamepad_buttons: Res<'w, ButtonInput<GamepadButton>>,
+    notes: Option<Res<'w, PlayerNotes>>,
+    console: Option<Res<'w, DebugConsole>>,
+}
+
+impl BoundInput<'_> {
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        if self.notes.as_ref().is_some_and(|n| n.capturing_text())
+            || self.console.as_ref().is_some_and(|c| c.capturing_text()) {
+            return false;
+        }
+
+        if self.keymap.key_just_pressed(action, &self.keyboard) {
+            return true;
+        }
+
+        if let Some(button_type) = self.keymap.binding(action).and_then(|b| b.gamepad) {
+            return self.gamepads.iter()
+                .any(|gamepad| self.gamepad_buttons.just_pressed(GamepadButton::new(gamepad, button_type)));
+        }
+
+        false
+    }
+
+    pub fn gamepad_connected(&self) -> bool {
+        self.gamepads.iter().next().is_some()
+    }
+}
+
+fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Escape => "ESC".to_string(),
+        KeyCode::Enter => "ENTER".to_string(),
+        KeyCode::Tab => "TAB".to_string(),
+        KeyCode::Space => "SPACE".to_string(),
+        KeyCode::ArrowLeft => "←".to_string(),
+        KeyCode::ArrowRight => "→".to_string(),
+        KeyCode::Backslash => "\\".to_string(),
+        _ => {
+            let name = format!("{:?}", key);
+            name.trim_start_matches("Key").trim_start_matches("Digit").to_string()
+        }
+    }
+}
+
+fn gamepad_glyph(button: GamepadButtonType) -> &'static str {
+    match button {
+        GamepadButtonType::South => "[A]",
+        GamepadButtonType::East => "[B]",
+        GamepadButtonType::West => "[X]",
+        GamepadButtonType::North => "[Y]",
+        GamepadButtonType::Start => "[Start]",
+        GamepadButtonType::Select => "[Back]",
+        GamepadButtonType::LeftTrigger => "[LB]",
+        GamepadButtonType::RightTrigger => "[RB]",
+        GamepadButtonType::DPadUp => "[D-Up]",
+        GamepadButtonType::DPadDown => "[D-Down]",
+        GamepadButtonType::DPadLeft => "[D-Left]",
+        GamepadButtonType::DPadRight => "[D-Right]",
+        _ => "[Pad]",
+    }
+}
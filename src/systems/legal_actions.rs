@@ -0,0 +1,171 @@
+use crate::components::*;
+
+// The rules layer's one answer to "where can this player send a worker, and what will they
+// have to decide once it lands". Clicks, the action bar, turn passing, the AI, the advisor,
+// the simulator and the fuzzer all ask here rather than checking spaces themselves.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RequiredChoice {
+    // Hand indices of the vine cards that can go into a field
+    VineCard(Vec<usize>),
+    // Hand indices of the orders the cellar can fill
+    WineOrder(Vec<usize>),
+    // Harvestable fields, best first, of which at most `limit` are brought in
+    HarvestFields { fields: Vec<usize>, limit: usize },
+}
+
+impl RequiredChoice {
+    pub fn options(&self) -> usize {
+        match self {
+            RequiredChoice::VineCard(cards) | RequiredChoice::WineOrder(cards) => cards.len(),
+            RequiredChoice::HarvestFields { fields, .. } => fields.len(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegalAction {
+    pub action: ActionSpace,
+    // An idle regular worker has a free column
+    pub regular: bool,
+    // The idle grande can go, to a free column or to the bonus slot of a full row
+    pub grande: bool,
+    // Every open column is taken, so only the grande's bonus slot is left
+    pub blocked: bool,
+    // The worker would be first on the space's bonus column
+    pub bonus: bool,
+    // Decisions waiting once the worker is placed; empty when the action has none
+    pub choices: Vec<RequiredChoice>,
+}
+
+impl LegalAction {
+    // Whether the placement takes the grande: a regular worker goes first unless the grande
+    // is asked for. None when the worker asked for cannot go.
+    pub fn uses_grande(&self, wants_grande: bool) -> Option<bool> {
+        if !wants_grande && self.regular {
+            Some(false)
+        } else if self.grande {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+// The board as the rules see it. Built from the live ECS queries or from the simulator;
+// without cards the placements are still right but no choices are listed.
+pub struct RulesState<'a> {
+    pub spaces: Vec<&'a ActionSpaceSlot>,
+    pub season: GameState,
+    pub player_count: u8,
+    // Workers not yet placed, as (owner, is_grande)
+    pub idle_workers: Vec<(PlayerId, bool)>,
+    pub hands: Vec<&'a Hand>,
+    pub vineyards: Vec<&'a Vineyard>,
+    pub structures: Vec<Structure>,
+}
+
+impl<'a> RulesState<'a> {
+    pub fn new(spaces: impl IntoIterator<Item = &'a ActionSpaceSlot>, season: &GameState, player_count: u8) -> Self {
+        Self {
+            spaces: spaces.into_iter().collect(),
+            season: season.clone(),
+            player_count,
+            idle_workers: Vec::new(),
+            hands: Vec::new(),
+            vineyards: Vec::new(),
+            structures: Vec::new(),
+        }
+    }
+
+    pub fn with_workers<'w>(mut self, workers: impl IntoIterator<Item = &'w Worker>) -> Self {
+        self.idle_workers.extend(workers.into_iter()
+            .filter(|w| w.placed_at.is_none())
+            .map(|w| (w.owner, w.is_grande)));
+        self
+    }
+
+    // Regular workers by count, for the simulator, which keeps no worker entities
+    pub fn with_idle_regulars(mut self, player: PlayerId, count: u8) -> Self {
+        self.idle_workers.extend(std::iter::repeat((player, false)).take(count as usize));
+        self
+    }
+
+    pub fn with_cards<'s>(
+        mut self,
+        hands: impl IntoIterator<Item = &'a Hand>,
+        vineyards: impl IntoIterator<Item = &'a Vineyard>,
+        structures: impl IntoIterator<Item = &'s Structure>,
+    ) -> Self {
+        self.hands.extend(hands);
+        self.vineyards.extend(vineyards);
+        self.structures.extend(structures.into_iter().cloned());
+        self
+    }
+
+    fn idle(&self, player: PlayerId, grande: bool) -> bool {
+        self.idle_workers.contains(&(player, grande))
+    }
+}
+
+// Every action `player` can send a worker to right now, in board order
+pub fn legal_actions(state: &RulesState, player: PlayerId) -> Vec<LegalAction> {
+    let (regular_idle, grande_idle) = (state.idle(player, false), state.idle(player, true));
+    if !regular_idle && !grande_idle {
+        return Vec::new();
+    }
+
+    let mut legal: Vec<LegalAction> = Vec::new();
+    for space in state.spaces.iter() {
+        if legal.iter().any(|l| l.action == space.action) {
+            continue;
+        }
+        let slot = |grande: bool| placement_slot(state.spaces.iter().copied(), space.action, player, state.player_count, &state.season, grande);
+        let free_column = slot(false);
+        let regular = regular_idle && free_column.is_some();
+        let grande = grande_idle && slot(true).is_some();
+        if !regular && !grande {
+            continue;
+        }
+        let bonus = free_column.is_some_and(|(column, _)| state.spaces.iter()
+            .any(|s| s.action == space.action && s.column == column && s.bonus_free()));
+        legal.push(LegalAction {
+            action: space.action,
+            regular,
+            grande,
+            blocked: free_column.is_none(),
+            bonus,
+            choices: required_choices(state, space.action, player, bonus),
+        });
+    }
+    legal
+}
+
+// The one entry for `action`, if it is legal
+pub fn legal_action(state: &RulesState, player: PlayerId, action: ActionSpace) -> Option<LegalAction> {
+    legal_actions(state, player).into_iter().find(|l| l.action == action)
+}
+
+fn required_choices(state: &RulesState, action: ActionSpace, player: PlayerId, bonus: bool) -> Vec<RequiredChoice> {
+    let hand = state.hands.iter().find(|h| h.owner == player);
+    let vineyard = state.vineyards.iter().find(|v| v.owner == player);
+    let choice = match (action, hand, vineyard) {
+        (ActionSpace::PlantVine, Some(hand), Some(vineyard)) => RequiredChoice::VineCard(
+            (0..hand.vine_cards.len())
+                .filter(|i| (0..vineyard.fields.len())
+                    .any(|field| vineyard.can_plant_vine_with_requirements(field, &hand.vine_cards[*i], &state.structures)))
+                .collect(),
+        ),
+        (ActionSpace::FillOrder, Some(hand), Some(vineyard)) => RequiredChoice::WineOrder(
+            (0..hand.wine_order_cards.len())
+                .filter(|i| vineyard.can_fulfill_order(&hand.wine_order_cards[*i]))
+                .collect(),
+        ),
+        (ActionSpace::Harvest, _, Some(vineyard)) => RequiredChoice::HarvestFields {
+            fields: vineyard.harvestable_fields(),
+            limit: HARVEST_FIELD_LIMIT + usize::from(bonus),
+        },
+        _ => return Vec::new(),
+    };
+    if choice.options() == 0 { Vec::new() } else { vec![choice] }
+}
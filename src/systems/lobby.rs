@@ -0,0 +1,758 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::expansions::ExpansionSettings;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::settings::UserSettings;
+use crate::systems::wake_up::{WakeUpChart, WakeUpCharts};
+
+// Pre-game lobby protocol. The transport only moves LobbyIncoming/LobbyOutgoing
+// events; everything the peers must agree on before Setup travels in LobbySettings.
+// The lobby screen is where seats, colours and the chat are picked before the host starts.
+
+pub const MAX_CHAT_LINES: usize = 50;
+const MAX_CHAT_INPUT: usize = 120;
+const MIN_ONLINE_PLAYERS: u8 = 2;
+pub const MAX_PLAYERS: u8 = 6;
+// Seat of a peer who joined with every human seat taken
+pub const NO_SEAT: u8 = u8::MAX;
+
+// Colours a seat can pick; seats keep their own index when nobody picks
+pub const SEAT_COLORS: [(&str, Srgba); 6] = [
+    ("Red", Srgba::new(0.8, 0.2, 0.2, 1.0)),
+    ("Blue", Srgba::new(0.2, 0.2, 0.8, 1.0)),
+    ("Green", Srgba::new(0.2, 0.8, 0.2, 1.0)),
+    ("Yellow", Srgba::new(0.8, 0.8, 0.2, 1.0)),
+    ("Purple", Srgba::new(0.6, 0.2, 0.8, 1.0)),
+    ("Orange", Srgba::new(0.9, 0.5, 0.1, 1.0)),
+];
+
+pub fn color_name(color: u8) -> &'static str {
+    SEAT_COLORS.get(color as usize).map_or("Grey", |(name, _)| *name)
+}
+
+// Settings the host negotiates; the Start message carries the final copy so every
+// peer builds an identical GameConfig
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LobbySettings {
+    pub player_count: u8,
+    pub ai_count: u8,
+    pub target_victory_points: u8,
+    pub max_years: u8,
+    pub tuscany_enabled: bool,
+    pub visitor_cards_enabled: bool,
+    pub advanced_boards_enabled: bool,
+    pub house_rule_bonus_chits: bool,
+    pub house_rule_order_swap: bool,
+    #[serde(default)]
+    pub house_rule_worker_upkeep: bool,
+    #[serde(default)]
+    pub house_rule_simultaneous_wake_up: bool,
+    #[serde(default)]
+    pub house_rule_wine_festival: bool,
+    #[serde(default)]
+    pub house_rule_no_undo_after_draw: bool,
+    #[serde(default)]
+    pub house_rule_sparkling_aging: bool,
+    #[serde(default)]
+    pub house_rule_temp_worker_bidding: bool,
+    #[serde(default)]
+    pub automa_mercy: bool,
+    #[serde(default)]
+    pub preset: RulesPreset,
+    // The whole chart rather than its index, so a custom chart reaches peers without it
+    #[serde(default)]
+    pub wake_up_chart: Option<WakeUpChart>,
+    // Per-seat AI levels and handicaps, by seat index
+    #[serde(default)]
+    pub seats: Vec<SeatSetup>,
+    // Every peer seeds its game from the host's, so shuffles and chits match
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+impl LobbySettings {
+    pub fn from_resources(
+        config: &GameConfig,
+        expansions: &ExpansionSettings,
+        settings: &UserSettings,
+        preset: RulesPreset,
+        charts: &WakeUpCharts,
+        game_settings: &GameSettings,
+    ) -> Self {
+        Self {
+            player_count: config.player_count,
+            ai_count: config.ai_count,
+            target_victory_points: config.target_victory_points,
+            max_years: config.max_years,
+            tuscany_enabled: expansions.tuscany_enabled,
+            visitor_cards_enabled: expansions.visitor_cards_enabled,
+            advanced_boards_enabled: expansions.advanced_boards_enabled,
+            house_rule_bonus_chits: settings.house_rule_bonus_chits,
+            house_rule_order_swap: settings.house_rule_order_swap,
+            house_rule_worker_upkeep: settings.house_rule_worker_upkeep,
+            house_rule_simultaneous_wake_up: settings.house_rule_simultaneous_wake_up,
+            house_rule_wine_festival: settings.house_rule_wine_festival,
+            house_rule_no_undo_after_draw: settings.house_rule_no_undo_after_draw,
+            house_rule_sparkling_aging: settings.house_rule_sparkling_aging,
+            house_rule_temp_worker_bidding: settings.house_rule_temp_worker_bidding,
+            automa_mercy: settings.automa_mercy,
+            preset,
+            wake_up_chart: Some(charts.active().clone()),
+            seats: game_settings.seats.clone(),
+            seed: config.seed,
+        }
+    }
+
+    pub fn apply(
+        &self,
+        config: &mut GameConfig,
+        expansions: &mut ExpansionSettings,
+        settings: &mut UserSettings,
+        preset: &mut RulesPreset,
+        charts: &mut WakeUpCharts,
+        game_settings: &mut GameSettings,
+    ) {
+        config.player_count = self.player_count;
+        config.ai_count = self.ai_count.min(self.player_count);
+        config.target_victory_points = self.target_victory_points;
+        config.max_years = self.max_years;
+        config.current_year = 1;
+        expansions.tuscany_enabled = self.tuscany_enabled;
+        expansions.visitor_cards_enabled = self.visitor_cards_enabled;
+        expansions.advanced_boards_enabled = self.advanced_boards_enabled;
+        settings.house_rule_bonus_chits = self.house_rule_bonus_chits;
+        settings.house_rule_order_swap = self.house_rule_order_swap;
+        settings.house_rule_worker_upkeep = self.house_rule_worker_upkeep;
+        settings.house_rule_simultaneous_wake_up = self.house_rule_simultaneous_wake_up;
+        settings.house_rule_wine_festival = self.house_rule_wine_festival;
+        settings.house_rule_no_undo_after_draw = self.house_rule_no_undo_after_draw;
+        settings.house_rule_sparkling_aging = self.house_rule_sparkling_aging;
+        settings.house_rule_temp_worker_bidding = self.house_rule_temp_worker_bidding;
+        settings.automa_mercy = self.automa_mercy;
+        *preset = self.preset;
+        if let Some(chart) = &self.wake_up_chart {
+            charts.active = match charts.charts.iter().position(|c| c == chart) {
+                Some(index) => index,
+                None => {
+                    charts.charts.push(chart.clone());
+                    charts.charts.len() - 1
+                }
+            };
+        }
+        game_settings.seats = self.seats.clone();
+        config.seed = self.seed;
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum LobbyMessage {
+    Join { name: String },
+    Welcome { peer: u8, peers: Vec<LobbyPeer>, settings: LobbySettings },
+    Chat { from: String, text: String },
+    Settings(LobbySettings),
+    Ready { peer: u8, ready: bool },
+    Start(LobbySettings),
+    // Client asks, the host answers everyone with the new Roster
+    PickSeat { seat: u8 },
+    PickColor { color: u8 },
+    Roster(Vec<LobbyPeer>),
+}
+
+impl LobbyMessage {
+    pub fn encode(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LobbyPeer {
+    pub id: u8,
+    pub name: String,
+    pub ready: bool,
+    #[serde(default)]
+    pub seat: u8,
+    #[serde(default)]
+    pub color: u8,
+}
+
+// From the transport: a message and the peer that sent it (0 is the host)
+#[derive(Event, Clone, Debug)]
+pub struct LobbyIncoming {
+    pub from: u8,
+    pub message: LobbyMessage,
+}
+
+// To the transport: the host broadcasts, clients send to the host
+#[derive(Event, Clone, Debug)]
+pub struct LobbyOutgoing(pub LobbyMessage);
+
+// Present while hosting or joined; removed once the game starts
+#[derive(Resource)]
+pub struct Lobby {
+    pub is_host: bool,
+    pub local_peer: u8,
+    pub peers: Vec<LobbyPeer>,
+    pub settings: LobbySettings,
+    pub chat: Vec<(String, String)>,
+}
+
+impl Lobby {
+    pub fn host(name: &str, settings: LobbySettings) -> Self {
+        Self {
+            is_host: true,
+            local_peer: 0,
+            peers: vec![LobbyPeer { id: 0, name: name.to_string(), ready: false, seat: 0, color: 0 }],
+            settings,
+            chat: Vec::new(),
+        }
+    }
+
+    // Filled in by the host's Welcome
+    pub fn join(settings: LobbySettings) -> Self {
+        Self {
+            is_host: false,
+            local_peer: u8::MAX,
+            peers: Vec::new(),
+            settings,
+            chat: Vec::new(),
+        }
+    }
+
+    pub fn all_ready(&self) -> bool {
+        !self.peers.is_empty() && self.peers.iter().all(|p| p.ready)
+    }
+
+    // AI seats are the last ai_count, as at setup
+    pub fn human_seats(&self) -> u8 {
+        self.settings.player_count.saturating_sub(self.settings.ai_count)
+    }
+
+    pub fn local(&self) -> Option<&LobbyPeer> {
+        self.peers.iter().find(|p| p.id == self.local_peer)
+    }
+
+    fn seat_free(&self, seat: u8, for_peer: u8) -> bool {
+        seat < self.human_seats() && !self.peers.iter().any(|p| p.id != for_peer && p.seat == seat)
+    }
+
+    fn color_free(&self, color: u8, for_peer: u8) -> bool {
+        (color as usize) < SEAT_COLORS.len() && !self.peers.iter().any(|p| p.id != for_peer && p.color == color)
+    }
+
+    // The next seat or colour from `current` that nobody else holds, going either way round
+    pub fn next_free_seat(&self, for_peer: u8, current: u8, forward: bool) -> Option<u8> {
+        let seats = self.human_seats() as i16;
+        let start = match (current < self.human_seats(), forward) {
+            (true, _) => current as i16,
+            (false, true) => -1,
+            (false, false) => seats,
+        };
+        let step = if forward { 1 } else { -1 };
+        (1..=seats).map(|n| (start + n * step).rem_euclid(seats) as u8).find(|seat| self.seat_free(*seat, for_peer))
+    }
+
+    pub fn next_free_color(&self, for_peer: u8, current: u8) -> Option<u8> {
+        let colors = SEAT_COLORS.len() as u8;
+        (1..=colors).map(|step| current.wrapping_add(step) % colors).find(|color| self.color_free(*color, for_peer))
+    }
+
+    // Everyone seated on a human seat of their own and ready
+    pub fn can_start(&self) -> bool {
+        self.all_ready()
+            && self.peers.len() as u8 == self.human_seats()
+            && self.peers.iter().all(|p| self.seat_free(p.seat, p.id))
+    }
+
+    // Moves anyone whose seat went to an AI, or who had none, onto a free one
+    fn reseat(&mut self) {
+        for i in 0..self.peers.len() {
+            let peer = self.peers[i].id;
+            if !self.seat_free(self.peers[i].seat, peer) {
+                self.peers[i].seat = (0..self.human_seats()).find(|s| self.seat_free(*s, peer)).unwrap_or(NO_SEAT);
+            }
+        }
+    }
+
+    // Host only: a peer dropped before the game started
+    pub fn leave(&mut self, peer: u8) -> Option<LobbyMessage> {
+        let index = self.peers.iter().position(|p| p.id == peer).filter(|_| self.is_host)?;
+        let gone = self.peers.remove(index);
+        self.push_chat("lobby", &format!("{} left", gone.name));
+        self.reseat();
+        Some(LobbyMessage::Roster(self.peers.clone()))
+    }
+
+    // Something the local player did on the lobby screen. The host applies it straight away;
+    // a client sends it and waits for the host's answer.
+    pub fn request(&mut self, message: LobbyMessage) -> Vec<LobbyMessage> {
+        if self.is_host {
+            self.handle(self.local_peer, message)
+        } else {
+            vec![message]
+        }
+    }
+
+    pub fn push_chat(&mut self, from: &str, text: &str) {
+        self.chat.push((from.to_string(), text.to_string()));
+        if self.chat.len() > MAX_CHAT_LINES {
+            self.chat.remove(0);
+        }
+    }
+
+    // Host only. Changed settings void every ready flag, so nobody starts on terms they didn't see.
+    pub fn change_settings(&mut self, settings: LobbySettings) -> Option<LobbyMessage> {
+        if !self.is_host || settings == self.settings {
+            return None;
+        }
+        self.settings = settings.clone();
+        for peer in self.peers.iter_mut() {
+            peer.ready = false;
+        }
+        self.reseat();
+        Some(LobbyMessage::Settings(settings))
+    }
+
+    pub fn start(&self) -> Option<LobbyMessage> {
+        (self.is_host && self.can_start()).then(|| LobbyMessage::Start(self.settings.clone()))
+    }
+
+    // Applies an incoming message; returns what the host relays to everyone
+    pub fn handle(&mut self, from: u8, message: LobbyMessage) -> Vec<LobbyMessage> {
+        let mut relay = Vec::new();
+        match message {
+            LobbyMessage::Join { name } if self.is_host => {
+                // The transport's peer id when it is free, so seats line up with connections
+                let taken = |id: u8| self.peers.iter().any(|p| p.id == id);
+                let id = if from != 0 && !taken(from) { from } else { self.peers.iter().map(|p| p.id).max().unwrap_or(0) + 1 };
+                let seat = (0..self.human_seats()).find(|s| self.seat_free(*s, id)).unwrap_or(NO_SEAT);
+                let color = (0..SEAT_COLORS.len() as u8).find(|c| self.color_free(*c, id)).unwrap_or(0);
+                self.peers.push(LobbyPeer { id, name: name.clone(), ready: false, seat, color });
+                self.push_chat("lobby", &format!("{} joined", name));
+                relay.push(LobbyMessage::Welcome { peer: id, peers: self.peers.clone(), settings: self.settings.clone() });
+            }
+            LobbyMessage::Welcome { peer, peers, settings } if !self.is_host => {
+                if self.local_peer == u8::MAX {
+                    self.local_peer = peer;
+                }
+                self.peers = peers;
+                self.settings = settings;
+            }
+            LobbyMessage::Chat { from: name, text } => {
+                self.push_chat(&name, &text);
+                if self.is_host {
+                    relay.push(LobbyMessage::Chat { from: name, text });
+                }
+            }
+            // Only the host's word counts for settings
+            LobbyMessage::Settings(settings) if !self.is_host && from == 0 => {
+                self.settings = settings;
+                for peer in self.peers.iter_mut() {
+                    peer.ready = false;
+                }
+            }
+            LobbyMessage::Ready { peer, ready } => {
+                // Clients may only ready themselves
+                let peer = if self.is_host { from } else { peer };
+                if let Some(entry) = self.peers.iter_mut().find(|p| p.id == peer) {
+                    entry.ready = ready;
+                }
+                if self.is_host {
+                    relay.push(LobbyMessage::Ready { peer, ready });
+                }
+            }
+            LobbyMessage::PickSeat { seat } if self.is_host => {
+                if self.seat_free(seat, from) {
+                    if let Some(entry) = self.peers.iter_mut().find(|p| p.id == from) {
+                        entry.seat = seat;
+                    }
+                }
+                relay.push(LobbyMessage::Roster(self.peers.clone()));
+            }
+            LobbyMessage::PickColor { color } if self.is_host => {
+                if self.color_free(color, from) {
+                    if let Some(entry) = self.peers.iter_mut().find(|p| p.id == from) {
+                        entry.color = color;
+                    }
+                }
+                relay.push(LobbyMessage::Roster(self.peers.clone()));
+            }
+            LobbyMessage::Roster(peers) if !self.is_host && from == 0 => {
+                self.peers = peers;
+            }
+            _ => {}
+        }
+        relay
+    }
+}
+
+// Host: mirrors local settings changes to the lobby. Everyone: applies incoming
+// messages and, on Start, adopts the host's settings and goes to Setup.
+pub fn lobby_message_system(
+    mut commands: Commands,
+    mut lobby: ResMut<Lobby>,
+    mut incoming: EventReader<LobbyIncoming>,
+    mut outgoing: EventWriter<LobbyOutgoing>,
+    mut config: ResMut<GameConfig>,
+    mut expansions: ResMut<ExpansionSettings>,
+    mut settings: ResMut<UserSettings>,
+    (mut preset, mut charts, mut game_settings): (ResMut<RulesPreset>, ResMut<WakeUpCharts>, ResMut<GameSettings>),
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if lobby.is_host {
+        let local = LobbySettings::from_resources(&config, &expansions, &settings, *preset, &charts, &game_settings);
+        if let Some(update) = lobby.change_settings(local) {
+            outgoing.send(LobbyOutgoing(update));
+        }
+    }
+
+    for event in incoming.read() {
+        if let LobbyMessage::Start(ref final_settings) = event.message {
+            if lobby.is_host || event.from != 0 {
+                continue;
+            }
+            final_settings.apply(&mut config, &mut expansions, &mut settings, &mut preset, &mut charts, &mut game_settings);
+            info!("🌐 Host started the game: {} players, {} VP over {} years",
+                  final_settings.player_count, final_settings.target_victory_points, final_settings.max_years);
+            insert_seating(&mut commands, &lobby);
+            commands.remove_resource::<Lobby>();
+            next_state.set(GameState::Setup);
+            return;
+        }
+
+        for relay in lobby.handle(event.from, event.message.clone()) {
+            outgoing.send(LobbyOutgoing(relay));
+        }
+    }
+}
+
+// Host calls this from the lobby screen once everyone has readied up
+pub fn start_lobby_game(
+    commands: &mut Commands,
+    lobby: &Lobby,
+    outgoing: &mut EventWriter<LobbyOutgoing>,
+    next_state: &mut NextState<GameState>,
+) -> bool {
+    let Some(start) = lobby.start() else { return false };
+    outgoing.send(LobbyOutgoing(start));
+    insert_seating(commands, lobby);
+    commands.remove_resource::<Lobby>();
+    next_state.set(GameState::Setup);
+    true
+}
+
+// Who sits where once the lobby is gone: peer to seat for the transport, names for setup
+#[derive(Resource, Clone, Debug, Default)]
+pub struct LobbySeating {
+    pub peers: Vec<LobbyPeer>,
+}
+
+impl LobbySeating {
+    pub fn seat_of(&self, peer: u8) -> Option<PlayerId> {
+        self.peers.iter().find(|p| p.id == peer && p.seat != NO_SEAT).map(|p| PlayerId(p.seat))
+    }
+
+    pub fn name_at(&self, seat: u8) -> Option<&str> {
+        self.peers.iter().find(|p| p.seat == seat).map(|p| p.name.as_str())
+    }
+}
+
+// Colour of every seat; empty outside online games, where seats keep their own index
+#[derive(Resource, Clone, Debug, Default)]
+pub struct PlayerColors(pub Vec<u8>);
+
+impl PlayerColors {
+    // Peers keep their pick, the other seats take the first colours nobody picked
+    pub fn from_peers(peers: &[LobbyPeer], player_count: u8) -> Self {
+        let mut colors: Vec<u8> = Vec::new();
+        for seat in 0..player_count {
+            let picked = peers.iter().find(|p| p.seat == seat).map(|p| p.color);
+            let color = picked.unwrap_or_else(|| (0..SEAT_COLORS.len() as u8)
+                .find(|c| !peers.iter().any(|p| p.color == *c) && !colors.contains(c))
+                .unwrap_or(seat));
+            colors.push(color);
+        }
+        Self(colors)
+    }
+
+    pub fn srgba(&self, player: PlayerId) -> Srgba {
+        let color = self.0.get(player.0 as usize).copied().unwrap_or(player.0);
+        SEAT_COLORS.get(color as usize).map_or(Srgba::new(0.6, 0.6, 0.6, 1.0), |(_, c)| *c)
+    }
+}
+
+fn insert_seating(commands: &mut Commands, lobby: &Lobby) {
+    commands.insert_resource(LobbySeating { peers: lobby.peers.clone() });
+    commands.insert_resource(PlayerColors::from_peers(&lobby.peers, lobby.settings.player_count));
+}
+
+pub fn seat_lines(lobby: &Lobby) -> Vec<String> {
+    (0..lobby.settings.player_count).map(|seat| {
+        let label = match lobby.peers.iter().find(|p| p.seat == seat) {
+            Some(peer) => format!("{} ({}){}{}",
+                peer.name,
+                color_name(peer.color),
+                if peer.id == lobby.local_peer { " — you" } else { "" },
+                if peer.ready { " ✔ ready" } else { "" }),
+            None if seat >= lobby.human_seats() => "🤖 AI".to_string(),
+            None => "open".to_string(),
+        };
+        format!("{}. {}", seat + 1, label)
+    }).collect()
+}
+
+pub fn lobby_screen_text(lobby: &Lobby, chat_input: &str) -> String {
+    let role = if lobby.is_host { "hosting" } else { "joined" };
+    let mut text = format!("🌐 ONLINE LOBBY ({})\n\nSeats:", role);
+    for line in seat_lines(lobby) {
+        text.push_str("\n  ");
+        text.push_str(&line);
+    }
+    for peer in lobby.peers.iter().filter(|p| p.seat == NO_SEAT) {
+        text.push_str(&format!("\n  waiting for a seat: {}", peer.name));
+    }
+
+    let settings = &lobby.settings;
+    text.push_str(&format!("\n\n{} players ({} AI), {} VP over {} years",
+        settings.player_count, settings.ai_count, settings.target_victory_points, settings.max_years));
+    if lobby.is_host && !lobby.can_start() {
+        text.push_str("\nStart opens once every human seat is taken and ready");
+    }
+
+    text.push_str("\n\nChat:");
+    for (from, line) in lobby.chat.iter().rev().take(10).rev() {
+        text.push_str(&format!("\n  {}: {}", from, line));
+    }
+    text.push_str(&format!("\n> {}_  (type and press Enter)", chat_input));
+    text
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LobbyAction {
+    SeatPrevious,
+    SeatNext,
+    Color,
+    Ready,
+    PlayersDown,
+    PlayersUp,
+    AiDown,
+    AiUp,
+    FillWithAi,
+    Start,
+}
+
+impl LobbyAction {
+    fn label(&self) -> &'static str {
+        match self {
+            LobbyAction::SeatPrevious => "◀ Seat",
+            LobbyAction::SeatNext => "Seat ▶",
+            LobbyAction::Color => "Colour",
+            LobbyAction::Ready => "Ready",
+            LobbyAction::PlayersDown => "Players −",
+            LobbyAction::PlayersUp => "Players +",
+            LobbyAction::AiDown => "AI −",
+            LobbyAction::AiUp => "AI +",
+            LobbyAction::FillWithAi => "Fill with AI",
+            LobbyAction::Start => "Start",
+        }
+    }
+}
+
+const PEER_ACTIONS: [LobbyAction; 4] = [LobbyAction::SeatPrevious, LobbyAction::SeatNext, LobbyAction::Color, LobbyAction::Ready];
+const HOST_ACTIONS: [LobbyAction; 6] = [
+    LobbyAction::PlayersDown,
+    LobbyAction::PlayersUp,
+    LobbyAction::AiDown,
+    LobbyAction::AiUp,
+    LobbyAction::FillWithAi,
+    LobbyAction::Start,
+];
+
+#[derive(Component)]
+pub struct LobbyButton(pub LobbyAction);
+
+#[derive(Component)]
+pub struct LobbyScreen;
+
+#[derive(Component)]
+pub struct LobbyScreenText;
+
+// Line being typed into the lobby chat
+#[derive(Resource, Default)]
+pub struct LobbyChatInput {
+    pub text: String,
+}
+
+// Shown for as long as the game sits in the lobby state
+pub fn lobby_screen_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    lobby: Option<Res<Lobby>>,
+    chat_input: Res<LobbyChatInput>,
+    screens: Query<Entity, With<LobbyScreen>>,
+    mut texts: Query<&mut Text, With<LobbyScreenText>>,
+) {
+    let Some(lobby) = lobby.filter(|_| matches!(current_state.get(), GameState::Lobby)) else {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let text = lobby_screen_text(&lobby, &chat_input.text);
+    if let Ok(mut existing) = texts.get_single_mut() {
+        if existing.sections[0].value != text {
+            existing.sections[0].value = text;
+        }
+        return;
+    }
+    if !screens.is_empty() {
+        return;
+    }
+
+    let host_actions: &[LobbyAction] = if lobby.is_host { &HOST_ACTIONS } else { &[] };
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Px(50.0),
+                width: Val::Px(560.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.12, 0.2, 0.95)).into(),
+            ..default()
+        },
+        LobbyScreen,
+    )).with_children(|panel| {
+        panel.spawn((
+            TextBundle::from_section(
+                text,
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ),
+            LobbyScreenText,
+        ));
+        for row in [&PEER_ACTIONS[..], host_actions] {
+            panel.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(8.0),
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|buttons| {
+                for action in row {
+                    buttons.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::from(Srgba::new(0.3, 0.3, 0.3, 1.0)).into(),
+                            ..default()
+                        },
+                        LobbyButton(*action),
+                    )).with_children(|button| {
+                        button.spawn(TextBundle::from_section(
+                            action.label(),
+                            TextStyle {
+                                font_size: 14.0,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ));
+                    });
+                }
+            });
+        }
+    });
+}
+
+// Typing goes to the chat line; seats, colours, readiness and the host's table go through the buttons
+pub fn lobby_input_system(
+    mut commands: Commands,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    buttons: Query<(&Interaction, &LobbyButton), Changed<Interaction>>,
+    mut lobby: ResMut<Lobby>,
+    mut chat_input: ResMut<LobbyChatInput>,
+    mut outgoing: EventWriter<LobbyOutgoing>,
+    mut config: ResMut<GameConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let mut requests = Vec::new();
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) if chat_input.text.len() < MAX_CHAT_INPUT => chat_input.text.push_str(chars),
+            Key::Space if chat_input.text.len() < MAX_CHAT_INPUT => chat_input.text.push(' '),
+            Key::Backspace => {
+                chat_input.text.pop();
+            }
+            Key::Enter if !chat_input.text.trim().is_empty() => {
+                let from = lobby.local().map_or_else(|| "you".to_string(), |p| p.name.clone());
+                requests.push(LobbyMessage::Chat { from, text: std::mem::take(&mut chat_input.text).trim().to_string() });
+            }
+            _ => {}
+        }
+    }
+
+    let me = lobby.local().cloned();
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match (button.0, me.as_ref()) {
+            (LobbyAction::SeatPrevious | LobbyAction::SeatNext, Some(me)) => {
+                if let Some(seat) = lobby.next_free_seat(me.id, me.seat, button.0 == LobbyAction::SeatNext) {
+                    requests.push(LobbyMessage::PickSeat { seat });
+                }
+            }
+            (LobbyAction::Color, Some(me)) => {
+                if let Some(color) = lobby.next_free_color(me.id, me.color) {
+                    requests.push(LobbyMessage::PickColor { color });
+                }
+            }
+            (LobbyAction::Ready, Some(me)) => requests.push(LobbyMessage::Ready { peer: me.id, ready: !me.ready }),
+            // The host's table changes reach the lobby through lobby_message_system
+            (LobbyAction::PlayersDown, _) if lobby.is_host => {
+                config.player_count = config.player_count.saturating_sub(1).max(MIN_ONLINE_PLAYERS);
+                config.ai_count = config.ai_count.min(config.player_count - 1);
+            }
+            (LobbyAction::PlayersUp, _) if lobby.is_host => config.player_count = (config.player_count + 1).min(MAX_PLAYERS),
+            (LobbyAction::AiDown, _) if lobby.is_host => config.ai_count = config.ai_count.saturating_sub(1),
+            (LobbyAction::AiUp, _) if lobby.is_host => config.ai_count = (config.ai_count + 1).min(config.player_count - 1),
+            (LobbyAction::FillWithAi, _) if lobby.is_host => {
+                config.ai_count = config.player_count.saturating_sub(lobby.peers.len() as u8).min(config.player_count - 1);
+            }
+            (LobbyAction::Start, _) if lobby.is_host => {
+                if !start_lobby_game(&mut commands, &lobby, &mut outgoing, &mut next_state) {
+                    lobby.push_chat("lobby", "Every human seat must be taken and ready first");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for request in requests {
+        for message in lobby.request(request) {
+            outgoing.send(LobbyOutgoing(message));
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub mod sprites;
 pub mod animations;
 pub mod audio;
 pub mod ai;
+pub mod ai_scheduler;
 pub mod validation;
 pub mod endgame;
 pub mod performance;
@@ -21,6 +22,74 @@ pub mod advanced;
 pub mod tutorial;
 pub mod achievements;
 pub mod onboarding;
+pub mod sandbox;
+pub mod keymap;
+pub mod simulation;
+pub mod determinism;
+pub mod action_bar;
+pub mod ghost;
+pub mod save_diff;
+pub mod bonus_chits;
+pub mod game_log;
+pub mod viewport;
+pub mod notes;
+pub mod asset_fallback;
+pub mod theme;
+pub mod win_probability;
+pub mod coop;
+pub mod clock;
+pub mod undo_diff;
+pub mod order_swap;
+pub mod hand_limit;
+pub mod season_summary;
+pub mod history;
+pub mod wake_up;
+pub mod shortcuts;
+pub mod resignation;
+pub mod supply;
+pub mod text_scale;
+pub mod first_game;
+pub mod agents;
+pub mod lobby;
+pub mod estate;
+pub mod hand_picker;
+pub mod field_picker;
+pub mod prediction;
+pub mod visitor_targeting;
+pub mod coach;
+pub mod upkeep;
+pub mod grande;
+pub mod action_columns;
+pub mod recent_actions;
+pub mod cursors;
+pub mod rng;
+pub mod festival;
+pub mod console;
+pub mod structure_roi;
+pub mod final_decks;
+pub mod retention;
+pub mod net;
+pub mod split_screen;
+pub mod worker_badges;
+pub mod handoff;
+pub mod order_tokens;
+pub mod play_by_file;
+pub mod opening_book;
+pub mod schedule_audit;
+pub mod headless;
+pub mod income_forecast;
+pub mod visitor_script;
+pub mod card_gallery;
+pub mod render_layers;
+pub mod planner;
+pub mod choice_memory;
+pub mod advisor;
+pub mod legal_actions;
+pub mod dashboard_sections;
+pub mod end_turn;
+pub mod seat_choices;
+#[cfg(feature = "inspector")]
+pub mod inspector;
 
 pub use setup::*;
 pub use ui::*;
@@ -30,6 +99,7 @@ pub use sprites::*;
 pub use animations::*;
 pub use audio::*;
 pub use ai::*;
+pub use ai_scheduler::*;
 pub use validation::*;
 pub use endgame::*;
 pub use performance::*;
@@ -45,3 +115,71 @@ pub use advanced::*;
 pub use tutorial::*;
 pub use achievements::*;
 pub use onboarding::*;
+pub use sandbox::*;
+pub use keymap::*;
+pub use simulation::*;
+pub use determinism::*;
+pub use action_bar::*;
+pub use ghost::*;
+pub use save_diff::*;
+pub use bonus_chits::*;
+pub use game_log::*;
+pub use viewport::*;
+pub use notes::*;
+pub use asset_fallback::*;
+pub use theme::*;
+pub use win_probability::*;
+pub use coop::*;
+pub use clock::*;
+pub use undo_diff::*;
+pub use order_swap::*;
+pub use hand_limit::*;
+pub use season_summary::*;
+pub use history::*;
+pub use wake_up::*;
+pub use shortcuts::*;
+pub use resignation::*;
+pub use supply::*;
+pub use text_scale::*;
+pub use first_game::*;
+pub use agents::*;
+pub use lobby::*;
+pub use estate::*;
+pub use hand_picker::*;
+pub use field_picker::*;
+pub use prediction::*;
+pub use visitor_targeting::*;
+pub use coach::*;
+pub use upkeep::*;
+pub use grande::*;
+pub use action_columns::*;
+pub use recent_actions::*;
+pub use cursors::*;
+pub use rng::*;
+pub use festival::*;
+pub use console::*;
+pub use structure_roi::*;
+pub use final_decks::*;
+pub use retention::*;
+pub use net::*;
+pub use split_screen::*;
+pub use worker_badges::*;
+pub use handoff::*;
+pub use order_tokens::*;
+pub use play_by_file::*;
+pub use opening_book::*;
+pub use schedule_audit::*;
+pub use headless::*;
+pub use income_forecast::*;
+pub use visitor_script::*;
+pub use card_gallery::*;
+pub use render_layers::*;
+pub use planner::*;
+pub use choice_memory::*;
+pub use advisor::*;
+pub use legal_actions::*;
+pub use dashboard_sections::*;
+pub use end_turn::*;
+pub use seat_choices::*;
+#[cfg(feature = "inspector")]
+pub use inspector::*;
@@ -0,0 +1,527 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+use crate::components::*;
+use crate::systems::ai::AISettings;
+use crate::systems::expansions::ExpansionSettings;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::wake_up::WakeUpCharts;
+use crate::systems::input::PassEvent;
+use crate::systems::lobby::*;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::*;
+use crate::systems::resignation::{ConnectionLost, ResignEvent, ResignReason, Resigned};
+use crate::systems::save::{create_save_data, load_save_data, state_to_u8, u8_to_state, GameSnapshot, SaveData};
+use crate::systems::seat_choices::SeatChoice;
+use crate::systems::settings::UserSettings;
+
+// Plain TCP transport for online play. One instance hosts, the others join by address;
+// every message is a length-prefixed JSON frame. Each connection gets a reader thread that
+// hands complete messages to the main thread, and writes happen straight from the systems.
+// The host is authoritative: it validates placements and passes and decides the seasons.
+
+pub const DEFAULT_PORT: u16 = 7878;
+const MAX_FRAME_BYTES: usize = 1 << 20;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum NetMessage {
+    // Sent by the host as soon as a peer connects; that peer's id for the session
+    Hello { peer: u8 },
+    Lobby(LobbyMessage),
+    Placement(PlacementMessage),
+    Pass { player: u8 },
+    // The host's season after every transition
+    Season { state: u8, year: u8 },
+    // A human's choice outside worker placement; relayed by the host once it checks out
+    Choice { player: u8, choice: SeatChoice },
+    Resign { player: u8, reason: ResignReason },
+    // The host's answer to a peer that joined mid-game in place of a dropped player: the seat,
+    // the table and the game so far as a save file's JSON
+    Rejoin { player: u8, peers: Vec<LobbyPeer>, settings: LobbySettings, save: String },
+}
+
+pub fn encode_frame(message: &NetMessage) -> Vec<u8> {
+    let body = serde_json::to_vec(message).unwrap_or_default();
+    let mut frame = (body.len() as u32).to_be_bytes().to_vec();
+    frame.extend(body);
+    frame
+}
+
+// Bytes read so far from one connection
+#[derive(Default)]
+pub struct FrameBuffer {
+    bytes: Vec<u8>,
+}
+
+impl FrameBuffer {
+    // Every message completed by `data`; an oversized or unreadable frame ends the connection
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<NetMessage>, String> {
+        self.bytes.extend_from_slice(data);
+        let mut messages = Vec::new();
+        while self.bytes.len() >= 4 {
+            let len = u32::from_be_bytes([self.bytes[0], self.bytes[1], self.bytes[2], self.bytes[3]]) as usize;
+            if len > MAX_FRAME_BYTES {
+                return Err(format!("frame of {} bytes", len));
+            }
+            if self.bytes.len() < 4 + len {
+                break;
+            }
+            let body: Vec<u8> = self.bytes.drain(..4 + len).skip(4).collect();
+            messages.push(serde_json::from_slice(&body).map_err(|e| e.to_string())?);
+        }
+        Ok(messages)
+    }
+}
+
+// How the game was launched: `--host [port]` or `--join <address>`, with an optional `--name`
+#[derive(Clone, Debug, PartialEq)]
+pub enum NetLaunch {
+    Host { port: u16, name: String },
+    Join { address: String, name: String },
+}
+
+pub fn parse_net_args(args: &[String]) -> Option<NetLaunch> {
+    let value_after = |flag: &str| args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1)).filter(|v| !v.starts_with("--"));
+    let name = value_after("--name").cloned().unwrap_or_else(|| "Player".to_string());
+    if args.iter().any(|a| a == "--host") {
+        let port = value_after("--host").and_then(|p| p.parse().ok()).unwrap_or(DEFAULT_PORT);
+        return Some(NetLaunch::Host { port, name });
+    }
+    value_after("--join").map(|address| {
+        let address = if address.contains(':') { address.clone() } else { format!("{}:{}", address, DEFAULT_PORT) };
+        NetLaunch::Join { address, name }
+    })
+}
+
+enum NetEvent {
+    Connected(u8, TcpStream),
+    Message(u8, NetMessage),
+    Disconnected(u8),
+}
+
+// Present while hosting or joined
+#[derive(Resource)]
+pub struct NetSession {
+    pub is_host: bool,
+    pub local_peer: u8,
+    // Write halves; the client's only peer is the host (0)
+    peers: Vec<(u8, TcpStream)>,
+    events: Mutex<Receiver<NetEvent>>,
+}
+
+impl NetSession {
+    pub fn host(port: u16) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        let (sender, events) = channel();
+        std::thread::spawn(move || {
+            let mut next_peer = 1u8;
+            for stream in listener.incoming().flatten() {
+                let Ok(writer) = stream.try_clone() else { continue };
+                let peer = next_peer;
+                next_peer = next_peer.wrapping_add(1).max(1);
+                if sender.send(NetEvent::Connected(peer, writer)).is_err() {
+                    return;
+                }
+                spawn_reader(peer, stream, sender.clone());
+            }
+        });
+        info!("🌐 Hosting on port {}", port);
+        Ok(Self { is_host: true, local_peer: 0, peers: Vec::new(), events: Mutex::new(events) })
+    }
+
+    pub fn join(address: &str) -> std::io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?;
+        let (sender, events) = channel();
+        spawn_reader(0, stream.try_clone()?, sender);
+        info!("🌐 Connected to {}", address);
+        // The real id arrives with the host's Hello
+        Ok(Self { is_host: false, local_peer: u8::MAX, peers: vec![(0, stream)], events: Mutex::new(events) })
+    }
+
+    pub fn connected_peers(&self) -> Vec<u8> {
+        self.peers.iter().map(|(peer, _)| *peer).collect()
+    }
+
+    // The host broadcasts to everyone, a client sends to the host
+    pub fn send(&mut self, message: &NetMessage) {
+        let frame = encode_frame(message);
+        self.peers.retain_mut(|(peer, stream)| match stream.write_all(&frame) {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("🌐 Dropping peer {}: {}", peer, e);
+                false
+            }
+        });
+    }
+
+    fn send_to(&mut self, peer: u8, message: &NetMessage) {
+        if let Some((_, stream)) = self.peers.iter_mut().find(|(p, _)| *p == peer) {
+            let _ = stream.write_all(&encode_frame(message));
+        }
+    }
+
+    fn drain(&self) -> Vec<NetEvent> {
+        self.events.lock().map(|events| events.try_iter().collect()).unwrap_or_default()
+    }
+}
+
+fn spawn_reader(peer: u8, mut stream: TcpStream, sender: Sender<NetEvent>) {
+    std::thread::spawn(move || {
+        let mut buffer = FrameBuffer::default();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let read = match stream.read(&mut chunk) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            match buffer.push(&chunk[..read]) {
+                Ok(messages) => {
+                    for message in messages {
+                        if sender.send(NetEvent::Message(peer, message)).is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("🌐 Bad frame from peer {}: {}", peer, e);
+                    break;
+                }
+            }
+        }
+        let _ = sender.send(NetEvent::Disconnected(peer));
+    });
+}
+
+// Passes and seasons from the network, for net_replication_system
+#[derive(Event, Clone, Debug)]
+pub struct NetIncoming {
+    pub from: u8,
+    pub message: NetMessage,
+}
+
+// Startup: opens the session asked for on the command line and goes straight to its lobby
+pub fn net_launch_system(
+    mut commands: Commands,
+    mut config: ResMut<GameConfig>,
+    expansions: Res<ExpansionSettings>,
+    settings: Res<UserSettings>,
+    (preset, charts, game_settings): (Res<RulesPreset>, Res<WakeUpCharts>, Res<GameSettings>),
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let args: Vec<String> = std::env::args().collect();
+    let Some(launch) = parse_net_args(&args) else { return };
+    let table = LobbySettings::from_resources(&config, &expansions, &settings, *preset, &charts, &game_settings);
+
+    match launch {
+        NetLaunch::Host { port, name } => match NetSession::host(port) {
+            Ok(session) => {
+                // Fixed up front so every peer's game replays the host's shuffles
+                let seed = *config.seed.get_or_insert_with(rand::random);
+                commands.insert_resource(Lobby::host(&name, LobbySettings { seed: Some(seed), ..table }));
+                commands.insert_resource(session);
+                next_state.set(GameState::Lobby);
+            }
+            Err(e) => error!("🌐 Could not host on port {}: {}", port, e),
+        },
+        NetLaunch::Join { address, name } => match NetSession::join(&address) {
+            Ok(mut session) => {
+                session.send(&NetMessage::Lobby(LobbyMessage::Join { name }));
+                commands.insert_resource(Lobby::join(table));
+                commands.insert_resource(session);
+                next_state.set(GameState::Lobby);
+            }
+            Err(e) => error!("🌐 Could not join {}: {}", address, e),
+        },
+    }
+}
+
+// Moves messages between the sockets and the lobby, placement and replication events
+pub fn net_transport_system(
+    mut commands: Commands,
+    mut session: ResMut<NetSession>,
+    mut lobby: Option<ResMut<Lobby>>,
+    (mut lobby_in, mut lobby_out): (EventWriter<LobbyIncoming>, EventReader<LobbyOutgoing>),
+    (mut placement_in, mut placement_out): (EventWriter<PlacementIncoming>, EventReader<PlacementOutgoing>),
+    mut net_in: EventWriter<NetIncoming>,
+    seating: Option<Res<LobbySeating>>,
+    players: Query<(Entity, &Player)>,
+    real_time: Res<Time<Real>>,
+) {
+    for event in session.drain() {
+        match event {
+            NetEvent::Connected(peer, stream) => {
+                let _ = stream.set_nodelay(true);
+                session.peers.push((peer, stream));
+                session.send_to(peer, &NetMessage::Hello { peer });
+                info!("🌐 Peer {} connected", peer);
+            }
+            NetEvent::Disconnected(peer) => {
+                session.peers.retain(|(p, _)| *p != peer);
+                warn!("🌐 Peer {} disconnected", peer);
+                // Before the game their seat opens up again
+                if let Some(roster) = lobby.as_mut().and_then(|lobby| lobby.leave(peer)) {
+                    session.send(&NetMessage::Lobby(roster));
+                }
+                // During it their seat waits out the reconnect grace period before they resign,
+                // unless they join again in time (net_rejoin_system)
+                let seat = seating.as_ref().filter(|_| session.is_host).and_then(|s| s.seat_of(peer));
+                if let Some((entity, _)) = players.iter().find(|(_, p)| Some(p.id) == seat) {
+                    commands.entity(entity).insert(ConnectionLost { since: real_time.elapsed_seconds() });
+                }
+            }
+            NetEvent::Message(from, NetMessage::Hello { peer }) if !session.is_host && from == 0 => {
+                session.local_peer = peer;
+                if let Some(lobby) = lobby.as_mut() {
+                    lobby.local_peer = peer;
+                }
+            }
+            NetEvent::Message(from, NetMessage::Lobby(message)) => {
+                lobby_in.send(LobbyIncoming { from, message });
+            }
+            NetEvent::Message(from, NetMessage::Placement(message)) => {
+                placement_in.send(PlacementIncoming { from, message });
+            }
+            NetEvent::Message(from, message) => {
+                net_in.send(NetIncoming { from, message });
+            }
+        }
+    }
+
+    for LobbyOutgoing(message) in lobby_out.read() {
+        session.send(&NetMessage::Lobby(message.clone()));
+    }
+    for PlacementOutgoing(message) in placement_out.read() {
+        session.send(&NetMessage::Placement(message.clone()));
+    }
+}
+
+// The seat a peer joining mid-game as `name` takes back: the dropped seat that name sat in,
+// or else the first one still waiting
+pub fn rejoin_seat(seating: &LobbySeating, dropped: &[PlayerId], name: &str) -> Option<PlayerId> {
+    dropped.iter().copied()
+        .find(|seat| seating.name_at(seat.0) == Some(name))
+        .or_else(|| dropped.first().copied())
+}
+
+// Host: a Join once the game is under way is a dropped player coming back. They take back
+// their seat if it is still in its grace period and get the game as it stands.
+pub fn net_rejoin_system(
+    mut commands: Commands,
+    mut session: ResMut<NetSession>,
+    (remote, seating): (Option<ResMut<RemotePlay>>, Option<ResMut<LobbySeating>>),
+    mut incoming: EventReader<LobbyIncoming>,
+    dropped: Query<(Entity, &Player), (With<ConnectionLost>, Without<Resigned>)>,
+    snapshot: GameSnapshot,
+    (expansions, settings, preset, charts, game_settings): (Res<ExpansionSettings>, Res<UserSettings>, Res<RulesPreset>, Res<WakeUpCharts>, Res<GameSettings>),
+) {
+    let (Some(mut remote), Some(mut seating)) = (remote.filter(|r| r.is_host), seating) else {
+        incoming.clear();
+        return;
+    };
+
+    for event in incoming.read() {
+        let LobbyMessage::Join { name } = &event.message else { continue };
+        let mut lost: Vec<PlayerId> = dropped.iter().map(|(_, p)| p.id).collect();
+        lost.sort_by_key(|id| id.0);
+        let Some(seat) = rejoin_seat(&seating, &lost, name) else {
+            warn!("🌐 {} joined mid-game with no dropped seat to take", name);
+            continue;
+        };
+
+        remote.seats.retain(|(_, player)| *player != seat);
+        remote.seats.push((event.from, seat));
+        if let Some(peer) = seating.peers.iter_mut().find(|p| p.seat == seat.0) {
+            peer.id = event.from;
+            peer.name = name.clone();
+        }
+        if let Some((entity, _)) = dropped.iter().find(|(_, p)| p.id == seat) {
+            commands.entity(entity).remove::<ConnectionLost>();
+        }
+
+        let table = LobbySettings::from_resources(&snapshot.config, &expansions, &settings, *preset, &charts, &game_settings);
+        match create_save_data(&snapshot).and_then(|save| serde_json::to_string(&save).map_err(|e| e.to_string())) {
+            Ok(save) => {
+                session.send_to(event.from, &NetMessage::Rejoin { player: seat.0, peers: seating.peers.clone(), settings: table, save });
+                info!("🌐 {} is back as player {}", name, seat.0 + 1);
+            }
+            Err(e) => error!("🌐 Could not send the game to {}: {}", name, e),
+        }
+    }
+}
+
+// Client: takes up the seat and the game the host sent back after a reconnect
+pub fn net_resume_system(
+    mut commands: Commands,
+    remote: Option<Res<RemotePlay>>,
+    mut incoming: EventReader<NetIncoming>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut config: ResMut<GameConfig>,
+    mut expansions: ResMut<ExpansionSettings>,
+    mut settings: ResMut<UserSettings>,
+    (mut preset, mut charts, mut game_settings): (ResMut<RulesPreset>, ResMut<WakeUpCharts>, ResMut<GameSettings>),
+    ai_settings: Option<Res<AISettings>>,
+) {
+    for event in incoming.read() {
+        let NetMessage::Rejoin { player, peers, settings: table, save } = &event.message else { continue };
+        if remote.is_some() || event.from != 0 {
+            continue;
+        }
+        let save: SaveData = match serde_json::from_str(save) {
+            Ok(save) => save,
+            Err(e) => {
+                error!("🌐 Could not read the game from the host: {}", e);
+                continue;
+            }
+        };
+
+        table.apply(&mut config, &mut expansions, &mut settings, &mut preset, &mut charts, &mut game_settings);
+        commands.insert_resource(LobbySeating { peers: peers.clone() });
+        commands.insert_resource(PlayerColors::from_peers(peers, table.player_count));
+        commands.insert_resource(RemotePlay::client(PlayerId(*player)));
+        commands.remove_resource::<Lobby>();
+        let difficulty = ai_settings.as_ref().map_or(AISettings::default().ai_difficulty, |s| s.ai_difficulty);
+        load_save_data(&mut commands, &save, &mut next_state, &game_settings, difficulty, None);
+        info!("🌐 Back in the game as player {}", player + 1);
+        return;
+    }
+}
+
+// Seats the peers once the lobby hands over to Setup, where each picked to sit
+pub fn net_seating_system(
+    mut commands: Commands,
+    session: Res<NetSession>,
+    remote: Option<Res<RemotePlay>>,
+    seating: Option<Res<LobbySeating>>,
+    current_state: Res<State<GameState>>,
+) {
+    if remote.is_some() || !matches!(current_state.get(), GameState::Setup) {
+        return;
+    }
+    let seat_of = |peer: u8| seating.as_ref().and_then(|s| s.seat_of(peer)).unwrap_or(PlayerId(peer));
+    let local_player = seat_of(session.local_peer);
+    let remote_play = if session.is_host {
+        let seats = session.connected_peers().into_iter().map(|peer| (peer, seat_of(peer))).collect();
+        RemotePlay::host(local_player, seats)
+    } else {
+        RemotePlay::client(local_player)
+    };
+    info!("🌐 Playing as player {}", local_player.0 + 1);
+    commands.insert_resource(remote_play);
+}
+
+// Sends what this machine decides: the host its season changes and the passes and
+// resignations of the seats it runs, a client its own
+pub fn net_forward_system(
+    mut session: ResMut<NetSession>,
+    remote: Option<Res<RemotePlay>>,
+    mut pass_events: EventReader<PassEvent>,
+    mut resign_events: EventReader<ResignEvent>,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+) {
+    let Some(remote) = remote else {
+        pass_events.clear();
+        resign_events.clear();
+        return;
+    };
+
+    for event in pass_events.read() {
+        let forward = if remote.is_host { !remote.is_remote_seat(event.player) } else { event.player == remote.local_player };
+        if forward {
+            session.send(&NetMessage::Pass { player: event.player.0 });
+        }
+    }
+
+    // The host also speaks for a dropped seat whose grace period ran out
+    for event in resign_events.read() {
+        let forward = if remote.is_host {
+            !remote.is_remote_seat(event.player) || event.reason == ResignReason::Disconnected
+        } else {
+            event.player == remote.local_player
+        };
+        if forward {
+            session.send(&NetMessage::Resign { player: event.player.0, reason: event.reason });
+        }
+    }
+
+    if remote.is_host && current_state.is_changed() {
+        session.send(&NetMessage::Season { state: state_to_u8(current_state.get()), year: config.current_year });
+    }
+}
+
+// Applies what the others decided: the host checks a peer's pass or resignation is theirs to
+// make and relays it, clients follow the host's passes, resignations and seasons
+pub fn net_replication_system(
+    mut session: ResMut<NetSession>,
+    remote: Option<Res<RemotePlay>>,
+    mut incoming: EventReader<NetIncoming>,
+    mut pass_events: EventWriter<PassEvent>,
+    mut resign_events: EventWriter<ResignEvent>,
+    turn_order: Res<TurnOrder>,
+    current_state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut config: ResMut<GameConfig>,
+) {
+    let Some(remote) = remote else {
+        incoming.clear();
+        return;
+    };
+
+    for event in incoming.read() {
+        match event.message {
+            NetMessage::Pass { player } if remote.is_host => {
+                let player = PlayerId(player);
+                let on_turn = turn_order.players.get(turn_order.current_player) == Some(&player);
+                if remote.seat_of(event.from) == Some(player) && on_turn {
+                    pass_events.send(PassEvent { player });
+                    session.send(&NetMessage::Pass { player: player.0 });
+                } else {
+                    warn!("🌐 Ignored pass for player {} from peer {}", player.0 + 1, event.from);
+                }
+            }
+            NetMessage::Pass { player } if event.from == 0 && PlayerId(player) != remote.local_player => {
+                pass_events.send(PassEvent { player: PlayerId(player) });
+            }
+            NetMessage::Resign { player, reason } if remote.is_host => {
+                let player = PlayerId(player);
+                if remote.seat_of(event.from) == Some(player) && reason == ResignReason::Choice {
+                    resign_events.send(ResignEvent { player, reason });
+                    session.send(&NetMessage::Resign { player: player.0, reason });
+                } else {
+                    warn!("🌐 Ignored resignation of player {} from peer {}", player.0 + 1, event.from);
+                }
+            }
+            NetMessage::Resign { player, reason } if event.from == 0 && PlayerId(player) != remote.local_player => {
+                resign_events.send(ResignEvent { player: PlayerId(player), reason });
+            }
+            NetMessage::Season { state, year } if !remote.is_host && event.from == 0 => {
+                config.current_year = year;
+                if let Some(state) = u8_to_state(state).filter(|s| s != current_state.get()) {
+                    next_state.set(state);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Run condition: AI seats are played by the host alone and reach clients as confirmed placements
+pub fn remote_client(remote: Option<Res<RemotePlay>>) -> bool {
+    remote.is_some_and(|r| !r.is_host)
+}
+
+// Whether this machine makes `player`'s choices: every seat offline, only its own seat in a
+// networked or play-by-file game
+pub fn plays_locally(remote: Option<&RemotePlay>, pbf: Option<&PlayByFile>, player: PlayerId) -> bool {
+    remote.map_or(true, |r| r.local_player == player) && pbf.map_or(true, |p| p.local_player == player)
+}
+
+// Run condition: board input only acts for the seat this machine plays
+pub fn local_turn(remote: Option<Res<RemotePlay>>, pbf: Option<Res<PlayByFile>>, turn_order: Res<TurnOrder>) -> bool {
+    let on_turn = turn_order.players.get(turn_order.current_player);
+    remote.map_or(true, |r| on_turn == Some(&r.local_player)) && pbf.map_or(true, |p| on_turn == Some(&p.local_player))
+}
@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use std::collections::HashMap;
+use crate::components::*;
+use crate::systems::keymap::{InputAction, Keymap};
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+const MAX_NOTE_LENGTH: usize = 280;
+
+// Private per-player notes; only the active human player's note is ever shown
+#[derive(Resource, Default, Clone)]
+pub struct PlayerNotes {
+    pub notes: HashMap<PlayerId, String>,
+    pub editing: Option<PlayerId>,
+}
+
+impl PlayerNotes {
+    pub fn note(&self, player_id: PlayerId) -> &str {
+        self.notes.get(&player_id).map(String::as_str).unwrap_or("")
+    }
+
+    // While a note is open, keyboard shortcuts are suppressed so typing doesn't trigger them
+    pub fn capturing_text(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+#[derive(Component)]
+pub struct NotesPanel;
+
+#[derive(Component)]
+pub struct NotesText;
+
+#[derive(Component)]
+pub struct PostGameNotesPanel;
+
+// The notes key opens the current human player's notes; Escape closes them
+pub fn notes_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    keymap: Res<Keymap>,
+    mut commands: Commands,
+    mut notes: ResMut<PlayerNotes>,
+    turn_order: Res<TurnOrder>,
+    players: Query<&Player>,
+    existing: Query<Entity, With<NotesPanel>>,
+) {
+    let current_player = turn_order.players.get(turn_order.current_player).copied();
+
+    // Close automatically when the turn passes so notes are never shown to the next player
+    let stale = notes.editing.is_some() && notes.editing != current_player;
+    let close_requested = notes.editing.is_some() && keyboard.just_pressed(KeyCode::Escape);
+
+    if stale || close_requested {
+        notes.editing = None;
+        for entity in existing.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if notes.editing.is_some() || !keymap.key_just_pressed(InputAction::Notes, &keyboard) {
+        return;
+    }
+
+    let Some(player_id) = current_player else { return };
+    if players.iter().any(|p| p.id == player_id && p.is_ai) {
+        return;
+    }
+
+    notes.editing = Some(player_id);
+    spawn_notes_panel(&mut commands, player_id, notes.note(player_id));
+}
+
+pub fn notes_text_input_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut notes: ResMut<PlayerNotes>,
+    mut texts: Query<&mut Text, With<NotesText>>,
+    mut opened_this_frame: Local<bool>,
+) {
+    let Some(player_id) = notes.editing else {
+        *opened_this_frame = true;
+        keyboard_events.clear();
+        return;
+    };
+
+    // Skip the key press that opened the panel
+    if *opened_this_frame {
+        *opened_this_frame = false;
+        keyboard_events.clear();
+        return;
+    }
+
+    let note = notes.notes.entry(player_id).or_default();
+    let mut changed = false;
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) if note.len() < MAX_NOTE_LENGTH => {
+                note.push_str(chars);
+                changed = true;
+            }
+            Key::Space if note.len() < MAX_NOTE_LENGTH => {
+                note.push(' ');
+                changed = true;
+            }
+            Key::Enter if note.len() < MAX_NOTE_LENGTH => {
+                note.push('\n');
+                changed = true;
+            }
+            Key::Backspace => {
+                note.pop();
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+
+    if changed {
+        let display = notes_display(notes.note(player_id));
+        for mut text in texts.iter_mut() {
+            text.sections[0].value = display.clone();
+        }
+    }
+}
+
+fn notes_display(note: &str) -> String {
+    if note.is_empty() {
+        "(type your plans...)".to_string()
+    } else {
+        format!("{}_", note)
+    }
+}
+
+fn spawn_notes_panel(commands: &mut Commands, player_id: PlayerId, note: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                left: Val::Px(20.0),
+                width: Val::Px(300.0),
+                min_height: Val::Px(120.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.18, 0.1, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.8, 0.7, 0.4, 1.0)).into(),
+            z_index: UiLayer::Screen.z_index(),
+            ..default()
+        },
+        NotesPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            format!("📝 Player {} notes (ESC to close)", player_id.0 + 1),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::from(Srgba::new(1.0, 0.9, 0.6, 1.0)),
+                ..default()
+            },
+        ));
+        panel.spawn((
+            TextBundle::from_section(
+                notes_display(note),
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ).with_style(Style {
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            }),
+            NotesText,
+        ));
+    });
+}
+
+// Optionally shows each human player's notes next to the game-over screen
+pub fn post_game_notes_system(
+    mut commands: Commands,
+    notes: Res<PlayerNotes>,
+    settings: Res<UserSettings>,
+    players: Query<&Player>,
+    modal: Query<Entity, With<GameOverModal>>,
+    existing: Query<Entity, With<PostGameNotesPanel>>,
+) {
+    if modal.is_empty() {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if !existing.is_empty() || !settings.show_notes_after_game {
+        return;
+    }
+
+    let mut text = String::from("📝 PLAYER NOTES\n");
+    let mut any = false;
+    let mut humans: Vec<_> = players.iter().filter(|p| !p.is_ai).collect();
+    humans.sort_by_key(|p| p.id.0);
+    for player in humans {
+        let note = notes.note(player.id);
+        if !note.is_empty() {
+            text.push_str(&format!("\n{}:\n{}\n", player.name, note));
+            any = true;
+        }
+    }
+
+    if !any {
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                right: Val::Px(20.0),
+                width: Val::Px(280.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.18, 0.1, 0.95)).into(),
+            z_index: UiLayer::GameOver.z_index(),
+            ..default()
+        },
+        PostGameNotesPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 13.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
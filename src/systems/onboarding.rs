@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use crate::components::*;
+use crate::systems::clock::{GameClock, GameTimer};
+use crate::systems::render_layers::UiLayer;
 
 #[derive(Serialize, Deserialize, Resource, Default)]
 pub struct OnboardingState {
@@ -137,7 +139,7 @@ fn show_welcome_screen(commands: &mut Commands, onboarding: &OnboardingState) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.0, 0.0, 0.9)).into(),
-            z_index: ZIndex::Global(1500),
+            z_index: UiLayer::Tutorial.z_index(),
             ..default()
         },
         OnboardingUI,
@@ -358,12 +360,12 @@ fn show_gameplay_tip(commands: &mut Commands, tip: &Tip) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.0, 0.4, 0.9)).into(),
-            z_index: ZIndex::Global(700),
+            z_index: UiLayer::Tips.z_index(),
             ..default()
         },
         GameplayTip {
             tip_id: tip.id.clone(),
-            display_timer: Timer::from_seconds(6.0, TimerMode::Once),
+            display_timer: GameClock::once(GameTimer::GameplayTip),
         },
     )).with_children(|parent| {
         parent.spawn(TextBundle::from_section(
@@ -442,7 +444,7 @@ pub fn new_player_encouragement_system(
                     ..default()
                 },
                 background_color: Color::from(Srgba::new(0.0, 0.3, 0.0, 0.9)).into(),
-                z_index: ZIndex::Global(1100),
+                z_index: UiLayer::Tutorial.z_index(),
                 ..default()
             },
             OnboardingUI,
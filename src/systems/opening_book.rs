@@ -0,0 +1,119 @@
+use rand::prelude::*;
+use crate::components::*;
+use crate::components::ActionSpace::*;
+use crate::systems::ai::{evaluate_action, AiDecisionInput};
+
+// Early years lean on a handful of known openings instead of the scorer. A line lists, for
+// each season of the first two years, the spaces to take in order of preference. The AI
+// keeps to lines that agree with the workers it has already placed, picks one of them at
+// random, and takes its first step that is open and would do something.
+
+pub const OPENING_BOOK_YEARS: u8 = 2;
+
+pub struct OpeningLine {
+    pub name: &'static str,
+    // Indexed by year - 1
+    pub summer: [&'static [ActionSpace]; 2],
+    pub winter: [&'static [ActionSpace]; 2],
+}
+
+// One space per action: grab vines before the other player does
+const TWO_PLAYER_LINES: &[OpeningLine] = &[
+    OpeningLine {
+        name: "Vine rush",
+        summer: [&[DrawVine, PlantVine, GiveTour], &[PlantVine, DrawVine, BuildStructure]],
+        winter: [&[DrawWineOrder, Harvest, TrainWorker], &[Harvest, MakeWine, FillOrder]],
+    },
+    OpeningLine {
+        name: "Tour first",
+        summer: [&[GiveTour, DrawVine, PlantVine], &[BuildStructure, PlantVine, GiveTour]],
+        winter: [&[TrainWorker, DrawWineOrder, Harvest], &[Harvest, MakeWine, FillOrder, DrawWineOrder]],
+    },
+    OpeningLine {
+        name: "Early order",
+        summer: [&[DrawVine, GiveTour, PlantVine], &[PlantVine, GiveTour, DrawVine]],
+        winter: [&[DrawWineOrder, TrainWorker, Harvest], &[Harvest, FillOrder, MakeWine]],
+    },
+];
+
+// A second column opens, so an early extra worker pays off
+const MID_TABLE_LINES: &[OpeningLine] = &[
+    OpeningLine {
+        name: "Draw, plant, tour",
+        summer: [&[DrawVine, PlantVine, GiveTour], &[PlantVine, BuildStructure, DrawVine]],
+        winter: [&[TrainWorker, DrawWineOrder, Harvest], &[Harvest, MakeWine, FillOrder]],
+    },
+    OpeningLine {
+        name: "Builder",
+        summer: [&[GiveTour, BuildStructure, DrawVine], &[DrawVine, PlantVine, GiveTour]],
+        winter: [&[DrawWineOrder, Harvest, TrainWorker], &[Harvest, MakeWine, DrawWineOrder]],
+    },
+    OpeningLine {
+        name: "Trader",
+        summer: [&[DrawVine, GiveTour, PlantVine], &[PlantVine, DrawVine, SellGrapes]],
+        winter: [&[DrawWineOrder, TrainWorker, Harvest], &[Harvest, FillOrder, MakeWine]],
+    },
+];
+
+// Crowded boards: take lira early, the plant spaces go fast
+const FULL_TABLE_LINES: &[OpeningLine] = &[
+    OpeningLine {
+        name: "Money first",
+        summer: [&[GiveTour, DrawVine, PlantVine], &[PlantVine, GiveTour, BuildStructure]],
+        winter: [&[TrainWorker, Harvest, DrawWineOrder], &[Harvest, MakeWine, FillOrder]],
+    },
+    OpeningLine {
+        name: "Draw, plant, build",
+        summer: [&[DrawVine, PlantVine, BuildStructure], &[DrawVine, PlantVine, GiveTour]],
+        winter: [&[DrawWineOrder, TrainWorker, Harvest], &[Harvest, MakeWine, FillOrder]],
+    },
+];
+
+pub fn opening_lines(player_count: u8) -> &'static [OpeningLine] {
+    match player_count {
+        0..=2 => TWO_PLAYER_LINES,
+        3..=4 => MID_TABLE_LINES,
+        _ => FULL_TABLE_LINES,
+    }
+}
+
+fn is_summer_action(action: ActionSpace) -> bool {
+    matches!(action, DrawVine | PlantVine | BuildStructure | GiveTour | SellGrapes)
+}
+
+// The line's steps for this season, or nothing once the book has run out
+pub fn book_steps(line: &OpeningLine, state: &GameState, year: u8) -> &'static [ActionSpace] {
+    if year == 0 || year > OPENING_BOOK_YEARS {
+        return &[];
+    }
+    let year = year as usize - 1;
+    match state {
+        GameState::Summer => line.summer[year],
+        GameState::Winter => line.winter[year],
+        _ => &[],
+    }
+}
+
+// A line still fits if every worker placed this season went to one of its steps
+fn line_fits(line: &OpeningLine, input: &AiDecisionInput) -> bool {
+    let steps = book_steps(line, &input.state, input.year);
+    let summer = matches!(input.state, GameState::Summer);
+    !steps.is_empty()
+        && input.placed.iter()
+            .filter(|a| is_summer_action(**a) == summer)
+            .all(|a| steps.contains(a))
+}
+
+// The book's move, or None to leave the decision to the difficulty's usual choice
+pub fn opening_book_action(input: &AiDecisionInput, rng: &mut impl Rng) -> Option<ActionSpace> {
+    let lines: Vec<&OpeningLine> = opening_lines(input.player_count).iter()
+        .filter(|line| line_fits(line, input))
+        .collect();
+    let line = lines.choose(rng)?;
+
+    book_steps(line, &input.state, input.year).iter().copied().find(|action| {
+        input.valid_actions.contains(action)
+            && !input.placed.contains(action)
+            && evaluate_action(*action, &input.player, &input.hand, &input.vineyard, &input.state, input.year) > 0.0
+    })
+}
@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::game_log::GameLog;
+use crate::systems::game_logic::ActionOutcome;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// With the order-swap house rule, vines and orders have their own soft limits
+// (checked at the Spring hand-limit step) instead of one shared limit of 7
+pub const VINE_HAND_LIMIT: usize = 5;
+pub const ORDER_HAND_LIMIT: usize = 4;
+
+// AI only swaps away orders it is this many wine tokens short of
+const AI_SWAP_DEFICIT: u8 = 3;
+
+// Swap offers after wine order draws, one per draw; the dialog answers them oldest first
+#[derive(Resource, Default)]
+pub struct OrderSwapOffer {
+    pub pending: Vec<PlayerId>,
+}
+
+impl OrderSwapOffer {
+    pub fn current(&self) -> Option<PlayerId> {
+        self.pending.first().copied()
+    }
+
+    pub fn answered(&mut self) {
+        if !self.pending.is_empty() {
+            self.pending.remove(0);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct OrderSwapDialog;
+
+#[derive(Component)]
+pub struct OrderSwapButton {
+    // The order card's id, so the answer still finds it if the hand changed since the dialog opened
+    pub order_id: Option<u32>,
+}
+
+// Discards the order at `index` and draws a replacement
+pub fn swap_wine_order(hand: &mut Hand, card_decks: &mut CardDecks, index: usize) -> bool {
+    if index >= hand.wine_order_cards.len() {
+        return false;
+    }
+    let discarded = hand.wine_order_cards.remove(index);
+    card_decks.wine_order_discard.push(discarded);
+    match card_decks.draw_wine_order_card() {
+        Some(card) => {
+            hand.wine_order_cards.push(card);
+            true
+        }
+        None => false,
+    }
+}
+
+// Discards the order card with this id, wherever it sits in the hand now
+pub fn swap_wine_order_by_id(hand: &mut Hand, card_decks: &mut CardDecks, order_id: u32) -> bool {
+    match hand.wine_order_cards.iter().position(|order| order.id == order_id) {
+        Some(index) => swap_wine_order(hand, card_decks, index),
+        None => false,
+    }
+}
+
+// How many value steps the best wine or grape of each color is short of the order
+pub fn wine_deficit(order: &WineOrderCard, vineyard: &Vineyard) -> u8 {
+    let shortfall = |needed: u8, wine: &ValueTrack, grapes: &ValueTrack| {
+        let best = wine.highest().max(grapes.highest()).unwrap_or(0);
+        if needed == 0 { 0 } else { needed.saturating_sub(best) }
+    };
+    shortfall(order.red_wine_needed, &vineyard.red_wine, &vineyard.red_grapes)
+        + shortfall(order.white_wine_needed, &vineyard.white_wine, &vineyard.white_grapes)
+}
+
+// The order the AI would throw back, if any is far out of reach
+pub fn ai_order_to_swap(hand: &Hand, vineyard: &Vineyard) -> Option<usize> {
+    hand.wine_order_cards.iter().enumerate()
+        .map(|(i, order)| (i, wine_deficit(order, vineyard)))
+        .filter(|(_, deficit)| *deficit >= AI_SWAP_DEFICIT)
+        .max_by_key(|(_, deficit)| *deficit)
+        .map(|(i, _)| i)
+}
+
+// Watches the game log for wine order draws: AI decides on the spot, humans get an open
+// offer for the dialog to answer
+pub fn order_swap_offer_system(
+    log: Res<GameLog>,
+    settings: Res<UserSettings>,
+    mut offer: ResMut<OrderSwapOffer>,
+    mut card_decks: ResMut<CardDecks>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+    mut hands: Query<&mut Hand>,
+    mut seen: Local<usize>,
+) {
+    if log.entries.len() < *seen {
+        *seen = 0;
+    }
+    let new_entries = &log.entries[*seen..];
+    *seen = log.entries.len();
+
+    if !settings.house_rule_order_swap {
+        return;
+    }
+
+    for entry in new_entries.iter().filter(|e| matches!(e.outcome, ActionOutcome::DrewOrder)) {
+        let Some(player) = players.iter().find(|p| p.id == entry.player) else { continue };
+        let Some(mut hand) = hands.iter_mut().find(|h| h.owner == entry.player) else { continue };
+
+        if player.is_ai {
+            let Some(vineyard) = vineyards.iter().find(|v| v.owner == entry.player) else { continue };
+            if let Some(index) = ai_order_to_swap(&hand, vineyard) {
+                if swap_wine_order(&mut hand, &mut card_decks, index) {
+                    info!("🔁 {} swapped a wine order", player.name);
+                }
+            }
+        } else {
+            offer.pending.push(entry.player);
+        }
+    }
+}
+
+// Shows the oldest open offer and takes the human's answer; the next one opens after it
+pub fn order_swap_dialog_system(
+    mut commands: Commands,
+    mut offer: ResMut<OrderSwapOffer>,
+    mut card_decks: ResMut<CardDecks>,
+    players: Query<&Player>,
+    mut hands: Query<&mut Hand>,
+    buttons: Query<(&Interaction, &OrderSwapButton), Changed<Interaction>>,
+    dialogs: Query<Entity, With<OrderSwapDialog>>,
+) {
+    let Some(player_id) = offer.current() else { return };
+
+    if dialogs.is_empty() {
+        let player = players.iter().find(|p| p.id == player_id);
+        let hand = hands.iter().find(|h| h.owner == player_id);
+        if let (Some(player), Some(hand)) = (player, hand) {
+            spawn_order_swap_dialog(&mut commands, player, hand);
+        }
+        return;
+    }
+
+    let Some(order_id) = buttons.iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| button.order_id) else { return };
+
+    if let Some(order_id) = order_id {
+        if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == player_id) {
+            if swap_wine_order_by_id(&mut hand, &mut card_decks, order_id) {
+                info!("🔁 Player {} swapped a wine order", player_id.0 + 1);
+            }
+        }
+    }
+
+    offer.answered();
+    for entity in dialogs.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn spawn_order_swap_dialog(commands: &mut Commands, player: &Player, hand: &Hand) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(200.0),
+                left: Val::Percent(35.0),
+                width: Val::Px(340.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.15, 0.1, 0.15, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.7, 0.3, 0.5, 1.0)).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        OrderSwapDialog,
+    )).with_children(|dialog| {
+        dialog.spawn(TextBundle::from_section(
+            format!("🔁 {}: discard an order to draw another?", player.name),
+            TextStyle {
+                font_size: 15.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        for order in hand.wine_order_cards.iter() {
+            spawn_swap_button(
+                dialog,
+                format!("Discard {}R/{}W → {} VP, {} lira", order.red_wine_needed, order.white_wine_needed, order.victory_points, order.payout),
+                Some(order.id),
+            );
+        }
+        spawn_swap_button(dialog, "Keep my orders".to_string(), None);
+    });
+}
+
+fn spawn_swap_button(parent: &mut ChildBuilder, label: String, order_id: Option<u32>) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                margin: UiRect::top(Val::Px(8.0)),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.3, 0.2, 0.3, 1.0)).into(),
+            ..default()
+        },
+        OrderSwapButton { order_id },
+    )).with_children(|button| {
+        button.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
@@ -0,0 +1,293 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::hand_picker::order_label;
+use crate::systems::input::ActionRequested;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Fill Order spends the cheapest wine that meets each requirement. With Pick Order Wines on,
+// a human holding more than one qualifying token of a colour chooses which to spend; the
+// choice is stored on the vineyard and the worker goes out through ActionRequested.
+#[derive(Resource, Default)]
+pub struct OrderTokenPicker {
+    pub pending: Option<ActionRequested>,
+    pub red: Option<u8>,
+    pub white: Option<u8>,
+}
+
+impl OrderTokenPicker {
+    // Starts on the tokens the rules would spend
+    pub fn open(&mut self, event: ActionRequested, order: &WineOrderCard, vineyard: &Vineyard) {
+        self.red = qualifying_tokens(&vineyard.order_red_wine(), order.red_wine_needed).first().copied();
+        self.white = qualifying_tokens(&vineyard.white_wine, order.white_wine_needed).first().copied();
+        self.pending = Some(event);
+    }
+
+    fn close(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[derive(Component)]
+pub struct OrderTokenOverlay;
+
+#[derive(Component)]
+pub struct OrderTokenButton {
+    pub red: bool,
+    pub value: u8,
+}
+
+#[derive(Component)]
+pub struct OrderTokenConfirm;
+
+#[derive(Component)]
+pub struct OrderTokenCancel;
+
+const ORDER_CARD_SIZE: Vec2 = Vec2::new(84.0, 112.0);
+
+// Cellar tokens that can pay a requirement, cheapest first; nothing when the order asks for none
+pub fn qualifying_tokens(track: &ValueTrack, needed: u8) -> Vec<u8> {
+    if needed == 0 {
+        return Vec::new();
+    }
+    track.values().into_iter().filter(|v| *v >= needed).collect()
+}
+
+// Only worth asking when some colour offers a real choice
+pub fn needs_token_pick(action: ActionSpace, order: &WineOrderCard, vineyard: &Vineyard) -> bool {
+    action == ActionSpace::FillOrder
+        && vineyard.can_fulfill_order(order)
+        && (qualifying_tokens(&vineyard.order_red_wine(), order.red_wine_needed).len() > 1
+            || qualifying_tokens(&vineyard.white_wine, order.white_wine_needed).len() > 1)
+}
+
+// The order Fill Order is about to resolve is the first in hand
+pub fn wants_token_pick(settings: &UserSettings, action: ActionSpace, hand: &Hand, vineyard: &Vineyard) -> bool {
+    settings.pick_order_wines
+        && hand.wine_order_cards.first().is_some_and(|order| needs_token_pick(action, order, vineyard))
+}
+
+pub fn order_token_picker_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    current_state: Res<State<GameState>>,
+    mut picker: ResMut<OrderTokenPicker>,
+    mut place_events: EventWriter<ActionRequested>,
+    hands: Query<&Hand>,
+    mut vineyards: Query<&mut Vineyard>,
+    token_buttons: Query<(&Interaction, &OrderTokenButton), Changed<Interaction>>,
+    confirm_buttons: Query<&Interaction, (Changed<Interaction>, With<OrderTokenConfirm>)>,
+    cancel_buttons: Query<&Interaction, (Changed<Interaction>, With<OrderTokenCancel>)>,
+    overlays: Query<Entity, With<OrderTokenOverlay>>,
+) {
+    let close = |commands: &mut Commands| {
+        for entity in overlays.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    };
+
+    let Some(pending) = picker.pending else {
+        close(&mut commands);
+        return;
+    };
+
+    let order = hands.iter().find(|h| h.owner == pending.player).and_then(|h| h.wine_order_cards.first());
+    let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == pending.player) else { return };
+    let cancelled = keyboard.just_pressed(KeyCode::Escape)
+        || cancel_buttons.iter().any(|i| *i == Interaction::Pressed)
+        || !matches!(current_state.get(), GameState::Summer | GameState::Winter);
+    let Some(order) = order.filter(|o| !cancelled && vineyard.can_fulfill_order(o)) else {
+        picker.close();
+        close(&mut commands);
+        return;
+    };
+
+    if confirm_buttons.iter().any(|i| *i == Interaction::Pressed) {
+        vineyard.order_wines = (picker.red, picker.white);
+        place_events.send(pending);
+        picker.close();
+        close(&mut commands);
+        return;
+    }
+
+    let reds = qualifying_tokens(&vineyard.order_red_wine(), order.red_wine_needed);
+    let whites = qualifying_tokens(&vineyard.white_wine, order.white_wine_needed);
+    let mut changed = false;
+    for (interaction, button) in token_buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if button.red && reds.contains(&button.value) {
+            picker.red = Some(button.value);
+            changed = true;
+        } else if !button.red && whites.contains(&button.value) {
+            picker.white = Some(button.value);
+            changed = true;
+        }
+    }
+
+    if changed || overlays.is_empty() {
+        close(&mut commands);
+        spawn_picker(&mut commands, order, &[(true, reds.as_slice(), picker.red), (false, whites.as_slice(), picker.white)]);
+    }
+}
+
+fn spawn_picker(commands: &mut Commands, order: &WineOrderCard, rows: &[(bool, &[u8], Option<u8>)]) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(130.0),
+                left: Val::Percent(30.0),
+                max_width: Val::Percent(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.1, 0.1, 0.1).with_alpha(0.95).into(),
+            border_color: Color::srgb(0.6, 0.5, 0.3).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        OrderTokenOverlay,
+    )).with_children(|overlay| {
+        overlay.spawn(TextBundle::from_section(
+            "🍷 Choose the wines to spend (Esc cancels)",
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        overlay.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|body| {
+            // The order card itself, tokens beside it
+            body.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(ORDER_CARD_SIZE.x),
+                    height: Val::Px(ORDER_CARD_SIZE.y),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    border: UiRect::all(Val::Px(3.0)),
+                    ..default()
+                },
+                background_color: order.art_style.get_color().into(),
+                border_color: order.art_style.get_border_color().into(),
+                ..default()
+            }).with_children(|card| {
+                card.spawn(TextBundle::from_section(
+                    order_label(order),
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::BLACK,
+                        ..default()
+                    },
+                ));
+            });
+
+            body.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                ..default()
+            }).with_children(|tokens| {
+                for (red, values, chosen) in rows.iter().filter(|(_, values, _)| !values.is_empty()) {
+                    tokens.spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(4.0),
+                            ..default()
+                        },
+                        ..default()
+                    }).with_children(|row| {
+                        row.spawn(TextBundle::from_section(
+                            if *red { "Red wine" } else { "White wine" },
+                            TextStyle {
+                                font_size: 13.0,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ));
+                        let background = if *red { Color::srgb(0.55, 0.15, 0.2) } else { Color::srgb(0.75, 0.7, 0.35) };
+                        for value in values.iter() {
+                            let picked = *chosen == Some(*value);
+                            row.spawn((
+                                ButtonBundle {
+                                    style: Style {
+                                        width: Val::Px(28.0),
+                                        height: Val::Px(28.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        border: UiRect::all(Val::Px(if picked { 3.0 } else { 1.0 })),
+                                        ..default()
+                                    },
+                                    background_color: background.into(),
+                                    border_color: if picked { Color::srgb(1.0, 0.84, 0.0) } else { Color::srgb(0.2, 0.2, 0.2) }.into(),
+                                    ..default()
+                                },
+                                OrderTokenButton { red: *red, value: *value },
+                            )).with_children(|button| {
+                                button.spawn(TextBundle::from_section(
+                                    value.to_string(),
+                                    TextStyle {
+                                        font_size: 13.0,
+                                        color: Color::WHITE,
+                                        ..default()
+                                    },
+                                ));
+                            });
+                        }
+                    });
+                }
+            });
+        });
+
+        overlay.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row| {
+            for (label, confirm) in [("Fill Order", true), ("Cancel", false)] {
+                let mut button = row.spawn(ButtonBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: if confirm { Color::srgb(0.25, 0.45, 0.25) } else { Color::srgb(0.3, 0.3, 0.3) }.into(),
+                    ..default()
+                });
+                if confirm {
+                    button.insert(OrderTokenConfirm);
+                } else {
+                    button.insert(OrderTokenCancel);
+                }
+                button.with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        label,
+                        TextStyle {
+                            font_size: 13.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+    });
+}
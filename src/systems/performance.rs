@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 use crate::components::*;
 use bevy::diagnostic::{FrameTimeDiagnosticsPlugin,DiagnosticsStore};
-
-const GREY: Srgba = Srgba::new(0.6, 0.6, 0.6, 1.0);
+use crate::systems::game_logic::TemporaryWorker;
+use crate::systems::lobby::PlayerColors;
+use crate::systems::sprites::spawn_worker_sprite;
+use crate::systems::render_layers::WorldLayer;
 
 #[derive(Resource)]
 pub struct PerformanceSettings {
@@ -58,9 +60,9 @@ pub fn cached_ui_update_system(
             let hand = hands.iter().find(|h| h.owner == p.id);
             let vineyard = vineyards.iter().find(|v| v.owner == p.id);
             let total_cards = hand.map(|h| h.vine_cards.len() + h.wine_order_cards.len()).unwrap_or(0) as u8;
-            let total_resources = vineyard.map(|v| v.red_grapes + v.white_grapes + v.red_wine + v.white_wine).unwrap_or(0);
+            let total_resources = vineyard.map(|v| v.red_grapes.count() + v.white_grapes.count() + v.red_wine.count() + v.white_wine.count()).unwrap_or(0);
             
-            (p.id, p.victory_points, p.lira, p.workers, total_cards + total_resources)
+            (p.id, p.victory_points.get(), p.lira.get(), p.workers, total_cards + total_resources)
         })
         .collect();
     
@@ -76,7 +78,7 @@ pub fn cached_ui_update_system(
             
             for player in players.iter() {
                 if player.victory_points > highest_vp {
-                    highest_vp = player.victory_points;
+                    highest_vp = player.victory_points.get();
                     leading_player = &player.name;
                 }
             }
@@ -93,9 +95,9 @@ pub fn cached_ui_update_system(
 pub fn culled_sprite_system(
     mut commands: Commands,
     performance: Res<PerformanceSettings>,
-    camera_q: Query<(&Camera, &GlobalTransform)>,
+    camera_q: Query<(&Camera, &GlobalTransform, &OrthographicProjection)>,
     windows: Query<&Window>,
-    workers: Query<&Worker>,
+    workers: Query<(&Worker, Has<TemporaryWorker>)>,
     vineyards: Query<&Vineyard>,
     hands: Query<&Hand>,
     worker_sprites: Query<Entity, With<WorkerSprite>>,
@@ -104,6 +106,7 @@ pub fn culled_sprite_system(
     turn_order: Res<TurnOrder>,
     mut cache: ResMut<FrameCache>,
     time: Res<Time>,
+    player_colors: Res<PlayerColors>,
 ) {
     if !performance.enable_sprite_culling {
         return;
@@ -117,9 +120,9 @@ pub fn culled_sprite_system(
     cache.last_sprite_update = current_time;
     
     // Get visible area from camera
-    let (camera, camera_transform) = camera_q.single();
+    let (camera, camera_transform, projection) = camera_q.single();
     let window = windows.single();
-    let viewport_size = Vec2::new(window.width(), window.height());
+    let viewport_size = crate::systems::viewport::visible_world_size(window, projection);
     
     // Clear existing sprites
     for entity in worker_sprites.iter() {
@@ -133,9 +136,9 @@ pub fn culled_sprite_system(
     }
     
     // Only render workers that are visible
-    for worker in workers.iter() {
+    for (worker, temporary) in workers.iter() {
         if is_position_visible(worker.position, camera_transform.translation().truncate(), viewport_size) {
-            spawn_worker_sprite(&mut commands, worker);
+            spawn_worker_sprite(&mut commands, worker, temporary, Color::from(player_colors.srgba(worker.owner)));
         }
     }
     
@@ -162,38 +165,6 @@ fn is_position_visible(pos: Vec2, camera_pos: Vec2, viewport_size: Vec2) -> bool
     pos.x >= min.x && pos.x <= max.x && pos.y >= min.y && pos.y <= max.y
 }
 
-fn spawn_worker_sprite(commands: &mut Commands, worker: &Worker) {
-    let player_colors = [
-        Color::from(Srgba::RED),
-        Color::from(Srgba::BLUE),
-        Color::from(Srgba::GREEN),
-        Color::from(Srgba::new(1.0, 0.0, 1.0, 1.0)),
-    ];
-    
-    let color_grey = Color::from(GREY);
-    let color = player_colors.get(worker.owner.0 as usize).unwrap_or(&color_grey);
-    let final_color = if worker.is_grande {
-        Color::from(Srgba::new(color.to_srgba().red * 1.2, color.to_srgba().green * 1.2, color.to_srgba().blue * 1.2, 1.0))
-    } else {
-        *color
-    };
-    
-    let size = if worker.is_grande { Vec2::new(20.0, 20.0) } else { Vec2::new(16.0, 16.0) };
-    
-    commands.spawn((
-        SpriteBundle {
-            sprite: Sprite {
-                color: final_color,
-                custom_size: Some(size),
-                ..default()
-            },
-            transform: Transform::from_translation(worker.position.extend(if worker.is_grande { 1.5 } else { 1.0 })),
-            ..default()
-        },
-        WorkerSprite { player_id: worker.owner },
-    ));
-}
-
 fn spawn_vineyard_sprites(commands: &mut Commands, vineyard: &Vineyard) {
     for (field_idx, field) in vineyard.fields.iter().enumerate() {
         let field_x = -200.0 + ((field_idx % 3) as f32 * 40.0);
@@ -221,7 +192,7 @@ fn spawn_vineyard_sprites(commands: &mut Commands, vineyard: &Vineyard) {
                     custom_size: Some(Vec2::new(35.0, 35.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(field_pos.extend(0.5)),
+                transform: Transform::from_translation(field_pos.extend(WorldLayer::Vineyard.at(0.5))),
                 ..default()
             },
             VineyardSprite { 
@@ -249,7 +220,7 @@ fn spawn_hand_sprites(commands: &mut Commands, hand: &Hand) {
                     custom_size: Some(Vec2::new(30.0, 40.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(card_pos.extend(2.0)),
+                transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.1))),
                 ..default()
             },
             CardSprite { card_type: CardType::Vine },
@@ -268,7 +239,7 @@ fn spawn_hand_sprites(commands: &mut Commands, hand: &Hand) {
                     custom_size: Some(Vec2::new(30.0, 40.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(card_pos.extend(2.0)),
+                transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.1))),
                 ..default()
             },
             CardSprite { card_type: CardType::WineOrder },
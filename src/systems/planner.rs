@@ -0,0 +1,144 @@
+use std::time::Duration;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::Instant;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::components::*;
+use crate::systems::ai::AiRules;
+use crate::systems::simulation::SimGame;
+use crate::systems::wake_up::WakeUpCharts;
+
+// Rollouts stop at the end of the year after this one unless the game ends first
+const HORIZON_YEARS: u8 = 1;
+const MAX_ROLLOUT_STEPS: usize = 400;
+// Upper bound on rollouts per decision, so fast machines don't search forever
+pub const PLANNER_MAX_ROLLOUTS: usize = 600;
+// Lira matter at the horizon, but a point always beats a purse
+const LIRA_WEIGHT: f32 = 0.1;
+// Thinking time per planner decision offered in settings, in milliseconds
+pub const PLANNER_BUDGET_STEPS_MS: [u64; 4] = [100, 250, 500, 1000];
+pub const DEFAULT_PLANNER_BUDGET_MS: u64 = 250;
+
+// Live game handed to the planning AI's task, with the board as it stands
+#[derive(Clone)]
+pub struct PlannerInput {
+    pub game: SimGame,
+    pub player_idx: usize,
+    pub budget: Duration,
+}
+
+impl PlannerInput {
+    pub fn plan(&self, candidates: &[ActionSpace], seed: u64) -> Option<ActionSpace> {
+        plan_action(&self.game, self.player_idx, candidates, self.budget, seed)
+    }
+}
+
+// Monte Carlo search: each candidate is applied in the rules engine and the game is played on
+// with the simulation AI. Every rollout reshuffles the unseen cards, so card draws act as chance
+// nodes and the average over rollouts is the expected value of the move. Candidates are tried in
+// turn until the budget runs out, each at least once.
+pub fn plan_action(
+    game: &SimGame,
+    player_idx: usize,
+    candidates: &[ActionSpace],
+    budget: Duration,
+    seed: u64,
+) -> Option<ActionSpace> {
+    if candidates.len() <= 1 {
+        return candidates.first().copied();
+    }
+
+    let started = Instant::now();
+    let mut totals = vec![0.0f32; candidates.len()];
+    let mut counts = vec![0u32; candidates.len()];
+    let mut rollout = 0;
+    while rollout < candidates.len()
+        || (rollout < PLANNER_MAX_ROLLOUTS && started.elapsed() < budget) {
+        let pick = rollout % candidates.len();
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(rollout as u64));
+        totals[pick] += rollout_value(game, player_idx, candidates[pick], &mut rng);
+        counts[pick] += 1;
+        rollout += 1;
+    }
+
+    // Ties keep the earlier candidate
+    let mut best = 0;
+    for i in 1..candidates.len() {
+        if totals[i] / counts[i] as f32 > totals[best] / counts[best] as f32 {
+            best = i;
+        }
+    }
+    Some(candidates[best])
+}
+
+// One determinized playout of `action`, scored for the planner
+pub fn rollout_value(game: &SimGame, player_idx: usize, action: ActionSpace, rng: &mut StdRng) -> f32 {
+    let mut game = game.clone();
+    game.decks.vine_deck.shuffle(rng);
+    game.decks.wine_order_deck.shuffle(rng);
+
+    let horizon = game.current_year.saturating_add(HORIZON_YEARS);
+    game.apply_action(player_idx, action);
+    game.current_player = (player_idx + 1) % game.players.len();
+    for _ in 0..MAX_ROLLOUT_STEPS {
+        if game.is_over() || game.current_year > horizon {
+            break;
+        }
+        game.step(rng);
+    }
+    position_value(&game, player_idx)
+}
+
+// Victory point lead over the best opponent, with lira as a tie-break
+pub fn position_value(game: &SimGame, player_idx: usize) -> f32 {
+    let worth = |p: &Player| p.victory_points.get() as f32 + p.lira.get() as f32 * LIRA_WEIGHT;
+    let own = worth(&game.players[player_idx]);
+    let best_opponent = game.players.iter().enumerate()
+        .filter(|(i, _)| *i != player_idx)
+        .map(|(_, p)| worth(p))
+        .fold(f32::MIN, f32::max);
+    if best_opponent == f32::MIN { own } else { own - best_opponent }
+}
+
+// The live game as the planner snapshots it; read-only, so it sits beside the AI's own queries
+#[derive(SystemParam)]
+pub struct PlannerWorld<'w, 's> {
+    players: Query<'w, 's, &'static Player>,
+    vineyards: Query<'w, 's, &'static Vineyard>,
+    hands: Query<'w, 's, &'static Hand>,
+    trackers: Query<'w, 's, &'static ResidualPaymentTracker>,
+    structures: Query<'w, 's, &'static Structure>,
+    workers: Query<'w, 's, &'static Worker>,
+    spaces: Query<'w, 's, &'static ActionSpaceSlot>,
+    card_decks: Res<'w, CardDecks>,
+    config: Res<'w, GameConfig>,
+    current_state: Res<'w, State<GameState>>,
+    wake_up_charts: Res<'w, WakeUpCharts>,
+}
+
+impl PlannerWorld<'_, '_> {
+    pub fn snapshot(&self, player: PlayerId, rules: AiRules, budget: Duration) -> PlannerInput {
+        let mut order: Vec<PlayerId> = self.players.iter().map(|p| p.id).collect();
+        order.sort_by_key(|id| id.0);
+        let player_idx = order.iter().position(|id| *id == player).unwrap_or(0);
+        let workers_left = order.iter()
+            .map(|id| self.workers.iter().filter(|w| w.owner == *id && w.placed_at.is_none()).count() as u8)
+            .collect();
+        let game = SimGame::from_parts(
+            self.players.iter().cloned().collect(),
+            self.vineyards.iter().cloned().collect(),
+            self.hands.iter().cloned().collect(),
+            self.trackers.iter().cloned().collect(),
+            self.structures.iter().cloned().collect(),
+            self.card_decks.clone(),
+            &self.config,
+            self.current_state.get().clone(),
+            player_idx,
+        )
+        .with_wake_up_chart(self.wake_up_charts.active())
+        .with_rules(rules)
+        .with_board(self.spaces.iter().cloned().collect(), workers_left);
+        PlannerInput { game, player_idx, budget }
+    }
+}
@@ -0,0 +1,521 @@
+use bevy::prelude::*;
+use bevy::window::FileDragAndDrop;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use crate::components::*;
+use crate::systems::game_logic::{validate_actions_with_requirements, ValidationResult};
+use crate::systems::input::{PassEvent, ActionRequested};
+use crate::systems::keymap::*;
+use crate::systems::legal_actions::{legal_action, RulesState};
+use crate::systems::prediction::CardChoice;
+use crate::systems::rng::GameRng;
+use crate::systems::save::{create_save_data, GameSnapshot, PlayByFileSave, SaveData};
+
+// Play-by-file: every human plays their own seat on their own machine, from the same seed.
+// When the local seat's turn ends the game writes a small turn file with the moves made and
+// digests of the state before and after; the others import it, the engine checks it starts
+// from the same digest, replays and validates the moves, and checks the digest it ends on.
+// A mismatch means the file was edited or the games drifted apart, and play stops there.
+// Seasons in between resolve on each machine, so wake-up rows are best left to Space.
+// Each turn also goes to the clipboard as a one-line turn code for pasting into an email;
+// Ctrl+V imports a code the same way a dropped file is.
+
+pub const TURN_FILE_DIR: &str = "turns";
+pub const TURN_FILE_VERSION: u32 = 1;
+pub const TURN_CODE_PREFIX: &str = "viticulture-turn:";
+// How often the turns folder is checked for the next file
+const POLL_SECONDS: f32 = 1.0;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum TurnMove {
+    Place { action: ActionSpace, grande: bool, #[serde(default)] choice: CardChoice },
+    Pass,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TurnFile {
+    pub version: u32,
+    // The game's seed; files from another game are refused
+    pub game_id: u64,
+    // Position in the game's sequence of turn files, from 1
+    pub seq: u32,
+    pub player: u8,
+    // Digests of the game as the turn began and as it ended
+    pub base_digest: u64,
+    pub result_digest: u64,
+    pub moves: Vec<TurnMove>,
+}
+
+impl TurnFile {
+    pub fn encode(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    // The file's JSON, or a turn code saved as text
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        if let Some(code) = std::str::from_utf8(bytes).ok().filter(|text| text.trim_start().starts_with(TURN_CODE_PREFIX)) {
+            return Self::from_code(code);
+        }
+        let file: Self = serde_json::from_slice(bytes).map_err(|e| e.to_string())?;
+        if file.version > TURN_FILE_VERSION {
+            return Err(format!("written by a newer build (version {})", file.version));
+        }
+        Ok(file)
+    }
+
+    // The file as one line of URL-safe base64 behind a prefix
+    pub fn to_code(&self) -> String {
+        format!("{}{}", TURN_CODE_PREFIX, base64_encode(&self.encode()))
+    }
+
+    // Mail clients wrap and indent long lines, so all whitespace is dropped first
+    pub fn from_code(text: &str) -> Result<Self, String> {
+        let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let body = compact.strip_prefix(TURN_CODE_PREFIX).ok_or("it isn't a turn code")?;
+        let bytes = base64_decode(body).ok_or("the turn code is damaged")?;
+        Self::decode(&bytes)
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len() * 4 / 3 + 3);
+    for chunk in bytes.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, b)| bits | (*b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            text.push(BASE64_ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    text
+}
+
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut bits = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET.iter().position(|a| a == c)? as u32;
+            bits |= value << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            bytes.push((bits >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+// The platform's own clipboard tools, so no extra dependency; the first one installed wins
+fn clipboard_tools(paste: bool) -> &'static [(&'static str, &'static [&'static str])] {
+    match (cfg!(target_os = "macos"), cfg!(windows), paste) {
+        (true, _, false) => &[("pbcopy", &[])],
+        (true, _, true) => &[("pbpaste", &[])],
+        (_, true, false) => &[("clip", &[])],
+        (_, true, true) => &[("powershell", &["-NoProfile", "-Command", "Get-Clipboard"])],
+        (_, _, false) => &[("wl-copy", &[]), ("xclip", &["-selection", "clipboard"]), ("xsel", &["--clipboard", "--input"])],
+        (_, _, true) => &[("wl-paste", &["--no-newline"]), ("xclip", &["-selection", "clipboard", "-o"]), ("xsel", &["--clipboard", "--output"])],
+    }
+}
+
+fn copy_to_clipboard(text: &str) -> bool {
+    clipboard_tools(false).iter().any(|(tool, args)| {
+        let Ok(mut child) = Command::new(tool).args(*args).stdin(Stdio::piped()).spawn() else { return false };
+        let written = child.stdin.take().is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        child.wait().is_ok_and(|status| status.success()) && written
+    })
+}
+
+fn paste_from_clipboard() -> Option<String> {
+    clipboard_tools(true).iter().find_map(|(tool, args)| {
+        let output = Command::new(tool).args(*args).output().ok().filter(|o| o.status.success())?;
+        String::from_utf8(output.stdout).ok()
+    })
+}
+
+pub fn turn_file_path(game_id: u64, seq: u32) -> String {
+    format!("{}/game-{:016x}-turn-{:04}.json", TURN_FILE_DIR, game_id, seq)
+}
+
+// `--mail <seat>`: this machine plays that seat, counted from 1, of a play-by-file game
+pub fn parse_play_by_file_args(args: &[String]) -> Option<PlayerId> {
+    let index = args.iter().position(|a| a == "--mail")?;
+    let seat: u8 = args.get(index + 1)?.parse().ok()?;
+    seat.checked_sub(1).map(PlayerId)
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+// Stable across machines and builds: query order, worker sprite positions and private notes
+// are left out, and the rest is hashed in its serialized form
+pub fn state_digest(save: &SaveData) -> u64 {
+    let mut canonical = save.clone();
+    canonical.notes.clear();
+    // Where each machine is in its own session isn't part of the game
+    canonical.rng = None;
+    canonical.play_by_file = None;
+    canonical.players.sort_by_key(|p| p.id);
+    canonical.vineyards.sort_by_key(|v| v.owner_id);
+    canonical.hands.sort_by_key(|h| h.owner_id);
+    canonical.trackers.sort_by_key(|t| t.owner_id);
+    canonical.structures.sort_by_key(|s| (s.owner_id, format!("{:?}", s.structure_type)));
+    canonical.action_spaces.sort_by_key(|s| (s.action, s.occupied_by, s.bonus_worker_slot, s.bonus_chit));
+    for worker in &mut canonical.workers {
+        worker.position_x = 0.0;
+        worker.position_y = 0.0;
+    }
+    canonical.workers.sort_by_key(|w| (w.owner_id, w.is_grande, w.placed_at));
+    canonical.mama_cards.sort_unstable();
+    canonical.papa_cards.sort_unstable();
+    fnv1a(&serde_json::to_vec(&canonical).unwrap_or_default())
+}
+
+// An imported turn, replayed one move per frame once its player is on turn here
+#[derive(Clone, Debug)]
+pub struct TurnReplay {
+    pub file: TurnFile,
+    pub started: bool,
+    pub next: usize,
+    // Workers the mover had placed when the last placement went out
+    pub awaiting: Option<usize>,
+}
+
+#[derive(Resource)]
+pub struct PlayByFile {
+    pub local_player: PlayerId,
+    pub game_id: Option<u64>,
+    // Turn files written or imported so far
+    pub seq: u32,
+    // The local turn being recorded: the digest it began from and the moves so far
+    pub recording: Option<(u64, Vec<TurnMove>)>,
+    pub replay: Option<TurnReplay>,
+    // Set once a file failed its checks; the game can't go on from here
+    pub desync: bool,
+    pub status: String,
+}
+
+impl PlayByFile {
+    pub fn new(local_player: PlayerId) -> Self {
+        Self {
+            local_player,
+            game_id: None,
+            seq: 0,
+            recording: None,
+            replay: None,
+            desync: false,
+            status: format!("📨 Play by file as Player {}", local_player.0 + 1),
+        }
+    }
+
+    // The next file in this game, for another seat
+    pub fn accepts(&self, file: &TurnFile) -> Result<(), String> {
+        if self.desync {
+            return Err("this game is out of sync".to_string());
+        }
+        if self.game_id.is_some_and(|id| id != file.game_id) {
+            return Err(format!("it belongs to another game ({:#x})", file.game_id));
+        }
+        if file.seq != self.seq + 1 {
+            return Err(format!("turn file {} was expected, not {}", self.seq + 1, file.seq));
+        }
+        if file.player == self.local_player.0 {
+            return Err("it is this seat's own turn".to_string());
+        }
+        Ok(())
+    }
+
+    // What a save keeps: the turn files so far and the local turn being recorded
+    pub fn session(&self) -> PlayByFileSave {
+        PlayByFileSave {
+            local_player: self.local_player.0,
+            game_id: self.game_id,
+            seq: self.seq,
+            recording: self.recording.clone(),
+        }
+    }
+
+    pub fn resume(session: &PlayByFileSave) -> Self {
+        Self {
+            game_id: session.game_id,
+            seq: session.seq,
+            recording: session.recording.clone(),
+            ..Self::new(PlayerId(session.local_player))
+        }
+    }
+
+    fn fail(&mut self, reason: &str) {
+        warn!("📨 {}", reason);
+        self.desync = true;
+        self.replay = None;
+        self.recording = None;
+        self.status = format!("⚠ {}", reason);
+    }
+
+    fn set_status(&mut self, status: String) {
+        if self.status != status {
+            self.status = status;
+        }
+    }
+}
+
+// Startup: `--mail <seat>` makes this a play-by-file game
+pub fn play_by_file_launch_system(mut commands: Commands) {
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(seat) = parse_play_by_file_args(&args) {
+        info!("📨 Play by file as player {}", seat.0 + 1);
+        commands.insert_resource(PlayByFile::new(seat));
+    }
+}
+
+// Runs before the placements resolve, while the picked cards are still first in hand
+pub fn play_by_file_record_system(
+    mut pbf: ResMut<PlayByFile>,
+    mut place_events: EventReader<ActionRequested>,
+    mut pass_events: EventReader<PassEvent>,
+    hands: Query<&Hand>,
+    vineyards: Query<&Vineyard>,
+) {
+    let local = pbf.local_player;
+    let Some((_, moves)) = pbf.recording.as_mut() else {
+        place_events.clear();
+        pass_events.clear();
+        return;
+    };
+
+    for event in place_events.read().filter(|e| e.player == local) {
+        moves.push(TurnMove::Place {
+            action: event.action,
+            grande: event.grande,
+            choice: CardChoice::capture(
+                hands.iter().find(|h| h.owner == local),
+                vineyards.iter().find(|v| v.owner == local),
+            ),
+        });
+    }
+    if pass_events.read().any(|e| e.player == local) {
+        moves.push(TurnMove::Pass);
+    }
+}
+
+// Starts and finishes the local turn's recording, picks up incoming files and checks digests.
+// Runs in PostUpdate so every reaction to a placement or a new season is in the digest.
+pub fn play_by_file_turn_system(
+    mut pbf: ResMut<PlayByFile>,
+    snapshot: GameSnapshot,
+    input: BoundInput,
+    rng: Res<GameRng>,
+    time: Res<Time>,
+    mut dropped: EventReader<FileDragAndDrop>,
+    mut since_poll: Local<f32>,
+) {
+    let state = snapshot.current_state.get();
+    if !matches!(state, GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter) || pbf.desync {
+        dropped.clear();
+        return;
+    }
+    let game_id = *pbf.game_id.get_or_insert(rng.seed);
+    let on_turn = snapshot.turn_order.players.get(snapshot.turn_order.current_player).copied()
+        .filter(|_| matches!(state, GameState::Summer | GameState::Winter));
+    let digest = || create_save_data(&snapshot).ok().map(|save| state_digest(&save));
+
+    // The local turn is over once someone else is on turn or the season has ended
+    if pbf.recording.is_some() && on_turn != Some(pbf.local_player) {
+        let Some((base_digest, moves)) = pbf.recording.take() else { return };
+        if !moves.is_empty() {
+            let file = TurnFile {
+                version: TURN_FILE_VERSION,
+                game_id,
+                seq: pbf.seq + 1,
+                player: pbf.local_player.0,
+                base_digest,
+                result_digest: digest().unwrap_or_default(),
+                moves,
+            };
+            let path = turn_file_path(game_id, file.seq);
+            let written = std::fs::create_dir_all(TURN_FILE_DIR).and_then(|()| std::fs::write(&path, file.encode()));
+            match written {
+                Ok(()) => {
+                    pbf.seq = file.seq;
+                    info!("📨 Wrote {}", path);
+                    if copy_to_clipboard(&file.to_code()) {
+                        pbf.set_status(format!("📨 Turn file written to {} and its code copied — send either on", path));
+                    } else {
+                        pbf.set_status(format!("📨 Turn file written to {} — send it on", path));
+                    }
+                }
+                Err(e) => pbf.fail(&format!("Could not write {}: {}", path, e)),
+            }
+        }
+    }
+    if pbf.recording.is_none() && pbf.replay.is_none() && on_turn == Some(pbf.local_player) {
+        if let Some(base) = digest() {
+            pbf.recording = Some((base, Vec::new()));
+            pbf.set_status("📨 Your turn — the turn file is written when it ends".to_string());
+        }
+    }
+
+    // A dropped file, a pasted code, or the next file turning up in the turns folder
+    let mut candidate = dropped.read().find_map(|event| match event {
+        FileDragAndDrop::DroppedFile { path_buf, .. } => Some((path_buf.display().to_string(), read_turn_file(path_buf))),
+        _ => None,
+    });
+    if candidate.is_none() && input.just_pressed(InputAction::PasteTurnCode) {
+        let pasted = paste_from_clipboard().ok_or_else(|| "nothing could be read from it".to_string());
+        candidate = Some(("The clipboard".to_string(), pasted.and_then(|text| TurnFile::from_code(&text))));
+    }
+    *since_poll += time.delta_seconds();
+    if candidate.is_none() && pbf.replay.is_none() && *since_poll >= POLL_SECONDS {
+        *since_poll = 0.0;
+        let expected = PathBuf::from(turn_file_path(game_id, pbf.seq + 1));
+        if expected.exists() {
+            candidate = Some((expected.display().to_string(), read_turn_file(&expected)));
+        }
+    }
+    if let Some((source, file)) = candidate.filter(|_| pbf.replay.is_none()) {
+        match file.and_then(|file| pbf.accepts(&file).map(|()| file)) {
+            Ok(file) => {
+                info!("📨 Imported turn file {} from player {}", file.seq, file.player + 1);
+                pbf.set_status(format!("📨 Replaying Player {}'s turn", file.player + 1));
+                pbf.replay = Some(TurnReplay { file, started: false, next: 0, awaiting: None });
+            }
+            // An own or already-played file left in the folder isn't worth a warning
+            Err(reason) => pbf.set_status(format!("📨 {} skipped: {}", source, reason)),
+        }
+    }
+
+    let Some(replay) = pbf.replay.clone() else {
+        if let Some(player) = on_turn.filter(|p| *p != pbf.local_player) {
+            pbf.set_status(format!("📨 Waiting for Player {}'s turn file {} (drop it here, put it in {}/ or Ctrl+V its code)", player.0 + 1, pbf.seq + 1, TURN_FILE_DIR));
+        }
+        return;
+    };
+    let mover = PlayerId(replay.file.player);
+    if !replay.started {
+        if on_turn != Some(mover) {
+            return;
+        }
+        if digest() != Some(replay.file.base_digest) {
+            pbf.fail(&format!("Turn file {} starts from a different game state", replay.file.seq));
+            return;
+        }
+        if let Some(replay) = pbf.replay.as_mut() {
+            replay.started = true;
+        }
+        return;
+    }
+
+    if on_turn != Some(mover) {
+        if replay.next < replay.file.moves.len() {
+            pbf.fail(&format!("Turn file {} has moves left after the turn ended", replay.file.seq));
+        } else if digest() != Some(replay.file.result_digest) {
+            pbf.fail(&format!("Turn file {} ends on a different game state", replay.file.seq));
+        } else {
+            pbf.seq = replay.file.seq;
+            pbf.replay = None;
+            pbf.set_status(format!("📨 Player {}'s turn replayed", mover.0 + 1));
+        }
+    }
+}
+
+fn read_turn_file(path: &Path) -> Result<TurnFile, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    TurnFile::decode(&bytes)
+}
+
+// Feeds the imported moves to the engine one at a time, each checked as a placement would be
+pub fn play_by_file_replay_system(
+    mut pbf: ResMut<PlayByFile>,
+    turn_order: Res<TurnOrder>,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    mut place_events: EventWriter<ActionRequested>,
+    mut pass_events: EventWriter<PassEvent>,
+    (players, mut hands, mut vineyards, structures): (Query<&Player>, Query<&mut Hand>, Query<&mut Vineyard>, Query<&Structure>),
+    workers: Query<&Worker>,
+    spaces: Query<&ActionSpaceSlot>,
+) {
+    let Some(replay) = pbf.replay.as_mut().filter(|r| r.started) else { return };
+    let mover = PlayerId(replay.file.player);
+    if turn_order.players.get(turn_order.current_player) != Some(&mover) || turn_order.has_passed(mover) {
+        return;
+    }
+    let placed = workers.iter().filter(|w| w.owner == mover && w.placed_at.is_some()).count();
+    if replay.awaiting.is_some_and(|before| placed <= before) {
+        return;
+    }
+    replay.awaiting = None;
+    let Some(next) = replay.file.moves.get(replay.next).cloned() else { return };
+    replay.next += 1;
+
+    match next {
+        TurnMove::Pass => {
+            pass_events.send(PassEvent { player: mover });
+        }
+        TurnMove::Place { action, grande, choice } => {
+            choice.apply(
+                hands.iter_mut().find(|h| h.owner == mover).as_deref_mut(),
+                vineyards.iter_mut().find(|v| v.owner == mover).as_deref_mut(),
+            );
+            let board = RulesState::new(spaces.iter(), current_state.get(), config.player_count).with_workers(workers.iter());
+            let open = legal_action(&board, mover, action).is_some_and(|legal| legal.uses_grande(grande).is_some());
+            let valid = matches!(
+                validate_actions_with_requirements(mover, action, &players, &hands.to_readonly(), &vineyards.to_readonly(), &structures),
+                ValidationResult::Valid
+            );
+            if open && valid {
+                replay.awaiting = Some(placed);
+                place_events.send(ActionRequested { player: mover, action, grande });
+            } else {
+                let seq = replay.file.seq;
+                pbf.fail(&format!("Turn file {} holds an illegal move: {:?}", seq, action));
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct PlayByFileStatusText;
+
+pub fn play_by_file_status_system(
+    mut commands: Commands,
+    pbf: Option<Res<PlayByFile>>,
+    mut texts: Query<(Entity, &mut Text), With<PlayByFileStatusText>>,
+) {
+    let Some(pbf) = pbf else {
+        for (entity, _) in texts.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    if let Ok((_, mut text)) = texts.get_single_mut() {
+        if text.sections[0].value != pbf.status {
+            text.sections[0].value = pbf.status.clone();
+            text.sections[0].style.color = if pbf.desync { Color::srgb(1.0, 0.5, 0.4) } else { Color::WHITE };
+        }
+        return;
+    }
+
+    commands.spawn((
+        TextBundle::from_section(
+            pbf.status.clone(),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(88.0),
+            right: Val::Px(20.0),
+            ..default()
+        }),
+        PlayByFileStatusText,
+    ));
+}
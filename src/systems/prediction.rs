@@ -0,0 +1,401 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::animations::WorkerAnimation;
+use crate::systems::clock::{GameClock, GameTimer};
+use crate::systems::game_logic::{validate_actions_with_requirements, ValidationResult};
+use crate::systems::input::ActionRequested;
+use crate::systems::legal_actions::{legal_action, RulesState};
+use crate::systems::render_layers::UiLayer;
+
+// In-game placement protocol for remote play. The host is authoritative: clients resolve
+// their own placements straight away, send a Request, and keep a rollback snapshot until
+// the host answers. Like the lobby, the transport only moves PlacementIncoming/Outgoing.
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum PlacementMessage {
+    Request { seq: u32, player: u8, action: ActionSpace, grande: bool, #[serde(default)] choice: CardChoice },
+    // Broadcast by the host for every accepted placement, its own and the AI's included (seq 0)
+    Confirmed { seq: u32, player: u8, action: ActionSpace, grande: bool, #[serde(default)] choice: CardChoice },
+    Rejected { seq: u32, player: u8, reason: String },
+}
+
+// What the placing player picked before the worker went out. The rules take the first card
+// in hand, so peers move the same cards to the front before resolving the placement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct CardChoice {
+    pub vine_card: Option<u32>,
+    pub order_card: Option<u32>,
+    pub harvest_fields: Vec<usize>,
+    #[serde(default)]
+    pub order_wines: (Option<u8>, Option<u8>),
+    // Picked in the make-wine dialog; None resolves to the automatic recipe
+    #[serde(default)]
+    pub recipe: Option<WineType>,
+}
+
+impl CardChoice {
+    pub fn capture(hand: Option<&Hand>, vineyard: Option<&Vineyard>) -> Self {
+        Self {
+            vine_card: hand.and_then(|h| h.vine_cards.first()).map(|c| c.id),
+            order_card: hand.and_then(|h| h.wine_order_cards.first()).map(|o| o.id),
+            harvest_fields: vineyard.map(|v| v.harvest_selection.clone()).unwrap_or_default(),
+            order_wines: vineyard.map(|v| v.order_wines).unwrap_or_default(),
+            recipe: vineyard.and_then(|v| v.wine_recipe),
+        }
+    }
+
+    pub fn apply(&self, hand: Option<&mut Hand>, vineyard: Option<&mut Vineyard>) {
+        if let Some(hand) = hand {
+            if let Some(index) = self.vine_card.and_then(|id| hand.vine_cards.iter().position(|c| c.id == id)) {
+                let card = hand.vine_cards.remove(index);
+                hand.vine_cards.insert(0, card);
+            }
+            if let Some(index) = self.order_card.and_then(|id| hand.wine_order_cards.iter().position(|o| o.id == id)) {
+                let order = hand.wine_order_cards.remove(index);
+                hand.wine_order_cards.insert(0, order);
+            }
+        }
+        if let Some(vineyard) = vineyard {
+            vineyard.harvest_selection = self.harvest_fields.clone();
+            vineyard.order_wines = self.order_wines;
+            vineyard.wine_recipe = self.recipe;
+        }
+    }
+}
+
+impl PlacementMessage {
+    pub fn encode(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+// From the transport: a message and the peer that sent it (0 is the host)
+#[derive(Event, Clone, Debug)]
+pub struct PlacementIncoming {
+    pub from: u8,
+    pub message: PlacementMessage,
+}
+
+// To the transport: the host broadcasts, clients send to the host
+#[derive(Event, Clone, Debug)]
+pub struct PlacementOutgoing(pub PlacementMessage);
+
+// Everything a predicted placement can touch, taken just before it resolved
+#[derive(Clone)]
+pub struct PlacementSnapshot {
+    pub player: Player,
+    pub vineyard: Option<Vineyard>,
+    pub hand: Option<Hand>,
+    pub tracker: Option<ResidualPaymentTracker>,
+    pub decks: CardDecks,
+    pub worker: Option<Entity>,
+    pub worker_position: Vec2,
+    // The turn as it stood, since the placement hands it to the next player
+    pub current_player: usize,
+    pub passed: Vec<PlayerId>,
+    // Column the worker went to, with its occupant and bonus slot beforehand
+    pub column: usize,
+    pub space: (Option<PlayerId>, Option<PlayerId>),
+}
+
+#[derive(Clone)]
+pub struct PredictedPlacement {
+    pub seq: u32,
+    pub event: ActionRequested,
+    pub before: PlacementSnapshot,
+}
+
+// Present for the whole of a networked game; the network layer inserts it when the game starts
+#[derive(Resource)]
+pub struct RemotePlay {
+    pub is_host: bool,
+    pub local_player: PlayerId,
+    // Which player each peer controls, so the host only accepts a peer's own placements
+    pub seats: Vec<(u8, PlayerId)>,
+    pub next_seq: u32,
+    pub pending: Vec<PredictedPlacement>,
+}
+
+impl RemotePlay {
+    pub fn host(local_player: PlayerId, seats: Vec<(u8, PlayerId)>) -> Self {
+        Self { is_host: true, local_player, seats, next_seq: 1, pending: Vec::new() }
+    }
+
+    pub fn client(local_player: PlayerId) -> Self {
+        Self { is_host: false, local_player, seats: Vec::new(), next_seq: 1, pending: Vec::new() }
+    }
+
+    pub fn seat_of(&self, peer: u8) -> Option<PlayerId> {
+        self.seats.iter().find(|(p, _)| *p == peer).map(|(_, player)| *player)
+    }
+
+    // Played from another machine, so the host hears of it through that peer's requests
+    pub fn is_remote_seat(&self, player: PlayerId) -> bool {
+        self.seats.iter().any(|(_, p)| *p == player)
+    }
+
+    // Oldest-first; a rejected placement takes every later prediction with it
+    pub fn take_from(&mut self, seq: u32) -> Vec<PredictedPlacement> {
+        match self.pending.iter().position(|p| p.seq == seq) {
+            Some(index) => self.pending.drain(index..).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct PredictionToast {
+    pub timer: Timer,
+}
+
+// Runs before action_resolution_system: snapshots the local player's placements and
+// sends them to the host, who broadcasts its own and the AI's as already confirmed
+pub fn predict_placement_system(
+    mut remote: ResMut<RemotePlay>,
+    mut place_events: EventReader<ActionRequested>,
+    mut outgoing: EventWriter<PlacementOutgoing>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+    hands: Query<&Hand>,
+    trackers: Query<&ResidualPaymentTracker>,
+    workers: Query<(Entity, &Worker)>,
+    spaces: Query<&ActionSpaceSlot>,
+    card_decks: Res<CardDecks>,
+    config: Res<GameConfig>,
+    current_state: Res<State<GameState>>,
+    turn_order: Res<TurnOrder>,
+) {
+    for event in place_events.read() {
+        let choice = CardChoice::capture(
+            hands.iter().find(|h| h.owner == event.player),
+            vineyards.iter().find(|v| v.owner == event.player),
+        );
+
+        if remote.is_host {
+            // Remote seats were confirmed when their request came in
+            if !remote.is_remote_seat(event.player) {
+                outgoing.send(PlacementOutgoing(PlacementMessage::Confirmed {
+                    seq: 0,
+                    player: event.player.0,
+                    action: event.action,
+                    grande: event.grande,
+                    choice,
+                }));
+            }
+            continue;
+        }
+        if event.player != remote.local_player {
+            continue;
+        }
+
+        let Some(player) = players.iter().find(|p| p.id == event.player) else { continue };
+        // Same worker action_resolution_system will move
+        let idle = |w: &Worker, grande: bool| w.owner == event.player && w.placed_at.is_none() && w.is_grande == grande;
+        let use_grande = event.grande || !workers.iter().any(|(_, w)| idle(w, false));
+        let worker = workers.iter().find(|(_, w)| idle(w, use_grande));
+        let column = placement_slot(spaces.iter(), event.action, event.player, config.player_count, current_state.get(), use_grande)
+            .map_or(0, |(column, _)| column);
+        let space = spaces.iter()
+            .find(|s| s.action == event.action && s.column == column)
+            .map_or((None, None), |s| (s.occupied_by, s.bonus_worker_slot));
+
+        let before = PlacementSnapshot {
+            player: player.clone(),
+            vineyard: vineyards.iter().find(|v| v.owner == event.player).cloned(),
+            hand: hands.iter().find(|h| h.owner == event.player).cloned(),
+            tracker: trackers.iter().find(|t| t.owner == event.player).cloned(),
+            decks: card_decks.clone(),
+            worker: worker.map(|(entity, _)| entity),
+            worker_position: worker.map_or(Vec2::ZERO, |(_, w)| w.position),
+            current_player: turn_order.current_player,
+            passed: turn_order.passed.clone(),
+            column,
+            space,
+        };
+
+        let seq = remote.next_seq;
+        remote.next_seq += 1;
+        remote.pending.push(PredictedPlacement { seq, event: *event, before });
+        outgoing.send(PlacementOutgoing(PlacementMessage::Request {
+            seq,
+            player: event.player.0,
+            action: event.action,
+            grande: event.grande,
+            choice,
+        }));
+    }
+}
+
+// Host: validates requests and broadcasts the verdict. Client: drops confirmed predictions,
+// plays other players' confirmed placements and rolls back rejected ones.
+pub fn reconcile_placement_system(
+    mut commands: Commands,
+    mut remote: ResMut<RemotePlay>,
+    mut incoming: EventReader<PlacementIncoming>,
+    mut outgoing: EventWriter<PlacementOutgoing>,
+    mut place_events: EventWriter<ActionRequested>,
+    mut turn_order: ResMut<TurnOrder>,
+    current_state: Res<State<GameState>>,
+    mut card_decks: ResMut<CardDecks>,
+    (mut players, mut vineyards, mut hands, mut trackers): (Query<&mut Player>, Query<&mut Vineyard>, Query<&mut Hand>, Query<&mut ResidualPaymentTracker>),
+    mut workers: Query<(Entity, &mut Worker, Option<&mut Transform>)>,
+    mut spaces: Query<&mut ActionSpaceSlot>,
+    (config, structures): (Res<GameConfig>, Query<&Structure>),
+) {
+    for event in incoming.read() {
+        match event.message.clone() {
+            PlacementMessage::Request { seq, player, action, grande, choice } if remote.is_host => {
+                let player = PlayerId(player);
+                choice.apply(
+                    hands.iter_mut().find(|h| h.owner == player).as_deref_mut(),
+                    vineyards.iter_mut().find(|v| v.owner == player).as_deref_mut(),
+                );
+                let verdict = validate_request(&remote, event.from, player, action, grande, &turn_order, current_state.get(), config.player_count, &workers, &spaces)
+                    .and_then(|()| match validate_actions_with_requirements(player, action, &players.to_readonly(), &hands.to_readonly(), &vineyards.to_readonly(), &structures) {
+                        ValidationResult::Valid => Ok(()),
+                        ValidationResult::Invalid(reason) => Err(reason),
+                    });
+                match verdict {
+                    Ok(()) => {
+                        place_events.send(ActionRequested { player, action, grande });
+                        outgoing.send(PlacementOutgoing(PlacementMessage::Confirmed { seq, player: player.0, action, grande, choice }));
+                    }
+                    Err(reason) => {
+                        outgoing.send(PlacementOutgoing(PlacementMessage::Rejected { seq, player: player.0, reason }));
+                    }
+                }
+            }
+            PlacementMessage::Confirmed { seq, player, action, grande, choice } if !remote.is_host && event.from == 0 => {
+                let player = PlayerId(player);
+                if player == remote.local_player {
+                    remote.pending.retain(|p| p.seq != seq);
+                } else {
+                    choice.apply(
+                        hands.iter_mut().find(|h| h.owner == player).as_deref_mut(),
+                        vineyards.iter_mut().find(|v| v.owner == player).as_deref_mut(),
+                    );
+                    place_events.send(ActionRequested { player, action, grande });
+                }
+            }
+            PlacementMessage::Rejected { seq, player, reason } if !remote.is_host && event.from == 0 => {
+                if PlayerId(player) != remote.local_player {
+                    continue;
+                }
+                let rejected = remote.take_from(seq);
+                // Newest first, so the oldest snapshot is the one left standing
+                for later in rejected.iter().rev() {
+                    let before = &later.before;
+                    if let Some(mut current) = players.iter_mut().find(|p| p.id == before.player.id) {
+                        *current = before.player.clone();
+                    }
+                    if let (Some(snapshot), Some(mut current)) = (&before.vineyard, vineyards.iter_mut().find(|v| v.owner == before.player.id)) {
+                        *current = snapshot.clone();
+                    }
+                    if let (Some(snapshot), Some(mut current)) = (&before.hand, hands.iter_mut().find(|h| h.owner == before.player.id)) {
+                        *current = snapshot.clone();
+                    }
+                    if let (Some(snapshot), Some(mut current)) = (&before.tracker, trackers.iter_mut().find(|t| t.owner == before.player.id)) {
+                        *current = snapshot.clone();
+                    }
+                    *card_decks = before.decks.clone();
+                    if let Some((entity, mut worker, transform)) = before.worker.and_then(|entity| workers.iter_mut().find(|(e, _, _)| *e == entity)) {
+                        worker.placed_at = None;
+                        worker.position = before.worker_position;
+                        if let Some(mut transform) = transform {
+                            transform.translation = before.worker_position.extend(transform.translation.z);
+                        }
+                        commands.entity(entity).remove::<WorkerAnimation>();
+                    }
+                    if let Some(mut space) = spaces.iter_mut().find(|s| s.action == later.event.action && s.column == before.column) {
+                        (space.occupied_by, space.bonus_worker_slot) = before.space;
+                    }
+                    turn_order.current_player = before.current_player;
+                    turn_order.passed = before.passed.clone();
+                }
+                let Some(rejected) = rejected.first() else { continue };
+
+                warn!("🌐 Host rejected {:?}: {}", rejected.event.action, reason);
+                spawn_prediction_toast(&mut commands, &format!("{:?} was undone: {}", rejected.event.action, reason));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn validate_request(
+    remote: &RemotePlay,
+    from: u8,
+    player: PlayerId,
+    action: ActionSpace,
+    grande: bool,
+    turn_order: &TurnOrder,
+    state: &GameState,
+    player_count: u8,
+    workers: &Query<(Entity, &mut Worker, Option<&mut Transform>)>,
+    spaces: &Query<&mut ActionSpaceSlot>,
+) -> Result<(), String> {
+    if remote.seat_of(from) != Some(player) {
+        return Err("not your seat".to_string());
+    }
+    if turn_order.players.get(turn_order.current_player) != Some(&player) {
+        return Err("not your turn".to_string());
+    }
+    if !spaces.iter().any(|s| s.action == action) {
+        return Err("no such action space".to_string());
+    }
+    let board = RulesState::new(spaces.iter(), state, player_count).with_workers(workers.iter().map(|(_, w, _)| w));
+    match legal_action(&board, player, action) {
+        Some(legal) if legal.uses_grande(grande).is_some() => Ok(()),
+        Some(_) => Err("no worker left for that space".to_string()),
+        None => Err("space already taken or no worker left".to_string()),
+    }
+}
+
+fn spawn_prediction_toast(commands: &mut Commands, message: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                right: Val::Px(20.0),
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.5, 0.2, 0.15, 0.95)).into(),
+            z_index: UiLayer::Toast.z_index(),
+            ..default()
+        },
+        PredictionToast {
+            timer: GameClock::once(GameTimer::Toast),
+        },
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("🌐 {}", message),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
+
+pub fn prediction_toast_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut PredictionToast), Without<MarkedForDespawn>>,
+) {
+    for (entity, mut toast) in toasts.iter_mut() {
+        toast.timer.tick(time.delta());
+
+        if toast.timer.finished() {
+            commands.entity(entity).insert(MarkedForDespawn);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::action_bar::button_label;
+use crate::systems::game_log::{GameLog, GameLogEntry};
+
+// Compact "recent actions" row in each dashboard's Recent actions section: the player's last
+// three actions this year as icons, with the details of whichever icon is hovered beside them.
+
+pub const RECENT_ACTIONS_SHOWN: usize = 3;
+const ICON_SIZE: f32 = 26.0;
+
+#[derive(Component)]
+pub struct RecentActionsRow {
+    pub player_id: PlayerId,
+}
+
+#[derive(Component)]
+pub struct RecentActionIcon {
+    pub player_id: PlayerId,
+    pub detail: String,
+}
+
+#[derive(Component)]
+pub struct RecentActionDetail {
+    pub player_id: PlayerId,
+}
+
+pub fn action_icon(action: ActionSpace) -> &'static str {
+    match action {
+        ActionSpace::DrawVine => "🌱",
+        ActionSpace::PlantVine => "🌿",
+        ActionSpace::BuildStructure => "🏗",
+        ActionSpace::GiveTour => "🧳",
+        ActionSpace::SellGrapes => "💰",
+        ActionSpace::TrainWorker => "👷",
+        ActionSpace::DrawWineOrder => "📜",
+        ActionSpace::Harvest => "🍇",
+        ActionSpace::MakeWine => "🍷",
+        ActionSpace::FillOrder => "✅",
+    }
+}
+
+// The player's latest actions this year, oldest first
+pub fn recent_actions(log: &GameLog, player: PlayerId, year: u8) -> Vec<&GameLogEntry> {
+    let mut recent: Vec<_> = log.entries.iter().rev()
+        .filter(|e| e.player == player && e.year == year)
+        .take(RECENT_ACTIONS_SHOWN)
+        .collect();
+    recent.reverse();
+    recent
+}
+
+pub fn recent_actions_row_system(
+    mut commands: Commands,
+    game_log: Res<GameLog>,
+    config: Res<GameConfig>,
+    rows: Query<(Entity, &RecentActionsRow)>,
+) {
+    if !game_log.is_changed() && !config.is_changed() {
+        return;
+    }
+
+    for (entity, row) in rows.iter() {
+        commands.entity(entity).despawn_descendants();
+        let entries = recent_actions(&game_log, row.player_id, config.current_year);
+        commands.entity(entity).with_children(|parent| {
+            for entry in entries {
+                parent.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(ICON_SIZE),
+                            height: Val::Px(ICON_SIZE),
+                            margin: UiRect::right(Val::Px(4.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: Color::srgb(0.25, 0.22, 0.18).into(),
+                        ..default()
+                    },
+                    RecentActionIcon {
+                        player_id: row.player_id,
+                        detail: format!("{:?}: {} — {}", entry.season, button_label(entry.action), entry.outcome.summary()),
+                    },
+                )).with_children(|icon| {
+                    icon.spawn(TextBundle::from_section(
+                        action_icon(entry.action),
+                        TextStyle {
+                            font_size: 16.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+    }
+}
+
+pub fn recent_action_hover_system(
+    icons: Query<(&Interaction, &RecentActionIcon)>,
+    mut details: Query<(&mut Text, &RecentActionDetail)>,
+) {
+    for (mut text, detail) in details.iter_mut() {
+        let hovered = icons.iter()
+            .find(|(interaction, icon)| icon.player_id == detail.player_id && **interaction != Interaction::None)
+            .map_or(String::new(), |(_, icon)| icon.detail.clone());
+        if text.sections[0].value != hovered {
+            text.sections[0].value = hovered;
+        }
+    }
+}
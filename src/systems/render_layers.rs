@@ -0,0 +1,175 @@
+use bevy::prelude::*;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::keymap::{BoundInput, InputAction};
+
+// Every draw layer in one place, back to front. Board sprites are ordered by translation z,
+// UI nodes by ZIndex::Global; spawns name a layer here instead of a bare number.
+
+// Sprites and 2D text on the board. Layers are a whole unit apart and offsets below 1.0
+// order the pieces within one; the default 2D camera only draws z from 0 to 999.9.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WorldLayer {
+    // Season art; cross-fading backgrounds step up in hundredths
+    Background,
+    // Action spaces, their bonus rims and column labels
+    Board,
+    // Fields, vines, cellars and wine tokens
+    Vineyard,
+    // Bonus chits and Automa blocks on the action spaces
+    Markers,
+    Workers,
+    Hand,
+    // Visitor targets, sandbox selection and undo changes, drawn over what they point at
+    Highlights,
+    Particles,
+    // Ghost worker showing where a placement would go
+    Preview,
+    // Cards flying from the deck to the hand
+    CardFlight,
+    FloatingText,
+    PhaseText,
+}
+
+impl WorldLayer {
+    pub const ALL: [WorldLayer; 12] = [
+        WorldLayer::Background,
+        WorldLayer::Board,
+        WorldLayer::Vineyard,
+        WorldLayer::Markers,
+        WorldLayer::Workers,
+        WorldLayer::Hand,
+        WorldLayer::Highlights,
+        WorldLayer::Particles,
+        WorldLayer::Preview,
+        WorldLayer::CardFlight,
+        WorldLayer::FloatingText,
+        WorldLayer::PhaseText,
+    ];
+
+    pub fn z(self) -> f32 {
+        Self::ALL.iter().position(|l| *l == self).unwrap_or(0) as f32
+    }
+
+    // A piece inside the layer; `offset` must stay below 1.0
+    pub fn at(self, offset: f32) -> f32 {
+        self.z() + offset.clamp(0.0, 0.99)
+    }
+
+    // The layer a sprite at this z belongs to
+    pub fn of(z: f32) -> Self {
+        Self::ALL.into_iter().rev().find(|l| z >= l.z()).unwrap_or(WorldLayer::Background)
+    }
+}
+
+// Screen-space UI. Panels of one layer never overlap by design; anything that must show
+// over another kind of panel goes in a later layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UiLayer {
+    // Full-screen colour wash between seasons, behind everything else
+    SeasonTransition,
+    // Always-on panels: wake-up track, supply, forecasts, win odds, split-screen halves
+    Hud,
+    // Coach, gameplay tips and F1 contextual help
+    Tips,
+    // Panels opened from a key or menu: settings, stats, gallery, estate, notes, past games
+    Screen,
+    // Choices the game waits on: pickers, the make-wine dialog, discards, resigning
+    Dialog,
+    // Festival, upkeep and season summary cards
+    Announcement,
+    // Final scores, co-op results, final decks and post-game notes
+    GameOver,
+    // Tutorial steps and onboarding screens
+    Tutorial,
+    // Hover tooltips and card zoom, which must read over any panel or dialog
+    Tooltip,
+    Toast,
+    // Debug console, entity inspector and save diff
+    DevTools,
+    // Hot-seat handoff hides the whole table until the next player is ready
+    Handoff,
+}
+
+impl UiLayer {
+    pub const ALL: [UiLayer; 12] = [
+        UiLayer::SeasonTransition,
+        UiLayer::Hud,
+        UiLayer::Tips,
+        UiLayer::Screen,
+        UiLayer::Dialog,
+        UiLayer::Announcement,
+        UiLayer::GameOver,
+        UiLayer::Tutorial,
+        UiLayer::Tooltip,
+        UiLayer::Toast,
+        UiLayer::DevTools,
+        UiLayer::Handoff,
+    ];
+
+    pub fn value(self) -> i32 {
+        (Self::ALL.iter().position(|l| *l == self).unwrap_or(0) as i32 + 1) * 100
+    }
+
+    pub fn z_index(self) -> ZIndex {
+        ZIndex::Global(self.value())
+    }
+
+    pub fn of(value: i32) -> Self {
+        Self::ALL.into_iter().rev().find(|l| value >= l.value()).unwrap_or(UiLayer::SeasonTransition)
+    }
+}
+
+// Evenly spread hues so neighbouring layers are easy to tell apart
+fn layer_color(index: usize, count: usize) -> Color {
+    Color::hsl(index as f32 * 360.0 / count as f32, 0.9, 0.55)
+}
+
+// The outline key in testing mode outlines every sprite and UI node in its layer's colour
+#[derive(Resource, Default)]
+pub struct LayerOutlines {
+    pub enabled: bool,
+}
+
+#[derive(Component)]
+pub struct LayerOutlineMarker;
+
+pub fn layer_outline_system(
+    mut commands: Commands,
+    input: BoundInput,
+    test_config: Res<AutoTestConfig>,
+    mut outlines: ResMut<LayerOutlines>,
+    sprites: Query<(&GlobalTransform, &Sprite)>,
+    nodes: Query<(Entity, &ZIndex), (With<Node>, Without<LayerOutlineMarker>)>,
+    outlined: Query<Entity, With<LayerOutlineMarker>>,
+    mut gizmos: Gizmos,
+) {
+    if input.just_pressed(InputAction::LayerOutlines) && test_config.enabled {
+        outlines.enabled = !outlines.enabled;
+        info!("🧅 Layer outlines {}", if outlines.enabled { "on" } else { "off" });
+        if !outlines.enabled {
+            for entity in outlined.iter() {
+                commands.entity(entity).remove::<(Outline, LayerOutlineMarker)>();
+            }
+        }
+    }
+    if !outlines.enabled {
+        return;
+    }
+
+    for (transform, sprite) in sprites.iter() {
+        let Some(size) = sprite.custom_size else { continue };
+        let layer = WorldLayer::of(transform.translation().z);
+        let index = WorldLayer::ALL.iter().position(|l| *l == layer).unwrap_or(0);
+        gizmos.rect_2d(transform.translation().truncate(), 0.0, size, layer_color(index, WorldLayer::ALL.len()));
+    }
+    // Nodes spawned while the outlines are on pick theirs up the next frame
+    for (entity, z_index) in nodes.iter() {
+        let ZIndex::Global(value) = z_index else { continue };
+        let layer = UiLayer::of(*value);
+        let index = UiLayer::ALL.iter().position(|l| *l == layer).unwrap_or(0);
+        commands.entity(entity).insert((
+            Outline::new(Val::Px(2.0), Val::ZERO, layer_color(index, UiLayer::ALL.len())),
+            LayerOutlineMarker,
+        ));
+    }
+}
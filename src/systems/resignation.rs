@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::game_logic::create_game_over_modal;
+use crate::systems::keymap::*;
+use crate::systems::net::plays_locally;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::render_layers::UiLayer;
+
+// How long a dropped network player keeps their seat before they are resigned
+pub const RECONNECT_GRACE_SECS: f32 = 60.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ResignReason {
+    Choice,
+    Disconnected,
+}
+
+impl ResignReason {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResignReason::Choice => "resigned",
+            ResignReason::Disconnected => "disconnected",
+        }
+    }
+}
+
+// On the Player entity of someone who left the game; they keep their score but take no more turns
+#[derive(Component, Clone, Debug)]
+pub struct Resigned {
+    pub year: u8,
+    pub season: GameState,
+    pub reason: ResignReason,
+}
+
+// Set by the host on a dropped player's entity; net_rejoin_system takes it off again when
+// they reconnect
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ConnectionLost {
+    pub since: f32,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ResignEvent {
+    pub player: PlayerId,
+    pub reason: ResignReason,
+}
+
+#[derive(Component)]
+pub struct ResignDialog;
+
+#[derive(Component)]
+pub struct ResignButton {
+    pub confirm: bool,
+}
+
+// Ctrl+R asks the human player on turn to confirm resigning, if this machine plays their seat
+pub fn resign_request_system(
+    mut commands: Commands,
+    input: BoundInput,
+    turn_order: Res<TurnOrder>,
+    (remote, pbf): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>),
+    players: Query<&Player, Without<Resigned>>,
+    buttons: Query<(&Interaction, &ResignButton), Changed<Interaction>>,
+    dialogs: Query<Entity, With<ResignDialog>>,
+    mut resign_events: EventWriter<ResignEvent>,
+    mut pending: Local<Option<PlayerId>>,
+) {
+    if let Some(player_id) = *pending {
+        let Some(confirm) = buttons.iter()
+            .find(|(interaction, _)| **interaction == Interaction::Pressed)
+            .map(|(_, button)| button.confirm) else { return };
+
+        if confirm {
+            resign_events.send(ResignEvent { player: player_id, reason: ResignReason::Choice });
+        }
+        *pending = None;
+        for entity in dialogs.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if !input.just_pressed(InputAction::Resign) {
+        return;
+    }
+
+    let current = turn_order.players.get(turn_order.current_player).copied();
+    let Some(player) = players.iter().find(|p| Some(p.id) == current && !p.is_ai) else { return };
+    if !plays_locally(remote.as_deref(), pbf.as_deref(), player.id) {
+        return;
+    }
+    *pending = Some(player.id);
+    spawn_resign_dialog(&mut commands, player);
+}
+
+// Dropped players who have not come back within the grace period resign automatically
+pub fn reconnect_grace_system(
+    real_time: Res<Time<Real>>,
+    players: Query<(&Player, &ConnectionLost), Without<Resigned>>,
+    mut resign_events: EventWriter<ResignEvent>,
+    mut reported: Local<Vec<PlayerId>>,
+) {
+    let now = real_time.elapsed_seconds();
+    reported.retain(|id| players.iter().any(|(p, _)| p.id == *id));
+
+    for (player, lost) in players.iter() {
+        if now - lost.since > RECONNECT_GRACE_SECS && !reported.contains(&player.id) {
+            reported.push(player.id);
+            resign_events.send(ResignEvent { player: player.id, reason: ResignReason::Disconnected });
+        }
+    }
+}
+
+// Takes a resigned player off the board: workers leave, their spaces free up and
+// wine orders go to the discard. Scores stay for the final standings.
+pub fn apply_resignation_system(
+    mut commands: Commands,
+    mut resign_events: EventReader<ResignEvent>,
+    mut turn_order: ResMut<TurnOrder>,
+    mut card_decks: ResMut<CardDecks>,
+    mut next_state: ResMut<NextState<GameState>>,
+    (config, current_state): (Res<GameConfig>, Res<State<GameState>>),
+    players: Query<(Entity, &Player, Has<Resigned>)>,
+    workers: Query<(Entity, &Worker)>,
+    mut action_spaces: Query<&mut ActionSpaceSlot>,
+    mut hands: Query<&mut Hand>,
+) {
+    for event in resign_events.read() {
+        let Some((entity, player, already)) = players.iter().find(|(_, p, _)| p.id == event.player) else { continue };
+        if already {
+            continue;
+        }
+
+        commands.entity(entity).insert(Resigned {
+            year: config.current_year,
+            season: current_state.get().clone(),
+            reason: event.reason,
+        });
+
+        for (worker_entity, worker) in workers.iter() {
+            if worker.owner == event.player {
+                commands.entity(worker_entity).despawn();
+            }
+        }
+
+        for mut space in action_spaces.iter_mut() {
+            if space.occupied_by == Some(event.player) {
+                space.occupied_by = space.bonus_worker_slot.take();
+            }
+            if space.bonus_worker_slot == Some(event.player) {
+                space.bonus_worker_slot = None;
+            }
+        }
+
+        if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == event.player) {
+            let orders: Vec<_> = hand.wine_order_cards.drain(..).collect();
+            card_decks.wine_order_discard.extend(orders);
+        }
+
+        // Whoever was after them in the order keeps their turn
+        if let Some(position) = turn_order.players.iter().position(|id| *id == event.player) {
+            turn_order.players.remove(position);
+            if position < turn_order.current_player {
+                turn_order.current_player -= 1;
+            }
+            if turn_order.current_player >= turn_order.players.len() {
+                turn_order.current_player = 0;
+            }
+        }
+        turn_order.wake_up_order.retain(|(id, _)| *id != event.player);
+
+        info!("🏳️ {} {} in Year {}", player.name, event.reason.label(), config.current_year);
+
+        // Last one standing wins
+        let remaining: Vec<&Player> = players.iter()
+            .filter(|(_, p, resigned)| !resigned && p.id != event.player)
+            .map(|(_, p, _)| p)
+            .collect();
+        if remaining.len() == 1 && players.iter().count() > 1 {
+            let winner = remaining[0];
+            info!("🏆 {} wins as the last player remaining", winner.name);
+            create_game_over_modal(&mut commands, &winner.name, winner.victory_points.get());
+            next_state.set(GameState::GameOver);
+        } else if remaining.is_empty() {
+            next_state.set(GameState::GameOver);
+        }
+    }
+}
+
+fn spawn_resign_dialog(commands: &mut Commands, player: &Player) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(240.0),
+                left: Val::Percent(36.0),
+                width: Val::Px(320.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(16.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.15, 0.1, 0.1, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.8, 0.3, 0.3, 1.0)).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        ResignDialog,
+    )).with_children(|dialog| {
+        dialog.spawn(TextBundle::from_section(
+            format!("🏳️ {}: resign from this game?\nYour workers leave and your orders are discarded.", player.name),
+            TextStyle {
+                font_size: 15.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        for (label, confirm) in [("Resign", true), ("Keep playing", false)] {
+            dialog.spawn((
+                ButtonBundle {
+                    style: Style {
+                        margin: UiRect::top(Val::Px(8.0)),
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    background_color: Color::from(Srgba::new(0.35, 0.15, 0.15, 1.0)).into(),
+                    ..default()
+                },
+                ResignButton { confirm },
+            )).with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    label,
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ));
+            });
+        }
+    });
+}
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, IoTaskPool, Task};
+use std::path::PathBuf;
+use std::time::SystemTime;
+use crate::components::*;
+use crate::systems::history::HISTORY_DIR;
+use crate::systems::save::{autosave_path, MAX_AUTOSAVE_RING};
+use crate::systems::settings::UserSettings;
+use crate::systems::undo::{UndoSystem, MAX_UNDO_HISTORY};
+
+// Caps on what the persistence features keep: autosaves on disk, undo steps in memory and
+// archived games in the history folder. Files over the caps are pruned off the main thread.
+
+pub const UNDO_DEPTH_STEPS: [u8; 4] = [5, 10, 20, 40];
+pub const DEFAULT_UNDO_DEPTH: u8 = MAX_UNDO_HISTORY as u8;
+pub const ARCHIVE_LIMIT_STEPS: [u16; 4] = [25, 50, 100, 250];
+pub const DEFAULT_ARCHIVE_LIMIT: u16 = 100;
+
+// Cycles a settings value through its steps, wrapping to the first
+pub fn next_step<T: Copy + PartialEq>(steps: &[T], current: T) -> T {
+    let i = steps.iter().position(|s| *s == current).map_or(0, |i| (i + 1) % steps.len());
+    steps[i]
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FolderUsage {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+impl FolderUsage {
+    // What the folder grows to once it holds `limit` files of the current average size
+    pub fn estimate_at(&self, limit: usize) -> u64 {
+        if self.files == 0 {
+            return 0;
+        }
+        self.bytes / self.files as u64 * limit as u64
+    }
+}
+
+// Last measurement of the persistence folders, refreshed after every prune
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct DiskUsage {
+    pub autosaves: FolderUsage,
+    pub archives: FolderUsage,
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1024 * 1024 => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+        b if b >= 1024 => format!("{:.0} KB", b as f64 / 1024.0),
+        b => format!("{} B", b),
+    }
+}
+
+pub fn disk_usage_label(usage: &DiskUsage, settings: &UserSettings) -> String {
+    format!(
+        "💽 Autosaves: {} now, ~{} at {}\n💽 Past games: {} now, ~{} at {}",
+        format_bytes(usage.autosaves.bytes),
+        format_bytes(usage.autosaves.estimate_at(settings.auto_save_ring as usize)),
+        settings.auto_save_ring,
+        format_bytes(usage.archives.bytes),
+        format_bytes(usage.archives.estimate_at(settings.max_archived_games as usize)),
+        settings.max_archived_games,
+    )
+}
+
+// Everything past the newest `keep` files
+pub fn files_to_prune(mut files: Vec<(PathBuf, SystemTime)>, keep: usize) -> Vec<PathBuf> {
+    files.sort_by_key(|(_, modified)| std::cmp::Reverse(*modified));
+    files.into_iter().skip(keep).map(|(path, _)| path).collect()
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn autosave_files() -> Vec<(PathBuf, SystemTime)> {
+    (0..MAX_AUTOSAVE_RING as usize)
+        .map(|i| PathBuf::from(autosave_path(i)))
+        .filter_map(|path| modified(&path).map(|t| (path, t)))
+        .collect()
+}
+
+fn archive_files() -> Vec<(PathBuf, SystemTime)> {
+    let Ok(dir) = std::fs::read_dir(HISTORY_DIR) else { return Vec::new() };
+    dir.filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| modified(&path).map(|t| (path, t)))
+        .collect()
+}
+
+fn measure(files: &[(PathBuf, SystemTime)]) -> FolderUsage {
+    FolderUsage {
+        files: files.len(),
+        bytes: files.iter().filter_map(|(path, _)| std::fs::metadata(path).ok()).map(|m| m.len()).sum(),
+    }
+}
+
+// Deletes whatever is over the limits, then measures what is left
+pub fn prune_and_measure(autosaves_kept: usize, archives_kept: usize) -> DiskUsage {
+    let stale = files_to_prune(autosave_files(), autosaves_kept).into_iter()
+        .chain(files_to_prune(archive_files(), archives_kept));
+    for path in stale {
+        match std::fs::remove_file(&path) {
+            Ok(()) => info!("🧹 Pruned {:?}", path),
+            Err(e) => warn!("Could not prune {:?}: {}", path, e),
+        }
+    }
+
+    DiskUsage {
+        autosaves: measure(&autosave_files()),
+        archives: measure(&archive_files()),
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct RetentionPruner {
+    task: Option<Task<DiskUsage>>,
+    // A prune was asked for while one was still running
+    pending: bool,
+}
+
+// Prunes once at startup, whenever the limits change and after each autosave or archive
+pub fn retention_prune_system(
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    mut pruner: ResMut<RetentionPruner>,
+    mut usage: ResMut<DiskUsage>,
+) {
+    if let Some(task) = pruner.task.as_mut() {
+        if let Some(measured) = block_on(future::poll_once(task)) {
+            *usage = measured;
+            pruner.task = None;
+        }
+    }
+
+    let wrote_files = current_state.is_changed()
+        && matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter | GameState::GameOver);
+    if settings.is_changed() || wrote_files {
+        pruner.pending = true;
+    }
+    if !pruner.pending || pruner.task.is_some() {
+        return;
+    }
+
+    pruner.pending = false;
+    let (autosaves, archives) = (settings.auto_save_ring as usize, settings.max_archived_games as usize);
+    pruner.task = Some(IoTaskPool::get().spawn(async move { prune_and_measure(autosaves, archives) }));
+}
+
+// Keeps the undo history within the configured depth
+pub fn undo_depth_system(settings: Res<UserSettings>, mut undo_system: ResMut<UndoSystem>) {
+    if settings.is_changed() {
+        undo_system.set_max_snapshots(settings.max_undo_depth as usize);
+    }
+}
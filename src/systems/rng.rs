@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::components::*;
+use crate::systems::game_log::GameLog;
+use crate::systems::keymap::*;
+
+// The one random source of a live game. Shuffles, chit placement, AI tie-breaks, season
+// events and particle effects all draw from it, so a game replays from its seed alone.
+#[derive(Resource)]
+pub struct GameRng {
+    pub seed: u64,
+    rng: StdRng,
+    // 32-bit words drawn since seeding; the generator hands out whole words whatever the
+    // call, so the seed and this count put it back where it was
+    position: u64,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: StdRng::seed_from_u64(seed), position: 0 }
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    // A saved game's generator, `position` words on from its seed
+    pub fn resume(seed: u64, position: u64) -> Self {
+        let mut rng = Self::new(seed);
+        for _ in 0..position {
+            rng.next_u32();
+        }
+        rng
+    }
+}
+
+// Systems pass `&mut *rng` wherever an Rng is expected
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.position += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.position += 2;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.position += dest.len().div_ceil(4) as u64;
+        self.rng.fill_bytes(dest)
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(rand::random())
+    }
+}
+
+// Runs first in Setup: the menu's seed if one was entered, a fresh one otherwise
+pub fn seed_game_rng_system(
+    config: Res<GameConfig>,
+    mut rng: ResMut<GameRng>,
+    mut log: ResMut<GameLog>,
+) {
+    let seed = config.seed.unwrap_or_else(rand::random);
+    rng.reseed(seed);
+    log.seed = Some(seed);
+    info!("🎲 Game seed {:#x}", seed);
+}
+
+// Seed typed on the main menu. Digits only, so typing can't trip the menu's letter keys
+#[derive(Resource, Default)]
+pub struct SeedEntry {
+    pub editing: bool,
+    pub text: String,
+}
+
+const MAX_SEED_DIGITS: usize = 20;
+
+// S (by default) starts typing a seed, Enter keeps it, Esc goes back to random seeds
+pub fn seed_entry_system(
+    input: BoundInput,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut entry: ResMut<SeedEntry>,
+    mut config: ResMut<GameConfig>,
+    mut commands: Commands,
+    menu_text: Query<Entity, With<PhaseText>>,
+) {
+    if !entry.editing {
+        keyboard_events.clear();
+        if input.just_pressed(InputAction::EnterSeed) {
+            entry.editing = true;
+            entry.text = config.seed.map(|s| s.to_string()).unwrap_or_default();
+            refresh_menu(&mut commands, &menu_text);
+        }
+        return;
+    }
+
+    let mut changed = false;
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) => {
+                for c in chars.chars().filter(|c| c.is_ascii_digit()) {
+                    if entry.text.len() < MAX_SEED_DIGITS {
+                        entry.text.push(c);
+                        changed = true;
+                    }
+                }
+            }
+            Key::Backspace => {
+                entry.text.pop();
+                changed = true;
+            }
+            Key::Enter => {
+                config.seed = entry.text.parse().ok();
+                entry.editing = false;
+                changed = true;
+            }
+            Key::Escape => {
+                config.seed = None;
+                entry.editing = false;
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+
+    if changed {
+        refresh_menu(&mut commands, &menu_text);
+    }
+}
+
+// The main menu rebuilds its text once it's gone
+fn refresh_menu(commands: &mut Commands, menu_text: &Query<Entity, With<PhaseText>>) {
+    for entity in menu_text.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn seed_label(config: &GameConfig, entry: &SeedEntry, keymap: &Keymap) -> String {
+    if entry.editing {
+        format!("Seed: {}_ (Enter to keep, Esc for random)", entry.text)
+    } else {
+        let key = keymap.binding(InputAction::EnterSeed).map(|b| b.key_label()).unwrap_or_default();
+        // The hex form is what game logs and past games show
+        config.seed.map_or(format!("Seed: random ({} to enter one)", key), |s| format!("Seed: {} = {:#x} ({} to change)", s, s, key))
+    }
+}
+
+// Particles and other cosmetic effects don't draw from GameRng: their counts follow the
+// animation settings, which would otherwise shift every later shuffle. Each burst is
+// seeded from where it spawns instead, so effects replay too.
+pub fn effect_rng(center: Vec2, count: usize) -> StdRng {
+    let seed = (((center.x.to_bits() as u64) << 32) | center.y.to_bits() as u64) ^ count as u64;
+    StdRng::seed_from_u64(seed)
+}
@@ -0,0 +1,230 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::keymap::*;
+use crate::systems::sprites::{field_sprite_position, hand_card_position};
+use crate::systems::render_layers::WorldLayer;
+
+const SELECT_COLOR: Srgba = Srgba::new(0.2, 1.0, 1.0, 0.6);
+const BATCH_GRAPE_VALUE: u8 = 3;
+const BATCH_VINE_VALUE: u8 = 2;
+
+// Sandbox multi-selection, only active while testing mode is enabled
+#[derive(Resource, Default)]
+pub struct SandboxSelection {
+    pub fields: Vec<(PlayerId, usize)>,
+    pub vine_cards: Vec<(PlayerId, usize)>,
+    pub order_cards: Vec<(PlayerId, usize)>,
+}
+
+impl SandboxSelection {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.vine_cards.is_empty() && self.order_cards.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.fields.clear();
+        self.vine_cards.clear();
+        self.order_cards.clear();
+    }
+}
+
+#[derive(Component)]
+pub struct SandboxHighlight;
+
+fn toggle<T: PartialEq>(list: &mut Vec<T>, item: T) {
+    if let Some(pos) = list.iter().position(|i| *i == item) {
+        list.remove(pos);
+    } else {
+        list.push(item);
+    }
+}
+
+// Shift-click toggles fields/cards in the selection, a plain click clears it
+pub fn sandbox_selection_system(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    vineyards: Query<&Vineyard>,
+    hands: Query<&Hand>,
+    turn_order: Res<TurnOrder>,
+    test_config: Res<AutoTestConfig>,
+    mut selection: ResMut<SandboxSelection>,
+) {
+    if !test_config.enabled {
+        if !selection.is_empty() {
+            selection.clear();
+        }
+        return;
+    }
+
+    if !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    if !shift {
+        selection.clear();
+        return;
+    }
+
+    let window = windows.single();
+    let (camera, camera_transform) = camera_q.single();
+    let Some(cursor_pos) = window.cursor_position() else { return };
+    let world_pos = camera.viewport_to_world_2d(camera_transform, cursor_pos).unwrap_or(Vec2::ZERO);
+
+    for vineyard in vineyards.iter() {
+        for field_idx in 0..vineyard.fields.len() {
+            let bounds = Rect::from_center_size(field_sprite_position(vineyard.owner, field_idx), Vec2::new(40.0, 40.0));
+            if bounds.contains(world_pos) {
+                toggle(&mut selection.fields, (vineyard.owner, field_idx));
+                return;
+            }
+        }
+    }
+
+    // Only the current player's hand is drawn on screen
+    if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
+        if let Some(hand) = hands.iter().find(|h| h.owner == *current_player_id) {
+            for i in 0..hand.vine_cards.len() {
+                let bounds = Rect::from_center_size(hand_card_position(hand, CardType::Vine, i), Vec2::new(32.0, 42.0));
+                if bounds.contains(world_pos) {
+                    toggle(&mut selection.vine_cards, (hand.owner, i));
+                    return;
+                }
+            }
+            for i in 0..hand.wine_order_cards.len() {
+                let bounds = Rect::from_center_size(hand_card_position(hand, CardType::WineOrder, i), Vec2::new(32.0, 42.0));
+                if bounds.contains(world_pos) {
+                    toggle(&mut selection.order_cards, (hand.owner, i));
+                    return;
+                }
+            }
+        }
+    }
+}
+
+// Batch operations on the selection, with their default keys:
+//   R / W  - plant a red / white vine in every selected empty field
+//   Delete - clear selected fields and discard selected cards
+//   G      - add 3 grapes per selected field (colour follows the planted vine)
+pub fn sandbox_batch_action_system(
+    input: BoundInput,
+    test_config: Res<AutoTestConfig>,
+    mut selection: ResMut<SandboxSelection>,
+    mut vineyards: Query<&mut Vineyard>,
+    mut hands: Query<&mut Hand>,
+) {
+    if !test_config.enabled || selection.is_empty() {
+        return;
+    }
+
+    let plant = if input.just_pressed(InputAction::SandboxPlantRed) {
+        Some(VineType::Red(BATCH_VINE_VALUE))
+    } else if input.just_pressed(InputAction::SandboxPlantWhite) {
+        Some(VineType::White(BATCH_VINE_VALUE))
+    } else {
+        None
+    };
+
+    if let Some(vine_type) = plant {
+        let mut planted = 0;
+        for &(owner, field_idx) in &selection.fields {
+            if let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == owner) {
+                if vineyard.fields[field_idx].is_empty() {
+                    vineyard.fields[field_idx].plant_vine(vine_type);
+                    planted += 1;
+                }
+            }
+        }
+        info!("🧪 Sandbox: planted {:?} in {} fields", vine_type, planted);
+    }
+
+    if input.just_pressed(InputAction::SandboxAddGrapes) {
+        for &(owner, field_idx) in &selection.fields {
+            if let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == owner) {
+                match vineyard.fields[field_idx].vine {
+                    Some(VineType::White(_)) => vineyard.white_grapes.place(BATCH_GRAPE_VALUE),
+                    _ => vineyard.red_grapes.place(BATCH_GRAPE_VALUE),
+                }
+            }
+        }
+        info!("🧪 Sandbox: added a value {} grape for {} fields", BATCH_GRAPE_VALUE, selection.fields.len());
+    }
+
+    if input.just_pressed(InputAction::SandboxClear) {
+        for &(owner, field_idx) in &selection.fields {
+            if let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == owner) {
+                vineyard.fields[field_idx].vine = None;
+            }
+        }
+
+        // Remove highest indices first so earlier indices stay valid
+        let mut vine_cards = selection.vine_cards.clone();
+        vine_cards.sort_by(|a, b| b.1.cmp(&a.1));
+        for (owner, idx) in vine_cards {
+            if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == owner) {
+                if idx < hand.vine_cards.len() {
+                    hand.vine_cards.remove(idx);
+                }
+            }
+        }
+
+        let mut order_cards = selection.order_cards.clone();
+        order_cards.sort_by(|a, b| b.1.cmp(&a.1));
+        for (owner, idx) in order_cards {
+            if let Some(mut hand) = hands.iter_mut().find(|h| h.owner == owner) {
+                if idx < hand.wine_order_cards.len() {
+                    hand.wine_order_cards.remove(idx);
+                }
+            }
+        }
+
+        info!("🧪 Sandbox: cleared {} fields, discarded {} cards",
+              selection.fields.len(), selection.vine_cards.len() + selection.order_cards.len());
+        selection.clear();
+    }
+}
+
+pub fn sandbox_highlight_system(
+    mut commands: Commands,
+    selection: Res<SandboxSelection>,
+    hands: Query<&Hand>,
+    existing: Query<Entity, With<SandboxHighlight>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let mut spawn_highlight = |pos: Vec2, size: Vec2| {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::from(SELECT_COLOR),
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_translation(pos.extend(WorldLayer::Highlights.z())),
+                ..default()
+            },
+            SandboxHighlight,
+        ));
+    };
+
+    for &(owner, field_idx) in &selection.fields {
+        spawn_highlight(field_sprite_position(owner, field_idx), Vec2::new(40.0, 40.0));
+    }
+
+    for &(owner, idx) in &selection.vine_cards {
+        if let Some(hand) = hands.iter().find(|h| h.owner == owner) {
+            spawn_highlight(hand_card_position(hand, CardType::Vine, idx), Vec2::new(32.0, 42.0));
+        }
+    }
+
+    for &(owner, idx) in &selection.order_cards {
+        if let Some(hand) = hands.iter().find(|h| h.owner == owner) {
+            spawn_highlight(hand_card_position(hand, CardType::WineOrder, idx), Vec2::new(32.0, 42.0));
+        }
+    }
+}
@@ -3,11 +3,66 @@
 // =============================================================================
 
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use serde::{Deserialize, Serialize};
 use crate::components::*;
+use crate::systems::ai::{AIDifficulty, AIPlayer, AISettings};
+use crate::systems::festival::WineFestival;
+use crate::systems::keymap::*;
+use crate::systems::notes::PlayerNotes;
+use crate::systems::play_by_file::{PlayByFile, TurnMove};
+use crate::systems::rng::{GameRng, SeedEntry};
+use crate::systems::settings::UserSettings;
+use std::time::SystemTime;
+
+// Saves are RON files, one per slot. Bump the version whenever a field changes meaning;
+// older saves still load with whatever they lack defaulted, newer ones are refused.
+pub const SAVE_FORMAT_VERSION: u32 = 2;
+pub const SAVE_DIR: &str = "saves";
+pub const QUICK_SAVE_SLOT: u8 = 1;
+// Season autosaves rotate through a ring of files; the size is a user setting
+pub const DEFAULT_AUTOSAVE_RING: u8 = 5;
+pub const MAX_AUTOSAVE_RING: u8 = 10;
+
+pub fn save_slot_path(slot: u8) -> String {
+    format!("{}/slot_{}.ron", SAVE_DIR, slot)
+}
+
+pub fn autosave_path(index: usize) -> String {
+    format!("{}/autosave_{}.ron", SAVE_DIR, index)
+}
+
+// Ring file to overwrite: the first one not written yet, else the oldest
+pub fn next_autosave_index(modified: &[Option<SystemTime>]) -> usize {
+    modified.iter().position(Option::is_none)
+        .or_else(|| modified.iter().enumerate().min_by_key(|(_, t)| **t).map(|(i, _)| i))
+        .unwrap_or(0)
+}
+
+pub fn latest_autosave_index(modified: &[Option<SystemTime>]) -> Option<usize> {
+    modified.iter().enumerate()
+        .filter_map(|(i, t)| t.map(|t| (i, t)))
+        .max_by_key(|(_, t)| *t)
+        .map(|(i, _)| i)
+}
+
+fn autosave_times(ring: u8) -> Vec<Option<SystemTime>> {
+    (0..ring as usize)
+        .map(|i| std::fs::metadata(autosave_path(i)).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+// Looks through the largest ring, so shrinking the setting never hides the newest save
+pub fn load_latest_autosave() -> Result<SaveData, Box<dyn std::error::Error>> {
+    let index = latest_autosave_index(&autosave_times(MAX_AUTOSAVE_RING)).ok_or("no autosave yet")?;
+    load_save_file(&autosave_path(index))
+}
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SaveData {
+    // Missing in the old JSON saves, which read as version 0
+    #[serde(default)]
+    pub version: u32,
     pub players: Vec<PlayerSave>,
     pub vineyards: Vec<VineyardSave>,
     pub hands: Vec<HandSave>,
@@ -16,6 +71,45 @@ pub struct SaveData {
     pub config: GameConfigSave,
     pub current_state: u8, // GameState as u8
     pub action_spaces: Vec<ActionSpaceSave>,
+    #[serde(default)]
+    pub trackers: Vec<TrackerSave>,
+    #[serde(default)]
+    pub decks: DeckSave,
+    #[serde(default)]
+    pub notes: Vec<(u8, String)>,
+    #[serde(default)]
+    pub structures: Vec<StructureSave>,
+    // Full deck contents; older saves keep the decks of the running session
+    #[serde(default)]
+    pub card_decks: Option<CardDecksSave>,
+    // Mama and Papa card ids in play
+    #[serde(default)]
+    pub mama_cards: Vec<u8>,
+    #[serde(default)]
+    pub papa_cards: Vec<u8>,
+    // Whether the wine festival has already been scored this game
+    #[serde(default)]
+    pub festival_held: bool,
+    // How far the game's random draws had got, so a loaded game shuffles as the saved one would
+    #[serde(default)]
+    pub rng: Option<RngSave>,
+    // The play-by-file session this machine was in, picked up again when loaded under --mail
+    #[serde(default)]
+    pub play_by_file: Option<PlayByFileSave>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RngSave {
+    pub seed: u64,
+    pub position: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PlayByFileSave {
+    pub local_player: u8,
+    pub game_id: Option<u64>,
+    pub seq: u32,
+    pub recording: Option<(u64, Vec<TurnMove>)>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -33,13 +127,29 @@ pub struct PlayerSave {
 pub struct VineyardSave {
     pub owner_id: u8,
     pub fields: [Option<VineFieldSave>; 9], // UPDATED: Use VineFieldSave instead of VineTypeSave
-    pub red_grapes: u8,
-    pub white_grapes: u8,
-    pub red_wine: u8,
-    pub white_wine: u8,
+    // Token values on the crush pad and in the cellar, lowest first. Older saves kept plain
+    // counters under different names; those load as an empty pad and cellar.
+    #[serde(default)]
+    pub red_grape_tokens: Vec<u8>,
+    #[serde(default)]
+    pub white_grape_tokens: Vec<u8>,
+    #[serde(default)]
+    pub red_wine_tokens: Vec<u8>,
+    #[serde(default)]
+    pub white_wine_tokens: Vec<u8>,
+    #[serde(default)]
+    pub vintages: Vec<VintageSave>,
     pub lira: u8,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VintageSave {
+    pub wine_type: u8, // WineType as u8
+    pub value: u8,
+    pub year: u8,
+    pub aged: u8,
+}
+
 // NEW: Save structure for VineyardField
 #[derive(Serialize, Deserialize, Clone)]
 pub struct VineFieldSave {
@@ -96,6 +206,10 @@ pub struct TurnOrderSave {
     pub players: Vec<u8>,
     pub current_player: usize,
     pub wake_up_order: Vec<(u8, u8)>,
+    #[serde(default)]
+    pub passed: Vec<u8>,
+    #[serde(default)]
+    pub wake_up_bonuses: Vec<WakeUpBonus>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -105,6 +219,8 @@ pub struct GameConfigSave {
     pub current_year: u8,
     pub max_years: u8,
     pub ai_count: u8, // ADDED: Missing field
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -112,110 +228,266 @@ pub struct ActionSpaceSave {
     pub action: u8, // ActionSpace as u8
     pub occupied_by: Option<u8>,
     pub bonus_worker_slot: Option<u8>,
+    #[serde(default)]
+    pub bonus_chit: Option<u8>, // BonusChit as u8
+    // Older saves list only the leftmost column
+    #[serde(default)]
+    pub column: u8,
 }
 
-#[derive(Resource)]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TrackerSave {
+    pub owner_id: u8,
+    pub level: u8,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StructureSave {
+    pub owner_id: u8,
+    pub structure_type: StructureType,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CardDecksSave {
+    pub vine_deck: Vec<VineCardSave>,
+    pub wine_order_deck: Vec<WineOrderCardSave>,
+    pub vine_discard: Vec<VineCardSave>,
+    pub wine_order_discard: Vec<WineOrderCardSave>,
+}
+
+// Deck sizes only; recorded so save diffs can spot lost or duplicated cards
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct DeckSave {
+    pub vine_deck: usize,
+    pub wine_order_deck: usize,
+    pub vine_discard: usize,
+    pub wine_order_discard: usize,
+}
+
+// Autosave policy: one save as each season starts, into the ring
+#[derive(Resource, Default)]
 pub struct SaveManager {
-    pub auto_save_timer: Timer,
-    pub last_save_time: f32,
+    // Season last autosaved, so re-entering it (a load, an undo) doesn't write it again
+    pub last_autosave: Option<(u8, GameState)>,
 }
 
-impl Default for SaveManager {
-    fn default() -> Self {
-        Self {
-            auto_save_timer: Timer::from_seconds(30.0, TimerMode::Repeating),
-            last_save_time: 0.0,
+impl SaveManager {
+    pub fn autosave_due(&mut self, year: u8, season: &GameState) -> bool {
+        let key = (year, season.clone());
+        if self.last_autosave.as_ref() == Some(&key) {
+            return false;
         }
+        self.last_autosave = Some(key);
+        true
     }
 }
 
+// Everything a save is made from, read together so every caller snapshots the same state
+#[derive(SystemParam)]
+pub struct GameSnapshot<'w, 's> {
+    pub players: Query<'w, 's, &'static Player>,
+    pub vineyards: Query<'w, 's, &'static Vineyard>,
+    pub hands: Query<'w, 's, &'static Hand>,
+    pub workers: Query<'w, 's, &'static Worker>,
+    pub action_spaces: Query<'w, 's, &'static ActionSpaceSlot>,
+    pub trackers: Query<'w, 's, &'static ResidualPaymentTracker>,
+    pub structures: Query<'w, 's, &'static Structure>,
+    pub mama_cards: Query<'w, 's, &'static MamaCard>,
+    pub papa_cards: Query<'w, 's, &'static PapaCard>,
+    pub turn_order: Res<'w, TurnOrder>,
+    pub config: Res<'w, GameConfig>,
+    pub current_state: Res<'w, State<GameState>>,
+    pub card_decks: Res<'w, CardDecks>,
+    pub notes: Res<'w, PlayerNotes>,
+    pub festival: Res<'w, WineFestival>,
+    pub rng: Res<'w, GameRng>,
+    pub play_by_file: Option<Res<'w, PlayByFile>>,
+}
+
 pub fn save_game_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    players: Query<&Player>,
-    vineyards: Query<&Vineyard>,
-    hands: Query<&Hand>,
-    current_state: Res<State<GameState>>,
-    mut save_timer: Local<Timer>,
-    time: Res<Time>,
+    input: BoundInput,
+    snapshot: GameSnapshot,
 ) {
-    // Don't auto-save in these states
-    match current_state.get() {
-        GameState::MainMenu | GameState::GameOver => return,
+    // Don't save in these states
+    match snapshot.current_state.get() {
+        GameState::MainMenu | GameState::Lobby | GameState::Gallery | GameState::GameOver => return,
         _ => {}
     }
     
-    // Initialize auto-save timer
-    if save_timer.duration() == std::time::Duration::ZERO {
-        *save_timer = Timer::from_seconds(30.0, TimerMode::Repeating); // Auto-save every 30 seconds
-    }
-    
-    save_timer.tick(time.delta());
-    
     // Manual save with Ctrl+S
-    if keyboard.pressed(KeyCode::ControlLeft) && keyboard.just_pressed(KeyCode::KeyS) {
-        perform_save(&players, &vineyards, &hands);
-        info!("Manual save completed");
-    }
-    
-    // Auto-save (only during gameplay)
-    if save_timer.just_finished() {
-        perform_save(&players, &vineyards, &hands);
-        info!("Auto-save completed");
+    if input.just_pressed(InputAction::SaveGame) {
+        perform_save(&snapshot, &save_slot_path(QUICK_SAVE_SLOT));
     }
 }
 
-fn perform_save(
-    players: &Query<&Player>,
-    vineyards: &Query<&Vineyard>,
-    hands: &Query<&Hand>,
+// Saves as each season starts, overwriting the oldest autosave once the ring is full
+pub fn season_autosave_system(
+    snapshot: GameSnapshot,
+    settings: Res<UserSettings>,
+    mut manager: ResMut<SaveManager>,
 ) {
-    // Your existing save logic here
-    info!("Game saved successfully");
+    if !snapshot.current_state.is_changed() {
+        return;
+    }
+    let season = snapshot.current_state.get();
+    match season {
+        GameState::Setup => manager.last_autosave = None,
+        GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter => {
+            if settings.auto_save_enabled && manager.autosave_due(snapshot.config.current_year, season) {
+                let ring = settings.auto_save_ring.clamp(1, MAX_AUTOSAVE_RING);
+                perform_save(&snapshot, &autosave_path(next_autosave_index(&autosave_times(ring))));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn perform_save(snapshot: &GameSnapshot, path: &str) {
+    let written = create_save_data(snapshot)
+        .and_then(|save_data| save_to_path(&save_data, path).map_err(|e| e.to_string()));
+    match written {
+        Ok(()) => info!("💾 Game saved to {}", path),
+        Err(e) => warn!("Failed to save to {}: {}", path, e),
+    }
 }
 
+// Anything a save restores; the rest of the world (camera, UI) stays
+type SavedEntity = Or<(
+    With<Player>, With<Vineyard>, With<Hand>, With<Worker>, With<ActionSpaceSlot>, With<ActionBoard>,
+    With<ResidualPaymentTracker>, With<Structure>, With<MamaCard>, With<PapaCard>, With<AIPlayer>,
+)>;
+
+// Loads the quick save, or the latest autosave when there is none
 pub fn load_game_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
-    entities: Query<Entity, (Without<Camera>, Without<Window>)>,
+    (ai_settings, game_settings, play_by_file): (Res<AISettings>, Res<GameSettings>, Option<Res<PlayByFile>>),
+    entities: Query<Entity, SavedEntity>,
 ) {
-    if keyboard.just_pressed(KeyCode::F9) {
-        if let Ok(save_data) = load_from_file() {
-            // Clear existing entities
+    if !input.just_pressed(InputAction::LoadGame) {
+        return;
+    }
+
+    let loaded = load_save_file(&save_slot_path(QUICK_SAVE_SLOT))
+        .or_else(|_| load_latest_autosave());
+    match loaded {
+        Ok(save_data) => {
             for entity in entities.iter() {
-                commands.entity(entity).despawn();
+                commands.entity(entity).despawn_recursive();
             }
-            
-            // Load game state
-            load_save_data(&mut commands, &save_data, &mut next_state);
+            load_save_data(&mut commands, &save_data, &mut next_state, &game_settings, ai_settings.ai_difficulty, play_by_file.as_deref());
             info!("Game loaded successfully");
-        } else {
-            warn!("Failed to load game - no save file found");
         }
+        Err(e) => warn!("Failed to load game: {}", e),
+    }
+}
+
+// Main menu line for Continue, when there is an autosave to go back to
+pub fn continue_label(keymap: &Keymap) -> Option<String> {
+    let key = keymap.binding(InputAction::ContinueGame)?.key_label();
+    let save_data = load_latest_autosave().ok()?;
+    let season = u8_to_state(save_data.current_state)?;
+    Some(format!("Press {} to Continue (year {}, {:?})", key, save_data.config.current_year, season))
+}
+
+// Continue: Enter (by default) on the main menu picks up the latest autosave
+pub fn continue_game_system(
+    input: BoundInput,
+    seed_entry: Res<SeedEntry>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    (ai_settings, game_settings, play_by_file): (Res<AISettings>, Res<GameSettings>, Option<Res<PlayByFile>>),
+    text_query: Query<Entity, With<Text>>,
+    entities: Query<Entity, SavedEntity>,
+) {
+    // Enter also closes the seed field
+    if seed_entry.editing || !input.just_pressed(InputAction::ContinueGame) {
+        return;
+    }
+
+    match load_latest_autosave() {
+        Ok(save_data) => {
+            // Same cleanup as setup_game_system; the HUD is normally built in spring
+            for entity in text_query.iter() {
+                commands.entity(entity).despawn();
+            }
+            for entity in entities.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            load_save_data(&mut commands, &save_data, &mut next_state, &game_settings, ai_settings.ai_difficulty, play_by_file.as_deref());
+            crate::systems::ui::setup_ui(&mut commands);
+            info!("▶ Continuing year {} from the latest autosave", save_data.config.current_year);
+        }
+        Err(e) => warn!("Nothing to continue: {}", e),
     }
 }
 
-fn create_save_data(
-    players: &Query<&Player>,
-    vineyards: &Query<&Vineyard>,
-    hands: &Query<&Hand>,
-    workers: &Query<&Worker>,
-    turn_order: &TurnOrder,
-    config: &GameConfig,
-    current_state: &State<GameState>,
-    action_spaces: &Query<&ActionSpaceSlot>,
-) -> Result<SaveData, String> {
-    let players_save: Vec<_> = players.iter().map(|p| PlayerSave {
+fn vine_card_save(card: &VineCard) -> VineCardSave {
+    VineCardSave {
+        id: card.id,
+        vine_type: match card.vine_type {
+            VineType::Red(val) => VineTypeSave { is_red: true, value: val },
+            VineType::White(val) => VineTypeSave { is_red: false, value: val },
+        },
+        cost: card.cost,
+        art_style: card_art_to_u8(card.art_style),
+        special_ability: card.special_ability.map(vine_ability_to_u8),
+    }
+}
+
+fn vine_card_from(save: &VineCardSave) -> VineCard {
+    VineCard {
+        id: save.id,
+        vine_type: if save.vine_type.is_red {
+            VineType::Red(save.vine_type.value)
+        } else {
+            VineType::White(save.vine_type.value)
+        },
+        cost: save.cost,
+        art_style: u8_to_card_art(save.art_style),
+        special_ability: save.special_ability.map(u8_to_vine_ability),
+    }
+}
+
+fn order_card_save(card: &WineOrderCard) -> WineOrderCardSave {
+    WineOrderCardSave {
+        id: card.id,
+        red_wine_needed: card.red_wine_needed,
+        white_wine_needed: card.white_wine_needed,
+        victory_points: card.victory_points,
+        payout: card.payout,
+        art_style: order_art_to_u8(card.art_style),
+        order_type: order_type_to_u8(card.order_type),
+        residual_payment: card.residual_payment,
+    }
+}
+
+fn order_card_from(save: &WineOrderCardSave) -> WineOrderCard {
+    WineOrderCard {
+        id: save.id,
+        red_wine_needed: save.red_wine_needed,
+        white_wine_needed: save.white_wine_needed,
+        victory_points: save.victory_points,
+        payout: save.payout,
+        art_style: u8_to_order_art(save.art_style),
+        order_type: u8_to_order_type(save.order_type),
+        residual_payment: save.residual_payment,
+    }
+}
+
+pub fn create_save_data(snapshot: &GameSnapshot) -> Result<SaveData, String> {
+    let players_save: Vec<_> = snapshot.players.iter().map(|p| PlayerSave {
         id: p.id.0,
         name: p.name.clone(),
-        victory_points: p.victory_points,
-        lira: p.lira,
+        victory_points: p.victory_points.get(),
+        lira: p.lira.get(),
         workers: p.workers,
         grande_worker_available: p.grande_worker_available,
         is_ai: p.is_ai, // ADDED: Missing field
     }).collect();
     
-    let vineyards_save: Vec<_> = vineyards.iter().map(|v| VineyardSave {
+    let vineyards_save: Vec<_> = snapshot.vineyards.iter().map(|v| VineyardSave {
         owner_id: v.owner.0,
         // FIXED: Convert VineyardField array to VineFieldSave array
         fields: v.fields.map(|field| {
@@ -228,38 +500,26 @@ fn create_save_data(
                 sold_this_year: field.sold_this_year,
             })
         }),
-        red_grapes: v.red_grapes,
-        white_grapes: v.white_grapes,
-        red_wine: v.red_wine,
-        white_wine: v.white_wine,
-        lira: v.lira,
+        red_grape_tokens: v.red_grapes.values(),
+        white_grape_tokens: v.white_grapes.values(),
+        red_wine_tokens: v.red_wine.values(),
+        white_wine_tokens: v.white_wine.values(),
+        vintages: v.vintages.iter().map(|vintage| VintageSave {
+            wine_type: wine_type_to_u8(vintage.wine_type),
+            value: vintage.value,
+            year: vintage.year,
+            aged: vintage.aged,
+        }).collect(),
+        lira: v.lira.get(),
     }).collect();
     
-    let hands_save: Vec<_> = hands.iter().map(|h| HandSave {
+    let hands_save: Vec<_> = snapshot.hands.iter().map(|h| HandSave {
         owner_id: h.owner.0,
-        vine_cards: h.vine_cards.iter().map(|vc| VineCardSave {
-            id: vc.id,
-            vine_type: match vc.vine_type {
-                VineType::Red(val) => VineTypeSave { is_red: true, value: val },
-                VineType::White(val) => VineTypeSave { is_red: false, value: val },
-            },
-            cost: vc.cost,
-            art_style: card_art_to_u8(vc.art_style), // ADDED: Missing field
-            special_ability: vc.special_ability.map(vine_ability_to_u8), // ADDED: Missing field
-        }).collect(),
-        wine_order_cards: h.wine_order_cards.iter().map(|woc| WineOrderCardSave {
-            id: woc.id,
-            red_wine_needed: woc.red_wine_needed,
-            white_wine_needed: woc.white_wine_needed,
-            victory_points: woc.victory_points,
-            payout: woc.payout,
-            art_style: order_art_to_u8(woc.art_style), // ADDED: Missing field
-            order_type: order_type_to_u8(woc.order_type), // ADDED: Missing field
-            residual_payment: woc.residual_payment,
-        }).collect(),
+        vine_cards: h.vine_cards.iter().map(vine_card_save).collect(),
+        wine_order_cards: h.wine_order_cards.iter().map(order_card_save).collect(),
     }).collect();
     
-    let workers_save: Vec<_> = workers.iter().map(|w| WorkerSave {
+    let workers_save: Vec<_> = snapshot.workers.iter().map(|w| WorkerSave {
         owner_id: w.owner.0,
         is_grande: w.is_grande,
         placed_at: w.placed_at.map(action_to_u8),
@@ -267,66 +527,128 @@ fn create_save_data(
         position_y: w.position.y,
     }).collect();
     
+    let turn_order = &snapshot.turn_order;
     let turn_order_save = TurnOrderSave {
         players: turn_order.players.iter().map(|p| p.0).collect(),
         current_player: turn_order.current_player,
         wake_up_order: turn_order.wake_up_order.iter().map(|(p, t)| (p.0, *t)).collect(),
+        passed: turn_order.passed.iter().map(|p| p.0).collect(),
+        wake_up_bonuses: turn_order.wake_up_bonuses.clone(),
     };
     
+    let config = &snapshot.config;
     let config_save = GameConfigSave {
         player_count: config.player_count,
         target_victory_points: config.target_victory_points,
         current_year: config.current_year,
         max_years: config.max_years,
         ai_count: config.ai_count, // ADDED: Missing field
+        seed: config.seed,
     };
     
-    let action_spaces_save: Vec<_> = action_spaces.iter().map(|s| ActionSpaceSave {
+    let action_spaces_save: Vec<_> = snapshot.action_spaces.iter().map(|s| ActionSpaceSave {
         action: action_to_u8(s.action),
         occupied_by: s.occupied_by.map(|p| p.0),
         bonus_worker_slot: s.bonus_worker_slot.map(|p| p.0),
+        bonus_chit: s.bonus_chit.map(bonus_chit_to_u8),
+        column: s.column as u8,
+    }).collect();
+    
+    let trackers_save: Vec<_> = snapshot.trackers.iter().map(|t| TrackerSave {
+        owner_id: t.owner.0,
+        level: t.level,
     }).collect();
     
+    let card_decks = &snapshot.card_decks;
+    let decks_save = DeckSave {
+        vine_deck: card_decks.vine_deck.len(),
+        wine_order_deck: card_decks.wine_order_deck.len(),
+        vine_discard: card_decks.vine_discard.len(),
+        wine_order_discard: card_decks.wine_order_discard.len(),
+    };
+    
     Ok(SaveData {
+        version: SAVE_FORMAT_VERSION,
         players: players_save,
         vineyards: vineyards_save,
         hands: hands_save,
         workers: workers_save,
         turn_order: turn_order_save,
         config: config_save,
-        current_state: state_to_u8(current_state.get()),
+        current_state: state_to_u8(snapshot.current_state.get()),
         action_spaces: action_spaces_save,
+        trackers: trackers_save,
+        decks: decks_save,
+        notes: snapshot.notes.notes.iter()
+            .filter(|(_, note)| !note.is_empty())
+            .map(|(id, note)| (id.0, note.clone()))
+            .collect(),
+        structures: snapshot.structures.iter().map(|s| StructureSave {
+            owner_id: s.owner.0,
+            structure_type: s.structure_type,
+        }).collect(),
+        card_decks: Some(CardDecksSave {
+            vine_deck: card_decks.vine_deck.iter().map(vine_card_save).collect(),
+            wine_order_deck: card_decks.wine_order_deck.iter().map(order_card_save).collect(),
+            vine_discard: card_decks.vine_discard.iter().map(vine_card_save).collect(),
+            wine_order_discard: card_decks.wine_order_discard.iter().map(order_card_save).collect(),
+        }),
+        mama_cards: snapshot.mama_cards.iter().map(|m| m.id).collect(),
+        papa_cards: snapshot.papa_cards.iter().map(|p| p.id).collect(),
+        festival_held: snapshot.festival.held,
+        rng: Some(RngSave { seed: snapshot.rng.seed, position: snapshot.rng.position() }),
+        play_by_file: snapshot.play_by_file.as_deref().map(PlayByFile::session),
     })
 }
 
-fn save_to_file(save_data: &SaveData) -> Result<(), Box<dyn std::error::Error>> {
-    let json = serde_json::to_string_pretty(save_data)?;
-    std::fs::write("viticulture_save.json", json)?;
+pub fn save_to_slot(save_data: &SaveData, slot: u8) -> Result<(), Box<dyn std::error::Error>> {
+    save_to_path(save_data, &save_slot_path(slot))
+}
+
+pub fn save_to_path(save_data: &SaveData, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let ron = ron::ser::to_string_pretty(save_data, ron::ser::PrettyConfig::default())?;
+    std::fs::create_dir_all(SAVE_DIR)?;
+    std::fs::write(path, ron)?;
     Ok(())
 }
 
-fn load_from_file() -> Result<SaveData, Box<dyn std::error::Error>> {
-    let json = std::fs::read_to_string("viticulture_save.json")?;
-    let save_data: SaveData = serde_json::from_str(&json)?;
+// RON slot files, or the JSON of older builds
+pub fn load_save_file(path: &str) -> Result<SaveData, Box<dyn std::error::Error>> {
+    let text = std::fs::read_to_string(path)?;
+    let save_data: SaveData = if path.ends_with(".json") {
+        serde_json::from_str(&text)?
+    } else {
+        ron::from_str(&text)?
+    };
+    if save_data.version > SAVE_FORMAT_VERSION {
+        return Err(format!("{} is save format v{}, this build reads up to v{}", path, save_data.version, SAVE_FORMAT_VERSION).into());
+    }
     Ok(save_data)
 }
 
-fn load_save_data(
+pub fn load_save_data(
     commands: &mut Commands,
     save_data: &SaveData,
     next_state: &mut ResMut<NextState<GameState>>,
+    game_settings: &GameSettings,
+    ai_difficulty: AIDifficulty,
+    play_by_file: Option<&PlayByFile>,
 ) {
     // Load players
     for player_save in &save_data.players {
         commands.spawn(Player {
             id: PlayerId(player_save.id),
             name: player_save.name.clone(),
-            victory_points: player_save.victory_points,
-            lira: player_save.lira,
+            victory_points: VicPoints::new(player_save.victory_points),
+            lira: Lira::new(player_save.lira),
             workers: player_save.workers,
             grande_worker_available: player_save.grande_worker_available,
             is_ai: player_save.is_ai, // ADDED: Missing field
         });
+        // setup_ai_players only runs during Setup
+        if player_save.is_ai {
+            commands.spawn(AIPlayer::new(PlayerId(player_save.id), game_settings.ai_difficulty(player_save.id, ai_difficulty)));
+        }
     }
     
     // Load vineyards
@@ -352,47 +674,34 @@ fn load_save_data(
         commands.spawn(Vineyard {
             owner: PlayerId(vineyard_save.owner_id),
             fields,
-            red_grapes: vineyard_save.red_grapes,
-            white_grapes: vineyard_save.white_grapes,
-            red_wine: vineyard_save.red_wine,
-            white_wine: vineyard_save.white_wine,
-            lira: vineyard_save.lira,
+            red_grapes: ValueTrack::from_values(&vineyard_save.red_grape_tokens),
+            white_grapes: ValueTrack::from_values(&vineyard_save.white_grape_tokens),
+            red_wine: ValueTrack::from_values(&vineyard_save.red_wine_tokens),
+            white_wine: ValueTrack::from_values(&vineyard_save.white_wine_tokens),
+            lira: Lira::new(vineyard_save.lira),
+            wine_recipe: None,
+            harvest_selection: Vec::new(),
+            order_wines: (None, None),
+            vintages: vineyard_save.vintages.iter().map(|vintage| Vintage {
+                wine_type: u8_to_wine_type(vintage.wine_type),
+                value: vintage.value,
+                year: vintage.year,
+                aged: vintage.aged,
+            }).collect(),
+            sparkling_aging: false,
         });
     }
     
     // Load hands
     for hand_save in &save_data.hands {
-        let vine_cards = hand_save.vine_cards.iter().map(|vc| VineCard {
-            id: vc.id,
-            vine_type: if vc.vine_type.is_red {
-                VineType::Red(vc.vine_type.value)
-            } else {
-                VineType::White(vc.vine_type.value)
-            },
-            cost: vc.cost,
-            art_style: u8_to_card_art(vc.art_style), // ADDED: Missing field
-            special_ability: vc.special_ability.map(u8_to_vine_ability), // ADDED: Missing field
-        }).collect();
-        
-        let wine_order_cards = hand_save.wine_order_cards.iter().map(|woc| WineOrderCard {
-            id: woc.id,
-            red_wine_needed: woc.red_wine_needed,
-            white_wine_needed: woc.white_wine_needed,
-            victory_points: woc.victory_points,
-            payout: woc.payout,
-            art_style: u8_to_order_art(woc.art_style), // ADDED: Missing field
-            order_type: u8_to_order_type(woc.order_type), // ADDED: Missing field
-            residual_payment: woc.residual_payment,
-        }).collect();
-        
         commands.spawn(Hand {
             owner: PlayerId(hand_save.owner_id),
-            vine_cards,
-            wine_order_cards,
+            vine_cards: hand_save.vine_cards.iter().map(vine_card_from).collect(),
+            wine_order_cards: hand_save.wine_order_cards.iter().map(order_card_from).collect(),
         });
     }
     
-    // Load workers
+    // Load workers; placed ones keep their space, and the spaces below keep their occupants
     for worker_save in &save_data.workers {
         commands.spawn((
             Worker {
@@ -401,23 +710,70 @@ fn load_save_data(
                 placed_at: worker_save.placed_at.and_then(u8_to_action),
                 position: Vec2::new(worker_save.position_x, worker_save.position_y),
             },
-            Clickable { size: Vec2::new(20.0, 20.0) },
+            Clickable { size: Vec2::splat(if worker_save.is_grande { 25.0 } else { 20.0 }) },
         ));
     }
     
-    // Load action spaces
+    // Load action spaces, matched by action and column like an undo restore
+    // Saves from before the extra columns only cover the leftmost ones; the rest start empty
     let action_board = ActionBoard::new();
-    for (i, space_save) in save_data.action_spaces.iter().enumerate() {
-        if let Some(mut space) = action_board.spaces.get(i).cloned() {
+    for mut space in action_board.spaces.iter().cloned() {
+        let action = action_to_u8(space.action);
+        if let Some(space_save) = save_data.action_spaces.iter().find(|s| s.action == action && s.column as usize == space.column) {
             space.occupied_by = space_save.occupied_by.map(PlayerId);
             space.bonus_worker_slot = space_save.bonus_worker_slot.map(PlayerId);
-            commands.spawn((
-                space,
-                Clickable { size: Vec2::new(60.0, 30.0) },
-            ));
+            space.bonus_chit = space_save.bonus_chit.map(u8_to_bonus_chit);
         }
+        commands.spawn((
+            space,
+            Clickable { size: Vec2::new(60.0, 30.0) },
+        ));
     }
     commands.spawn(action_board);
+    commands.insert_resource(WineFestival { held: save_data.festival_held });
+    if let Some(rng) = save_data.rng {
+        commands.insert_resource(GameRng::resume(rng.seed, rng.position));
+    }
+    // Only a game played by file as the same seat picks its turn files up where they left off
+    if let (Some(pbf), Some(session)) = (play_by_file, &save_data.play_by_file) {
+        if session.local_player == pbf.local_player.0 {
+            commands.insert_resource(PlayByFile::resume(session));
+        }
+    }
+    
+    // Older saves have no trackers; setup_residual_payment_system only runs during Setup
+    for tracker_save in &save_data.trackers {
+        commands.spawn(ResidualPaymentTracker {
+            owner: PlayerId(tracker_save.owner_id),
+            level: tracker_save.level,
+        });
+    }
+    
+    for structure_save in &save_data.structures {
+        commands.spawn(Structure {
+            structure_type: structure_save.structure_type,
+            owner: PlayerId(structure_save.owner_id),
+        });
+    }
+    
+    // Mama and Papa cards come back from the full set by id
+    let all_cards = CardDecks::new();
+    for mama in all_cards.mama_cards.iter().filter(|m| save_data.mama_cards.contains(&m.id)) {
+        commands.spawn(mama.clone());
+    }
+    for papa in all_cards.papa_cards.iter().filter(|p| save_data.papa_cards.contains(&p.id)) {
+        commands.spawn(papa.clone());
+    }
+    
+    if let Some(decks) = &save_data.card_decks {
+        commands.insert_resource(CardDecks {
+            vine_deck: decks.vine_deck.iter().map(vine_card_from).collect(),
+            wine_order_deck: decks.wine_order_deck.iter().map(order_card_from).collect(),
+            vine_discard: decks.vine_discard.iter().map(vine_card_from).collect(),
+            wine_order_discard: decks.wine_order_discard.iter().map(order_card_from).collect(),
+            ..all_cards
+        });
+    }
     
     // Load resources
     commands.insert_resource(TurnOrder {
@@ -425,7 +781,8 @@ fn load_save_data(
         current_player: save_data.turn_order.current_player,
         wake_up_order: save_data.turn_order.wake_up_order.iter()
             .map(|(id, time)| (PlayerId(*id), *time)).collect(),
-        wake_up_bonuses: Vec::new(),
+        wake_up_bonuses: save_data.turn_order.wake_up_bonuses.clone(),
+        passed: save_data.turn_order.passed.iter().map(|&id| PlayerId(id)).collect(),
     });
     
     commands.insert_resource(GameConfig {
@@ -434,6 +791,12 @@ fn load_save_data(
         current_year: save_data.config.current_year,
         max_years: save_data.config.max_years,
         ai_count: save_data.config.ai_count, // ADDED: Missing field
+        seed: save_data.config.seed,
+    });
+    
+    commands.insert_resource(PlayerNotes {
+        notes: save_data.notes.iter().map(|(id, note)| (PlayerId(*id), note.clone())).collect(),
+        editing: None,
     });
     
     // Set game state
@@ -459,6 +822,24 @@ fn u8_to_field_type(value: u8) -> FieldType {
     }
 }
 
+fn wine_type_to_u8(wine_type: WineType) -> u8 {
+    match wine_type {
+        WineType::Red => 0,
+        WineType::White => 1,
+        WineType::Blush => 2,
+        WineType::Sparkling => 3,
+    }
+}
+
+fn u8_to_wine_type(value: u8) -> WineType {
+    match value {
+        1 => WineType::White,
+        2 => WineType::Blush,
+        3 => WineType::Sparkling,
+        _ => WineType::Red,
+    }
+}
+
 fn card_art_to_u8(art: CardArt) -> u8 {
     match art {
         CardArt::BasicRed => 0,
@@ -560,7 +941,21 @@ fn u8_to_action(value: u8) -> Option<ActionSpace> {
     }
 }
 
-fn state_to_u8(state: &GameState) -> u8 {
+fn bonus_chit_to_u8(chit: BonusChit) -> u8 {
+    match chit {
+        BonusChit::ExtraCard => 0,
+        BonusChit::ExtraLira => 1,
+    }
+}
+
+fn u8_to_bonus_chit(value: u8) -> BonusChit {
+    match value {
+        1 => BonusChit::ExtraLira,
+        _ => BonusChit::ExtraCard,
+    }
+}
+
+pub fn state_to_u8(state: &GameState) -> u8 {
     match state {
         GameState::MainMenu => 0,
         GameState::Setup => 1,
@@ -569,10 +964,12 @@ fn state_to_u8(state: &GameState) -> u8 {
         GameState::Fall => 4,
         GameState::Winter => 5,
         GameState::GameOver => 6,
+        GameState::Lobby => 7,
+        GameState::Gallery => 8,
     }
 }
 
-fn u8_to_state(value: u8) -> Option<GameState> {
+pub fn u8_to_state(value: u8) -> Option<GameState> {
     match value {
         0 => Some(GameState::MainMenu),
         1 => Some(GameState::Setup),
@@ -581,6 +978,8 @@ fn u8_to_state(value: u8) -> Option<GameState> {
         4 => Some(GameState::Fall),
         5 => Some(GameState::Winter),
         6 => Some(GameState::GameOver),
+        7 => Some(GameState::Lobby),
+        8 => Some(GameState::Gallery),
         _ => None,
     }
 }
\ No newline at end of file
@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+use serde_json::Value;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::save::{create_save_data, load_save_file, save_slot_path, GameSnapshot, SaveData, QUICK_SAVE_SLOT};
+use crate::systems::render_layers::UiLayer;
+
+// When present, F5 compares the quick save with this file instead of with the live game
+const COMPARE_PATH: &str = "saves/compare.ron";
+const MAX_PANEL_LINES: usize = 30;
+
+#[derive(Clone, Debug)]
+pub struct SaveDiffEntry {
+    pub path: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl SaveDiffEntry {
+    // Top-level section of the save this entry belongs to (players, vineyards, decks, ...)
+    pub fn section(&self) -> &str {
+        self.path.split(['.', '[']).next().unwrap_or("")
+    }
+}
+
+#[derive(Component)]
+pub struct SaveDiffPanel;
+
+// Per-player lists are sorted so entity query order doesn't show up as a difference
+fn normalized(save: &SaveData) -> SaveData {
+    let mut save = save.clone();
+    save.players.sort_by_key(|p| p.id);
+    save.vineyards.sort_by_key(|v| v.owner_id);
+    save.hands.sort_by_key(|h| h.owner_id);
+    save.trackers.sort_by_key(|t| t.owner_id);
+    save.workers.sort_by_key(|w| (w.owner_id, w.is_grande));
+    save.action_spaces.sort_by_key(|s| s.action);
+    save.notes.sort();
+    save
+}
+
+pub fn diff_saves(left: &SaveData, right: &SaveData) -> Vec<SaveDiffEntry> {
+    let mut entries = Vec::new();
+    let (Ok(left), Ok(right)) = (
+        serde_json::to_value(normalized(left)),
+        serde_json::to_value(normalized(right)),
+    ) else {
+        return entries;
+    };
+
+    diff_values(String::new(), &left, &right, &mut entries);
+    entries
+}
+
+// Array elements carrying a player id are labelled by it, e.g. players[id=1].lira
+fn element_label(path: &str, index: usize, value: &Value) -> String {
+    for key in ["id", "owner_id"] {
+        if let Some(id) = value.get(key).and_then(Value::as_u64) {
+            return format!("{}[{}={}]", path, key, id);
+        }
+    }
+    format!("{}[{}]", path, index)
+}
+
+fn diff_values(path: String, left: &Value, right: &Value, out: &mut Vec<SaveDiffEntry>) {
+    match (left, right) {
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                diff_values(child, a.get(key).unwrap_or(&Value::Null), b.get(key).unwrap_or(&Value::Null), out);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                let l = a.get(i).unwrap_or(&Value::Null);
+                let r = b.get(i).unwrap_or(&Value::Null);
+                let label_source = if l.is_null() { r } else { l };
+                diff_values(element_label(&path, i, label_source), l, r, out);
+            }
+        }
+        _ if left != right => out.push(SaveDiffEntry {
+            path,
+            left: value_text(left),
+            right: value_text(right),
+        }),
+        _ => {}
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => "—".to_string(),
+        Value::Object(_) | Value::Array(_) => "{…}".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// F5 in testing mode: diff the save file against the compare file, or against the live game
+pub fn save_diff_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    test_config: Res<AutoTestConfig>,
+    mut commands: Commands,
+    existing: Query<Entity, With<SaveDiffPanel>>,
+    snapshot: GameSnapshot,
+) {
+    if !test_config.enabled || !keyboard.just_pressed(KeyCode::F5) {
+        return;
+    }
+
+    if !existing.is_empty() {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let save_path = save_slot_path(QUICK_SAVE_SLOT);
+    let left = match load_save_file(&save_path) {
+        Ok(save) => save,
+        Err(e) => {
+            warn!("Save diff: could not read {}: {}", save_path, e);
+            return;
+        }
+    };
+
+    let (right, right_label) = match load_save_file(COMPARE_PATH) {
+        Ok(save) => (save, COMPARE_PATH),
+        Err(_) => match create_save_data(&snapshot) {
+            Ok(save) => (save, "live game"),
+            Err(e) => {
+                warn!("Save diff: could not snapshot live game: {}", e);
+                return;
+            }
+        },
+    };
+
+    let entries = diff_saves(&left, &right);
+    info!("🔍 Save diff {} vs {}: {} differences", save_path, right_label, entries.len());
+    for entry in &entries {
+        info!("  {}: {} -> {}", entry.path, entry.left, entry.right);
+    }
+
+    show_save_diff_panel(&mut commands, &entries, &save_path, right_label);
+}
+
+fn show_save_diff_panel(commands: &mut Commands, entries: &[SaveDiffEntry], save_path: &str, right_label: &str) {
+    let mut text = format!("SAVE DIFF (F5 to close)\n{} -> {}\n\n", save_path, right_label);
+
+    if entries.is_empty() {
+        text.push_str("No differences");
+    }
+
+    let mut section = "";
+    for entry in entries.iter().take(MAX_PANEL_LINES) {
+        if entry.section() != section {
+            section = entry.section();
+            text.push_str(&format!("\n{}\n", section.to_uppercase()));
+        }
+        text.push_str(&format!("  {}: {} -> {}\n", entry.path, entry.left, entry.right));
+    }
+
+    if entries.len() > MAX_PANEL_LINES {
+        text.push_str(&format!("\n... {} more (see log)", entries.len() - MAX_PANEL_LINES));
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(50.0),
+                right: Val::Px(50.0),
+                width: Val::Px(460.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.1, 0.15, 0.95)).into(),
+            z_index: UiLayer::DevTools.z_index(),
+            ..default()
+        },
+        SaveDiffPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 12.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
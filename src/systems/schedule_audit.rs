@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+// Debug builds look over every schedule once before the first frame. A system added twice
+// runs twice a frame, and one guarded by a condition and its negation never runs; both
+// are logged as errors so they get fixed rather than noticed in play.
+
+// One system as the schedule holds it, with the names of its own run conditions
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisteredSystem {
+    pub name: String,
+    pub conditions: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScheduleProblem {
+    Duplicate {
+        schedule: String,
+        system: String,
+        count: usize,
+        // The copies are gated differently, so which one was meant is unclear
+        conditions_differ: bool,
+    },
+    ContradictoryConditions {
+        schedule: String,
+        system: String,
+        condition: String,
+    },
+}
+
+impl std::fmt::Display for ScheduleProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleProblem::Duplicate { schedule, system, count, conditions_differ } => {
+                write!(f, "{} is registered {} times in {}", system, count, schedule)?;
+                if *conditions_differ {
+                    write!(f, " with conflicting run conditions")?;
+                }
+                Ok(())
+            }
+            ScheduleProblem::ContradictoryConditions { schedule, system, condition } => {
+                write!(f, "{} in {} runs only if both {} and !{}, so never runs", system, schedule, condition, condition)
+            }
+        }
+    }
+}
+
+pub fn schedule_registrations(schedule: &Schedule) -> Vec<RegisteredSystem> {
+    schedule.graph().systems()
+        // Sync points Bevy adds itself
+        .filter(|(_, system, _)| !system.name().ends_with("apply_deferred"))
+        .map(|(_, system, conditions)| RegisteredSystem {
+            name: system.name().to_string(),
+            conditions: conditions.iter().map(|c| c.name().to_string()).collect(),
+        })
+        .collect()
+}
+
+// The parts of a condition that must all hold; anything with an `or_else` is taken whole
+fn conjuncts(condition: &str) -> Vec<&str> {
+    if condition.contains(" || ") {
+        vec![condition]
+    } else {
+        condition.split(" && ").collect()
+    }
+}
+
+pub fn audit_registrations(schedule: &str, systems: &[RegisteredSystem]) -> Vec<ScheduleProblem> {
+    let mut problems = Vec::new();
+    let mut seen: Vec<&str> = Vec::new();
+
+    for system in systems {
+        let parts: Vec<&str> = system.conditions.iter().flat_map(|c| conjuncts(c)).collect();
+        if let Some(condition) = parts.iter().find(|c| parts.contains(&format!("!{}", c).as_str())) {
+            problems.push(ScheduleProblem::ContradictoryConditions {
+                schedule: schedule.to_string(),
+                system: system.name.clone(),
+                condition: condition.to_string(),
+            });
+        }
+
+        if seen.contains(&system.name.as_str()) {
+            continue;
+        }
+        seen.push(&system.name);
+        let copies: Vec<&RegisteredSystem> = systems.iter().filter(|s| s.name == system.name).collect();
+        if copies.len() > 1 {
+            problems.push(ScheduleProblem::Duplicate {
+                schedule: schedule.to_string(),
+                system: system.name.clone(),
+                count: copies.len(),
+                conditions_differ: copies.iter().any(|s| s.conditions != system.conditions),
+            });
+        }
+    }
+    problems
+}
+
+// Runs in PreStartup, so PreStartup itself (checked out while it runs) is the one schedule missed
+pub fn schedule_audit_system(schedules: Res<Schedules>) {
+    let mut clean = true;
+    for (label, schedule) in schedules.iter() {
+        for problem in audit_registrations(&format!("{:?}", label), &schedule_registrations(schedule)) {
+            error!("🗓️ Schedule check: {}", problem);
+            clean = false;
+        }
+    }
+    if clean {
+        info!("🗓️ Schedule check: no duplicate systems or contradictory run conditions");
+    }
+}
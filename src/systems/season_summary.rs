@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::clock::GameClock;
+use crate::systems::game_log::{GameLog, GameLogEntry};
+use crate::systems::game_logic::ActionOutcome;
+use crate::systems::render_layers::UiLayer;
+
+// Real seconds before the card dismisses itself; the game clock is paused meanwhile
+const SUMMARY_SECONDS: f32 = 4.0;
+
+#[derive(Component)]
+pub struct SeasonSummaryCard {
+    pub shown_at: f32,
+}
+
+fn plural(count: u32, one: &str, many: &str) -> String {
+    format!("{} {}", count, if count == 1 { one } else { many })
+}
+
+// "Summer, Year 2: 12 grapes harvested, 3 structures built" from the log entries of one season
+pub fn season_summary(year: u8, season: &GameState, entries: &[&GameLogEntry]) -> String {
+    let (mut vines_drawn, mut orders_drawn, mut planted, mut grapes, mut wine, mut orders, mut vp, mut lira, mut workers, mut built) =
+        (0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32, 0u32);
+
+    for entry in entries {
+        match entry.outcome {
+            ActionOutcome::DrewVine => vines_drawn += 1,
+            ActionOutcome::DrewOrder => orders_drawn += 1,
+            ActionOutcome::Planted { .. } => planted += 1,
+            ActionOutcome::Harvested { grapes: g } => grapes += g as u32,
+            ActionOutcome::MadeWine { .. } => wine += 1,
+            ActionOutcome::FilledOrder { victory_points, lira: l, .. } => {
+                orders += 1;
+                vp += victory_points as u32;
+                lira += l as u32;
+            }
+            ActionOutcome::GainedLira { amount } => lira += amount as u32,
+            ActionOutcome::TrainedWorker => workers += 1,
+            ActionOutcome::Built => built += 1,
+            ActionOutcome::Nothing => {}
+        }
+    }
+
+    let mut parts = Vec::new();
+    if grapes > 0 { parts.push(format!("{} harvested", plural(grapes, "grape", "grapes"))); }
+    if planted > 0 { parts.push(format!("{} planted", plural(planted, "vine", "vines"))); }
+    if wine > 0 { parts.push(format!("{} made", plural(wine, "wine", "wines"))); }
+    if orders > 0 { parts.push(format!("{} filled for {} VP", plural(orders, "order", "orders"), vp)); }
+    if built > 0 { parts.push(format!("{} built", plural(built, "structure", "structures"))); }
+    if workers > 0 { parts.push(format!("{} trained", plural(workers, "worker", "workers"))); }
+    if vines_drawn + orders_drawn > 0 { parts.push(format!("{} drawn", plural(vines_drawn + orders_drawn, "card", "cards"))); }
+    if lira > 0 { parts.push(format!("{} lira earned", lira)); }
+    if parts.is_empty() { parts.push(format!("{} with no effect", plural(entries.len() as u32, "action", "actions"))); }
+
+    format!("{:?}, Year {}: {} across all players", season, year, parts.join(", "))
+}
+
+// When a season ends, shows what happened in it and pauses until clicked or timed out
+pub fn season_summary_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    log: Res<GameLog>,
+    test_config: Option<Res<AutoTestConfig>>,
+    mut clock: ResMut<GameClock>,
+    real_time: Res<Time<Real>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    cards: Query<(Entity, &SeasonSummaryCard)>,
+    mut active_season: Local<Option<(GameState, u8)>>,
+) {
+    let now = real_time.elapsed_seconds();
+
+    if let Ok((entity, card)) = cards.get_single() {
+        if mouse.just_pressed(MouseButton::Left) || now - card.shown_at > SUMMARY_SECONDS {
+            commands.entity(entity).despawn_recursive();
+            clock.paused = false;
+        }
+    }
+
+    let state = current_state.get().clone();
+    let in_season = matches!(state, GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+
+    let ended = match active_season.as_ref() {
+        Some((season, _)) => *season != state,
+        None => false,
+    };
+
+    // Balance testing runs unattended, so no pause points there
+    if ended && !test_config.is_some_and(|t| t.enabled) {
+        if let Some((season, year)) = active_season.as_ref() {
+            let entries: Vec<_> = log.entries.iter()
+                .filter(|e| e.year == *year && e.season == *season)
+                .collect();
+
+            if !entries.is_empty() && cards.is_empty() && in_season {
+                spawn_summary_card(&mut commands, &season_summary(*year, season, &entries), now);
+                clock.paused = true;
+            }
+        }
+    }
+
+    if !in_season || ended || active_season.is_none() {
+        *active_season = if in_season { Some((state, config.current_year)) } else { None };
+    }
+}
+
+fn spawn_summary_card(commands: &mut Commands, summary: &str, shown_at: f32) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(35.0),
+                left: Val::Percent(25.0),
+                width: Val::Percent(50.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(20.0)),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.12, 0.1, 0.08, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.8, 0.65, 0.3, 1.0)).into(),
+            z_index: UiLayer::Announcement.z_index(),
+            ..default()
+        },
+        SeasonSummaryCard { shown_at },
+    )).with_children(|card| {
+        card.spawn(TextBundle::from_section(
+            summary,
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+        card.spawn(TextBundle::from_section(
+            "Click to continue",
+            TextStyle {
+                font_size: 14.0,
+                color: Color::srgb(0.7, 0.7, 0.7),
+                ..default()
+            },
+        ).with_style(Style {
+            margin: UiRect::top(Val::Px(10.0)),
+            ..default()
+        }));
+    });
+}
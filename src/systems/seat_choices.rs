@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::expansions::{replay_visitor, VisitorDeck};
+use crate::systems::hand_limit::{discard_cards, discard_requirement, DiscardCard, HandLimitDiscard};
+use crate::systems::net::{NetIncoming, NetMessage, NetSession};
+use crate::systems::prediction::RemotePlay;
+use crate::systems::rng::GameRng;
+use crate::systems::settings::UserSettings;
+use crate::systems::visitor_targeting::VisitorTarget;
+use crate::systems::wake_up::WakeUpDraft;
+
+// What a human decides outside worker placement. Each machine only asks its own seat, so
+// these travel like placements: to the host, which checks them and relays them to the rest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SeatChoice {
+    WakeUpRow(u8),
+    WakeUpRanking(Vec<u8>),
+    TempWorkerBid(u8),
+    Discard(Vec<DiscardCard>),
+    // The visitor drawn from the summer or winter pile, and what it was played on; None for
+    // an untargeted card, or a targeted one that went back on its pile
+    Visitor { summer: bool, target: Option<VisitorTarget> },
+}
+
+// Sent by a dialog when the local seat settles a choice; already applied on this machine
+#[derive(Event, Clone, Debug)]
+pub struct SeatChoiceMade {
+    pub player: PlayerId,
+    pub choice: SeatChoice,
+}
+
+// A discard the hand-limit modal would have accepted: the right count, each card once and
+// in the hand
+pub fn valid_discard(hand: &Hand, separate_limits: bool, selection: &[DiscardCard]) -> bool {
+    let in_hand = selection.iter().all(|c| match c {
+        DiscardCard::Vine(i) => *i < hand.vine_cards.len(),
+        DiscardCard::Order(i) => *i < hand.wine_order_cards.len(),
+    });
+    let unique = selection.iter().enumerate().all(|(i, c)| !selection[..i].contains(c));
+    in_hand && unique && discard_requirement(hand, separate_limits).satisfied_by(selection)
+}
+
+// Sends the local seat's choices and applies everyone else's. The host takes a peer's
+// choice only for that peer's seat and only when the rules allow it, then relays it. Clients
+// follow the host in order, holding a choice back until their own game has caught up to it.
+pub fn seat_choice_system(
+    mut session: ResMut<NetSession>,
+    remote: Option<Res<RemotePlay>>,
+    mut incoming: EventReader<NetIncoming>,
+    mut made: EventReader<SeatChoiceMade>,
+    mut draft: ResMut<WakeUpDraft>,
+    mut discards: ResMut<HandLimitDiscard>,
+    settings: Res<UserSettings>,
+    turn_order: Res<TurnOrder>,
+    current_state: Res<State<GameState>>,
+    mut rng: ResMut<GameRng>,
+    mut visitor_deck: Option<ResMut<VisitorDeck>>,
+    mut card_decks: ResMut<CardDecks>,
+    (mut hands, mut players, mut vineyards): (Query<&mut Hand>, Query<&mut Player>, Query<&mut Vineyard>),
+    // Choices from the host that didn't apply yet, oldest first
+    mut held: Local<Vec<(PlayerId, SeatChoice)>>,
+) {
+    let Some(remote) = remote else {
+        incoming.clear();
+        made.clear();
+        held.clear();
+        return;
+    };
+
+    for SeatChoiceMade { player, choice } in made.read() {
+        session.send(&NetMessage::Choice { player: player.0, choice: choice.clone() });
+    }
+
+    for event in incoming.read() {
+        let NetMessage::Choice { player, choice } = &event.message else { continue };
+        let player = PlayerId(*player);
+        let trusted = if remote.is_host {
+            remote.seat_of(event.from) == Some(player)
+        } else {
+            event.from == 0 && player != remote.local_player
+        };
+        if trusted {
+            held.push((player, choice.clone()));
+        } else {
+            warn!("🌐 Ignored choice for player {} from peer {}", player.0 + 1, event.from);
+        }
+    }
+
+    while let Some((player, choice)) = held.first().cloned() {
+        let applied = match &choice {
+            SeatChoice::WakeUpRow(row) => draft.pick(player, *row),
+            SeatChoice::WakeUpRanking(ranking) => draft.submit_ranking(player, ranking.clone()),
+            SeatChoice::TempWorkerBid(bid) => draft.submit_bid(player, *bid),
+            SeatChoice::Discard(selection) => {
+                let hand = hands.iter_mut().find(|h| h.owner == player);
+                match hand {
+                    Some(mut hand) if discards.pending.first() == Some(&player)
+                        && valid_discard(&hand, settings.house_rule_order_swap, selection) => {
+                        discard_cards(&mut hand, &mut card_decks, selection);
+                        discards.pending.remove(0);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            SeatChoice::Visitor { summer, target } => {
+                let on_turn = turn_order.players.get(turn_order.current_player) == Some(&player);
+                let in_season = matches!((current_state.get(), summer), (GameState::Summer, true) | (GameState::Winter, false));
+                match visitor_deck.as_deref_mut() {
+                    Some(deck) if on_turn && in_season => replay_visitor(
+                        player, *summer, *target, deck, &mut rng, &mut hands, &mut players, &mut vineyards, &turn_order, &mut card_decks,
+                    ),
+                    _ => false,
+                }
+            }
+        };
+
+        if applied {
+            held.remove(0);
+            if remote.is_host {
+                session.send(&NetMessage::Choice { player: player.0, choice });
+            }
+        } else if remote.is_host {
+            warn!("🌐 Ignored {:?} for player {}", choice, player.0 + 1);
+            held.remove(0);
+        } else {
+            break;
+        }
+    }
+}
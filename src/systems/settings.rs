@@ -1,16 +1,52 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use crate::systems::ai::{AIDifficulty, AISettings};
 use crate::systems::audio::*;
+use crate::systems::dashboard_sections::DashboardSections;
+use crate::systems::keymap::*;
+use crate::systems::planner::{DEFAULT_PLANNER_BUDGET_MS, PLANNER_BUDGET_STEPS_MS};
+use crate::systems::retention::{disk_usage_label, next_step, DiskUsage, ARCHIVE_LIMIT_STEPS, DEFAULT_ARCHIVE_LIMIT, DEFAULT_UNDO_DEPTH, UNDO_DEPTH_STEPS};
+use crate::systems::save::{DEFAULT_AUTOSAVE_RING, MAX_AUTOSAVE_RING};
+use crate::systems::text_scale::{TEXT_SCALE_MAX, TEXT_SCALE_MIN, TEXT_SCALE_STEP};
+use crate::systems::render_layers::UiLayer;
 
 #[derive(Serialize, Deserialize, Resource, Clone)]
+#[serde(default)]
 pub struct UserSettings {
     pub audio_enabled: bool,
     pub sfx_volume: f32,
     pub music_volume: f32,
     pub auto_save_enabled: bool,
+    pub auto_save_ring: u8, // Season autosaves kept before the oldest is overwritten
+    pub max_undo_depth: u8, // Placements that can be undone in a season
+    pub max_archived_games: u16, // Oldest past games are deleted beyond this
     pub show_tooltips: bool,
     pub performance_mode: bool,
-    pub ai_difficulty: u8, // 1 = Beginner, 2 = Intermediate
+    pub ai_difficulty: u8, // 1-based rung of AIDifficulty::LADDER, from Beginner up to the planner
+    pub planner_budget_ms: u64, // Thinking time per decision for the planner AI, one of PLANNER_BUDGET_STEPS_MS
+    pub show_pacing_hints: bool,
+    pub coach_tips: bool, // At most one board-aware tip per season for the human on turn
+    pub advisor: bool, // Outline the AI's three best spaces for the human on turn, with reasons
+    pub house_rule_bonus_chits: bool,
+    pub house_rule_order_swap: bool, // Discard an order to redraw; separate vine/order hand limits
+    pub house_rule_worker_upkeep: bool, // Workers beyond the second cost 1 lira each at year end
+    pub house_rule_simultaneous_wake_up: bool, // Everyone ranks wake-up rows in secret; the furthest behind wins ties
+    pub house_rule_wine_festival: bool, // Year 4 opens with 1 VP per wine of value 5+ in the cellar
+    pub house_rule_no_undo_after_draw: bool, // Undo can't step back past a card draw
+    pub house_rule_sparkling_aging: bool, // Sparkling can't fill an order the year it was made; cellar shows age badges
+    pub house_rule_temp_worker_bidding: bool, // Secret lira bids for the temporary worker instead of wake-up row 7
+    pub automa_mercy: bool, // Solo Automa skips its top block for a season when the human trails by more than 8 VP from year 4
+    pub show_notes_after_game: bool,
+    pub adaptive_ai: bool, // Opt-in: adjust AI difficulty between games from win rates
+    pub win_odds_in_play: bool, // Win probability is always shown when no human is playing
+    pub text_scale: f32, // Applied to every UI font size, TEXT_SCALE_MIN..=TEXT_SCALE_MAX
+    pub contextual_cursors: bool, // Off falls back to the system cursor everywhere
+    pub split_screen: bool, // Two humans on one screen each keep their own half
+    pub hotseat_handoff: bool, // Cover the board between two humans' turns until the next one confirms
+    pub pick_order_wines: bool, // Fill Order asks which cellar tokens to spend instead of the cheapest
+    pub reset_choices_each_game: bool, // Dialogs forget each player's last picks when a new game starts
+    pub dashboard_sections: DashboardSections, // Dashboard detail sections left open
 }
 
 impl Default for UserSettings {
@@ -20,9 +56,35 @@ impl Default for UserSettings {
             sfx_volume: 0.7,
             music_volume: 0.3,
             auto_save_enabled: true,
+            auto_save_ring: DEFAULT_AUTOSAVE_RING,
+            max_undo_depth: DEFAULT_UNDO_DEPTH,
+            max_archived_games: DEFAULT_ARCHIVE_LIMIT,
             show_tooltips: true,
             performance_mode: false,
             ai_difficulty: 1,
+            planner_budget_ms: DEFAULT_PLANNER_BUDGET_MS,
+            show_pacing_hints: true,
+            coach_tips: false,
+            advisor: false,
+            house_rule_bonus_chits: false,
+            house_rule_order_swap: false,
+            house_rule_worker_upkeep: false,
+            house_rule_simultaneous_wake_up: false,
+            house_rule_wine_festival: false,
+            house_rule_no_undo_after_draw: false,
+            house_rule_sparkling_aging: false,
+            house_rule_temp_worker_bidding: false,
+            automa_mercy: false,
+            show_notes_after_game: true,
+            adaptive_ai: false,
+            win_odds_in_play: false,
+            text_scale: 1.0,
+            contextual_cursors: true,
+            split_screen: false,
+            hotseat_handoff: true,
+            pick_order_wines: false,
+            reset_choices_each_game: false,
+            dashboard_sections: DashboardSections::default(),
         }
     }
 }
@@ -40,6 +102,15 @@ impl UserSettings {
             let _ = std::fs::write("viticulture_settings.json", json);
         }
     }
+
+    pub fn table_difficulty(&self) -> AIDifficulty {
+        let rung = (self.ai_difficulty.max(1) as usize - 1).min(AIDifficulty::LADDER.len() - 1);
+        AIDifficulty::LADDER[rung]
+    }
+
+    pub fn planner_budget(&self) -> Duration {
+        Duration::from_millis(self.planner_budget_ms)
+    }
 }
 
 #[derive(Component)]
@@ -58,10 +129,36 @@ pub enum SettingType {
     MusicVolumeUp,
     MusicVolumeDown,
     ToggleAutoSave,
+    CycleAutoSaveRing,
+    CycleUndoDepth,
+    CycleArchiveLimit,
     ToggleTooltips,
     TogglePerformance,
+    TogglePacingHints,
+    ToggleCoachTips,
+    ToggleAdvisor,
+    ToggleBonusChits,
+    ToggleOrderSwap,
+    ToggleWorkerUpkeep,
+    ToggleSimultaneousWakeUp,
+    ToggleWineFestival,
+    ToggleNoUndoAfterDraw,
+    ToggleSparklingAging,
+    ToggleTempWorkerBidding,
+    ToggleAutomaMercy,
+    ToggleNotesAfterGame,
     AiDifficultyUp,
     AiDifficultyDown,
+    CyclePlannerBudget,
+    ToggleAdaptiveAi,
+    ToggleWinOdds,
+    TextScaleUp,
+    TextScaleDown,
+    ToggleContextualCursors,
+    ToggleSplitScreen,
+    ToggleHotseatHandoff,
+    TogglePickOrderWines,
+    ToggleResetChoices,
     ResetSettings,
     CloseSettings,
 }
@@ -72,21 +169,22 @@ pub fn initialize_settings_system(mut commands: Commands) {
 }
 
 pub fn settings_menu_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut commands: Commands,
     settings: Res<UserSettings>,
+    usage: Res<DiskUsage>,
     existing_settings: Query<Entity, With<SettingsPanel>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if input.just_pressed(InputAction::Settings) {
         if existing_settings.is_empty() {
-            show_settings_menu(&mut commands, &settings);
+            show_settings_menu(&mut commands, &settings, &usage);
         } else {
             hide_settings_menu(&mut commands, existing_settings);
         }
     }
 }
 
-fn show_settings_menu(commands: &mut Commands, settings: &UserSettings) {
+fn show_settings_menu(commands: &mut Commands, settings: &UserSettings, usage: &DiskUsage) {
     // Background overlay
     commands.spawn((
         NodeBundle {
@@ -99,7 +197,7 @@ fn show_settings_menu(commands: &mut Commands, settings: &UserSettings) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.0, 0.0, 0.8)).into(),
-            z_index: ZIndex::Global(200),
+            z_index: UiLayer::Screen.z_index(),
             ..default()
         },
         SettingsPanel,
@@ -108,7 +206,7 @@ fn show_settings_menu(commands: &mut Commands, settings: &UserSettings) {
         parent.spawn(NodeBundle {
             style: Style {
                 width: Val::Px(400.0),
-                height: Val::Px(500.0),
+                height: Val::Px(780.0),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(20.0)),
                 ..default()
@@ -136,11 +234,49 @@ fn show_settings_menu(commands: &mut Commands, settings: &UserSettings) {
             
             // Game Section
             create_setting_row(panel, "💾 Auto-Save", &format!("{}", if settings.auto_save_enabled { "ON" } else { "OFF" }), SettingType::ToggleAutoSave);
+            create_setting_row(panel, "🗂 Autosaves Kept", &settings.auto_save_ring.to_string(), SettingType::CycleAutoSaveRing);
+            create_setting_row(panel, "↩️ Undo Steps Kept", &settings.max_undo_depth.to_string(), SettingType::CycleUndoDepth);
+            create_setting_row(panel, "🗄 Past Games Kept", &settings.max_archived_games.to_string(), SettingType::CycleArchiveLimit);
+            panel.spawn(TextBundle::from_section(
+                disk_usage_label(usage, settings),
+                TextStyle {
+                    font_size: 12.0,
+                    color: Color::from(Srgba::new(0.7, 0.7, 0.7, 1.0)),
+                    ..default()
+                },
+            ).with_style(Style {
+                margin: UiRect::bottom(Val::Px(10.0)),
+                ..default()
+            }));
             create_setting_row(panel, "💡 Tooltips", &format!("{}", if settings.show_tooltips { "ON" } else { "OFF" }), SettingType::ToggleTooltips);
             create_setting_row(panel, "⚡ Performance Mode", &format!("{}", if settings.performance_mode { "ON" } else { "OFF" }), SettingType::TogglePerformance);
+            create_setting_row(panel, "🧭 Pacing Hints", &format!("{}", if settings.show_pacing_hints { "ON" } else { "OFF" }), SettingType::TogglePacingHints);
+            create_setting_row(panel, "🎓 Coach Tips", &format!("{}", if settings.coach_tips { "ON" } else { "OFF" }), SettingType::ToggleCoachTips);
+            create_setting_row(panel, "🔮 Advisor (top 3 moves)", &format!("{}", if settings.advisor { "ON" } else { "OFF" }), SettingType::ToggleAdvisor);
+            create_setting_row(panel, "🎲 Bonus Chits (house rule)", &format!("{}", if settings.house_rule_bonus_chits { "ON" } else { "OFF" }), SettingType::ToggleBonusChits);
+            create_setting_row(panel, "🔁 Order Swap (house rule)", &format!("{}", if settings.house_rule_order_swap { "ON" } else { "OFF" }), SettingType::ToggleOrderSwap);
+            create_setting_row(panel, "👷 Worker Upkeep (variant)", &format!("{}", if settings.house_rule_worker_upkeep { "ON" } else { "OFF" }), SettingType::ToggleWorkerUpkeep);
+            create_setting_row(panel, "🌅 Simultaneous Wake-up (variant)", &format!("{}", if settings.house_rule_simultaneous_wake_up { "ON" } else { "OFF" }), SettingType::ToggleSimultaneousWakeUp);
+            create_setting_row(panel, "🍷 Wine Festival (variant)", &format!("{}", if settings.house_rule_wine_festival { "ON" } else { "OFF" }), SettingType::ToggleWineFestival);
+            create_setting_row(panel, "🙈 No Undo After Draws (house rule)", &format!("{}", if settings.house_rule_no_undo_after_draw { "ON" } else { "OFF" }), SettingType::ToggleNoUndoAfterDraw);
+            create_setting_row(panel, "🥂 Sparkling Must Age (variant)", &format!("{}", if settings.house_rule_sparkling_aging { "ON" } else { "OFF" }), SettingType::ToggleSparklingAging);
+            create_setting_row(panel, "⏳ Temporary Worker Bidding (variant)", &format!("{}", if settings.house_rule_temp_worker_bidding { "ON" } else { "OFF" }), SettingType::ToggleTempWorkerBidding);
+            create_setting_row(panel, "🕊 Automa Mercy (solo)", &format!("{}", if settings.automa_mercy { "ON" } else { "OFF" }), SettingType::ToggleAutomaMercy);
+            create_setting_row(panel, "📝 Notes After Game", &format!("{}", if settings.show_notes_after_game { "ON" } else { "OFF" }), SettingType::ToggleNotesAfterGame);
             
             // AI Section
-            create_difficulty_row(panel, "🤖 AI Difficulty", settings.ai_difficulty);
+            create_stepper_row(panel, "🤖 AI Difficulty", &format!("{:?}", settings.table_difficulty()), SettingType::AiDifficultyDown, SettingType::AiDifficultyUp);
+            create_setting_row(panel, "🧠 Planner Thinking Time", &format!("{} ms", settings.planner_budget_ms), SettingType::CyclePlannerBudget);
+            create_setting_row(panel, "🎚️ Adaptive AI (between games)", &format!("{}", if settings.adaptive_ai { "ON" } else { "OFF" }), SettingType::ToggleAdaptiveAi);
+            create_setting_row(panel, "📈 Win Odds (with humans)", &format!("{}", if settings.win_odds_in_play { "ON" } else { "OFF" }), SettingType::ToggleWinOdds);
+            
+            // Accessibility Section
+            create_volume_row(panel, "🔠 Text Size", settings.text_scale, SettingType::TextScaleDown, SettingType::TextScaleUp);
+            create_setting_row(panel, "🖱 Contextual Cursors", &format!("{}", if settings.contextual_cursors { "ON" } else { "OFF" }), SettingType::ToggleContextualCursors);
+            create_setting_row(panel, "🪟 Split Screen (two humans)", &format!("{}", if settings.split_screen { "ON" } else { "OFF" }), SettingType::ToggleSplitScreen);
+            create_setting_row(panel, "🔒 Pass-the-Device Screen", &format!("{}", if settings.hotseat_handoff { "ON" } else { "OFF" }), SettingType::ToggleHotseatHandoff);
+            create_setting_row(panel, "🍷 Pick Order Wines", &format!("{}", if settings.pick_order_wines { "ON" } else { "OFF" }), SettingType::TogglePickOrderWines);
+            create_setting_row(panel, "🧠 Forget Last Choices Each Game", &format!("{}", if settings.reset_choices_each_game { "ON" } else { "OFF" }), SettingType::ToggleResetChoices);
             
             // Action Buttons
             panel.spawn(NodeBundle {
@@ -216,6 +352,11 @@ fn create_setting_row(parent: &mut ChildBuilder, label: &str, value: &str, toggl
 }
 
 fn create_volume_row(parent: &mut ChildBuilder, label: &str, volume: f32, down_type: SettingType, up_type: SettingType) {
+    create_stepper_row(parent, label, &format!("{:.0}%", volume * 100.0), down_type, up_type);
+}
+
+// A label with - and + buttons either side of the current value
+fn create_stepper_row(parent: &mut ChildBuilder, label: &str, value: &str, down_type: SettingType, up_type: SettingType) {
     parent.spawn(NodeBundle {
         style: Style {
             flex_direction: FlexDirection::Row,
@@ -266,9 +407,8 @@ fn create_volume_row(parent: &mut ChildBuilder, label: &str, volume: f32, down_t
                 }));
             });
             
-            // Volume display
             controls.spawn(TextBundle::from_section(
-                &format!("{:.0}%", volume * 100.0),
+                value,
                 TextStyle {
                     font_size: 14.0,
                     color: Color::WHITE,
@@ -305,16 +445,6 @@ fn create_volume_row(parent: &mut ChildBuilder, label: &str, volume: f32, down_t
     });
 }
 
-fn create_difficulty_row(parent: &mut ChildBuilder, label: &str, difficulty: u8) {
-    let difficulty_text = match difficulty {
-        1 => "Beginner",
-        2 => "Intermediate",
-        _ => "Unknown",
-    };
-    
-    create_volume_row(parent, label, difficulty as f32, SettingType::AiDifficultyDown, SettingType::AiDifficultyUp);
-}
-
 fn create_action_button(parent: &mut ChildBuilder, text: &str, setting_type: SettingType, color: Color) {
     parent.spawn((
         ButtonBundle {
@@ -353,6 +483,8 @@ pub fn handle_settings_interaction_system(
     mut commands: Commands,
     existing_settings: Query<Entity, With<SettingsPanel>>,
     mut audio_settings: ResMut<AudioSettings>,
+    usage: Res<DiskUsage>,
+    mut ai_settings: ResMut<AISettings>,
 ) {
     let mut should_refresh = false;
     let mut should_close = false;
@@ -384,23 +516,106 @@ pub fn handle_settings_interaction_system(
                     SettingType::ToggleAutoSave => {
                         settings.auto_save_enabled = !settings.auto_save_enabled;
                     }
+                    SettingType::CycleAutoSaveRing => {
+                        settings.auto_save_ring = settings.auto_save_ring % MAX_AUTOSAVE_RING + 1;
+                    }
+                    SettingType::CycleUndoDepth => {
+                        settings.max_undo_depth = next_step(&UNDO_DEPTH_STEPS, settings.max_undo_depth);
+                    }
+                    SettingType::CycleArchiveLimit => {
+                        settings.max_archived_games = next_step(&ARCHIVE_LIMIT_STEPS, settings.max_archived_games);
+                    }
                     SettingType::ToggleTooltips => {
                         settings.show_tooltips = !settings.show_tooltips;
                     }
                     SettingType::TogglePerformance => {
                         settings.performance_mode = !settings.performance_mode;
                     }
+                    SettingType::TogglePacingHints => {
+                        settings.show_pacing_hints = !settings.show_pacing_hints;
+                    }
+                    SettingType::ToggleCoachTips => {
+                        settings.coach_tips = !settings.coach_tips;
+                    }
+                    SettingType::ToggleAdvisor => {
+                        settings.advisor = !settings.advisor;
+                    }
+                    SettingType::ToggleBonusChits => {
+                        settings.house_rule_bonus_chits = !settings.house_rule_bonus_chits;
+                    }
+                    SettingType::ToggleOrderSwap => {
+                        settings.house_rule_order_swap = !settings.house_rule_order_swap;
+                    }
+                    SettingType::ToggleWorkerUpkeep => {
+                        settings.house_rule_worker_upkeep = !settings.house_rule_worker_upkeep;
+                    }
+                    SettingType::ToggleSimultaneousWakeUp => {
+                        settings.house_rule_simultaneous_wake_up = !settings.house_rule_simultaneous_wake_up;
+                    }
+                    SettingType::ToggleWineFestival => {
+                        settings.house_rule_wine_festival = !settings.house_rule_wine_festival;
+                    }
+                    SettingType::ToggleNoUndoAfterDraw => {
+                        settings.house_rule_no_undo_after_draw = !settings.house_rule_no_undo_after_draw;
+                    }
+                    SettingType::ToggleSparklingAging => {
+                        settings.house_rule_sparkling_aging = !settings.house_rule_sparkling_aging;
+                    }
+                    SettingType::ToggleTempWorkerBidding => {
+                        settings.house_rule_temp_worker_bidding = !settings.house_rule_temp_worker_bidding;
+                    }
+                    SettingType::ToggleAutomaMercy => {
+                        settings.automa_mercy = !settings.automa_mercy;
+                    }
+                    SettingType::ToggleNotesAfterGame => {
+                        settings.show_notes_after_game = !settings.show_notes_after_game;
+                    }
                     SettingType::AiDifficultyUp => {
-                        settings.ai_difficulty = (settings.ai_difficulty + 1).min(2);
+                        settings.ai_difficulty = (settings.ai_difficulty + 1).min(AIDifficulty::LADDER.len() as u8);
+                        ai_settings.ai_difficulty = settings.table_difficulty();
                     }
                     SettingType::AiDifficultyDown => {
-                        settings.ai_difficulty = (settings.ai_difficulty - 1).max(1);
+                        settings.ai_difficulty = settings.ai_difficulty.saturating_sub(1).max(1);
+                        ai_settings.ai_difficulty = settings.table_difficulty();
+                    }
+                    SettingType::CyclePlannerBudget => {
+                        settings.planner_budget_ms = next_step(&PLANNER_BUDGET_STEPS_MS, settings.planner_budget_ms);
+                        ai_settings.planner_budget = settings.planner_budget();
+                    }
+                    SettingType::ToggleAdaptiveAi => {
+                        settings.adaptive_ai = !settings.adaptive_ai;
+                    }
+                    SettingType::ToggleWinOdds => {
+                        settings.win_odds_in_play = !settings.win_odds_in_play;
+                    }
+                    SettingType::TextScaleUp => {
+                        settings.text_scale = (settings.text_scale + TEXT_SCALE_STEP).min(TEXT_SCALE_MAX);
+                    }
+                    SettingType::TextScaleDown => {
+                        settings.text_scale = (settings.text_scale - TEXT_SCALE_STEP).max(TEXT_SCALE_MIN);
+                    }
+                    SettingType::ToggleContextualCursors => {
+                        settings.contextual_cursors = !settings.contextual_cursors;
+                    }
+                    SettingType::ToggleSplitScreen => {
+                        settings.split_screen = !settings.split_screen;
+                    }
+                    SettingType::ToggleHotseatHandoff => {
+                        settings.hotseat_handoff = !settings.hotseat_handoff;
+                    }
+                    SettingType::TogglePickOrderWines => {
+                        settings.pick_order_wines = !settings.pick_order_wines;
+                    }
+                    SettingType::ToggleResetChoices => {
+                        settings.reset_choices_each_game = !settings.reset_choices_each_game;
                     }
                     SettingType::ResetSettings => {
                         *settings = UserSettings::default();
                         audio_settings.enabled = settings.audio_enabled;
                         audio_settings.sfx_volume = settings.sfx_volume;
                         audio_settings.music_volume = settings.music_volume;
+                        ai_settings.ai_difficulty = settings.table_difficulty();
+                        ai_settings.planner_budget = settings.planner_budget();
                     }
                     SettingType::CloseSettings => {
                         should_close = true;
@@ -429,6 +644,6 @@ pub fn handle_settings_interaction_system(
         hide_settings_menu(&mut commands, existing_settings);
     } else if should_refresh {
         hide_settings_menu(&mut commands, existing_settings);
-        show_settings_menu(&mut commands, &settings);
+        show_settings_menu(&mut commands, &settings, &usage);
     }
 }
\ No newline at end of file
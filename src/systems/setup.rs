@@ -1,8 +1,12 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::wake_up::WakeUpDraft;
+use crate::systems::rng::GameRng;
+use crate::systems::lobby::LobbySeating;
 
 pub fn setup_camera(mut commands: Commands) {
-    commands.spawn(Camera2dBundle::default());
+    commands.spawn((Camera2dBundle::default(), crate::systems::viewport::MainCamera));
 }
 
 pub fn load_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -15,12 +19,50 @@ pub fn load_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(assets);
 }
 
+// Where setup lays out a player's nth regular worker, in a column under their seat
+pub fn regular_worker_position(owner: PlayerId, index: usize) -> Vec2 {
+    Vec2::new(-500.0 + (owner.0 as f32 * 120.0), -200.0 + (index as f32 * 30.0))
+}
+
+pub fn spawn_regular_worker(commands: &mut Commands, owner: PlayerId, index: usize) {
+    commands.spawn((
+        Worker::new(owner, false, regular_worker_position(owner, index)),
+        Clickable { size: Vec2::new(20.0, 20.0) },
+    ));
+}
+
+// Spawns or despawns regular workers until `owner` has `count` on the board, laid out as at
+// setup. Idle workers go first; pass the workers without the temporary one.
+pub fn match_regular_workers<'a>(
+    commands: &mut Commands,
+    owner: PlayerId,
+    count: u8,
+    workers: impl IntoIterator<Item = (Entity, &'a Worker)>,
+) {
+    let mut regulars: Vec<(Entity, &Worker)> = workers.into_iter()
+        .filter(|(_, w)| w.owner == owner && !w.is_grande)
+        .collect();
+    regulars.sort_by_key(|(_, w)| w.placed_at.is_some());
+
+    for index in regulars.len()..count as usize {
+        spawn_regular_worker(commands, owner, index);
+    }
+    for (entity, _) in regulars.iter().take(regulars.len().saturating_sub(count as usize)) {
+        commands.entity(*entity).despawn();
+    }
+}
+
 pub fn setup_game_system(
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
     config: Res<GameConfig>,
+    preset: Res<RulesPreset>,
     mut turn_order: ResMut<TurnOrder>,
     mut card_decks: ResMut<CardDecks>,
+    mut draft: ResMut<WakeUpDraft>,
+    mut rng: ResMut<GameRng>,
+    seating: Option<Res<LobbySeating>>,
+    game_settings: Res<GameSettings>,
     text_query: Query<Entity, With<Text>>,
     existing_entities: Query<Entity, (With<PlayerId>, Without<Camera>)>,
 ) {
@@ -33,19 +75,23 @@ pub fn setup_game_system(
     }
     
     turn_order.players.clear();
+    *draft = WakeUpDraft::default();
     
     // Prepare Mama & Papa cards
     let mut mama_cards = card_decks.mama_cards.clone();
     let mut papa_cards = card_decks.papa_cards.clone();
     use rand::seq::SliceRandom;
-    let mut rng = rand::rng();
-    mama_cards.shuffle(&mut rng);
-    papa_cards.shuffle(&mut rng);
+    mama_cards.shuffle(&mut *rng);
+    papa_cards.shuffle(&mut *rng);
     
     // Create players with Mama & Papa cards
     for i in 0..config.player_count {
         let is_ai = i >= (config.player_count - config.ai_count);
-        let name = if is_ai {
+        // Online seats carry the name picked in the lobby
+        let lobby_name = seating.as_ref().and_then(|s| s.name_at(i)).filter(|_| !is_ai);
+        let name = if let Some(name) = lobby_name {
+            name.to_string()
+        } else if is_ai {
             format!("AI Player {}", i + 1)
         } else {
             format!("Player {}", i + 1)
@@ -80,6 +126,20 @@ pub fn setup_game_system(
             }
         }
         
+        // Seat handicap from the setup screen
+        if game_settings.seat(i).handicap {
+            if is_ai {
+                player.lira += AI_HANDICAP_LIRA;
+                vineyard.lira += AI_HANDICAP_LIRA;
+            } else {
+                for _ in 0..HUMAN_HANDICAP_VINES {
+                    if let Some(vine_card) = card_decks.draw_vine_card() {
+                        hand.vine_cards.push(vine_card);
+                    }
+                }
+            }
+        }
+        
         let mama_card_clone = mama_card.clone();
         let papa_card_clone = papa_card.clone();
 
@@ -97,31 +157,20 @@ pub fn setup_game_system(
             });
         }
         
-        // Create workers (exactly 2 regular workers per player)
-        for w in 0..2 {
-            let worker_pos = Vec2::new(-500.0 + (i as f32 * 120.0), -200.0 + (w as f32 * 30.0));
-            commands.spawn((
-                Worker::new(PlayerId(i), false, worker_pos),
-                Clickable { size: Vec2::new(20.0, 20.0) },
-            ));
+        // Create workers (exactly 2 regular workers per player), then the Mama card's bonus ones
+        for w in 0..2 + mama_card_clone.bonus_workers as usize {
+            spawn_regular_worker(&mut commands, PlayerId(i), w);
         }
         
-        // Create bonus workers from Mama card
-        for w in 0..mama_card_clone.bonus_workers {
-            let worker_pos = Vec2::new(-500.0 + (i as f32 * 120.0), -140.0 + (w as f32 * 30.0));
+        // Create exactly 1 grande worker per player (First Game plays without them)
+        if preset.grande_workers() {
+            let grande_pos = Vec2::new(-500.0 + (i as f32 * 120.0), -170.0);
             commands.spawn((
-                Worker::new(PlayerId(i), false, worker_pos),
-                Clickable { size: Vec2::new(20.0, 20.0) },
+                Worker::new(PlayerId(i), true, grande_pos),
+                Clickable { size: Vec2::new(25.0, 25.0) },
             ));
         }
         
-        // Create exactly 1 grande worker per player
-        let grande_pos = Vec2::new(-500.0 + (i as f32 * 120.0), -170.0);
-        commands.spawn((
-            Worker::new(PlayerId(i), true, grande_pos),
-            Clickable { size: Vec2::new(25.0, 25.0) },
-        ));
-        
         turn_order.players.push(PlayerId(i));
     }
     
@@ -135,16 +184,20 @@ pub fn setup_game_system(
     }
     commands.spawn(action_board);
     
-    info!("Enhanced game setup complete: {} players ({} AI) with Mama & Papa cards", 
-          config.player_count, config.ai_count);
+    info!("Enhanced game setup complete: {} players ({} AI) with Mama & Papa cards, {}", 
+          config.player_count, config.ai_count, preset.label());
     next_state.set(GameState::Spring);
 }
 
 // Update setup to include residual payment trackers
 pub fn setup_residual_payment_system(
     mut commands: Commands,
+    preset: Res<RulesPreset>,
     players: Query<&Player>,
 ) {
+    if !preset.residuals() {
+        return;
+    }
     for player in players.iter() {
         commands.spawn(ResidualPaymentTracker::new(player.id));
     }
@@ -0,0 +1,291 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::choice_memory::ChoiceMemory;
+use crate::systems::game_logic::{auto_wine_recipe, can_make_recipe};
+use crate::systems::input::ActionRequested;
+use crate::systems::keymap::{BoundInput, InputAction};
+use crate::systems::legal_actions::{legal_action, RulesState};
+use crate::systems::order_tokens::{wants_token_pick, OrderTokenPicker};
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Expert cellar shortcuts for the human player on turn (default keys, rebindable in the keymap):
+//   H - harvest, M - make-wine dialog (M again confirms), O - select next order, F - fill it
+// They place workers through ActionRequested, exactly like the action bar.
+const RECIPES: [WineType; 4] = [WineType::Red, WineType::White, WineType::Blush, WineType::Sparkling];
+
+#[derive(Resource, Default)]
+pub struct ExpertShortcuts {
+    pub selected_order: usize,
+    pub wine_dialog: Option<PlayerId>,
+}
+
+#[derive(Component)]
+pub struct MakeWineDialog;
+
+#[derive(Component)]
+pub struct RecipeButton(pub WineType);
+
+#[derive(Component)]
+pub struct SelectedOrderText;
+
+// With every hint switched off the table plays "strict", so the shortcuts stay out of the way
+fn shortcuts_enabled(settings: &UserSettings) -> bool {
+    settings.show_tooltips || settings.show_pacing_hints
+}
+
+fn placement(
+    action: ActionSpace,
+    player: PlayerId,
+    state: &GameState,
+    player_count: u8,
+    spaces: &Query<&ActionSpaceSlot>,
+    workers: &Query<&Worker>,
+) -> Option<ActionRequested> {
+    let board = RulesState::new(spaces.iter(), state, player_count).with_workers(workers.iter());
+    let grande = legal_action(&board, player, action)?.uses_grande(false)?;
+    Some(ActionRequested { player, action, grande })
+}
+
+fn key_label(input: &BoundInput, action: InputAction) -> String {
+    input.keymap.binding(action).map(|b| b.key_label()).unwrap_or_default()
+}
+
+fn recipe_label(recipe: WineType) -> &'static str {
+    match recipe {
+        WineType::Red => "Red (1 red grape)",
+        WineType::White => "White (1 white grape)",
+        WineType::Blush => "Blush (1 red + 1 white, medium cellar)",
+        WineType::Sparkling => "Sparkling (2 red + 1 white, large cellar)",
+    }
+}
+
+pub fn expert_shortcuts_system(
+    mut commands: Commands,
+    input: BoundInput,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    turn_order: Res<TurnOrder>,
+    mut shortcuts: ResMut<ExpertShortcuts>,
+    mut place_events: EventWriter<ActionRequested>,
+    (players, spaces, workers, structures): (Query<&Player>, Query<&ActionSpaceSlot>, Query<&Worker>, Query<&Structure>),
+    mut hands: Query<&mut Hand>,
+    mut vineyards: Query<&mut Vineyard>,
+    recipe_buttons: Query<(&Interaction, &RecipeButton), Changed<Interaction>>,
+    dialogs: Query<Entity, With<MakeWineDialog>>,
+    config: Res<GameConfig>,
+    mut token_picker: ResMut<OrderTokenPicker>,
+    mut memory: ResMut<ChoiceMemory>,
+) {
+    let state = current_state.get();
+    let player_id = turn_order.players.get(turn_order.current_player).copied();
+    let human_turn = player_id.is_some_and(|id| players.iter().any(|p| p.id == id && !p.is_ai));
+
+    let close_dialog = |commands: &mut Commands| {
+        for entity in dialogs.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    };
+
+    if !shortcuts_enabled(&settings) || !human_turn || !matches!(state, GameState::Summer | GameState::Winter) {
+        if shortcuts.wine_dialog.is_some() {
+            shortcuts.wine_dialog = None;
+            close_dialog(&mut commands);
+        }
+        return;
+    }
+    let Some(player_id) = player_id else { return };
+    let name = players.iter().find(|p| p.id == player_id).map(|p| p.name.clone()).unwrap_or_default();
+    let owned: Vec<Structure> = structures.iter().filter(|s| s.owner == player_id).cloned().collect();
+
+    // An open dialog belongs to the player who opened it
+    if shortcuts.wine_dialog.is_some_and(|owner| owner != player_id) {
+        shortcuts.wine_dialog = None;
+        close_dialog(&mut commands);
+    }
+
+    if shortcuts.wine_dialog.is_some() {
+        let Some(mut vineyard) = vineyards.iter_mut().find(|v| v.owner == player_id) else { return };
+        let preselected = memory.get(&name).recipe
+            .filter(|recipe| can_make_recipe(&vineyard, *recipe, &owned))
+            .or_else(|| auto_wine_recipe(&vineyard, &owned));
+
+        let clicked = recipe_buttons.iter()
+            .find(|(interaction, _)| **interaction == Interaction::Pressed)
+            .map(|(_, button)| button.0);
+        let chosen = clicked.or(preselected.filter(|_| input.just_pressed(InputAction::MakeWine)));
+
+        if let Some(recipe) = chosen {
+            if let Some(event) = placement(ActionSpace::MakeWine, player_id, state, config.player_count, &spaces, &workers) {
+                vineyard.wine_recipe = Some(recipe);
+                memory.remember(&name, |choices| choices.recipe = Some(recipe));
+                place_events.send(event);
+            }
+            shortcuts.wine_dialog = None;
+            close_dialog(&mut commands);
+        }
+        return;
+    }
+
+    if input.just_pressed(InputAction::Harvest) {
+        let has_vines = vineyards.iter()
+            .any(|v| v.owner == player_id && v.fields.iter().any(|f| f.vine.is_some()));
+        match placement(ActionSpace::Harvest, player_id, state, config.player_count, &spaces, &workers) {
+            Some(event) if has_vines => { place_events.send(event); }
+            _ => info!("⌨️ Harvest not available"),
+        }
+    }
+
+    if input.just_pressed(InputAction::NextOrder) {
+        if let Some(hand) = hands.iter().find(|h| h.owner == player_id) {
+            if !hand.wine_order_cards.is_empty() {
+                shortcuts.selected_order = (shortcuts.selected_order + 1) % hand.wine_order_cards.len();
+            }
+        }
+    }
+
+    if input.just_pressed(InputAction::FillOrder) {
+        let vineyard = vineyards.iter().find(|v| v.owner == player_id);
+        let hand = hands.iter_mut().find(|h| h.owner == player_id);
+        let event = placement(ActionSpace::FillOrder, player_id, state, config.player_count, &spaces, &workers);
+
+        match (vineyard, hand, event) {
+            (Some(vineyard), Some(mut hand), Some(event))
+                if hand.wine_order_cards.get(shortcuts.selected_order).is_some_and(|o| vineyard.can_fulfill_order(o)) =>
+            {
+                // Fill Order resolves the first order in hand
+                let order = hand.wine_order_cards.remove(shortcuts.selected_order);
+                hand.wine_order_cards.insert(0, order);
+                shortcuts.selected_order = 0;
+                if wants_token_pick(&settings, ActionSpace::FillOrder, &hand, vineyard) {
+                    token_picker.open(event, &hand.wine_order_cards[0], vineyard);
+                } else {
+                    place_events.send(event);
+                }
+            }
+            _ => info!("⌨️ Selected order can't be filled"),
+        }
+    }
+
+    if input.just_pressed(InputAction::MakeWine) {
+        let Some(vineyard) = vineyards.iter().find(|v| v.owner == player_id) else { return };
+        if placement(ActionSpace::MakeWine, player_id, state, config.player_count, &spaces, &workers).is_none() {
+            info!("⌨️ Make Wine not available");
+            return;
+        }
+        let preselected = memory.get(&name).recipe
+            .filter(|recipe| can_make_recipe(vineyard, *recipe, &owned))
+            .or_else(|| auto_wine_recipe(vineyard, &owned));
+        shortcuts.wine_dialog = Some(player_id);
+        spawn_make_wine_dialog(&mut commands, vineyard, &owned, preselected, &key_label(&input, InputAction::MakeWine));
+    }
+}
+
+// Shows which order F will fill while the current player holds any
+pub fn selected_order_indicator_system(
+    mut commands: Commands,
+    input: BoundInput,
+    settings: Res<UserSettings>,
+    turn_order: Res<TurnOrder>,
+    shortcuts: Res<ExpertShortcuts>,
+    hands: Query<&Hand>,
+    existing: Query<Entity, With<SelectedOrderText>>,
+    mut last_shown: Local<Option<String>>,
+) {
+    let player_id = turn_order.players.get(turn_order.current_player).copied();
+    let order = player_id
+        .and_then(|id| hands.iter().find(|h| h.owner == id))
+        .and_then(|hand| hand.wine_order_cards.get(shortcuts.selected_order));
+
+    let label = order.filter(|_| shortcuts_enabled(&settings)).map(|order| format!(
+        "Selected order ({}/{}): {}R {}W → {} VP",
+        key_label(&input, InputAction::NextOrder), key_label(&input, InputAction::FillOrder),
+        order.red_wine_needed, order.white_wine_needed, order.victory_points,
+    ));
+    if *last_shown == label {
+        return;
+    }
+    *last_shown = label.clone();
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+    let Some(label) = label else { return };
+
+    commands.spawn((
+        TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::srgb(0.85, 0.7, 1.0),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(90.0),
+            right: Val::Px(20.0),
+            ..default()
+        }),
+        SelectedOrderText,
+    ));
+}
+
+fn spawn_make_wine_dialog(commands: &mut Commands, vineyard: &Vineyard, structures: &[Structure], preselected: Option<WineType>, confirm_key: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(220.0),
+                left: Val::Percent(38.0),
+                width: Val::Px(280.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.05, 0.1, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.7, 0.2, 0.3, 1.0)).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        MakeWineDialog,
+    )).with_children(|dialog| {
+        dialog.spawn(TextBundle::from_section(
+            format!("🍷 Make wine (red grapes {:?}, white {:?})\n{} confirms the highlighted recipe", vineyard.red_grapes.values(), vineyard.white_grapes.values(), confirm_key),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        for recipe in RECIPES.into_iter().filter(|r| can_make_recipe(vineyard, *r, structures)) {
+            let color = if Some(recipe) == preselected {
+                Srgba::new(0.6, 0.25, 0.35, 1.0)
+            } else {
+                Srgba::new(0.3, 0.15, 0.2, 1.0)
+            };
+            dialog.spawn((
+                ButtonBundle {
+                    style: Style {
+                        margin: UiRect::top(Val::Px(6.0)),
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    background_color: Color::from(color).into(),
+                    ..default()
+                },
+                RecipeButton(recipe),
+            )).with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    recipe_label(recipe),
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ));
+            });
+        }
+    });
+}
@@ -0,0 +1,454 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use crate::components::*;
+use crate::systems::ai::{action_bonus_value, evaluate_action, AiRules};
+use crate::systems::bonus_chits::CHITS_PER_YEAR;
+use crate::systems::festival::{festival_points, FESTIVAL_YEAR};
+use crate::systems::game_logic::{resolve_action, ActionOutcome};
+use crate::systems::legal_actions::{legal_actions, LegalAction, RulesState};
+use crate::systems::upkeep::pay_worker_upkeep;
+use crate::systems::wake_up::WakeUpChart;
+
+// Headless copy of the game state that runs the same rule functions as the
+// live ECS systems, driven by a seeded RNG so runs are reproducible
+#[derive(Clone)]
+pub struct SimGame {
+    pub players: Vec<Player>,
+    pub vineyards: Vec<Vineyard>,
+    pub hands: Vec<Hand>,
+    pub trackers: Vec<ResidualPaymentTracker>,
+    pub structures: Vec<Structure>,
+    pub decks: CardDecks,
+    pub spaces: Vec<ActionSpaceSlot>,
+    pub workers_left: Vec<u8>,
+    pub phase: GameState,
+    pub current_player: usize,
+    pub current_year: u8,
+    pub max_years: u8,
+    pub target_victory_points: u8,
+    pub wake_up_chart: WakeUpChart,
+    // Variants in play; worker upkeep is paid as each year ends, the festival and the bonus
+    // chits in its spring
+    pub rules: AiRules,
+}
+
+// What a single simulation step did, named after the live system it mirrors
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SimStep {
+    pub system: &'static str,
+    pub player: Option<PlayerId>,
+    pub action: Option<ActionSpace>,
+    pub outcome: Option<ActionOutcome>,
+}
+
+impl SimStep {
+    fn phase(system: &'static str) -> Self {
+        Self { system, player: None, action: None, outcome: None }
+    }
+}
+
+impl SimGame {
+    // Fresh game mirroring setup_game_system, with Mama/Papa cards dealt from the seeded RNG
+    pub fn new(config: &GameConfig, rng: &mut StdRng) -> Self {
+        let mut decks = CardDecks::new();
+        let mut mama_cards = decks.mama_cards.clone();
+        let mut papa_cards = decks.papa_cards.clone();
+        mama_cards.shuffle(rng);
+        papa_cards.shuffle(rng);
+
+        let mut players = Vec::new();
+        let mut vineyards = Vec::new();
+        let mut hands = Vec::new();
+        let mut trackers = Vec::new();
+        let mut structures = Vec::new();
+
+        for i in 0..config.player_count {
+            let is_ai = i >= (config.player_count - config.ai_count);
+            let mama_card = mama_cards[i as usize % mama_cards.len()].clone();
+            let papa_card = papa_cards[i as usize % papa_cards.len()].clone();
+
+            let mut player = Player::new(i, format!("Sim Player {}", i + 1), is_ai);
+            player.lira += mama_card.bonus_lira;
+            player.workers += mama_card.bonus_workers;
+            player.victory_points += papa_card.bonus_vp;
+
+            let mut vineyard = Vineyard::new(PlayerId(i));
+            vineyard.lira += mama_card.bonus_lira;
+            if papa_card.bonus_fields > 0 {
+                vineyard.fields[8] = VineyardField::new(FieldType::Premium);
+            }
+
+            let mut hand = Hand::new(PlayerId(i));
+            for _ in 0..mama_card.bonus_vine_cards {
+                if let Some(vine_card) = decks.draw_vine_card() {
+                    hand.vine_cards.push(vine_card);
+                }
+            }
+
+            for structure_type in papa_card.starting_structures {
+                structures.push(Structure { structure_type, owner: PlayerId(i) });
+            }
+
+            players.push(player);
+            vineyards.push(vineyard);
+            hands.push(hand);
+            trackers.push(ResidualPaymentTracker::new(PlayerId(i)));
+        }
+
+        Self::from_parts(players, vineyards, hands, trackers, structures, decks, config, GameState::Spring, 0)
+    }
+
+    // Snapshot of the live game; per-player vectors are sorted by player id
+    pub fn from_parts(
+        mut players: Vec<Player>,
+        mut vineyards: Vec<Vineyard>,
+        mut hands: Vec<Hand>,
+        mut trackers: Vec<ResidualPaymentTracker>,
+        structures: Vec<Structure>,
+        decks: CardDecks,
+        config: &GameConfig,
+        phase: GameState,
+        current_player: usize,
+    ) -> Self {
+        players.sort_by_key(|p| p.id.0);
+        vineyards.sort_by_key(|v| v.owner.0);
+        hands.sort_by_key(|h| h.owner.0);
+        trackers.sort_by_key(|t| t.owner.0);
+
+        let workers_left = players.iter().map(|p| p.total_workers()).collect();
+
+        Self {
+            players,
+            vineyards,
+            hands,
+            trackers,
+            structures,
+            decks,
+            spaces: ActionBoard::new().spaces,
+            workers_left,
+            phase,
+            current_player,
+            current_year: config.current_year,
+            max_years: config.max_years,
+            target_victory_points: config.target_victory_points,
+            wake_up_chart: WakeUpChart::base(),
+            rules: AiRules {
+                wine_festival: false,
+                worker_upkeep: false,
+                sparkling_aging: false,
+                bonus_chits: false,
+                max_years: config.max_years,
+                target_victory_points: config.target_victory_points,
+            },
+        }
+    }
+
+    pub fn with_wake_up_chart(mut self, chart: &WakeUpChart) -> Self {
+        self.wake_up_chart = chart.clone();
+        self
+    }
+
+    // The live TurnOrder counts in wake-up order; the simulator indexes players by id
+    pub fn with_player_on_turn(mut self, player: Option<PlayerId>) -> Self {
+        if let Some(idx) = player.and_then(|id| self.players.iter().position(|p| p.id == id)) {
+            self.current_player = idx;
+        }
+        self
+    }
+
+    // Mid-season snapshot: occupied spaces and idle workers, by player id, as on the live board
+    pub fn with_board(mut self, spaces: Vec<ActionSpaceSlot>, workers_left: Vec<u8>) -> Self {
+        self.spaces = spaces;
+        self.workers_left = workers_left;
+        self
+    }
+
+    pub fn with_worker_upkeep(mut self, enabled: bool) -> Self {
+        self.rules.worker_upkeep = enabled;
+        self
+    }
+
+    // Plays under the given variants and game length; vineyards take the sparkling aging rule
+    pub fn with_rules(mut self, rules: AiRules) -> Self {
+        self.max_years = rules.max_years;
+        self.target_victory_points = rules.target_victory_points;
+        for vineyard in self.vineyards.iter_mut() {
+            vineyard.sparkling_aging = rules.sparkling_aging;
+        }
+        self.rules = rules;
+        self
+    }
+
+    // Mirrors apply_wake_up_bonus; wake-up order follows player order as in spring_system
+    fn apply_wake_up_bonus(&mut self, player_idx: usize, bonus: WakeUpBonus) {
+        match bonus {
+            WakeUpBonus::DrawVineCard => {
+                if let Some(card) = self.decks.draw_vine_card() {
+                    self.hands[player_idx].vine_cards.push(card);
+                }
+            }
+            WakeUpBonus::GainLira(amount) => self.players[player_idx].gain_lira(amount),
+            WakeUpBonus::GainVictoryPoint => self.players[player_idx].gain_victory_points(1),
+            WakeUpBonus::DrawWineOrderCard => {
+                if let Some(card) = self.decks.draw_wine_order_card() {
+                    self.hands[player_idx].wine_order_cards.push(card);
+                }
+            }
+            WakeUpBonus::PlayExtraWorker => {}
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        matches!(self.phase, GameState::GameOver)
+    }
+
+    // The rules layer's view of this board for one player; the simulator has no grande
+    pub fn rules_state(&self, player_idx: usize) -> RulesState<'_> {
+        let workers_left = self.workers_left.get(player_idx).copied().unwrap_or(0);
+        RulesState::new(self.spaces.iter(), &self.phase, self.player_count())
+            .with_idle_regulars(self.players[player_idx].id, workers_left)
+            .with_cards(self.hands.iter(), self.vineyards.iter(), self.structures.iter())
+    }
+
+    pub fn legal_moves(&self, player_idx: usize) -> Vec<LegalAction> {
+        legal_actions(&self.rules_state(player_idx), self.players[player_idx].id)
+    }
+
+    pub fn legal_actions(&self, player_idx: usize) -> Vec<ActionSpace> {
+        self.legal_moves(player_idx).into_iter().map(|legal| legal.action).collect()
+    }
+
+    pub fn player_count(&self) -> u8 {
+        self.players.len() as u8
+    }
+
+    // Actions whose bonus space the player's next worker would take
+    pub fn bonus_actions(&self, player_idx: usize) -> Vec<ActionSpace> {
+        self.legal_moves(player_idx).into_iter().filter(|legal| legal.bonus).map(|legal| legal.action).collect()
+    }
+
+    // Chits still waiting on open spaces; only the first worker on a space collects its chit
+    pub fn bonus_chits(&self) -> Vec<(ActionSpace, BonusChit)> {
+        let player_count = self.player_count();
+        self.spaces.iter()
+            .filter(|s| s.is_open(player_count) && s.occupied_by.is_none())
+            .filter_map(|s| s.bonus_chit.map(|chit| (s.action, chit)))
+            .collect()
+    }
+
+    // Mirrors AiDecisionInput::choose_smart, but picks among the top three with the seeded RNG
+    fn choose_action(&self, player_idx: usize, rng: &mut StdRng) -> Option<ActionSpace> {
+        let legal = self.legal_actions(player_idx);
+        let bonus_actions = self.bonus_actions(player_idx);
+        let bonus_chits = self.bonus_chits();
+        let mut scored: Vec<_> = legal.into_iter()
+            .map(|action| {
+                let mut score = evaluate_action(
+                    action,
+                    &self.players[player_idx],
+                    &self.hands[player_idx],
+                    &self.vineyards[player_idx],
+                    &self.phase,
+                    self.current_year,
+                ) + self.rules.action_adjustment(
+                    action,
+                    &self.players[player_idx],
+                    &self.hands[player_idx],
+                    &self.vineyards[player_idx],
+                    self.current_year,
+                );
+                if bonus_actions.contains(&action) {
+                    score += action_bonus_value(action, &self.hands[player_idx], &self.vineyards[player_idx]);
+                }
+                score += bonus_chits.iter().filter(|(a, _)| *a == action).map(|(_, chit)| chit.ai_value()).sum::<f32>();
+                (action, score)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        let top: Vec<_> = scored.iter().take(3).map(|(action, _)| *action).collect();
+        top.choose(rng).copied()
+    }
+
+    pub fn apply_action(&mut self, player_idx: usize, action: ActionSpace) -> ActionOutcome {
+        let player_count = self.player_count();
+        let mut bonus = false;
+        let mut chit = None;
+        if let Some(space) = self.spaces.iter_mut()
+            .find(|s| s.action == action && s.is_open(player_count) && s.occupied_by.is_none()) {
+            bonus = space.bonus_free();
+            chit = space.bonus_chit.take().map(|chit| (chit, space.is_summer));
+            space.occupied_by = Some(self.players[player_idx].id);
+        }
+        self.workers_left[player_idx] = self.workers_left[player_idx].saturating_sub(1);
+
+        let player_id = self.players[player_idx].id;
+        let player_structures: Vec<_> = self.structures.iter()
+            .filter(|s| s.owner == player_id)
+            .cloned()
+            .collect();
+
+        let outcome = resolve_action(
+            action,
+            self.current_year,
+            self.hands.get_mut(player_idx),
+            self.vineyards.get_mut(player_idx),
+            self.players.get_mut(player_idx),
+            self.trackers.get_mut(player_idx),
+            &mut self.decks,
+            &player_structures,
+            bonus,
+        );
+
+        // Mirrors award_bonus_chits_system
+        match chit {
+            Some((BonusChit::ExtraCard, true)) => {
+                if let Some(card) = self.decks.draw_vine_card() {
+                    self.hands[player_idx].vine_cards.push(card);
+                }
+            }
+            Some((BonusChit::ExtraCard, false)) => {
+                if let Some(card) = self.decks.draw_wine_order_card() {
+                    self.hands[player_idx].wine_order_cards.push(card);
+                }
+            }
+            Some((BonusChit::ExtraLira, _)) => self.players[player_idx].gain_lira(1),
+            None => {}
+        }
+        outcome
+    }
+
+    // Advances the game by one worker placement or one phase transition
+    pub fn step(&mut self, rng: &mut StdRng) -> SimStep {
+        self.step_with(rng, |game, player_idx, rng| game.choose_action(player_idx, rng))
+    }
+
+    // Same as step, with worker placements chosen by the given policy instead of the AI
+    pub fn step_with(
+        &mut self,
+        rng: &mut StdRng,
+        mut policy: impl FnMut(&SimGame, usize, &mut StdRng) -> Option<ActionSpace>,
+    ) -> SimStep {
+        if self.players.iter().any(|p| p.victory_points >= self.target_victory_points)
+            || self.current_year > self.max_years {
+            self.phase = GameState::GameOver;
+        }
+
+        match self.phase {
+            GameState::Spring => {
+                // Mirrors wine_festival_system, which scores as the festival year's spring opens
+                if self.rules.wine_festival && self.current_year == FESTIVAL_YEAR {
+                    for (player, vineyard) in self.players.iter_mut().zip(self.vineyards.iter()) {
+                        player.gain_victory_points(festival_points(vineyard));
+                    }
+                }
+                self.workers_left = self.players.iter().map(|p| p.total_workers()).collect();
+                for space in self.spaces.iter_mut() {
+                    space.occupied_by = None;
+                    space.bonus_worker_slot = None;
+                    space.bonus_chit = None;
+                }
+                // Mirrors place_bonus_chits_system: one chit per action at most, on its leftmost space
+                if self.rules.bonus_chits {
+                    let mut leftmost: Vec<_> = self.spaces.iter_mut().filter(|s| s.column == 0).collect();
+                    leftmost.shuffle(rng);
+                    for space in leftmost.into_iter().take(CHITS_PER_YEAR) {
+                        space.bonus_chit = Some(if rng.random_bool(0.5) { BonusChit::ExtraCard } else { BonusChit::ExtraLira });
+                    }
+                }
+                for idx in 0..self.players.len() {
+                    if let Some(bonus) = self.wake_up_chart.bonus_at(idx) {
+                        self.apply_wake_up_bonus(idx, bonus);
+                    }
+                }
+                self.current_player = 0;
+                self.phase = GameState::Summer;
+                SimStep::phase("spring_system")
+            }
+            GameState::Summer | GameState::Winter => {
+                let player_count = self.players.len();
+                for offset in 0..player_count {
+                    let idx = (self.current_player + offset) % player_count;
+                    if let Some(action) = policy(self, idx, rng) {
+                        let outcome = self.apply_action(idx, action);
+                        self.current_player = (idx + 1) % player_count;
+                        return SimStep {
+                            system: "action_resolution_system",
+                            player: Some(self.players[idx].id),
+                            action: Some(action),
+                            outcome: Some(outcome),
+                        };
+                    }
+                }
+
+                // Nobody can place a worker: the season ends
+                self.current_player = 0;
+                if matches!(self.phase, GameState::Summer) {
+                    self.phase = GameState::Fall;
+                } else {
+                    // Mirrors worker_upkeep_system, which pays before the new year's spring
+                    if self.rules.worker_upkeep {
+                        for player in self.players.iter_mut() {
+                            pay_worker_upkeep(player);
+                        }
+                    }
+                    self.workers_left = self.players.iter().map(|p| p.total_workers()).collect();
+                    self.current_year += 1;
+                    self.phase = GameState::Spring;
+                }
+                SimStep::phase("worker_placement_system")
+            }
+            GameState::Fall => {
+                let structures = Vec::new();
+                for vineyard in self.vineyards.iter_mut() {
+                    vineyard.harvest_grapes(&structures);
+                }
+                self.workers_left = self.players.iter().map(|p| p.total_workers()).collect();
+                self.phase = GameState::Winter;
+                SimStep::phase("fall_system")
+            }
+            _ => SimStep::phase("check_victory_system"),
+        }
+    }
+
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        for player in &self.players {
+            (player.id.0, player.victory_points, player.lira, player.workers, player.grande_worker_available).hash(&mut hasher);
+        }
+        for vineyard in &self.vineyards {
+            (vineyard.red_grapes, vineyard.white_grapes, vineyard.red_wine, vineyard.white_wine, vineyard.lira).hash(&mut hasher);
+            for field in &vineyard.fields {
+                match field.vine {
+                    Some(VineType::Red(v)) => (1u8, v).hash(&mut hasher),
+                    Some(VineType::White(v)) => (2u8, v).hash(&mut hasher),
+                    None => (0u8, 0u8).hash(&mut hasher),
+                }
+            }
+        }
+        for hand in &self.hands {
+            for card in &hand.vine_cards {
+                card.id.hash(&mut hasher);
+            }
+            for order in &hand.wine_order_cards {
+                order.id.hash(&mut hasher);
+            }
+        }
+        for tracker in &self.trackers {
+            tracker.level.hash(&mut hasher);
+        }
+        (self.decks.vine_deck.len(), self.decks.wine_order_deck.len()).hash(&mut hasher);
+        for space in &self.spaces {
+            space.occupied_by.map(|p| p.0).hash(&mut hasher);
+        }
+        self.workers_left.hash(&mut hasher);
+        self.phase.hash(&mut hasher);
+        (self.current_player, self.current_year).hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
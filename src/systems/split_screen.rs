@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::hand_picker::{order_label, vine_label};
+use crate::systems::prediction::RemotePlay;
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+
+// Two humans on one machine can each keep a half of the screen: their own mat and hand stay
+// up all game on their side, the shared action board sits in the middle, and the hand row
+// under the board is hidden so neither sees the other's cards.
+
+const HALF_WIDTH: f32 = 250.0;
+
+// The two human seats, lowest id on the left; None unless exactly two humans play
+pub fn split_screen_seats<'a>(players: impl IntoIterator<Item = &'a Player>) -> Option<[PlayerId; 2]> {
+    let mut humans: Vec<PlayerId> = players.into_iter().filter(|p| !p.is_ai).map(|p| p.id).collect();
+    humans.sort_by_key(|id| id.0);
+    match humans.as_slice() {
+        [left, right] => Some([*left, *right]),
+        _ => None,
+    }
+}
+
+// Which seats have a half this frame
+#[derive(Resource, Default)]
+pub struct SplitScreen {
+    pub seats: Option<[PlayerId; 2]>,
+}
+
+impl SplitScreen {
+    pub fn active(&self) -> bool {
+        self.seats.is_some()
+    }
+}
+
+fn track(label: &str, values: Vec<u8>) -> String {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("{}: {}", label, if values.is_empty() { "-".to_string() } else { values.join(" ") })
+}
+
+// Everything one player may see of their own estate
+pub fn half_text(player: &Player, vineyard: &Vineyard, hand: &Hand, structures: &[StructureType], on_turn: bool) -> String {
+    let mut text = String::new();
+    if on_turn {
+        text.push_str("▶ YOUR TURN\n");
+    }
+    text.push_str(&format!("{}\n{} VP · {} lira · {} workers{}",
+        player.name, player.victory_points, vineyard.lira, player.workers,
+        if player.grande_worker_available { " + grande" } else { "" }));
+
+    text.push_str("\n\nFields:");
+    for (i, field) in vineyard.fields.iter().enumerate() {
+        let vine = match field.vine {
+            Some(VineType::Red(v)) => format!("Red {}", v),
+            Some(VineType::White(v)) => format!("White {}", v),
+            None => "empty".to_string(),
+        };
+        text.push_str(&format!("\n  {}. {:?}: {}", i + 1, field.field_type, vine));
+    }
+
+    text.push_str(&format!("\n\n{}\n{}\n{}\n{}",
+        track("Red grapes", vineyard.red_grapes.values()),
+        track("White grapes", vineyard.white_grapes.values()),
+        track("Red wine", vineyard.red_wine.values()),
+        track("White wine", vineyard.white_wine.values())));
+
+    let built: Vec<String> = structures.iter().map(|s| format!("{:?}", s)).collect();
+    text.push_str(&format!("\nStructures: {}", if built.is_empty() { "none".to_string() } else { built.join(", ") }));
+
+    text.push_str(&format!("\n\nVines in hand ({}):", hand.vine_cards.len()));
+    for card in &hand.vine_cards {
+        text.push_str(&format!("\n  {}", vine_label(card).replace('\n', ", ")));
+    }
+    text.push_str(&format!("\nOrders in hand ({}):", hand.wine_order_cards.len()));
+    for order in &hand.wine_order_cards {
+        text.push_str(&format!("\n  {}", order_label(order).replace('\n', ", ")));
+    }
+    text
+}
+
+#[derive(Component)]
+pub struct SplitScreenHalf(pub PlayerId);
+
+// The half of the player on turn lights up
+fn half_color(on_turn: bool) -> BackgroundColor {
+    let color = if on_turn { Srgba::new(0.25, 0.2, 0.05, 0.92) } else { Srgba::new(0.1, 0.1, 0.1, 0.92) };
+    Color::from(color).into()
+}
+
+// Only for a local game; online every machine already shows just its own seat
+pub fn split_screen_system(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    remote: Option<Res<RemotePlay>>,
+    mut split: ResMut<SplitScreen>,
+    current_state: Res<State<GameState>>,
+    turn_order: Res<TurnOrder>,
+    (players, vineyards, hands, structures): (Query<&Player>, Query<&Vineyard>, Query<&Hand>, Query<&Structure>),
+    mut halves: Query<(Entity, &SplitScreenHalf, &Children, &mut BackgroundColor)>,
+    mut texts: Query<&mut Text>,
+) {
+    let in_play = matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+    let seats = split_screen_seats(players.iter()).filter(|_| settings.split_screen && in_play && remote.is_none());
+    if split.seats != seats {
+        split.seats = seats;
+    }
+
+    let Some(seats) = seats else {
+        for (entity, _, _, _) in halves.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let on_turn = turn_order.players.get(turn_order.current_player).copied();
+    for (side, seat) in seats.into_iter().enumerate() {
+        let estate = players.iter().find(|p| p.id == seat).and_then(|player| {
+            Some((player, vineyards.iter().find(|v| v.owner == seat)?, hands.iter().find(|h| h.owner == seat)?))
+        });
+        let Some((player, vineyard, hand)) = estate else { continue };
+        let owned: Vec<StructureType> = structures.iter().filter(|s| s.owner == seat).map(|s| s.structure_type).collect();
+        let text = half_text(player, vineyard, hand, &owned, on_turn == Some(seat));
+
+        if let Some((_, _, children, mut color)) = halves.iter_mut().find(|(_, half, _, _)| half.0 == seat) {
+            let lit = half_color(on_turn == Some(seat));
+            if *color != lit {
+                *color = lit;
+            }
+            for &child in children.iter() {
+                if let Ok(mut existing) = texts.get_mut(child) {
+                    if existing.sections[0].value != text {
+                        existing.sections[0].value = text.clone();
+                    }
+                }
+            }
+            continue;
+        }
+
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(90.0),
+                    bottom: Val::Px(10.0),
+                    left: if side == 0 { Val::Px(10.0) } else { Val::Auto },
+                    right: if side == 1 { Val::Px(10.0) } else { Val::Auto },
+                    width: Val::Px(HALF_WIDTH),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                background_color: half_color(on_turn == Some(seat)),
+                z_index: UiLayer::Hud.z_index(),
+                ..default()
+            },
+            SplitScreenHalf(seat),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font_size: 13.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+    }
+}
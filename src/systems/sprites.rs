@@ -1,19 +1,51 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::tooltips::TooltipTarget;
+use crate::systems::game_logic::TemporaryWorker;
+use crate::systems::lobby::PlayerColors;
+use crate::systems::split_screen::SplitScreen;
+use crate::systems::handoff::Handoff;
+use crate::systems::net::plays_locally;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::render_layers::WorldLayer;
 
 const GREY: Srgba = Srgba::new(0.6, 0.6, 0.6, 1.0);
 
+const WORKER_SIZE: Vec2 = Vec2::new(18.0, 18.0);
+const GRANDE_WORKER_SIZE: Vec2 = Vec2::new(26.0, 26.0);
+const GRANDE_GOLD: Srgba = Srgba::new(1.0, 0.84, 0.0, 1.0);
+const TEMPORARY_OUTLINE: Srgba = Srgba::new(0.3, 0.9, 1.0, 1.0);
+
+// Field index used for cellar sprites so they are cleared with the vineyard
+const CELLAR_SPRITE_INDEX: usize = 9;
+
+// Crack lines drawn over Poor fields: (offset from field centre, rotation)
+const FIELD_CRACKS: [(Vec2, f32); 3] = [
+    (Vec2::new(-6.0, 8.0), 0.6),
+    (Vec2::new(4.0, -2.0), -0.4),
+    (Vec2::new(-2.0, -11.0), 1.1),
+];
+
 // Update the vineyard field rendering in update_sprites_system:
 pub fn update_sprites_system(
     mut commands: Commands,
-    workers: Query<&Worker>,
+    workers: Query<(&Worker, Has<TemporaryWorker>)>,
     vineyards: Query<&Vineyard>,
     hands: Query<&Hand>,
     worker_sprites: Query<Entity, With<WorkerSprite>>,
     vineyard_sprites: Query<Entity, With<VineyardSprite>>,
     card_sprites: Query<Entity, With<CardSprite>>,
     turn_order: Res<TurnOrder>,
+    config: Res<GameConfig>,
+    structures: Query<&Structure>,
+    player_colors: Res<PlayerColors>,
+    split: Res<SplitScreen>,
+    handoff: Res<Handoff>,
+    (remote, pbf): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>),
 ) {
+    let on_turn = turn_order.players.get(turn_order.current_player).copied();
+
     // Clear existing sprites
     for entity in worker_sprites.iter() {
         commands.entity(entity).despawn();
@@ -26,66 +58,14 @@ pub fn update_sprites_system(
     }
     
     // Enhanced worker sprites with better distinction
-    for worker in workers.iter() {
-        let player_colors = [
-            Color::srgb(0.8, 0.2, 0.2), // Red
-            Color::srgb(0.2, 0.2, 0.8), // Blue  
-            Color::srgb(0.2, 0.8, 0.2), // Green
-            Color::srgb(0.8, 0.8, 0.2), // Yellow
-        ];
-        
-        let color_grey = Color::srgb(0.6, 0.6, 0.6);
-        let base_color = player_colors.get(worker.owner.0 as usize)
-            .unwrap_or(&color_grey);
-        
-        // Enhanced visual distinction for grande workers
-        let (final_color, size, z_index) = if worker.is_grande {
-            let bright_color = Color::srgb(
-                (base_color.to_srgba().red * 1.3).min(1.0),
-                (base_color.to_srgba().green * 1.3).min(1.0),
-                (base_color.to_srgba().blue * 1.3).min(1.0)
-            );
-            (bright_color, Vec2::new(24.0, 24.0), 2.0)
-        } else {
-            (*base_color, Vec2::new(18.0, 18.0), 1.0)
-        };
-        
-        commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color: final_color,
-                    custom_size: Some(size),
-                    ..default()
-                },
-                transform: Transform::from_translation(worker.position.extend(z_index)),
-                ..default()
-            },
-            WorkerSprite { player_id: worker.owner },
-        ));
-        
-        // Add border for grande workers
-        if worker.is_grande {
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        color: Color::srgb(1.0, 1.0, 0.8),
-                        custom_size: Some(Vec2::new(28.0, 28.0)),
-                        ..default()
-                    },
-                    transform: Transform::from_translation(worker.position.extend(z_index - 0.1)),
-                    ..default()
-                },
-                WorkerSprite { player_id: worker.owner },
-            ));
-        }
+    for (worker, temporary) in workers.iter() {
+        spawn_worker_sprite(&mut commands, worker, temporary, Color::from(player_colors.srgba(worker.owner)));
     }
     
     // Enhanced vineyard visualization - FIXED field access
     for vineyard in vineyards.iter() {
         for (field_idx, field) in vineyard.fields.iter().enumerate() {
-            let field_x = -200.0 + ((field_idx % 3) as f32 * 45.0);
-            let field_y = 100.0 - ((field_idx / 3) as f32 * 45.0);
-            let field_pos = Vec2::new(field_x + (vineyard.owner.0 as f32 * 220.0), field_y);
+            let field_pos = field_sprite_position(vineyard.owner, field_idx);
             
             // Base field color based on field type
             let base_color = match field.field_type {
@@ -102,7 +82,7 @@ pub fn update_sprites_system(
                         custom_size: Some(Vec2::new(40.0, 40.0)),
                         ..default()
                     },
-                    transform: Transform::from_translation(field_pos.extend(0.1)),
+                    transform: Transform::from_translation(field_pos.extend(WorldLayer::Vineyard.at(0.1))),
                     ..default()
                 },
                 VineyardSprite { 
@@ -126,7 +106,7 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(30.0, 30.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(field_pos.extend(0.5)),
+                        transform: Transform::from_translation(field_pos.extend(WorldLayer::Vineyard.at(0.5))),
                         ..default()
                     },
                     VineyardSprite { 
@@ -145,7 +125,7 @@ pub fn update_sprites_system(
                                 custom_size: Some(Vec2::new(8.0, 8.0)),
                                 ..default()
                             },
-                            transform: Transform::from_translation(field_pos.extend(0.8) + Vec3::new(12.0, 12.0, 0.0)),
+                            transform: Transform::from_translation(field_pos.extend(WorldLayer::Vineyard.at(0.8)) + Vec3::new(12.0, 12.0, 0.0)),
                             ..default()
                         },
                         VineyardSprite { 
@@ -156,42 +136,148 @@ pub fn update_sprites_system(
                 }
             }
             
-            // Field type indicator
-            let indicator_color = match field.field_type {
-                FieldType::Premium => Some(Color::srgb(1.0, 0.8, 0.0)),
-                FieldType::Poor => Some(Color::srgb(0.6, 0.6, 0.6)),
-                FieldType::Standard => None,
-            };
+            // Field quality: golden border for Premium, cracked soil for Poor
+            match field.field_type {
+                FieldType::Premium => {
+                    commands.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(1.0, 0.8, 0.0),
+                                custom_size: Some(Vec2::new(44.0, 44.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(field_pos.extend(WorldLayer::Vineyard.at(0.05))),
+                            ..default()
+                        },
+                        VineyardSprite { 
+                            player_id: vineyard.owner,
+                            field_index: field_idx,
+                        },
+                    ));
+                }
+                FieldType::Poor => {
+                    for (offset, angle) in FIELD_CRACKS {
+                        commands.spawn((
+                            SpriteBundle {
+                                sprite: Sprite {
+                                    color: Color::srgb(0.15, 0.15, 0.15),
+                                    custom_size: Some(Vec2::new(18.0, 2.0)),
+                                    ..default()
+                                },
+                                transform: Transform::from_translation(field_pos.extend(WorldLayer::Vineyard.at(0.2)) + offset.extend(0.0))
+                                    .with_rotation(Quat::from_rotation_z(angle)),
+                                ..default()
+                            },
+                            VineyardSprite { 
+                                player_id: vineyard.owner,
+                                field_index: field_idx,
+                            },
+                        ));
+                    }
+                }
+                FieldType::Standard => {}
+            }
             
-            if let Some(color) = indicator_color {
+            // Hover tooltip explaining the field's harvest modifier, with planting previews
+            // only when the owner's hand is theirs to see
+            let hand = hands.iter()
+                .find(|h| h.owner == vineyard.owner)
+                .filter(|h| hand_on_view(h.owner, on_turn, remote.as_deref(), pbf.as_deref(), &split, &handoff));
+            commands.spawn((
+                TooltipTarget {
+                    tooltip_text: field_tooltip_text(field, hand),
+                    bounds: Rect::from_center_size(field_pos, Vec2::new(40.0, 40.0)),
+                },
+                VineyardSprite { 
+                    player_id: vineyard.owner,
+                    field_index: field_idx,
+                },
+            ));
+        }
+        
+        // Cellar strip under the field grid, hover for the aging preview
+        let cellar_pos = cellar_position(vineyard.owner);
+        let player_structures: Vec<_> = structures.iter()
+            .filter(|s| s.owner == vineyard.owner)
+            .cloned()
+            .collect();
+        let previews = vineyard.dry_run_aging(&player_structures);
+        let cellar_color = if previews.iter().any(|p| p.overflows()) {
+            Color::srgb(0.5, 0.15, 0.1)
+        } else {
+            Color::srgb(0.25, 0.15, 0.1)
+        };
+        
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: cellar_color,
+                    custom_size: Some(Vec2::new(130.0, 20.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(cellar_pos.extend(WorldLayer::Vineyard.at(0.1))),
+                ..default()
+            },
+            VineyardSprite { 
+                player_id: vineyard.owner,
+                field_index: CELLAR_SPRITE_INDEX,
+            },
+            TooltipTarget {
+                tooltip_text: cellar_tooltip_text(vineyard, &previews),
+                bounds: Rect::from_center_size(cellar_pos, Vec2::new(130.0, 20.0)),
+            },
+        ));
+        
+        for (wine_type, track, color) in [
+            (WineType::Red, vineyard.red_wine, Color::srgb(0.6, 0.05, 0.15)),
+            (WineType::White, vineyard.white_wine, Color::srgb(0.95, 0.9, 0.6)),
+        ] {
+            for value in track.values() {
                 commands.spawn((
                     SpriteBundle {
                         sprite: Sprite {
                             color,
-                            custom_size: Some(Vec2::new(6.0, 6.0)),
+                            custom_size: Some(Vec2::new(8.0, 8.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(field_pos.extend(0.3) + Vec3::new(-15.0, -15.0, 0.0)),
+                        transform: Transform::from_translation(cellar_token_position(vineyard.owner, wine_type, value).extend(WorldLayer::Vineyard.at(0.2))),
                         ..default()
                     },
                     VineyardSprite { 
                         player_id: vineyard.owner,
-                        field_index: field_idx,
+                        field_index: CELLAR_SPRITE_INDEX,
                     },
                 ));
+                
+                // Age badge under the sparkling aging house rule; resting sparkling shows in amber
+                if let Some(vintage) = vineyard.vintage(wine_type, value).filter(|_| vineyard.sparkling_aging) {
+                    let badge_color = if vintage.resting() { Color::srgb(1.0, 0.6, 0.1) } else { Color::srgb(0.85, 0.85, 0.85) };
+                    commands.spawn((
+                        Text2dBundle {
+                            text: Text::from_section(
+                                format!("{}y", vintage.aged),
+                                TextStyle { font_size: 8.0, color: badge_color, ..default() },
+                            ),
+                            transform: Transform::from_translation((cellar_token_position(vineyard.owner, wine_type, value) + Vec2::new(0.0, 7.0)).extend(WorldLayer::Vineyard.at(0.3))),
+                            ..default()
+                        },
+                        VineyardSprite { 
+                            player_id: vineyard.owner,
+                            field_index: CELLAR_SPRITE_INDEX,
+                        },
+                    ));
+                }
             }
         }
     }
     
     // Enhanced card sprites with better art (rest remains the same...)
-    if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
+    // In split screen each hand lives on its owner's half instead; between hotseat turns none shows
+    if let Some(current_player_id) = turn_order.players.get(turn_order.current_player).filter(|_| !split.active() && !handoff.hides_hand()) {
         if let Some(hand) = hands.iter().find(|h| h.owner == *current_player_id) {
-            let hand_y = -200.0;
-            let mut card_x = -350.0;
-            
             // Vine cards with enhanced visuals
             for (i, vine_card) in hand.vine_cards.iter().enumerate() {
-                let card_pos = Vec2::new(card_x + (i as f32 * 38.0), hand_y);
+                let card_pos = hand_card_position(hand, CardType::Vine, i);
                 
                 // Card background
                 commands.spawn((
@@ -201,7 +287,7 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(32.0, 42.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(card_pos.extend(2.0)),
+                        transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.1))),
                         ..default()
                     },
                     CardSprite { card_type: CardType::Vine },
@@ -215,7 +301,7 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(36.0, 46.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(card_pos.extend(1.9)),
+                        transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.z())),
                         ..default()
                     },
                     CardSprite { card_type: CardType::Vine },
@@ -229,18 +315,16 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(8.0, 8.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(card_pos.extend(2.1) + Vec3::new(-12.0, 15.0, 0.0)),
+                        transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.2)) + Vec3::new(-12.0, 15.0, 0.0)),
                         ..default()
                     },
                     CardSprite { card_type: CardType::Vine },
                 ));
             }
             
-            card_x += hand.vine_cards.len() as f32 * 38.0 + 25.0;
-            
             // Wine order cards with enhanced visuals
             for (i, order_card) in hand.wine_order_cards.iter().enumerate() {
-                let card_pos = Vec2::new(card_x + (i as f32 * 38.0), hand_y);
+                let card_pos = hand_card_position(hand, CardType::WineOrder, i);
                 
                 // Card background
                 commands.spawn((
@@ -250,7 +334,7 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(32.0, 42.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(card_pos.extend(2.0)),
+                        transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.1))),
                         ..default()
                     },
                     CardSprite { card_type: CardType::WineOrder },
@@ -264,7 +348,7 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(36.0, 46.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(card_pos.extend(1.9)),
+                        transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.z())),
                         ..default()
                     },
                     CardSprite { card_type: CardType::WineOrder },
@@ -278,12 +362,186 @@ pub fn update_sprites_system(
                             custom_size: Some(Vec2::new(10.0, 10.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(card_pos.extend(2.1) + Vec3::new(12.0, 15.0, 0.0)),
+                        transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.2)) + Vec3::new(12.0, 15.0, 0.0)),
                         ..default()
                     },
                     CardSprite { card_type: CardType::WineOrder },
                 ));
+                
+                // Order type effect indicator (bright when a seasonal order is in season)
+                let effect_color = match order_card.order_type {
+                    OrderType::Premium => Some(Color::srgb(0.9, 0.6, 1.0)),
+                    OrderType::Seasonal if order_card.seasonal_bonus(config.current_year) > 0 => Some(Color::srgb(1.0, 0.6, 0.1)),
+                    OrderType::Seasonal => Some(Color::from(GREY)),
+                    OrderType::Regular => None,
+                };
+                
+                if let Some(color) = effect_color {
+                    commands.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color,
+                                custom_size: Some(Vec2::new(8.0, 8.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(card_pos.extend(WorldLayer::Hand.at(0.2)) + Vec3::new(-12.0, -15.0, 0.0)),
+                            ..default()
+                        },
+                        CardSprite { card_type: CardType::WineOrder },
+                    ));
+                }
             }
         }
     }
-}
\ No newline at end of file
+}
+// One worker token. The grande is larger and brighter with a gold rim and a star over it;
+// a temporary worker gets a cyan outline so it reads as gone at year end.
+pub fn spawn_worker_sprite(commands: &mut Commands, worker: &Worker, temporary: bool, base_color: Color) {
+    let (color, size, z_index) = if worker.is_grande {
+        let base = base_color.to_srgba();
+        (Color::srgb((base.red * 1.3).min(1.0), (base.green * 1.3).min(1.0), (base.blue * 1.3).min(1.0)), GRANDE_WORKER_SIZE, WorldLayer::Workers.at(0.6))
+    } else {
+        (base_color, WORKER_SIZE, WorldLayer::Workers.at(0.2))
+    };
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(size),
+                ..default()
+            },
+            transform: Transform::from_translation(worker.position.extend(z_index)),
+            ..default()
+        },
+        WorkerSprite { player_id: worker.owner },
+    ));
+
+    let outline = if worker.is_grande {
+        Some(GRANDE_GOLD)
+    } else if temporary {
+        Some(TEMPORARY_OUTLINE)
+    } else {
+        None
+    };
+    if let Some(outline) = outline {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::from(outline),
+                    custom_size: Some(size + Vec2::splat(4.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(worker.position.extend(z_index - 0.1)),
+                ..default()
+            },
+            WorkerSprite { player_id: worker.owner },
+        ));
+    }
+
+    if worker.is_grande {
+        commands.spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    "★",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::from(GRANDE_GOLD),
+                        ..default()
+                    },
+                ),
+                transform: Transform::from_translation((worker.position + Vec2::new(0.0, size.y / 2.0 + 8.0)).extend(z_index + 0.1)),
+                ..default()
+            },
+            WorkerSprite { player_id: worker.owner },
+        ));
+    }
+}
+
+// Screen position of a vineyard field sprite (3x3 grid per player)
+pub fn field_sprite_position(owner: PlayerId, field_idx: usize) -> Vec2 {
+    let field_x = -200.0 + ((field_idx % 3) as f32 * 45.0);
+    let field_y = 100.0 - ((field_idx / 3) as f32 * 45.0);
+    Vec2::new(field_x + (owner.0 as f32 * 220.0), field_y)
+}
+
+// Cellar strip under the field grid
+pub fn cellar_position(owner: PlayerId) -> Vec2 {
+    field_sprite_position(owner, 7) + Vec2::new(0.0, -45.0)
+}
+
+// A wine token sits along the cellar strip at its value; red on top, white below
+pub fn cellar_token_position(owner: PlayerId, wine_type: WineType, value: u8) -> Vec2 {
+    let row = if matches!(wine_type, WineType::Red | WineType::Sparkling) { 0.0 } else { 1.0 };
+    cellar_position(owner) + Vec2::new(-60.0 + value.saturating_sub(1) as f32 * 15.0, 5.0 - row * 10.0)
+}
+
+// Screen position of a card in the current player's hand row
+pub fn hand_card_position(hand: &Hand, card_type: CardType, card_idx: usize) -> Vec2 {
+    let hand_y = -200.0;
+    let card_x = match card_type {
+        CardType::Vine => -350.0,
+        CardType::WineOrder => -350.0 + hand.vine_cards.len() as f32 * 38.0 + 25.0,
+    };
+    Vec2::new(card_x + (card_idx as f32 * 38.0), hand_y)
+}
+
+// Whether the table may see `owner`'s hand: only this machine's seat in a networked or
+// play-by-file game; offline the hand on show, or each half's own in split screen
+fn hand_on_view(owner: PlayerId, on_turn: Option<PlayerId>, remote: Option<&RemotePlay>, pbf: Option<&PlayByFile>, split: &SplitScreen, handoff: &Handoff) -> bool {
+    if remote.is_some() || pbf.is_some() {
+        return plays_locally(remote, pbf, owner);
+    }
+    match split.seats {
+        Some(seats) => seats.contains(&owner),
+        None => on_turn == Some(owner) && !handoff.hides_hand(),
+    }
+}
+
+// Field tooltip, including projected harvest for each vine card in the owner's hand when
+// that hand is on view
+fn field_tooltip_text(field: &VineyardField, hand: Option<&Hand>) -> String {
+    let mut text = field.field_type.description().to_string();
+    
+    if let Some(vine) = field.vine {
+        text.push_str(&format!("\nPlanted: {:?} -> {} grapes per harvest", vine, field.get_harvest_value()));
+    } else if let Some(hand) = hand {
+        for (i, vine_card) in hand.vine_cards.iter().enumerate() {
+            text.push_str(&format!(
+                "\nPlant card {} ({:?}): {} grapes per harvest",
+                i + 1,
+                vine_card.vine_type,
+                field.projected_harvest(vine_card.vine_type)
+            ));
+        }
+    }
+    
+    text
+}
+
+// Cellar tooltip: what each wine token becomes after the next aging step
+fn cellar_tooltip_text(vineyard: &Vineyard, previews: &[WineAgingPreview]) -> String {
+    let mut text = format!("Cellar: red {:?}, white {:?}", vineyard.red_wine.values(), vineyard.white_wine.values());
+    
+    if previews.is_empty() {
+        text.push_str("\nNo wine to age");
+    }
+    
+    for preview in previews {
+        text.push_str(&format!("\nNext aging: {:?} {} -> {}", preview.wine_type, preview.current, preview.next));
+        if let Some(cellar) = preview.required_cellar() {
+            text.push_str(&format!(" (held at {} without {:?})", preview.current, cellar));
+        }
+    }
+    
+    if vineyard.sparkling_aging {
+        for vintage in vineyard.vintages.iter().filter(|v| vineyard.vintage(v.wine_type, v.value).is_some()) {
+            text.push_str(&format!("\n{:?} {}: made in year {}, aged {} year(s)", vintage.wine_type, vintage.value, vintage.year, vintage.aged));
+            if vintage.resting() {
+                text.push_str(" - can't fill an order until the next year end");
+            }
+        }
+    }
+    
+    text
+}
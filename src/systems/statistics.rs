@@ -1,8 +1,13 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use crate::components::*;
+use crate::systems::keymap::*;
+use crate::systems::coop::CoopMode;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::render_layers::UiLayer;
 
 #[derive(Serialize, Deserialize, Resource, Default)]
+#[serde(default)]
 pub struct GameStatistics {
     pub total_games_played: u32,
     pub total_games_won: u32,
@@ -15,8 +20,18 @@ pub struct GameStatistics {
     pub average_game_length: f32,
     pub total_vp_earned: u32,
     pub total_lira_earned: u32,
+    // Pacing data: winner VP at the end of each year (index 0 = year 1)
+    pub winner_vp_by_year: Vec<u32>,
+    pub winner_vp_samples: Vec<u32>,
+    // Quick games keep their own records so they don't skew the standard ones
+    pub quick_games: Option<Box<GameStatistics>>,
+    // Solo games where the Automa showed mercy; only counted, never recorded as wins or records
+    pub assisted_games: u32,
 }
 
+// Need a few finished games before pacing hints mean anything
+const MIN_PACING_SAMPLES: u32 = 3;
+
 #[derive(Resource, Default)]
 pub struct SessionStats {
     pub session_start_time: f32,
@@ -24,6 +39,7 @@ pub struct SessionStats {
     pub actions_this_game: Vec<ActionSpace>,
     pub vp_this_game: u8,
     pub lira_this_game: u8,
+    pub vp_by_year: Vec<Vec<(PlayerId, u8)>>,
 }
 
 impl GameStatistics {
@@ -40,6 +56,19 @@ impl GameStatistics {
         }
     }
     
+    // Standard games record into the top level, quick games into their own bucket
+    pub fn bucket(&self, quick_game: bool) -> Option<&GameStatistics> {
+        if quick_game { self.quick_games.as_deref() } else { Some(self) }
+    }
+
+    pub fn bucket_mut(&mut self, quick_game: bool) -> &mut GameStatistics {
+        if quick_game {
+            self.quick_games.get_or_insert_with(Default::default)
+        } else {
+            self
+        }
+    }
+
     pub fn games_win_rate(&self) -> f32 {
         if self.total_games_played == 0 {
             0.0
@@ -56,6 +85,16 @@ impl GameStatistics {
         }
     }
     
+    // Typical winner VP at the end of the given year, once enough games are recorded
+    pub fn typical_winner_vp(&self, year: u8) -> Option<u8> {
+        let idx = (year as usize).checked_sub(1)?;
+        let samples = *self.winner_vp_samples.get(idx)?;
+        if samples < MIN_PACING_SAMPLES {
+            return None;
+        }
+        Some((self.winner_vp_by_year[idx] as f32 / samples as f32).round() as u8)
+    }
+    
     pub fn most_used_action(&self) -> Option<ActionSpace> {
         self.favorite_actions.iter()
             .max_by_key(|(_, count)| *count)
@@ -75,6 +114,7 @@ pub fn initialize_session_system(
         actions_this_game: Vec::new(),
         vp_this_game: 0,
         lira_this_game: 0,
+        vp_by_year: Vec::new(),
     });
 }
 
@@ -84,6 +124,7 @@ pub fn track_session_system(
     current_state: Res<State<GameState>>,
     players: Query<&Player>,
     turn_order: Res<TurnOrder>,
+    config: Res<GameConfig>,
 ) {
     // Reset game timer when new game starts
     if current_state.is_changed() && matches!(current_state.get(), GameState::Setup) {
@@ -91,13 +132,23 @@ pub fn track_session_system(
         session_stats.actions_this_game.clear();
         session_stats.vp_this_game = 0;
         session_stats.lira_this_game = 0;
+        session_stats.vp_by_year.clear();
+    }
+    
+    // Each spring after the first closes out the previous year: record everyone's VP
+    if current_state.is_changed() && matches!(current_state.get(), GameState::Spring) {
+        let completed_years = config.current_year.saturating_sub(1) as usize;
+        if completed_years > session_stats.vp_by_year.len() {
+            let snapshot = players.iter().map(|p| (p.id, p.victory_points.get())).collect();
+            session_stats.vp_by_year.push(snapshot);
+        }
     }
     
     // Track current player's progress
     if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
         if let Some(player) = players.iter().find(|p| p.id == *current_player_id) {
-            session_stats.vp_this_game = session_stats.vp_this_game.max(player.victory_points);
-            session_stats.lira_this_game = session_stats.lira_this_game.max(player.lira);
+            session_stats.vp_this_game = session_stats.vp_this_game.max(player.victory_points.get());
+            session_stats.lira_this_game = session_stats.lira_this_game.max(player.lira.get());
         }
     }
 }
@@ -114,14 +165,23 @@ pub fn track_action_usage_system(
 }
 
 pub fn update_statistics_on_game_end_system(
-    mut stats: ResMut<GameStatistics>,
+    mut all_stats: ResMut<GameStatistics>,
     session_stats: ResMut<SessionStats>,
     time: Res<Time>,
     current_state: Res<State<GameState>>,
     players: Query<&Player>,
     turn_order: Res<TurnOrder>,
+    preset: Res<RulesPreset>,
+    coop: Res<CoopMode>,
 ) {
     if current_state.is_changed() && matches!(current_state.get(), GameState::GameOver) {
+        if coop.mercy_triggered() {
+            all_stats.assisted_games += 1;
+            info!("Automa mercy was used this game; career statistics left unchanged");
+            all_stats.save();
+            return;
+        }
+        let stats = all_stats.bucket_mut(preset.is_quick_game());
         let game_duration = time.elapsed_seconds() - session_stats.current_game_start;
         
         // Find if current player won
@@ -160,37 +220,51 @@ pub fn update_statistics_on_game_end_system(
         // Update average game length
         stats.average_game_length = (stats.average_game_length * (stats.total_games_played - 1) as f32 + game_duration) / stats.total_games_played as f32;
         
+        // Track winner pacing per completed year
+        if let Some(winner) = players.iter().max_by_key(|p| p.victory_points) {
+            for (year_idx, snapshot) in session_stats.vp_by_year.iter().enumerate() {
+                if let Some((_, vp)) = snapshot.iter().find(|(id, _)| *id == winner.id) {
+                    if stats.winner_vp_by_year.len() <= year_idx {
+                        stats.winner_vp_by_year.resize(year_idx + 1, 0);
+                        stats.winner_vp_samples.resize(year_idx + 1, 0);
+                    }
+                    stats.winner_vp_by_year[year_idx] += *vp as u32;
+                    stats.winner_vp_samples[year_idx] += 1;
+                }
+            }
+        }
+        
         // Track favorite actions
         for action in &session_stats.actions_this_game {
             let action_id = action_to_u8(*action);
             *stats.favorite_actions.entry(action_id).or_insert(0) += 1;
         }
         
+        info!("{} statistics updated - Games: {}, Win Rate: {:.1}%, Streak: {}", 
+              preset.label(), stats.total_games_played, stats.games_win_rate(), stats.current_streak);
+
         // Save to file
-        stats.save();
-        
-        info!("Game statistics updated - Games: {}, Win Rate: {:.1}%, Streak: {}", 
-              stats.total_games_played, stats.games_win_rate(), stats.current_streak);
+        all_stats.save();
     }
 }
 
 pub fn display_statistics_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut commands: Commands,
     stats: Res<GameStatistics>,
     session_stats: Res<SessionStats>,
     time: Res<Time>,
     existing_stats_ui: Query<Entity, With<StatsPanel>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Tab) {
+    if input.just_pressed(InputAction::Statistics) {
         if existing_stats_ui.is_empty() {
             // Show statistics panel
             let session_time = time.elapsed_seconds() - session_stats.session_start_time;
             
-            let stats_text = format!(
+            let mut stats_text = format!(
                 "📊 GAME STATISTICS 📊\n\
                  \n\
-                 🎮 CAREER STATS:\n\
+                 🎮 CAREER STATS (standard games):\n\
                  Games Played: {}\n\
                  Games Won: {} ({:.1}%)\n\
                  Current Win Streak: {}\n\
@@ -224,6 +298,15 @@ pub fn display_statistics_system(
                 session_time / 60.0,
                 session_stats.actions_this_game.len()
             );
+            if let Some(quick) = stats.bucket(true) {
+                stats_text.push_str(&format!(
+                    "\n\n⏱ QUICK GAMES (counted separately):\nPlayed: {}  Won: {} ({:.1}%)  Highest VP: {}",
+                    quick.total_games_played, quick.total_games_won, quick.games_win_rate(), quick.highest_victory_points,
+                ));
+            }
+            if stats.assisted_games > 0 {
+                stats_text.push_str(&format!("\n\n🕊 Solo games with Automa mercy (not counted above): {}", stats.assisted_games));
+            }
             
             commands.spawn((
                 TextBundle::from_section(
@@ -253,7 +336,7 @@ pub fn display_statistics_system(
                         ..default()
                     },
                     background_color: Color::from(Srgba::new(0.0, 0.0, 0.0, 0.7)).into(),
-                    z_index: ZIndex::Global(100),
+                    z_index: UiLayer::Screen.z_index(),
                     ..default()
                 },
                 StatsPanel,
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::advanced::AdvancedStructureType;
+use crate::systems::game_logic::TOUR_LIRA_REWARD;
+use crate::systems::settings::UserSettings;
+use crate::systems::supply::ALL_STRUCTURES;
+use crate::systems::render_layers::UiLayer;
+
+// Hovering Build Structure lists what each unbuilt structure is worth to the player on turn,
+// worked out from their lira, fields, hand and cellar rather than the rulebook text.
+
+const WINDMILL_LIRA_PER_VP: u8 = 7;
+const BASE_CELLAR_CAP: u8 = 3;
+const MEDIUM_CELLAR_CAP: u8 = 6;
+
+// What the player needs to judge a purchase
+pub struct RoiContext<'a> {
+    pub vineyard: &'a Vineyard,
+    pub hand: &'a Hand,
+    pub structures: &'a [Structure],
+    // Harvests and falls still to come, this year's included
+    pub years_left: u8,
+}
+
+pub fn years_left(config: &GameConfig) -> u8 {
+    config.max_years.saturating_sub(config.current_year) + 1
+}
+
+fn tours_to_pay_for(cost: u8) -> u8 {
+    cost.div_ceil(TOUR_LIRA_REWARD)
+}
+
+// One line per structure: the live payoff, then how long its cost takes to earn back
+pub fn structure_roi_line(structure: StructureType, ctx: &RoiContext) -> String {
+    let cost = AdvancedStructureType::Basic(structure).cost();
+    let lira = ctx.vineyard.lira.get();
+    let owns = |s: StructureType| ctx.structures.iter().any(|b| b.owner == ctx.vineyard.owner && b.structure_type == s);
+    let planted = ctx.vineyard.fields.iter().filter(|f| f.vine.is_some()).count();
+    let wines_at = |cap: u8| ctx.vineyard.red_wine.values().into_iter()
+        .chain(ctx.vineyard.white_wine.values())
+        .filter(|v| *v == cap)
+        .count();
+
+    let payoff = match structure {
+        StructureType::Windmill => {
+            let left = lira.saturating_sub(cost);
+            format!("at your current {} lira → +{} VP at game end, +1 per further {} lira",
+                lira, left / WINDMILL_LIRA_PER_VP, WINDMILL_LIRA_PER_VP)
+        }
+        StructureType::Trellis => {
+            let unlocks = ctx.hand.vine_cards.iter().filter(|v| v.requirements().needs_trellis).count();
+            format!("+1 grape value on your {} planted field(s); unlocks {} vine(s) in hand", planted, unlocks)
+        }
+        StructureType::Irrigation => {
+            let unlocks = ctx.hand.vine_cards.iter().filter(|v| v.requirements().needs_irrigation).count();
+            format!("plant your {} vine(s) in hand 1 lira cheaper; unlocks {} of them", ctx.hand.vine_cards.len(), unlocks)
+        }
+        StructureType::Yoke => {
+            let harvests = if planted > 0 { ctx.years_left } else { 0 };
+            format!("+1 lira per harvest → ~{} lira over the {} year(s) left", harvests, ctx.years_left)
+        }
+        StructureType::MediumCellar => {
+            format!("{} wine(s) held at {} can age up to {}", wines_at(BASE_CELLAR_CAP), BASE_CELLAR_CAP, MEDIUM_CELLAR_CAP)
+        }
+        StructureType::LargeCellar if !owns(StructureType::MediumCellar) => {
+            "no effect until you own a Medium Cellar".to_string()
+        }
+        StructureType::LargeCellar => {
+            format!("{} wine(s) held at {} can age up to {}", wines_at(MEDIUM_CELLAR_CAP), MEDIUM_CELLAR_CAP, MAX_TOKEN_VALUE)
+        }
+        StructureType::Cottage => format!("+1 visitor each fall → {} more visitor(s)", ctx.years_left),
+        StructureType::TastingRoom => "no bonus in the current rules".to_string(),
+    };
+
+    let affordable = if lira >= cost { String::new() } else { format!(" — need {} more lira", cost - lira) };
+    format!("{:?} ({} lira): {}; cost earned back after ~{} tours{}",
+        structure, cost, payoff, tours_to_pay_for(cost), affordable)
+}
+
+pub fn build_menu_tooltip(ctx: &RoiContext) -> String {
+    let mut text = String::from("🏗 Structures you can still build:");
+    let unbuilt: Vec<_> = ALL_STRUCTURES.into_iter()
+        .filter(|s| !ctx.structures.iter().any(|b| b.owner == ctx.vineyard.owner && b.structure_type == *s))
+        .collect();
+    if unbuilt.is_empty() {
+        text.push_str("\nAll built");
+    }
+    for structure in unbuilt {
+        text.push('\n');
+        text.push_str(&structure_roi_line(structure, ctx));
+    }
+    text
+}
+
+#[derive(Component)]
+pub struct StructureRoiPanel;
+
+// Shown beside the action bar while Build Structure is hovered
+pub fn structure_roi_tooltip_system(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    buttons: Query<(&Interaction, &ActionButton)>,
+    turn_order: Res<TurnOrder>,
+    config: Res<GameConfig>,
+    vineyards: Query<&Vineyard>,
+    hands: Query<&Hand>,
+    structures: Query<&Structure>,
+    panels: Query<(Entity, &Children), With<StructureRoiPanel>>,
+    mut texts: Query<&mut Text>,
+) {
+    let hovered = settings.show_tooltips && buttons.iter()
+        .any(|(interaction, button)| button.action == ActionSpace::BuildStructure && *interaction == Interaction::Hovered);
+    let player = turn_order.players.get(turn_order.current_player).copied();
+    let context = player.filter(|_| hovered).and_then(|id| {
+        Some((vineyards.iter().find(|v| v.owner == id)?, hands.iter().find(|h| h.owner == id)?))
+    });
+
+    let Some((vineyard, hand)) = context else {
+        for (entity, _) in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let owned: Vec<Structure> = structures.iter().cloned().collect();
+    let text = build_menu_tooltip(&RoiContext { vineyard, hand, structures: &owned, years_left: years_left(&config) });
+
+    if let Ok((_, children)) = panels.get_single() {
+        for &child in children.iter() {
+            if let Ok(mut existing) = texts.get_mut(child) {
+                if existing.sections[0].value != text {
+                    existing.sections[0].value = text;
+                }
+                return;
+            }
+        }
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(100.0),
+                right: Val::Px(260.0),
+                max_width: Val::Px(440.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.1, 0.1, 0.92)).into(),
+            z_index: UiLayer::Tooltip.z_index(),
+            ..default()
+        },
+        StructureRoiPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 13.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::expansions::VisitorDeck;
+use crate::systems::keymap::*;
+use crate::systems::render_layers::UiLayer;
+
+pub const ALL_STRUCTURES: [StructureType; 8] = [
+    StructureType::Trellis,
+    StructureType::Irrigation,
+    StructureType::Yoke,
+    StructureType::MediumCellar,
+    StructureType::LargeCellar,
+    StructureType::Windmill,
+    StructureType::Cottage,
+    StructureType::TastingRoom,
+];
+
+#[derive(Resource, Default)]
+pub struct SupplyPanelState {
+    pub open: bool,
+}
+
+#[derive(Component)]
+pub struct SupplyPanel;
+
+fn structure_initial(structure: StructureType) -> &'static str {
+    match structure {
+        StructureType::Trellis => "Tr",
+        StructureType::Irrigation => "Ir",
+        StructureType::Yoke => "Yk",
+        StructureType::MediumCellar => "MC",
+        StructureType::LargeCellar => "LC",
+        StructureType::Windmill => "Wm",
+        StructureType::Cottage => "Ct",
+        StructureType::TastingRoom => "TR",
+    }
+}
+
+// Board-edge supply summary: crush pad and cellar space left (tracks stop at MAX_TOKEN_VALUE),
+// lira in play, unbuilt structures per player and deck/discard sizes
+pub fn supply_text(
+    players: &[Player],
+    vineyards: &[Vineyard],
+    structures: &[Structure],
+    decks: &CardDecks,
+    visitors: Option<&VisitorDeck>,
+) -> String {
+    let mut text = String::from("🏦 SUPPLY\n\n");
+
+    let lira_in_play: u32 = players.iter().map(|p| p.lira.get() as u32).sum();
+    text.push_str(&format!("Bank: unlimited ({} lira in play)\n\n", lira_in_play));
+
+    text.push_str(&format!("Token space left (of {} per track):\n", MAX_TOKEN_VALUE));
+    for player in players {
+        let Some(vineyard) = vineyards.iter().find(|v| v.owner == player.id) else { continue };
+        text.push_str(&format!(
+            "  {}: grapes {}R/{}W  wine {}R/{}W\n",
+            player.name,
+            MAX_TOKEN_VALUE.saturating_sub(vineyard.red_grapes.count()),
+            MAX_TOKEN_VALUE.saturating_sub(vineyard.white_grapes.count()),
+            MAX_TOKEN_VALUE.saturating_sub(vineyard.red_wine.count()),
+            MAX_TOKEN_VALUE.saturating_sub(vineyard.white_wine.count()),
+        ));
+    }
+
+    text.push_str("\nUnbuilt structures:\n");
+    for player in players {
+        let unbuilt: Vec<_> = ALL_STRUCTURES.iter()
+            .filter(|s| !structures.iter().any(|b| b.owner == player.id && b.structure_type == **s))
+            .map(|s| structure_initial(*s))
+            .collect();
+        let list = if unbuilt.is_empty() { "all built".to_string() } else { unbuilt.join(" ") };
+        text.push_str(&format!("  {}: {}\n", player.name, list));
+    }
+
+    text.push_str(&format!(
+        "\nDecks (draw / discard):\n  Vines {} / {}\n  Orders {} / {}\n",
+        decks.vine_deck.len(), decks.vine_discard.len(),
+        decks.wine_order_deck.len(), decks.wine_order_discard.len(),
+    ));
+    if let Some(visitors) = visitors {
+        text.push_str(&format!(
+            "  Summer visitors {} / {}\n  Winter visitors {} / {}\n",
+            visitors.summer_visitors.len(), visitors.summer_discard.len(),
+            visitors.winter_visitors.len(), visitors.winter_discard.len(),
+        ));
+    }
+
+    text
+}
+
+// Ctrl+B toggles the panel during a game; while open it is rebuilt whenever the supply changes
+pub fn supply_panel_system(
+    mut commands: Commands,
+    input: BoundInput,
+    mut state: ResMut<SupplyPanelState>,
+    current_state: Res<State<GameState>>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+    structures: Query<&Structure>,
+    (decks, visitors): (Res<CardDecks>, Option<Res<VisitorDeck>>),
+    existing: Query<Entity, With<SupplyPanel>>,
+    mut last_text: Local<String>,
+) {
+    if input.just_pressed(InputAction::SupplyPanel) {
+        state.open = !state.open;
+    }
+
+    let in_game = matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+    if !state.open || !in_game {
+        if !existing.is_empty() {
+            for entity in existing.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            last_text.clear();
+        }
+        return;
+    }
+
+    let mut sorted: Vec<Player> = players.iter().cloned().collect();
+    sorted.sort_by_key(|p| p.id.0);
+    let vineyards: Vec<Vineyard> = vineyards.iter().cloned().collect();
+    let structures: Vec<Structure> = structures.iter().cloned().collect();
+
+    let text = supply_text(&sorted, &vineyards, &structures, &decks, visitors.as_deref());
+    if *last_text == text && !existing.is_empty() {
+        return;
+    }
+    *last_text = text.clone();
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                left: Val::Px(0.0),
+                width: Val::Px(260.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.08, 0.12, 0.08, 0.92)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        SupplyPanel,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            text,
+            TextStyle {
+                font_size: 13.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use crate::systems::settings::UserSettings;
+
+pub const TEXT_SCALE_MIN: f32 = 0.8;
+pub const TEXT_SCALE_MAX: f32 = 2.0;
+pub const TEXT_SCALE_STEP: f32 = 0.1;
+
+// Font sizes as the spawning code wrote them, and what we last set after scaling
+#[derive(Component)]
+pub struct ScaledText {
+    pub base: Vec<f32>,
+    pub applied: Vec<f32>,
+}
+
+// Original height of a fixed-height UI node, so it can grow with its text and shrink back
+#[derive(Component)]
+pub struct ScaledNode {
+    pub height: Val,
+    pub min_height: Val,
+}
+
+pub fn current_text_scale(settings: &UserSettings) -> f32 {
+    settings.text_scale.clamp(TEXT_SCALE_MIN, TEXT_SCALE_MAX)
+}
+
+// Every Text in the app is scaled from its spawned size. Systems that later rewrite a
+// section's font size are picked up too: a size we didn't set becomes the new base.
+pub fn text_scale_system(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    mut texts: Query<(Entity, &mut Text, Option<&mut ScaledText>)>,
+    mut last_scale: Local<Option<f32>>,
+) {
+    let scale = current_text_scale(&settings);
+    let rescale = *last_scale != Some(scale);
+    *last_scale = Some(scale);
+
+    for (entity, mut text, scaled) in texts.iter_mut() {
+        let Some(mut scaled) = scaled else {
+            let base: Vec<f32> = text.sections.iter().map(|s| s.font_size).collect();
+            let applied: Vec<f32> = base.iter().map(|size| size * scale).collect();
+            for (section, size) in text.sections.iter_mut().zip(&applied) {
+                section.font_size = *size;
+            }
+            commands.entity(entity).insert(ScaledText { base, applied });
+            continue;
+        };
+
+        if !rescale && !text.is_changed() {
+            continue;
+        }
+
+        if text.sections.len() != scaled.base.len() {
+            scaled.base = text.sections.iter().map(|s| s.font_size).collect();
+            scaled.applied = scaled.base.clone();
+        }
+        for (i, section) in text.sections.iter().enumerate() {
+            if section.font_size != scaled.applied[i] {
+                scaled.base[i] = section.font_size;
+            }
+        }
+        scaled.applied = scaled.base.iter().map(|size| size * scale).collect();
+
+        // Only touch the Text when a size actually moves, so change detection settles
+        let needs_update = text.sections.iter().zip(&scaled.applied).any(|(s, size)| s.font_size != *size);
+        if needs_update {
+            for (section, size) in text.sections.iter_mut().zip(&scaled.applied) {
+                section.font_size = *size;
+            }
+        }
+    }
+}
+
+// Above 100% fixed-height panels and buttons turn their height into a minimum,
+// so larger text makes them reflow taller instead of clipping
+pub fn text_reflow_system(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    mut new_nodes: Query<(Entity, &mut Style), (Added<Node>, Without<ScaledNode>)>,
+    mut scaled_nodes: Query<(&mut Style, &ScaledNode)>,
+    mut last_scale: Local<Option<f32>>,
+) {
+    let scale = current_text_scale(&settings);
+    let grow = scale > 1.0;
+
+    for (entity, mut style) in new_nodes.iter_mut() {
+        if !matches!(style.height, Val::Px(_)) {
+            continue;
+        }
+        commands.entity(entity).insert(ScaledNode { height: style.height, min_height: style.min_height });
+        if grow {
+            style.min_height = style.height;
+            style.height = Val::Auto;
+        }
+    }
+
+    if *last_scale == Some(scale) {
+        return;
+    }
+    *last_scale = Some(scale);
+
+    for (mut style, original) in scaled_nodes.iter_mut() {
+        if grow {
+            style.min_height = original.height;
+            style.height = Val::Auto;
+        } else {
+            style.min_height = original.min_height;
+            style.height = original.height;
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use bevy::asset::LoadState;
+use crate::components::*;
+use crate::systems::settings::UserSettings;
+use crate::systems::clock::GameClock;
+use crate::systems::viewport::DESIGN_RESOLUTION;
+use crate::systems::render_layers::WorldLayer;
+
+const CROSSFADE_SECS: f32 = 1.2;
+
+pub struct SeasonBackground {
+    pub season: GameState,
+    pub path: &'static str,
+    // Used as a flat wash when the illustration is missing or still loading
+    pub tint: Color,
+    pub image: Handle<Image>,
+}
+
+// Active visual theme; board art is looked up per season
+#[derive(Resource)]
+pub struct BoardTheme {
+    pub name: &'static str,
+    pub backgrounds: Vec<SeasonBackground>,
+}
+
+impl Default for BoardTheme {
+    fn default() -> Self {
+        let background = |season, path, tint| SeasonBackground { season, path, tint, image: Handle::default() };
+        Self {
+            name: "classic",
+            backgrounds: vec![
+                background(GameState::Spring, "themes/classic/spring.png", Color::srgb(0.16, 0.22, 0.14)),
+                background(GameState::Summer, "themes/classic/summer.png", Color::srgb(0.26, 0.22, 0.12)),
+                background(GameState::Fall, "themes/classic/fall.png", Color::srgb(0.26, 0.14, 0.08)),
+                background(GameState::Winter, "themes/classic/winter.png", Color::srgb(0.12, 0.14, 0.22)),
+            ],
+        }
+    }
+}
+
+impl BoardTheme {
+    pub fn background(&self, season: &GameState) -> Option<&SeasonBackground> {
+        self.backgrounds.iter().find(|b| b.season == *season)
+    }
+}
+
+#[derive(Component)]
+pub struct BoardBackground {
+    pub season: GameState,
+    pub fade: Timer,
+}
+
+pub fn load_board_theme_system(asset_server: Res<AssetServer>, mut theme: ResMut<BoardTheme>) {
+    for background in theme.backgrounds.iter_mut() {
+        background.image = asset_server.load(background.path);
+    }
+    info!("🎨 Loaded board theme '{}'", theme.name);
+}
+
+fn art_ready(asset_server: &AssetServer, background: &SeasonBackground) -> bool {
+    matches!(asset_server.get_load_state(background.image.id()), Some(LoadState::Loaded))
+}
+
+fn background_sprite(asset_server: &AssetServer, background: &SeasonBackground, alpha: f32) -> Sprite {
+    let size = Some(DESIGN_RESOLUTION * 2.0);
+    if art_ready(asset_server, background) {
+        Sprite { color: Color::WHITE.with_alpha(alpha), custom_size: size, ..default() }
+    } else {
+        Sprite { color: background.tint.with_alpha(alpha), custom_size: size, ..default() }
+    }
+}
+
+// Spawns the new season's art over the old one and fades it in; performance mode swaps instantly
+pub fn season_background_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    theme: Res<BoardTheme>,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    time: Res<Time>,
+    mut backgrounds: Query<(Entity, &mut BoardBackground, &mut Sprite, &mut Handle<Image>)>,
+    mut shown: Local<Option<GameState>>,
+    mut layer: Local<u32>,
+) {
+    let season = current_state.get();
+
+    // Menus and scoring keep whichever season was last on screen
+    if let Some(background) = theme.background(season) {
+        if shown.as_ref() != Some(season) {
+            *shown = Some(season.clone());
+            *layer += 1;
+
+            let instant = settings.performance_mode || backgrounds.is_empty();
+            let mut fade = GameClock::once_secs(CROSSFADE_SECS);
+            if instant {
+                fade.tick(fade.duration());
+                for (entity, _, _, _) in backgrounds.iter() {
+                    commands.entity(entity).despawn();
+                }
+            }
+
+            let alpha = if instant { 1.0 } else { 0.0 };
+            let texture = if art_ready(&asset_server, background) { background.image.clone() } else { Handle::default() };
+            commands.spawn((
+                SpriteBundle {
+                    sprite: background_sprite(&asset_server, background, alpha),
+                    texture,
+                    // Newer seasons draw above the one fading out
+                    transform: Transform::from_xyz(0.0, 0.0, WorldLayer::Background.at((*layer % 100) as f32 * 0.01)),
+                    ..default()
+                },
+                BoardBackground { season: season.clone(), fade },
+            ));
+            return;
+        }
+    }
+
+    let mut faded_in = None;
+    for (entity, mut background, mut sprite, mut texture) in backgrounds.iter_mut() {
+        let Some(theme_bg) = theme.background(&background.season) else { continue };
+
+        // Pick up art that finished loading after the sprite was spawned
+        if art_ready(&asset_server, theme_bg) && *texture != theme_bg.image {
+            *texture = theme_bg.image.clone();
+            *sprite = background_sprite(&asset_server, theme_bg, background.fade.fraction());
+        }
+
+        if background.fade.finished() {
+            continue;
+        }
+        background.fade.tick(time.delta());
+        *sprite = background_sprite(&asset_server, theme_bg, background.fade.fraction());
+        if background.fade.finished() {
+            faded_in = Some(entity);
+        }
+    }
+
+    // Once the new background is opaque, the ones underneath can go
+    if let Some(newest) = faded_in {
+        for (entity, _, _, _) in backgrounds.iter() {
+            if entity != newest {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::clock::{GameClock, GameTimer};
+use crate::systems::keymap::*;
+use crate::systems::wake_up::{WakeUpChart, WakeUpCharts};
+use crate::systems::render_layers::UiLayer;
 
 #[derive(Component)]
 pub struct Tooltip {
@@ -27,7 +31,7 @@ impl Default for TooltipState {
     fn default() -> Self {
         Self {
             current_tooltip: None,
-            hover_timer: Timer::from_seconds(0.5, TimerMode::Once), // Show after 0.5s hover
+            hover_timer: GameClock::once(GameTimer::TooltipHover),
             mouse_position: Vec2::ZERO,
         }
     }
@@ -44,14 +48,14 @@ fn setup_action_tooltips(commands: &mut Commands) {
     // These would be positioned based on actual UI layout
     let action_tooltips = vec![
         (ActionSpace::DrawVine, "Draw a vine card from the deck. Vine cards are needed to plant vines in your vineyard fields."),
-        (ActionSpace::PlantVine, "Plant a vine card from your hand into an empty field. Costs lira based on vine type."),
+        (ActionSpace::PlantVine, "Plant a vine card from your hand into an empty field. Costs lira based on vine type. Bonus: plant a second vine."),
         (ActionSpace::BuildStructure, "Build a structure that provides ongoing benefits. Structures cost lira but give permanent advantages."),
-        (ActionSpace::GiveTour, "Gain 2 lira by giving tours to visitors. Tasting Room structure increases this bonus."),
+        (ActionSpace::GiveTour, "Gain 2 lira by giving tours to visitors. Tasting Room structure increases this bonus. Bonus: +1 lira."),
         (ActionSpace::SellGrapes, "Sell all your grapes for 1 lira each. Useful when you need quick money."),
         (ActionSpace::TrainWorker, "Pay 4 lira to gain an additional worker for future turns."),
         (ActionSpace::DrawWineOrder, "Draw a wine order card. These show what wines customers want and reward VP."),
-        (ActionSpace::Harvest, "Collect grapes from your planted vines. Each vine produces grapes equal to its value."),
-        (ActionSpace::MakeWine, "Convert grapes into wine. Can make regular wine, blush (red+white), or sparkling wine."),
+        (ActionSpace::Harvest, "Pick up to two planted fields and collect their grapes at the vine's value. Bonus: pick a third field."),
+        (ActionSpace::MakeWine, "Convert grapes into wine. Can make regular wine, blush (red+white), or sparkling wine. Bonus: make a second wine."),
         (ActionSpace::FillOrder, "Fulfill a wine order card for victory points and lira rewards."),
     ];
     
@@ -151,7 +155,7 @@ fn spawn_tooltip(commands: &mut Commands, text: &str, mouse_pos: Vec2) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.0, 0.0, 0.9)).into(),
-            z_index: ZIndex::Global(1000),
+            z_index: UiLayer::Tooltip.z_index(),
             ..default()
         },
         TooltipUI,
@@ -172,13 +176,15 @@ pub struct ContextualHelp;
 
 // Quick reference overlay
 pub fn quick_reference_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut commands: Commands,
     existing_reference: Query<Entity, With<QuickReference>>,
+    wake_up_charts: Res<WakeUpCharts>,
+    config: Res<GameConfig>,
 ) {
-    if keyboard.just_pressed(KeyCode::F2) {
+    if input.just_pressed(InputAction::QuickReference) {
         if existing_reference.is_empty() {
-            show_quick_reference(&mut commands);
+            show_quick_reference(&mut commands, &input.keymap, input.gamepad_connected(), wake_up_charts.active(), &config);
         } else {
             for entity in existing_reference.iter() {
                 commands.entity(entity).despawn_recursive();
@@ -187,22 +193,20 @@ pub fn quick_reference_system(
     }
 }
 
-fn show_quick_reference(commands: &mut Commands) {
-    let reference_text = 
-        "QUICK REFERENCE (F2 to close)\n\n\
-         🎮 CONTROLS:\n\
-         F1 - Contextual Help\n\
-         F2 - Quick Reference\n\
-         F5 - Save Game\n\
-         F9 - Load Game\n\
-         TAB - Statistics\n\
-         ESC - Settings\n\
-         Ctrl+Z - Undo\n\
-         ENTER - End Turn\n\n\
-         🏆 VICTORY:\n\
-         • First to 20 VP wins\n\
-         • Or highest VP after 7 years\n\
-         • Fulfill wine orders for VP\n\n\
+fn show_quick_reference(commands: &mut Commands, keymap: &Keymap, show_gamepad: bool, wake_up_chart: &WakeUpChart, config: &GameConfig) {
+    // Controls come from the live keymap so the help always matches the bindings
+    let close_key = keymap.binding(InputAction::QuickReference)
+        .map(|b| b.key_label())
+        .unwrap_or_default();
+    let mut reference_text = format!("QUICK REFERENCE ({} to close)\n\n🎮 CONTROLS:\n", close_key);
+    reference_text.push_str(&keymap.reference_lines(false, show_gamepad));
+    // Victory terms follow the configured game, so quick games read correctly
+    reference_text.push_str(&format!(
+        "\n🏆 VICTORY:\n• First to {} VP wins\n• Or highest VP after {} years\n",
+        config.target_victory_points, config.max_years,
+    ));
+    reference_text.push_str(
+        "• Fulfill wine orders for VP\n\n\
          ⚙️ STRUCTURES:\n\
          • Trellis: +1 vine value\n\
          • Irrigation: -1 vine cost\n\
@@ -213,19 +217,10 @@ fn show_quick_reference(commands: &mut Commands) {
          🍷 WINE TYPES:\n\
          • Regular: 1 grape = 1 wine\n\
          • Blush: 1 red + 1 white = 1 wine\n\
-         • Sparkling: 1 red + 1 white = 2 wine\n\n\
-         🌅 WAKE-UP BONUSES:\n\
-         1st: Draw vine card\n\
-         2nd: +1 lira\n\
-         3rd: No bonus\n\
-         4th: +1 lira\n\
-         5th: Draw wine order\n\
-         6th+: +1 victory point\n\n\
-         🎯 EXPANSIONS (if enabled):\n\
-         F6 - Toggle Tuscany\n\
-         F7 - Toggle Visitor Cards\n\
-         F8 - Toggle Advanced Boards\n\
-         V - Draw/Play Visitor Card";
+         • Sparkling: 1 red + 1 white = 2 wine\n\n");
+    reference_text.push_str(&format!("🌅 WAKE-UP BONUSES ({}):\n{}\n", wake_up_chart.name, wake_up_chart.reference_lines()));
+    reference_text.push_str("🎯 EXPANSIONS (if enabled):\n");
+    reference_text.push_str(&keymap.reference_lines(true, show_gamepad));
     
     commands.spawn((
         NodeBundle {
@@ -238,7 +233,7 @@ fn show_quick_reference(commands: &mut Commands) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.1, 0.1, 0.1, 0.95)).into(),
-            z_index: ZIndex::Global(600),
+            z_index: UiLayer::Screen.z_index(),
             ..default()
         },
         QuickReference,
@@ -319,6 +314,9 @@ fn show_card_info_panel(commands: &mut Commands, hand: &Hand) {
             order.victory_points,
             order.payout
         ));
+        if !order.effect_text().is_empty() {
+            info_text.push_str(&format!("     {}\n", order.effect_text()));
+        }
     }
     
     if hand.vine_cards.is_empty() && hand.wine_order_cards.is_empty() {
@@ -336,7 +334,7 @@ fn show_card_info_panel(commands: &mut Commands, hand: &Hand) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.2, 0.1, 0.0, 0.95)).into(),
-            z_index: ZIndex::Global(400),
+            z_index: UiLayer::Screen.z_index(),
             ..default()
         },
         CardInfoPanel,
@@ -457,12 +455,12 @@ pub fn get_rule_explanation(concept: &str) -> String {
 
 // Contextual help system
 pub fn contextual_help_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut commands: Commands,
     current_state: Res<State<GameState>>,
     existing_help: Query<Entity, With<ContextualHelp>>,
 ) {
-    if keyboard.just_pressed(KeyCode::F1) {
+    if input.just_pressed(InputAction::ContextualHelp) {
         if existing_help.is_empty() {
             show_contextual_help(&mut commands, current_state.get());
         } else {
@@ -483,6 +481,20 @@ fn show_contextual_help(commands: &mut Commands, game_state: &GameState) {
              • TAB - View statistics\n\
              • F1 - Toggle this help"
         }
+        GameState::Lobby => {
+            "ONLINE LOBBY\n\n\
+             • Pick a seat and colour, then press Ready\n\
+             • Type to chat, ENTER to send\n\
+             • The host sets the table and starts the game\n\
+             • F1 - Toggle help"
+        }
+        GameState::Gallery => {
+            "CARD GALLERY\n\n\
+             • Type to search card names and rules\n\
+             • Buttons show one kind of card\n\
+             • Ctrl+G - Back to the main menu\n\
+             • F1 - Toggle help"
+        }
         GameState::Spring => {
             "SPRING PHASE - Wake-up Order\n\n\
              Choose when to wake up (1-7):\n\
@@ -501,7 +513,7 @@ fn show_contextual_help(commands: &mut Commands, game_state: &GameState) {
              • Sell Grapes - Convert grapes to lira\n\
              • Train Worker - Gain extra worker\n\n\
              • Click action spaces or use buttons\n\
-             • ENTER - End turn\n\
+             • End Turn button (ENTER) - End turn\n\
              • F1 - Toggle help"
         }
         GameState::Winter => {
@@ -512,7 +524,7 @@ fn show_contextual_help(commands: &mut Commands, game_state: &GameState) {
              • Make Wine - Convert grapes to wine\n\
              • Fill Order - Complete orders for VP\n\n\
              • Focus on fulfilling wine orders for VP\n\
-             • ENTER - End turn\n\
+             • End Turn button (ENTER) - End turn\n\
              • F1 - Toggle help"
         }
         GameState::Fall => {
@@ -547,7 +559,7 @@ fn show_contextual_help(commands: &mut Commands, game_state: &GameState) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.2, 0.4, 0.95)).into(),
-            z_index: ZIndex::Global(500),
+            z_index: UiLayer::Tips.z_index(),
             ..default()
         },
         ContextualHelp,
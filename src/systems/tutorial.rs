@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use crate::components::*;
+use crate::systems::input::PassEvent;
+use crate::systems::render_layers::UiLayer;
 
 #[derive(Resource, Default)]
 pub struct TutorialState {
@@ -46,6 +48,7 @@ pub struct TutorialStep {
 pub enum TutorialAction {
     ClickUI(String),           // Click specific UI element
     PressKey(KeyCode),         // Press specific key
+    EndTurn,                   // End the turn from the End Turn button or key
     PlaceWorker(ActionSpace),  // Place worker on action space
     ViewHelp,                  // Open help system
     CompletePhase,             // Finish current phase
@@ -140,7 +143,7 @@ fn show_tutorial_intro(commands: &mut Commands, progress: &TutorialProgress) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.0, 0.0, 0.8)).into(),
-            z_index: ZIndex::Global(1000),
+            z_index: UiLayer::Tutorial.z_index(),
             ..default()
         },
         TutorialUI,
@@ -174,7 +177,9 @@ pub fn tutorial_guidance_system(
     workers: Query<&Worker>,
     turn_order: Res<TurnOrder>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    (mut pass_events, players): (EventReader<PassEvent>, Query<&Player>),
 ) {
+    let ended_turn = pass_events.read().any(|e| players.iter().any(|p| p.id == e.player && !p.is_ai));
     if !tutorial_state.active || tutorial_state.skip_tutorial {
         return;
     }
@@ -189,7 +194,7 @@ pub fn tutorial_guidance_system(
         show_tutorial_step(&mut commands, &step);
         
         // Check if step is completed
-        if check_step_completion(&step, &workers, &turn_order, &keyboard, current_state.get()) {
+        if check_step_completion(&step, &workers, &turn_order, &keyboard, ended_turn, current_state.get()) {
             tutorial_state.completed_steps.push(step.id);
             tutorial_state.current_step += 1;
             
@@ -240,8 +245,8 @@ fn get_tutorial_step(step_num: usize, game_state: &GameState) -> Option<Tutorial
         5 if matches!(game_state, GameState::Summer) => Some(TutorialStep {
             id: 5,
             title: "End Your Turn".to_string(),
-            description: "You've used your workers. Press ENTER to end your turn and let other players act.".to_string(),
-            action_required: TutorialAction::PressKey(KeyCode::Enter),
+            description: "You've used your workers. Click End Turn under the actions (or press ENTER) to let other players act.".to_string(),
+            action_required: TutorialAction::EndTurn,
             highlight_element: None,
             completion_message: "Good! Your turn is over. The game will continue to Fall phase.".to_string(),
         }),
@@ -317,7 +322,7 @@ fn show_tutorial_step(commands: &mut Commands, step: &TutorialStep) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.3, 0.0, 0.95)).into(),
-            z_index: ZIndex::Global(800),
+            z_index: UiLayer::Tutorial.z_index(),
             ..default()
         },
         TutorialUI,
@@ -339,10 +344,12 @@ fn check_step_completion(
     workers: &Query<&Worker>,
     turn_order: &TurnOrder,
     keyboard: &ButtonInput<KeyCode>,
+    ended_turn: bool,
     game_state: &GameState,
 ) -> bool {
     match &step.action_required {
         TutorialAction::PressKey(key) => keyboard.just_pressed(*key),
+        TutorialAction::EndTurn => ended_turn,
         TutorialAction::PlaceWorker(action) => {
             if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
                 workers.iter().any(|w| w.owner == *current_player_id && w.placed_at == Some(*action))
@@ -371,7 +378,7 @@ fn show_step_completion(commands: &mut Commands, message: &str) {
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.6, 0.0, 0.9)).into(),
-            z_index: ZIndex::Global(900),
+            z_index: UiLayer::Tutorial.z_index(),
             ..default()
         },
         TutorialUI,
@@ -407,7 +414,7 @@ fn complete_tutorial(tutorial_state: &mut TutorialState, commands: &mut Commands
                 ..default()
             },
             background_color: Color::from(Srgba::new(0.0, 0.5, 0.0, 0.8)).into(),
-            z_index: ZIndex::Global(1000),
+            z_index: UiLayer::Tutorial.z_index(),
             ..default()
         },
         TutorialUI,
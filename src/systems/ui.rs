@@ -1,21 +1,42 @@
 use bevy::prelude::*;
 use crate::components::*;
 use crate::systems::balance::*;
+use crate::systems::statistics::GameStatistics;
+use crate::systems::first_game::RulesPreset;
+use crate::systems::settings::UserSettings;
+use crate::systems::action_bar::*;
+use crate::systems::estate::EstateMatSurface;
+use crate::systems::dashboard_sections::{spawn_dashboard_section, DashboardSection};
+use crate::systems::end_turn::{EndTurnButton, EndTurnLabel};
+use crate::systems::rng::{seed_label, SeedEntry};
+use crate::systems::save::continue_label;
+use crate::systems::keymap::{BoundInput, InputAction};
+use crate::systems::render_layers::UiLayer;
 
 const YELLOW: Srgba = Srgba::new(1.0, 1.0, 0.0, 1.0);
 const GOLD: Srgba = Srgba::new(1.0, 0.84, 0.0, 1.0);
 
 pub fn main_menu_system(
     keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut next_state: ResMut<NextState<GameState>>,
     mut commands: Commands,
     mut config: ResMut<GameConfig>,
+    seed_entry: Res<SeedEntry>,
     text_query: Query<Entity, With<PhaseText>>, // Changed query
+    mut game_settings: ResMut<GameSettings>,
+    mut selected_seat: Local<u8>,
 ) {
+    *selected_seat = (*selected_seat).min(config.player_count.saturating_sub(1));
+
     if text_query.is_empty() {
+        let key = |action| input.keymap.binding(action).map(|b| b.key_label()).unwrap_or_default();
         commands.spawn((
             TextBundle::from_section(
-                "VITICULTURE - Enhanced Edition\n\nPress SPACE to Start Game\nPress 1-4 to set player count\nPress A to cycle AI count\nPress C to view player cards in-game",
+                format!(
+                    "VITICULTURE - Enhanced Edition\n\nPress SPACE to Start Game\nPress 1-6 to set player count\nPress A to cycle AI count\nPress {} to pick a seat, {} for its AI level, {} for its handicap\nPress C to view player cards in-game\nPress Ctrl+G to browse the card gallery",
+                    key(InputAction::NextSeat), key(InputAction::SeatDifficulty), key(InputAction::SeatHandicap),
+                ),
                 TextStyle {
                     font_size: 28.0,
                     color: Color::WHITE,
@@ -32,8 +53,10 @@ pub fn main_menu_system(
         
         commands.spawn((
             TextBundle::from_section(
-                format!("Current Setup: {} players ({} AI)", 
-                       config.player_count, config.ai_count),
+                format!("Current Setup: {} players ({} AI)\n{}\n{}{}", 
+                       config.player_count, config.ai_count, seat_lines(&config, &game_settings, *selected_seat),
+                       seed_label(&config, &seed_entry, &input.keymap),
+                       continue_label(&input.keymap).map(|label| format!("\n{}", label)).unwrap_or_default()),
                 TextStyle {
                     font_size: 18.0,
                     color: Color::srgb(0.8, 0.8, 0.8),
@@ -41,7 +64,7 @@ pub fn main_menu_system(
                 },
             ).with_style(Style {
                 position_type: PositionType::Absolute,
-                top: Val::Px(380.0),
+                top: Val::Px(420.0),
                 left: Val::Px(50.0),
                 ..default()
             }),
@@ -49,6 +72,11 @@ pub fn main_menu_system(
         ));
     }
     
+    // Digits belong to the seed while one is being typed
+    if seed_entry.editing {
+        return;
+    }
+    
     // Player count selection
     if keyboard.just_pressed(KeyCode::Digit1) {
         config.player_count = 1;
@@ -66,6 +94,15 @@ pub fn main_menu_system(
         config.player_count = 4;
         config.ai_count = config.ai_count.min(3);
         clear_menu_text(&mut commands, &text_query);
+    } else if keyboard.just_pressed(KeyCode::Digit5) {
+        // 5-6 players open the third column of every action
+        config.player_count = 5;
+        config.ai_count = config.ai_count.min(4);
+        clear_menu_text(&mut commands, &text_query);
+    } else if keyboard.just_pressed(KeyCode::Digit6) {
+        config.player_count = 6;
+        config.ai_count = config.ai_count.min(5);
+        clear_menu_text(&mut commands, &text_query);
     }
     
     // AI count adjustment
@@ -74,6 +111,25 @@ pub fn main_menu_system(
         clear_menu_text(&mut commands, &text_query);
     }
     
+    // Per-seat difficulty and handicap, kept in GameSettings for the next session
+    if input.just_pressed(InputAction::NextSeat) {
+        *selected_seat = (*selected_seat + 1) % config.player_count.max(1);
+        clear_menu_text(&mut commands, &text_query);
+    }
+    // The keymap keeps these apart from Ctrl+D and Ctrl+H (diagnostics and past games)
+    let seat_is_ai = *selected_seat >= config.player_count - config.ai_count;
+    if input.just_pressed(InputAction::SeatDifficulty) && seat_is_ai {
+        game_settings.seat_mut(*selected_seat).cycle_difficulty();
+        game_settings.save();
+        clear_menu_text(&mut commands, &text_query);
+    }
+    if input.just_pressed(InputAction::SeatHandicap) {
+        let seat = game_settings.seat_mut(*selected_seat);
+        seat.handicap = !seat.handicap;
+        game_settings.save();
+        clear_menu_text(&mut commands, &text_query);
+    }
+    
     if keyboard.just_pressed(KeyCode::Space) {
         for entity in text_query.iter() {
             commands.entity(entity).despawn();
@@ -82,6 +138,18 @@ pub fn main_menu_system(
     }
 }
 
+// One line per seat, AI seats last as at setup, with the selected one marked
+fn seat_lines(config: &GameConfig, game_settings: &GameSettings, selected: u8) -> String {
+    (0..config.player_count)
+        .map(|seat| {
+            let is_ai = seat >= config.player_count - config.ai_count;
+            let marker = if seat == selected { "▶" } else { " " };
+            format!("{} Seat {}: {}", marker, seat + 1, game_settings.seat(seat).label(is_ai))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn clear_menu_text(commands: &mut Commands, text_query: &Query<Entity, With<PhaseText>>) {
     for entity in text_query.iter() {
         commands.entity(entity).despawn();
@@ -101,8 +169,8 @@ pub fn setup_ui(commands: &mut Commands) {
         },
         UIPanel,
     )).with_children(|parent| {
-        // Top status bar
-        parent.spawn(NodeBundle {
+        // Top status bar, dressed in the selected estate mat
+        parent.spawn((NodeBundle {
             style: Style {
                 width: Val::Percent(100.0),
                 height: Val::Px(80.0),
@@ -113,7 +181,7 @@ pub fn setup_ui(commands: &mut Commands) {
             },
             background_color: Color::from(Srgba::new(0.2, 0.2, 0.2, 0.9)).into(),
             ..default()
-        }).with_children(|status_bar| {
+        }, EstateMatSurface)).with_children(|status_bar| {
             status_bar.spawn((
                 TextBundle::from_section(
                     "Game Starting...",
@@ -126,6 +194,64 @@ pub fn setup_ui(commands: &mut Commands) {
                 GameStatusText,
             ));
             
+            // Year countdown with progress bar and optional pacing hint
+            status_bar.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            }).with_children(|year_area| {
+                year_area.spawn((
+                    TextBundle::from_section(
+                        "Year 1 of 7",
+                        TextStyle {
+                            font_size: 16.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    YearCountdownText,
+                ));
+                
+                year_area.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(140.0),
+                        height: Val::Px(8.0),
+                        margin: UiRect::vertical(Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::from(Srgba::new(0.35, 0.35, 0.35, 1.0)).into(),
+                    ..default()
+                }).with_children(|bar| {
+                    bar.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: Color::from(Srgba::new(0.6, 0.2, 0.4, 1.0)).into(),
+                            ..default()
+                        },
+                        YearProgressFill,
+                    ));
+                });
+                
+                year_area.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 12.0,
+                            color: Color::from(Srgba::new(0.8, 0.8, 0.6, 1.0)),
+                            ..default()
+                        },
+                    ),
+                    PacingHintText,
+                ));
+            });
+            
             status_bar.spawn((
                 TextBundle::from_section(
                     "Player 1's Turn",
@@ -155,6 +281,15 @@ pub fn setup_ui(commands: &mut Commands) {
     });
 }
 
+#[derive(Component)]
+pub struct YearCountdownText;
+
+#[derive(Component)]
+pub struct YearProgressFill;
+
+#[derive(Component)]
+pub struct PacingHintText;
+
 // Add a marker component for action board elements
 #[derive(Component)]
 pub struct ActionBoardElement;
@@ -176,27 +311,21 @@ fn setup_action_board(parent: &mut ChildBuilder) {
         },
         ActionBoardElement,
     )).with_children(|action_area| {
-        // Summer Actions Header
-        action_area.spawn(TextBundle::from_section(
-            "SUMMER ACTIONS",
-            TextStyle {
-                font_size: 18.0,
-                color: Color::from(Srgba::new(1.0, 1.0, 0.5, 1.0)),
-                ..default()
-            },
+        // Header and buttons are relabelled/collapsed per season by contextual_action_bar_system
+        action_area.spawn((
+            TextBundle::from_section(
+                "SUMMER ACTIONS",
+                TextStyle {
+                    font_size: 18.0,
+                    color: Color::from(Srgba::new(1.0, 1.0, 0.5, 1.0)),
+                    ..default()
+                },
+            ),
+            ActionBarHeader,
         ));
         
-        // Summer actions
-        let summer_actions = [
-            ("Draw Vine", ActionSpace::DrawVine, false),
-            ("Plant Vine (+1)", ActionSpace::PlantVine, true),
-            ("Build Structure", ActionSpace::BuildStructure, false),
-            ("Give Tour (+1)", ActionSpace::GiveTour, true),
-            ("Sell Grapes", ActionSpace::SellGrapes, false),
-            ("Train Worker", ActionSpace::TrainWorker, false),
-        ];
-        
-        for (label, action, _has_bonus) in summer_actions {
+        for (label, action) in ACTION_BAR_ENTRIES {
+            let summer = is_summer_button(action);
             action_area.spawn((
                 ButtonBundle {
                     style: Style {
@@ -205,9 +334,10 @@ fn setup_action_board(parent: &mut ChildBuilder) {
                         margin: UiRect::all(Val::Px(5.0)),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
+                        display: if summer { Display::Flex } else { Display::None },
                         ..default()
                     },
-                    background_color: Color::from(Srgba::new(0.8, 0.8, 0.2, 0.8)).into(),
+                    background_color: Color::from(if summer { SUMMER_BUTTON_COLOR } else { WINTER_BUTTON_COLOR }).into(),
                     ..default()
                 },
                 ActionButton { action },
@@ -218,7 +348,7 @@ fn setup_action_board(parent: &mut ChildBuilder) {
                         label.to_string(),
                         TextStyle {
                             font_size: 16.0,
-                            color: Color::BLACK,
+                            color: if summer { Color::BLACK } else { Color::WHITE },
                             ..default()
                         },
                     ),
@@ -228,57 +358,36 @@ fn setup_action_board(parent: &mut ChildBuilder) {
                 });
             });
         }
-        
-        // Winter Actions Header
-        action_area.spawn(TextBundle::from_section(
-            "WINTER ACTIONS",
-            TextStyle {
-                font_size: 18.0,
-                color: Color::from(Srgba::new(0.5, 0.5, 1.0, 1.0)),
+
+        // End Turn: label and colour are kept current by end_turn_status_system
+        action_area.spawn((
+            ButtonBundle {
+                style: Style {
+                    min_width: Val::Px(200.0),
+                    padding: UiRect::horizontal(Val::Px(12.0)),
+                    height: Val::Px(36.0),
+                    margin: UiRect::top(Val::Px(15.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::srgb(0.45, 0.2, 0.2).into(),
                 ..default()
             },
-        ));
-        
-        // Winter actions
-        let winter_actions = [
-            ("Draw Wine Order", ActionSpace::DrawWineOrder, false),
-            ("Harvest (+1)", ActionSpace::Harvest, true),
-            ("Make Wine (+1)", ActionSpace::MakeWine, true),
-            ("Fill Order", ActionSpace::FillOrder, false),
-        ];
-        
-        for (label, action, _has_bonus) in winter_actions {
-            action_area.spawn((
-                ButtonBundle {
-                    style: Style {
-                        width: Val::Px(200.0),
-                        height: Val::Px(40.0),
-                        margin: UiRect::all(Val::Px(5.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
+            EndTurnButton,
+        )).with_children(|button| {
+            button.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::WHITE,
                         ..default()
                     },
-                    background_color: Color::from(Srgba::new(0.2, 0.2, 0.8, 0.8)).into(),
-                    ..default()
-                },
-                ActionButton { action },
-            )).with_children(|button| {
-                // Store text directly in the button - this should persist
-                button.spawn(TextBundle {
-                    text: Text::from_section(
-                        label.to_string(),
-                        TextStyle {
-                            font_size: 16.0,
-                            color: Color::WHITE,
-                            ..default()
-                        },
-                    ),
-                    // Force the text to stay visible
-                    visibility: Visibility::Inherited,
-                    ..default()
-                });
-            });
-        }
+                ),
+                EndTurnLabel,
+            ));
+        });
     });
 }
 
@@ -364,6 +473,11 @@ fn setup_player_dashboards(parent: &mut ChildBuilder) {
                         ..default()
                     },
                 ));
+
+                // Details stay folded until the player opens them
+                for section in DashboardSection::ALL {
+                    spawn_dashboard_section(dashboard, PlayerId(i), section);
+                }
                 
                 dashboard.spawn(NodeBundle {
                     style: Style {
@@ -381,12 +495,18 @@ fn setup_player_dashboards(parent: &mut ChildBuilder) {
 }
 
 pub fn update_ui_system(
-    mut status_query: Query<&mut Text, (With<GameStatusText>, Without<TurnIndicator>)>,
-    mut turn_query: Query<&mut Text, (With<TurnIndicator>, Without<GameStatusText>)>,
+    mut status_query: Query<&mut Text, (With<GameStatusText>, Without<TurnIndicator>, Without<YearCountdownText>, Without<PacingHintText>)>,
+    mut turn_query: Query<&mut Text, (With<TurnIndicator>, Without<GameStatusText>, Without<YearCountdownText>, Without<PacingHintText>)>,
+    mut year_query: Query<&mut Text, (With<YearCountdownText>, Without<PacingHintText>)>,
+    mut hint_query: Query<&mut Text, With<PacingHintText>>,
+    mut progress_query: Query<&mut Style, With<YearProgressFill>>,
     players: Query<&Player>,
     turn_order: Res<TurnOrder>,
     current_state: Res<State<GameState>>,
     config: Res<GameConfig>,
+    stats: Res<GameStatistics>,
+    settings: Res<UserSettings>,
+    preset: Res<RulesPreset>,
 ) {
     if let Ok(mut status_text) = status_query.get_single_mut() {
         let mut leading_player = "None";
@@ -394,17 +514,34 @@ pub fn update_ui_system(
         
         for player in players.iter() {
             if player.victory_points > highest_vp {
-                highest_vp = player.victory_points;
+                highest_vp = player.victory_points.get();
                 leading_player = &player.name;
             }
         }
         
         status_text.sections[0].value = format!(
-            "Year {} | Leader: {} ({} VP) | Target: {} VP",
-            config.current_year, leading_player, highest_vp, config.target_victory_points
+            "Leader: {} ({} VP) | Target: {} VP",
+            leading_player, highest_vp, config.target_victory_points
         );
     }
     
+    let year = config.current_year.min(config.max_years);
+    if let Ok(mut year_text) = year_query.get_single_mut() {
+        year_text.sections[0].value = format!("Year {} of {}", year, config.max_years);
+    }
+    
+    if let Ok(mut fill) = progress_query.get_single_mut() {
+        let progress = year as f32 / config.max_years.max(1) as f32;
+        fill.width = Val::Percent(progress * 100.0);
+    }
+    
+    if let Ok(mut hint_text) = hint_query.get_single_mut() {
+        // Quick games are paced against other quick games only
+        hint_text.sections[0].value = stats.bucket(preset.is_quick_game())
+            .and_then(|bucket| pacing_hint(&players, &turn_order, &config, bucket, &settings))
+            .unwrap_or_default();
+    }
+    
     if let Ok(mut turn_text) = turn_query.get_single_mut() {
         if let Some(current_player_id) = turn_order.players.get(turn_order.current_player) {
             let phase = match current_state.get() {
@@ -414,11 +551,39 @@ pub fn update_ui_system(
                 GameState::Fall => "Fall",
                 _ => "Game",
             };
-            turn_text.sections[0].value = format!("{} - Player {}'s Turn", phase, current_player_id.0 + 1);
+            let mut text = format!("{} - Player {}'s Turn", phase, current_player_id.0 + 1);
+            if !turn_order.passed.is_empty() {
+                let passed: Vec<String> = turn_order.passed.iter().map(|id| format!("P{}", id.0 + 1)).collect();
+                text.push_str(&format!(" (passed: {})", passed.join(", ")));
+            }
+            turn_text.sections[0].value = text;
         }
     }
 }
 
+// Compares the current human player's VP with how past winners were doing at this point
+fn pacing_hint(
+    players: &Query<&Player>,
+    turn_order: &TurnOrder,
+    config: &GameConfig,
+    stats: &GameStatistics,
+    settings: &UserSettings,
+) -> Option<String> {
+    if !settings.show_pacing_hints {
+        return None;
+    }
+    
+    let completed_year = config.current_year.checked_sub(1).filter(|y| *y > 0)?;
+    let typical_vp = stats.typical_winner_vp(completed_year)?;
+    let current_player_id = turn_order.players.get(turn_order.current_player)?;
+    let player = players.iter().find(|p| p.id == *current_player_id && !p.is_ai)?;
+    
+    Some(format!(
+        "Most winners had {} VP after year {} (you: {})",
+        typical_vp, completed_year, player.victory_points
+    ))
+}
+
 pub fn ui_game_over_system(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
@@ -537,7 +702,7 @@ pub fn display_player_cards_system(
                         ..default()
                     },
                     background_color: Color::srgb(0.1, 0.1, 0.1).with_alpha(0.95).into(),
-                    z_index: ZIndex::Global(800),
+                    z_index: UiLayer::Screen.z_index(),
                     ..default()
                 },
                 PlayerCardsUI,
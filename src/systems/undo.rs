@@ -4,24 +4,90 @@
 
 use bevy::prelude::*;
 use crate::components::*;
+use crate::systems::input::ActionRequested;
+use crate::systems::keymap::*;
+use crate::systems::settings::UserSettings;
+use crate::systems::undo_diff::{diff_snapshots, UndoDiff};
+
+// Default placements kept per season; the oldest falls off first
+pub const MAX_UNDO_HISTORY: usize = 20;
 
 #[derive(Resource)]
 pub struct UndoSystem {
     pub snapshots: Vec<GameSnapshot>,
+    // States undone away from, newest last; a fresh placement discards them
+    pub redo: Vec<GameSnapshot>,
     pub max_snapshots: usize,
     pub undo_available: bool,
+    // Bumped on every undo so in-flight work started before it can tell it is stale
+    pub undo_count: u32,
 }
 
 impl Default for UndoSystem {
     fn default() -> Self {
         Self {
             snapshots: Vec::new(),
-            max_snapshots: 5, // Keep last 5 actions
+            redo: Vec::new(),
+            max_snapshots: MAX_UNDO_HISTORY,
             undo_available: false,
+            undo_count: 0,
+        }
+    }
+}
+
+impl UndoSystem {
+    // Taken just before a placement resolves. Branching off an undone line drops its redo states
+    pub fn record(&mut self, snapshot: GameSnapshot) {
+        self.redo.clear();
+        self.push_undo(snapshot);
+    }
+
+    // The state to roll back to; `current` becomes redoable
+    pub fn undo(&mut self, current: GameSnapshot) -> Option<GameSnapshot> {
+        let snapshot = self.snapshots.pop()?;
+        self.redo.push(current);
+        self.undo_available = !self.snapshots.is_empty();
+        self.undo_count += 1;
+        Some(snapshot)
+    }
+
+    pub fn redo(&mut self, current: GameSnapshot) -> Option<GameSnapshot> {
+        let snapshot = self.redo.pop()?;
+        self.push_undo(current);
+        self.undo_count += 1;
+        Some(snapshot)
+    }
+
+    // Drops the oldest steps when the limit shrinks below the current history
+    pub fn set_max_snapshots(&mut self, max: usize) {
+        self.max_snapshots = max.max(1);
+        let excess = self.snapshots.len().saturating_sub(self.max_snapshots);
+        self.snapshots.drain(..excess);
+        self.undo_available = !self.snapshots.is_empty();
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+        self.redo.clear();
+        self.undo_available = false;
+    }
+
+    fn push_undo(&mut self, snapshot: GameSnapshot) {
+        self.snapshots.push(snapshot);
+        if self.snapshots.len() > self.max_snapshots {
+            self.snapshots.remove(0);
         }
+        self.undo_available = true;
     }
 }
 
+// Cards left the draw piles between the two states, so rolling back would let a player
+// redo the turn knowing what they drew
+pub fn reveals_information(earlier: &GameSnapshot, later: &GameSnapshot) -> bool {
+    earlier.decks.vine_deck.len() != later.decks.vine_deck.len()
+        || earlier.decks.wine_order_deck.len() != later.decks.wine_order_deck.len()
+}
+
 #[derive(Clone)]
 pub struct GameSnapshot {
     pub players: Vec<PlayerSnapshot>,
@@ -30,27 +96,30 @@ pub struct GameSnapshot {
     pub workers: Vec<WorkerSnapshot>,
     pub turn_order: TurnOrderSnapshot,
     pub action_spaces: Vec<ActionSpaceSnapshot>,
-    pub timestamp: f32,
+    pub trackers: Vec<ResidualPaymentTracker>,
+    pub decks: CardDecks,
 }
 
 #[derive(Clone)]
 pub struct PlayerSnapshot {
     pub id: u8,
     pub name: String,
-    pub victory_points: u8,
-    pub lira: u8,
+    pub victory_points: VicPoints,
+    pub lira: Lira,
     pub workers: u8,
+    pub grande_worker_available: bool,
     pub is_ai: bool, // ADDED: Missing field
 }
 
 #[derive(Clone)]
 pub struct VineyardSnapshot {
     pub owner_id: u8,
-    pub red_grapes: u8,
-    pub white_grapes: u8,
-    pub red_wine: u8,
-    pub white_wine: u8,
-    pub lira: u8,
+    pub red_grapes: ValueTrack,
+    pub white_grapes: ValueTrack,
+    pub red_wine: ValueTrack,
+    pub white_wine: ValueTrack,
+    pub vintages: Vec<Vintage>,
+    pub lira: Lira,
     pub fields: [VineyardFieldSnapshot; 9], // FIXED: Use VineyardFieldSnapshot instead of Option<(bool, u8)>
 }
 
@@ -65,12 +134,14 @@ pub struct VineyardFieldSnapshot {
 #[derive(Clone)]
 pub struct HandSnapshot {
     pub owner_id: u8,
-    pub vine_card_count: usize,
-    pub wine_order_count: usize,
+    pub vine_cards: Vec<VineCard>,
+    pub wine_order_cards: Vec<WineOrderCard>,
 }
 
 #[derive(Clone)]
 pub struct WorkerSnapshot {
+    // Workers are restored onto the same entities; ones trained since are despawned
+    pub entity: Entity,
     pub owner_id: u8,
     pub is_grande: bool,
     pub placed_at: Option<u8>, // ActionSpace as u8
@@ -81,104 +152,117 @@ pub struct WorkerSnapshot {
 #[derive(Clone)]
 pub struct TurnOrderSnapshot {
     pub current_player: usize,
+    pub passed: Vec<PlayerId>,
 }
 
 #[derive(Clone)]
 pub struct ActionSpaceSnapshot {
     pub action: u8,
+    pub column: usize,
     pub occupied_by: Option<u8>,
     pub bonus_worker_slot: Option<u8>,
+    pub bonus_chit: Option<BonusChit>,
 }
 
+// Snapshot just before each placement resolves. History only spans the current season
 pub fn create_snapshot_system(
     mut undo_system: ResMut<UndoSystem>,
+    mut place_events: EventReader<ActionRequested>,
+    current_state: Res<State<GameState>>,
     players: Query<&Player>,
     vineyards: Query<&Vineyard>,
     hands: Query<&Hand>,
-    workers: Query<&Worker>,
+    workers: Query<(Entity, &Worker)>,
     turn_order: Res<TurnOrder>,
-    action_spaces: Query<&ActionSpaceSlot>,
-    time: Res<Time>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    (action_spaces, trackers, decks): (Query<&ActionSpaceSlot>, Query<&ResidualPaymentTracker>, Res<CardDecks>),
 ) {
-    // Create snapshot before each player action (when ENTER is pressed)
-    if keyboard.just_pressed(KeyCode::Enter) || keyboard.just_pressed(KeyCode::Space) {
-        let snapshot = create_game_snapshot(
-            &players, &vineyards, &hands, &workers,
-            &turn_order, &action_spaces, time.elapsed_seconds()
-        );
-        
-        undo_system.snapshots.push(snapshot);
-        
-        // Keep only the last N snapshots
-        if undo_system.snapshots.len() > undo_system.max_snapshots {
-            undo_system.snapshots.remove(0);
-        }
-        
-        undo_system.undo_available = !undo_system.snapshots.is_empty();
+    if current_state.is_changed() {
+        undo_system.clear();
+    }
+    if place_events.read().count() == 0 {
+        return;
     }
+
+    undo_system.record(create_game_snapshot(
+        players.iter(), vineyards.iter(), hands.iter(), workers.iter(),
+        &turn_order, action_spaces.iter(), trackers.iter(), &decks,
+    ));
 }
 
+// Ctrl+Z steps back one placement, Ctrl+Y steps forward again
 pub fn undo_action_system(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: BoundInput,
     mut undo_system: ResMut<UndoSystem>,
+    settings: Res<UserSettings>,
     mut commands: Commands,
-    entities: Query<Entity, (Without<Camera>, Without<Window>)>,
     mut turn_order: ResMut<TurnOrder>,
-    time: Res<Time>,
+    mut decks: ResMut<CardDecks>,
+    (mut players, mut vineyards, mut hands, mut workers, mut action_spaces, mut trackers): (
+        Query<&mut Player>, Query<&mut Vineyard>, Query<&mut Hand>, Query<(Entity, &mut Worker)>,
+        Query<&mut ActionSpaceSlot>, Query<&mut ResidualPaymentTracker>,
+    ),
+    mut undo_diff: ResMut<UndoDiff>,
 ) {
-    // Undo with Ctrl+Z
-    if (keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight)) && 
-       keyboard.just_pressed(KeyCode::KeyZ) {
-        
-        if let Some(snapshot) = undo_system.snapshots.pop() {
-            // Only allow undo within 30 seconds of the action
-            if time.elapsed_seconds() - snapshot.timestamp < 30.0 {
-                info!("Undoing last action");
-                
-                // Clear current game state
-                for entity in entities.iter() {
-                    commands.entity(entity).despawn();
-                }
-                
-                // Restore from snapshot
-                restore_from_snapshot(&mut commands, &snapshot, &mut turn_order);
-                
-                undo_system.undo_available = !undo_system.snapshots.is_empty();
-            } else {
-                info!("Undo expired (too much time passed)");
-                undo_system.snapshots.push(snapshot); // Put it back
-            }
-        } else {
-            info!("No actions to undo");
-        }
+    let undo = input.just_pressed(InputAction::Undo);
+    let redo = input.just_pressed(InputAction::Redo);
+    if !undo && !redo {
+        return;
+    }
+
+    let current = create_game_snapshot(
+        players.iter(), vineyards.iter(), hands.iter(), workers.iter(),
+        &turn_order, action_spaces.iter(), trackers.iter(), &decks,
+    );
+
+    if undo && settings.house_rule_no_undo_after_draw
+        && undo_system.snapshots.last().is_some_and(|s| reveals_information(s, &current)) {
+        info!("Undo locked: cards were drawn since that action");
+        return;
     }
+
+    let target = if undo { undo_system.undo(current.clone()) } else { undo_system.redo(current.clone()) };
+    let Some(snapshot) = target else {
+        info!("Nothing to {}", if undo { "undo" } else { "redo" });
+        return;
+    };
+    info!("{} last action", if undo { "Undoing" } else { "Redoing" });
+
+    // Diff against the state being thrown away so the overlay can show what changed
+    undo_diff.show(diff_snapshots(&current, &snapshot));
+
+    restore_snapshot(
+        &snapshot, &mut commands, &mut players, &mut vineyards, &mut hands, &mut workers,
+        &mut action_spaces, &mut trackers, &mut decks, &mut turn_order,
+    );
 }
 
-fn create_game_snapshot(
-    players: &Query<&Player>,
-    vineyards: &Query<&Vineyard>,
-    hands: &Query<&Hand>,
-    workers: &Query<&Worker>,
+fn create_game_snapshot<'a>(
+    players: impl Iterator<Item = &'a Player>,
+    vineyards: impl Iterator<Item = &'a Vineyard>,
+    hands: impl Iterator<Item = &'a Hand>,
+    workers: impl Iterator<Item = (Entity, &'a Worker)>,
     turn_order: &TurnOrder,
-    action_spaces: &Query<&ActionSpaceSlot>,
-    timestamp: f32,
+    action_spaces: impl Iterator<Item = &'a ActionSpaceSlot>,
+    trackers: impl Iterator<Item = &'a ResidualPaymentTracker>,
+    decks: &CardDecks,
 ) -> GameSnapshot {
-    let players_snapshot: Vec<_> = players.iter().map(|p| PlayerSnapshot {
+    let players_snapshot: Vec<_> = players.map(|p| PlayerSnapshot {
         id: p.id.0,
         name: p.name.clone(),
         victory_points: p.victory_points,
         lira: p.lira,
         workers: p.workers,
+        grande_worker_available: p.grande_worker_available,
         is_ai: p.is_ai, // ADDED: Missing field
     }).collect();
     
-    let vineyards_snapshot: Vec<_> = vineyards.iter().map(|v| VineyardSnapshot {
+    let vineyards_snapshot: Vec<_> = vineyards.map(|v| VineyardSnapshot {
         owner_id: v.owner.0,
         red_grapes: v.red_grapes,
         white_grapes: v.white_grapes,
         red_wine: v.red_wine,
         white_wine: v.white_wine,
+        vintages: v.vintages.clone(),
         lira: v.lira,
         // FIXED: Convert VineyardField array to VineyardFieldSnapshot array
         fields: v.fields.map(|field| VineyardFieldSnapshot {
@@ -191,13 +275,14 @@ fn create_game_snapshot(
         }),
     }).collect();
     
-    let hands_snapshot: Vec<_> = hands.iter().map(|h| HandSnapshot {
+    let hands_snapshot: Vec<_> = hands.map(|h| HandSnapshot {
         owner_id: h.owner.0,
-        vine_card_count: h.vine_cards.len(),
-        wine_order_count: h.wine_order_cards.len(),
+        vine_cards: h.vine_cards.clone(),
+        wine_order_cards: h.wine_order_cards.clone(),
     }).collect();
     
-    let workers_snapshot: Vec<_> = workers.iter().map(|w| WorkerSnapshot {
+    let workers_snapshot: Vec<_> = workers.map(|(entity, w)| WorkerSnapshot {
+        entity,
         owner_id: w.owner.0,
         is_grande: w.is_grande,
         placed_at: w.placed_at.map(action_to_u8),
@@ -207,12 +292,15 @@ fn create_game_snapshot(
     
     let turn_order_snapshot = TurnOrderSnapshot {
         current_player: turn_order.current_player,
+        passed: turn_order.passed.clone(),
     };
     
-    let action_spaces_snapshot: Vec<_> = action_spaces.iter().map(|s| ActionSpaceSnapshot {
+    let action_spaces_snapshot: Vec<_> = action_spaces.map(|s| ActionSpaceSnapshot {
         action: action_to_u8(s.action),
+        column: s.column,
         occupied_by: s.occupied_by.map(|p| p.0),
         bonus_worker_slot: s.bonus_worker_slot.map(|p| p.0),
+        bonus_chit: s.bonus_chit,
     }).collect();
     
     GameSnapshot {
@@ -222,33 +310,35 @@ fn create_game_snapshot(
         workers: workers_snapshot,
         turn_order: turn_order_snapshot,
         action_spaces: action_spaces_snapshot,
-        timestamp,
+        trackers: trackers.cloned().collect(),
+        decks: decks.clone(),
     }
 }
 
-fn restore_from_snapshot(
-    commands: &mut Commands,
+// Written back onto the live entities so the board, HUD and sprites stay where they are
+fn restore_snapshot(
     snapshot: &GameSnapshot,
-    turn_order: &mut ResMut<TurnOrder>,
+    commands: &mut Commands,
+    players: &mut Query<&mut Player>,
+    vineyards: &mut Query<&mut Vineyard>,
+    hands: &mut Query<&mut Hand>,
+    workers: &mut Query<(Entity, &mut Worker)>,
+    action_spaces: &mut Query<&mut ActionSpaceSlot>,
+    trackers: &mut Query<&mut ResidualPaymentTracker>,
+    decks: &mut CardDecks,
+    turn_order: &mut TurnOrder,
 ) {
-    // Restore players
-    for player_snap in &snapshot.players {
-        commands.spawn(Player {
-            id: PlayerId(player_snap.id),
-            name: player_snap.name.clone(),
-            victory_points: player_snap.victory_points,
-            lira: player_snap.lira,
-            workers: player_snap.workers,
-            grande_worker_available: true,
-            is_ai: player_snap.is_ai, // ADDED: Missing field
-        });
+    for mut player in players.iter_mut() {
+        let Some(player_snap) = snapshot.players.iter().find(|p| p.id == player.id.0) else { continue };
+        player.victory_points = player_snap.victory_points;
+        player.lira = player_snap.lira;
+        player.workers = player_snap.workers;
+        player.grande_worker_available = player_snap.grande_worker_available;
     }
     
-    // Restore vineyards
-    for vineyard_snap in &snapshot.vineyards {
-        // FIXED: Convert VineyardFieldSnapshot array back to VineyardField array
-        // Now works because VineyardFieldSnapshot implements Copy
-        let fields = vineyard_snap.fields.map(|field_snap| VineyardField {
+    for mut vineyard in vineyards.iter_mut() {
+        let Some(vineyard_snap) = snapshot.vineyards.iter().find(|v| v.owner_id == vineyard.owner.0) else { continue };
+        vineyard.fields = vineyard_snap.fields.map(|field_snap| VineyardField {
             vine: field_snap.vine.map(|(is_red, val)| {
                 if is_red {
                     VineType::Red(val)
@@ -259,29 +349,33 @@ fn restore_from_snapshot(
             field_type: u8_to_field_type(field_snap.field_type),
             sold_this_year: field_snap.sold_this_year,
         });
-        
-        commands.spawn(Vineyard {
-            owner: PlayerId(vineyard_snap.owner_id),
-            fields,
-            red_grapes: vineyard_snap.red_grapes,
-            white_grapes: vineyard_snap.white_grapes,
-            red_wine: vineyard_snap.red_wine,
-            white_wine: vineyard_snap.white_wine,
-            lira: vineyard_snap.lira,
-        });
+        vineyard.red_grapes = vineyard_snap.red_grapes;
+        vineyard.white_grapes = vineyard_snap.white_grapes;
+        vineyard.red_wine = vineyard_snap.red_wine;
+        vineyard.white_wine = vineyard_snap.white_wine;
+        vineyard.vintages = vineyard_snap.vintages.clone();
+        vineyard.lira = vineyard_snap.lira;
+        vineyard.wine_recipe = None;
+        vineyard.harvest_selection.clear();
+        vineyard.order_wines = (None, None);
     }
     
-    // Restore hands (simplified - just create empty hands)
-    for hand_snap in &snapshot.hands {
-        commands.spawn(Hand {
-            owner: PlayerId(hand_snap.owner_id),
-            vine_cards: Vec::new(), // Simplified restoration
-            wine_order_cards: Vec::new(),
-        });
+    for mut hand in hands.iter_mut() {
+        let Some(hand_snap) = snapshot.hands.iter().find(|h| h.owner_id == hand.owner.0) else { continue };
+        hand.vine_cards = hand_snap.vine_cards.clone();
+        hand.wine_order_cards = hand_snap.wine_order_cards.clone();
     }
     
-    // Restore workers
-    for worker_snap in &snapshot.workers {
+    for (entity, mut worker) in workers.iter_mut() {
+        match snapshot.workers.iter().find(|w| w.entity == entity) {
+            Some(worker_snap) => {
+                worker.placed_at = worker_snap.placed_at.and_then(u8_to_action);
+                worker.position = Vec2::new(worker_snap.position_x, worker_snap.position_y);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+    for worker_snap in snapshot.workers.iter().filter(|w| !workers.contains(w.entity)) {
         commands.spawn((
             Worker {
                 owner: PlayerId(worker_snap.owner_id),
@@ -293,26 +387,29 @@ fn restore_from_snapshot(
         ));
     }
     
-    // Restore action spaces
-    let action_board = ActionBoard::new();
-    for (i, space_snap) in snapshot.action_spaces.iter().enumerate() {
-        if let Some(mut space) = action_board.spaces.get(i).cloned() {
-            space.occupied_by = space_snap.occupied_by.map(PlayerId);
-            space.bonus_worker_slot = space_snap.bonus_worker_slot.map(PlayerId);
-            commands.spawn((
-                space,
-                Clickable { size: Vec2::new(60.0, 30.0) },
-            ));
+    for mut space in action_spaces.iter_mut() {
+        let action = action_to_u8(space.action);
+        let Some(space_snap) = snapshot.action_spaces.iter().find(|s| s.action == action && s.column == space.column) else { continue };
+        space.occupied_by = space_snap.occupied_by.map(PlayerId);
+        space.bonus_worker_slot = space_snap.bonus_worker_slot.map(PlayerId);
+        space.bonus_chit = space_snap.bonus_chit;
+    }
+    
+    for mut tracker in trackers.iter_mut() {
+        if let Some(tracker_snap) = snapshot.trackers.iter().find(|t| t.owner == tracker.owner) {
+            tracker.level = tracker_snap.level;
         }
     }
-    commands.spawn(action_board);
     
-    // Restore turn order
+    *decks = snapshot.decks.clone();
+    
     turn_order.current_player = snapshot.turn_order.current_player;
+    turn_order.passed = snapshot.turn_order.passed.clone();
 }
 
 pub fn display_undo_status_system(
     undo_system: Res<UndoSystem>,
+    keymap: Res<Keymap>,
     mut commands: Commands,
     existing_undo_ui: Query<Entity, With<UndoStatusText>>,
 ) {
@@ -321,10 +418,18 @@ pub fn display_undo_status_system(
         commands.entity(entity).despawn();
     }
     
+    let key = |action| keymap.binding(action).map(|b| b.key_label()).unwrap_or_default();
+    let mut hints = Vec::new();
     if undo_system.undo_available {
+        hints.push(format!("{} to undo", key(InputAction::Undo)));
+    }
+    if !undo_system.redo.is_empty() {
+        hints.push(format!("{} to redo", key(InputAction::Redo)));
+    }
+    if !hints.is_empty() {
         commands.spawn((
             TextBundle::from_section(
-                "Press Ctrl+Z to undo last action",
+                format!("Press {}", hints.join(", ")),
                 TextStyle {
                     font_size: 14.0,
                     color: Color::srgb(1.0, 1.0, 0.0).with_alpha(0.8),
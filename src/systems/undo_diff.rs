@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::clock::{GameClock, GameTimer};
+use crate::systems::sprites::field_sprite_position;
+use crate::systems::undo::GameSnapshot;
+use crate::systems::render_layers::WorldLayer;
+
+const OUTLINE_WIDTH: f32 = 3.0;
+const WORKER_COLOR: Color = Color::srgb(0.3, 0.6, 1.0);
+const FIELD_COLOR: Color = Color::srgb(0.3, 0.9, 0.3);
+const RESOURCE_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+const CARD_COLOR: Color = Color::srgb(0.8, 0.4, 1.0);
+
+// Something an undo changed, outlined on the board while the overlay fades
+#[derive(Clone, Debug)]
+pub struct UndoHighlight {
+    pub position: Vec2,
+    pub size: Vec2,
+    pub color: Color,
+    pub label: Option<String>,
+}
+
+#[derive(Resource, Default)]
+pub struct UndoDiff {
+    pub highlights: Vec<UndoHighlight>,
+    pub fade: Timer,
+}
+
+impl UndoDiff {
+    pub fn show(&mut self, highlights: Vec<UndoHighlight>) {
+        self.highlights = highlights;
+        self.fade = GameClock::once(GameTimer::UndoHighlight);
+    }
+}
+
+#[derive(Component)]
+pub struct UndoHighlightSprite;
+
+fn changes(pairs: &[(&str, u8, u8)]) -> Option<String> {
+    let changed: Vec<_> = pairs.iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(name, before, after)| format!("{} {}→{}", name, before, after))
+        .collect();
+    if changed.is_empty() { None } else { Some(changed.join(", ")) }
+}
+
+// `before` is the state being undone, `after` the snapshot it was rolled back to
+pub fn diff_snapshots(before: &GameSnapshot, after: &GameSnapshot) -> Vec<UndoHighlight> {
+    let mut highlights = Vec::new();
+
+    for worker in &after.workers {
+        let moved = !before.workers.iter().any(|w| {
+            w.owner_id == worker.owner_id && w.is_grande == worker.is_grande
+                && w.placed_at == worker.placed_at
+                && w.position_x == worker.position_x && w.position_y == worker.position_y
+        });
+        if moved {
+            highlights.push(UndoHighlight {
+                position: Vec2::new(worker.position_x, worker.position_y),
+                size: Vec2::splat(26.0),
+                color: WORKER_COLOR,
+                label: None,
+            });
+        }
+    }
+
+    for vineyard in &after.vineyards {
+        let Some(old) = before.vineyards.iter().find(|v| v.owner_id == vineyard.owner_id) else { continue };
+        let owner = PlayerId(vineyard.owner_id);
+
+        for (i, field) in vineyard.fields.iter().enumerate() {
+            let old_field = &old.fields[i];
+            if field.vine != old_field.vine || field.sold_this_year != old_field.sold_this_year {
+                highlights.push(UndoHighlight {
+                    position: field_sprite_position(owner, i),
+                    size: Vec2::splat(44.0),
+                    color: FIELD_COLOR,
+                    label: None,
+                });
+            }
+        }
+
+        let player = after.players.iter().find(|p| p.id == vineyard.owner_id);
+        let old_player = before.players.iter().find(|p| p.id == vineyard.owner_id);
+        let (vp, old_vp) = (player.map_or(0, |p| p.victory_points.get()), old_player.map_or(0, |p| p.victory_points.get()));
+        let (lira, old_lira) = (player.map_or(0, |p| p.lira.get()), old_player.map_or(0, |p| p.lira.get()));
+
+        // Reversed pairs read as "undone value → restored value"
+        if let Some(label) = changes(&[
+            ("VP", old_vp, vp),
+            ("Lira", old_lira, lira),
+            ("Red grapes", old.red_grapes.count(), vineyard.red_grapes.count()),
+            ("White grapes", old.white_grapes.count(), vineyard.white_grapes.count()),
+            ("Red wine", old.red_wine.count(), vineyard.red_wine.count()),
+            ("White wine", old.white_wine.count(), vineyard.white_wine.count()),
+        ]) {
+            highlights.push(UndoHighlight {
+                position: field_sprite_position(owner, 4),
+                size: Vec2::splat(140.0),
+                color: RESOURCE_COLOR,
+                label: Some(label),
+            });
+        }
+    }
+
+    for hand in &after.hands {
+        let Some(old) = before.hands.iter().find(|h| h.owner_id == hand.owner_id) else { continue };
+        if hand.vine_cards.len() != old.vine_cards.len() || hand.wine_order_cards.len() != old.wine_order_cards.len() {
+            let anchor = field_sprite_position(PlayerId(hand.owner_id), 7);
+            highlights.push(UndoHighlight {
+                position: anchor + Vec2::new(0.0, -90.0),
+                size: Vec2::new(140.0, 40.0),
+                color: CARD_COLOR,
+                label: Some(format!(
+                    "Cards {}/{} → {}/{}",
+                    old.vine_cards.len(), old.wine_order_cards.len(), hand.vine_cards.len(), hand.wine_order_cards.len(),
+                )),
+            });
+        }
+    }
+
+    highlights
+}
+
+// Redrawn every frame, like the other world sprites, with alpha following the fade timer
+pub fn undo_diff_overlay_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut diff: ResMut<UndoDiff>,
+    existing: Query<Entity, With<UndoHighlightSprite>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if diff.highlights.is_empty() {
+        return;
+    }
+
+    diff.fade.tick(time.delta());
+    if diff.fade.finished() {
+        diff.highlights.clear();
+        return;
+    }
+
+    let alpha = 1.0 - diff.fade.fraction();
+    for highlight in &diff.highlights {
+        let color = highlight.color.with_alpha(alpha);
+        let half = highlight.size / 2.0;
+        let edges = [
+            (Vec2::new(0.0, half.y), Vec2::new(highlight.size.x, OUTLINE_WIDTH)),
+            (Vec2::new(0.0, -half.y), Vec2::new(highlight.size.x, OUTLINE_WIDTH)),
+            (Vec2::new(-half.x, 0.0), Vec2::new(OUTLINE_WIDTH, highlight.size.y)),
+            (Vec2::new(half.x, 0.0), Vec2::new(OUTLINE_WIDTH, highlight.size.y)),
+        ];
+
+        for (offset, size) in edges {
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite { color, custom_size: Some(size), ..default() },
+                    transform: Transform::from_translation((highlight.position + offset).extend(WorldLayer::Highlights.at(0.5))),
+                    ..default()
+                },
+                UndoHighlightSprite,
+            ));
+        }
+
+        if let Some(label) = &highlight.label {
+            commands.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        label.clone(),
+                        TextStyle { font_size: 12.0, color, ..default() },
+                    ),
+                    transform: Transform::from_translation((highlight.position + Vec2::new(0.0, half.y + 10.0)).extend(WorldLayer::Highlights.at(0.6))),
+                    ..default()
+                },
+                UndoHighlightSprite,
+            ));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use crate::components::*;
+use crate::systems::clock::GameClock;
+use crate::systems::settings::UserSettings;
+use crate::systems::simulation::SimGame;
+use crate::systems::render_layers::UiLayer;
+
+// Worker upkeep variant: at year end every worker beyond the second costs 1 lira. A worker
+// that can't be paid for leaves instead. The grande worker never costs upkeep.
+
+pub const UPKEEP_FREE_WORKERS: u8 = 2;
+pub const UPKEEP_PER_WORKER: u8 = 1;
+const UPKEEP_CARD_SECONDS: f32 = 6.0;
+const SIM_MAX_STEPS: usize = 5000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WorkerUpkeep {
+    pub paid: u8,
+    pub lost: u8,
+}
+
+// Pays for each worker in turn, so a player short on lira keeps as many as they can afford
+pub fn pay_worker_upkeep(player: &mut Player) -> WorkerUpkeep {
+    let mut upkeep = WorkerUpkeep::default();
+    for _ in 0..player.workers.saturating_sub(UPKEEP_FREE_WORKERS) {
+        if player.lira >= UPKEEP_PER_WORKER {
+            player.lira -= UPKEEP_PER_WORKER;
+            upkeep.paid += UPKEEP_PER_WORKER;
+        } else {
+            player.workers -= 1;
+            upkeep.lost += 1;
+        }
+    }
+    upkeep
+}
+
+pub fn upkeep_summary(year: u8, entries: &[(PlayerId, WorkerUpkeep)]) -> String {
+    let mut text = format!("👷 Worker upkeep, end of year {}", year);
+    for (player, upkeep) in entries {
+        text.push_str(&format!("\nPlayer {}: paid {} lira", player.0 + 1, upkeep.paid));
+        if upkeep.lost > 0 {
+            text.push_str(&format!(", lost {} worker{}", upkeep.lost, if upkeep.lost == 1 { "" } else { "s" }));
+        }
+    }
+    text
+}
+
+#[derive(Component)]
+pub struct UpkeepSummaryCard {
+    pub timer: Timer,
+}
+
+// Runs as the year turns over, before spring income is paid
+pub fn worker_upkeep_system(
+    mut commands: Commands,
+    settings: Res<UserSettings>,
+    current_state: Res<State<GameState>>,
+    config: Res<GameConfig>,
+    mut players: Query<&mut Player>,
+    workers: Query<(Entity, &Worker)>,
+) {
+    if !settings.house_rule_worker_upkeep || !current_state.is_changed() || !matches!(current_state.get(), GameState::Spring) {
+        return;
+    }
+    // Year 1 spring follows setup, not a year end
+    let Some(ended_year) = config.current_year.checked_sub(1).filter(|y| *y > 0) else { return };
+
+    let mut entries = Vec::new();
+    for mut player in players.iter_mut() {
+        let upkeep = pay_worker_upkeep(&mut player);
+        if upkeep == WorkerUpkeep::default() {
+            continue;
+        }
+
+        // Drop the departed workers' meeples, never the grande
+        let regular: Vec<Entity> = workers.iter()
+            .filter(|(_, w)| w.owner == player.id && !w.is_grande)
+            .map(|(entity, _)| entity)
+            .collect();
+        let surplus = regular.len().saturating_sub(player.workers as usize);
+        for entity in regular.into_iter().rev().take(surplus) {
+            commands.entity(entity).insert(MarkedForDespawn);
+        }
+
+        info!("👷 Player {:?} upkeep: paid {} lira, lost {} workers", player.id, upkeep.paid, upkeep.lost);
+        entries.push((player.id, upkeep));
+    }
+
+    if !entries.is_empty() {
+        entries.sort_by_key(|(id, _)| id.0);
+        spawn_upkeep_card(&mut commands, &upkeep_summary(ended_year, &entries));
+    }
+}
+
+pub fn upkeep_summary_card_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut cards: Query<(Entity, &mut UpkeepSummaryCard), Without<MarkedForDespawn>>,
+) {
+    for (entity, mut card) in cards.iter_mut() {
+        card.timer.tick(time.delta());
+        if card.timer.finished() || mouse.just_pressed(MouseButton::Left) {
+            commands.entity(entity).insert(MarkedForDespawn);
+        }
+    }
+}
+
+fn spawn_upkeep_card(commands: &mut Commands, summary: &str) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                right: Val::Px(20.0),
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.15, 0.12, 0.08, 0.95)).into(),
+            border_color: Color::from(Srgba::new(0.8, 0.65, 0.3, 1.0)).into(),
+            z_index: UiLayer::Announcement.z_index(),
+            ..default()
+        },
+        UpkeepSummaryCard {
+            timer: GameClock::once_secs(UPKEEP_CARD_SECONDS),
+        },
+    )).with_children(|card| {
+        card.spawn(TextBundle::from_section(
+            summary,
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
+
+/// Average results of AI-only simulator games with and without the upkeep variant
+#[derive(Clone, Debug, Default)]
+pub struct UpkeepBalanceReport {
+    pub games: u32,
+    pub avg_winner_vp: (f32, f32),
+    pub avg_final_workers: (f32, f32),
+    pub avg_years: (f32, f32),
+}
+
+/// Plays the same seeds with the variant off and on; the first value of each pair is "off"
+pub fn upkeep_balance_report(config: &GameConfig, seeds: std::ops::Range<u64>) -> UpkeepBalanceReport {
+    let mut report = UpkeepBalanceReport::default();
+    let mut totals = [[0f32; 3]; 2];
+
+    for seed in seeds {
+        report.games += 1;
+        for (variant, upkeep) in [false, true].into_iter().enumerate() {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut game = SimGame::new(config, &mut rng).with_worker_upkeep(upkeep);
+            for _ in 0..SIM_MAX_STEPS {
+                if game.is_over() {
+                    break;
+                }
+                game.step(&mut rng);
+            }
+            let players = game.players.len().max(1) as f32;
+            totals[variant][0] += game.players.iter().map(|p| p.victory_points.get()).max().unwrap_or(0) as f32;
+            totals[variant][1] += game.players.iter().map(|p| p.workers as f32).sum::<f32>() / players;
+            totals[variant][2] += game.current_year.min(game.max_years) as f32;
+        }
+    }
+
+    let games = report.games.max(1) as f32;
+    report.avg_winner_vp = (totals[0][0] / games, totals[1][0] / games);
+    report.avg_final_workers = (totals[0][1] / games, totals[1][1] / games);
+    report.avg_years = (totals[0][2] / games, totals[1][2] / games);
+    report
+}
@@ -105,7 +105,7 @@ pub fn validate_action_requirements(
             }
         }
         ActionSpace::MakeWine => {
-            let total_grapes = vineyard.red_grapes + vineyard.white_grapes;
+            let total_grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
             if total_grapes == 0 {
                 return ValidationResult::Invalid("No grapes available to make wine".to_string());
             }
@@ -131,7 +131,7 @@ pub fn validate_action_requirements(
             }
         }
         ActionSpace::SellGrapes => {
-            let total_grapes = vineyard.red_grapes + vineyard.white_grapes;
+            let total_grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
             if total_grapes == 0 {
                 return ValidationResult::Invalid("No grapes to sell".to_string());
             }
@@ -190,8 +190,8 @@ pub fn check_tie_breaker(
     let mut player_scores: Vec<_> = players.iter()
         .map(|p| {
             let vineyard = vineyards.iter().find(|v| v.owner == p.id).unwrap();
-            let total_wine = vineyard.red_wine + vineyard.white_wine;
-            let total_grapes = vineyard.red_grapes + vineyard.white_grapes;
+            let total_wine = vineyard.red_wine.count() + vineyard.white_wine.count();
+            let total_grapes = vineyard.red_grapes.count() + vineyard.white_grapes.count();
             
             (p.id, p.victory_points, vineyard.lira, total_wine, total_grapes)
         })
@@ -214,7 +214,7 @@ pub fn check_tie_breaker(
     }
 }
 
-pub fn balance_card_distribution(card_decks: &mut ResMut<CardDecks>) {
+pub fn balance_card_distribution(card_decks: &mut ResMut<CardDecks>, rng: &mut impl rand::Rng) {
     // Ensure balanced vine card distribution
     let mut red_count = 0;
     let mut white_count = 0;
@@ -243,7 +243,6 @@ pub fn balance_card_distribution(card_decks: &mut ResMut<CardDecks>) {
     
     // Shuffle decks for randomness
     use rand::seq::SliceRandom;
-    let mut rng = rand::rng();
-    card_decks.vine_deck.shuffle(&mut rng);
-    card_decks.wine_order_deck.shuffle(&mut rng);
+    card_decks.vine_deck.shuffle(rng);
+    card_decks.wine_order_deck.shuffle(rng);
 }
\ No newline at end of file
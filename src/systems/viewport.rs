@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy::window::WindowResized;
+
+// World-space layout (board, phase text, animated text) is authored for this size
+pub const DESIGN_RESOLUTION: Vec2 = Vec2::new(1200.0, 800.0);
+
+#[derive(Component)]
+pub struct MainCamera;
+
+// Projection scale that keeps the whole design area visible and centred
+pub fn projection_scale_for(window_size: Vec2) -> f32 {
+    if window_size.x <= 0.0 || window_size.y <= 0.0 {
+        return 1.0;
+    }
+    (DESIGN_RESOLUTION.x / window_size.x).max(DESIGN_RESOLUTION.y / window_size.y)
+}
+
+// Size of the visible area in world units for the current window
+pub fn visible_world_size(window: &Window, projection: &OrthographicProjection) -> Vec2 {
+    Vec2::new(window.width(), window.height()) * projection.scale
+}
+
+// Rescales the camera on resize/maximize; because the camera stays centred on the
+// origin, world-space text and sprites keep their design positions and
+// viewport_to_world_2d hit-testing stays consistent
+pub fn window_resize_system(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Query<&Window>,
+    mut cameras: Query<(&mut OrthographicProjection, &mut Transform), With<MainCamera>>,
+) {
+    let Some(event) = resize_events.read().last() else { return };
+    let Ok(window) = windows.get(event.window) else { return };
+
+    let scale = projection_scale_for(Vec2::new(window.width(), window.height()));
+    for (mut projection, mut transform) in cameras.iter_mut() {
+        projection.scale = scale;
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+    }
+    info!("🖥️ Window resized to {}x{}, world scale {:.2}", window.width(), window.height(), scale);
+}
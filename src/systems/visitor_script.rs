@@ -0,0 +1,419 @@
+use bevy::prelude::*;
+use std::fmt;
+use crate::components::*;
+use crate::systems::expansions::{VisitorCard, VisitorEffect, VisitorSeason};
+use crate::systems::visitor_targeting::VisitorTarget;
+
+// Visitors as data. Each line of a `.visitors` file in assets/visitors is one card:
+//   id | name | summer or winter | cost | effect
+// The effect is a list of steps separated by `;`:
+//   gain N lira|vp|worker    pay N lira|vp|grape|vine|order    draw N vine|order    plant
+//   trade N <payable> for N <gainable or drawable>    choose (steps | steps | ...)
+// A card plays all of its steps or none of them. It may hold one `choose`, whose options
+// are offered like any other visitor target.
+
+pub const VISITOR_SCRIPT_DIR: &str = "assets/visitors";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptResource {
+    Lira,
+    Vp,
+    Worker,
+    Grape,
+    Vine,
+    Order,
+}
+
+impl ScriptResource {
+    fn parse(word: Option<&str>) -> Result<Self, String> {
+        let word = word.ok_or("missing resource")?;
+        match word.strip_suffix('s').unwrap_or(word) {
+            "lira" => Ok(Self::Lira),
+            "vp" => Ok(Self::Vp),
+            "worker" => Ok(Self::Worker),
+            "grape" => Ok(Self::Grape),
+            "vine" => Ok(Self::Vine),
+            "order" => Ok(Self::Order),
+            _ => Err(format!("unknown resource '{}'", word)),
+        }
+    }
+
+    fn gainable(self) -> bool {
+        matches!(self, Self::Lira | Self::Vp | Self::Worker)
+    }
+
+    fn payable(self) -> bool {
+        matches!(self, Self::Lira | Self::Vp | Self::Grape | Self::Vine | Self::Order)
+    }
+
+    fn drawable(self) -> bool {
+        matches!(self, Self::Vine | Self::Order)
+    }
+}
+
+impl fmt::Display for ScriptResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Lira => "lira",
+            Self::Vp => "vp",
+            Self::Worker => "worker",
+            Self::Grape => "grape",
+            Self::Vine => "vine",
+            Self::Order => "order",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScriptStep {
+    Gain(u8, ScriptResource),
+    Pay(u8, ScriptResource),
+    Draw(u8, ScriptResource),
+    // The first vine card in hand goes into the first empty field
+    Plant,
+    // Pay the first, then gain or draw the second
+    Trade { give: (u8, ScriptResource), get: (u8, ScriptResource) },
+    Choose(Vec<Vec<ScriptStep>>),
+}
+
+impl fmt::Display for ScriptStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gain(n, r) => write!(f, "gain {} {}", n, r),
+            Self::Pay(n, r) => write!(f, "pay {} {}", n, r),
+            Self::Draw(n, r) => write!(f, "draw {} {}", n, r),
+            Self::Plant => f.write_str("plant"),
+            Self::Trade { give, get } => write!(f, "trade {} {} for {} {}", give.0, give.1, get.0, get.1),
+            Self::Choose(options) => {
+                let options: Vec<String> = options.iter().map(|o| steps_text(o)).collect();
+                write!(f, "choose ({})", options.join(" | "))
+            }
+        }
+    }
+}
+
+fn steps_text(steps: &[ScriptStep]) -> String {
+    steps.iter().map(|s| s.to_string()).collect::<Vec<_>>().join("; ")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VisitorScript {
+    pub steps: Vec<ScriptStep>,
+}
+
+impl fmt::Display for VisitorScript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&steps_text(&self.steps))
+    }
+}
+
+// Splits on `sep` outside parentheses; every piece must hold something
+fn split_top(text: &str, sep: char) -> Result<Vec<&str>, String> {
+    let mut pieces = Vec::new();
+    let (mut depth, mut start) = (0i32, 0);
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                pieces.push(text[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            return Err("unbalanced parentheses".to_string());
+        }
+    }
+    if depth != 0 {
+        return Err("unbalanced parentheses".to_string());
+    }
+    pieces.push(text[start..].trim());
+    if pieces.iter().any(|p| p.is_empty()) {
+        return Err(format!("empty step in '{}'", text.trim()));
+    }
+    Ok(pieces)
+}
+
+fn parse_amount(word: Option<&str>) -> Result<u8, String> {
+    let word = word.ok_or("missing amount")?;
+    match word.parse::<u8>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("'{}' is not an amount", word)),
+    }
+}
+
+// `N resource`, where `allowed` says whether the verb can take that resource
+fn quantity<'a>(verb: &str, words: &mut impl Iterator<Item = &'a str>, allowed: fn(ScriptResource) -> bool) -> Result<(u8, ScriptResource), String> {
+    let amount = parse_amount(words.next())?;
+    let resource = ScriptResource::parse(words.next())?;
+    if !allowed(resource) {
+        return Err(format!("can't {} {}", verb, resource));
+    }
+    Ok((amount, resource))
+}
+
+fn parse_steps(text: &str, allow_choose: bool) -> Result<Vec<ScriptStep>, String> {
+    split_top(text, ';')?.into_iter().map(|step| parse_step(step, allow_choose)).collect()
+}
+
+fn parse_step(text: &str, allow_choose: bool) -> Result<ScriptStep, String> {
+    let (verb, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    if verb == "choose" {
+        if !allow_choose {
+            return Err("a choose can't sit inside another".to_string());
+        }
+        let inner = rest.trim().strip_prefix('(').and_then(|r| r.strip_suffix(')')).ok_or("choose needs its options in parentheses")?;
+        let options = split_top(inner, '|')?.into_iter()
+            .map(|option| parse_steps(option, false))
+            .collect::<Result<Vec<_>, _>>()?;
+        if options.len() < 2 {
+            return Err("choose needs at least two options".to_string());
+        }
+        return Ok(ScriptStep::Choose(options));
+    }
+
+    let mut words = rest.split_whitespace();
+    let step = match verb {
+        "gain" => quantity(verb, &mut words, ScriptResource::gainable).map(|(n, r)| ScriptStep::Gain(n, r))?,
+        "pay" => quantity(verb, &mut words, ScriptResource::payable).map(|(n, r)| ScriptStep::Pay(n, r))?,
+        "draw" => quantity(verb, &mut words, ScriptResource::drawable).map(|(n, r)| ScriptStep::Draw(n, r))?,
+        "trade" => {
+            let give = quantity(verb, &mut words, ScriptResource::payable)?;
+            if words.next() != Some("for") {
+                return Err(format!("trade needs 'for' in '{}'", text));
+            }
+            let get = quantity(verb, &mut words, |r| r.gainable() || r.drawable())?;
+            ScriptStep::Trade { give, get }
+        }
+        "plant" => ScriptStep::Plant,
+        _ => return Err(format!("unknown step '{}'", verb)),
+    };
+    if let Some(extra) = words.next() {
+        return Err(format!("unexpected '{}' in '{}'", extra, text));
+    }
+    Ok(step)
+}
+
+impl VisitorScript {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let steps = parse_steps(text, true)?;
+        if steps.iter().filter(|s| matches!(s, ScriptStep::Choose(_))).count() > 1 {
+            return Err("a card can hold only one choose".to_string());
+        }
+        Ok(Self { steps })
+    }
+
+    fn options(&self) -> Option<&[Vec<ScriptStep>]> {
+        self.steps.iter().find_map(|step| match step {
+            ScriptStep::Choose(options) => Some(options.as_slice()),
+            _ => None,
+        })
+    }
+
+    pub fn has_choice(&self) -> bool {
+        self.options().is_some()
+    }
+
+    // What picking this option does, for the target panel
+    pub fn option_text(&self, index: usize) -> Option<String> {
+        self.options()?.get(index).map(|option| steps_text(option))
+    }
+}
+
+// Working copies a card plays against, kept only if every step succeeds
+#[derive(Clone)]
+struct Estate {
+    player: Player,
+    vineyard: Vineyard,
+    hand: Hand,
+    decks: CardDecks,
+}
+
+impl Estate {
+    fn run(&mut self, steps: &[ScriptStep], choice: Option<usize>) -> Result<(), String> {
+        for step in steps {
+            match step {
+                ScriptStep::Gain(n, r) => self.gain(*n, *r),
+                ScriptStep::Pay(n, r) => self.pay(*n, *r)?,
+                ScriptStep::Draw(n, r) => self.draw(*n, *r),
+                ScriptStep::Plant => self.plant()?,
+                ScriptStep::Trade { give, get } => {
+                    self.pay(give.0, give.1)?;
+                    if get.1.drawable() {
+                        self.draw(get.0, get.1);
+                    } else {
+                        self.gain(get.0, get.1);
+                    }
+                }
+                ScriptStep::Choose(options) => {
+                    let option = choice.and_then(|i| options.get(i)).ok_or("no option chosen")?;
+                    self.run(option, None)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn gain(&mut self, amount: u8, resource: ScriptResource) {
+        match resource {
+            ScriptResource::Lira => self.player.gain_lira(amount),
+            ScriptResource::Vp => self.player.gain_victory_points(amount),
+            ScriptResource::Worker => {
+                for _ in 0..amount {
+                    self.player.gain_worker();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(&mut self, amount: u8, resource: ScriptResource) {
+        for _ in 0..amount {
+            match resource {
+                ScriptResource::Vine => self.hand.vine_cards.extend(self.decks.draw_vine_card()),
+                ScriptResource::Order => self.hand.wine_order_cards.extend(self.decks.draw_wine_order_card()),
+                _ => {}
+            }
+        }
+    }
+
+    fn pay(&mut self, amount: u8, resource: ScriptResource) -> Result<(), String> {
+        let n = amount as usize;
+        let paid = match resource {
+            ScriptResource::Lira => self.player.lira.spend(amount),
+            ScriptResource::Vp => self.player.victory_points.spend(amount),
+            ScriptResource::Grape => (0..amount).all(|_| self.take_lowest_grape()),
+            ScriptResource::Vine if self.hand.vine_cards.len() >= n => {
+                self.decks.vine_discard.extend(self.hand.vine_cards.drain(..n));
+                true
+            }
+            ScriptResource::Order if self.hand.wine_order_cards.len() >= n => {
+                self.decks.wine_order_discard.extend(self.hand.wine_order_cards.drain(..n));
+                true
+            }
+            _ => false,
+        };
+        if paid { Ok(()) } else { Err(format!("not enough {}", resource)) }
+    }
+
+    fn take_lowest_grape(&mut self) -> bool {
+        let v = &mut self.vineyard;
+        match (v.red_grapes.lowest_at_least(1), v.white_grapes.lowest_at_least(1)) {
+            (Some(red), Some(white)) if white < red => v.white_grapes.take(white),
+            (Some(red), _) => v.red_grapes.take(red),
+            (None, Some(white)) => v.white_grapes.take(white),
+            (None, None) => false,
+        }
+    }
+
+    fn plant(&mut self) -> Result<(), String> {
+        if self.hand.vine_cards.is_empty() {
+            return Err("no vine to plant".to_string());
+        }
+        let field = self.vineyard.fields.iter_mut().find(|f| f.vine.is_none()).ok_or("no empty field")?;
+        field.vine = Some(self.hand.vine_cards.remove(0).vine_type);
+        Ok(())
+    }
+}
+
+fn play(script: &VisitorScript, cost: u8, choice: Option<usize>, mut estate: Estate) -> Result<Estate, String> {
+    estate.pay(cost, ScriptResource::Lira)?;
+    estate.run(&script.steps, choice)?;
+    Ok(estate)
+}
+
+// Pays the card's cost and runs its steps; on any failure nothing changes. `choice` picks
+// the option of the card's `choose`.
+pub fn run_visitor_script(
+    script: &VisitorScript,
+    cost: u8,
+    choice: Option<usize>,
+    player: &mut Player,
+    vineyard: &mut Vineyard,
+    hand: &mut Hand,
+    decks: &mut CardDecks,
+) -> Result<(), String> {
+    let estate = Estate { player: player.clone(), vineyard: vineyard.clone(), hand: hand.clone(), decks: decks.clone() };
+    let estate = play(script, cost, choice, estate)?;
+    *player = estate.player;
+    *vineyard = estate.vineyard;
+    *hand = estate.hand;
+    *decks = estate.decks;
+    Ok(())
+}
+
+// The options of the card's `choose` that could be played right now
+pub fn script_choices(script: &VisitorScript, cost: u8, player: &Player, vineyard: &Vineyard, hand: &Hand, decks: &CardDecks) -> Vec<VisitorTarget> {
+    let estate = Estate { player: player.clone(), vineyard: vineyard.clone(), hand: hand.clone(), decks: decks.clone() };
+    let options = script.options().map_or(0, |o| o.len());
+    (0..options)
+        .filter(|i| play(script, cost, Some(*i), estate.clone()).is_ok())
+        .map(VisitorTarget::Choice)
+        .collect()
+}
+
+// A blank line or `#` comment gives None
+pub fn parse_visitor_line(line: &str) -> Result<Option<VisitorCard>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    // The effect comes last, so a `|` inside a choose stays with it
+    let fields: Vec<&str> = line.splitn(5, '|').map(str::trim).collect();
+    let [id, name, season, cost, effect] = fields[..] else {
+        return Err("expected id | name | season | cost | effect".to_string());
+    };
+    let id = id.parse().map_err(|_| format!("'{}' is not a card id", id))?;
+    if name.is_empty() {
+        return Err("missing name".to_string());
+    }
+    let season = match season {
+        "summer" => VisitorSeason::Summer,
+        "winter" => VisitorSeason::Winter,
+        _ => return Err(format!("'{}' is not summer or winter", season)),
+    };
+    let cost = cost.parse().map_err(|_| format!("'{}' is not a cost", cost))?;
+    let script = VisitorScript::parse(effect)?;
+    Ok(Some(VisitorCard { id, name: name.to_string(), effect: VisitorEffect::Script(script), season, cost }))
+}
+
+// Good lines become cards; bad ones are reported by line number
+pub fn parse_visitor_file(text: &str) -> (Vec<VisitorCard>, Vec<String>) {
+    let mut cards = Vec::new();
+    let mut errors = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        match parse_visitor_line(line) {
+            Ok(card) => cards.extend(card),
+            Err(e) => errors.push(format!("line {}: {}", number + 1, e)),
+        }
+    }
+    (cards, errors)
+}
+
+// Every `.visitors` file in the folder, in name order; a missing folder adds nothing
+pub fn load_visitor_scripts(dir: &str) -> Vec<VisitorCard> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<std::path::PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "visitors"))
+        .collect();
+    paths.sort();
+
+    let mut cards = Vec::new();
+    for path in paths {
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                let (mut found, errors) = parse_visitor_file(&text);
+                for e in errors {
+                    warn!("Ignoring a visitor in {}, {}", path.display(), e);
+                }
+                cards.append(&mut found);
+            }
+            Err(e) => warn!("Ignoring {}: {}", path.display(), e),
+        }
+    }
+    cards
+}
@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::expansions::*;
+use crate::systems::sprites::{cellar_token_position, field_sprite_position};
+use crate::systems::render_layers::{UiLayer, WorldLayer};
+use crate::systems::seat_choices::{SeatChoice, SeatChoiceMade};
+
+// Visitors that need a target wait here while the human chooses one. Legal targets are
+// highlighted on the board and listed in a panel; a click on either confirms, Esc puts the
+// card back on its pile. The AI picks from the same visitor_targets() list.
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum VisitorTarget {
+    Opponent(PlayerId),
+    Field(usize),
+    Wine { wine_type: WineType, value: u8 },
+    // An option of a scripted visitor's choose
+    Choice(usize),
+}
+
+#[derive(Clone)]
+pub struct PendingVisitor {
+    pub player: PlayerId,
+    pub card: VisitorCard,
+    pub targets: Vec<VisitorTarget>,
+    // Which pile it came from, so other machines draw the same card
+    pub summer: bool,
+}
+
+#[derive(Resource, Default)]
+pub struct VisitorTargeting {
+    pub pending: Option<PendingVisitor>,
+}
+
+#[derive(Component)]
+pub struct VisitorTargetPanel;
+
+#[derive(Component)]
+pub struct VisitorTargetButton(pub usize);
+
+#[derive(Component)]
+pub struct VisitorTargetHighlight(pub usize);
+
+const HIGHLIGHT_COLOR: Srgba = Srgba::new(1.0, 0.85, 0.2, 0.45);
+
+pub fn needs_target(effect: &VisitorEffect) -> bool {
+    match effect {
+        VisitorEffect::Script(script) => script.has_choice(),
+        _ => matches!(effect, VisitorEffect::PlantFreeVine | VisitorEffect::StealLira(_) | VisitorEffect::AgeWine(_)),
+    }
+}
+
+// Every legal target for the effect; empty when the card has nothing to act on. Script
+// choices depend on what the player can pay, so they come from script_choices()
+pub fn visitor_targets(effect: &VisitorEffect, vineyard: &Vineyard, hand: &Hand, opponents: &[PlayerId]) -> Vec<VisitorTarget> {
+    match effect {
+        VisitorEffect::PlantFreeVine if !hand.vine_cards.is_empty() => vineyard.fields.iter()
+            .enumerate()
+            .filter(|(_, field)| field.vine.is_none())
+            .map(|(idx, _)| VisitorTarget::Field(idx))
+            .collect(),
+        VisitorEffect::StealLira(_) => opponents.iter().map(|id| VisitorTarget::Opponent(*id)).collect(),
+        VisitorEffect::AgeWine(_) => vineyard.red_wine.values().into_iter()
+            .map(|value| VisitorTarget::Wine { wine_type: WineType::Red, value })
+            .chain(vineyard.white_wine.values().into_iter().map(|value| VisitorTarget::Wine { wine_type: WineType::White, value }))
+            // A token already at the top has nowhere to go
+            .filter(|target| !matches!(target, VisitorTarget::Wine { value, .. } if *value >= MAX_TOKEN_VALUE))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn target_label(target: &VisitorTarget) -> String {
+    match target {
+        VisitorTarget::Opponent(id) => format!("Player {}", id.0 + 1),
+        VisitorTarget::Field(idx) => format!("Field {}", idx + 1),
+        VisitorTarget::Wine { wine_type, value } => format!("{:?} wine {}", wine_type, value),
+        VisitorTarget::Choice(idx) => format!("Option {}", idx + 1),
+    }
+}
+
+// Script options read as the steps they lead to
+pub fn card_target_label(card: &VisitorCard, target: &VisitorTarget) -> String {
+    match (&card.effect, target) {
+        (VisitorEffect::Script(script), VisitorTarget::Choice(idx)) => script.option_text(*idx).unwrap_or_else(|| target_label(target)),
+        _ => target_label(target),
+    }
+}
+
+// Where a target sits on the board, and the size of its highlight; choices are panel-only
+fn target_bounds(player: PlayerId, target: &VisitorTarget) -> Option<Rect> {
+    match target {
+        // The middle field marks the centre of an opponent's vineyard
+        VisitorTarget::Opponent(id) => Some(Rect::from_center_size(field_sprite_position(*id, 4), Vec2::new(140.0, 140.0))),
+        VisitorTarget::Field(idx) => Some(Rect::from_center_size(field_sprite_position(player, *idx), Vec2::new(40.0, 40.0))),
+        VisitorTarget::Wine { wine_type, value } => Some(Rect::from_center_size(cellar_token_position(player, *wine_type, *value), Vec2::new(12.0, 12.0))),
+        VisitorTarget::Choice(_) => None,
+    }
+}
+
+pub fn visitor_targeting_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    current_state: Res<State<GameState>>,
+    mut targeting: ResMut<VisitorTargeting>,
+    visitor_deck: Option<ResMut<VisitorDeck>>,
+    (mut hands, mut players, mut vineyards, mut card_decks): (Query<&mut Hand>, Query<&mut Player>, Query<&mut Vineyard>, ResMut<CardDecks>),
+    buttons: Query<(&Interaction, &VisitorTargetButton), Changed<Interaction>>,
+    panels: Query<Entity, Or<(With<VisitorTargetPanel>, With<VisitorTargetHighlight>)>>,
+    mut choices: EventWriter<SeatChoiceMade>,
+) {
+    let close = |commands: &mut Commands| {
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    };
+
+    let Some(pending) = targeting.pending.clone() else {
+        close(&mut commands);
+        return;
+    };
+
+    let cancelled = keyboard.just_pressed(KeyCode::Escape)
+        || !matches!(current_state.get(), GameState::Summer | GameState::Winter);
+    if cancelled {
+        if let Some(mut deck) = visitor_deck {
+            deck.return_visitor(pending.card);
+        }
+        info!("Visitor play cancelled");
+        choices.send(SeatChoiceMade { player: pending.player, choice: SeatChoice::Visitor { summer: pending.summer, target: None } });
+        targeting.pending = None;
+        close(&mut commands);
+        return;
+    }
+
+    let mut chosen = buttons.iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| button.0);
+
+    if chosen.is_none() && mouse_input.just_pressed(MouseButton::Left) {
+        let world_pos = windows.get_single().ok()
+            .and_then(|window| window.cursor_position())
+            .zip(camera_q.get_single().ok())
+            .and_then(|(cursor, (camera, transform))| camera.viewport_to_world_2d(transform, cursor));
+        if let Some(world_pos) = world_pos {
+            chosen = pending.targets.iter().position(|t| target_bounds(pending.player, t).is_some_and(|b| b.contains(world_pos)));
+        }
+    }
+
+    if let Some(target) = chosen.and_then(|idx| pending.targets.get(idx).copied()) {
+        execute_visitor_effect(pending.player, &pending.card, Some(target), &mut hands, &mut players, &mut vineyards, &mut card_decks);
+        info!("Player {:?} played visitor: {} on {}", pending.player, pending.card.name, card_target_label(&pending.card, &target));
+        choices.send(SeatChoiceMade { player: pending.player, choice: SeatChoice::Visitor { summer: pending.summer, target: Some(target) } });
+        targeting.pending = None;
+        close(&mut commands);
+        return;
+    }
+
+    if panels.is_empty() {
+        spawn_targeting(&mut commands, &pending);
+    }
+}
+
+fn spawn_targeting(commands: &mut Commands, pending: &PendingVisitor) {
+    for (idx, target) in pending.targets.iter().enumerate() {
+        let Some(bounds) = target_bounds(pending.player, target) else { continue };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::from(HIGHLIGHT_COLOR),
+                    custom_size: Some(bounds.size()),
+                    ..default()
+                },
+                transform: Transform::from_translation(bounds.center().extend(WorldLayer::Highlights.z())),
+                ..default()
+            },
+            VisitorTargetHighlight(idx),
+        ));
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(180.0),
+                right: Val::Px(20.0),
+                width: Val::Px(240.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: Color::srgb(0.1, 0.1, 0.1).with_alpha(0.95).into(),
+            border_color: Color::from(HIGHLIGHT_COLOR.with_alpha(1.0)).into(),
+            z_index: UiLayer::Dialog.z_index(),
+            ..default()
+        },
+        VisitorTargetPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            format!("🎭 {}: choose a target (Esc cancels)", pending.card.name),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        for (idx, target) in pending.targets.iter().enumerate() {
+            panel.spawn((
+                ButtonBundle {
+                    style: Style {
+                        margin: UiRect::top(Val::Px(4.0)),
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::srgb(0.3, 0.25, 0.1).into(),
+                    ..default()
+                },
+                VisitorTargetButton(idx),
+            )).with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    card_target_label(&pending.card, target),
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ));
+            });
+        }
+    });
+}
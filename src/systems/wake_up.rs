@@ -0,0 +1,945 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::systems::clock::GameClock;
+use crate::systems::keymap::{BoundInput, InputAction};
+use crate::systems::settings::UserSettings;
+use crate::systems::render_layers::UiLayer;
+use crate::systems::net::plays_locally;
+use crate::systems::play_by_file::PlayByFile;
+use crate::systems::prediction::RemotePlay;
+use crate::systems::seat_choices::{SeatChoice, SeatChoiceMade};
+
+// The built-in charts are data too, bundled from assets/wake_up so the rules engine and
+// headless runs have them without an asset server
+const BASE_CHART_JSON: &str = include_str!("../../assets/wake_up/base.json");
+const TUSCANY_CHART_JSON: &str = include_str!("../../assets/wake_up/tuscany.json");
+
+// Optional custom chart, same format as the built-in ones:
+// { "name": "House chart", "rows": [null, "DrawVineCard", {"GainLira": 2}, ...] }
+pub const CUSTOM_CHART_PATH: &str = "viticulture_wake_up.json";
+
+// Bonus for each wake-up row, top row first; rows past the end give nothing
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WakeUpChart {
+    pub name: String,
+    pub rows: Vec<Option<WakeUpBonus>>,
+}
+
+impl WakeUpChart {
+    // Row 7 has no bonus in either built-in chart; it gets the temporary worker instead
+    pub fn base() -> Self {
+        Self::bundled(BASE_CHART_JSON)
+    }
+
+    // Tuscany's row 5 visitor card is paid as lira; visitors are drawn through the Fall visitor step here
+    pub fn tuscany() -> Self {
+        Self::bundled(TUSCANY_CHART_JSON)
+    }
+
+    fn bundled(json: &str) -> Self {
+        serde_json::from_str(json).expect("bundled wake-up chart is valid")
+    }
+
+    pub fn load_custom() -> Option<Self> {
+        let json = std::fs::read_to_string(CUSTOM_CHART_PATH).ok()?;
+        match serde_json::from_str(&json) {
+            Ok(chart) => Some(chart),
+            Err(e) => {
+                warn!("Ignoring {}: {}", CUSTOM_CHART_PATH, e);
+                None
+            }
+        }
+    }
+
+    pub fn bonus_at(&self, position: usize) -> Option<WakeUpBonus> {
+        self.rows.get(position).copied().flatten()
+    }
+
+    pub fn reference_lines(&self) -> String {
+        self.rows.iter().enumerate()
+            .map(|(i, bonus)| format!("{}: {}\n", i + 1, bonus.map_or("No bonus".to_string(), |b| b.label())))
+            .collect()
+    }
+}
+
+impl WakeUpBonus {
+    pub fn label(&self) -> String {
+        match self {
+            WakeUpBonus::DrawVineCard => "Draw vine card".to_string(),
+            WakeUpBonus::GainLira(amount) => format!("+{} lira", amount),
+            WakeUpBonus::GainVictoryPoint => "+1 victory point".to_string(),
+            WakeUpBonus::DrawWineOrderCard => "Draw wine order".to_string(),
+            WakeUpBonus::PlayExtraWorker => "Extra worker this year".to_string(),
+        }
+    }
+}
+
+// Charts on offer at setup; rules, the Spring panel and the simulation all read the active one
+#[derive(Resource)]
+pub struct WakeUpCharts {
+    pub charts: Vec<WakeUpChart>,
+    pub active: usize,
+}
+
+impl Default for WakeUpCharts {
+    fn default() -> Self {
+        Self {
+            charts: vec![WakeUpChart::base(), WakeUpChart::tuscany()],
+            active: 0,
+        }
+    }
+}
+
+impl WakeUpCharts {
+    pub fn active(&self) -> &WakeUpChart {
+        &self.charts[self.active.min(self.charts.len() - 1)]
+    }
+
+    pub fn cycle(&mut self) {
+        self.active = (self.active + 1) % self.charts.len();
+    }
+}
+
+#[derive(Component)]
+pub struct WakeUpChartMenuText;
+
+#[derive(Component)]
+pub struct WakeUpChartPanel;
+
+pub fn load_wake_up_charts_system(mut charts: ResMut<WakeUpCharts>) {
+    if let Some(custom) = WakeUpChart::load_custom() {
+        info!("🌅 Loaded custom wake-up chart \"{}\"", custom.name);
+        charts.charts.push(custom);
+    }
+}
+
+// W (by default) on the main menu cycles the chart used for the next game
+pub fn wake_up_chart_menu_system(
+    input: BoundInput,
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    mut charts: ResMut<WakeUpCharts>,
+    existing: Query<Entity, With<WakeUpChartMenuText>>,
+) {
+    if !matches!(current_state.get(), GameState::MainMenu) {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let pressed = input.just_pressed(InputAction::WakeUpChart);
+    if pressed {
+        charts.cycle();
+    }
+
+    if !pressed && !existing.is_empty() {
+        return;
+    }
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let key = input.keymap.binding(InputAction::WakeUpChart).map(|b| b.key_label()).unwrap_or_default();
+    commands.spawn((
+        TextBundle::from_section(
+            format!("Wake-up chart: {} - press {} to change", charts.active().name, key),
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.95, 0.8, 0.5),
+                ..default()
+            },
+        ).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(440.0),
+            left: Val::Px(50.0),
+            ..default()
+        }),
+        WakeUpChartMenuText,
+    ));
+}
+
+// Shows the active chart while players wake up in Spring
+pub fn wake_up_chart_panel_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    charts: Res<WakeUpCharts>,
+    existing: Query<Entity, With<WakeUpChartPanel>>,
+) {
+    let in_spring = matches!(current_state.get(), GameState::Spring);
+    if in_spring == !existing.is_empty() {
+        return;
+    }
+
+    if !in_spring {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let chart = charts.active();
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(120.0),
+                right: Val::Px(20.0),
+                width: Val::Px(220.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.15, 0.05, 0.9)).into(),
+            ..default()
+        },
+        WakeUpChartPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            format!("🌅 WAKE-UP ({})\n\n{}", chart.name, chart.reference_lines()),
+            TextStyle {
+                font_size: 14.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+    });
+}
+
+pub const WAKE_UP_ROWS: u8 = 7;
+
+// AI never bids more than this for the temporary worker
+pub const MAX_AI_BID: u8 = 3;
+
+// Spring draft of wake-up rows: the player furthest behind on VP picks first. In the
+// simultaneous variant everyone hands in a secret ranking of rows instead, and the draft
+// order only settles conflicts. The blind bidding variant then collects secret lira bids
+// for the temporary worker the same way.
+#[derive(Resource, Default)]
+pub struct WakeUpDraft {
+    pub year: Option<u8>,
+    pub pickers: Vec<PlayerId>,
+    pub picks: Vec<(PlayerId, u8)>,
+    pub rankings: Vec<(PlayerId, Vec<u8>)>,
+    pub bids: Vec<(PlayerId, u8)>,
+    // Winner and price once the bids are revealed; nobody wins if every bid is 0
+    pub temp_worker: Option<(PlayerId, u8)>,
+    pub bids_revealed: bool,
+}
+
+impl WakeUpDraft {
+    // Ties keep the current turn order
+    pub fn start(&mut self, year: u8, standings: &[(PlayerId, u8)]) {
+        let mut pickers = standings.to_vec();
+        pickers.sort_by_key(|(_, vp)| *vp);
+        self.year = Some(year);
+        self.pickers = pickers.into_iter().map(|(id, _)| id).collect();
+        self.picks.clear();
+        self.rankings.clear();
+        self.bids.clear();
+        self.temp_worker = None;
+        self.bids_revealed = false;
+    }
+
+    pub fn has_ranked(&self, player: PlayerId) -> bool {
+        self.rankings.iter().any(|(id, _)| *id == player)
+    }
+
+    // First player, in draft order, still to hand in a ranking
+    pub fn next_to_rank(&self) -> Option<PlayerId> {
+        self.pickers.iter().copied().find(|id| !self.has_ranked(*id))
+    }
+
+    pub fn submit_ranking(&mut self, player: PlayerId, ranking: Vec<u8>) -> bool {
+        if !self.pickers.contains(&player) || self.has_ranked(player) || !self.picks.is_empty() {
+            return false;
+        }
+        self.rankings.push((player, ranking));
+        true
+    }
+
+    // Once every ranking is in, each player in draft order gets their best row still free;
+    // rows left off a ranking fall back to the first free row
+    pub fn resolve_rankings(&mut self) -> bool {
+        if self.next_to_rank().is_some() || !self.picks.is_empty() {
+            return false;
+        }
+        for player in self.pickers.clone() {
+            let ranking = self.rankings.iter().find(|(id, _)| *id == player).map(|(_, r)| r.clone()).unwrap_or_default();
+            let row = ranking.into_iter()
+                .find(|row| (1..=WAKE_UP_ROWS).contains(row) && !self.is_taken(*row))
+                .or_else(|| self.first_free_row());
+            if let Some(row) = row {
+                self.picks.push((player, row));
+            }
+        }
+        true
+    }
+
+    pub fn has_bid(&self, player: PlayerId) -> bool {
+        self.bids.iter().any(|(id, _)| *id == player)
+    }
+
+    // Bids open once every row is picked
+    pub fn next_to_bid(&self) -> Option<PlayerId> {
+        if !self.is_complete() || self.bids_revealed {
+            return None;
+        }
+        self.pickers.iter().copied().find(|id| !self.has_bid(*id))
+    }
+
+    pub fn submit_bid(&mut self, player: PlayerId, bid: u8) -> bool {
+        if !self.is_complete() || !self.pickers.contains(&player) || self.has_bid(player) || self.bids_revealed {
+            return false;
+        }
+        self.bids.push((player, bid));
+        true
+    }
+
+    // Once every bid is in, the highest one wins; ties go to whoever wakes up first
+    pub fn resolve_bids(&mut self) -> bool {
+        if !self.is_complete() || self.next_to_bid().is_some() || self.bids_revealed {
+            return false;
+        }
+        let row = |player: PlayerId| self.picks.iter().find(|(id, _)| *id == player).map_or(u8::MAX, |(_, r)| *r);
+        self.temp_worker = self.bids.iter().copied()
+            .filter(|(_, bid)| *bid > 0)
+            .min_by_key(|(id, bid)| (std::cmp::Reverse(*bid), row(*id)));
+        self.bids_revealed = true;
+        true
+    }
+
+    // A worker is worth about three lira to the AI, and it keeps enough to play the year
+    pub fn ai_bid(lira: u8) -> u8 {
+        (lira / 3).min(MAX_AI_BID)
+    }
+
+    // AI ranks paying rows first, earliest first, then the rest
+    pub fn ai_ranking(chart: &WakeUpChart) -> Vec<u8> {
+        let (paying, rest): (Vec<u8>, Vec<u8>) = (1..=WAKE_UP_ROWS)
+            .partition(|row| chart.bonus_at((*row - 1) as usize).is_some());
+        paying.into_iter().chain(rest).collect()
+    }
+
+    pub fn current_picker(&self) -> Option<PlayerId> {
+        self.pickers.get(self.picks.len()).copied()
+    }
+
+    pub fn is_taken(&self, row: u8) -> bool {
+        self.picks.iter().any(|(_, r)| *r == row)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.picks.len() >= self.pickers.len()
+    }
+
+    pub fn pick(&mut self, player: PlayerId, row: u8) -> bool {
+        if self.current_picker() != Some(player) || self.is_taken(row) || !(1..=WAKE_UP_ROWS).contains(&row) {
+            return false;
+        }
+        self.picks.push((player, row));
+        true
+    }
+
+    pub fn first_free_row(&self) -> Option<u8> {
+        (1..=WAKE_UP_ROWS).find(|row| !self.is_taken(*row))
+    }
+
+    // AI takes the earliest free row that pays something, else the earliest free row
+    pub fn ai_row(&self, chart: &WakeUpChart) -> Option<u8> {
+        (1..=WAKE_UP_ROWS)
+            .find(|row| !self.is_taken(*row) && chart.bonus_at((*row - 1) as usize).is_some())
+            .or_else(|| self.first_free_row())
+    }
+
+    // SPACE fills every remaining seat with the first free rows
+    pub fn auto_assign_rest(&mut self) {
+        while let (Some(player), Some(row)) = (self.current_picker(), self.first_free_row()) {
+            self.picks.push((player, row));
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct WakeUpTrack;
+
+#[derive(Component)]
+pub struct WakeUpRowButton(pub u8);
+
+#[derive(Component)]
+pub struct WakeUpHoverText;
+
+// With blind bidding on, the last row no longer comes with the temporary worker
+fn row_bonus_label(chart: &WakeUpChart, row: u8, bidding: bool) -> String {
+    match chart.bonus_at((row - 1) as usize) {
+        Some(bonus) => bonus.label(),
+        None if row == WAKE_UP_ROWS && !bidding => "Temporary worker this year".to_string(),
+        None => "No bonus".to_string(),
+    }
+}
+
+// Clickable wake-up track for the human on the clock; taken rows are blocked and
+// hovering a row shows its bonus from the active chart. A human at another machine picks
+// there, and this one waits for their pick to arrive.
+pub fn wake_up_track_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    mut draft: ResMut<WakeUpDraft>,
+    charts: Res<WakeUpCharts>,
+    players: Query<&Player>,
+    buttons: Query<(&Interaction, &WakeUpRowButton), Changed<Interaction>>,
+    mut hover_text: Query<&mut Text, With<WakeUpHoverText>>,
+    track: Query<Entity, With<WakeUpTrack>>,
+    mut shown: Local<Option<usize>>,
+    settings: Res<UserSettings>,
+    (remote, pbf, mut choices): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, EventWriter<SeatChoiceMade>),
+) {
+    let picker = draft.current_picker();
+    let human_picking = picker.is_some_and(|id| players.iter().any(|p| p.id == id && !p.is_ai))
+        && picker.is_some_and(|id| plays_locally(remote.as_deref(), pbf.as_deref(), id));
+
+    // The simultaneous variant ranks rows in its own panel
+    if !matches!(current_state.get(), GameState::Spring) || !human_picking || settings.house_rule_simultaneous_wake_up {
+        if !track.is_empty() {
+            for entity in track.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+        *shown = None;
+        return;
+    }
+    let Some(picker) = picker else { return };
+
+    let chart = charts.active();
+    for (interaction, button) in buttons.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                if draft.pick(picker, button.0) {
+                    info!("🌅 Player {} wakes up at row {}", picker.0 + 1, button.0);
+                    choices.send(SeatChoiceMade { player: picker, choice: SeatChoice::WakeUpRow(button.0) });
+                }
+            }
+            Interaction::Hovered => {
+                for mut text in hover_text.iter_mut() {
+                    text.sections[0].value = format!("Row {}: {}", button.0, row_bonus_label(chart, button.0, settings.house_rule_temp_worker_bidding));
+                }
+            }
+            Interaction::None => {}
+        }
+    }
+
+    if *shown == Some(draft.picks.len()) && !track.is_empty() {
+        return;
+    }
+    *shown = Some(draft.picks.len());
+
+    for entity in track.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let name = players.iter().find(|p| p.id == picker).map(|p| p.name.clone()).unwrap_or_default();
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(120.0),
+                left: Val::Percent(30.0),
+                width: Val::Px(460.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.15, 0.05, 0.95)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        WakeUpTrack,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            format!("🌅 {}: choose when to wake up", name),
+            TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        panel.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                margin: UiRect::vertical(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row_list| {
+            for row in 1..=WAKE_UP_ROWS {
+                let taken_by = draft.picks.iter().find(|(_, r)| *r == row).map(|(id, _)| *id);
+                let (label, color) = match taken_by {
+                    Some(id) => (format!("{}\nP{}", row, id.0 + 1), Srgba::new(0.25, 0.25, 0.25, 1.0)),
+                    None => (row.to_string(), Srgba::new(0.6, 0.45, 0.15, 1.0)),
+                };
+                let mut button = row_list.spawn(ButtonBundle {
+                    style: Style {
+                        width: Val::Px(54.0),
+                        height: Val::Px(44.0),
+                        margin: UiRect::right(Val::Px(6.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: Color::from(color).into(),
+                    ..default()
+                });
+                if taken_by.is_none() {
+                    button.insert(WakeUpRowButton(row));
+                }
+                button.with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        label,
+                        TextStyle {
+                            font_size: 15.0,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+
+        panel.spawn((
+            TextBundle::from_section(
+                "Hover a row to see its bonus",
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::srgb(0.95, 0.8, 0.5),
+                    ..default()
+                },
+            ),
+            WakeUpHoverText,
+        ));
+    });
+}
+
+#[derive(Component)]
+pub struct WakeUpRankingPanel;
+
+// Position in the ranking, 0 = first choice
+#[derive(Component)]
+pub struct WakeUpRankEntry(pub usize);
+
+#[derive(Component)]
+pub struct WakeUpRankConfirm;
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+// Simultaneous variant: the next human still to rank drags rows into order of preference,
+// then hands the ranking in. Only their own ranking is ever shown.
+pub fn wake_up_ranking_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    settings: Res<UserSettings>,
+    mut draft: ResMut<WakeUpDraft>,
+    charts: Res<WakeUpCharts>,
+    players: Query<&Player>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    entries: Query<(&WakeUpRankEntry, &Interaction, &Node, &GlobalTransform)>,
+    confirm: Query<&Interaction, (Changed<Interaction>, With<WakeUpRankConfirm>)>,
+    panels: Query<Entity, With<WakeUpRankingPanel>>,
+    // The ranking being edited and the entry being dragged
+    mut editing: Local<Option<(PlayerId, Vec<u8>)>>,
+    mut dragging: Local<Option<usize>>,
+    (remote, pbf, mut choices): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, EventWriter<SeatChoiceMade>),
+) {
+    let ranker = draft.next_to_rank()
+        .filter(|id| players.iter().any(|p| p.id == *id && !p.is_ai))
+        .filter(|id| plays_locally(remote.as_deref(), pbf.as_deref(), *id))
+        .filter(|_| settings.house_rule_simultaneous_wake_up && matches!(current_state.get(), GameState::Spring));
+    let Some(ranker) = ranker else {
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        *editing = None;
+        *dragging = None;
+        return;
+    };
+
+    let mut rebuild = panels.is_empty();
+    if editing.as_ref().map(|(id, _)| *id) != Some(ranker) {
+        *editing = Some((ranker, (1..=WAKE_UP_ROWS).collect()));
+        rebuild = true;
+    }
+    let Some((_, ranking)) = editing.as_mut() else { return };
+
+    if confirm.iter().any(|i| *i == Interaction::Pressed) {
+        if draft.submit_ranking(ranker, ranking.clone()) {
+            choices.send(SeatChoiceMade { player: ranker, choice: SeatChoice::WakeUpRanking(ranking.clone()) });
+        }
+        info!("🌅 Player {} handed in a wake-up ranking", ranker.0 + 1);
+        *editing = None;
+        *dragging = None;
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if let Some((entry, _, _, _)) = entries.iter().find(|(_, i, _, _)| **i == Interaction::Pressed) {
+        if dragging.is_none() {
+            *dragging = Some(entry.0);
+        }
+    }
+
+    // Dropping on another entry moves the dragged row to that place
+    if mouse.just_released(MouseButton::Left) {
+        let cursor = windows.get_single().ok().and_then(|w| w.cursor_position());
+        let target = cursor.and_then(|cursor| entries.iter()
+            .find(|(_, _, node, transform)| node.logical_rect(transform).contains(cursor))
+            .map(|(entry, _, _, _)| entry.0));
+        if let (Some(from), Some(to)) = (dragging.take(), target) {
+            if from != to && from < ranking.len() && to < ranking.len() {
+                let row = ranking.remove(from);
+                ranking.insert(to, row);
+                rebuild = true;
+            }
+        }
+    }
+
+    if !rebuild {
+        return;
+    }
+    for entity in panels.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let name = players.iter().find(|p| p.id == ranker).map(|p| p.name.clone()).unwrap_or_default();
+    spawn_ranking_panel(&mut commands, &name, ranking, charts.active(), settings.house_rule_temp_worker_bidding);
+}
+
+fn spawn_ranking_panel(commands: &mut Commands, name: &str, ranking: &[u8], chart: &WakeUpChart, bidding: bool) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(100.0),
+                left: Val::Percent(30.0),
+                width: Val::Px(360.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.15, 0.05, 0.95)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        WakeUpRankingPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            format!("🌅 {}: drag rows into your order of preference
+Kept secret until everyone has ranked", name),
+            TextStyle {
+                font_size: 15.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        ));
+
+        for (place, row) in ranking.iter().enumerate() {
+            panel.spawn((
+                ButtonBundle {
+                    style: Style {
+                        margin: UiRect::top(Val::Px(4.0)),
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::from(Srgba::new(0.6, 0.45, 0.15, 1.0)).into(),
+                    ..default()
+                },
+                WakeUpRankEntry(place),
+            )).with_children(|entry| {
+                entry.spawn(TextBundle::from_section(
+                    format!("{} choice — row {}: {}", ordinal(place + 1), row, row_bonus_label(chart, *row, bidding)),
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ));
+            });
+        }
+
+        panel.spawn((
+            ButtonBundle {
+                style: Style {
+                    margin: UiRect::top(Val::Px(10.0)),
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                    align_self: AlignSelf::FlexEnd,
+                    ..default()
+                },
+                background_color: Color::srgb(0.25, 0.45, 0.25).into(),
+                ..default()
+            },
+            WakeUpRankConfirm,
+        )).with_children(|button| {
+            button.spawn(TextBundle::from_section(
+                "Hand in ranking",
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
+
+#[derive(Component)]
+pub struct TempWorkerBidPanel;
+
+// Lira added to the bid being edited
+#[derive(Component)]
+pub struct TempWorkerBidStep(pub i8);
+
+#[derive(Component)]
+pub struct TempWorkerBidConfirm;
+
+// Blind bidding variant: the next human still to bid sets an amount up to their lira and
+// hands it in. As with rankings, only their own bid is ever shown.
+pub fn temp_worker_bid_system(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    settings: Res<UserSettings>,
+    mut draft: ResMut<WakeUpDraft>,
+    players: Query<&Player>,
+    steps: Query<(&Interaction, &TempWorkerBidStep), Changed<Interaction>>,
+    confirm: Query<&Interaction, (Changed<Interaction>, With<TempWorkerBidConfirm>)>,
+    panels: Query<Entity, With<TempWorkerBidPanel>>,
+    // The bidder and the amount being edited
+    mut editing: Local<Option<(PlayerId, u8)>>,
+    (remote, pbf, mut choices): (Option<Res<RemotePlay>>, Option<Res<PlayByFile>>, EventWriter<SeatChoiceMade>),
+) {
+    let bidder = draft.next_to_bid()
+        .filter(|id| plays_locally(remote.as_deref(), pbf.as_deref(), *id))
+        .and_then(|id| players.iter().find(|p| p.id == id && !p.is_ai))
+        .filter(|_| settings.house_rule_temp_worker_bidding && matches!(current_state.get(), GameState::Spring));
+    let Some(bidder) = bidder else {
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        *editing = None;
+        return;
+    };
+
+    let mut rebuild = panels.is_empty();
+    if editing.map(|(id, _)| id) != Some(bidder.id) {
+        *editing = Some((bidder.id, 0));
+        rebuild = true;
+    }
+    let Some((_, bid)) = editing.as_mut() else { return };
+
+    if confirm.iter().any(|i| *i == Interaction::Pressed) {
+        if draft.submit_bid(bidder.id, *bid) {
+            choices.send(SeatChoiceMade { player: bidder.id, choice: SeatChoice::TempWorkerBid(*bid) });
+        }
+        info!("⏳ Player {} handed in a bid for the temporary worker", bidder.id.0 + 1);
+        *editing = None;
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    for (_, step) in steps.iter().filter(|(i, _)| **i == Interaction::Pressed) {
+        let next = bid.saturating_add_signed(step.0).min(bidder.lira.get());
+        if next != *bid {
+            *bid = next;
+            rebuild = true;
+        }
+    }
+
+    if !rebuild {
+        return;
+    }
+    for entity in panels.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn_bid_panel(&mut commands, &bidder.name, *bid, bidder.lira.get());
+}
+
+fn spawn_bid_panel(commands: &mut Commands, name: &str, bid: u8, lira: u8) {
+    let button_style = Style {
+        margin: UiRect::right(Val::Px(6.0)),
+        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+        ..default()
+    };
+    let text_style = TextStyle {
+        font_size: 14.0,
+        color: Color::WHITE,
+        ..default()
+    };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(100.0),
+                left: Val::Percent(30.0),
+                width: Val::Px(360.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.2, 0.15, 0.05, 0.95)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        TempWorkerBidPanel,
+    )).with_children(|panel| {
+        panel.spawn(TextBundle::from_section(
+            format!("⏳ {}: bid lira for this year's temporary worker
+Kept secret until everyone has bid; 0 sits it out", name),
+            TextStyle {
+                font_size: 15.0,
+                ..text_style.clone()
+            },
+        ));
+
+        panel.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                margin: UiRect::vertical(Val::Px(8.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row| {
+            for (label, step) in [("−", -1), ("+", 1)] {
+                row.spawn((
+                    ButtonBundle {
+                        style: button_style.clone(),
+                        background_color: Color::from(Srgba::new(0.6, 0.45, 0.15, 1.0)).into(),
+                        ..default()
+                    },
+                    TempWorkerBidStep(step),
+                )).with_children(|button| {
+                    button.spawn(TextBundle::from_section(label, text_style.clone()));
+                });
+            }
+            row.spawn(TextBundle::from_section(format!("{} of {} lira", bid, lira), text_style.clone()));
+        });
+
+        panel.spawn((
+            ButtonBundle {
+                style: Style {
+                    align_self: AlignSelf::FlexEnd,
+                    ..button_style.clone()
+                },
+                background_color: Color::srgb(0.25, 0.45, 0.25).into(),
+                ..default()
+            },
+            TempWorkerBidConfirm,
+        )).with_children(|button| {
+            button.spawn(TextBundle::from_section("Hand in bid", text_style.clone()));
+        });
+    });
+}
+
+const BID_REVEAL_STEP_SECONDS: f32 = 0.6;
+// Steps the verdict stays up after the last bid turns over
+const BID_REVEAL_HOLD_STEPS: usize = 5;
+
+// Bids turn over one at a time in wake-up order, then the winner is named
+#[derive(Component)]
+pub struct TempWorkerBidReveal {
+    pub lines: Vec<String>,
+    pub verdict: String,
+    pub shown: usize,
+    pub timer: Timer,
+}
+
+impl TempWorkerBidReveal {
+    pub fn new(draft: &WakeUpDraft) -> Self {
+        let mut picks = draft.picks.clone();
+        picks.sort_by_key(|(_, row)| *row);
+        let lines = picks.iter()
+            .map(|(id, _)| {
+                let bid = draft.bids.iter().find(|(bidder, _)| bidder == id).map_or(0, |(_, bid)| *bid);
+                format!("Player {}: {} lira", id.0 + 1, bid)
+            })
+            .collect();
+        let verdict = match draft.temp_worker {
+            Some((winner, price)) => format!("Player {} hires the temporary worker for {} lira", winner.0 + 1, price),
+            None => "No bids: nobody hires the temporary worker".to_string(),
+        };
+        Self { lines, verdict, shown: 0, timer: GameClock::once_secs(BID_REVEAL_STEP_SECONDS) }
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = "⏳ Temporary worker bids".to_string();
+        for line in self.lines.iter().take(self.shown) {
+            text.push('\n');
+            text.push_str(line);
+        }
+        if self.shown > self.lines.len() {
+            text.push_str("\n\n");
+            text.push_str(&self.verdict);
+        }
+        text
+    }
+}
+
+pub fn spawn_bid_reveal(commands: &mut Commands, draft: &WakeUpDraft) {
+    let reveal = TempWorkerBidReveal::new(draft);
+    let mut card = TextBundle::from_section(
+        reveal.text(),
+        TextStyle {
+            font_size: 15.0,
+            color: Color::WHITE,
+            ..default()
+        },
+    ).with_style(Style {
+        position_type: PositionType::Absolute,
+        top: Val::Px(60.0),
+        left: Val::Percent(35.0),
+        width: Val::Px(320.0),
+        padding: UiRect::all(Val::Px(12.0)),
+        ..default()
+    }).with_background_color(Color::from(Srgba::new(0.15, 0.12, 0.08, 0.95)));
+    card.z_index = UiLayer::Announcement.z_index();
+    commands.spawn((card, reveal));
+}
+
+pub fn temp_worker_bid_reveal_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut reveals: Query<(Entity, &mut TempWorkerBidReveal, &mut Text), Without<MarkedForDespawn>>,
+) {
+    for (entity, mut reveal, mut text) in reveals.iter_mut() {
+        if mouse.just_pressed(MouseButton::Left) {
+            commands.entity(entity).insert(MarkedForDespawn);
+            continue;
+        }
+        reveal.timer.tick(time.delta());
+        if !reveal.timer.finished() {
+            continue;
+        }
+        reveal.timer.reset();
+        reveal.shown += 1;
+        if reveal.shown > reveal.lines.len() + BID_REVEAL_HOLD_STEPS {
+            commands.entity(entity).insert(MarkedForDespawn);
+            continue;
+        }
+        text.sections[0].value = reveal.text();
+    }
+}
@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use crate::components::*;
+use crate::systems::balance::AutoTestConfig;
+use crate::systems::settings::UserSettings;
+use crate::systems::simulation::SimGame;
+use crate::systems::wake_up::WakeUpCharts;
+use crate::systems::render_layers::UiLayer;
+
+const ROLLOUTS: usize = 24;
+const MAX_ROLLOUT_STEPS: usize = 2000;
+const SPARKLINE: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SPARKLINE_LENGTH: usize = 16;
+
+// Per-season win estimates for the current game, one entry per sample
+#[derive(Resource, Default)]
+pub struct WinProbability {
+    pub samples: Vec<WinSample>,
+    // Rollouts running on the async compute pool; dropping it cancels the task
+    pub pending: Option<PendingSample>,
+}
+
+pub struct PendingSample {
+    pub task: Task<Vec<(PlayerId, f32)>>,
+    pub year: u8,
+    pub season: GameState,
+}
+
+#[derive(Clone, Debug)]
+pub struct WinSample {
+    pub year: u8,
+    pub season: GameState,
+    pub odds: Vec<(PlayerId, f32)>,
+}
+
+impl WinProbability {
+    pub fn latest(&self, player: PlayerId) -> Option<f32> {
+        self.samples.last()?.odds.iter().find(|(id, _)| *id == player).map(|(_, p)| *p)
+    }
+
+    pub fn sparkline(&self, player: PlayerId) -> String {
+        let start = self.samples.len().saturating_sub(SPARKLINE_LENGTH);
+        self.samples[start..].iter()
+            .filter_map(|s| s.odds.iter().find(|(id, _)| *id == player))
+            .map(|(_, p)| SPARKLINE[((p * SPARKLINE.len() as f32) as usize).min(SPARKLINE.len() - 1)])
+            .collect()
+    }
+}
+
+#[derive(Component)]
+pub struct WinProbabilityPanel;
+
+#[derive(Component)]
+pub struct WinProbabilityText;
+
+// Plays the position out with the simulation AI and counts how often each player wins
+pub fn estimate_win_probability(base: &SimGame, seed: u64) -> Vec<(PlayerId, f32)> {
+    let mut wins = vec![0.0f32; base.players.len()];
+
+    for rollout in 0..ROLLOUTS {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(rollout as u64));
+        let mut game = base.clone();
+        for _ in 0..MAX_ROLLOUT_STEPS {
+            if game.is_over() {
+                break;
+            }
+            game.step(&mut rng);
+        }
+
+        // Unfinished rollouts count for whoever leads; ties share the win
+        let best = game.players.iter().map(|p| p.victory_points.get()).max().unwrap_or(0);
+        let leaders: Vec<_> = game.players.iter().enumerate()
+            .filter(|(_, p)| p.victory_points == best)
+            .map(|(i, _)| i)
+            .collect();
+        for i in &leaders {
+            wins[*i] += 1.0 / leaders.len() as f32;
+        }
+    }
+
+    base.players.iter()
+        .zip(wins)
+        .map(|(player, w)| (player.id, w / ROLLOUTS as f32))
+        .collect()
+}
+
+// Spectator (all-AI) games and testing mode always show odds; games with humans need the setting
+fn win_odds_enabled(settings: &UserSettings, test_config: &AutoTestConfig, players: &Query<&Player>) -> bool {
+    test_config.enabled || settings.win_odds_in_play || players.iter().all(|p| p.is_ai)
+}
+
+// Samples once at the start of each season; the rollouts run off the main thread
+pub fn win_probability_system(
+    mut win_probability: ResMut<WinProbability>,
+    settings: Res<UserSettings>,
+    test_config: Res<AutoTestConfig>,
+    players: Query<&Player>,
+    vineyards: Query<&Vineyard>,
+    hands: Query<&Hand>,
+    trackers: Query<&ResidualPaymentTracker>,
+    structures: Query<&Structure>,
+    (card_decks, config, turn_order, current_state): (Res<CardDecks>, Res<GameConfig>, Res<TurnOrder>, Res<State<GameState>>),
+    wake_up_charts: Res<WakeUpCharts>,
+    mut last_sampled: Local<Option<(u8, GameState)>>,
+) {
+    let season = current_state.get();
+    if matches!(season, GameState::Setup) {
+        win_probability.samples.clear();
+        win_probability.pending = None;
+        *last_sampled = None;
+        return;
+    }
+
+    // Record the running estimate once it completes
+    if let Some(pending) = win_probability.pending.as_mut() {
+        if let Some(odds) = block_on(future::poll_once(&mut pending.task)) {
+            let sample = WinSample { year: pending.year, season: pending.season.clone(), odds };
+            win_probability.pending = None;
+            win_probability.samples.push(sample);
+        }
+    }
+
+    if !matches!(season, GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter)
+        || players.is_empty()
+        || !win_odds_enabled(&settings, &test_config, &players) {
+        return;
+    }
+
+    let key = (config.current_year, season.clone());
+    if last_sampled.as_ref() == Some(&key) {
+        return;
+    }
+    *last_sampled = Some(key);
+
+    let base = SimGame::from_parts(
+        players.iter().cloned().collect(),
+        vineyards.iter().cloned().collect(),
+        hands.iter().cloned().collect(),
+        trackers.iter().cloned().collect(),
+        structures.iter().cloned().collect(),
+        card_decks.clone(),
+        &config,
+        season.clone(),
+        0,
+    )
+    .with_wake_up_chart(wake_up_charts.active())
+    .with_player_on_turn(turn_order.players.get(turn_order.current_player).copied());
+
+    // A season that ends before its estimate does is superseded by the new one
+    let seed = ((config.current_year as u64) << 8) | win_probability.samples.len() as u64;
+    win_probability.pending = Some(PendingSample {
+        task: AsyncComputeTaskPool::get().spawn(async move { estimate_win_probability(&base, seed) }),
+        year: config.current_year,
+        season: season.clone(),
+    });
+}
+
+pub fn win_probability_display_system(
+    mut commands: Commands,
+    win_probability: Res<WinProbability>,
+    settings: Res<UserSettings>,
+    test_config: Res<AutoTestConfig>,
+    players: Query<&Player>,
+    current_state: Res<State<GameState>>,
+    panels: Query<Entity, With<WinProbabilityPanel>>,
+    mut texts: Query<&mut Text, With<WinProbabilityText>>,
+) {
+    let in_game = matches!(current_state.get(), GameState::Spring | GameState::Summer | GameState::Fall | GameState::Winter);
+    let visible = in_game
+        && !win_probability.samples.is_empty()
+        && win_odds_enabled(&settings, &test_config, &players);
+
+    if !visible {
+        for entity in panels.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if !win_probability.is_changed() && !panels.is_empty() {
+        return;
+    }
+
+    let mut sorted: Vec<_> = players.iter().collect();
+    sorted.sort_by_key(|p| p.id.0);
+
+    let mut text = String::from("📈 WIN ODDS\n");
+    for player in sorted {
+        let odds = win_probability.latest(player.id).unwrap_or(0.0);
+        text.push_str(&format!("\n{:<10} {:>3.0}% {}", player.name, odds * 100.0, win_probability.sparkline(player.id)));
+    }
+
+    if let Ok(mut existing) = texts.get_single_mut() {
+        existing.sections[0].value = text;
+        return;
+    }
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                right: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::from(Srgba::new(0.1, 0.1, 0.15, 0.85)).into(),
+            z_index: UiLayer::Hud.z_index(),
+            ..default()
+        },
+        WinProbabilityPanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section(
+                text,
+                TextStyle {
+                    font_size: 13.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ),
+            WinProbabilityText,
+        ));
+    });
+}
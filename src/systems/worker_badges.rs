@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::game_logic::TemporaryWorker;
+
+// A badge on each player dashboard counting the workers still at home, split into regular,
+// grande and temporary, straight from the worker entities on the board.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorkerPool {
+    pub regular_free: u8,
+    pub regular_total: u8,
+    pub grande_free: u8,
+    pub grande_total: u8,
+    pub temporary_free: u8,
+    pub temporary_total: u8,
+}
+
+impl WorkerPool {
+    pub fn available(&self) -> u8 {
+        self.regular_free + self.grande_free + self.temporary_free
+    }
+}
+
+// `workers` pairs each worker with whether it is this year's temporary one
+pub fn worker_pool<'a>(owner: PlayerId, workers: impl IntoIterator<Item = (&'a Worker, bool)>) -> WorkerPool {
+    let mut pool = WorkerPool::default();
+    for (worker, temporary) in workers.into_iter().filter(|(w, _)| w.owner == owner) {
+        let free = worker.placed_at.is_none() as u8;
+        let (free_count, total) = if worker.is_grande {
+            (&mut pool.grande_free, &mut pool.grande_total)
+        } else if temporary {
+            (&mut pool.temporary_free, &mut pool.temporary_total)
+        } else {
+            (&mut pool.regular_free, &mut pool.regular_total)
+        };
+        *free_count += free;
+        *total += 1;
+    }
+    pool
+}
+
+pub fn badge_label(pool: &WorkerPool) -> String {
+    let mut label = format!("👷 {}/{}", pool.regular_free, pool.regular_total);
+    if pool.grande_total > 0 {
+        label.push_str(if pool.grande_free > 0 { "  ★ ready" } else { "  ★ used" });
+    }
+    if pool.temporary_total > 0 {
+        label.push_str(&format!("  ⏳ {}/{}", pool.temporary_free, pool.temporary_total));
+    }
+    label
+}
+
+#[derive(Component)]
+pub struct WorkerCountBadge {
+    pub player_id: PlayerId,
+}
+
+// Badges are added to the dashboards the first time they are seen, then kept current
+pub fn worker_badge_system(
+    mut commands: Commands,
+    dashboards: Query<(Entity, &PlayerDashboard)>,
+    mut badges: Query<(&WorkerCountBadge, &mut Text)>,
+    workers: Query<(&Worker, Has<TemporaryWorker>)>,
+) {
+    for (entity, dashboard) in dashboards.iter() {
+        let pool = worker_pool(dashboard.player_id, workers.iter());
+        let label = badge_label(&pool);
+        let color = if pool.available() > 0 { Color::srgb(0.6, 0.9, 0.6) } else { Color::srgb(0.6, 0.6, 0.6) };
+
+        if let Some((_, mut text)) = badges.iter_mut().find(|(badge, _)| badge.player_id == dashboard.player_id) {
+            if text.sections[0].value != label {
+                text.sections[0].value = label;
+                text.sections[0].style.color = color;
+            }
+            continue;
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    label,
+                    TextStyle {
+                        font_size: 14.0,
+                        color,
+                        ..default()
+                    },
+                ).with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    right: Val::Px(10.0),
+                    ..default()
+                }),
+                WorkerCountBadge { player_id: dashboard.player_id },
+            ));
+        });
+    }
+}
@@ -0,0 +1,83 @@
+// Action pipeline: every ActionRequested is validated, applied and logged by one system.
+
+use bevy::prelude::*;
+use viticulture::components::*;
+use viticulture::systems::*;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(State::new(GameState::Summer))
+        .insert_resource(GameConfig::default())
+        .insert_resource(CardDecks::new())
+        .insert_resource(GameLog::default())
+        .add_event::<ActionRequested>()
+        .add_event::<ActionResolved>()
+        .add_systems(Update, action_resolution_system);
+    app.world_mut().spawn(ActionSpaceSlot::new(ActionSpace::DrawVine, Vec2::new(10.0, 20.0), true, true));
+    for id in 0..2 {
+        app.world_mut().spawn(Player::new(id, format!("P{}", id + 1), false));
+        app.world_mut().spawn(Hand::new(PlayerId(id)));
+        app.world_mut().spawn(Worker::new(PlayerId(id), false, Vec2::ZERO));
+    }
+    app
+}
+
+fn request(app: &mut App, player: u8, action: ActionSpace, grande: bool) {
+    app.world_mut().send_event(ActionRequested { player: PlayerId(player), action, grande });
+    app.update();
+}
+
+fn resolved(app: &mut App) -> Vec<ActionResolved> {
+    app.world_mut().resource_mut::<Events<ActionResolved>>().drain().collect()
+}
+
+#[test]
+fn a_request_places_the_worker_and_logs_the_outcome() {
+    let mut app = app();
+    request(&mut app, 0, ActionSpace::DrawVine, false);
+
+    let events = resolved(&mut app);
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].player, events[0].outcome), (PlayerId(0), ActionOutcome::DrewVine));
+    assert_eq!(events[0].from, Vec2::ZERO);
+
+    let worker = app.world().get::<Worker>(events[0].worker).unwrap();
+    assert_eq!((worker.placed_at, worker.position), (Some(ActionSpace::DrawVine), Vec2::new(10.0, 20.0)));
+    let mut spaces = app.world_mut().query::<&ActionSpaceSlot>();
+    assert_eq!(spaces.single(app.world()).occupied_by, Some(PlayerId(0)));
+    let log = app.world().resource::<GameLog>();
+    assert_eq!(log.entries.len(), 1);
+    assert_eq!(log.entries[0].season, GameState::Summer);
+}
+
+#[test]
+fn illegal_requests_are_dropped() {
+    let mut app = app();
+    request(&mut app, 0, ActionSpace::DrawVine, false);
+    resolved(&mut app);
+
+    // The only column is taken and the second player has no grande
+    request(&mut app, 1, ActionSpace::DrawVine, false);
+    // Nor one for a space that is not on the board
+    request(&mut app, 1, ActionSpace::DrawWineOrder, false);
+    assert!(resolved(&mut app).is_empty());
+    assert_eq!(app.world().resource::<GameLog>().entries.len(), 1);
+    let mut workers = app.world_mut().query::<&Worker>();
+    assert_eq!(workers.iter(app.world()).filter(|w| w.placed_at.is_some()).count(), 1);
+}
+
+#[test]
+fn a_grande_takes_the_bonus_slot_of_an_occupied_space() {
+    let mut app = app();
+    app.world_mut().spawn(Worker::new(PlayerId(1), true, Vec2::ONE));
+    request(&mut app, 0, ActionSpace::DrawVine, false);
+    request(&mut app, 1, ActionSpace::DrawVine, true);
+
+    let events = resolved(&mut app);
+    assert_eq!(events.len(), 2);
+    assert!(events[1].grande);
+    assert_eq!(events[1].from, Vec2::ONE);
+    let mut spaces = app.world_mut().query::<&ActionSpaceSlot>();
+    let space = spaces.single(app.world());
+    assert_eq!((space.occupied_by, space.bonus_worker_slot), (Some(PlayerId(0)), Some(PlayerId(1))));
+}
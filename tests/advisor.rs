@@ -0,0 +1,50 @@
+// Advisor: the AI's top three spaces for the human's position, each with a short reason.
+
+use viticulture::components::*;
+use viticulture::systems::advisor::*;
+use viticulture::systems::ai::{AIDifficulty, AiDecisionInput};
+
+fn winter_input() -> AiDecisionInput {
+    let mut hand = Hand::new(PlayerId(0));
+    hand.wine_order_cards.push(WineOrderCard::new(1, 2, 0, 5, 1));
+    hand.wine_order_cards.push(WineOrderCard::new(2, 0, 6, 4, 2));
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(4);
+    vineyard.white_grapes.place(3);
+    AiDecisionInput {
+        difficulty: AIDifficulty::Intermediate,
+        valid_actions: vec![ActionSpace::DrawWineOrder, ActionSpace::Harvest, ActionSpace::MakeWine, ActionSpace::FillOrder, ActionSpace::TrainWorker],
+        hand,
+        vineyard,
+        ..AiDecisionInput::new(Player::new(0, "Ana".to_string(), false), GameState::Winter, 2)
+    }
+}
+
+#[test]
+fn the_best_three_scoring_actions_are_advised_best_first() {
+    let hints = advisor_hints(&winter_input());
+    assert_eq!(hints.len(), ADVISOR_HINTS);
+    assert_eq!(hints[0].action, ActionSpace::FillOrder);
+    assert_eq!(hints[0].reason, "Fill Order: +5 VP available");
+    assert_eq!(hints[1].action, ActionSpace::MakeWine);
+    assert_eq!(hints[1].reason, "Make Wine: 1 grape(s) on the crush pad");
+    assert_eq!(hints[2].action, ActionSpace::DrawWineOrder);
+    // Harvest scores nothing with no planted field, so it is never advised
+    assert!(hints.iter().all(|hint| hint.action != ActionSpace::Harvest));
+}
+
+#[test]
+fn pointless_actions_are_left_out_even_when_fewer_than_three_remain() {
+    let mut input = winter_input();
+    input.valid_actions = vec![ActionSpace::Harvest, ActionSpace::TrainWorker];
+    assert!(advisor_hints(&input).is_empty());
+}
+
+#[test]
+fn grande_only_spaces_say_so() {
+    let mut input = winter_input();
+    input.valid_actions.retain(|action| *action != ActionSpace::FillOrder);
+    input.grande_actions = vec![ActionSpace::FillOrder];
+    let hints = advisor_hints(&input);
+    assert_eq!(hints[0].reason, "Fill Order: +5 VP available (grande worker)");
+}
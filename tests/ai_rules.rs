@@ -0,0 +1,113 @@
+// AI rules configuration: every rule-changing setting reaches AI scoring and the simulator.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use viticulture::components::*;
+use viticulture::systems::ai::AiRules;
+use viticulture::systems::bonus_chits::CHITS_PER_YEAR;
+use viticulture::systems::festival::FESTIVAL_YEAR;
+use viticulture::systems::settings::UserSettings;
+use viticulture::systems::simulation::SimGame;
+
+fn player_with_workers(workers: u8) -> Player {
+    let mut player = Player::new(0, "AI".to_string(), true);
+    player.workers = workers;
+    player
+}
+
+#[test]
+fn rules_come_from_the_settings_and_the_game_config() {
+    let settings = UserSettings {
+        house_rule_worker_upkeep: true,
+        house_rule_sparkling_aging: true,
+        house_rule_bonus_chits: true,
+        ..UserSettings::default()
+    };
+    let config = GameConfig { max_years: 5, target_victory_points: 15, ..GameConfig::default() };
+    let rules = AiRules::from_settings(&settings, &config);
+    assert!(rules.worker_upkeep && rules.sparkling_aging && rules.bonus_chits && !rules.wine_festival);
+    assert_eq!((rules.max_years, rules.target_victory_points), (5, 15));
+    assert_eq!(rules.years_left(3), 2);
+    assert_eq!(AiRules::default(), AiRules::from_settings(&UserSettings::default(), &GameConfig::default()));
+}
+
+#[test]
+fn upkeep_makes_extra_workers_cost_more_the_earlier_they_are_trained() {
+    let hand = Hand::new(PlayerId(0));
+    let vineyard = Vineyard::new(PlayerId(0));
+    let player = player_with_workers(3);
+    let base = AiRules::default();
+    let upkeep = AiRules { worker_upkeep: true, ..base };
+
+    assert_eq!(base.action_adjustment(ActionSpace::TrainWorker, &player, &hand, &vineyard, 2), 0.0);
+    let early = upkeep.action_adjustment(ActionSpace::TrainWorker, &player, &hand, &vineyard, 2);
+    let late = upkeep.action_adjustment(ActionSpace::TrainWorker, &player, &hand, &vineyard, 6);
+    assert!(early < late && late < 0.0);
+    // Workers still inside the free allowance cost nothing
+    assert_eq!(upkeep.action_adjustment(ActionSpace::TrainWorker, &player_with_workers(1), &hand, &vineyard, 2), 0.0);
+}
+
+#[test]
+fn a_shortened_game_brings_the_final_year_forward() {
+    let hand = Hand::new(PlayerId(0));
+    let vineyard = Vineyard::new(PlayerId(0));
+    let player = player_with_workers(2);
+    let full = AiRules::default();
+    let short = AiRules { max_years: 5, ..full };
+
+    for action in [ActionSpace::DrawVine, ActionSpace::BuildStructure, ActionSpace::TrainWorker] {
+        assert_eq!(full.action_adjustment(action, &player, &hand, &vineyard, 5), 0.0);
+        assert!(short.action_adjustment(action, &player, &hand, &vineyard, 5) < 0.0, "{:?}", action);
+    }
+}
+
+#[test]
+fn an_order_that_reaches_the_target_is_worth_more() {
+    let mut hand = Hand::new(PlayerId(0));
+    hand.wine_order_cards.push(WineOrderCard::new(1, 1, 0, 3, 1));
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(2);
+    let mut player = player_with_workers(2);
+    let rules = AiRules { target_victory_points: 15, ..AiRules::default() };
+
+    player.victory_points = VicPoints::new(11);
+    assert_eq!(rules.action_adjustment(ActionSpace::FillOrder, &player, &hand, &vineyard, 3), 0.0);
+    player.victory_points = VicPoints::new(12);
+    assert!(rules.action_adjustment(ActionSpace::FillOrder, &player, &hand, &vineyard, 3) > 0.0);
+}
+
+#[test]
+fn the_simulator_plays_the_variants_it_is_given() {
+    let config = GameConfig { player_count: 2, ai_count: 2, ..GameConfig::default() };
+    let mut rng = StdRng::seed_from_u64(4);
+    let rules = AiRules { wine_festival: true, sparkling_aging: true, max_years: 5, ..AiRules::default() };
+    let mut game = SimGame::new(&config, &mut rng).with_rules(rules);
+    assert_eq!(game.max_years, 5);
+    assert!(game.vineyards.iter().all(|v| v.sparkling_aging));
+    assert_eq!(game.view_for(0, 0).rules, rules);
+
+    // The festival pays out as its spring opens
+    game.current_year = FESTIVAL_YEAR;
+    game.vineyards[0].red_wine.place(6);
+    let before = game.players[0].victory_points.get();
+    game.step(&mut rng);
+    assert_eq!(game.players[0].victory_points.get(), before + 1);
+}
+
+#[test]
+fn the_simulator_deals_bonus_chits_and_the_first_worker_collects_one() {
+    let config = GameConfig { player_count: 2, ai_count: 2, ..GameConfig::default() };
+    let mut rng = StdRng::seed_from_u64(4);
+    let mut plain = SimGame::new(&config, &mut rng);
+    plain.step(&mut rng);
+    assert!(plain.bonus_chits().is_empty());
+
+    let mut game = SimGame::new(&config, &mut rng).with_rules(AiRules { bonus_chits: true, ..AiRules::default() });
+    game.step(&mut rng);
+    let chits = game.bonus_chits();
+    assert_eq!(chits.len(), CHITS_PER_YEAR);
+
+    game.apply_action(0, chits[0].0);
+    assert_eq!(game.bonus_chits().len(), CHITS_PER_YEAR - 1);
+    assert!(game.bonus_chits().iter().all(|(action, _)| *action != chits[0].0));
+}
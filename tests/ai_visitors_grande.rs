@@ -0,0 +1,67 @@
+// AI visitor play and grande worker placement, gated by difficulty and value thresholds.
+
+use viticulture::components::*;
+use viticulture::systems::ai::*;
+use viticulture::systems::expansions::*;
+
+fn visitor(effect: VisitorEffect, cost: u8) -> VisitorCard {
+    VisitorCard { id: 1, name: "Test".to_string(), effect, season: VisitorSeason::Summer, cost }
+}
+
+fn player(lira: u8) -> Player {
+    let mut player = Player::new(0, "AI".to_string(), true);
+    player.lira = Lira::new(lira);
+    player
+}
+
+fn input(difficulty: AIDifficulty, grande_actions: Vec<ActionSpace>) -> AiDecisionInput {
+    let mut hand = Hand::new(PlayerId(0));
+    hand.wine_order_cards.push(WineOrderCard::new(1, 1, 0, 2, 1));
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(3);
+    vineyard.lira = Lira::new(6);
+    AiDecisionInput {
+        difficulty,
+        valid_actions: vec![ActionSpace::DrawVine],
+        grande_actions,
+        hand,
+        vineyard,
+        ..AiDecisionInput::new(player(6), GameState::Winter, 5)
+    }
+}
+
+#[test]
+fn visitors_are_valued_for_what_they_would_do_on_this_board() {
+    let hand = Hand::new(PlayerId(0));
+    let vineyard = Vineyard::new(PlayerId(0));
+    assert!(visitor_value(&visitor(VisitorEffect::GainVP(2), 0), &player(0), &hand, &vineyard) > VISITOR_PLAY_THRESHOLD);
+    // Nothing to plant, no wine to age, or no lira to pay: worth nothing
+    assert_eq!(visitor_value(&visitor(VisitorEffect::PlantFreeVine, 0), &player(5), &hand, &vineyard), 0.0);
+    assert_eq!(visitor_value(&visitor(VisitorEffect::AgeWine(2), 0), &player(5), &hand, &vineyard), 0.0);
+    assert_eq!(visitor_value(&visitor(VisitorEffect::GainVP(2), 3), &player(1), &hand, &vineyard), 0.0);
+    assert!(visitor_value(&visitor(VisitorEffect::GainLira(3), 2), &player(5), &hand, &vineyard)
+        < visitor_value(&visitor(VisitorEffect::GainLira(3), 0), &player(5), &hand, &vineyard));
+}
+
+#[test]
+fn only_stronger_ais_draw_from_a_worthwhile_pile() {
+    let hand = Hand::new(PlayerId(0));
+    let vineyard = Vineyard::new(PlayerId(0));
+    let rich = vec![visitor(VisitorEffect::GainVP(2), 0), visitor(VisitorEffect::GainLira(3), 0)];
+    let poor = vec![visitor(VisitorEffect::PlantFreeVine, 0), visitor(VisitorEffect::SwapFields, 0)];
+
+    assert!(should_play_visitor(AIDifficulty::Intermediate, &rich, &player(0), &hand, &vineyard));
+    assert!(should_play_visitor(AIDifficulty::Planner, &rich, &player(0), &hand, &vineyard));
+    assert!(!should_play_visitor(AIDifficulty::Beginner, &rich, &player(0), &hand, &vineyard));
+    assert!(!should_play_visitor(AIDifficulty::Intermediate, &poor, &player(0), &hand, &vineyard));
+    assert!(!should_play_visitor(AIDifficulty::Intermediate, &[], &player(0), &hand, &vineyard));
+}
+
+#[test]
+fn the_grande_goes_to_blocked_spaces_only_when_they_are_worth_it() {
+    let smart = input(AIDifficulty::Intermediate, vec![ActionSpace::FillOrder, ActionSpace::GiveTour]);
+    assert_eq!(smart.candidates(), vec![ActionSpace::DrawVine, ActionSpace::FillOrder]);
+
+    let beginner = input(AIDifficulty::Beginner, vec![ActionSpace::FillOrder]);
+    assert_eq!(beginner.candidates(), vec![ActionSpace::DrawVine]);
+}
@@ -0,0 +1,37 @@
+// Solo Automa mercy: when it triggers, how it is reported, and that it only applies to solo games.
+
+use viticulture::components::*;
+use viticulture::systems::coop::*;
+
+#[test]
+fn mercy_needs_year_four_and_a_deficit_over_eight() {
+    assert!(!mercy_applies(2, 20, MERCY_YEAR - 1));
+    assert!(!mercy_applies(2, 10, MERCY_YEAR));
+    assert!(!mercy_applies(2, 2 + MERCY_DEFICIT, MERCY_YEAR));
+    assert!(mercy_applies(2, 3 + MERCY_DEFICIT, MERCY_YEAR));
+    assert!(mercy_applies(0, 12, 6));
+    assert!(!mercy_applies(15, 3, 6));
+}
+
+#[test]
+fn only_a_single_human_against_the_automa_plays_solo() {
+    let mut coop = CoopMode::default();
+    assert!(!coop.is_solo(1));
+    coop.difficulty = Some(AutomaDifficulty::Normal);
+    assert!(coop.is_solo(1));
+    assert!(!coop.is_solo(2));
+    assert_eq!(coop.shared_target(1), 15);
+}
+
+#[test]
+fn skipped_blocks_are_listed_and_mark_the_game() {
+    let mut coop = CoopMode { difficulty: Some(AutomaDifficulty::Hard), ..Default::default() };
+    assert!(!coop.mercy_triggered());
+    assert_eq!(coop.mercy_text(), None);
+
+    coop.mercy_skips.push(MercySkip { year: 4, season: GameState::Summer, action: ActionSpace::PlantVine });
+    coop.mercy_skips.push(MercySkip { year: 6, season: GameState::Winter, action: ActionSpace::FillOrder });
+    assert!(coop.mercy_triggered());
+    let text = coop.mercy_text().unwrap();
+    assert!(text.contains("PlantVine in Summer of year 4, FillOrder in Winter of year 6"), "{}", text);
+}
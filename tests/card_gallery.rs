@@ -0,0 +1,67 @@
+// Card gallery: the enabled card pool, listed once per distinct card, with search and filters.
+
+use viticulture::components::*;
+use viticulture::systems::card_gallery::*;
+use viticulture::systems::expansions::*;
+use viticulture::systems::first_game::RulesPreset;
+use viticulture::systems::visitor_script::*;
+
+fn copies(cards: &[GalleryCard], kind: CardKind) -> usize {
+    cards.iter().filter(|c| c.kind == kind).map(|c| c.copies as usize).sum()
+}
+
+#[test]
+fn every_card_in_the_decks_is_listed() {
+    let decks = CardDecks::new();
+    let cards = gallery_cards(&decks, &[], RulesPreset::Full);
+    assert_eq!(copies(&cards, CardKind::Vine), decks.vine_deck.len());
+    assert_eq!(copies(&cards, CardKind::Order), decks.wine_order_deck.len());
+    assert_eq!(copies(&cards, CardKind::Mama), decks.mama_cards.len());
+    assert_eq!(copies(&cards, CardKind::Papa), decks.papa_cards.len());
+    assert_eq!(copies(&cards, CardKind::Visitor), 0);
+    assert!(cards.iter().all(|c| !c.rules.is_empty()));
+}
+
+#[test]
+fn the_first_game_pool_leaves_out_special_orders() {
+    let decks = CardDecks::new();
+    let allowed = decks.wine_order_deck.iter().filter(|o| RulesPreset::FirstGame.allows_order(o)).count();
+    let cards = gallery_cards(&decks, &[], RulesPreset::FirstGame);
+    assert_eq!(copies(&cards, CardKind::Order), allowed);
+    assert!(cards.iter().filter(|c| c.kind == CardKind::Order).all(|c| !c.rules.contains("Residual")));
+}
+
+#[test]
+fn visitors_come_from_the_deck_and_the_visitor_files() {
+    let mut deck = VisitorDeck::new();
+    deck.add_visitors(load_visitor_scripts(VISITOR_SCRIPT_DIR));
+    let visitors: Vec<VisitorCard> = deck.summer_visitors.into_iter().chain(deck.winter_visitors).collect();
+    let cards = gallery_cards(&CardDecks::new(), &visitors, RulesPreset::Full);
+    assert_eq!(copies(&cards, CardKind::Visitor), visitors.len());
+
+    let merchant = cards.iter().find(|c| c.name == "Merchant").unwrap();
+    assert_eq!(merchant.rules, "Summer visitor. Gain 3 lira.");
+    let broker = cards.iter().find(|c| c.name == "Broker").unwrap();
+    assert_eq!(broker.rules, "Summer visitor. Choose (gain 3 lira | draw 1 order).");
+}
+
+#[test]
+fn search_and_kind_filters_narrow_the_list() {
+    let mut gallery = CardGallery {
+        cards: gallery_cards(&CardDecks::new(), &[], RulesPreset::Full),
+        ..Default::default()
+    };
+    let everything = gallery.filtered().len();
+    assert_eq!(everything, gallery.cards.len());
+
+    gallery.search = "TRELLIS".to_string();
+    let trellis = gallery.filtered();
+    assert!(!trellis.is_empty() && trellis.len() < everything);
+    assert!(trellis.iter().all(|c| c.matches("trellis")));
+
+    gallery.kind = Some(CardKind::Mama);
+    assert!(gallery.filtered().iter().all(|c| c.kind == CardKind::Mama));
+
+    gallery.search = "no such card".to_string();
+    assert!(gallery.filtered().is_empty());
+}
@@ -0,0 +1,60 @@
+// Choice memory: each player's last dialog picks, found again in a new hand.
+
+use viticulture::components::*;
+use viticulture::systems::choice_memory::*;
+
+fn vine(id: u32, vine_type: VineType) -> VineCard {
+    VineCard { id, vine_type, cost: 1, art_style: CardArt::BasicRed, special_ability: None }
+}
+
+#[test]
+fn remembered_cards_are_found_by_kind_not_by_id() {
+    let mut memory = ChoiceMemory::default();
+    memory.remember("Ana", |choices| {
+        choices.vine = Some(VineType::White(2));
+        choices.order = Some((1, 1));
+    });
+
+    let mut hand = Hand::new(PlayerId(0));
+    hand.vine_cards = vec![vine(1, VineType::Red(2)), vine(9, VineType::White(2))];
+    hand.wine_order_cards = vec![WineOrderCard::new(3, 2, 0, 2, 1), WineOrderCard::new(4, 1, 1, 3, 1)];
+    assert_eq!(memory.vine_index("Ana", &hand), Some(1));
+    assert_eq!(memory.order_index("Ana", &hand), Some(1));
+
+    // Nothing carries over to another player
+    assert_eq!(memory.vine_index("Bo", &hand), None);
+    hand.vine_cards.truncate(1);
+    assert_eq!(memory.vine_index("Ana", &hand), None);
+}
+
+#[test]
+fn remembered_fields_must_still_be_harvestable() {
+    let mut memory = ChoiceMemory::default();
+    assert_eq!(memory.harvest_fields("Ana", &[0, 1, 2], 2), None);
+
+    memory.remember("Ana", |choices| choices.harvest_fields = vec![4, 1, 2]);
+    assert_eq!(memory.harvest_fields("Ana", &[0, 1, 2], 2), Some(vec![1, 2]));
+    assert_eq!(memory.harvest_fields("Ana", &[0, 1, 2], 1), Some(vec![1]));
+    assert_eq!(memory.harvest_fields("Ana", &[0, 3], 2), None);
+}
+
+#[test]
+fn choices_round_trip_through_the_profile_file_format() {
+    let mut memory = ChoiceMemory::default();
+    memory.remember("Ana", |choices| {
+        choices.vine = Some(VineType::Red(3));
+        choices.recipe = Some(WineType::Blush);
+    });
+    memory.remember("Ana", |choices| choices.harvest_fields = vec![0, 5]);
+
+    let json = serde_json::to_string(&memory).unwrap();
+    let loaded: ChoiceMemory = serde_json::from_str(&json).unwrap();
+    let expected = RememberedChoices {
+        vine: Some(VineType::Red(3)),
+        order: None,
+        recipe: Some(WineType::Blush),
+        harvest_fields: vec![0, 5],
+    };
+    assert_eq!(loaded.get("Ana"), expected);
+    assert_eq!(loaded.get("Bo"), RememberedChoices::default());
+}
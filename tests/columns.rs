@@ -0,0 +1,44 @@
+// Action space columns by player count, including the third column at 5-6 players.
+
+use viticulture::components::*;
+
+#[test]
+fn columns_open_by_player_count() {
+    assert_eq!([1, 2, 3, 4, 5, 6].map(open_columns), [1, 1, 2, 2, 3, 3]);
+
+    let board = ActionBoard::new();
+    let open = |player_count| board.spaces.iter().filter(|s| s.is_open(player_count)).count();
+    assert_eq!((open(2), open(4), open(6)), (10, 20, 30));
+}
+
+#[test]
+fn sixth_player_reaches_the_third_column() {
+    let mut board = ActionBoard::new();
+    for space in board.spaces.iter_mut().filter(|s| s.action == ActionSpace::DrawVine && s.column < 2) {
+        space.occupied_by = Some(PlayerId(0));
+    }
+
+    let slot = placement_slot(board.spaces.iter(), ActionSpace::DrawVine, PlayerId(5), 6, &GameState::Summer, false);
+    assert_eq!(slot, Some((2, false)));
+    assert_eq!(placement_slot(board.spaces.iter(), ActionSpace::DrawVine, PlayerId(3), 4, &GameState::Summer, false), None);
+}
+
+#[test]
+fn grande_slot_waits_for_a_full_row() {
+    let mut board = ActionBoard::new();
+    let plant = |board: &mut ActionBoard, column: usize| {
+        board.spaces.iter_mut()
+            .find(|s| s.action == ActionSpace::PlantVine && s.column == column)
+            .unwrap()
+            .occupied_by = Some(PlayerId(0));
+    };
+
+    plant(&mut board, 0);
+    assert!(!action_row_full(board.spaces.iter(), ActionSpace::PlantVine, 5));
+    assert_eq!(placement_slot(board.spaces.iter(), ActionSpace::PlantVine, PlayerId(1), 5, &GameState::Summer, true), Some((1, false)));
+
+    plant(&mut board, 1);
+    plant(&mut board, 2);
+    assert!(action_row_full(board.spaces.iter(), ActionSpace::PlantVine, 5));
+    assert_eq!(placement_slot(board.spaces.iter(), ActionSpace::PlantVine, PlayerId(1), 5, &GameState::Summer, true), Some((0, true)));
+}
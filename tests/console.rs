@@ -0,0 +1,60 @@
+// Debug console: command parsing, and commands run headless against a simulator game.
+
+use viticulture::components::*;
+use viticulture::systems::console::*;
+use viticulture::systems::simulation::SimGame;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn game() -> SimGame {
+    let config = GameConfig { player_count: 2, ai_count: 2, ..GameConfig::default() };
+    SimGame::new(&config, &mut StdRng::seed_from_u64(7))
+}
+
+#[test]
+fn parses_commands() {
+    assert_eq!(ConsoleCommand::parse("force 2 Make_Wine"), Ok(ConsoleCommand::Force { player: PlayerId(1), action: ActionSpace::MakeWine }));
+    assert_eq!(ConsoleCommand::parse(" set 1 vp 12 "), Ok(ConsoleCommand::Set { player: PlayerId(0), resource: ConsoleResource::VictoryPoints, value: 12 }));
+    assert_eq!(ConsoleCommand::parse("rollouts 20"), Ok(ConsoleCommand::Rollouts(20)));
+    assert!(ConsoleCommand::parse("force 0 harvest").is_err());
+    assert!(ConsoleCommand::parse("set 1 grapes 3").is_err());
+    assert!(ConsoleCommand::parse("rollouts 0").is_err());
+    assert!(ConsoleCommand::parse("").is_err());
+}
+
+#[test]
+fn edits_and_advances_a_headless_game() {
+    let mut game = game();
+    run_console_command(&mut game, ConsoleCommand::Set { player: PlayerId(1), resource: ConsoleResource::Lira, value: 9 }, 0).unwrap();
+    assert_eq!((game.players[1].lira.get(), game.vineyards[1].lira.get()), (9, 9));
+    assert!(run_console_command(&mut game, ConsoleCommand::Set { player: PlayerId(4), resource: ConsoleResource::Lira, value: 1 }, 0).is_err());
+
+    let hand = game.hands[0].vine_cards.len();
+    run_console_command(&mut game, ConsoleCommand::Force { player: PlayerId(0), action: ActionSpace::DrawVine }, 0).unwrap();
+    assert_eq!(game.hands[0].vine_cards.len(), hand + 1);
+
+    game.phase = GameState::Winter;
+    let year = game.current_year;
+    run_console_command(&mut game, ConsoleCommand::Advance, 0).unwrap();
+    assert_eq!((game.phase.clone(), game.current_year), (GameState::Spring, year + 1));
+    assert!(dump_state(&game).contains("P2"));
+}
+
+#[test]
+fn rollouts_replay_from_their_seed() {
+    let game = game();
+    let scores = rollout_scores(&game, 5, 3);
+    assert_eq!(scores.len(), 2);
+    assert!((scores.iter().map(|s| s.wins).sum::<f32>() - 5.0).abs() < 1e-3);
+    assert_eq!(scores, rollout_scores(&game, 5, 3));
+}
+
+#[test]
+fn the_player_on_turn_is_found_by_id_not_wake_up_position() {
+    let config = GameConfig { player_count: 3, ai_count: 3, ..GameConfig::default() };
+    let game = SimGame::new(&config, &mut StdRng::seed_from_u64(7));
+    // Wake-up order 2, 0, 1: the second seat to act is player 0
+    let wake_up_order = [PlayerId(2), PlayerId(0), PlayerId(1)];
+    let game = game.with_player_on_turn(wake_up_order.get(1).copied());
+    assert_eq!(game.players[game.current_player].id, PlayerId(0));
+}
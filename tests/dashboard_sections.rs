@@ -0,0 +1,61 @@
+// Dashboard sections: folded details, their summaries, and the open ones kept in settings.
+
+use viticulture::components::*;
+use viticulture::systems::dashboard_sections::*;
+use viticulture::systems::game_log::GameLog;
+use viticulture::systems::game_logic::ActionOutcome;
+use viticulture::systems::settings::UserSettings;
+
+#[test]
+fn sections_start_folded_and_toggle_open_and_shut() {
+    let mut sections = UserSettings::default().dashboard_sections;
+    assert!(DashboardSection::ALL.iter().all(|s| !sections.is_expanded(*s)));
+
+    sections.toggle(DashboardSection::Residuals);
+    assert!(sections.is_expanded(DashboardSection::Residuals));
+    assert_eq!(header_label(DashboardSection::Residuals, true), "▾ Residual income");
+    sections.toggle(DashboardSection::Residuals);
+    assert!(!sections.is_expanded(DashboardSection::Residuals));
+    assert_eq!(header_label(DashboardSection::Residuals, false), "▸ Residual income");
+}
+
+#[test]
+fn summaries_cover_only_the_players_own_details() {
+    let structures = [
+        Structure { structure_type: StructureType::Trellis, owner: PlayerId(0) },
+        Structure { structure_type: StructureType::Windmill, owner: PlayerId(1) },
+        Structure { structure_type: StructureType::Cottage, owner: PlayerId(0) },
+    ];
+    let structures: Vec<&Structure> = structures.iter().collect();
+    assert_eq!(structures_summary(PlayerId(0), &structures), "Trellis, Cottage");
+    assert_eq!(structures_summary(PlayerId(2), &structures), "None built");
+
+    let mut tracker = ResidualPaymentTracker::new(PlayerId(0));
+    tracker.advance(2);
+    assert_eq!(residuals_summary(Some(&tracker)), "Level 2: +2 lira each year");
+    assert_eq!(residuals_summary(None), "Level 0: +0 lira each year");
+
+    let mut log = GameLog::default();
+    let filled = |vp| ActionOutcome::FilledOrder { victory_points: vp, lira: 1, seasonal_bonus: 0 };
+    log.record(2, GameState::Winter, PlayerId(0), ActionSpace::FillOrder, filled(3));
+    log.record(3, GameState::Winter, PlayerId(1), ActionSpace::FillOrder, filled(4));
+    log.record(4, GameState::Winter, PlayerId(0), ActionSpace::FillOrder, filled(5));
+    log.record(4, GameState::Winter, PlayerId(0), ActionSpace::FillOrder, ActionOutcome::Nothing);
+    assert_eq!(filled_orders_summary(&log, PlayerId(0)), "2 filled, 8 VP: Y2 +3 VP, Y4 +5 VP");
+    assert_eq!(filled_orders_summary(&log, PlayerId(2)), "None yet");
+}
+
+#[test]
+fn open_sections_are_kept_with_the_settings() {
+    let mut settings = UserSettings::default();
+    settings.dashboard_sections.toggle(DashboardSection::FilledOrders);
+    settings.dashboard_sections.toggle(DashboardSection::Structures);
+
+    let json = serde_json::to_string(&settings).unwrap();
+    let loaded: UserSettings = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.dashboard_sections.expanded, vec![DashboardSection::FilledOrders, DashboardSection::Structures]);
+
+    // Settings files from before the sections existed open with everything folded
+    let old: UserSettings = serde_json::from_str("{\"advisor\": true}").unwrap();
+    assert_eq!(old.dashboard_sections, DashboardSections::default());
+}
@@ -0,0 +1,135 @@
+// Difficulty ladder: Advanced and Expert tiers, and per-seat difficulty and handicaps.
+
+use std::time::Duration;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use viticulture::components::*;
+use viticulture::systems::ai::*;
+use viticulture::systems::planner::{plan_action, PlannerInput};
+use viticulture::systems::settings::UserSettings;
+use viticulture::systems::simulation::SimGame;
+
+fn winter_input(difficulty: AIDifficulty, seed: u64) -> AiDecisionInput {
+    let mut hand = Hand::new(PlayerId(0));
+    hand.wine_order_cards.push(WineOrderCard::new(1, 2, 0, 5, 1));
+    hand.wine_order_cards.push(WineOrderCard::new(2, 0, 6, 4, 2));
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(4);
+    vineyard.white_grapes.place(3);
+    AiDecisionInput {
+        difficulty,
+        valid_actions: vec![ActionSpace::DrawWineOrder, ActionSpace::Harvest, ActionSpace::MakeWine, ActionSpace::FillOrder, ActionSpace::TrainWorker],
+        hand,
+        vineyard,
+        seed,
+        // Past the opening book
+        ..AiDecisionInput::new(Player::new(0, "AI".to_string(), true), GameState::Winter, 3)
+    }
+}
+
+// Without a rules snapshot every tier scores the same way, picking among fewer of the best
+#[test]
+fn stronger_tiers_stray_less_from_the_best_action() {
+    let best = winter_input(AIDifficulty::Expert, 0).scored_actions()[0].0;
+    let picks = |difficulty| (0..40).map(|seed| winter_input(difficulty, seed).choose().unwrap()).collect::<Vec<_>>();
+
+    assert!(picks(AIDifficulty::Expert).iter().all(|action| *action == best));
+    let top_two: Vec<ActionSpace> = winter_input(AIDifficulty::Advanced, 0).scored_actions().iter().take(2).map(|(a, _)| *a).collect();
+    assert!(picks(AIDifficulty::Advanced).iter().all(|action| top_two.contains(action)));
+    assert!(picks(AIDifficulty::Intermediate).iter().any(|action| !top_two.contains(action)));
+}
+
+#[test]
+fn expert_follows_the_planner_when_given_a_snapshot() {
+    let config = GameConfig { player_count: 3, ai_count: 3, ..GameConfig::default() };
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut game = SimGame::new(&config, &mut rng);
+    game.step(&mut rng);
+    let candidates = game.legal_actions(0);
+
+    let input = AiDecisionInput {
+        difficulty: AIDifficulty::Expert,
+        valid_actions: candidates.clone(),
+        seed: 42,
+        planner: Some(PlannerInput { game: game.clone(), player_idx: 0, budget: Duration::ZERO }),
+        ..AiDecisionInput::new(game.players[0].clone(), GameState::Summer, 3)
+    };
+    assert_eq!(input.choose(), plan_action(&game, 0, &candidates, Duration::ZERO, 42));
+}
+
+#[test]
+fn the_ladder_steps_one_rung_at_a_time() {
+    assert_eq!(AIDifficulty::Intermediate.harder(), Some(AIDifficulty::Advanced));
+    assert_eq!(AIDifficulty::Expert.harder(), Some(AIDifficulty::Planner));
+    assert_eq!(AIDifficulty::Planner.harder(), None);
+    assert_eq!(AIDifficulty::Advanced.easier(), Some(AIDifficulty::Intermediate));
+    assert_eq!(AIDifficulty::Beginner.easier(), None);
+}
+
+#[test]
+fn expert_searches_briefly_and_the_planner_for_the_configured_budget() {
+    let configured = Duration::from_millis(500);
+    assert_eq!(AIDifficulty::Planner.planner_budget(configured), Some(configured));
+    assert_eq!(AIDifficulty::Expert.planner_budget(configured), Some(EXPERT_PLANNER_BUDGET));
+    assert_eq!(AIDifficulty::Expert.planner_budget(Duration::from_millis(50)), Some(Duration::from_millis(50)));
+    assert_eq!(AIDifficulty::Advanced.planner_budget(configured), None);
+}
+
+#[test]
+fn settings_pick_the_table_level_and_planner_budget() {
+    let mut settings = UserSettings::default();
+    assert_eq!(AISettings::from_settings(&settings).ai_difficulty, AIDifficulty::Beginner);
+    settings.ai_difficulty = AIDifficulty::LADDER.len() as u8;
+    settings.planner_budget_ms = 1000;
+    let ai = AISettings::from_settings(&settings);
+    assert_eq!(ai.ai_difficulty, AIDifficulty::Planner);
+    assert_eq!(ai.planner_budget, Duration::from_millis(1000));
+    // Out-of-range values from an old file land on the nearest rung
+    settings.ai_difficulty = 0;
+    assert_eq!(settings.table_difficulty(), AIDifficulty::Beginner);
+}
+
+#[test]
+fn seats_cycle_through_the_ladder_and_back_to_the_table_level() {
+    let mut settings = GameSettings::default();
+    assert_eq!(settings.ai_difficulty(3, AIDifficulty::Intermediate), AIDifficulty::Intermediate);
+
+    let mut seen = Vec::new();
+    for _ in 0..6 {
+        settings.seat_mut(3).cycle_difficulty();
+        seen.push(settings.seat(3).difficulty);
+    }
+    assert_eq!(seen, vec![
+        Some(AIDifficulty::Beginner),
+        Some(AIDifficulty::Intermediate),
+        Some(AIDifficulty::Advanced),
+        Some(AIDifficulty::Expert),
+        Some(AIDifficulty::Planner),
+        None,
+    ]);
+    // Seats before the one set keep their defaults
+    assert_eq!(settings.seat(0), SeatSetup::default());
+}
+
+#[test]
+fn seat_labels_name_the_handicap_that_applies() {
+    let seat = SeatSetup { difficulty: Some(AIDifficulty::Expert), handicap: true };
+    assert_eq!(seat.label(true), format!("AI Expert, +{} lira", AI_HANDICAP_LIRA));
+    assert_eq!(seat.label(false), "Human, +1 vine card");
+    assert_eq!(SeatSetup::default().label(true), "AI (table level)");
+}
+
+#[test]
+fn seats_round_trip_through_the_settings_file_format() {
+    let mut settings = GameSettings::default();
+    *settings.seat_mut(1) = SeatSetup { difficulty: Some(AIDifficulty::Advanced), handicap: true };
+
+    let json = serde_json::to_string(&settings).unwrap();
+    let loaded: GameSettings = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.seats, settings.seats);
+    assert_eq!(loaded.ai_difficulty(1, AIDifficulty::Beginner), AIDifficulty::Advanced);
+
+    // A file without seats loads with every seat at its default
+    let old: GameSettings = serde_json::from_str("{\"ai_enabled\": true}").unwrap();
+    assert!(old.seats.is_empty());
+}
@@ -0,0 +1,37 @@
+// End Turn button: what it says, and whether it takes input, as the turn moves around the table.
+
+use viticulture::components::*;
+use viticulture::systems::end_turn::*;
+
+#[test]
+fn the_local_player_passes_the_season_until_a_worker_is_out() {
+    let ana = Player::new(0, "Ana".to_string(), false);
+    let state = end_turn_state(&GameState::Summer, Some(&ana), true, false, 3, 0);
+    assert_eq!(state, EndTurnState::PassSeason);
+    assert_eq!(state.label(), "Pass for the season");
+    assert!(state.enabled());
+
+    let state = end_turn_state(&GameState::Winter, Some(&ana), true, false, 2, 1);
+    assert_eq!(state, EndTurnState::EndTurn { workers_left: 2 });
+    assert_eq!(state.label(), "End Turn (2 workers left)");
+    assert!(state.enabled());
+    assert_eq!(EndTurnState::EndTurn { workers_left: 1 }.label(), "End Turn (1 worker left)");
+}
+
+#[test]
+fn other_seats_and_half_made_placements_leave_it_disabled() {
+    let ana = Player::new(0, "Ana".to_string(), false);
+    let bot = Player::new(1, "Bot".to_string(), true);
+
+    let placing = end_turn_state(&GameState::Summer, Some(&ana), true, true, 2, 1);
+    assert_eq!(placing.label(), "Place a worker…");
+    let ai = end_turn_state(&GameState::Summer, Some(&bot), true, false, 2, 0);
+    assert_eq!(ai.label(), "Waiting for AI…");
+    let remote = end_turn_state(&GameState::Winter, Some(&ana), false, false, 2, 0);
+    assert_eq!(remote.label(), "Waiting for Ana…");
+    assert!(![placing, ai, remote].iter().any(|s| s.enabled()));
+
+    // No worker turns outside summer and winter
+    assert_eq!(end_turn_state(&GameState::Fall, Some(&ana), true, false, 2, 0), EndTurnState::Hidden);
+    assert_eq!(end_turn_state(&GameState::Summer, None, true, false, 0, 0), EndTurnState::Hidden);
+}
@@ -0,0 +1,27 @@
+// Wine festival: which cellar wines score, and how the AI plans toward them.
+
+use viticulture::components::*;
+use viticulture::systems::festival::*;
+
+#[test]
+fn only_wines_of_value_five_or_more_score() {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(4);
+    vineyard.red_wine.place(5);
+    vineyard.white_wine.place(7);
+    vineyard.white_wine.place(2);
+    assert_eq!(festival_points(&vineyard), 2);
+    assert_eq!(festival_points(&Vineyard::new(PlayerId(1))), 0);
+}
+
+#[test]
+fn ai_holds_festival_wines_the_year_before() {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(6);
+    assert!(festival_ai_bonus(ActionSpace::FillOrder, &vineyard, FESTIVAL_YEAR - 1) < 0.0);
+    assert_eq!(festival_ai_bonus(ActionSpace::FillOrder, &vineyard, FESTIVAL_YEAR + 1), 0.0);
+
+    vineyard.white_grapes.place(5);
+    assert!(festival_ai_bonus(ActionSpace::MakeWine, &vineyard, 2) > 0.0);
+    assert_eq!(festival_ai_bonus(ActionSpace::MakeWine, &vineyard, FESTIVAL_YEAR), 0.0);
+}
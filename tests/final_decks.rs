@@ -0,0 +1,35 @@
+// Post-game deck browser: pile paging and the folded card listing.
+
+use viticulture::components::*;
+use viticulture::systems::final_decks::*;
+use viticulture::systems::keymap::Keymap;
+
+#[test]
+fn piles_cycle_both_ways() {
+    let mut pile = DeckPile::default();
+    for _ in 0..DECK_PILES.len() {
+        pile = pile.next();
+    }
+    assert_eq!(pile, DeckPile::WineOrderDeck);
+    assert_eq!(DeckPile::WineOrderDeck.previous(), DeckPile::VineDiscard);
+}
+
+#[test]
+fn listing_folds_identical_cards() {
+    let mut decks = CardDecks::new();
+    decks.vine_discard.clear();
+    let card = decks.vine_deck[0].clone();
+    decks.vine_discard.push(card.clone());
+    decks.vine_discard.push(card);
+
+    let lines = pile_lines(&decks, DeckPile::VineDiscard);
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].starts_with("2× "));
+    assert!(!lines[0].contains('\n'));
+
+    decks.wine_order_discard.clear();
+    let text = final_decks_text(&decks, DeckPile::WineOrderDiscard, &Keymap::default());
+    assert!(text.starts_with("🃏 FINAL DECKS  (←/→ change pile, D to close)"));
+    assert!(text.contains("Order discard: 0"));
+    assert!(text.ends_with("Empty"));
+}
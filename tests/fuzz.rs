@@ -0,0 +1,131 @@
+// Random-but-legal action sequences through the headless rules engine.
+// Run with `cargo test -- --ignored fuzz`; VITICULTURE_FUZZ_ITERATIONS sets the number of seeds.
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+use viticulture::components::*;
+use viticulture::systems::legal_actions::RequiredChoice;
+use viticulture::systems::simulation::SimGame;
+
+const DEFAULT_ITERATIONS: u64 = 200;
+const MAX_STEPS: usize = 5000;
+const PASS_CHANCE: f64 = 0.05;
+
+fn iterations() -> u64 {
+    std::env::var("VITICULTURE_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_ITERATIONS)
+}
+
+fn random_legal_action(game: &SimGame, player_idx: usize, rng: &mut StdRng) -> Option<ActionSpace> {
+    if rng.random_bool(PASS_CHANCE) {
+        return None;
+    }
+    game.legal_moves(player_idx).choose(rng).map(|legal| legal.action)
+}
+
+fn check_invariants(game: &SimGame) -> Result<(), String> {
+    for (i, player) in game.players.iter().enumerate() {
+        if game.workers_left[i] > player.total_workers() {
+            return Err(format!("{} has {} workers left of {}", player.name, game.workers_left[i], player.total_workers()));
+        }
+    }
+
+    if game.current_year > game.max_years + 1 {
+        return Err(format!("year {} past the {} year limit", game.current_year, game.max_years));
+    }
+
+    let mut order_ids = HashSet::new();
+    let orders = game.hands.iter().flat_map(|h| h.wine_order_cards.iter())
+        .chain(game.decks.wine_order_deck.iter())
+        .chain(game.decks.wine_order_discard.iter());
+    for order in orders {
+        if !order_ids.insert(order.id) {
+            return Err(format!("wine order {} exists twice", order.id));
+        }
+    }
+
+    let mut vine_ids = HashSet::new();
+    let vines = game.hands.iter().flat_map(|h| h.vine_cards.iter())
+        .chain(game.decks.vine_deck.iter())
+        .chain(game.decks.vine_discard.iter());
+    for vine in vines {
+        if !vine_ids.insert(vine.id) {
+            return Err(format!("vine card {} exists twice", vine.id));
+        }
+    }
+
+    if let Some(space) = game.spaces.iter().find(|s| s.occupied_by.is_some() && !s.is_open(game.player_count())) {
+        return Err(format!("{:?} column {} used in a {}-player game", space.action, space.column + 1, game.player_count()));
+    }
+
+    // Every listed move has a column to go to and only offers choices that exist
+    for (i, player) in game.players.iter().enumerate() {
+        for legal in game.legal_moves(i) {
+            if placement_slot(game.spaces.iter(), legal.action, player.id, game.player_count(), &game.phase, false).is_none() {
+                return Err(format!("{:?} listed for {} with no free column", legal.action, player.name));
+            }
+            let (hand, vineyard) = (&game.hands[i], &game.vineyards[i]);
+            let stale = legal.choices.iter().any(|choice| match choice {
+                RequiredChoice::VineCard(cards) => cards.is_empty() || cards.iter().any(|c| *c >= hand.vine_cards.len()),
+                RequiredChoice::WineOrder(orders) => orders.is_empty()
+                    || orders.iter().any(|o| hand.wine_order_cards.get(*o).is_none_or(|order| !vineyard.can_fulfill_order(order))),
+                RequiredChoice::HarvestFields { fields, .. } => fields.is_empty()
+                    || fields.iter().any(|f| vineyard.fields.get(*f).is_none_or(|field| field.vine.is_none())),
+            });
+            if stale {
+                return Err(format!("{:?} for {} offers a choice that is not there: {:?}", legal.action, player.name, legal.choices));
+            }
+        }
+    }
+
+    let occupied = game.spaces.iter().filter(|s| s.occupied_by.is_some()).count();
+    let workers: usize = game.players.iter().map(|p| p.total_workers() as usize).sum();
+    if occupied > workers {
+        return Err(format!("{} spaces occupied by {} workers", occupied, workers));
+    }
+
+    Ok(())
+}
+
+fn run_seed(seed: u64) -> Result<usize, String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let config = GameConfig {
+        player_count: 1 + (seed % 6) as u8,
+        ai_count: 0,
+        ..GameConfig::default()
+    };
+
+    let mut game = SimGame::new(&config, &mut rng);
+    for step in 0..MAX_STEPS {
+        if game.is_over() {
+            return Ok(step);
+        }
+        let result = game.step_with(&mut rng, random_legal_action);
+        check_invariants(&game).map_err(|e| format!("step {} ({}): {}", step, result.system, e))?;
+    }
+
+    Err(format!("game did not finish within {} steps", MAX_STEPS))
+}
+
+#[test]
+#[ignore]
+fn fuzz_random_legal_actions() {
+    let iterations = iterations();
+    let mut failures = Vec::new();
+    let mut total_steps = 0;
+
+    for seed in 0..iterations {
+        match panic::catch_unwind(AssertUnwindSafe(|| run_seed(seed))) {
+            Ok(Ok(steps)) => total_steps += steps,
+            Ok(Err(e)) => failures.push(format!("seed {}: {}", seed, e)),
+            Err(_) => failures.push(format!("seed {}: panicked", seed)),
+        }
+    }
+
+    println!("fuzzed {} seeds, {} steps", iterations, total_steps);
+    assert!(failures.is_empty(), "{} failing seeds:\n{}", failures.len(), failures.join("\n"));
+}
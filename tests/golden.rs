@@ -0,0 +1,250 @@
+// Replays the hand-verified game transcripts in tests/data through the headless
+// rules engine and fails on the first divergence from the recorded outcomes or end state.
+// Games start from the plain setup (no Mama/Papa cards) so every step can be checked by hand.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+use viticulture::components::*;
+use viticulture::systems::simulation::SimGame;
+
+const MAX_STEPS: usize = 1000;
+
+#[derive(Deserialize)]
+struct Transcript {
+    name: String,
+    player_count: u8,
+    max_years: u8,
+    target_victory_points: u8,
+    years: Vec<TranscriptYear>,
+    expected: ExpectedEnd,
+}
+
+#[derive(Deserialize)]
+struct TranscriptYear {
+    summer: Vec<Placement>,
+    winter: Vec<Placement>,
+}
+
+#[derive(Deserialize, Clone)]
+struct Placement {
+    player: usize,
+    action: String,
+    outcome: String,
+}
+
+#[derive(Deserialize)]
+struct ExpectedEnd {
+    year: u8,
+    vine_deck: usize,
+    wine_order_deck: usize,
+    players: Vec<ExpectedPlayer>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct ExpectedPlayer {
+    victory_points: u8,
+    lira: u8,
+    workers: u8,
+    vineyard_lira: u8,
+    // Token values, lowest first
+    red_grapes: Vec<u8>,
+    white_grapes: Vec<u8>,
+    red_wine: Vec<u8>,
+    white_wine: Vec<u8>,
+    planted_fields: usize,
+    vine_cards: usize,
+    wine_orders: usize,
+    residual_level: u8,
+}
+
+// A placement tagged with the year and season it belongs to
+struct Scheduled {
+    year: u8,
+    summer: bool,
+    placement: Placement,
+}
+
+// Orders placements by season: summer of year y is 2y, winter 2y + 1
+fn season_key(year: u8, summer: bool) -> u16 {
+    year as u16 * 2 + if summer { 0 } else { 1 }
+}
+
+// Earliest season key the game can still place workers in
+fn game_position(game: &SimGame) -> u16 {
+    match game.phase {
+        GameState::Spring | GameState::Summer => season_key(game.current_year, true),
+        GameState::Fall | GameState::Winter => season_key(game.current_year, false),
+        _ => u16::MAX,
+    }
+}
+
+fn parse_action(name: &str) -> Option<ActionSpace> {
+    ActionBoard::new().spaces.iter()
+        .map(|s| s.action)
+        .find(|action| format!("{:?}", action) == name)
+}
+
+fn new_game(transcript: &Transcript) -> SimGame {
+    let config = GameConfig {
+        player_count: transcript.player_count,
+        target_victory_points: transcript.target_victory_points,
+        max_years: transcript.max_years,
+        ai_count: 0,
+        ..GameConfig::default()
+    };
+
+    let ids = 0..transcript.player_count;
+    SimGame::from_parts(
+        ids.clone().map(|i| Player::new(i, format!("Golden Player {}", i + 1), false)).collect(),
+        ids.clone().map(|i| Vineyard::new(PlayerId(i))).collect(),
+        ids.clone().map(|i| Hand::new(PlayerId(i))).collect(),
+        ids.map(|i| ResidualPaymentTracker::new(PlayerId(i))).collect(),
+        Vec::new(),
+        CardDecks::new(),
+        &config,
+        GameState::Spring,
+        0,
+    )
+}
+
+fn actual_player(game: &SimGame, idx: usize) -> ExpectedPlayer {
+    let player = &game.players[idx];
+    let vineyard = &game.vineyards[idx];
+    ExpectedPlayer {
+        victory_points: player.victory_points.get(),
+        lira: player.lira.get(),
+        workers: player.workers,
+        vineyard_lira: vineyard.lira.get(),
+        red_grapes: vineyard.red_grapes.values(),
+        white_grapes: vineyard.white_grapes.values(),
+        red_wine: vineyard.red_wine.values(),
+        white_wine: vineyard.white_wine.values(),
+        planted_fields: vineyard.fields.iter().filter(|f| f.has_vine()).count(),
+        vine_cards: game.hands[idx].vine_cards.len(),
+        wine_orders: game.hands[idx].wine_order_cards.len(),
+        residual_level: game.trackers[idx].level,
+    }
+}
+
+fn replay(transcript: &Transcript) -> Result<(), String> {
+    let mut schedule: VecDeque<Scheduled> = VecDeque::new();
+    for (i, year) in transcript.years.iter().enumerate() {
+        let year_number = i as u8 + 1;
+        for (summer, placements) in [(true, &year.summer), (false, &year.winter)] {
+            for placement in placements {
+                if parse_action(&placement.action).is_none() {
+                    return Err(format!("unknown action '{}'", placement.action));
+                }
+                schedule.push_back(Scheduled { year: year_number, summer, placement: placement.clone() });
+            }
+        }
+    }
+
+    let mut game = new_game(transcript);
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut illegal = None;
+
+    for _ in 0..MAX_STEPS {
+        if game.is_over() {
+            break;
+        }
+
+        let result = game.step_with(&mut rng, |game, idx, _| {
+            let next = schedule.front()?;
+            let summer = matches!(game.phase, GameState::Summer);
+            if next.year != game.current_year || next.summer != summer || next.placement.player != idx {
+                return None;
+            }
+            let action = parse_action(&next.placement.action)?;
+            if !game.legal_actions(idx).contains(&action) {
+                illegal = Some(format!("year {}: player {} cannot place on {:?}", game.current_year, idx, action));
+                return None;
+            }
+            Some(action)
+        });
+
+        if let Some(error) = illegal.take() {
+            return Err(error);
+        }
+
+        if let Some(action) = result.action {
+            let expected = schedule.pop_front().expect("placement made without a scheduled entry");
+            let outcome = result.outcome.map(|o| format!("{:?}", o)).unwrap_or_default();
+            if outcome != expected.placement.outcome {
+                return Err(format!(
+                    "year {}: player {} {:?} gave {}, transcript says {}",
+                    expected.year, expected.placement.player, action, outcome, expected.placement.outcome
+                ));
+            }
+        } else if let Some(next) = schedule.front() {
+            if season_key(next.year, next.summer) < game_position(&game) {
+                return Err(format!(
+                    "season ended before player {} placed on {} in year {}",
+                    next.placement.player, next.placement.action, next.year
+                ));
+            }
+        }
+    }
+
+    if !game.is_over() {
+        return Err(format!("game did not finish within {} steps", MAX_STEPS));
+    }
+    if let Some(next) = schedule.front() {
+        return Err(format!("game ended with {} placements unplayed, starting at year {} {}", schedule.len(), next.year, next.placement.action));
+    }
+
+    let expected = &transcript.expected;
+    if game.current_year != expected.year {
+        return Err(format!("ended in year {}, expected {}", game.current_year, expected.year));
+    }
+    if game.decks.vine_deck.len() != expected.vine_deck {
+        return Err(format!("vine deck has {} cards, expected {}", game.decks.vine_deck.len(), expected.vine_deck));
+    }
+    if game.decks.wine_order_deck.len() != expected.wine_order_deck {
+        return Err(format!("wine order deck has {} cards, expected {}", game.decks.wine_order_deck.len(), expected.wine_order_deck));
+    }
+    if game.players.len() != expected.players.len() {
+        return Err(format!("{} players, expected {}", game.players.len(), expected.players.len()));
+    }
+    for (idx, expected_player) in expected.players.iter().enumerate() {
+        let actual = actual_player(&game, idx);
+        if actual != *expected_player {
+            return Err(format!("player {} ended as {:?}, expected {:?}", idx, actual, expected_player));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn golden_transcripts() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .expect("tests/data is missing")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no transcripts in {}", dir.display());
+
+    let mut failures = Vec::new();
+    for path in &paths {
+        let text = fs::read_to_string(path).expect("unreadable transcript");
+        let transcript: Transcript = match serde_json::from_str(&text) {
+            Ok(transcript) => transcript,
+            Err(e) => {
+                failures.push(format!("{}: {}", path.display(), e));
+                continue;
+            }
+        };
+        if let Err(e) = replay(&transcript) {
+            failures.push(format!("{}: {}", transcript.name, e));
+        }
+    }
+
+    assert!(failures.is_empty(), "{} diverging transcripts:\n{}", failures.len(), failures.join("\n"));
+}
@@ -0,0 +1,45 @@
+// Hand limit discards: what must go, what the AI picks, and where the cards end up.
+
+use viticulture::components::*;
+use viticulture::systems::hand_limit::*;
+
+fn vine(id: u32, value: u8) -> VineCard {
+    VineCard { id, vine_type: VineType::Red(value), cost: 1, art_style: CardArt::BasicRed, special_ability: None }
+}
+
+fn full_hand() -> Hand {
+    let mut hand = Hand::new(PlayerId(0));
+    hand.vine_cards = vec![vine(1, 2), vine(2, 1), vine(3, 2), vine(4, 2), vine(5, 2), vine(6, 2)];
+    // A cheap order far out of reach, and two good ones
+    hand.wine_order_cards = vec![WineOrderCard::new(10, 2, 0, 6, 2), WineOrderCard::new(11, 3, 0, 1, 1), WineOrderCard::new(12, 1, 0, 6, 2)];
+    hand
+}
+
+#[test]
+fn requirement_follows_the_limits() {
+    let hand = full_hand();
+    assert_eq!(discard_requirement(&hand, false), DiscardRequirement { vines: 0, orders: 0, any: 2 });
+    assert_eq!(discard_requirement(&hand, true), DiscardRequirement { vines: 1, orders: 0, any: 0 });
+
+    let requirement = discard_requirement(&hand, false);
+    assert!(requirement.satisfied_by(&[DiscardCard::Vine(0), DiscardCard::Order(2)]));
+    assert!(!requirement.satisfied_by(&[DiscardCard::Vine(0)]));
+}
+
+#[test]
+fn ai_discards_its_weakest_cards_to_the_piles() {
+    let mut hand = full_hand();
+    let vineyard = Vineyard::new(PlayerId(0));
+    let requirement = discard_requirement(&hand, false);
+
+    let choice = ai_discard_choice(&hand, &vineyard, &[], requirement);
+    assert_eq!(choice, vec![DiscardCard::Order(1), DiscardCard::Vine(1)]);
+
+    let mut decks = CardDecks::new();
+    let (vine_discard, order_discard) = (decks.vine_discard.len(), decks.wine_order_discard.len());
+    discard_cards(&mut hand, &mut decks, &choice);
+    assert_eq!(hand.vine_cards.len() + hand.wine_order_cards.len(), HAND_LIMIT);
+    assert_eq!(decks.vine_discard.len(), vine_discard + 1);
+    assert_eq!(decks.wine_order_discard.last().map(|o| o.id), Some(11));
+    assert_eq!(decks.wine_order_discard.len(), order_discard + 1);
+}
@@ -0,0 +1,43 @@
+// Hotseat handoff: the privacy screen goes up only when the turn reaches a different human.
+
+use viticulture::components::*;
+use viticulture::systems::handoff::*;
+
+#[test]
+fn first_human_turn_waits_for_confirmation() {
+    let mut handoff = Handoff::default();
+    handoff.turn_changed(Some(PlayerId(0)));
+    assert_eq!(handoff.pending, Some(PlayerId(0)));
+    assert!(handoff.hides_hand());
+
+    handoff.confirm();
+    assert_eq!(handoff.pending, None);
+    assert_eq!(handoff.last_shown, Some(PlayerId(0)));
+    assert!(!handoff.hides_hand());
+}
+
+#[test]
+fn same_human_again_keeps_the_board_open() {
+    let mut handoff = Handoff::default();
+    handoff.turn_changed(Some(PlayerId(1)));
+    handoff.confirm();
+
+    // An AI turn in between does not count as passing the device
+    handoff.turn_changed(None);
+    handoff.turn_changed(Some(PlayerId(1)));
+    assert!(!handoff.hides_hand());
+}
+
+#[test]
+fn next_human_triggers_the_screen() {
+    let mut handoff = Handoff::default();
+    handoff.turn_changed(Some(PlayerId(0)));
+    handoff.confirm();
+
+    handoff.turn_changed(Some(PlayerId(2)));
+    assert_eq!(handoff.pending, Some(PlayerId(2)));
+    // Still waiting while the turn sits with the same player
+    handoff.turn_changed(Some(PlayerId(2)));
+    assert_eq!(handoff.pending, Some(PlayerId(2)));
+    assert_eq!(handoff.last_shown, Some(PlayerId(0)));
+}
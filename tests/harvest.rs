@@ -0,0 +1,34 @@
+// Harvest field selection: the picked fields, and the best-first default when nothing was picked.
+
+use viticulture::components::*;
+
+fn planted_vineyard() -> Vineyard {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.fields[0].vine = Some(VineType::Red(2));
+    vineyard.fields[2].vine = Some(VineType::Red(3));
+    vineyard.fields[4].vine = Some(VineType::White(4));
+    vineyard.fields[5].vine = Some(VineType::Red(1));
+    vineyard
+}
+
+#[test]
+fn harvests_only_the_selected_fields() {
+    let mut vineyard = planted_vineyard();
+
+    // Field 3 is empty and field 0 is listed twice: only two grapes come in
+    assert_eq!(vineyard.harvest_selected(&[], &[0, 5, 3, 0]), 2);
+    assert_eq!(vineyard.red_grapes.values(), vec![1, 2]);
+    assert!(vineyard.white_grapes.values().is_empty());
+}
+
+#[test]
+fn default_harvest_takes_the_best_fields_up_to_the_limit() {
+    let mut vineyard = planted_vineyard();
+    assert_eq!(vineyard.harvestable_fields(), vec![4, 2, 0, 5]);
+
+    assert_eq!(vineyard.harvest_fields(&[], harvest_limit(false)), 2);
+    assert_eq!((vineyard.red_grapes.values(), vineyard.white_grapes.values()), (vec![3], vec![4]));
+
+    let mut bonus = planted_vineyard();
+    assert_eq!(bonus.harvest_fields(&[], harvest_limit(true)), 3);
+}
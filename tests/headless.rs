@@ -0,0 +1,100 @@
+// Headless balance batches: argument parsing, seat rotation and the aggregate report.
+
+use bevy::prelude::*;
+use viticulture::systems::agents::*;
+use viticulture::systems::ai::AiRules;
+use viticulture::systems::headless::*;
+use viticulture::HeadlessBatchPlugin;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn headless_flag_is_required_and_the_rest_default() {
+    assert_eq!(parse_headless_args(&args(&["viticulture", "--games", "10"])), None);
+
+    let batch = parse_headless_args(&args(&["viticulture", "--headless"])).unwrap();
+    assert_eq!(batch.games, DEFAULT_BATCH_GAMES);
+    assert_eq!(batch.agents, vec!["intermediate", "beginner"]);
+    assert_eq!(batch.report, DEFAULT_REPORT_STEM);
+
+    let batch = parse_headless_args(&args(&[
+        "viticulture", "--headless", "--games", "5000", "--agents", "beginner,beginner,intermediate", "--seed", "9", "--report", "runs/b",
+    ])).unwrap();
+    assert_eq!(batch.games, 5000);
+    assert_eq!(batch.agents.len(), 3);
+    assert_eq!(batch.seed, 9);
+    assert_eq!(batch.report, "runs/b");
+}
+
+#[test]
+fn seats_rotate_between_games() {
+    let batch = parse_headless_args(&args(&["viticulture", "--headless", "--agents", "a,b,c"])).unwrap();
+    assert_eq!(batch.seating(0), vec!["a", "b", "c"]);
+    assert_eq!(batch.seating(1), vec!["b", "c", "a"]);
+    assert_eq!(batch.seating(3), batch.seating(0));
+}
+
+#[test]
+fn tally_counts_every_seat_and_one_winner_per_game() {
+    let batch = parse_headless_args(&args(&["viticulture", "--headless", "--games", "6"])).unwrap();
+    let registry = AgentRegistry::default();
+    let mut tally = BatchTally::default();
+    for game in 0..batch.games {
+        let mut agents: Vec<Box<dyn Agent>> = batch.seating(game).iter().map(|n| registry.create(n).unwrap()).collect();
+        tally.record(&run_agent_match(&AiRules::default(), game as u64, &mut agents));
+    }
+
+    let report = tally.report(&batch);
+    assert_eq!(report.games, 6);
+    assert_eq!(report.players, 2);
+    assert_eq!(report.agents.iter().map(|a| a.games).sum::<u32>(), 12);
+    assert_eq!(report.agents.iter().map(|a| a.wins).sum::<u32>(), 6);
+    assert_eq!(report.seats.iter().map(|s| s.wins).sum::<u32>(), 6);
+    assert_eq!(report.to_csv().lines().count(), 1 + report.agents.len() + report.seats.len());
+}
+
+#[test]
+fn batch_app_writes_the_report_and_exits() {
+    let stem = std::env::temp_dir().join(format!("viticulture-batch-{}", std::process::id()));
+    let stem = stem.to_string_lossy().to_string();
+    let batch = parse_headless_args(&args(&["viticulture", "--headless", "--games", "3", "--report", &stem])).unwrap();
+
+    let mut app = App::new();
+    app.add_plugins(HeadlessBatchPlugin(batch));
+    app.update();
+
+    assert_eq!(app.should_exit(), Some(AppExit::Success));
+    let report: BatchReport = serde_json::from_str(&std::fs::read_to_string(format!("{}.json", stem)).unwrap()).unwrap();
+    assert_eq!(report.games, 3);
+    assert!(std::fs::read_to_string(format!("{}.csv", stem)).unwrap().starts_with("kind,name"));
+    let _ = std::fs::remove_file(format!("{}.json", stem));
+    let _ = std::fs::remove_file(format!("{}.csv", stem));
+}
+
+#[test]
+fn variant_batches_replay_the_games_under_each_rule_set() {
+    let batch = parse_headless_args(&args(&["viticulture", "--headless", "--games", "2", "--variants"])).unwrap();
+    assert!(batch.variants);
+    assert_eq!(batch.rule_sets()[0].0, "base");
+    assert_eq!(batch.total_games(), 2 * (1 + variant_rule_sets().len() as u32));
+
+    let registry = AgentRegistry::default();
+    let mut tally = BatchTally::default();
+    for (set, (variant, rules)) in batch.rule_sets().into_iter().enumerate() {
+        for game in 0..batch.games {
+            let mut agents: Vec<Box<dyn Agent>> = batch.seating(game).iter().map(|n| registry.create(n).unwrap()).collect();
+            let result = run_agent_match(&rules, game as u64, &mut agents);
+            if set == 0 { tally.record(&result) } else { tally.record_variant(variant, &result) }
+        }
+    }
+
+    let report = tally.report(&batch);
+    assert_eq!(tally.total_played(), batch.total_games());
+    assert_eq!(report.games, 2);
+    assert_eq!(report.variants.len(), variant_rule_sets().len());
+    assert!(report.variants.iter().all(|v| v.games == 2 && v.agents.iter().map(|a| a.wins).sum::<u32>() == 2));
+    let variant_rows = report.variants.iter().map(|v| v.agents.len()).sum::<usize>();
+    assert_eq!(report.to_csv().lines().count(), 1 + report.agents.len() + report.seats.len() + variant_rows);
+}
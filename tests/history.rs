@@ -0,0 +1,76 @@
+// Past games: the list filters and the archived final board shown in the viewer.
+
+use viticulture::components::*;
+use viticulture::systems::history::*;
+use viticulture::systems::save::*;
+use viticulture::systems::settings::UserSettings;
+
+fn final_state() -> SaveData {
+    let mut fields: [Option<VineFieldSave>; 9] = Default::default();
+    fields[0] = Some(VineFieldSave { vine: Some(VineTypeSave { is_red: true, value: 3 }), field_type: 0, sold_this_year: false });
+    SaveData {
+        version: SAVE_FORMAT_VERSION,
+        players: vec![
+            PlayerSave { id: 1, name: "Bea".to_string(), victory_points: 17, lira: 2, workers: 3, grande_worker_available: true, is_ai: true },
+            PlayerSave { id: 0, name: "Ana".to_string(), victory_points: 21, lira: 4, workers: 3, grande_worker_available: true, is_ai: false },
+        ],
+        vineyards: vec![VineyardSave {
+            owner_id: 0,
+            fields,
+            red_grape_tokens: Vec::new(),
+            white_grape_tokens: vec![2],
+            red_wine_tokens: vec![7],
+            white_wine_tokens: Vec::new(),
+            vintages: Vec::new(),
+            lira: 4,
+        }],
+        hands: Vec::new(),
+        workers: Vec::new(),
+        turn_order: TurnOrderSave { players: vec![0, 1], current_player: 0, wake_up_order: Vec::new(), passed: Vec::new(), wake_up_bonuses: Vec::new() },
+        config: GameConfigSave { player_count: 6, target_victory_points: 20, current_year: 6, max_years: 7, ai_count: 1, seed: None },
+        current_state: 6,
+        action_spaces: Vec::new(),
+        trackers: Vec::new(),
+        decks: DeckSave::default(),
+        notes: Vec::new(),
+        structures: vec![StructureSave { owner_id: 0, structure_type: StructureType::Cottage }],
+        card_decks: None,
+        mama_cards: Vec::new(),
+        papa_cards: Vec::new(),
+        festival_held: false,
+        rng: None,
+        play_by_file: None,
+    }
+}
+
+fn archive(player_count: u8) -> ArchivedGame {
+    ArchivedGame {
+        timestamp: 1_700_000_000,
+        seed: None,
+        player_count,
+        ai_count: 1,
+        years_played: 6,
+        result: GameResult::Won,
+        standings: Vec::new(),
+        settings: UserSettings::default(),
+        final_state: final_state(),
+        log: String::new(),
+    }
+}
+
+#[test]
+fn the_player_filter_reaches_six_seat_games() {
+    let past = PastGames { games: vec![archive(2), archive(6)], player_filter: Some(6), ..Default::default() };
+    assert_eq!(past.filtered(1_700_000_000), vec![1]);
+}
+
+#[test]
+fn the_viewer_shows_the_archived_board_seat_by_seat() {
+    let board = final_board_text(&final_state());
+    let ana = board.find("Ana: 21 VP, 4 lira").unwrap();
+    let bea = board.find("Bea (AI): 17 VP, 2 lira").unwrap();
+    assert!(ana < bea);
+    assert!(board.contains("Fields: R3 - - - - - - - -"));
+    assert!(board.contains("Cellar: red [7], white []"));
+    assert!(board.contains("Buildings: Cottage"));
+}
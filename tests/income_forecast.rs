@@ -0,0 +1,97 @@
+// Income forecast: projected lira from residuals, standing income, Yoke and upkeep.
+
+use viticulture::components::*;
+use viticulture::systems::income_forecast::*;
+
+struct Estate {
+    player: Player,
+    vineyard: Vineyard,
+    tracker: ResidualPaymentTracker,
+    structures: Vec<Structure>,
+    incomes: Vec<ResidualIncome>,
+}
+
+fn estate(id: u8) -> Estate {
+    Estate {
+        player: Player::new(id, format!("P{}", id + 1), false),
+        vineyard: Vineyard::new(PlayerId(id)),
+        tracker: ResidualPaymentTracker::new(PlayerId(id)),
+        structures: Vec::new(),
+        incomes: Vec::new(),
+    }
+}
+
+fn forecast(estate: &Estate, upkeep: bool, year: u8, max_years: u8) -> IncomeForecast {
+    let incomes: Vec<&ResidualIncome> = estate.incomes.iter().collect();
+    let inputs = ForecastInputs {
+        player: &estate.player,
+        vineyard: &estate.vineyard,
+        tracker: Some(&estate.tracker),
+        residual_incomes: &incomes,
+        structures: &estate.structures,
+        worker_upkeep: upkeep,
+    };
+    forecast_income(&inputs, year, max_years)
+}
+
+#[test]
+fn a_fresh_estate_expects_nothing() {
+    let f = forecast(&estate(0), false, 1, 7);
+    assert_eq!(f.years, vec![2, 3, 4]);
+    assert!(f.lines.is_empty());
+    assert_eq!(f.total(), 0);
+}
+
+#[test]
+fn every_recurring_source_is_counted_each_year() {
+    let mut e = estate(0);
+    e.tracker.advance(2);
+    e.incomes.push(ResidualIncome { owner: PlayerId(0), amount: 1, source: "Tasting room".to_string() });
+    e.incomes.push(ResidualIncome { owner: PlayerId(1), amount: 5, source: "Not ours".to_string() });
+    e.structures.push(Structure { structure_type: StructureType::Yoke, owner: PlayerId(0) });
+    e.vineyard.fields[0].vine = Some(VineType::Red(2));
+    e.player.workers = 4;
+
+    let f = forecast(&e, true, 2, 7);
+    let sources: Vec<&str> = f.lines.iter().map(|l| l.source.as_str()).collect();
+    assert_eq!(sources, vec!["Residual payments", "Tasting room", "Yoke harvests", "Worker upkeep"]);
+    assert_eq!(f.yearly_totals(), vec![2, 2, 2]);
+    assert_eq!(f.total(), 6);
+}
+
+#[test]
+fn yoke_needs_something_to_harvest_and_upkeep_needs_the_house_rule() {
+    let mut e = estate(0);
+    e.structures.push(Structure { structure_type: StructureType::Yoke, owner: PlayerId(0) });
+    e.player.workers = 5;
+    assert!(forecast(&e, false, 1, 7).lines.is_empty());
+    assert_eq!(forecast(&e, true, 1, 7).total(), -9);
+}
+
+#[test]
+fn forecast_stops_at_the_last_year() {
+    let mut e = estate(0);
+    e.tracker.advance(1);
+    assert_eq!(forecast(&e, false, 5, 7).years, vec![6, 7]);
+    assert_eq!(forecast(&e, false, 5, 7).total(), 2);
+    assert!(forecast(&e, false, 7, 7).years.is_empty());
+    assert!(forecast_text(Some(("P1", &forecast(&e, false, 7, 7))), &[]).contains("game ends this year"));
+}
+
+#[test]
+fn opponents_show_totals_only() {
+    let mut mine = estate(0);
+    mine.tracker.advance(2);
+    let mut theirs = estate(1);
+    theirs.tracker.advance(3);
+    let (mine, theirs) = (forecast(&mine, false, 1, 7), forecast(&theirs, false, 1, 7));
+
+    let text = forecast_text(Some(("P1", &mine)), &[("P2", &theirs)]);
+    assert!(text.contains("Residual payments: +2 / +2 / +2"));
+    assert!(text.contains("P2: +9 lira"));
+    assert_eq!(text.matches("Residual payments").count(), 1);
+
+    let hidden = forecast_text(None, &[("P1", &mine), ("P2", &theirs)]);
+    assert!(!hidden.contains("Residual payments"));
+    assert!(hidden.contains("P1: +6 lira"));
+}
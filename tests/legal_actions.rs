@@ -0,0 +1,97 @@
+// Legal actions: placements and the choices they bring, as the rules layer lists them.
+
+use bevy::prelude::Vec2;
+use viticulture::components::*;
+use viticulture::systems::legal_actions::*;
+
+fn vine(id: u32, vine_type: VineType) -> VineCard {
+    VineCard { id, vine_type, cost: 1, art_style: CardArt::BasicRed, special_ability: None }
+}
+
+fn occupy(board: &mut ActionBoard, action: ActionSpace, columns: usize) {
+    for space in board.spaces.iter_mut().filter(|s| s.action == action && s.column < columns) {
+        space.occupied_by = Some(PlayerId(1));
+    }
+}
+
+fn find(legal: &[LegalAction], action: ActionSpace) -> Option<&LegalAction> {
+    legal.iter().find(|l| l.action == action)
+}
+
+#[test]
+fn only_the_seasons_spaces_are_listed_once_each() {
+    let board = ActionBoard::new();
+    let workers = [Worker::new(PlayerId(0), false, Vec2::ZERO)];
+    let state = RulesState::new(board.spaces.iter(), &GameState::Summer, 4).with_workers(workers.iter());
+    let legal = legal_actions(&state, PlayerId(0));
+
+    let actions: Vec<ActionSpace> = legal.iter().map(|l| l.action).collect();
+    assert_eq!(actions, vec![ActionSpace::DrawVine, ActionSpace::PlantVine, ActionSpace::BuildStructure, ActionSpace::GiveTour, ActionSpace::SellGrapes]);
+    assert!(legal.iter().all(|l| l.regular && !l.grande && !l.blocked));
+    assert!(find(&legal, ActionSpace::GiveTour).unwrap().bonus);
+    assert!(!find(&legal, ActionSpace::DrawVine).unwrap().bonus);
+
+    // Nobody without an idle worker has anywhere to go
+    assert!(legal_actions(&state, PlayerId(1)).is_empty());
+    let state = RulesState::new(board.spaces.iter(), &GameState::Fall, 4).with_workers(workers.iter());
+    assert!(legal_actions(&state, PlayerId(0)).is_empty());
+}
+
+#[test]
+fn a_full_row_is_left_to_the_grande() {
+    let mut board = ActionBoard::new();
+    occupy(&mut board, ActionSpace::PlantVine, 2);
+    occupy(&mut board, ActionSpace::GiveTour, 1);
+    let regular = [Worker::new(PlayerId(0), false, Vec2::ZERO)];
+    let both = [Worker::new(PlayerId(0), false, Vec2::ZERO), Worker::new(PlayerId(0), true, Vec2::ZERO)];
+
+    let state = RulesState::new(board.spaces.iter(), &GameState::Summer, 4).with_workers(regular.iter());
+    let legal = legal_actions(&state, PlayerId(0));
+    assert!(find(&legal, ActionSpace::PlantVine).is_none());
+    // The second column is still free, but its bonus went to the first worker
+    let tour = find(&legal, ActionSpace::GiveTour).unwrap();
+    assert!(tour.regular && !tour.bonus);
+
+    let state = RulesState::new(board.spaces.iter(), &GameState::Summer, 4).with_workers(both.iter());
+    let plant = legal_action(&state, PlayerId(0), ActionSpace::PlantVine).unwrap();
+    assert!(plant.blocked && plant.grande && !plant.regular);
+    assert_eq!(plant.uses_grande(false), Some(true));
+    let draw = legal_action(&state, PlayerId(0), ActionSpace::DrawVine).unwrap();
+    assert_eq!((draw.uses_grande(false), draw.uses_grande(true)), (Some(false), Some(true)));
+}
+
+#[test]
+fn choices_list_only_the_options_that_can_be_taken() {
+    let board = ActionBoard::new();
+    let workers = [Worker::new(PlayerId(0), false, Vec2::ZERO)];
+    let mut hand = Hand::new(PlayerId(0));
+    // A value-3 vine needs a trellis this player does not have
+    hand.vine_cards = vec![vine(1, VineType::Red(3)), vine(2, VineType::White(1))];
+    hand.wine_order_cards = vec![WineOrderCard::new(1, 2, 0, 3, 1), WineOrderCard::new(2, 0, 1, 2, 1)];
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.white_wine.place(2);
+    let structures: [Structure; 0] = [];
+
+    let summer = RulesState::new(board.spaces.iter(), &GameState::Summer, 4)
+        .with_workers(workers.iter())
+        .with_cards([&hand], [&vineyard], structures.iter());
+    let plant = legal_action(&summer, PlayerId(0), ActionSpace::PlantVine).unwrap();
+    assert_eq!(plant.choices, vec![RequiredChoice::VineCard(vec![1])]);
+    assert!(legal_action(&summer, PlayerId(0), ActionSpace::DrawVine).unwrap().choices.is_empty());
+
+    let winter = RulesState::new(board.spaces.iter(), &GameState::Winter, 4)
+        .with_workers(workers.iter())
+        .with_cards([&hand], [&vineyard], structures.iter());
+    let fill = legal_action(&winter, PlayerId(0), ActionSpace::FillOrder).unwrap();
+    assert_eq!(fill.choices, vec![RequiredChoice::WineOrder(vec![1])]);
+    // Nothing planted: nothing to pick, though the space can still be taken
+    let harvest = legal_action(&winter, PlayerId(0), ActionSpace::Harvest).unwrap();
+    assert!(harvest.choices.is_empty());
+
+    vineyard.fields[0].vine = Some(VineType::Red(2));
+    let winter = RulesState::new(board.spaces.iter(), &GameState::Winter, 4)
+        .with_workers(workers.iter())
+        .with_cards([&hand], [&vineyard], structures.iter());
+    let harvest = legal_action(&winter, PlayerId(0), ActionSpace::Harvest).unwrap();
+    assert_eq!(harvest.choices, vec![RequiredChoice::HarvestFields { fields: vec![0], limit: HARVEST_FIELD_LIMIT + 1 }]);
+}
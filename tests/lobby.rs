@@ -0,0 +1,122 @@
+// Online lobby: seats, colours, AI fill-ins and who may start.
+
+use viticulture::components::*;
+use viticulture::systems::ai::AIDifficulty;
+use viticulture::systems::expansions::ExpansionSettings;
+use viticulture::systems::lobby::*;
+use viticulture::systems::first_game::RulesPreset;
+use viticulture::systems::settings::UserSettings;
+use viticulture::systems::wake_up::{WakeUpChart, WakeUpCharts};
+
+fn table(player_count: u8, ai_count: u8) -> LobbySettings {
+    let config = GameConfig { player_count, ai_count, ..GameConfig::default() };
+    LobbySettings::from_resources(
+        &config,
+        &ExpansionSettings::default(),
+        &UserSettings::default(),
+        RulesPreset::default(),
+        &WakeUpCharts::default(),
+        &GameSettings::default(),
+    )
+}
+
+fn hosted(player_count: u8, ai_count: u8, guests: &[&str]) -> Lobby {
+    let mut lobby = Lobby::host("Host", table(player_count, ai_count));
+    for (i, name) in guests.iter().enumerate() {
+        lobby.handle(i as u8 + 1, LobbyMessage::Join { name: name.to_string() });
+    }
+    lobby
+}
+
+#[test]
+fn joining_takes_the_first_free_seat_and_colour() {
+    let lobby = hosted(3, 0, &["Ana", "Bo"]);
+    let seats: Vec<(u8, u8)> = lobby.peers.iter().map(|p| (p.seat, p.color)).collect();
+    assert_eq!(seats, vec![(0, 0), (1, 1), (2, 2)]);
+}
+
+#[test]
+fn a_taken_seat_or_colour_cannot_be_picked() {
+    let mut lobby = hosted(3, 0, &["Ana"]);
+    let relay = lobby.handle(1, LobbyMessage::PickSeat { seat: 0 });
+    assert!(matches!(relay.as_slice(), [LobbyMessage::Roster(_)]));
+    assert_eq!(lobby.peers[1].seat, 1);
+
+    lobby.handle(1, LobbyMessage::PickSeat { seat: 2 });
+    lobby.handle(1, LobbyMessage::PickColor { color: 0 });
+    assert_eq!((lobby.peers[1].seat, lobby.peers[1].color), (2, 1));
+    assert_eq!(lobby.next_free_seat(0, 0, true), Some(1));
+    assert_eq!(lobby.next_free_color(0, 0), Some(2));
+}
+
+#[test]
+fn start_waits_for_every_human_seat_to_be_filled_and_ready() {
+    let mut lobby = hosted(3, 0, &["Ana"]);
+    lobby.request(LobbyMessage::Ready { peer: 0, ready: true });
+    lobby.handle(1, LobbyMessage::Ready { peer: 1, ready: true });
+    assert!(lobby.all_ready());
+    assert!(lobby.start().is_none());
+
+    // Filling the open seat with an AI voids the ready flags
+    lobby.change_settings(table(3, 1));
+    assert!(lobby.start().is_none());
+    for peer in [0, 1] {
+        lobby.handle(peer, LobbyMessage::Ready { peer, ready: true });
+    }
+    assert!(matches!(lobby.start(), Some(LobbyMessage::Start(_))));
+}
+
+#[test]
+fn seats_given_to_the_ai_move_their_players() {
+    let mut lobby = hosted(3, 0, &["Ana"]);
+    lobby.handle(1, LobbyMessage::PickSeat { seat: 2 });
+    lobby.change_settings(table(3, 1));
+    assert_eq!(lobby.peers[1].seat, 1);
+
+    lobby.handle(2, LobbyMessage::Join { name: "Bo".to_string() });
+    assert_eq!(lobby.peers[2].seat, NO_SEAT);
+    assert!(matches!(lobby.leave(1), Some(LobbyMessage::Roster(_))));
+    assert_eq!((lobby.peers[1].name.as_str(), lobby.peers[1].seat), ("Bo", 1));
+}
+
+#[test]
+fn seating_and_colours_carry_into_the_game() {
+    let mut lobby = hosted(3, 1, &["Ana"]);
+    lobby.handle(1, LobbyMessage::PickColor { color: 4 });
+
+    let seating = LobbySeating { peers: lobby.peers.clone() };
+    assert_eq!(seating.seat_of(1), Some(PlayerId(1)));
+    assert_eq!(seating.name_at(1), Some("Ana"));
+
+    let colors = PlayerColors::from_peers(&lobby.peers, 3);
+    assert_eq!(colors.0, vec![0, 4, 1]);
+    assert_eq!(PlayerColors::default().srgba(PlayerId(2)), SEAT_COLORS[2].1);
+}
+
+#[test]
+fn the_start_message_carries_the_preset_chart_mercy_and_seats() {
+    let mut custom = WakeUpChart::base();
+    custom.name = "House chart".to_string();
+    let host_charts = WakeUpCharts { charts: vec![WakeUpChart::base(), custom.clone()], active: 1 };
+    let host_seats = GameSettings {
+        seats: vec![SeatSetup::default(), SeatSetup { difficulty: Some(AIDifficulty::Expert), handicap: true }],
+        ..GameSettings::default()
+    };
+    let host_settings = UserSettings { automa_mercy: true, ..UserSettings::default() };
+    let start = LobbySettings::from_resources(
+        &GameConfig::default(),
+        &ExpansionSettings::default(),
+        &host_settings,
+        RulesPreset::QuickGame,
+        &host_charts,
+        &host_seats,
+    );
+
+    let (mut config, mut expansions, mut settings) = (GameConfig::default(), ExpansionSettings::default(), UserSettings::default());
+    let (mut preset, mut charts, mut seats) = (RulesPreset::default(), WakeUpCharts::default(), GameSettings::default());
+    start.apply(&mut config, &mut expansions, &mut settings, &mut preset, &mut charts, &mut seats);
+    assert_eq!(preset, RulesPreset::QuickGame);
+    assert_eq!(charts.active(), &custom);
+    assert!(settings.automa_mercy);
+    assert_eq!(seats.seats, host_seats.seats);
+}
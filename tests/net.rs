@@ -0,0 +1,127 @@
+// Online play: frame encoding, launch arguments, replicated card and seat choices and
+// rejoining a dropped seat.
+
+use viticulture::components::*;
+use viticulture::systems::net::*;
+use viticulture::systems::prediction::*;
+use viticulture::systems::hand_limit::DiscardCard;
+use viticulture::systems::lobby::{LobbyPeer, LobbySeating};
+use viticulture::systems::resignation::ResignReason;
+use viticulture::systems::seat_choices::*;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn frames_survive_being_split_across_reads() {
+    let first = NetMessage::Pass { player: 2 };
+    let second = NetMessage::Season { state: 3, year: 4 };
+    let mut bytes = encode_frame(&first);
+    bytes.extend(encode_frame(&second));
+
+    let mut buffer = FrameBuffer::default();
+    let (head, tail) = bytes.split_at(6);
+    assert!(buffer.push(head).unwrap().is_empty());
+    assert_eq!(buffer.push(tail).unwrap(), vec![first, second]);
+}
+
+#[test]
+fn seat_choices_travel_in_frames() {
+    let choice = NetMessage::Choice { player: 1, choice: SeatChoice::Discard(vec![DiscardCard::Vine(0), DiscardCard::Order(2)]) };
+    let mut buffer = FrameBuffer::default();
+    assert_eq!(buffer.push(&encode_frame(&choice)).unwrap(), vec![choice]);
+}
+
+#[test]
+fn resignations_travel_in_frames() {
+    let resign = NetMessage::Resign { player: 2, reason: ResignReason::Choice };
+    let mut buffer = FrameBuffer::default();
+    assert_eq!(buffer.push(&encode_frame(&resign)).unwrap(), vec![resign]);
+}
+
+#[test]
+fn a_rejoining_player_takes_back_the_seat_they_dropped_from() {
+    let peer = |id: u8, name: &str, seat: u8| LobbyPeer { id, name: name.to_string(), ready: true, seat, color: seat };
+    let seating = LobbySeating { peers: vec![peer(0, "Host", 0), peer(1, "Ana", 1), peer(2, "Ben", 2)] };
+    let dropped = [PlayerId(1), PlayerId(2)];
+
+    assert_eq!(rejoin_seat(&seating, &dropped, "Ben"), Some(PlayerId(2)));
+    // A new name still fills a seat that is waiting
+    assert_eq!(rejoin_seat(&seating, &dropped, "Cy"), Some(PlayerId(1)));
+    assert_eq!(rejoin_seat(&seating, &[], "Ben"), None);
+}
+
+#[test]
+fn a_discard_from_another_machine_is_checked_against_the_hand() {
+    let mut hand = Hand::new(PlayerId(1));
+    hand.vine_cards = (0..8).map(|id| VineCard { id, vine_type: VineType::Red(1), cost: 1, art_style: CardArt::BasicRed, special_ability: None }).collect();
+    assert!(valid_discard(&hand, false, &[DiscardCard::Vine(7)]));
+    assert!(!valid_discard(&hand, false, &[DiscardCard::Vine(8)]));
+    assert!(!valid_discard(&hand, false, &[DiscardCard::Vine(1), DiscardCard::Vine(1)]));
+    assert!(!valid_discard(&hand, false, &[]));
+}
+
+#[test]
+fn oversized_frames_are_refused() {
+    let mut buffer = FrameBuffer::default();
+    assert!(buffer.push(&u32::MAX.to_be_bytes()).is_err());
+}
+
+#[test]
+fn launch_arguments() {
+    assert_eq!(parse_net_args(&args(&["viticulture"])), None);
+    assert_eq!(
+        parse_net_args(&args(&["viticulture", "--host", "--name", "Ana"])),
+        Some(NetLaunch::Host { port: DEFAULT_PORT, name: "Ana".to_string() }),
+    );
+    assert_eq!(
+        parse_net_args(&args(&["viticulture", "--join", "10.0.0.2"])),
+        Some(NetLaunch::Join { address: format!("10.0.0.2:{}", DEFAULT_PORT), name: "Player".to_string() }),
+    );
+}
+
+#[test]
+fn card_choice_moves_the_picked_cards_to_the_front() {
+    let decks = CardDecks::new();
+    let mut hand = Hand::new(PlayerId(1));
+    hand.vine_cards = decks.vine_deck[..3].to_vec();
+    let picked = hand.vine_cards[2].id;
+
+    let choice = CardChoice { vine_card: Some(picked), order_card: None, harvest_fields: vec![4], order_wines: (None, None), recipe: Some(WineType::Blush) };
+    let mut other = hand.clone();
+    let mut vineyard = Vineyard::new(PlayerId(1));
+    choice.apply(Some(&mut other), Some(&mut vineyard));
+    assert_eq!(other.vine_cards[0].id, picked);
+    assert_eq!(other.vine_cards.len(), 3);
+    assert_eq!(vineyard.wine_recipe, Some(WineType::Blush));
+    assert_eq!(CardChoice::capture(Some(&other), Some(&vineyard)), choice);
+}
+
+#[test]
+fn a_rejection_takes_every_later_prediction_with_it() {
+    let prediction = |seq: u32, action: ActionSpace| PredictedPlacement {
+        seq,
+        event: viticulture::systems::input::ActionRequested { player: PlayerId(1), action, grande: false },
+        before: PlacementSnapshot {
+            player: Player::new(1, "Ana".to_string(), false),
+            vineyard: None,
+            hand: None,
+            tracker: None,
+            decks: CardDecks::new(),
+            worker: None,
+            worker_position: bevy::prelude::Vec2::ZERO,
+            current_player: 1,
+            passed: Vec::new(),
+            column: 0,
+            space: (None, None),
+        },
+    };
+    let mut remote = RemotePlay::client(PlayerId(1));
+    remote.pending = vec![prediction(1, ActionSpace::DrawVine), prediction(2, ActionSpace::GiveTour), prediction(3, ActionSpace::SellGrapes)];
+
+    let taken: Vec<u32> = remote.take_from(2).iter().map(|p| p.seq).collect();
+    assert_eq!(taken, vec![2, 3]);
+    assert_eq!(remote.pending.len(), 1);
+    assert!(remote.take_from(9).is_empty());
+}
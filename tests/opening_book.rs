@@ -0,0 +1,95 @@
+// Opening book: the known lines AIs follow through the first two years.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use viticulture::components::*;
+use viticulture::systems::ai::{AIDifficulty, AiDecisionInput};
+use viticulture::systems::opening_book::*;
+
+fn vine(id: u32) -> VineCard {
+    VineCard { id, vine_type: VineType::Red(1), cost: 1, art_style: CardArt::BasicRed, special_ability: None }
+}
+
+fn input(state: GameState, year: u8, player_count: u8, valid_actions: Vec<ActionSpace>) -> AiDecisionInput {
+    AiDecisionInput {
+        difficulty: AIDifficulty::Beginner,
+        valid_actions,
+        player_count,
+        ..AiDecisionInput::new(Player::new(0, "AI".to_string(), true), state, year)
+    }
+}
+
+const SUMMER: [ActionSpace; 5] = [
+    ActionSpace::DrawVine,
+    ActionSpace::PlantVine,
+    ActionSpace::BuildStructure,
+    ActionSpace::GiveTour,
+    ActionSpace::SellGrapes,
+];
+
+#[test]
+fn every_player_count_has_lines_for_both_book_years() {
+    for player_count in 1..=6 {
+        let lines = opening_lines(player_count);
+        assert!(lines.len() >= 2, "{} players", player_count);
+        for line in lines {
+            for year in 1..=OPENING_BOOK_YEARS {
+                assert!(!book_steps(line, &GameState::Summer, year).is_empty(), "{}", line.name);
+                assert!(!book_steps(line, &GameState::Winter, year).is_empty(), "{}", line.name);
+                assert!(book_steps(line, &GameState::Summer, year).iter().all(|a| SUMMER.contains(a)), "{}", line.name);
+                assert!(book_steps(line, &GameState::Winter, year).iter().all(|a| !SUMMER.contains(a)), "{}", line.name);
+            }
+        }
+    }
+}
+
+#[test]
+fn book_is_closed_after_year_two_and_outside_worker_seasons() {
+    let line = &opening_lines(2)[0];
+    assert!(book_steps(line, &GameState::Summer, OPENING_BOOK_YEARS + 1).is_empty());
+    assert!(book_steps(line, &GameState::Fall, 1).is_empty());
+
+    let mut rng = StdRng::seed_from_u64(1);
+    assert_eq!(opening_book_action(&input(GameState::Summer, 3, 2, SUMMER.to_vec()), &mut rng), None);
+}
+
+#[test]
+fn book_never_plants_without_a_vine_in_hand() {
+    for seed in 0..50 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let choice = opening_book_action(&input(GameState::Summer, 1, 2, vec![ActionSpace::PlantVine, ActionSpace::GiveTour]), &mut rng);
+        assert_eq!(choice, Some(ActionSpace::GiveTour));
+    }
+
+    let mut with_vine = input(GameState::Summer, 1, 2, vec![ActionSpace::PlantVine]);
+    with_vine.hand.vine_cards.push(vine(1));
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(opening_book_action(&with_vine, &mut rng), Some(ActionSpace::PlantVine));
+}
+
+#[test]
+fn book_continues_the_line_already_started() {
+    // Only "Builder" opens on a tour and builds next
+    let mut started = input(GameState::Summer, 1, 4, SUMMER.to_vec());
+    started.placed = vec![ActionSpace::GiveTour, ActionSpace::BuildStructure];
+    for seed in 0..50 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        assert_eq!(opening_book_action(&started, &mut rng), Some(ActionSpace::DrawVine));
+    }
+}
+
+#[test]
+fn book_steps_aside_when_nothing_in_it_is_open() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let blocked = input(GameState::Winter, 1, 2, vec![ActionSpace::FillOrder]);
+    assert_eq!(opening_book_action(&blocked, &mut rng), None);
+}
+
+#[test]
+fn openings_vary_between_seeds() {
+    let choices: std::collections::HashSet<_> = (0..50)
+        .filter_map(|seed| opening_book_action(&input(GameState::Summer, 1, 2, SUMMER.to_vec()), &mut StdRng::seed_from_u64(seed)))
+        .map(|a| format!("{:?}", a))
+        .collect();
+    assert!(choices.len() >= 2);
+}
@@ -0,0 +1,31 @@
+// Order swap: offers queue per draw, and answers find the order by card id.
+
+use viticulture::components::*;
+use viticulture::systems::order_swap::*;
+
+#[test]
+fn a_second_offer_waits_for_the_first_to_be_answered() {
+    let mut offer = OrderSwapOffer::default();
+    offer.pending.push(PlayerId(0));
+    offer.pending.push(PlayerId(1));
+    assert_eq!(offer.current(), Some(PlayerId(0)));
+    offer.answered();
+    assert_eq!(offer.current(), Some(PlayerId(1)));
+    offer.answered();
+    assert_eq!(offer.current(), None);
+}
+
+#[test]
+fn the_swap_follows_the_card_after_the_hand_changes() {
+    let mut decks = CardDecks::new();
+    let mut hand = Hand::new(PlayerId(0));
+    hand.wine_order_cards.push(WineOrderCard::new(901, 1, 0, 1, 1));
+    hand.wine_order_cards.push(WineOrderCard::new(902, 0, 1, 1, 1));
+    // The first order is filled while the dialog is open, so 902 moves to index 0
+    hand.wine_order_cards.remove(0);
+
+    assert!(swap_wine_order_by_id(&mut hand, &mut decks, 902));
+    assert!(hand.wine_order_cards.iter().all(|o| o.id != 902));
+    assert_eq!(decks.wine_order_discard.last().map(|o| o.id), Some(902));
+    assert!(!swap_wine_order_by_id(&mut hand, &mut decks, 901));
+}
@@ -0,0 +1,51 @@
+// Order token picks: which cellar wines a Fill Order spends.
+
+use viticulture::components::*;
+use viticulture::systems::order_tokens::*;
+
+fn cellar(red: &[u8], white: &[u8]) -> Vineyard {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine = ValueTrack::from_values(red);
+    vineyard.white_wine = ValueTrack::from_values(white);
+    vineyard
+}
+
+#[test]
+fn qualifying_tokens_meet_the_printed_value() {
+    let vineyard = cellar(&[2, 4, 7], &[]);
+    assert_eq!(qualifying_tokens(&vineyard.red_wine, 3), vec![4, 7]);
+    assert!(qualifying_tokens(&vineyard.red_wine, 0).is_empty());
+    assert!(qualifying_tokens(&vineyard.white_wine, 1).is_empty());
+}
+
+#[test]
+fn picker_only_asks_when_there_is_a_choice() {
+    let order = WineOrderCard::new(1, 3, 0, 2, 1);
+    assert!(needs_token_pick(ActionSpace::FillOrder, &order, &cellar(&[4, 7], &[])));
+    assert!(!needs_token_pick(ActionSpace::FillOrder, &order, &cellar(&[2, 4], &[])));
+    assert!(!needs_token_pick(ActionSpace::FillOrder, &order, &cellar(&[1], &[])));
+    assert!(!needs_token_pick(ActionSpace::MakeWine, &order, &cellar(&[4, 7], &[])));
+}
+
+#[test]
+fn picked_tokens_are_spent_instead_of_the_cheapest() {
+    let order = WineOrderCard::new(1, 3, 2, 2, 1);
+    let mut vineyard = cellar(&[4, 7], &[2, 5]);
+    vineyard.order_wines = (Some(7), None);
+
+    assert!(vineyard.take_order_wines(&order));
+    assert_eq!(vineyard.red_wine.values(), vec![4]);
+    assert_eq!(vineyard.white_wine.values(), vec![5]);
+    assert_eq!(vineyard.order_wines, (None, None));
+}
+
+#[test]
+fn stale_picks_fall_back_to_the_cheapest() {
+    let order = WineOrderCard::new(1, 3, 0, 2, 1);
+    let mut vineyard = cellar(&[2, 4, 7], &[]);
+    // Too weak for the order, so the rules choose
+    vineyard.order_wines = (Some(2), None);
+
+    assert!(vineyard.take_order_wines(&order));
+    assert_eq!(vineyard.red_wine.values(), vec![2, 7]);
+}
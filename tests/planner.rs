@@ -0,0 +1,68 @@
+// Planning AI: rollouts in the rules engine pick the move with the best expected lead.
+
+use std::time::Duration;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use viticulture::components::*;
+use viticulture::systems::planner::*;
+use viticulture::systems::simulation::SimGame;
+
+fn summer_game() -> SimGame {
+    let config = GameConfig { player_count: 3, ai_count: 3, ..GameConfig::default() };
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut game = SimGame::new(&config, &mut rng);
+    game.step(&mut rng);
+    assert_eq!(game.phase, GameState::Summer);
+    game
+}
+
+#[test]
+fn trivial_choices_skip_the_search() {
+    let game = summer_game();
+    assert_eq!(plan_action(&game, 0, &[], Duration::from_secs(1), 0), None);
+    assert_eq!(plan_action(&game, 0, &[ActionSpace::GiveTour], Duration::from_secs(1), 0), Some(ActionSpace::GiveTour));
+}
+
+#[test]
+fn a_zero_budget_still_tries_every_candidate_and_replays_from_the_seed() {
+    let game = summer_game();
+    let candidates = game.legal_actions(0);
+    assert!(candidates.len() > 1);
+
+    let first = plan_action(&game, 0, &candidates, Duration::ZERO, 42);
+    assert!(first.is_some_and(|action| candidates.contains(&action)));
+    assert_eq!(plan_action(&game, 0, &candidates, Duration::ZERO, 42), first);
+}
+
+#[test]
+fn rollouts_leave_the_searched_position_untouched() {
+    let game = summer_game();
+    let hash = game.state_hash();
+    let mut rng = StdRng::seed_from_u64(3);
+    rollout_value(&game, 1, ActionSpace::DrawVine, &mut rng);
+    assert_eq!(game.state_hash(), hash);
+}
+
+#[test]
+fn positions_are_scored_against_the_best_opponent() {
+    let mut game = summer_game();
+    for (player, (vp, lira)) in game.players.iter_mut().zip([(6, 0), (4, 10), (2, 0)]) {
+        player.victory_points = VicPoints::new(vp);
+        player.lira = Lira::new(lira);
+    }
+    assert!((position_value(&game, 0) - 1.0).abs() < 1e-4);
+    assert!((position_value(&game, 1) + 1.0).abs() < 1e-4);
+    assert!(position_value(&game, 2) < position_value(&game, 1));
+}
+
+#[test]
+fn a_mid_season_snapshot_keeps_the_live_board() {
+    let game = summer_game();
+    let mut spaces = game.spaces.clone();
+    for space in spaces.iter_mut().filter(|s| s.action == ActionSpace::DrawVine) {
+        space.occupied_by = Some(PlayerId(1));
+    }
+    let game = game.with_board(spaces, vec![2, 0, 1]);
+    assert!(!game.legal_actions(0).contains(&ActionSpace::DrawVine));
+    assert!(game.legal_actions(1).is_empty());
+}
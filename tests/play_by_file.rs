@@ -0,0 +1,147 @@
+// Play-by-file turn files: the launch flag, the file format, which files are accepted and the
+// state digest both sides compare, and the turn codes sent by mail.
+
+use viticulture::components::*;
+use viticulture::systems::play_by_file::*;
+use viticulture::systems::prediction::CardChoice;
+use viticulture::systems::save::*;
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|a| a.to_string()).collect()
+}
+
+fn save() -> SaveData {
+    SaveData {
+        version: 1,
+        players: vec![
+            PlayerSave { id: 0, name: "Player 1".to_string(), victory_points: 3, lira: 5, workers: 2, grande_worker_available: true, is_ai: false },
+            PlayerSave { id: 1, name: "Player 2".to_string(), victory_points: 1, lira: 7, workers: 2, grande_worker_available: true, is_ai: false },
+        ],
+        vineyards: Vec::new(),
+        hands: Vec::new(),
+        workers: vec![
+            WorkerSave { owner_id: 0, is_grande: false, placed_at: Some(3), position_x: 10.0, position_y: -20.0 },
+            WorkerSave { owner_id: 1, is_grande: false, placed_at: None, position_x: 40.0, position_y: 5.0 },
+        ],
+        turn_order: TurnOrderSave { players: vec![0, 1], current_player: 1, wake_up_order: Vec::new(), passed: Vec::new(), wake_up_bonuses: Vec::new() },
+        config: GameConfigSave { player_count: 2, target_victory_points: 20, current_year: 2, max_years: 7, ai_count: 0, seed: Some(42) },
+        current_state: 3,
+        action_spaces: Vec::new(),
+        trackers: Vec::new(),
+        decks: DeckSave::default(),
+        notes: Vec::new(),
+        structures: Vec::new(),
+        card_decks: None,
+        mama_cards: vec![0, 1],
+        papa_cards: vec![0, 1],
+        festival_held: false,
+        rng: None,
+        play_by_file: None,
+    }
+}
+
+fn turn_file(seq: u32, player: u8) -> TurnFile {
+    TurnFile {
+        version: TURN_FILE_VERSION,
+        game_id: 42,
+        seq,
+        player,
+        base_digest: 1,
+        result_digest: 2,
+        moves: vec![
+            TurnMove::Place { action: ActionSpace::FillOrder, grande: false, choice: CardChoice { order_card: Some(7), ..Default::default() } },
+            TurnMove::Pass,
+        ],
+    }
+}
+
+#[test]
+fn mail_flag_picks_the_local_seat() {
+    assert_eq!(parse_play_by_file_args(&args(&["viticulture", "--mail", "2"])), Some(PlayerId(1)));
+    assert_eq!(parse_play_by_file_args(&args(&["viticulture", "--mail", "0"])), None);
+    assert_eq!(parse_play_by_file_args(&args(&["viticulture", "--host"])), None);
+}
+
+#[test]
+fn turn_files_round_trip_and_newer_ones_are_refused() {
+    let file = turn_file(3, 1);
+    assert_eq!(TurnFile::decode(&file.encode()), Ok(file.clone()));
+
+    let newer = TurnFile { version: TURN_FILE_VERSION + 1, ..file };
+    assert!(TurnFile::decode(&newer.encode()).is_err());
+    assert!(TurnFile::decode(b"not a turn file").is_err());
+}
+
+#[test]
+fn only_the_next_file_from_another_seat_is_accepted() {
+    let mut pbf = PlayByFile::new(PlayerId(0));
+    pbf.game_id = Some(42);
+    pbf.seq = 2;
+
+    assert!(pbf.accepts(&turn_file(3, 1)).is_ok());
+    assert!(pbf.accepts(&turn_file(2, 1)).is_err());
+    assert!(pbf.accepts(&turn_file(4, 1)).is_err());
+    assert!(pbf.accepts(&turn_file(3, 0)).is_err());
+    assert!(pbf.accepts(&TurnFile { game_id: 7, ..turn_file(3, 1) }).is_err());
+
+    pbf.desync = true;
+    assert!(pbf.accepts(&turn_file(3, 1)).is_err());
+}
+
+#[test]
+fn digest_ignores_query_order_sprites_notes_and_the_session() {
+    let base = save();
+    let mut shuffled = save();
+    shuffled.players.reverse();
+    shuffled.workers.reverse();
+    shuffled.workers[0].position_x = -300.0;
+    shuffled.notes.push((0, "keep the red 7 for the big order".to_string()));
+    shuffled.rng = Some(RngSave { seed: 42, position: 12 });
+    shuffled.play_by_file = Some(PlayByFileSave { local_player: 0, game_id: Some(42), seq: 3, recording: None });
+
+    assert_eq!(state_digest(&base), state_digest(&shuffled));
+}
+
+#[test]
+fn a_saved_session_resumes_its_turn_files() {
+    let mut pbf = PlayByFile::new(PlayerId(1));
+    pbf.game_id = Some(42);
+    pbf.seq = 5;
+    pbf.recording = Some((9, vec![TurnMove::Pass]));
+
+    let resumed = PlayByFile::resume(&pbf.session());
+    assert_eq!(resumed.local_player, PlayerId(1));
+    assert_eq!((resumed.game_id, resumed.seq), (Some(42), 5));
+    assert_eq!(resumed.recording, pbf.recording);
+}
+
+#[test]
+fn digest_changes_with_the_game() {
+    let base = save();
+    let mut edited = save();
+    edited.players[1].lira += 1;
+
+    assert_ne!(state_digest(&base), state_digest(&edited));
+}
+
+#[test]
+fn turn_codes_survive_being_mailed() {
+    let file = turn_file(3, 1);
+    let code = file.to_code();
+    assert!(code.starts_with(TURN_CODE_PREFIX));
+    assert!(!code.contains(char::is_whitespace));
+    assert_eq!(TurnFile::from_code(&code), Ok(file.clone()));
+
+    // Wrapped and indented by a mail client, or saved to a text file and dropped
+    let wrapped: String = code.as_bytes().chunks(60).map(|line| format!("  {}\n", String::from_utf8_lossy(line))).collect();
+    assert_eq!(TurnFile::from_code(&wrapped), Ok(file.clone()));
+    assert_eq!(TurnFile::decode(format!("\n{}\n", code).as_bytes()), Ok(file));
+}
+
+#[test]
+fn damaged_turn_codes_are_refused() {
+    let code = turn_file(1, 0).to_code();
+    assert!(TurnFile::from_code("hello").is_err());
+    assert!(TurnFile::from_code(&code[..code.len() - 5]).is_err());
+    assert!(TurnFile::from_code(&code.replacen(TURN_CODE_PREFIX, &format!("{}*", TURN_CODE_PREFIX), 1)).is_err());
+}
@@ -0,0 +1,35 @@
+// Plugin split: the rules and the AI play a game on MinimalPlugins with no UiPlugin.
+
+use bevy::prelude::*;
+use viticulture::components::*;
+use viticulture::plugins::*;
+use viticulture::systems::*;
+
+fn state(app: &App) -> GameState {
+    app.world().resource::<State<GameState>>().get().clone()
+}
+
+fn run_until(app: &mut App, wanted: GameState) {
+    for _ in 0..20 {
+        if state(app) == wanted {
+            return;
+        }
+        app.update();
+    }
+    assert_eq!(state(app), wanted);
+}
+
+#[test]
+fn rules_and_ai_run_without_the_ui() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, RulesPlugin, AiPlugin));
+    app.update();
+
+    app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Setup);
+    run_until(&mut app, GameState::Spring);
+    assert_eq!(app.world_mut().query::<&Player>().iter(app.world()).count(), 2);
+
+    // No SPACE key without the UI; the embedder moves the wake-up draft on itself
+    app.world_mut().send_event(PhaseContinue);
+    run_until(&mut app, GameState::Summer);
+}
@@ -0,0 +1,27 @@
+// Bounded resource types: gains stop at the track's end, spending never goes below zero.
+
+use viticulture::components::*;
+
+#[test]
+fn lira_and_points_stay_in_bounds() {
+    let mut player = Player::new(0, "Test".to_string(), false);
+    player.lira = Lira::new(48);
+    player.gain_lira(5);
+    assert_eq!(player.lira, Lira::MAX);
+
+    player.lira -= 60;
+    assert_eq!(player.lira.get(), 0);
+    assert!(!player.lira.spend(1));
+
+    player.victory_points = VicPoints::new(200);
+    assert_eq!(player.victory_points.get(), VicPoints::MAX);
+}
+
+#[test]
+fn workers_stop_at_the_cap() {
+    let mut player = Player::new(0, "Test".to_string(), false);
+    player.workers = MAX_WORKERS - 1;
+    assert!(player.gain_worker());
+    assert!(!player.gain_worker());
+    assert_eq!(player.workers, MAX_WORKERS);
+}
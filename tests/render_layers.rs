@@ -0,0 +1,40 @@
+// Render layers: one ordered registry for board z values and UI z-indices.
+
+use viticulture::systems::render_layers::*;
+
+#[test]
+fn world_layers_stack_in_order_inside_the_camera_range() {
+    for pair in WorldLayer::ALL.windows(2) {
+        assert!(pair[0].at(0.99) < pair[1].z(), "{:?} overlaps {:?}", pair[0], pair[1]);
+    }
+    assert_eq!(WorldLayer::Background.z(), 0.0);
+    assert!(WorldLayer::PhaseText.at(0.99) < 999.9);
+    assert!(WorldLayer::Hand.z() < WorldLayer::Highlights.z());
+    assert!(WorldLayer::Particles.z() < WorldLayer::FloatingText.z());
+}
+
+#[test]
+fn a_z_value_maps_back_to_its_layer() {
+    for layer in WorldLayer::ALL {
+        assert_eq!(WorldLayer::of(layer.z()), layer);
+        assert_eq!(WorldLayer::of(layer.at(0.5)), layer);
+    }
+    // Offsets can't spill into the next layer
+    assert_eq!(WorldLayer::of(WorldLayer::Workers.at(3.0)), WorldLayer::Workers);
+    assert_eq!(WorldLayer::of(-1.0), WorldLayer::Background);
+
+    for layer in UiLayer::ALL {
+        assert_eq!(UiLayer::of(layer.value()), layer);
+        assert_eq!(UiLayer::of(layer.value() + 50), layer);
+    }
+}
+
+#[test]
+fn tooltips_read_over_dialogs_and_the_handoff_covers_everything() {
+    let values: Vec<i32> = UiLayer::ALL.iter().map(|l| l.value()).collect();
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(UiLayer::Tooltip.value() > UiLayer::Dialog.value());
+    assert!(UiLayer::Tooltip.value() > UiLayer::Screen.value());
+    assert!(UiLayer::Toast.value() > UiLayer::GameOver.value());
+    assert_eq!(UiLayer::ALL.last(), Some(&UiLayer::Handoff));
+}
@@ -0,0 +1,33 @@
+// Retention limits: settings steps, which files get pruned and the disk usage estimates.
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use viticulture::systems::retention::*;
+
+#[test]
+fn steps_cycle_and_recover_from_unknown_values() {
+    assert_eq!(next_step(&UNDO_DEPTH_STEPS, 20), 40);
+    assert_eq!(next_step(&UNDO_DEPTH_STEPS, 40), 5);
+    assert_eq!(next_step(&ARCHIVE_LIMIT_STEPS, 7), ARCHIVE_LIMIT_STEPS[0]);
+}
+
+#[test]
+fn oldest_files_are_pruned_first() {
+    let at = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+    let files = vec![
+        (PathBuf::from("b"), at(20)),
+        (PathBuf::from("a"), at(10)),
+        (PathBuf::from("c"), at(30)),
+    ];
+    assert_eq!(files_to_prune(files.clone(), 2), vec![PathBuf::from("a")]);
+    assert!(files_to_prune(files, 5).is_empty());
+}
+
+#[test]
+fn estimates_scale_the_average_file() {
+    let usage = FolderUsage { files: 4, bytes: 4096 };
+    assert_eq!(usage.estimate_at(10), 10240);
+    assert_eq!(FolderUsage::default().estimate_at(10), 0);
+    assert_eq!(format_bytes(10240), "10 KB");
+    assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MB");
+}
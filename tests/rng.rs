@@ -0,0 +1,44 @@
+// Seeded game RNG: the same seed gives the same shuffles, reseeding starts over, and a saved
+// position resumes the draws.
+
+use rand::seq::SliceRandom;
+use rand::RngCore;
+use viticulture::systems::rng::*;
+
+fn shuffled(rng: &mut GameRng) -> Vec<u32> {
+    let mut deck: Vec<u32> = (0..30).collect();
+    deck.shuffle(rng);
+    deck
+}
+
+#[test]
+fn same_seed_same_shuffles() {
+    let (mut a, mut b) = (GameRng::new(0x5EED), GameRng::new(0x5EED));
+    assert_eq!(shuffled(&mut a), shuffled(&mut b));
+    assert_eq!(shuffled(&mut a), shuffled(&mut b));
+    assert_ne!(shuffled(&mut a), shuffled(&mut GameRng::new(0x5EEE)));
+}
+
+#[test]
+fn reseeding_replays_from_the_start() {
+    let mut rng = GameRng::new(7);
+    let first = shuffled(&mut rng);
+    shuffled(&mut rng);
+
+    rng.reseed(7);
+    assert_eq!(rng.seed, 7);
+    assert_eq!(shuffled(&mut rng), first);
+}
+
+#[test]
+fn a_saved_position_resumes_the_same_draws() {
+    let mut rng = GameRng::new(7);
+    shuffled(&mut rng);
+    rng.next_u64();
+    let mut bytes = [0u8; 5];
+    rng.fill_bytes(&mut bytes);
+
+    let mut resumed = GameRng::resume(rng.seed, rng.position());
+    assert_eq!(resumed.position(), rng.position());
+    assert_eq!(shuffled(&mut resumed), shuffled(&mut rng));
+}
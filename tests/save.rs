@@ -0,0 +1,77 @@
+// Versioned RON saves: what goes in comes back, saves from a newer build are refused, and
+// season autosaves rotate through their ring.
+
+use viticulture::components::*;
+use viticulture::systems::save::*;
+use std::time::{Duration, SystemTime};
+
+fn save_data(version: u32) -> SaveData {
+    SaveData {
+        version,
+        players: Vec::new(),
+        vineyards: Vec::new(),
+        hands: Vec::new(),
+        workers: vec![WorkerSave { owner_id: 1, is_grande: false, placed_at: Some(3), position_x: 10.0, position_y: -20.0 }],
+        turn_order: TurnOrderSave { players: vec![0, 1], current_player: 1, wake_up_order: Vec::new(), passed: vec![0], wake_up_bonuses: Vec::new() },
+        config: GameConfigSave { player_count: 2, target_victory_points: 20, current_year: 3, max_years: 7, ai_count: 1, seed: Some(42) },
+        current_state: 3,
+        action_spaces: Vec::new(),
+        trackers: Vec::new(),
+        decks: DeckSave::default(),
+        notes: Vec::new(),
+        structures: vec![StructureSave { owner_id: 1, structure_type: StructureType::Windmill }],
+        card_decks: None,
+        mama_cards: vec![0, 1],
+        papa_cards: vec![0, 1],
+        festival_held: true,
+        rng: Some(RngSave { seed: 42, position: 130 }),
+        play_by_file: Some(PlayByFileSave { local_player: 1, game_id: Some(42), seq: 4, recording: None }),
+    }
+}
+
+fn write(name: &str, data: &SaveData) -> String {
+    let path = std::env::temp_dir().join(name).to_string_lossy().into_owned();
+    std::fs::write(&path, ron::ser::to_string_pretty(data, ron::ser::PrettyConfig::default()).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn ron_save_round_trips() {
+    let path = write("viticulture_round_trip.ron", &save_data(SAVE_FORMAT_VERSION));
+    let loaded = load_save_file(&path).unwrap();
+
+    assert_eq!(loaded.version, SAVE_FORMAT_VERSION);
+    assert_eq!(loaded.workers[0].placed_at, Some(3));
+    assert_eq!(loaded.turn_order.passed, vec![0]);
+    assert_eq!(loaded.structures[0].structure_type, StructureType::Windmill);
+    assert_eq!(loaded.config.seed, Some(42));
+    assert!(loaded.festival_held);
+    assert_eq!(loaded.rng, Some(RngSave { seed: 42, position: 130 }));
+    assert_eq!(loaded.play_by_file.map(|s| s.seq), Some(4));
+}
+
+#[test]
+fn newer_save_format_is_refused() {
+    let path = write("viticulture_from_the_future.ron", &save_data(SAVE_FORMAT_VERSION + 1));
+    assert!(load_save_file(&path).is_err());
+}
+
+#[test]
+fn slots_live_in_the_saves_folder() {
+    assert_eq!(save_slot_path(QUICK_SAVE_SLOT), "saves/slot_1.ron");
+    assert_eq!(autosave_path(2), "saves/autosave_2.ron");
+}
+
+#[test]
+fn autosave_ring_overwrites_the_oldest() {
+    let at = |secs| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+    assert_eq!(next_autosave_index(&[at(5), None, None]), 1);
+    assert_eq!(next_autosave_index(&[at(5), at(3), at(9)]), 1);
+    assert_eq!(latest_autosave_index(&[at(5), at(3), at(9)]), Some(2));
+    assert_eq!(latest_autosave_index(&[None, None]), None);
+
+    let mut manager = SaveManager::default();
+    assert!(manager.autosave_due(2, &GameState::Summer));
+    assert!(!manager.autosave_due(2, &GameState::Summer));
+    assert!(manager.autosave_due(2, &GameState::Fall));
+}
@@ -0,0 +1,60 @@
+// Schedule check: duplicate system registrations and contradictory run conditions.
+
+use bevy::prelude::*;
+use viticulture::systems::schedule_audit::*;
+
+fn tick() {}
+
+fn other_tick() {}
+
+fn enabled() -> bool {
+    true
+}
+
+fn registered(name: &str, conditions: &[&str]) -> RegisteredSystem {
+    RegisteredSystem {
+        name: name.to_string(),
+        conditions: conditions.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+#[test]
+fn duplicates_are_reported_once_with_their_count() {
+    let systems = [registered("a", &[]), registered("b", &[]), registered("a", &[]), registered("a", &[])];
+    assert_eq!(audit_registrations("Update", &systems), vec![ScheduleProblem::Duplicate {
+        schedule: "Update".to_string(),
+        system: "a".to_string(),
+        count: 3,
+        conditions_differ: false,
+    }]);
+}
+
+#[test]
+fn differently_gated_copies_are_flagged_as_conflicting() {
+    let systems = [registered("a", &["in_summer"]), registered("a", &["in_summer && !testing"])];
+    let problems = audit_registrations("Update", &systems);
+    assert!(matches!(problems.as_slice(), [ScheduleProblem::Duplicate { conditions_differ: true, .. }]));
+    assert!(problems[0].to_string().contains("conflicting run conditions"));
+}
+
+#[test]
+fn a_condition_and_its_negation_never_run() {
+    let systems = [registered("a", &["testing", "in_summer && !testing"]), registered("b", &["summer || !summer"])];
+    assert_eq!(audit_registrations("Update", &systems), vec![ScheduleProblem::ContradictoryConditions {
+        schedule: "Update".to_string(),
+        system: "a".to_string(),
+        condition: "testing".to_string(),
+    }]);
+}
+
+#[test]
+fn registrations_are_read_from_a_real_schedule() {
+    let mut schedule = Schedule::default();
+    schedule.add_systems((tick, tick.run_if(enabled), other_tick));
+
+    let systems = schedule_registrations(&schedule);
+    assert_eq!(systems.len(), 3);
+    let problems = audit_registrations("Update", &systems);
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(&problems[0], ScheduleProblem::Duplicate { system, count: 2, conditions_differ: true, .. } if system.ends_with("::tick")));
+}
@@ -0,0 +1,87 @@
+// Sparkling aging variant: cellar tokens carry the year they were made, and fresh sparkling rests a year.
+
+use viticulture::components::*;
+use viticulture::systems::game_logic::*;
+
+fn cellars() -> Vec<Structure> {
+    [StructureType::MediumCellar, StructureType::LargeCellar].into_iter()
+        .map(|structure_type| Structure { structure_type, owner: PlayerId(0) })
+        .collect()
+}
+
+fn make_wine(vineyard: &mut Vineyard, year: u8) -> ActionOutcome {
+    let mut decks = CardDecks::new();
+    resolve_action(ActionSpace::MakeWine, year, None, Some(vineyard), None, None, &mut decks, &cellars(), false)
+}
+
+// Sparkling 9 on the red row from 2 red grapes and a white
+fn sparkling_cellar(year: u8) -> Vineyard {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_grapes.place(4);
+    vineyard.red_grapes.place(3);
+    vineyard.white_grapes.place(2);
+    assert!(matches!(make_wine(&mut vineyard, year), ActionOutcome::MadeWine { wine_type: WineType::Sparkling, value: 9 }));
+    vineyard
+}
+
+#[test]
+fn made_wine_is_tagged_with_its_year() {
+    let vineyard = sparkling_cellar(3);
+    let vintage = vineyard.vintage(WineType::Sparkling, 9).unwrap();
+    assert_eq!((vintage.wine_type, vintage.year, vintage.aged), (WineType::Sparkling, 3, 0));
+    assert!(vineyard.vintage(WineType::White, 9).is_none());
+}
+
+#[test]
+fn fresh_sparkling_only_fills_orders_without_the_house_rule() {
+    let order = WineOrderCard::new(202, 3, 0, 8, 7);
+    let mut vineyard = sparkling_cellar(2);
+    assert!(vineyard.can_fulfill_order(&order));
+
+    vineyard.sparkling_aging = true;
+    assert!(!vineyard.can_fulfill_order(&order));
+    assert!(!vineyard.take_order_wines(&order));
+    assert!(vineyard.red_wine.has(9));
+
+    // Plain red made the same year is not held back
+    vineyard.red_wine.place(5);
+    vineyard.record_vintage(WineType::Red, 5, 2);
+    assert!(vineyard.take_order_wines(&order));
+    assert_eq!(vineyard.red_wine.values(), vec![9]);
+}
+
+#[test]
+fn a_year_end_lets_sparkling_fill_orders() {
+    let order = WineOrderCard::new(202, 3, 0, 8, 7);
+    let mut vineyard = sparkling_cellar(2);
+    vineyard.sparkling_aging = true;
+    vineyard.apply_aging(&cellars());
+
+    let vintage = vineyard.vintage(WineType::Sparkling, 9).unwrap();
+    assert_eq!((vintage.year, vintage.aged), (2, 1));
+    assert!(vineyard.take_order_wines(&order));
+    assert!(vineyard.red_wine.is_empty());
+    assert!(vineyard.vintages.is_empty());
+}
+
+#[test]
+fn tags_follow_their_tokens_through_aging() {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.red_wine.place(2);
+    vineyard.red_wine.place(3);
+    vineyard.record_vintage(WineType::Red, 2, 1);
+    vineyard.white_wine.place(4);
+    vineyard.record_vintage(WineType::Blush, 4, 1);
+    vineyard.apply_aging(&cellars());
+
+    // 3 moves up first, then 2 follows into the slot it left
+    assert_eq!(vineyard.red_wine.values(), vec![3, 4]);
+    assert_eq!(vineyard.vintage(WineType::Red, 3).map(|v| v.aged), Some(1));
+    assert!(vineyard.vintage(WineType::Red, 4).is_none());
+    assert_eq!(vineyard.vintage(WineType::White, 5).map(|v| v.wine_type), Some(WineType::Blush));
+
+    // A tag whose token was spent some other way is dropped at the next year end
+    vineyard.red_wine.take(3);
+    vineyard.apply_aging(&cellars());
+    assert_eq!(vineyard.vintages.len(), 1);
+}
@@ -0,0 +1,35 @@
+// Split screen: which seats get a half and what each half shows.
+
+use viticulture::components::*;
+use viticulture::systems::hand_picker::vine_label;
+use viticulture::systems::split_screen::*;
+
+#[test]
+fn only_two_humans_get_halves() {
+    let two_and_ai = [
+        Player::new(2, "B".to_string(), false),
+        Player::new(1, "AI".to_string(), true),
+        Player::new(0, "A".to_string(), false),
+    ];
+    assert_eq!(split_screen_seats(two_and_ai.iter()), Some([PlayerId(0), PlayerId(2)]));
+
+    let three = [0, 1, 2].map(|i| Player::new(i, format!("P{}", i), false));
+    assert_eq!(split_screen_seats(three.iter()), None);
+    assert_eq!(split_screen_seats(three[..1].iter()), None);
+}
+
+#[test]
+fn a_half_lists_its_own_hand_and_marks_the_turn() {
+    let decks = CardDecks::new();
+    let player = Player::new(0, "Ana".to_string(), false);
+    let vineyard = Vineyard::new(PlayerId(0));
+    let mut hand = Hand::new(PlayerId(0));
+    hand.vine_cards.push(decks.vine_deck[0].clone());
+
+    let text = half_text(&player, &vineyard, &hand, &[StructureType::Trellis], true);
+    assert!(text.starts_with("▶ YOUR TURN\nAna"));
+    assert!(text.contains(&vine_label(&hand.vine_cards[0]).replace('\n', ", ")));
+    assert!(text.contains("Vines in hand (1)"));
+    assert!(text.contains("Structures: Trellis"));
+    assert!(!half_text(&player, &vineyard, &hand, &[], false).contains("YOUR TURN"));
+}
@@ -0,0 +1,32 @@
+// Build Structure tooltips: each structure's value worked out from the player's own state.
+
+use viticulture::components::*;
+use viticulture::systems::structure_roi::*;
+
+#[test]
+fn windmill_counts_the_lira_left_after_paying() {
+    let mut vineyard = Vineyard::new(PlayerId(0));
+    vineyard.lira = Lira::new(12);
+    let hand = Hand::new(PlayerId(0));
+    let ctx = RoiContext { vineyard: &vineyard, hand: &hand, structures: &[], years_left: 3 };
+
+    let line = structure_roi_line(StructureType::Windmill, &ctx);
+    assert!(line.contains("current 12 lira → +1 VP"), "{}", line);
+    assert!(line.contains("after ~3 tours"), "{}", line);
+}
+
+#[test]
+fn lists_only_unbuilt_structures_and_flags_what_is_unaffordable() {
+    let mut vineyard = Vineyard::new(PlayerId(1));
+    vineyard.lira = Lira::new(2);
+    vineyard.red_wine.place(3);
+    let hand = Hand::new(PlayerId(1));
+    let owned = [Structure { structure_type: StructureType::Trellis, owner: PlayerId(1) }];
+    let ctx = RoiContext { vineyard: &vineyard, hand: &hand, structures: &owned, years_left: 2 };
+
+    let text = build_menu_tooltip(&ctx);
+    assert!(!text.contains("Trellis"));
+    assert!(text.contains("MediumCellar (4 lira): 1 wine(s) held at 3"), "{}", text);
+    assert!(text.contains("need 2 more lira"));
+    assert!(text.contains("LargeCellar (6 lira): no effect until you own a Medium Cellar"));
+}
@@ -0,0 +1,81 @@
+// Undo/redo history: bounded snapshots, redo branches and the no-undo-after-draw check.
+
+use viticulture::components::*;
+use viticulture::systems::undo::*;
+
+// Snapshots told apart by whose turn it was
+fn snapshot(current_player: usize) -> GameSnapshot {
+    GameSnapshot {
+        players: Vec::new(),
+        vineyards: Vec::new(),
+        hands: Vec::new(),
+        workers: Vec::new(),
+        turn_order: TurnOrderSnapshot { current_player, passed: Vec::new() },
+        action_spaces: Vec::new(),
+        trackers: Vec::new(),
+        decks: CardDecks::new(),
+    }
+}
+
+#[test]
+fn undo_and_redo_walk_the_history() {
+    let mut undo = UndoSystem::default();
+    undo.record(snapshot(0));
+    undo.record(snapshot(1));
+
+    assert_eq!(undo.undo(snapshot(2)).unwrap().turn_order.current_player, 1);
+    assert_eq!(undo.undo(snapshot(1)).unwrap().turn_order.current_player, 0);
+    assert!(!undo.undo_available);
+    assert!(undo.undo(snapshot(0)).is_none());
+
+    assert_eq!(undo.redo(snapshot(0)).unwrap().turn_order.current_player, 1);
+    assert_eq!(undo.redo(snapshot(1)).unwrap().turn_order.current_player, 2);
+    assert!(undo.redo(snapshot(2)).is_none());
+    assert_eq!(undo.undo_count, 4);
+}
+
+#[test]
+fn new_action_discards_redo_branch() {
+    let mut undo = UndoSystem::default();
+    undo.record(snapshot(0));
+    undo.undo(snapshot(1));
+    assert_eq!(undo.redo.len(), 1);
+
+    undo.record(snapshot(0));
+    assert!(undo.redo.is_empty());
+    assert_eq!(undo.snapshots.len(), 1);
+}
+
+#[test]
+fn history_is_bounded() {
+    let mut undo = UndoSystem::default();
+    for i in 0..MAX_UNDO_HISTORY + 3 {
+        undo.record(snapshot(i));
+    }
+    assert_eq!(undo.snapshots.len(), MAX_UNDO_HISTORY);
+    assert_eq!(undo.snapshots[0].turn_order.current_player, 3);
+}
+
+#[test]
+fn drawing_a_card_reveals_information() {
+    let before = snapshot(0);
+    let mut after = snapshot(0);
+    assert!(!reveals_information(&before, &after));
+
+    after.decks.vine_deck.pop();
+    assert!(reveals_information(&before, &after));
+}
+
+#[test]
+fn shrinking_the_limit_drops_oldest_steps() {
+    let mut undo = UndoSystem::default();
+    for i in 0..5 {
+        undo.record(snapshot(i));
+    }
+    undo.set_max_snapshots(2);
+    assert_eq!(undo.snapshots.len(), 2);
+    assert_eq!(undo.snapshots[0].turn_order.current_player, 3);
+
+    undo.record(snapshot(5));
+    assert_eq!(undo.snapshots.len(), 2);
+}
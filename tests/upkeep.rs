@@ -0,0 +1,63 @@
+// Worker upkeep variant: the year-end rule itself, and its effect over simulator games.
+
+use viticulture::components::*;
+use viticulture::systems::simulation::SimGame;
+use viticulture::systems::upkeep::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const BALANCE_SEEDS: u64 = 20;
+const MAX_STEPS: usize = 5000;
+
+#[test]
+fn upkeep_pays_for_workers_beyond_the_second() {
+    let mut player = Player::new(0, "Upkeep".to_string(), false);
+    player.workers = 4;
+    player.lira = Lira::new(5);
+
+    assert_eq!(pay_worker_upkeep(&mut player), WorkerUpkeep { paid: 2, lost: 0 });
+    assert_eq!((player.workers, player.lira.get()), (4, 3));
+}
+
+#[test]
+fn unpaid_workers_leave() {
+    let mut player = Player::new(0, "Upkeep".to_string(), false);
+    player.workers = 5;
+    player.lira = Lira::new(1);
+
+    assert_eq!(pay_worker_upkeep(&mut player), WorkerUpkeep { paid: 1, lost: 2 });
+    assert_eq!((player.workers, player.lira.get()), (3, 0));
+}
+
+#[test]
+fn starting_workers_are_free() {
+    let mut player = Player::new(0, "Upkeep".to_string(), false);
+    player.lira = Lira::new(0);
+
+    assert_eq!(pay_worker_upkeep(&mut player), WorkerUpkeep::default());
+    assert_eq!(player.workers, UPKEEP_FREE_WORKERS);
+}
+
+#[test]
+fn upkeep_games_still_finish_with_the_base_crew() {
+    let config = GameConfig { player_count: 3, ai_count: 3, ..GameConfig::default() };
+
+    for seed in 0..BALANCE_SEEDS {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut game = SimGame::new(&config, &mut rng).with_worker_upkeep(true);
+        for _ in 0..MAX_STEPS {
+            if game.is_over() {
+                break;
+            }
+            game.step(&mut rng);
+        }
+        assert!(game.is_over(), "seed {} did not finish", seed);
+        for player in &game.players {
+            assert!(player.workers >= UPKEEP_FREE_WORKERS, "seed {}: {} ended with {} workers", seed, player.name, player.workers);
+        }
+    }
+
+    let report = upkeep_balance_report(&config, 0..BALANCE_SEEDS);
+    assert_eq!(report.games, BALANCE_SEEDS as u32);
+    assert!(report.avg_final_workers.1 >= UPKEEP_FREE_WORKERS as f32);
+}
@@ -0,0 +1,115 @@
+// Scripted visitors: parsing the card format and playing it all-or-nothing.
+
+use viticulture::components::*;
+use viticulture::systems::expansions::*;
+use viticulture::systems::visitor_script::*;
+use viticulture::systems::visitor_targeting::*;
+
+struct Table {
+    player: Player,
+    vineyard: Vineyard,
+    hand: Hand,
+    decks: CardDecks,
+}
+
+fn table() -> Table {
+    Table {
+        player: Player::new(0, "P1".to_string(), false),
+        vineyard: Vineyard::new(PlayerId(0)),
+        hand: Hand::new(PlayerId(0)),
+        decks: CardDecks::new(),
+    }
+}
+
+fn play(t: &mut Table, script: &str, cost: u8, choice: Option<usize>) -> Result<(), String> {
+    let script = VisitorScript::parse(script).unwrap();
+    run_visitor_script(&script, cost, choice, &mut t.player, &mut t.vineyard, &mut t.hand, &mut t.decks)
+}
+
+#[test]
+fn scripts_print_back_the_way_they_parse() {
+    let text = "pay 2 lira; draw 2 vine; trade 1 grape for 3 lira; choose (gain 3 lira | pay 1 order; gain 2 vp); plant";
+    let script = VisitorScript::parse(text).unwrap();
+    assert_eq!(script.steps.len(), 5);
+    assert_eq!(script.to_string(), text);
+    assert_eq!(VisitorScript::parse("gain 2 vps;draw 1 vines").unwrap().to_string(), "gain 2 vp; draw 1 vine");
+    assert_eq!(script.option_text(1).as_deref(), Some("pay 1 order; gain 2 vp"));
+}
+
+#[test]
+fn malformed_scripts_say_what_is_wrong() {
+    let error = |text: &str| VisitorScript::parse(text).unwrap_err();
+    assert!(error("gain 2 grape").contains("can't gain grape"));
+    assert!(error("pay 0 lira").contains("not an amount"));
+    assert!(error("dance").contains("unknown step"));
+    assert!(error("trade 1 grape 3 lira").contains("'for'"));
+    assert!(error("gain 1 lira;").contains("empty step"));
+    assert!(error("choose (gain 1 lira)").contains("two options"));
+    assert!(error("choose (gain 1 lira | choose (gain 1 vp | plant))").contains("inside another"));
+    assert!(error("choose (gain 1 lira | plant); choose (gain 1 vp | plant)").contains("only one choose"));
+    assert!(error("choose (gain 1 lira | plant").contains("parentheses"));
+}
+
+#[test]
+fn a_card_plays_all_its_steps_or_none() {
+    let mut t = table();
+    let vines = t.decks.vine_deck.len();
+    assert!(play(&mut t, "pay 2 lira; draw 2 vine; plant", 1, None).is_ok());
+    assert_eq!(t.player.lira.get(), 0);
+    assert_eq!(t.hand.vine_cards.len(), 1);
+    assert_eq!(t.decks.vine_deck.len(), vines - 2);
+    assert!(t.vineyard.fields[0].vine.is_some());
+
+    let before = (t.player.victory_points.get(), t.hand.vine_cards.len(), t.decks.vine_deck.len());
+    assert_eq!(play(&mut t, "gain 2 vp; draw 1 vine; pay 1 lira", 0, None), Err("not enough lira".to_string()));
+    assert_eq!((t.player.victory_points.get(), t.hand.vine_cards.len(), t.decks.vine_deck.len()), before);
+}
+
+#[test]
+fn trades_spend_the_cheapest_grape() {
+    let mut t = table();
+    t.vineyard.red_grapes.place(4);
+    t.vineyard.white_grapes.place(2);
+    assert!(play(&mut t, "trade 1 grape for 3 lira", 0, None).is_ok());
+    assert_eq!(t.player.lira.get(), 6);
+    assert!(t.vineyard.white_grapes.is_empty());
+    assert!(t.vineyard.red_grapes.has(4));
+}
+
+#[test]
+fn only_playable_options_are_offered() {
+    let t = table();
+    let script = VisitorScript::parse("choose (pay 3 lira; gain 2 vp | pay 1 order | gain 2 lira)").unwrap();
+    assert_eq!(script_choices(&script, 0, &t.player, &t.vineyard, &t.hand, &t.decks), vec![VisitorTarget::Choice(0), VisitorTarget::Choice(2)]);
+    // The cost comes first, leaving too little for the first option
+    assert_eq!(script_choices(&script, 1, &t.player, &t.vineyard, &t.hand, &t.decks), vec![VisitorTarget::Choice(2)]);
+
+    let mut t = table();
+    assert!(play(&mut t, "choose (gain 1 vp | gain 2 lira)", 0, None).is_err());
+    assert!(play(&mut t, "choose (gain 1 vp | gain 2 lira)", 0, Some(1)).is_ok());
+    assert_eq!((t.player.victory_points.get(), t.player.lira.get()), (0, 5));
+}
+
+#[test]
+fn card_files_skip_bad_lines_and_keep_the_rest() {
+    let text = "# comment\n\n3000 | Broker | summer | 0 | choose (gain 3 lira | draw 1 order)\n3001 | Oops | autumn | 0 | plant\n3002 | Patron | winter | 2 | gain 1 vp\n";
+    let (cards, errors) = parse_visitor_file(text);
+    assert_eq!(cards.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["Broker", "Patron"]);
+    assert_eq!(errors, vec!["line 4: 'autumn' is not summer or winter".to_string()]);
+    assert!(needs_target(&cards[0].effect));
+    assert!(!needs_target(&cards[1].effect));
+    assert_eq!(card_target_label(&cards[0], &VisitorTarget::Choice(1)), "draw 1 order");
+}
+
+#[test]
+fn the_shipped_visitors_all_parse() {
+    let text = std::fs::read_to_string(format!("{}/tuscany.visitors", VISITOR_SCRIPT_DIR)).unwrap();
+    let (cards, errors) = parse_visitor_file(&text);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert!(!cards.is_empty());
+
+    let mut deck = VisitorDeck::new();
+    let builtin = deck.summer_visitors.len() + deck.winter_visitors.len();
+    deck.add_visitors(load_visitor_scripts(VISITOR_SCRIPT_DIR));
+    assert_eq!(deck.summer_visitors.len() + deck.winter_visitors.len(), builtin + cards.len());
+}
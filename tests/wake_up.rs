@@ -0,0 +1,106 @@
+// Wake-up: the bundled charts, secret rankings settled in draft order, and blind bids for the temporary worker.
+
+use viticulture::components::*;
+use viticulture::systems::wake_up::*;
+
+#[test]
+fn the_built_in_charts_load_from_their_bundled_files() {
+    let tuscany = WakeUpChart::tuscany();
+    assert_eq!(tuscany.name, "Tuscany");
+    assert_eq!(tuscany.bonus_at(0), None);
+    assert_eq!(tuscany.bonus_at(4), Some(WakeUpBonus::GainLira(2)));
+    assert_eq!(WakeUpChart::base().rows.len(), 7);
+}
+
+#[test]
+fn rankings_resolve_in_draft_order() {
+    let mut draft = WakeUpDraft::default();
+    // Player 2 is furthest behind and wins any conflict
+    draft.start(2, &[(PlayerId(0), 5), (PlayerId(1), 3), (PlayerId(2), 1)]);
+
+    assert!(draft.submit_ranking(PlayerId(0), vec![6, 1]));
+    assert!(!draft.resolve_rankings());
+    assert!(draft.submit_ranking(PlayerId(2), vec![6, 2]));
+    assert!(draft.submit_ranking(PlayerId(1), vec![6]));
+    assert!(!draft.submit_ranking(PlayerId(1), vec![1]));
+
+    assert!(draft.resolve_rankings());
+    assert_eq!(draft.picks, vec![(PlayerId(2), 6), (PlayerId(1), 1), (PlayerId(0), 2)]);
+    assert!(draft.is_complete());
+}
+
+#[test]
+fn ai_ranks_paying_rows_first() {
+    let ranking = WakeUpDraft::ai_ranking(&WakeUpChart::base());
+    assert_eq!(ranking, vec![1, 2, 4, 5, 6, 3, 7]);
+}
+
+fn picked_draft() -> WakeUpDraft {
+    let mut draft = WakeUpDraft::default();
+    draft.start(1, &[(PlayerId(0), 0), (PlayerId(1), 0), (PlayerId(2), 0)]);
+    assert!(draft.pick(PlayerId(0), 5));
+    assert!(draft.pick(PlayerId(1), 2));
+    assert!(draft.pick(PlayerId(2), 7));
+    draft
+}
+
+#[test]
+fn bids_open_after_the_rows_and_the_top_bid_wins() {
+    let mut draft = WakeUpDraft::default();
+    draft.start(1, &[(PlayerId(0), 0), (PlayerId(1), 0)]);
+    assert_eq!(draft.next_to_bid(), None);
+    assert!(!draft.submit_bid(PlayerId(0), 2));
+
+    let mut draft = picked_draft();
+    assert!(draft.submit_bid(PlayerId(2), 1));
+    assert!(draft.submit_bid(PlayerId(0), 3));
+    assert!(!draft.submit_bid(PlayerId(0), 4));
+    assert!(!draft.resolve_bids());
+    assert_eq!(draft.next_to_bid(), Some(PlayerId(1)));
+    assert!(draft.submit_bid(PlayerId(1), 0));
+
+    assert!(draft.resolve_bids());
+    assert_eq!(draft.temp_worker, Some((PlayerId(0), 3)));
+    assert!(!draft.resolve_bids());
+    assert!(!draft.submit_bid(PlayerId(1), 5));
+}
+
+#[test]
+fn tied_bids_go_to_the_earlier_riser_and_zero_bids_hire_nobody() {
+    let mut draft = picked_draft();
+    for (player, bid) in [(0, 2), (1, 2), (2, 2)] {
+        draft.submit_bid(PlayerId(player), bid);
+    }
+    draft.resolve_bids();
+    assert_eq!(draft.temp_worker, Some((PlayerId(1), 2)));
+
+    let mut draft = picked_draft();
+    for player in 0..3 {
+        draft.submit_bid(PlayerId(player), 0);
+    }
+    assert!(draft.resolve_bids());
+    assert_eq!(draft.temp_worker, None);
+
+    // A new spring clears last year's bids
+    draft.start(2, &[(PlayerId(0), 0)]);
+    assert!(draft.bids.is_empty() && !draft.bids_revealed);
+}
+
+#[test]
+fn the_reveal_turns_bids_over_in_wake_up_order() {
+    let mut draft = picked_draft();
+    for (player, bid) in [(0, 1), (1, 0), (2, 3)] {
+        draft.submit_bid(PlayerId(player), bid);
+    }
+    draft.resolve_bids();
+
+    let mut reveal = TempWorkerBidReveal::new(&draft);
+    assert_eq!(reveal.lines, vec!["Player 2: 0 lira", "Player 1: 1 lira", "Player 3: 3 lira"]);
+    assert!(!reveal.text().contains("Player 2"));
+    reveal.shown = 1;
+    assert!(reveal.text().contains("Player 2") && !reveal.text().contains("Player 1"));
+    reveal.shown = 4;
+    assert!(reveal.text().ends_with("Player 3 hires the temporary worker for 3 lira"));
+    assert_eq!(WakeUpDraft::ai_bid(10), MAX_AI_BID);
+    assert_eq!(WakeUpDraft::ai_bid(2), 0);
+}
@@ -0,0 +1,37 @@
+// Worker count badges: the pool is read from the worker entities.
+
+use bevy::math::Vec2;
+use viticulture::components::*;
+use viticulture::systems::worker_badges::*;
+
+#[test]
+fn pool_splits_regular_grande_and_temporary_workers() {
+    let mut placed = Worker::new(PlayerId(0), false, Vec2::ZERO);
+    placed.placed_at = Some(ActionSpace::GiveTour);
+    let workers = [
+        (Worker::new(PlayerId(0), false, Vec2::ZERO), false),
+        (placed, false),
+        (Worker::new(PlayerId(0), true, Vec2::ZERO), false),
+        (Worker::new(PlayerId(0), false, Vec2::ZERO), true),
+        (Worker::new(PlayerId(1), false, Vec2::ZERO), false),
+    ];
+
+    let pool = worker_pool(PlayerId(0), workers.iter().map(|(w, t)| (w, *t)));
+    assert_eq!(pool, WorkerPool {
+        regular_free: 1,
+        regular_total: 2,
+        grande_free: 1,
+        grande_total: 1,
+        temporary_free: 1,
+        temporary_total: 1,
+    });
+    assert_eq!(pool.available(), 3);
+    assert_eq!(badge_label(&pool), "👷 1/2  ★ ready  ⏳ 1/1");
+}
+
+#[test]
+fn badge_leaves_out_what_the_player_does_not_have() {
+    let pool = WorkerPool { regular_free: 0, regular_total: 2, grande_total: 1, ..WorkerPool::default() };
+    assert_eq!(badge_label(&pool), "👷 0/2  ★ used");
+    assert_eq!(pool.available(), 0);
+}